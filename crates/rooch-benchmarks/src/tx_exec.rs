@@ -4,6 +4,8 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+use bitcoin::hashes::Hash;
+use bitcoin::BlockHash;
 use criterion::{Criterion, SamplingMode};
 use tracing::info;
 
@@ -69,6 +71,23 @@ pub fn tx_exec_benchmark(c: &mut Criterion) {
             if heights.is_empty() {
                 panic!("No btc block data found in {:?}, please set the btc rpc config to prepare the data.", btc_blk_dir);
             }
+            // The blocks are processed continuously from the first block
+            let start_height = heights[0];
+            let start_block = create_btc_blk_tx(
+                start_height,
+                &btc_blk_dir.join(format!("{}.hex", start_height)),
+            )
+            .unwrap();
+            let start_block_hash = BlockHash::from_slice(&start_block.block.block_hash).unwrap();
+            binding_test = tokio::runtime::Runtime::new()
+                .unwrap()
+                .block_on(async {
+                    binding_test::RustBindingTest::new_with_bitcoin_start_block(
+                        start_height,
+                        start_block_hash,
+                    )
+                })
+                .unwrap();
             for (cnt, height) in heights.into_iter().enumerate() {
                 if cnt >= tx_cnt {
                     break;
@@ -6,9 +6,11 @@ use crate::store_config::StoreConfig;
 use anyhow::Result;
 use clap::Parser;
 use moveos_config::{temp_dir, DataDirPath};
+use moveos_types::h256::H256;
 use once_cell::sync::Lazy;
 use rooch_types::crypto::RoochKeyPair;
 use rooch_types::genesis_config::GenesisConfig;
+use rooch_types::indexer::custom_index::CustomIndexConfig;
 use rooch_types::rooch_network::{BuiltinChainID, RoochChainID, RoochNetwork};
 use rooch_types::service_status::ServiceStatus;
 use rooch_types::service_type::ServiceType;
@@ -122,6 +124,24 @@ pub struct RoochOpt {
     /// The interval of sync BTC block, default is none.
     pub btc_sync_block_interval: Option<u64>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, env = "BTC_BLOCKS_DIR")]
+    /// The blocks directory of bitcoind, if set, the relayer reads the blocks from the blk*.dat files
+    /// instead of the Bitcoin RPC, it is used to import the historical blocks.
+    /// The blocks are located by the block index in `<blocks_dir>/index`, bitcoind should be stopped while reading.
+    pub btc_blocks_dir: Option<PathBuf>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    /// The RPC url of the node to fork, if set, the server forks the state of the remote node,
+    /// the missing state is fetched from the remote node and cached in the local store.
+    pub fork_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, requires = "fork_url")]
+    /// The state root of the remote node to fork, default is the latest state root.
+    pub fork_state_root: Option<H256>,
+
     /// The address of the sequencer account
     #[clap(long)]
     pub sequencer_account: Option<String>,
@@ -144,6 +164,41 @@ pub struct RoochOpt {
     #[clap(long, default_value_t, value_enum)]
     pub service_type: ServiceType,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    /// The port of the admin JSON-RPC server, it only listens on localhost.
+    /// If not set, the admin methods are served at `/admin` of the public port when the admin token is set.
+    pub admin_port: Option<u16>,
+
+    #[serde(skip_serializing)]
+    #[clap(long, env = "ROOCH_ADMIN_TOKEN")]
+    /// The bearer token required by the admin JSON-RPC methods in the `Authorization` header.
+    pub admin_token: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    /// The max number of pending transactions of a sender in the txpool, default is 64.
+    pub txpool_max_pending_per_sender: Option<usize>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    /// The pending transactions in the txpool are evicted after the seconds, default is 600.
+    pub txpool_tx_expiration_secs: Option<u64>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[clap(long = "custom-index")]
+    /// The custom index maintained by the indexer, in the format `<struct_tag>.<field>[.<field>]*`,
+    /// such as `0x42::market::Listing.price`. The option can be repeated to define multiple custom indexes.
+    /// The new custom indexes are backfilled from the existing objects on startup,
+    /// and the entries of the custom indexes removed from the option are deleted.
+    pub custom_indexes: Vec<CustomIndexConfig>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    /// The OTLP gRPC endpoint of the OpenTelemetry collector, such as `http://localhost:4317`.
+    /// If set, the tracing spans of the transaction lifecycle are exported to the collector.
+    pub otlp_endpoint: Option<String>,
+
     #[serde(skip)]
     #[clap(skip)]
     base: Option<Arc<BaseConfig>>,
@@ -173,6 +228,9 @@ impl RoochOpt {
             btc_rpc_password: None,
             btc_end_block_height: None,
             btc_sync_block_interval: None,
+            btc_blocks_dir: None,
+            fork_url: None,
+            fork_state_root: None,
             sequencer_account: None,
             proposer_account: None,
             da: DAConfig::default(),
@@ -181,6 +239,12 @@ impl RoochOpt {
             traffic_burst_size: None,
             base: None,
             service_type: ServiceType::default(),
+            admin_port: None,
+            admin_token: None,
+            txpool_max_pending_per_sender: None,
+            txpool_tx_expiration_secs: None,
+            custom_indexes: vec![],
+            otlp_endpoint: None,
         };
         opt.init()?;
         Ok(opt)
@@ -221,13 +285,16 @@ impl RoochOpt {
     }
 
     pub fn bitcoin_relayer_config(&self) -> Option<BitcoinRelayerConfig> {
-        self.btc_rpc_url.as_ref()?;
+        if self.btc_rpc_url.is_none() && self.btc_blocks_dir.is_none() {
+            return None;
+        }
         Some(BitcoinRelayerConfig {
-            btc_rpc_url: self.btc_rpc_url.clone().unwrap(),
-            btc_rpc_user_name: self.btc_rpc_username.clone().unwrap(),
-            btc_rpc_password: self.btc_rpc_password.clone().unwrap(),
+            btc_rpc_url: self.btc_rpc_url.clone().unwrap_or_default(),
+            btc_rpc_user_name: self.btc_rpc_username.clone().unwrap_or_default(),
+            btc_rpc_password: self.btc_rpc_password.clone().unwrap_or_default(),
             btc_end_block_height: self.btc_end_block_height,
             btc_sync_block_interval: self.btc_sync_block_interval,
+            btc_blocks_dir: self.btc_blocks_dir.clone(),
         })
     }
 
@@ -291,6 +358,14 @@ pub struct BitcoinRelayerConfig {
     pub btc_rpc_password: String,
     pub btc_end_block_height: Option<u64>,
     pub btc_sync_block_interval: Option<u64>,
+    pub btc_blocks_dir: Option<PathBuf>,
+}
+
+impl BitcoinRelayerConfig {
+    /// The Bitcoin RPC is optional when the relayer reads the blocks from the block files
+    pub fn has_rpc(&self) -> bool {
+        !self.btc_rpc_url.is_empty()
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
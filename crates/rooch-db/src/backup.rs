@@ -0,0 +1,381 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::RoochDB;
+use accumulator::{Accumulator, MerkleAccumulator};
+use anyhow::{anyhow, ensure, Result};
+use moveos_store::config_store::ConfigStore;
+use moveos_store::transaction_store::TransactionStore as TxExecutionInfoStore;
+use moveos_store::MoveOSStore;
+use rooch_indexer::indexer_reader::IndexerReader;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::transaction_store::TransactionStore;
+use rooch_store::RoochStore;
+use rooch_types::backup::{BackupManifest, BACKUP_INDEXER_SUBDIR, BACKUP_STORE_SUBDIR};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+impl RoochDB {
+    /// Take a backup of the moveos and rooch stores, and the indexer dbs into `backup_dir`,
+    /// the node must be stopped. The running node takes the backup via `backup_indexer` and
+    /// `backup_store` of its actors, then `finish_backup`.
+    pub fn backup(&self, backup_dir: &Path) -> Result<BackupManifest> {
+        Self::prepare_backup_dir(backup_dir)?;
+        self.indexer_store
+            .backup_to(&backup_dir.join(BACKUP_INDEXER_SUBDIR))?;
+        self.backup_store(backup_dir)?;
+        Self::finish_backup(backup_dir)
+    }
+
+    /// Make sure the `backup_dir` is empty before taking a backup.
+    pub fn prepare_backup_dir(backup_dir: &Path) -> Result<()> {
+        ensure!(
+            is_empty_dir(backup_dir)?,
+            "Backup dir {:?} is not empty",
+            backup_dir.display()
+        );
+        std::fs::create_dir_all(backup_dir)?;
+        Ok(())
+    }
+
+    /// Create a checkpoint of the moveos and rooch stores in `backup_dir`.
+    /// The moveos and rooch stores share one RocksDB instance, so the checkpoint of them is atomic.
+    /// The caller should make sure no transaction is executing during the checkpoint,
+    /// the pipeline processor does this for the online backup.
+    pub fn backup_store(&self, backup_dir: &Path) -> Result<()> {
+        let db = self
+            .store_instance
+            .db()
+            .ok_or_else(|| anyhow!("The store instance is not a RocksDB instance"))?;
+        db.create_checkpoint(backup_dir.join(BACKUP_STORE_SUBDIR))
+    }
+
+    /// Check the store checkpoint and the indexer copy in `backup_dir`, and save the backup manifest.
+    /// The indexer must be copied before the store checkpoint is created, so the indexer copy never
+    /// gets ahead of the store, the indexer catches up the gap on the node startup after restoring.
+    pub fn finish_backup(backup_dir: &Path) -> Result<BackupManifest> {
+        // Read the roots from the backup instead of the live db, so the manifest always matches the backup.
+        let (moveos_store, rooch_store) =
+            Self::open_backup_stores(&backup_dir.join(BACKUP_STORE_SUBDIR))?;
+        let mut manifest = Self::check_consistency(&moveos_store, &rooch_store)?;
+        manifest.indexer_tx_order =
+            Self::check_backup_indexer(&backup_dir.join(BACKUP_INDEXER_SUBDIR), manifest.tx_order)?;
+        manifest.timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+        manifest.save(backup_dir)?;
+        Ok(manifest)
+    }
+
+    /// Validate the backup in `backup_dir`, then restore it to the `store_dir` and `indexer_dir`.
+    /// The node must be stopped, and the target dirs must not exist or be empty.
+    pub fn restore(
+        backup_dir: &Path,
+        store_dir: &Path,
+        indexer_dir: &Path,
+    ) -> Result<BackupManifest> {
+        let manifest = Self::validate_backup(backup_dir)?;
+        for dir in [store_dir, indexer_dir] {
+            ensure!(
+                is_empty_dir(dir)?,
+                "Restore target dir {:?} is not empty, please remove it first",
+                dir.display()
+            );
+        }
+        copy_dir(&backup_dir.join(BACKUP_STORE_SUBDIR), store_dir)?;
+        copy_dir(&backup_dir.join(BACKUP_INDEXER_SUBDIR), indexer_dir)?;
+        Ok(manifest)
+    }
+
+    /// Check the sequencer info, startup info and accumulator root of the backup are consistent,
+    /// the last indexed tx order of the indexer is not ahead of the store, and they match the backup manifest.
+    pub fn validate_backup(backup_dir: &Path) -> Result<BackupManifest> {
+        let manifest = BackupManifest::load(backup_dir)?;
+        let (moveos_store, rooch_store) =
+            Self::open_backup_stores(&backup_dir.join(BACKUP_STORE_SUBDIR))?;
+        let checked = Self::check_consistency(&moveos_store, &rooch_store)?;
+        ensure!(
+            checked.tx_order == manifest.tx_order
+                && checked.tx_hash == manifest.tx_hash
+                && checked.state_root == manifest.state_root
+                && checked.size == manifest.size
+                && checked.accumulator_root == manifest.accumulator_root,
+            "The backup store {:?} does not match the backup manifest {:?}",
+            checked,
+            manifest
+        );
+        let indexer_tx_order =
+            Self::check_backup_indexer(&backup_dir.join(BACKUP_INDEXER_SUBDIR), checked.tx_order)?;
+        ensure!(
+            indexer_tx_order == manifest.indexer_tx_order,
+            "The last indexed tx order {:?} of the backup indexer does not match the backup manifest {:?}",
+            indexer_tx_order,
+            manifest.indexer_tx_order
+        );
+        Ok(manifest)
+    }
+
+    /// Return the last indexed tx order of the backup indexer, it should not be ahead of the store.
+    fn check_backup_indexer(indexer_dir: &Path, tx_order: u64) -> Result<Option<u64>> {
+        ensure!(
+            indexer_dir.is_dir(),
+            "The indexer backup dir {:?} does not exist",
+            indexer_dir.display()
+        );
+        let registry = prometheus::Registry::new();
+        let indexer_reader = IndexerReader::new(indexer_dir.to_path_buf(), &registry)?;
+        let indexer_tx_order = indexer_reader.query_last_indexed_tx_order()?;
+        ensure!(
+            indexer_tx_order.map_or(true, |indexer_tx_order| indexer_tx_order <= tx_order),
+            "The last indexed tx order {:?} of the backup indexer is ahead of the backup store {}",
+            indexer_tx_order,
+            tx_order
+        );
+        Ok(indexer_tx_order)
+    }
+
+    fn open_backup_stores(store_dir: &Path) -> Result<(MoveOSStore, RoochStore)> {
+        ensure!(
+            store_dir.is_dir(),
+            "The store backup dir {:?} does not exist",
+            store_dir.display()
+        );
+        let registry = prometheus::Registry::new();
+        let mut instance =
+            Self::open_store_instance(store_dir, true, Default::default(), &registry)?;
+        // The backup stores are short-lived, no need to report the metrics
+        instance.cancel_metrics_task()?;
+        let moveos_store = MoveOSStore::new_with_instance(instance.clone(), &registry)?;
+        let rooch_store = RoochStore::new_with_instance(instance, &registry)?;
+        Ok((moveos_store, rooch_store))
+    }
+
+    fn check_consistency(
+        moveos_store: &MoveOSStore,
+        rooch_store: &RoochStore,
+    ) -> Result<BackupManifest> {
+        let sequencer_info = rooch_store
+            .get_sequencer_info()?
+            .ok_or_else(|| anyhow!("Load sequencer info failed"))?;
+        let startup_info = moveos_store
+            .get_startup_info()?
+            .ok_or_else(|| anyhow!("Load startup info failed"))?;
+        let last_order = sequencer_info.last_order;
+        let accumulator_info = sequencer_info.last_accumulator_info;
+
+        // The accumulator leaves are the tx hashes, indexed by tx order.
+        ensure!(
+            accumulator_info.num_leaves == last_order + 1,
+            "The accumulator leaves {} do not match the last order {}",
+            accumulator_info.num_leaves,
+            last_order
+        );
+        let tx_accumulator = MerkleAccumulator::new_with_info(
+            accumulator_info.clone(),
+            rooch_store.get_transaction_accumulator_store(),
+        );
+        let tx_hash = rooch_store
+            .get_tx_hashes(vec![last_order])?
+            .pop()
+            .flatten()
+            .ok_or_else(|| anyhow!("tx hash not exist via tx order {}", last_order))?;
+        let leaf = tx_accumulator.get_leaf(last_order)?;
+        ensure!(
+            leaf == Some(tx_hash),
+            "The accumulator leaf {:?} does not match the tx hash {} at order {}",
+            leaf,
+            tx_hash,
+            last_order
+        );
+        let ledger_tx = rooch_store
+            .get_transaction_by_hash(tx_hash)?
+            .ok_or_else(|| anyhow!("the ledger tx not exist via tx_hash {}", tx_hash))?;
+        ensure!(
+            ledger_tx.sequence_info.tx_accumulator_root == accumulator_info.accumulator_root,
+            "The accumulator root {} of tx order {} does not match the sequencer info {}",
+            ledger_tx.sequence_info.tx_accumulator_root,
+            last_order,
+            accumulator_info.accumulator_root
+        );
+
+        // The last sequenced txs may not be executed yet, they will be executed on startup,
+        // so the startup info should match the last executed tx.
+        let mut order = last_order;
+        let execution_info = loop {
+            let hash = rooch_store
+                .get_tx_hashes(vec![order])?
+                .pop()
+                .flatten()
+                .ok_or_else(|| anyhow!("tx hash not exist via tx order {}", order))?;
+            if let Some(execution_info) = moveos_store.get_tx_execution_info(hash)? {
+                break execution_info;
+            }
+            ensure!(order > 0, "No executed tx found in the store");
+            order -= 1;
+        };
+        ensure!(
+            startup_info.state_root == execution_info.state_root
+                && startup_info.size == execution_info.size,
+            "The startup info {:?} does not match the execution info of tx order {}: {:?}",
+            startup_info,
+            order,
+            execution_info
+        );
+
+        Ok(BackupManifest {
+            tx_order: last_order,
+            tx_hash,
+            state_root: startup_info.state_root,
+            size: startup_info.size,
+            accumulator_root: accumulator_info.accumulator_root,
+            indexer_tx_order: None,
+            timestamp: 0,
+        })
+    }
+}
+
+fn is_empty_dir(dir: &Path) -> Result<bool> {
+    if !dir.exists() {
+        return Ok(true);
+    }
+    Ok(std::fs::read_dir(dir)?.next().is_none())
+}
+
+fn copy_dir(from: &Path, to: &Path) -> Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let target: PathBuf = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use accumulator::accumulator_info::AccumulatorInfo;
+    use move_core_types::vm_status::KeptVMStatus;
+    use moveos_types::h256::H256;
+    use moveos_types::moveos_std::tx_context::TxContext;
+    use moveos_types::startup_info::StartupInfo;
+    use moveos_types::test_utils::random_verified_move_action;
+    use moveos_types::transaction::TransactionExecutionInfo;
+    use rooch_config::RoochOpt;
+    use rooch_indexer::store::traits::IndexerStoreTrait;
+    use rooch_types::indexer::transaction::IndexerTransaction;
+    use rooch_types::sequencer::SequencerInfo;
+    use rooch_types::test_utils::random_ledger_transaction;
+
+    /// Save the first transaction, its execution info, the sequencer info and the startup info,
+    /// and index the transaction if `indexed` is true.
+    fn save_first_tx(rooch_db: &RoochDB, indexed: bool) -> Result<()> {
+        let mut ledger_tx = random_ledger_transaction();
+        let tx_hash = ledger_tx.tx_hash();
+        let tx_accumulator = MerkleAccumulator::new_with_info(
+            AccumulatorInfo::default(),
+            rooch_db.rooch_store.get_transaction_accumulator_store(),
+        );
+        let accumulator_root = tx_accumulator.append(&[tx_hash])?;
+        tx_accumulator.flush()?;
+        ledger_tx.sequence_info.tx_order = 0;
+        ledger_tx.sequence_info.tx_accumulator_root = accumulator_root;
+        rooch_db.rooch_store.save_transaction(ledger_tx.clone())?;
+        rooch_db
+            .rooch_store
+            .get_meta_store()
+            .save_sequencer_info_ignore_check(SequencerInfo::new(0, tx_accumulator.get_info()))?;
+
+        let execution_info = TransactionExecutionInfo::new(
+            tx_hash,
+            H256::random(),
+            1,
+            H256::random(),
+            0,
+            KeptVMStatus::Executed,
+        );
+        rooch_db
+            .moveos_store
+            .save_tx_execution_info(execution_info.clone())?;
+        rooch_db
+            .moveos_store
+            .get_config_store()
+            .save_startup_info(StartupInfo::new(
+                execution_info.state_root,
+                execution_info.size,
+            ))?;
+        if indexed {
+            rooch_db
+                .indexer_store
+                .persist_transactions(vec![IndexerTransaction::new(
+                    ledger_tx,
+                    execution_info,
+                    random_verified_move_action().into(),
+                    TxContext::random_for_testing_only(),
+                )?])?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_backup_restore_and_validate() -> Result<()> {
+        let opt = RoochOpt::new_with_temp_store()?;
+        let rooch_db = RoochDB::init_with_mock_metrics_for_test(opt.store_config())?;
+        save_first_tx(&rooch_db, true)?;
+
+        let backup_dir = moveos_config::temp_dir();
+        let manifest = rooch_db.backup(backup_dir.path())?;
+        assert_eq!(manifest.tx_order, 0);
+        assert_eq!(manifest.indexer_tx_order, Some(0));
+        assert_eq!(RoochDB::validate_backup(backup_dir.path())?, manifest);
+        // The backup dir must be empty
+        assert!(rooch_db.backup(backup_dir.path()).is_err());
+
+        let restore_opt = RoochOpt::new_with_temp_store()?;
+        let store_config = restore_opt.store_config();
+        let restored = RoochDB::restore(
+            backup_dir.path(),
+            &store_config.get_store_dir(),
+            &store_config.get_indexer_dir(),
+        )?;
+        assert_eq!(restored, manifest);
+        let restored_db = RoochDB::init_with_mock_metrics_for_test(store_config)?;
+        let startup_info = restored_db.moveos_store.get_startup_info()?.unwrap();
+        assert_eq!(startup_info.state_root, manifest.state_root);
+        assert_eq!(
+            restored_db.indexer_reader.query_last_indexed_tx_order()?,
+            Some(0)
+        );
+        // The restore target dirs must be empty
+        assert!(RoochDB::restore(
+            backup_dir.path(),
+            &store_config.get_store_dir(),
+            &store_config.get_indexer_dir(),
+        )
+        .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_backup_with_mismatched_manifest() -> Result<()> {
+        let opt = RoochOpt::new_with_temp_store()?;
+        let rooch_db = RoochDB::init_with_mock_metrics_for_test(opt.store_config())?;
+        save_first_tx(&rooch_db, false)?;
+
+        let backup_dir = moveos_config::temp_dir();
+        let mut manifest = rooch_db.backup(backup_dir.path())?;
+        assert_eq!(manifest.indexer_tx_order, None);
+
+        manifest.indexer_tx_order = Some(0);
+        manifest.save(backup_dir.path())?;
+        assert!(RoochDB::validate_backup(backup_dir.path()).is_err());
+
+        manifest.indexer_tx_order = None;
+        manifest.state_root = H256::random();
+        manifest.save(backup_dir.path())?;
+        assert!(RoochDB::validate_backup(backup_dir.path()).is_err());
+        Ok(())
+    }
+}
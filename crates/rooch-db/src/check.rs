@@ -0,0 +1,485 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::RoochDB;
+use accumulator::inmemory::InMemoryAccumulator;
+use anyhow::{anyhow, Result};
+use moveos_store::config_store::ConfigStore;
+use moveos_store::transaction_store::TransactionStore as TxExecutionInfoStore;
+use moveos_types::moveos_std::object::GENESIS_STATE_ROOT;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::transaction_store::TransactionStore;
+use serde::{Deserialize, Serialize};
+use smt::NodeReader;
+
+const CHECK_BATCH_SIZE: u64 = 1000;
+
+/// A range of tx orders, both ends are inclusive
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OrderRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+/// The result of `RoochDB::check`, the gaps are the tx order ranges which fail the check.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DBCheckReport {
+    pub last_order: u64,
+    /// The last tx order which has execution info
+    pub last_executed_order: Option<u64>,
+    pub last_indexed_order: Option<u64>,
+    pub missing_tx_hashes: Vec<OrderRange>,
+    pub missing_transactions: Vec<OrderRange>,
+    pub missing_execution_infos: Vec<OrderRange>,
+    /// The state root of the execution info is not in the state node store
+    pub missing_state_roots: Vec<OrderRange>,
+    /// The first tx order whose accumulator root does not match the rebuilt accumulator
+    pub accumulator_mismatch_order: Option<u64>,
+    /// The sequencer info does not match the accumulator rebuilt from all the tx hashes
+    pub sequencer_info_mismatch: bool,
+    /// The startup info does not match the execution info of the last executed tx
+    pub startup_info_mismatch: bool,
+    pub errors: Vec<String>,
+    pub repair_actions: Vec<String>,
+}
+
+impl DBCheckReport {
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    fn first_bad_order(&self) -> Option<u64> {
+        [
+            &self.missing_tx_hashes,
+            &self.missing_transactions,
+            &self.missing_execution_infos,
+            &self.missing_state_roots,
+        ]
+        .into_iter()
+        .filter_map(|gaps| gaps.first().map(|range| range.start))
+        .chain(self.accumulator_mismatch_order)
+        .min()
+    }
+
+    /// `rooch db rollback --tx-order` only succeeds if the order is before the last order,
+    /// the tx of the order is complete and no tx hash is missing after the order.
+    fn can_rollback_to(&self, tx_order: u64) -> bool {
+        let in_gaps = |gaps: &Vec<OrderRange>| {
+            gaps.iter()
+                .any(|range| range.start <= tx_order && tx_order <= range.end)
+        };
+        tx_order < self.last_order
+            && self
+                .missing_tx_hashes
+                .iter()
+                .all(|range| range.end < tx_order)
+            && !in_gaps(&self.missing_transactions)
+            && !in_gaps(&self.missing_execution_infos)
+    }
+
+    fn suggest_rollback(&mut self, action: &str, tx_order: Option<u64>) {
+        match tx_order.filter(|tx_order| self.can_rollback_to(*tx_order)) {
+            Some(tx_order) => self.repair_actions.push(format!(
+                "{} by `rooch db rollback --tx-order {}`",
+                action, tx_order
+            )),
+            None => self.repair_actions.push(format!(
+                "{}, but it can not be done by `rooch db rollback`, please restore the db from a backup by `rooch db restore`",
+                action
+            )),
+        }
+    }
+
+    fn suggest_repair_actions(&mut self) {
+        if self.is_ok() {
+            return;
+        }
+        let only_unexecuted_tail = self.missing_tx_hashes.is_empty()
+            && self.missing_transactions.is_empty()
+            && self.missing_state_roots.is_empty()
+            && self.accumulator_mismatch_order.is_none()
+            && self.missing_execution_infos.len() == 1
+            && self.missing_execution_infos[0].end == self.last_order;
+        if only_unexecuted_tail {
+            let start = self.missing_execution_infos[0].start;
+            match start
+                .checked_sub(1)
+                .filter(|tx_order| self.can_rollback_to(*tx_order))
+            {
+                Some(tx_order) => self.repair_actions.push(format!(
+                    "The txs from order {} are sequenced but not executed, they will be executed when the node starts, or rollback them by `rooch db rollback --tx-order {}`",
+                    start, tx_order
+                )),
+                None => self.repair_actions.push(format!(
+                    "The txs from order {} are sequenced but not executed, they will be executed when the node starts",
+                    start
+                )),
+            }
+        } else if let Some(first_bad_order) = self.first_bad_order() {
+            self.suggest_rollback(
+                "Rollback the state to the last consistent tx order",
+                first_bad_order.checked_sub(1),
+            );
+        } else if self.sequencer_info_mismatch {
+            self.suggest_rollback(
+                "Reset the sequencer info from the ledger txs",
+                self.last_order.checked_sub(1),
+            );
+        } else if self.startup_info_mismatch {
+            // The rollback target must be before the last order, the last executed tx is dropped if it is the last tx.
+            let tx_order = self.last_executed_order.and_then(|tx_order| {
+                if tx_order < self.last_order {
+                    Some(tx_order)
+                } else {
+                    tx_order.checked_sub(1)
+                }
+            });
+            self.suggest_rollback("Reset the startup info to an executed tx", tx_order);
+        }
+
+        match (self.last_indexed_order, self.last_executed_order) {
+            (Some(indexed), Some(executed)) if indexed > executed => {
+                self.repair_actions.push(format!(
+                    "The indexer is ahead of the store after the tx order {}, rebuild the indexer by `rooch indexer rebuild`",
+                    executed
+                ));
+            }
+            (indexed, Some(executed)) if indexed.map(|v| v < executed).unwrap_or(true) => {
+                self.repair_actions.push(format!(
+                    "The indexer is behind the store from the tx order {}, rebuild the indexer by `rooch indexer rebuild`",
+                    indexed.map(|v| v + 1).unwrap_or_default()
+                ));
+            }
+            _ => {}
+        }
+    }
+}
+
+fn push_gap(gaps: &mut Vec<OrderRange>, order: u64) {
+    match gaps.last_mut() {
+        Some(range) if range.end + 1 == order => range.end = order,
+        _ => gaps.push(OrderRange {
+            start: order,
+            end: order,
+        }),
+    }
+}
+
+impl RoochDB {
+    /// Walk the rooch and moveos stores from the genesis to the last tx order, check every tx order
+    /// has a tx and execution info, the state roots exist in the state node store,
+    /// the tx accumulator rebuilt from the tx hashes matches the sequencer info,
+    /// and the startup info and indexer match the last executed tx.
+    pub fn check(&self) -> Result<DBCheckReport> {
+        let sequencer_info = self
+            .rooch_store
+            .get_sequencer_info()?
+            .ok_or_else(|| anyhow!("Load sequencer info failed"))?;
+        let last_order = sequencer_info.last_order;
+        let mut report = DBCheckReport {
+            last_order,
+            ..Default::default()
+        };
+
+        let mut accumulator = InMemoryAccumulator::default();
+        let mut last_execution_info = None;
+        let mut start = 0;
+        while start <= last_order {
+            let end = std::cmp::min(start + CHECK_BATCH_SIZE, last_order + 1);
+            let orders = (start..end).collect::<Vec<_>>();
+            let tx_hashes = self.rooch_store.get_tx_hashes(orders.clone())?;
+
+            let mut hashes = vec![];
+            for (order, tx_hash) in orders.iter().zip(tx_hashes) {
+                match tx_hash {
+                    Some(tx_hash) => hashes.push((*order, tx_hash)),
+                    None => push_gap(&mut report.missing_tx_hashes, *order),
+                }
+            }
+            let tx_hashes = hashes.iter().map(|(_, hash)| *hash).collect::<Vec<_>>();
+            let ledger_txs = self
+                .rooch_store
+                .get_transactions_by_hash(tx_hashes.clone())?;
+            let execution_infos = self
+                .moveos_store
+                .get_transaction_store()
+                .multi_get_tx_execution_infos(tx_hashes)?;
+
+            for (((order, tx_hash), ledger_tx), execution_info) in
+                hashes.into_iter().zip(ledger_txs).zip(execution_infos)
+            {
+                // The accumulator can not be rebuilt once a leaf is missing
+                if report.missing_tx_hashes.is_empty() {
+                    accumulator = accumulator.append(&[tx_hash]);
+                }
+                match ledger_tx {
+                    Some(ledger_tx) => {
+                        if report.missing_tx_hashes.is_empty()
+                            && report.accumulator_mismatch_order.is_none()
+                            && accumulator.root_hash()
+                                != ledger_tx.sequence_info.tx_accumulator_root
+                        {
+                            report.accumulator_mismatch_order = Some(order);
+                        }
+                    }
+                    None => push_gap(&mut report.missing_transactions, order),
+                }
+                match execution_info {
+                    Some(execution_info) => {
+                        if execution_info.state_root != *GENESIS_STATE_ROOT
+                            && self
+                                .moveos_store
+                                .get_state_node_store()
+                                .get(&execution_info.state_root)?
+                                .is_none()
+                        {
+                            push_gap(&mut report.missing_state_roots, order);
+                        }
+                        report.last_executed_order = Some(order);
+                        last_execution_info = Some(execution_info);
+                    }
+                    None => push_gap(&mut report.missing_execution_infos, order),
+                }
+            }
+            start = end;
+        }
+
+        for (name, gaps) in [
+            ("tx hashes", &report.missing_tx_hashes),
+            ("transactions", &report.missing_transactions),
+            ("execution infos", &report.missing_execution_infos),
+            ("state roots", &report.missing_state_roots),
+        ] {
+            if !gaps.is_empty() {
+                report
+                    .errors
+                    .push(format!("Missing {} at tx orders {:?}", name, gaps));
+            }
+        }
+        if let Some(order) = report.accumulator_mismatch_order {
+            report.errors.push(format!(
+                "The rebuilt tx accumulator root does not match the tx at order {}",
+                order
+            ));
+        } else if report.missing_tx_hashes.is_empty()
+            && (accumulator.root_hash() != sequencer_info.last_accumulator_info.accumulator_root
+                || accumulator.num_leaves() != sequencer_info.last_accumulator_info.num_leaves)
+        {
+            report.sequencer_info_mismatch = true;
+            report.errors.push(format!(
+                "The rebuilt tx accumulator root {} with {} leaves does not match the sequencer info {:?}",
+                accumulator.root_hash(),
+                accumulator.num_leaves(),
+                sequencer_info.last_accumulator_info
+            ));
+        }
+
+        let startup_info = self.moveos_store.get_config_store().get_startup_info()?;
+        match (&startup_info, &last_execution_info) {
+            (Some(startup_info), Some(execution_info)) => {
+                if startup_info.state_root != execution_info.state_root
+                    || startup_info.size != execution_info.size
+                {
+                    report.startup_info_mismatch = true;
+                    report.errors.push(format!(
+                        "The startup info {:?} does not match the execution info of the last executed tx order {:?}",
+                        startup_info, report.last_executed_order
+                    ));
+                }
+            }
+            (None, _) => {
+                report.startup_info_mismatch = true;
+                report
+                    .errors
+                    .push("The startup info is missing".to_string())
+            }
+            (_, None) => report
+                .errors
+                .push("No executed tx found in the store".to_string()),
+        }
+
+        report.last_indexed_order = self
+            .indexer_reader
+            .query_last_indexed_tx_order()
+            .map_err(|e| anyhow!("Query last indexed tx order error: {:?}", e))?;
+        if report.last_indexed_order != report.last_executed_order {
+            report.errors.push(format!(
+                "The last indexed tx order {:?} does not match the last executed tx order {:?}",
+                report.last_indexed_order, report.last_executed_order
+            ));
+        }
+
+        report.suggest_repair_actions();
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use accumulator::{Accumulator, MerkleAccumulator};
+    use move_core_types::vm_status::KeptVMStatus;
+    use moveos_types::h256::H256;
+    use moveos_types::moveos_std::tx_context::TxContext;
+    use moveos_types::startup_info::StartupInfo;
+    use moveos_types::test_utils::random_verified_move_action;
+    use moveos_types::transaction::TransactionExecutionInfo;
+    use rooch_config::RoochOpt;
+    use rooch_indexer::store::traits::IndexerStoreTrait;
+    use rooch_types::indexer::transaction::IndexerTransaction;
+    use rooch_types::sequencer::SequencerInfo;
+    use rooch_types::test_utils::random_ledger_transaction;
+
+    /// Save `count` sequenced txs, execute and index all of them except the `unexecuted` orders,
+    /// and return the tx hashes.
+    fn save_txs(rooch_db: &RoochDB, count: u64, unexecuted: &[u64]) -> Result<Vec<H256>> {
+        let tx_accumulator = MerkleAccumulator::new_with_info(
+            Default::default(),
+            rooch_db.rooch_store.get_transaction_accumulator_store(),
+        );
+        let mut tx_hashes = vec![];
+        let mut last_execution_info = None;
+        for tx_order in 0..count {
+            let mut ledger_tx = random_ledger_transaction();
+            let tx_hash = ledger_tx.tx_hash();
+            ledger_tx.sequence_info.tx_order = tx_order;
+            ledger_tx.sequence_info.tx_accumulator_root = tx_accumulator.append(&[tx_hash])?;
+            rooch_db.rooch_store.save_transaction(ledger_tx.clone())?;
+            tx_hashes.push(tx_hash);
+            if unexecuted.contains(&tx_order) {
+                continue;
+            }
+
+            let execution_info = TransactionExecutionInfo::new(
+                tx_hash,
+                H256::random(),
+                1,
+                H256::random(),
+                0,
+                KeptVMStatus::Executed,
+            );
+            rooch_db
+                .moveos_store
+                .get_state_node_store()
+                .put(execution_info.state_root, vec![0])?;
+            rooch_db
+                .moveos_store
+                .save_tx_execution_info(execution_info.clone())?;
+            rooch_db
+                .indexer_store
+                .persist_transactions(vec![IndexerTransaction::new(
+                    ledger_tx,
+                    execution_info.clone(),
+                    random_verified_move_action().into(),
+                    TxContext::random_for_testing_only(),
+                )?])?;
+            last_execution_info = Some(execution_info);
+        }
+        tx_accumulator.flush()?;
+        rooch_db
+            .rooch_store
+            .get_meta_store()
+            .save_sequencer_info_ignore_check(SequencerInfo::new(
+                count - 1,
+                tx_accumulator.get_info(),
+            ))?;
+        if let Some(execution_info) = last_execution_info {
+            rooch_db
+                .moveos_store
+                .get_config_store()
+                .save_startup_info(StartupInfo::new(
+                    execution_info.state_root,
+                    execution_info.size,
+                ))?;
+        }
+        Ok(tx_hashes)
+    }
+
+    fn check(count: u64, unexecuted: &[u64], missing_tx_hashes: &[u64]) -> Result<DBCheckReport> {
+        let opt = RoochOpt::new_with_temp_store()?;
+        let rooch_db = RoochDB::init_with_mock_metrics_for_test(opt.store_config())?;
+        let tx_hashes = save_txs(&rooch_db, count, unexecuted)?;
+        for tx_order in missing_tx_hashes {
+            rooch_db
+                .rooch_store
+                .remove_transaction(tx_hashes[*tx_order as usize], *tx_order)?;
+        }
+        rooch_db.check()
+    }
+
+    fn range(start: u64, end: u64) -> OrderRange {
+        OrderRange { start, end }
+    }
+
+    #[test]
+    fn test_check_ok() -> Result<()> {
+        let report = check(5, &[], &[])?;
+        assert!(report.is_ok(), "{:?}", report.errors);
+        assert_eq!(report.last_order, 4);
+        assert_eq!(report.last_executed_order, Some(4));
+        assert_eq!(report.last_indexed_order, Some(4));
+        assert!(report.repair_actions.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_execution_gap() -> Result<()> {
+        let report = check(6, &[2, 3], &[])?;
+        assert!(!report.is_ok());
+        assert_eq!(report.missing_execution_infos, vec![range(2, 3)]);
+        assert!(report.missing_tx_hashes.is_empty());
+        assert_eq!(report.accumulator_mismatch_order, None);
+        assert!(report.repair_actions[0].contains("`rooch db rollback --tx-order 1`"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_unexecuted_tail() -> Result<()> {
+        let report = check(6, &[4, 5], &[])?;
+        assert_eq!(report.missing_execution_infos, vec![range(4, 5)]);
+        assert_eq!(report.last_executed_order, Some(3));
+        assert_eq!(report.repair_actions.len(), 1);
+        assert!(report.repair_actions[0].contains("`rooch db rollback --tx-order 3`"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_missing_tx_hash() -> Result<()> {
+        // The rollback aborts on the missing tx hash, only the restore can repair the db.
+        let report = check(6, &[2], &[4])?;
+        assert_eq!(report.missing_tx_hashes, vec![range(4, 4)]);
+        assert_eq!(report.missing_execution_infos, vec![range(2, 2)]);
+        assert!(!report.repair_actions[0].contains("--tx-order"));
+        assert!(report.repair_actions[0].contains("`rooch db restore`"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_suggest_rollback_before_last_order() {
+        // The last executed tx is the last tx, rollback to it fails.
+        let mut report = DBCheckReport {
+            last_order: 3,
+            last_executed_order: Some(3),
+            last_indexed_order: Some(3),
+            startup_info_mismatch: true,
+            errors: vec!["The startup info mismatch".to_string()],
+            ..Default::default()
+        };
+        report.suggest_repair_actions();
+        assert_eq!(report.repair_actions.len(), 1);
+        assert!(report.repair_actions[0].contains("`rooch db rollback --tx-order 2`"));
+
+        // Only the genesis tx, nothing to rollback to.
+        let mut report = DBCheckReport {
+            last_order: 0,
+            last_executed_order: Some(0),
+            last_indexed_order: Some(0),
+            sequencer_info_mismatch: true,
+            errors: vec!["The sequencer info mismatch".to_string()],
+            ..Default::default()
+        };
+        report.suggest_repair_actions();
+        assert!(!report.repair_actions[0].contains("--tx-order"));
+        assert!(report.repair_actions[0].contains("`rooch db restore`"));
+    }
+}
@@ -2,8 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::collections::{HashMap, HashSet};
+use std::path::Path;
 
 use anyhow::{anyhow, Error, Result};
+use moveos_config::store_config::RocksdbConfig;
 use moveos_store::config_store::ConfigStore;
 use moveos_store::transaction_store::TransactionStore as TxExecutionInfoStore;
 use moveos_store::MoveOSStore;
@@ -26,12 +28,16 @@ use rooch_types::indexer::state::{
 };
 use rooch_types::sequencer::SequencerInfo;
 
+pub mod backup;
+pub mod check;
+
 #[derive(Clone)]
 pub struct RoochDB {
     pub moveos_store: MoveOSStore,
     pub rooch_store: RoochStore,
     pub indexer_store: IndexerStore,
     pub indexer_reader: IndexerReader,
+    store_instance: StoreInstance,
 }
 
 impl RoochDB {
@@ -47,7 +53,7 @@ impl RoochDB {
     ) -> Result<Self> {
         let indexer_dir = config.get_indexer_dir();
         let moveos_store = MoveOSStore::new_with_instance(instance.clone(), registry)?;
-        let rooch_store = RoochStore::new_with_instance(instance, registry)?;
+        let rooch_store = RoochStore::new_with_instance(instance.clone(), registry)?;
         let indexer_store = IndexerStore::new(indexer_dir.clone(), registry)?;
         let indexer_reader = IndexerReader::new(indexer_dir, registry)?;
 
@@ -56,6 +62,7 @@ impl RoochDB {
             rooch_store,
             indexer_store,
             indexer_reader,
+            store_instance: instance,
         })
     }
 
@@ -63,7 +70,21 @@ impl RoochDB {
         config: &StoreConfig,
         registry: &Registry,
     ) -> Result<StoreInstance> {
-        let store_dir = config.get_store_dir();
+        Self::open_store_instance(
+            &config.get_store_dir(),
+            false,
+            config.rocksdb_config(),
+            registry,
+        )
+    }
+
+    /// Open the RocksDB at `store_dir` with the column families of both the moveos and rooch stores.
+    pub fn open_store_instance(
+        store_dir: &Path,
+        readonly: bool,
+        rocksdb_config: RocksdbConfig,
+        registry: &Registry,
+    ) -> Result<StoreInstance> {
         let mut column_families = moveos_store::StoreMeta::get_column_family_names().to_vec();
         column_families.append(&mut rooch_store::StoreMeta::get_column_family_names().to_vec());
         //ensure no duplicate column families
@@ -78,7 +99,7 @@ impl RoochDB {
 
         let db_metrics = DBMetrics::get_or_init(registry).clone();
         let instance = StoreInstance::new_db_instance(
-            RocksDB::new(store_dir, column_families, config.rocksdb_config())?,
+            RocksDB::open_with_cfs(store_dir, column_families, readonly, rocksdb_config)?,
             db_metrics,
         );
 
@@ -206,6 +227,7 @@ impl RoochDB {
         self.moveos_store
             .transaction_store
             .remove_tx_execution_info(tx_hash)?;
+        self.moveos_store.event_store.remove_tx_event_ids(tx_hash)?;
 
         // remove the state change set
         let state_change_set_ext_opt = self.rooch_store.get_state_change_set(tx_order)?;
@@ -266,6 +288,7 @@ impl RoochDB {
             self.indexer_store
                 .apply_object_states(indexer_object_state_change_set)
                 .map_err(|e| anyhow!(format!("Revert indexer states error: {:?}", e)))?;
+            self.indexer_store.renew_generation()?;
         }
         println!(
             "revert tx and indexer succ, tx_hash: {:?}, tx_order {}",
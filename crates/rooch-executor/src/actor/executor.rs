@@ -3,8 +3,9 @@
 
 use super::messages::{
     ConvertL2TransactionData, DryRunTransactionMessage, DryRunTransactionResult,
-    ExecuteTransactionMessage, ExecuteTransactionResult, GetRootMessage, SaveStateChangeSetMessage,
-    ValidateL1BlockMessage, ValidateL1TxMessage, ValidateL2TxMessage,
+    ExecuteTransactionMessage, ExecuteTransactionResult, FlushModuleCacheMessage, GetRootMessage,
+    SaveStateChangeSetMessage, ValidateL1BlockMessage, ValidateL1TxMessage,
+    ValidateL2TxAuthenticatorMessage, ValidateL2TxMessage,
 };
 use crate::metrics::ExecutorMetrics;
 use anyhow::Result;
@@ -31,7 +32,7 @@ use rooch_event::event::GasUpgradeEvent;
 use rooch_genesis::FrameworksGasParameters;
 use rooch_store::state_store::StateStore;
 use rooch_store::RoochStore;
-use rooch_types::address::{BitcoinAddress, MultiChainAddress};
+use rooch_types::address::{BitcoinAddress, MultiChainAddress, RoochAddress};
 use rooch_types::bitcoin::BitcoinModule;
 use rooch_types::framework::auth_validator::{
     AuthValidatorCaller, BuiltinAuthValidator, TxValidateResult,
@@ -271,9 +272,17 @@ impl ExecutorActor {
         debug!("executor validate_l2_tx: {:?}, sender: {}", tx_hash, sender);
 
         let authenticator = tx.authenticator_info();
+        let fee_payer_authenticator = tx.fee_payer_authenticator_info()?;
         let mut moveos_tx: MoveOSTransaction = tx.into_moveos_transaction(self.root.clone());
         let tx_size = moveos_tx.ctx.tx_size;
-        let tx_result = self.validate_authenticator(&moveos_tx.ctx, authenticator);
+        let tx_result = self
+            .validate_authenticator(&moveos_tx.ctx, authenticator)
+            .and_then(|vm_result| match (vm_result, fee_payer_authenticator) {
+                (Ok(tx_validate_result), Some((fee_payer, fee_payer_authenticator))) => Ok(self
+                    .validate_fee_payer(&moveos_tx.ctx, fee_payer, fee_payer_authenticator)?
+                    .map(|_| tx_validate_result)),
+                (vm_result, _) => Ok(vm_result),
+            });
         let result = match tx_result {
             Ok(vm_result) => match vm_result {
                 Ok(tx_validate_result) => {
@@ -324,6 +333,38 @@ impl ExecutorActor {
         result
     }
 
+    /// Validate the authenticators of the L2 tx without requiring the tx to be the next tx of the sender,
+    /// the txpool uses it to check the txs whose sequence numbers are ahead of the sequence number on chain.
+    pub fn validate_l2_tx_authenticator(
+        &self,
+        mut tx: RoochTransaction,
+        chain_sequence_number: u64,
+    ) -> Result<()> {
+        let tx_hash = tx.tx_hash();
+        let authenticator = tx.authenticator_info();
+        let fee_payer_authenticator = tx.fee_payer_authenticator_info()?;
+        let mut moveos_tx: MoveOSTransaction = tx.into_moveos_transaction(self.root.clone());
+        // The signatures commit to the tx hash, the sequence number of the context is only used by
+        // the sequence number check, so the tx is validated as the next tx of the sender.
+        moveos_tx.ctx.sequence_number = chain_sequence_number;
+        let vm_result = match self.validate_authenticator(&moveos_tx.ctx, authenticator)? {
+            Ok(_) => match fee_payer_authenticator {
+                Some((fee_payer, fee_payer_authenticator)) => {
+                    self.validate_fee_payer(&moveos_tx.ctx, fee_payer, fee_payer_authenticator)?
+                }
+                None => Ok(()),
+            },
+            Err(vm_status) => Err(vm_status),
+        };
+        vm_result.map_err(|vm_status| {
+            anyhow::anyhow!(
+                "The authenticator of tx {} is invalid: {:?}",
+                tx_hash,
+                vm_status
+            )
+        })
+    }
+
     #[named]
     pub fn validate_authenticator(
         &self,
@@ -364,6 +405,27 @@ impl ExecutorActor {
         Ok(vm_result)
     }
 
+    /// Validate the fee payer's authenticator of a sponsored transaction
+    #[named]
+    pub fn validate_fee_payer(
+        &self,
+        ctx: &TxContext,
+        fee_payer: RoochAddress,
+        authenticator: AuthenticatorInfo,
+    ) -> Result<Result<(), VMStatus>> {
+        let fn_name = function_name!();
+        let _timer = self
+            .metrics
+            .executor_validate_tx_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let tx_validator = self.as_module_binding::<TransactionValidator>();
+        let vm_result = tx_validator
+            .validate_fee_payer(ctx, fee_payer.into(), authenticator)?
+            .into_result();
+        Ok(vm_result)
+    }
+
     pub fn convert_to_verified_tx(
         &self,
         tx_data: RoochTransactionData,
@@ -393,6 +455,9 @@ impl ExecutorActor {
         };
 
         tx_ctx.add(dummy_result)?;
+        if let Some(gas_price) = tx_data.gas_price {
+            tx_ctx.add(gas_price)?;
+        }
 
         let verified_action = match tx_data.action {
             MoveAction::Script(script_call) => VerifiedMoveAction::Script { call: script_call },
@@ -445,7 +510,18 @@ impl Handler<ValidateL2TxMessage> for ExecutorActor {
         msg: ValidateL2TxMessage,
         _ctx: &mut ActorContext,
     ) -> Result<VerifiedMoveOSTransaction> {
-        self.validate_l2_tx(msg.tx)
+        msg.span.in_scope(|| self.validate_l2_tx(msg.tx))
+    }
+}
+
+#[async_trait]
+impl Handler<ValidateL2TxAuthenticatorMessage> for ExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: ValidateL2TxAuthenticatorMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<()> {
+        self.validate_l2_tx_authenticator(msg.tx, msg.chain_sequence_number)
     }
 }
 
@@ -467,7 +543,7 @@ impl Handler<ValidateL1BlockMessage> for ExecutorActor {
         msg: ValidateL1BlockMessage,
         _ctx: &mut ActorContext,
     ) -> Result<VerifiedMoveOSTransaction> {
-        self.validate_l1_block(msg.l1_block)
+        msg.span.in_scope(|| self.validate_l1_block(msg.l1_block))
     }
 }
 
@@ -478,7 +554,7 @@ impl Handler<ValidateL1TxMessage> for ExecutorActor {
         msg: ValidateL1TxMessage,
         _ctx: &mut ActorContext,
     ) -> Result<VerifiedMoveOSTransaction> {
-        self.validate_l1_tx(msg.l1_tx)
+        msg.span.in_scope(|| self.validate_l1_tx(msg.l1_tx))
     }
 }
 
@@ -489,7 +565,7 @@ impl Handler<ExecuteTransactionMessage> for ExecutorActor {
         msg: ExecuteTransactionMessage,
         _ctx: &mut ActorContext,
     ) -> Result<ExecuteTransactionResult> {
-        self.execute(msg.tx)
+        msg.span.in_scope(|| self.execute(msg.tx))
     }
 }
 
@@ -504,6 +580,17 @@ impl Handler<DryRunTransactionMessage> for ExecutorActor {
     }
 }
 
+#[async_trait]
+impl Handler<FlushModuleCacheMessage> for ExecutorActor {
+    async fn handle(
+        &mut self,
+        _msg: FlushModuleCacheMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<()> {
+        self.moveos.flush_module_cache(true)
+    }
+}
+
 #[async_trait]
 impl Handler<GetRootMessage> for ExecutorActor {
     async fn handle(
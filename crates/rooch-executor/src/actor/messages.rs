@@ -21,16 +21,30 @@ use rooch_types::transaction::{
     L1BlockWithBody, L1Transaction, RoochTransaction, RoochTransactionData,
 };
 use serde::{Deserialize, Serialize};
+use tracing::Span;
 
 #[derive(Debug)]
 pub struct ValidateL2TxMessage {
     pub tx: RoochTransaction,
+    /// The tracing span of the tx lifecycle
+    pub span: Span,
 }
 
 impl Message for ValidateL2TxMessage {
     type Result = Result<VerifiedMoveOSTransaction>;
 }
 
+/// Validate the authenticators of a L2 tx whose sequence number may be ahead of the sender's sequence number on chain
+#[derive(Debug)]
+pub struct ValidateL2TxAuthenticatorMessage {
+    pub tx: RoochTransaction,
+    pub chain_sequence_number: u64,
+}
+
+impl Message for ValidateL2TxAuthenticatorMessage {
+    type Result = Result<()>;
+}
+
 #[derive(Debug)]
 pub struct ConvertL2TransactionData {
     pub tx_data: RoochTransactionData,
@@ -43,6 +57,8 @@ impl Message for ConvertL2TransactionData {
 #[derive(Debug)]
 pub struct ValidateL1BlockMessage {
     pub l1_block: L1BlockWithBody,
+    /// The tracing span of the tx lifecycle
+    pub span: Span,
 }
 
 impl Message for ValidateL1BlockMessage {
@@ -52,6 +68,8 @@ impl Message for ValidateL1BlockMessage {
 #[derive(Debug)]
 pub struct ValidateL1TxMessage {
     pub l1_tx: L1Transaction,
+    /// The tracing span of the tx lifecycle
+    pub span: Span,
 }
 
 impl Message for ValidateL1TxMessage {
@@ -61,6 +79,8 @@ impl Message for ValidateL1TxMessage {
 #[derive(Debug)]
 pub struct ExecuteTransactionMessage {
     pub tx: VerifiedMoveOSTransaction,
+    /// The tracing span of the tx lifecycle
+    pub span: Span,
 }
 
 #[derive(Debug)]
@@ -199,6 +219,15 @@ impl Message for GetTxExecutionInfosByHashMessage {
     type Result = Result<Vec<Option<TransactionExecutionInfo>>>;
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetStateNodesMessage {
+    pub node_hashes: Vec<H256>,
+}
+
+impl Message for GetStateNodesMessage {
+    type Result = Result<Vec<Option<Vec<u8>>>>;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RefreshStateMessage {
     pub root: ObjectMeta,
@@ -209,6 +238,13 @@ impl Message for RefreshStateMessage {
     type Result = Result<()>;
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FlushModuleCacheMessage {}
+
+impl Message for FlushModuleCacheMessage {
+    type Result = Result<()>;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GetRootMessage {}
 
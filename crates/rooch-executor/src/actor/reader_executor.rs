@@ -7,8 +7,8 @@ use super::messages::{
     RefreshStateMessage, StatesMessage,
 };
 use crate::actor::messages::{
-    GetEventsByEventIDsMessage, GetTxExecutionInfosByHashMessage, ListAnnotatedStatesMessage,
-    ListStatesMessage,
+    FlushModuleCacheMessage, GetEventsByEventIDsMessage, GetStateNodesMessage,
+    GetTxExecutionInfosByHashMessage, ListAnnotatedStatesMessage, ListStatesMessage,
 };
 use anyhow::Result;
 use async_trait::async_trait;
@@ -33,6 +33,7 @@ use rooch_event::event::GasUpgradeEvent;
 use rooch_genesis::FrameworksGasParameters;
 use rooch_store::RoochStore;
 use rooch_types::framework::{system_post_execute_functions, system_pre_execute_functions};
+use smt::NodeReader;
 
 pub struct ReaderExecutorActor {
     root: ObjectMeta,
@@ -305,6 +306,22 @@ impl Handler<GetTxExecutionInfosByHashMessage> for ReaderExecutorActor {
     }
 }
 
+#[async_trait]
+impl Handler<GetStateNodesMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: GetStateNodesMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<Option<Vec<u8>>>> {
+        let GetStateNodesMessage { node_hashes } = msg;
+        let node_store = self.moveos_store.get_state_node_store();
+        node_hashes
+            .iter()
+            .map(|node_hash| node_store.get(node_hash))
+            .collect()
+    }
+}
+
 #[async_trait]
 impl Handler<RefreshStateMessage> for ReaderExecutorActor {
     async fn handle(&mut self, msg: RefreshStateMessage, _ctx: &mut ActorContext) -> Result<()> {
@@ -313,6 +330,17 @@ impl Handler<RefreshStateMessage> for ReaderExecutorActor {
     }
 }
 
+#[async_trait]
+impl Handler<FlushModuleCacheMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        _msg: FlushModuleCacheMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<()> {
+        self.moveos.flush_module_cache(true)
+    }
+}
+
 #[async_trait]
 impl Handler<EventData> for ReaderExecutorActor {
     async fn handle(&mut self, message: EventData, _ctx: &mut ActorContext) -> Result<()> {
@@ -2,17 +2,18 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::actor::messages::{
-    ConvertL2TransactionData, DryRunTransactionResult, GetAnnotatedEventsByEventIDsMessage,
-    GetEventsByEventHandleMessage, GetEventsByEventIDsMessage, GetStateChangeSetsMessage,
-    GetTxExecutionInfosByHashMessage, ListAnnotatedStatesMessage, ListStatesMessage,
-    RefreshStateMessage, SaveStateChangeSetMessage, ValidateL1BlockMessage, ValidateL1TxMessage,
+    ConvertL2TransactionData, DryRunTransactionResult, FlushModuleCacheMessage,
+    GetAnnotatedEventsByEventIDsMessage, GetEventsByEventHandleMessage, GetEventsByEventIDsMessage,
+    GetStateChangeSetsMessage, GetStateNodesMessage, GetTxExecutionInfosByHashMessage,
+    ListAnnotatedStatesMessage, ListStatesMessage, RefreshStateMessage, SaveStateChangeSetMessage,
+    ValidateL1BlockMessage, ValidateL1TxMessage,
 };
 use crate::actor::reader_executor::ReaderExecutorActor;
 use crate::actor::{
     executor::ExecutorActor,
     messages::{
         AnnotatedStatesMessage, ExecuteViewFunctionMessage, GetAnnotatedEventsByEventHandleMessage,
-        StatesMessage, ValidateL2TxMessage,
+        StatesMessage, ValidateL2TxAuthenticatorMessage, ValidateL2TxMessage,
     },
 };
 use anyhow::{anyhow, Result};
@@ -42,6 +43,7 @@ use rooch_types::transaction::{
     L1BlockWithBody, L1Transaction, RoochTransaction, RoochTransactionData,
 };
 use tokio::runtime::Handle;
+use tracing::Span;
 
 #[derive(Clone)]
 pub struct ExecutorProxy {
@@ -61,7 +63,25 @@ impl ExecutorProxy {
     }
 
     pub async fn validate_l2_tx(&self, tx: RoochTransaction) -> Result<VerifiedMoveOSTransaction> {
-        self.actor.send(ValidateL2TxMessage { tx }).await?
+        self.actor
+            .send(ValidateL2TxMessage {
+                tx,
+                span: Span::current(),
+            })
+            .await?
+    }
+
+    pub async fn validate_l2_tx_authenticator(
+        &self,
+        tx: RoochTransaction,
+        chain_sequence_number: u64,
+    ) -> Result<()> {
+        self.actor
+            .send(ValidateL2TxAuthenticatorMessage {
+                tx,
+                chain_sequence_number,
+            })
+            .await?
     }
 
     pub async fn convert_to_verified_tx(
@@ -77,11 +97,21 @@ impl ExecutorProxy {
         &self,
         l1_block: L1BlockWithBody,
     ) -> Result<VerifiedMoveOSTransaction> {
-        self.actor.send(ValidateL1BlockMessage { l1_block }).await?
+        self.actor
+            .send(ValidateL1BlockMessage {
+                l1_block,
+                span: Span::current(),
+            })
+            .await?
     }
 
     pub async fn validate_l1_tx(&self, l1_tx: L1Transaction) -> Result<VerifiedMoveOSTransaction> {
-        self.actor.send(ValidateL1TxMessage { l1_tx }).await?
+        self.actor
+            .send(ValidateL1TxMessage {
+                l1_tx,
+                span: Span::current(),
+            })
+            .await?
     }
 
     //TODO ensure the execute result
@@ -91,7 +121,10 @@ impl ExecutorProxy {
     ) -> Result<(TransactionOutput, TransactionExecutionInfo)> {
         let result = self
             .actor
-            .send(crate::actor::messages::ExecuteTransactionMessage { tx })
+            .send(crate::actor::messages::ExecuteTransactionMessage {
+                tx,
+                span: Span::current(),
+            })
             .await??;
         Ok((result.output, result.transaction_info))
     }
@@ -231,6 +264,13 @@ impl ExecutorProxy {
             .await?
     }
 
+    /// Get the raw Sparse Merkle Tree nodes by node hashes
+    pub async fn get_state_nodes(&self, node_hashes: Vec<H256>) -> Result<Vec<Option<Vec<u8>>>> {
+        self.reader_actor
+            .send(GetStateNodesMessage { node_hashes })
+            .await?
+    }
+
     pub async fn refresh_state(&self, root: ObjectMeta, is_upgrade: bool) -> Result<()> {
         self.reader_actor
             .send(RefreshStateMessage { root, is_upgrade })
@@ -246,6 +286,12 @@ impl ExecutorProxy {
         self.refresh_state(root.metadata, false).await
     }
 
+    /// Flush the Move module cache of the executor and the reader executor
+    pub async fn flush_module_cache(&self) -> Result<()> {
+        self.actor.send(FlushModuleCacheMessage {}).await??;
+        self.reader_actor.send(FlushModuleCacheMessage {}).await?
+    }
+
     pub async fn save_state_change_set(
         &self,
         tx_order: u64,
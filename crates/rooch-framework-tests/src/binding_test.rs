@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::{bail, Result};
+use bitcoin::BlockHash;
 use metrics::RegistryService;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::vm_status::KeptVMStatus;
@@ -74,6 +75,15 @@ impl RustBindingTest {
         Self::new_with_network(BuiltinChainID::Local.into())
     }
 
+    /// Create a local binding test which starts to process the Bitcoin blocks from the given block,
+    /// the blocks after it must be executed continuously.
+    pub fn new_with_bitcoin_start_block(block_height: u64, block_hash: BlockHash) -> Result<Self> {
+        let mut network = RoochNetwork::local();
+        network.genesis_config.bitcoin_block_height = block_height;
+        network.genesis_config.bitcoin_block_hash = block_hash;
+        Self::new_with_network(network)
+    }
+
     pub fn new_with_network(mut network: RoochNetwork) -> Result<Self> {
         let kp = RoochKeyPair::generate_secp256k1();
         let sequencer = kp.public().bitcoin_address()?;
@@ -13,4 +13,6 @@ mod ethereum_test;
 mod multisign_account_tests;
 mod ord_test;
 mod session_validator_tests;
+mod sponsor_tests;
 mod view_function_gas;
+mod webauthn_validator_tests;
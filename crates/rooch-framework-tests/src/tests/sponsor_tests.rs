@@ -0,0 +1,97 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use moveos_types::transaction::MoveAction;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::framework::empty::Empty;
+use rooch_types::transaction::authenticator::Authenticator;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+
+#[tokio::test]
+async fn test_sponsored_transaction() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(2);
+    let sender = keystore.addresses()[0];
+    let sponsor = keystore.addresses()[1];
+
+    // The fee payer must be an existing account
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sponsor, 0, action);
+    let tx = keystore.sign_transaction(&sponsor, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action).with_fee_payer(sponsor);
+    let sponsor_kp = keystore.get_key_pair(&sponsor, None).unwrap();
+    let tx = keystore
+        .sign_transaction(&sender, tx_data, None)
+        .unwrap()
+        .sign_fee_payer(&sponsor_kp)
+        .unwrap();
+    assert_eq!(tx.fee_payer(), Some(sponsor));
+    // The sponsored transaction keeps the fee payer authenticator after the encode and decode
+    let tx = RoochTransaction::decode(&tx.encode()).unwrap();
+    assert!(tx.fee_payer_authenticator().is_some());
+    binding_test.execute(tx).unwrap();
+    assert_eq!(
+        binding_test
+            .get_account_sequence_number(sender.into())
+            .unwrap(),
+        1
+    );
+    // The sequence number of the fee payer should not be changed
+    assert_eq!(
+        binding_test
+            .get_account_sequence_number(sponsor.into())
+            .unwrap(),
+        1
+    );
+
+    let sender_kp = keystore.get_key_pair(&sender, None).unwrap();
+
+    // The sponsor's signature over the tx hash is not a fee payer signature, it should be rejected.
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 1, action).with_fee_payer(sponsor);
+    let fee_payer_authenticator = Authenticator::bitcoin(&sponsor_kp, &tx_data);
+    let tx = keystore
+        .sign_transaction(&sender, tx_data, None)
+        .unwrap()
+        .with_fee_payer_authenticator(fee_payer_authenticator)
+        .unwrap();
+    assert!(binding_test.execute(tx).is_err());
+
+    // The sender does not commit to the fee payer, the fee payer can not be attached.
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 1, action);
+    let tx = keystore
+        .sign_transaction(&sender, tx_data.clone(), None)
+        .unwrap();
+    assert!(tx.clone().sign_fee_payer(&sponsor_kp).is_err());
+
+    // The sender's signature over the data without fee payer is invalid for the sponsored data.
+    let sponsored_data = tx_data.with_fee_payer(sponsor);
+    let fee_payer_authenticator = Authenticator::bitcoin_fee_payer(&sponsor_kp, &sponsored_data);
+    let tx = RoochTransaction::new_sponsored(
+        sponsored_data,
+        tx.authenticator().clone(),
+        fee_payer_authenticator,
+    )
+    .unwrap();
+    assert!(binding_test.execute(tx).is_err());
+
+    // Only the designated fee payer can co-sign the transaction.
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 1, action).with_fee_payer(sponsor);
+    let tx = RoochTransaction::new(
+        tx_data.clone(),
+        Authenticator::bitcoin(&sender_kp, &tx_data),
+    );
+    assert!(tx.clone().sign_fee_payer(&sender_kp).is_err());
+
+    // The sponsored transaction without the fee payer authenticator should be rejected.
+    assert!(binding_test.execute(tx).is_err());
+}
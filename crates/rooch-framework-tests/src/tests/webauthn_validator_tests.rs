@@ -0,0 +1,127 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::u256::U256;
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::move_std::string::MoveString;
+use moveos_types::move_types::FunctionId;
+use moveos_types::state::MoveStructType;
+use moveos_types::transaction::MoveAction;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use rooch_types::bitcoin::multisign_account;
+use rooch_types::crypto::{RoochKeyPair, RoochSignature};
+use rooch_types::framework::auth_payload::{MultisignAuthPayload, SignData};
+use rooch_types::framework::auth_validator::BuiltinAuthValidator;
+use rooch_types::framework::empty::Empty;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::framework::session_key::{SessionKeyModule, SessionScope};
+use rooch_types::framework::transfer::TransferModule;
+use rooch_types::transaction::authenticator::Authenticator;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+use std::str::FromStr;
+
+#[tokio::test]
+async fn test_webauthn_validator() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+    let sequence_number = 0;
+
+    // register the passkey as a session key of the sender
+    let passkey = RoochKeyPair::generate_secp256r1();
+    let passkey_auth_key = passkey.authentication_key();
+    let session_scope = SessionScope::new(ROOCH_FRAMEWORK_ADDRESS, "*", "*").unwrap();
+    let action = SessionKeyModule::create_session_key_action(
+        MoveString::from_str("test").unwrap(),
+        MoveString::from_str("https://test.rooch.network").unwrap(),
+        passkey_auth_key.as_ref().to_vec(),
+        session_scope,
+        100,
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, sequence_number, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+
+    // send transaction via the passkey
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, sequence_number + 1, action);
+    let authenticator = Authenticator::webauthn(&passkey, &tx_data);
+    let tx = RoochTransaction::new(tx_data, authenticator);
+    binding_test.execute(tx).unwrap();
+
+    // a transaction signed by an unregistered passkey should be rejected
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, sequence_number + 2, action);
+    let authenticator = Authenticator::webauthn(&RoochKeyPair::generate_secp256r1(), &tx_data);
+    let tx = RoochTransaction::new(tx_data, authenticator);
+    assert!(binding_test.execute_as_result(tx).is_err());
+
+    let session_key_module = binding_test.as_module_binding::<SessionKeyModule>();
+    assert!(session_key_module
+        .get_session_key(sender.into(), &passkey_auth_key)
+        .unwrap()
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_upgrade_auth_validators() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    // The rooch dao is the 1-of-1 multisign account of the sequencer in the binding test
+    let sequencer = binding_test.sequencer;
+    let kp = binding_test.sequencer_kp().copy();
+    let public_key = kp.bitcoin_public_key().unwrap().to_bytes();
+    let rooch_dao = multisign_account::generate_multisign_address(1, vec![public_key.clone()])
+        .unwrap()
+        .to_rooch_address();
+
+    let gas_action = TransferModule::create_transfer_coin_action(
+        RGas::struct_tag(),
+        rooch_dao.into(),
+        U256::from(1000000000u128),
+    );
+    let sequence_number = binding_test.get_account_sequence_number(sequencer).unwrap();
+    let tx =
+        RoochTransactionData::new_for_test(sequencer.into(), sequence_number, gas_action).sign(&kp);
+    binding_test.execute(tx).unwrap();
+
+    // The validator is registered by the genesis, the upgrade keeps it idempotent
+    let action = MoveAction::new_function_call(
+        FunctionId::from_str("0x3::upgrade::upgrade_auth_validators").unwrap(),
+        vec![],
+        vec![],
+    );
+    let tx_data = RoochTransactionData::new_for_test(rooch_dao, 0, action);
+    let sign_data = SignData::new_with_default(&tx_data);
+    let signature = kp.sign(sign_data.data_hash().as_bytes());
+    let payload = MultisignAuthPayload {
+        signatures: vec![signature.signature_bytes().to_vec()],
+        message_prefix: sign_data.message_prefix.clone(),
+        message_info: sign_data.message_info_without_tx_hash(),
+        public_keys: vec![public_key],
+    };
+    let authenticator = Authenticator::new(
+        BuiltinAuthValidator::BitcoinMultisign.flag().into(),
+        bcs::to_bytes(&payload).unwrap(),
+    );
+    binding_test
+        .execute(RoochTransaction::new(tx_data, authenticator))
+        .unwrap();
+
+    // Only the admin can upgrade the auth validators
+    let action = MoveAction::new_function_call(
+        FunctionId::from_str("0x3::upgrade::upgrade_auth_validators").unwrap(),
+        vec![],
+        vec![],
+    );
+    let sequence_number = binding_test.get_account_sequence_number(sequencer).unwrap();
+    let tx =
+        RoochTransactionData::new_for_test(sequencer.into(), sequence_number, action).sign(&kp);
+    assert!(binding_test.execute(tx).is_err());
+}
@@ -55,9 +55,13 @@ pub static ROOCH_LOCAL_GENESIS: Lazy<RoochGenesis> = Lazy::new(|| {
     let network: RoochNetwork = BuiltinChainID::Local.into();
     RoochGenesis::build(network).expect("build rooch genesis failed")
 });
-pub const LATEST_GAS_SCHEDULE_VERSION: u64 = GAS_SCHEDULE_RELEASE_V1;
+pub const LATEST_GAS_SCHEDULE_VERSION: u64 = GAS_SCHEDULE_RELEASE_V2;
 // update the gas config for function calling
 pub const GAS_SCHEDULE_RELEASE_V1: u64 = 1;
+// add the gas config of the natives: pow, runes, sighash, ecdsa_r1, schnorr and ethereum_proof.
+// These natives are only registered when their gas entries are onchain,
+// so the gas config must be upgraded before the framework which calls them.
+pub const GAS_SCHEDULE_RELEASE_V2: u64 = 2;
 
 pub(crate) const STATIC_GENESIS_DIR: Dir = include_dir!("released");
 
@@ -141,6 +145,20 @@ impl FrameworksGasParameters {
         gas_parameter
     }
 
+    /// Returns the keys of the gas entries which are not in the onchain gas schedule.
+    pub fn missing_gas_entries(
+        &self,
+        chain_id: ChainID,
+        onchain_entries: &BTreeMap<String, u64>,
+    ) -> Vec<String> {
+        self.to_gas_schedule_config(chain_id)
+            .entries
+            .into_iter()
+            .map(|entry| entry.key.to_string())
+            .filter(|key| !onchain_entries.contains_key(key))
+            .collect()
+    }
+
     pub fn to_gas_schedule_config(&self, chain_id: ChainID) -> GasScheduleConfig {
         let mut entries = self.vm_gas_params.to_on_chain_gas_schedule();
         entries.extend(self.rooch_framework_gas_params.to_on_chain_gas_schedule());
@@ -692,6 +710,35 @@ mod tests {
         genesis_init_test_case(network, genesis);
     }
 
+    #[test]
+    fn test_missing_gas_entries() {
+        let chain_id = BuiltinChainID::Test.chain_id();
+        let latest = FrameworksGasParameters::latest();
+        let latest_entries = latest
+            .to_gas_schedule_config(chain_id.clone())
+            .entries
+            .into_iter()
+            .map(|entry| (entry.key.to_string(), entry.val))
+            .collect::<BTreeMap<_, _>>();
+        assert!(latest
+            .missing_gas_entries(chain_id.clone(), &latest_entries)
+            .is_empty());
+
+        // The released testnet genesis is before the gas schedule release v2
+        let genesis = load_genesis_from_binary(BuiltinChainID::Test)
+            .unwrap()
+            .unwrap();
+        let genesis_entries = genesis
+            .initial_gas_config
+            .entries
+            .into_iter()
+            .map(|entry| (entry.key.to_string(), entry.val))
+            .collect::<BTreeMap<_, _>>();
+        let missing_entries = latest.missing_gas_entries(chain_id, &genesis_entries);
+        assert!(missing_entries.contains(&"pow.check_proof_of_work.base".to_owned()));
+        assert!(missing_entries.contains(&"schnorr.verify.base".to_owned()));
+    }
+
     #[test]
     fn test_genesis_load_from_binary() {
         assert!(load_genesis_from_binary(BuiltinChainID::Test)
@@ -0,0 +1 @@
+DROP TABLE IF EXISTS runes;
\ No newline at end of file
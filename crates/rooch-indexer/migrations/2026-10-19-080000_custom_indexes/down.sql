@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS custom_index_configs;
+DROP TABLE IF EXISTS custom_indexes;
\ No newline at end of file
@@ -0,0 +1,19 @@
+CREATE TABLE custom_indexes
+(
+    object_id          VARCHAR        NOT NULL,
+    field_path         VARCHAR        NOT NULL,
+    object_type        VARCHAR        NOT NULL,
+    field_type         VARCHAR        NOT NULL,
+    value              VARCHAR        NOT NULL,
+    tx_order           BIGINT         NOT NULL,
+    PRIMARY KEY (object_id, field_path)
+);
+
+CREATE INDEX idx_custom_indexes_value ON custom_indexes (object_type, field_path, value, object_id);
+
+CREATE TABLE custom_index_configs
+(
+    object_type        VARCHAR        NOT NULL,
+    field_path         VARCHAR        NOT NULL,
+    PRIMARY KEY (object_type, field_path)
+);
@@ -0,0 +1 @@
+DROP TABLE IF EXISTS indexer_generation;
\ No newline at end of file
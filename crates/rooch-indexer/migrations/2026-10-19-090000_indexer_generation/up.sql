@@ -0,0 +1,5 @@
+CREATE TABLE indexer_generation
+(
+    id                 INTEGER        PRIMARY KEY NOT NULL,
+    generation         BIGINT         NOT NULL
+);
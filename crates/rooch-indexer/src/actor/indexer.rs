@@ -2,64 +2,480 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::actor::messages::{
-    IndexerApplyObjectStatesMessage, IndexerDeleteAnyObjectStatesMessage, IndexerEventsMessage,
+    IndexerApplyObjectStatesMessage, IndexerBackupMessage, IndexerCatchUpMessage,
+    IndexerDeleteAnyObjectStatesMessage, IndexerEventsMessage,
     IndexerPersistOrUpdateAnyObjectStatesMessage, IndexerRevertMessage, IndexerStatesMessage,
-    IndexerTransactionMessage, UpdateIndexerMessage,
+    IndexerTransactionMessage, QueryIndexerStatusMessage, RenewIndexerGenerationMessage,
+    UpdateIndexerMessage,
 };
+use crate::indexer_reader::IndexerReader;
+use crate::metrics::{IndexerCatchUpMetrics, IndexerMetrics};
 use crate::store::traits::IndexerStoreTrait;
 use crate::IndexerStore;
-use anyhow::Result;
+use anyhow::{bail, Result};
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
+use function_name::named;
+use move_core_types::language_storage::TypeTag;
+use moveos_store::event_store::EventStore;
+use moveos_store::transaction_store::TransactionStore as TxExecutionInfoStore;
+use moveos_store::MoveOSStore;
+use moveos_types::h256::ACCUMULATOR_PLACEHOLDER_HASH;
 use moveos_types::moveos_std::object::ObjectMeta;
+use moveos_types::state::StateChangeSet;
+use moveos_types::state_resolver::{RootObjectResolver, StateResolver};
 use moveos_types::transaction::MoveAction;
+use prometheus::Registry;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::state_store::StateStore;
+use rooch_store::transaction_store::TransactionStore;
+use rooch_store::RoochStore;
+use rooch_types::indexer::custom_index::{
+    extract_custom_indexes, handle_custom_index_object_change,
+    handle_revert_custom_index_object_change, CustomIndexConfig, IndexerCustomIndexChangeSet,
+};
 use rooch_types::indexer::event::IndexerEvent;
 use rooch_types::indexer::state::{
     handle_object_change, handle_revert_object_change, IndexerObjectStateChangeSet,
-    IndexerObjectStatesIndexGenerator, ObjectStateType,
+    IndexerObjectStatesIndexGenerator, ObjectStateFilter, ObjectStateType,
 };
+use rooch_types::indexer::status::{IndexerStatus, IndexerSyncStatus};
 use rooch_types::indexer::transaction::IndexerTransaction;
+use std::cmp::{max, min};
+use std::time::Duration;
+use tracing::{debug, error, info, warn};
+
+/// The max number of transactions replayed in one catch-up batch.
+/// The live transactions and the status queries wait for at most one batch.
+const CATCH_UP_BATCH_SIZE: u64 = 100;
+/// The interval to retry the catch-up when the next transaction is not fully persisted yet.
+const CATCH_UP_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+const CATCH_UP_MAX_RETRIES: u32 = 60;
+/// The number of objects read in one batch when backfilling a new custom index.
+const CUSTOM_INDEX_BACKFILL_BATCH_SIZE: usize = 1000;
+
+/// The stores to replay the executed transactions from when the indexer is behind the store.
+struct IndexerCatchUp {
+    indexer_reader: IndexerReader,
+    moveos_store: MoveOSStore,
+    rooch_store: RoochStore,
+    metrics: IndexerCatchUpMetrics,
+    retries: u32,
+}
+
+/// The configured custom indexes and the store to resolve the layouts of the indexed object values.
+struct IndexerCustomIndexes {
+    configs: Vec<CustomIndexConfig>,
+    moveos_store: MoveOSStore,
+}
 
 pub struct IndexerActor {
     root: ObjectMeta,
     indexer_store: IndexerStore,
+    metrics: IndexerMetrics,
+    catch_up: Option<IndexerCatchUp>,
+    custom_indexes: Option<IndexerCustomIndexes>,
+    status: IndexerStatus,
 }
 
 impl IndexerActor {
-    pub fn new(root: ObjectMeta, indexer_store: IndexerStore) -> Result<Self> {
+    pub fn new(root: ObjectMeta, indexer_store: IndexerStore, registry: &Registry) -> Result<Self> {
         Ok(Self {
             root,
             indexer_store,
+            metrics: IndexerMetrics::new(registry),
+            catch_up: None,
+            custom_indexes: None,
+            status: IndexerStatus::default(),
         })
     }
-}
 
-impl Actor for IndexerActor {}
+    /// Enable the online catch-up: on startup, the actor replays the stored transactions, events and
+    /// state change sets after the last indexed tx order in batches, the live transactions are replayed
+    /// from the store until the indexer catches up.
+    pub fn with_catch_up(
+        mut self,
+        indexer_reader: IndexerReader,
+        moveos_store: MoveOSStore,
+        rooch_store: RoochStore,
+        registry: &Registry,
+    ) -> Self {
+        self.catch_up = Some(IndexerCatchUp {
+            indexer_reader,
+            moveos_store,
+            rooch_store,
+            metrics: IndexerCatchUpMetrics::new(registry),
+            retries: 0,
+        });
+        self
+    }
 
-#[async_trait]
-impl Handler<UpdateIndexerMessage> for IndexerActor {
-    async fn handle(&mut self, msg: UpdateIndexerMessage, _ctx: &mut ActorContext) -> Result<()> {
+    /// Maintain the custom indexes of the object changes, the custom indexes are disabled if `configs` is empty.
+    /// The recorded custom indexes are synced with the `configs` before the actor starts.
+    pub fn with_custom_indexes(
+        mut self,
+        configs: Vec<CustomIndexConfig>,
+        moveos_store: MoveOSStore,
+        indexer_reader: &IndexerReader,
+    ) -> Result<Self> {
+        self.sync_custom_indexes(&configs, &moveos_store, indexer_reader)?;
+        if !configs.is_empty() {
+            info!(
+                "Indexer custom indexes: {}",
+                configs
+                    .iter()
+                    .map(|config| config.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            );
+            self.custom_indexes = Some(IndexerCustomIndexes {
+                configs,
+                moveos_store,
+            });
+        }
+        Ok(self)
+    }
+
+    /// Delete the entries of the custom indexes which are removed from the config,
+    /// and backfill the new custom indexes from the existing objects.
+    fn sync_custom_indexes(
+        &self,
+        configs: &[CustomIndexConfig],
+        moveos_store: &MoveOSStore,
+        indexer_reader: &IndexerReader,
+    ) -> Result<()> {
+        let recorded_configs = indexer_reader.query_custom_index_configs()?;
+        for config in recorded_configs
+            .iter()
+            .filter(|config| !configs.contains(config))
+        {
+            info!("Delete the removed custom index {}", config);
+            self.indexer_store
+                .delete_custom_index_config(config.clone())?;
+        }
+
+        let resolver = RootObjectResolver::new(self.root.clone(), moveos_store);
+        for config in configs
+            .iter()
+            .filter(|config| !recorded_configs.contains(config))
+        {
+            let count = self.backfill_custom_index(config, &resolver, indexer_reader)?;
+            // Record the custom index after it is backfilled, so an interrupted backfill is restarted
+            self.indexer_store
+                .persist_custom_index_configs(vec![config.clone()])?;
+            info!(
+                "Backfill the custom index {} with {} entries",
+                config, count
+            );
+        }
+        Ok(())
+    }
+
+    /// Index the objects of the custom index type found in the indexed object states, the objects are read
+    /// from the current state. The objects changed by the transactions which are not indexed yet are
+    /// indexed when the transactions are caught up.
+    fn backfill_custom_index(
+        &self,
+        config: &CustomIndexConfig,
+        resolver: &RootObjectResolver<MoveOSStore>,
+        indexer_reader: &IndexerReader,
+    ) -> Result<usize> {
+        let object_type = TypeTag::Struct(Box::new(config.object_type.clone()));
+        let state_type = ObjectStateType::from_object_type(&object_type);
+        let mut cursor = None;
+        let mut count = 0;
+        loop {
+            let object_ids = indexer_reader.query_object_ids_with_filter(
+                ObjectStateFilter::ObjectType(config.object_type.clone()),
+                cursor,
+                CUSTOM_INDEX_BACKFILL_BATCH_SIZE,
+                false,
+                state_type.clone(),
+            )?;
+            let Some((_, last_state_id)) = object_ids.last() else {
+                break;
+            };
+            cursor = Some(last_state_id.clone());
+
+            let mut custom_index_change_set = IndexerCustomIndexChangeSet::default();
+            for (object_id, state_id) in object_ids {
+                // The object may be deleted by the transactions which are not indexed yet
+                let Some(object) = resolver.get_object(&object_id)? else {
+                    continue;
+                };
+                if !config.match_object_type(&object.metadata.object_type) {
+                    continue;
+                }
+                extract_custom_indexes(
+                    vec![config],
+                    resolver,
+                    state_id.tx_order,
+                    &mut custom_index_change_set,
+                    &object.metadata,
+                    &object.value,
+                )?;
+            }
+            count += custom_index_change_set.new_indexes.len();
+            self.indexer_store
+                .persist_or_update_custom_indexes(custom_index_change_set.new_indexes)?;
+        }
+        Ok(count)
+    }
+
+    fn index_custom_indexes(&self, tx_order: u64, state_change_set: &StateChangeSet) -> Result<()> {
+        let Some(custom_indexes) = &self.custom_indexes else {
+            return Ok(());
+        };
+        let resolver = RootObjectResolver::new(
+            state_change_set.root_metadata(),
+            &custom_indexes.moveos_store,
+        );
+        let mut custom_index_change_set = IndexerCustomIndexChangeSet::default();
+        for object_change in state_change_set.changes.values() {
+            handle_custom_index_object_change(
+                &custom_indexes.configs,
+                &resolver,
+                tx_order,
+                &mut custom_index_change_set,
+                object_change,
+            )?;
+        }
+        if !custom_index_change_set.is_empty() {
+            self.indexer_store
+                .apply_custom_indexes(custom_index_change_set)?;
+        }
+        Ok(())
+    }
+
+    fn index_object_changes(&self, tx_order: u64, state_change_set: StateChangeSet) -> Result<()> {
+        self.index_custom_indexes(tx_order, &state_change_set)?;
+
+        // indexer object state index generator
+        let mut state_index_generator = IndexerObjectStatesIndexGenerator::default();
+        let mut indexer_object_state_change_set = IndexerObjectStateChangeSet::default();
+
+        for (_field_key, object_change) in state_change_set.changes {
+            handle_object_change(
+                &mut state_index_generator,
+                tx_order,
+                &mut indexer_object_state_change_set,
+                object_change,
+            )?;
+        }
+        self.indexer_store
+            .apply_object_states(indexer_object_state_change_set)?;
+        Ok(())
+    }
+
+    fn is_behind(&self) -> bool {
+        match (
+            self.status.last_indexed_tx_order,
+            self.status.target_tx_order,
+        ) {
+            (_, None) => false,
+            (None, Some(_)) => true,
+            (Some(last_indexed_tx_order), Some(target_tx_order)) => {
+                last_indexed_tx_order < target_tx_order
+            }
+        }
+    }
+
+    fn start_catch_up(&mut self, ctx: &mut ActorContext) -> Result<()> {
+        let Some(catch_up) = &self.catch_up else {
+            return Ok(());
+        };
+        self.status.last_indexed_tx_order =
+            catch_up.indexer_reader.query_last_indexed_tx_order()?;
+        self.status.target_tx_order = catch_up
+            .rooch_store
+            .get_sequencer_info()?
+            .map(|sequencer_info| sequencer_info.last_order);
+        if self.is_behind() {
+            info!(
+                "Indexer catch-up started, last indexed tx order: {:?}, target tx order: {:?}",
+                self.status.last_indexed_tx_order, self.status.target_tx_order
+            );
+            self.status.sync_status = IndexerSyncStatus::CatchingUp;
+            Self::notify_catch_up(ctx);
+        }
+        Ok(())
+    }
+
+    fn fail_catch_up(&mut self, error: anyhow::Error) {
+        error!(
+            "Indexer catch-up failed at tx order {:?}: {:?}",
+            self.status.last_indexed_tx_order.map(|v| v + 1),
+            error
+        );
+        self.status.sync_status = IndexerSyncStatus::Failed;
+        self.status.error = Some(error.to_string());
+    }
+
+    fn notify_catch_up(ctx: &mut ActorContext) {
+        if let Err(e) = ctx.actor_ref::<Self>().notify(IndexerCatchUpMessage {}) {
+            warn!("Indexer notify catch-up error: {:?}", e);
+        }
+    }
+
+    fn notify_catch_up_later(ctx: &mut ActorContext) {
+        let actor_ref = ctx.actor_ref::<Self>();
+        tokio::spawn(async move {
+            tokio::time::sleep(CATCH_UP_RETRY_INTERVAL).await;
+            if let Err(e) = actor_ref.notify(IndexerCatchUpMessage {}) {
+                warn!("Indexer notify catch-up error: {:?}", e);
+            }
+        });
+    }
+
+    /// Replay the next batch of the stored transactions to the indexer, return the last replayed tx order,
+    /// or `None` if the next transaction is not fully persisted yet.
+    #[named]
+    fn replay_batch(&self) -> Result<Option<u64>> {
+        let Some(catch_up) = &self.catch_up else {
+            return Ok(None);
+        };
+        let fn_name = function_name!();
+        let _timer = catch_up
+            .metrics
+            .indexer_catch_up_batch_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+
+        let start = self.status.last_indexed_tx_order.map_or(0, |v| v + 1);
+        let end = min(
+            start + CATCH_UP_BATCH_SIZE - 1,
+            self.status.target_tx_order.unwrap_or_default(),
+        );
+        let tx_orders = (start..=end).collect::<Vec<_>>();
+        // Only replay the continuous transactions from the start
+        let tx_hashes = catch_up
+            .rooch_store
+            .get_tx_hashes(tx_orders.clone())?
+            .into_iter()
+            .map_while(|tx_hash| tx_hash)
+            .collect::<Vec<_>>();
+        let ledger_transactions = catch_up
+            .rooch_store
+            .get_transactions_by_hash(tx_hashes.clone())?;
+        let execution_infos = catch_up
+            .moveos_store
+            .multi_get_tx_execution_infos(tx_hashes.clone())?;
+        let state_change_sets = catch_up
+            .rooch_store
+            .multi_get_state_change_set(tx_orders[..tx_hashes.len()].to_vec())?;
+
+        let mut transactions = vec![];
+        let mut events = vec![];
+        let mut replayed_state_change_sets = vec![];
+        for (((tx_hash, ledger_transaction), execution_info), state_change_set_ext) in tx_hashes
+            .into_iter()
+            .zip(ledger_transactions)
+            .zip(execution_infos)
+            .zip(state_change_sets)
+        {
+            // The transaction is sequenced but not executed yet, or the state change set is not saved yet
+            let (Some(mut ledger_transaction), Some(execution_info), Some(state_change_set_ext)) =
+                (ledger_transaction, execution_info, state_change_set_ext)
+            else {
+                break;
+            };
+            let tx_order = ledger_transaction.sequence_info.tx_order;
+            let (tx_context, action_type) =
+                IndexerTransaction::replay_context(&mut ledger_transaction);
+            let tx_events = match catch_up.moveos_store.get_events_by_tx_hash(tx_hash)? {
+                Some(tx_events) => tx_events,
+                // The event root of a transaction without events is the placeholder hash
+                None if execution_info.event_root == *ACCUMULATOR_PLACEHOLDER_HASH => vec![],
+                None => bail!(
+                    "The event ids of tx {:?}(tx order {}) are not recorded, the events can not be replayed, please rebuild the indexer",
+                    tx_hash,
+                    tx_order
+                ),
+            };
+            events.extend(tx_events.into_iter().map(|event| {
+                IndexerEvent::new(event, ledger_transaction.clone(), tx_context.clone())
+            }));
+            transactions.push(IndexerTransaction::new_with_action_type(
+                ledger_transaction,
+                execution_info,
+                action_type,
+                tx_context,
+            )?);
+            replayed_state_change_sets.push((tx_order, state_change_set_ext.state_change_set));
+        }
+        let Some(last_tx_order) = replayed_state_change_sets
+            .last()
+            .map(|(tx_order, _)| *tx_order)
+        else {
+            return Ok(None);
+        };
+
+        // The transactions table records the catch-up progress, so write it last.
+        // The states are upserted and the events of the batch are deleted before inserting,
+        // so the batch can be replayed again if the node stops in the middle of the batch.
+        let replayed_tx_orders = replayed_state_change_sets
+            .iter()
+            .map(|(tx_order, _)| *tx_order)
+            .collect::<Vec<_>>();
+        for (tx_order, state_change_set) in replayed_state_change_sets {
+            self.index_object_changes(tx_order, state_change_set)?;
+        }
+        self.indexer_store.delete_events(replayed_tx_orders)?;
+        self.indexer_store.persist_events(events)?;
+        self.indexer_store.persist_transactions(transactions)?;
+        Ok(Some(last_tx_order))
+    }
+
+    fn report_catch_up_metrics(&self) {
+        if let Some(catch_up) = &self.catch_up {
+            catch_up
+                .metrics
+                .indexer_catch_up_last_indexed_tx_order
+                .set(self.status.last_indexed_tx_order.unwrap_or_default() as i64);
+            catch_up
+                .metrics
+                .indexer_catch_up_target_tx_order
+                .set(self.status.target_tx_order.unwrap_or_default() as i64);
+        }
+    }
+
+    fn update_indexer(&mut self, msg: UpdateIndexerMessage) -> Result<()> {
         let UpdateIndexerMessage {
             ledger_transaction,
             execution_info,
             moveos_tx,
             events,
             state_change_set,
+            span: _,
         } = msg;
 
         self.root = state_change_set.root_metadata();
         let tx_order = ledger_transaction.sequence_info.tx_order;
 
-        // 1. update indexer transaction
-        let move_action = MoveAction::from(moveos_tx.action);
-        let indexer_transaction = IndexerTransaction::new(
-            ledger_transaction.clone(),
-            execution_info.clone(),
-            move_action,
-            moveos_tx.ctx.clone(),
-        )?;
-        let transactions = vec![indexer_transaction];
-        self.indexer_store.persist_transactions(transactions)?;
+        match self.status.sync_status {
+            // The transaction is replayed from the store by the catch-up
+            IndexerSyncStatus::CatchingUp => {
+                self.status.target_tx_order = max(self.status.target_tx_order, Some(tx_order));
+                self.report_catch_up_metrics();
+                return Ok(());
+            }
+            // Do not index over the gap, the catch-up restarts from the gap when the node restarts
+            IndexerSyncStatus::Failed => return Ok(()),
+            IndexerSyncStatus::Synced => {}
+        }
+        if self
+            .status
+            .last_indexed_tx_order
+            .is_some_and(|last_indexed_tx_order| tx_order <= last_indexed_tx_order)
+        {
+            debug!("Indexer skip the indexed tx order {}", tx_order);
+            return Ok(());
+        }
+
+        // Write in the same order as the catch-up replay, the transactions table records the
+        // indexing progress, so write it last.
+        // 1. update indexer full object state, including object_states, utxos and inscriptions
+        self.index_object_changes(tx_order, state_change_set)?;
 
         // 2. update indexer event
         let events: Vec<_> = events
@@ -72,28 +488,120 @@ impl Handler<UpdateIndexerMessage> for IndexerActor {
                 )
             })
             .collect();
+        self.indexer_store.delete_events(vec![tx_order])?;
         self.indexer_store.persist_events(events)?;
 
-        // 3. update indexer full object state, including object_states, utxos and inscriptions
-        // indexer object state index generator
-        let mut state_index_generator = IndexerObjectStatesIndexGenerator::default();
-        let mut indexer_object_state_change_set = IndexerObjectStateChangeSet::default();
+        // 3. update indexer transaction
+        let move_action = MoveAction::from(moveos_tx.action);
+        let indexer_transaction = IndexerTransaction::new(
+            ledger_transaction,
+            execution_info,
+            move_action,
+            moveos_tx.ctx,
+        )?;
+        self.indexer_store
+            .persist_transactions(vec![indexer_transaction])?;
+        self.status.last_indexed_tx_order = Some(tx_order);
+        self.report_catch_up_metrics();
 
-        for (_feild_key, object_change) in state_change_set.changes {
-            let _ = handle_object_change(
-                &mut state_index_generator,
-                tx_order,
-                &mut indexer_object_state_change_set,
-                object_change,
-            )?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Actor for IndexerActor {
+    async fn started(&mut self, ctx: &mut ActorContext) {
+        if let Err(e) = self.start_catch_up(ctx) {
+            self.fail_catch_up(e);
         }
-        self.indexer_store
-            .apply_object_states(indexer_object_state_change_set)?;
+        self.report_catch_up_metrics();
+    }
+}
 
+#[async_trait]
+impl Handler<IndexerCatchUpMessage> for IndexerActor {
+    async fn handle(&mut self, _msg: IndexerCatchUpMessage, ctx: &mut ActorContext) -> Result<()> {
+        if !self.status.is_catching_up() {
+            return Ok(());
+        }
+        match self.replay_batch() {
+            Ok(Some(last_tx_order)) => {
+                self.status.last_indexed_tx_order = Some(last_tx_order);
+                if let Some(catch_up) = self.catch_up.as_mut() {
+                    catch_up.retries = 0;
+                }
+                if self.is_behind() {
+                    Self::notify_catch_up(ctx);
+                } else {
+                    info!(
+                        "Indexer catch-up finished at tx order {}, switch to index the live transactions",
+                        last_tx_order
+                    );
+                    self.status.sync_status = IndexerSyncStatus::Synced;
+                }
+            }
+            Ok(None) => {
+                let retries = self.catch_up.as_mut().map_or(0, |catch_up| {
+                    catch_up.retries += 1;
+                    catch_up.retries
+                });
+                if retries > CATCH_UP_MAX_RETRIES {
+                    self.fail_catch_up(anyhow::anyhow!(
+                        "The transaction, execution info or state change set is missing after {} retries",
+                        CATCH_UP_MAX_RETRIES
+                    ));
+                } else {
+                    Self::notify_catch_up_later(ctx);
+                }
+            }
+            Err(e) => self.fail_catch_up(e),
+        }
+        self.report_catch_up_metrics();
         Ok(())
     }
 }
 
+#[async_trait]
+impl Handler<QueryIndexerStatusMessage> for IndexerActor {
+    async fn handle(
+        &mut self,
+        _msg: QueryIndexerStatusMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<IndexerStatus> {
+        Ok(self.status.clone())
+    }
+}
+
+#[async_trait]
+impl Handler<IndexerBackupMessage> for IndexerActor {
+    async fn handle(&mut self, msg: IndexerBackupMessage, _ctx: &mut ActorContext) -> Result<()> {
+        self.indexer_store.backup_to(&msg.backup_dir)
+    }
+}
+
+#[async_trait]
+impl Handler<UpdateIndexerMessage> for IndexerActor {
+    async fn handle(&mut self, msg: UpdateIndexerMessage, _ctx: &mut ActorContext) -> Result<()> {
+        let _timer = self
+            .metrics
+            .indexer_update_indexer_latency_seconds
+            .start_timer();
+        let span = msg.span.clone();
+        span.in_scope(|| self.update_indexer(msg))
+    }
+}
+
+#[async_trait]
+impl Handler<RenewIndexerGenerationMessage> for IndexerActor {
+    async fn handle(
+        &mut self,
+        _msg: RenewIndexerGenerationMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<u64> {
+        self.indexer_store.renew_generation()
+    }
+}
+
 #[async_trait]
 impl Handler<IndexerStatesMessage> for IndexerActor {
     async fn handle(&mut self, msg: IndexerStatesMessage, _ctx: &mut ActorContext) -> Result<()> {
@@ -105,24 +613,7 @@ impl Handler<IndexerStatesMessage> for IndexerActor {
         } = msg;
 
         self.root = root;
-
-        // indexer state index generator
-        let mut state_index_generator = IndexerObjectStatesIndexGenerator::default();
-        let mut indexer_object_state_change_set = IndexerObjectStateChangeSet::default();
-
-        for (_field_key, object_change) in state_change_set.changes {
-            handle_object_change(
-                &mut state_index_generator,
-                tx_order,
-                &mut indexer_object_state_change_set,
-                object_change,
-            )?;
-        }
-
-        self.indexer_store
-            .apply_object_states(indexer_object_state_change_set)?;
-
-        Ok(())
+        self.index_object_changes(tx_order, state_change_set)
     }
 }
 
@@ -186,6 +677,9 @@ impl Handler<IndexerPersistOrUpdateAnyObjectStatesMessage> for IndexerActor {
             ObjectStateType::Inscription => self
                 .indexer_store
                 .persist_or_update_object_state_inscriptions(states)?,
+            ObjectStateType::Rune => self
+                .indexer_store
+                .persist_or_update_object_state_runes(states)?,
         }
         Ok(())
     }
@@ -210,6 +704,7 @@ impl Handler<IndexerDeleteAnyObjectStatesMessage> for IndexerActor {
             ObjectStateType::Inscription => self
                 .indexer_store
                 .delete_object_state_inscriptions(state_pks)?,
+            ObjectStateType::Rune => self.indexer_store.delete_object_state_runes(state_pks)?,
         }
         Ok(())
     }
@@ -251,7 +746,25 @@ impl Handler<IndexerRevertMessage> for IndexerActor {
         // 2. revert indexer event
         self.indexer_store.delete_events(vec![revert_tx_order])?;
 
-        // 3. revert indexer full object state, including object_states, utxos and inscriptions
+        // 3. revert the custom indexes to the object states before the reverted transaction
+        if let Some(custom_indexes) = &self.custom_indexes {
+            let previous_resolver =
+                RootObjectResolver::new(self.root.clone(), &custom_indexes.moveos_store);
+            let mut custom_index_change_set = IndexerCustomIndexChangeSet::default();
+            for object_change in revert_state_change_set.state_change_set.changes.values() {
+                handle_revert_custom_index_object_change(
+                    &custom_indexes.configs,
+                    &previous_resolver,
+                    revert_tx_order,
+                    &mut custom_index_change_set,
+                    object_change,
+                )?;
+            }
+            self.indexer_store
+                .apply_custom_indexes(custom_index_change_set)?;
+        }
+
+        // 4. revert indexer full object state, including object_states, utxos and inscriptions
         // indexer object state index generator
         let mut state_index_generator = IndexerObjectStatesIndexGenerator::default();
         let mut indexer_object_state_change_set = IndexerObjectStateChangeSet::default();
@@ -267,6 +780,9 @@ impl Handler<IndexerRevertMessage> for IndexerActor {
         }
         self.indexer_store
             .apply_object_states(indexer_object_state_change_set)?;
+        self.indexer_store.renew_generation()?;
+        self.status.last_indexed_tx_order = revert_tx_order.checked_sub(1);
+        self.report_catch_up_metrics();
 
         Ok(())
     }
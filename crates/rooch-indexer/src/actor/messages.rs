@@ -8,15 +8,19 @@ use moveos_types::moveos_std::object::{ObjectID, ObjectMeta};
 use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::state::{StateChangeSet, StateChangeSetExt};
 use moveos_types::transaction::{MoveAction, TransactionExecutionInfo, VerifiedMoveOSTransaction};
+use rooch_types::indexer::custom_index::{CustomIndexFilter, CustomIndexID};
 use rooch_types::indexer::event::{EventFilter, IndexerEvent, IndexerEventID};
 use rooch_types::indexer::state::{
     IndexerObjectState, IndexerObjectStateChangeSet, IndexerStateID, ObjectStateFilter,
     ObjectStateType,
 };
+use rooch_types::indexer::status::IndexerStatus;
 use rooch_types::indexer::transaction::{IndexerTransaction, TransactionFilter};
 use rooch_types::transaction::LedgerTransaction;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
+use tracing::Span;
 
 /// Indexer write Message
 #[derive(Debug, Clone)]
@@ -26,6 +30,8 @@ pub struct UpdateIndexerMessage {
     pub moveos_tx: VerifiedMoveOSTransaction,
     pub events: Vec<Event>,
     pub state_change_set: StateChangeSet,
+    /// The tracing span of the tx lifecycle
+    pub span: Span,
 }
 
 impl Message for UpdateIndexerMessage {
@@ -112,6 +118,20 @@ impl Message for QueryIndexerObjectIdsMessage {
     type Result = Result<Vec<(ObjectID, IndexerStateID)>>;
 }
 
+/// Query object ids by custom index Message
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QueryIndexerObjectIdsByCustomIndexMessage {
+    pub filter: CustomIndexFilter,
+    // exclusive cursor if `Some`, otherwise start from the beginning
+    pub cursor: Option<CustomIndexID>,
+    pub limit: usize,
+    pub descending_order: bool,
+}
+
+impl Message for QueryIndexerObjectIdsByCustomIndexMessage {
+    type Result = Result<Vec<(ObjectID, CustomIndexID)>>;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct IndexerPersistOrUpdateAnyObjectStatesMessage {
     pub states: Vec<IndexerObjectState>,
@@ -161,3 +181,43 @@ pub struct IndexerRevertMessage {
 impl Message for IndexerRevertMessage {
     type Result = Result<()>;
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QueryIndexerGenerationMessage {}
+
+impl Message for QueryIndexerGenerationMessage {
+    type Result = Result<u64>;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RenewIndexerGenerationMessage {}
+
+impl Message for RenewIndexerGenerationMessage {
+    type Result = Result<u64>;
+}
+
+/// Replay the next batch of the stored transactions to the indexer, the indexer actor sends it to itself
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IndexerCatchUpMessage {}
+
+impl Message for IndexerCatchUpMessage {
+    type Result = Result<()>;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QueryIndexerStatusMessage {}
+
+impl Message for QueryIndexerStatusMessage {
+    type Result = Result<IndexerStatus>;
+}
+
+/// Copy the indexer dbs into `backup_dir`, the indexer actor handles it between two indexed transactions,
+/// so every table of the copy is indexed to the same tx order.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IndexerBackupMessage {
+    pub backup_dir: PathBuf,
+}
+
+impl Message for IndexerBackupMessage {
+    type Result = Result<()>;
+}
@@ -2,13 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::actor::messages::{
-    QueryIndexerEventsMessage, QueryIndexerTransactionsMessage, QueryLastStateIndexByTxOrderMessage,
+    QueryIndexerEventsMessage, QueryIndexerGenerationMessage,
+    QueryIndexerObjectIdsByCustomIndexMessage, QueryIndexerTransactionsMessage,
+    QueryLastStateIndexByTxOrderMessage,
 };
 use crate::indexer_reader::IndexerReader;
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
 use moveos_types::moveos_std::object::ObjectID;
+use rooch_types::indexer::custom_index::CustomIndexID;
 use rooch_types::indexer::event::IndexerEvent;
 use rooch_types::indexer::state::IndexerStateID;
 use rooch_types::indexer::transaction::IndexerTransaction;
@@ -85,6 +88,25 @@ impl Handler<QueryIndexerObjectIdsMessage> for IndexerReaderActor {
     }
 }
 
+#[async_trait]
+impl Handler<QueryIndexerObjectIdsByCustomIndexMessage> for IndexerReaderActor {
+    async fn handle(
+        &mut self,
+        msg: QueryIndexerObjectIdsByCustomIndexMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<(ObjectID, CustomIndexID)>> {
+        let QueryIndexerObjectIdsByCustomIndexMessage {
+            filter,
+            cursor,
+            limit,
+            descending_order,
+        } = msg;
+        self.indexer_reader
+            .query_object_ids_by_custom_index(filter, cursor, limit, descending_order)
+            .map_err(|e| anyhow!(format!("Failed to query indexer custom index: {:?}", e)))
+    }
+}
+
 #[async_trait]
 impl Handler<QueryLastStateIndexByTxOrderMessage> for IndexerReaderActor {
     async fn handle(
@@ -107,3 +129,16 @@ impl Handler<QueryLastStateIndexByTxOrderMessage> for IndexerReaderActor {
             })
     }
 }
+
+#[async_trait]
+impl Handler<QueryIndexerGenerationMessage> for IndexerReaderActor {
+    async fn handle(
+        &mut self,
+        _msg: QueryIndexerGenerationMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<u64> {
+        self.indexer_reader
+            .query_generation()
+            .map_err(|e| anyhow!(format!("Failed to query indexer generation: {:?}", e)))
+    }
+}
@@ -3,32 +3,41 @@
 
 use crate::errors::IndexerError;
 use crate::metrics::IndexerReaderMetrics;
+use crate::models::custom_indexes::{StoredCustomIndexConfig, StoredCustomIndexID};
 use crate::models::events::StoredEvent;
 use crate::models::states::{StoredObjectStateInfo, StoredStateID};
 use crate::models::transactions::StoredTransaction;
-use crate::schema::{events, transactions};
+use crate::schema::{
+    custom_index_configs, custom_indexes, events, indexer_generation, transactions,
+};
 use crate::utils::escape_sql_string;
 use crate::{
     IndexerResult, IndexerStoreMeta, IndexerTableName, SqliteConnectionConfig,
     SqliteConnectionPoolConfig, SqlitePoolConnection, DEFAULT_BUSY_TIMEOUT,
-    INDEXER_EVENTS_TABLE_NAME, INDEXER_OBJECT_STATES_TABLE_NAME,
-    INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME, INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME,
+    INDEXER_CUSTOM_INDEXES_TABLE_NAME, INDEXER_EVENTS_TABLE_NAME, INDEXER_GENERATION_TABLE_NAME,
+    INDEXER_OBJECT_STATES_TABLE_NAME, INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME,
+    INDEXER_OBJECT_STATE_RUNES_TABLE_NAME, INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME,
     INDEXER_TRANSACTIONS_TABLE_NAME,
 };
 use anyhow::{anyhow, Result};
 use diesel::{
-    r2d2::ConnectionManager, Connection, ExpressionMethods, QueryDsl, RunQueryDsl, SqliteConnection,
+    r2d2::ConnectionManager, BoolExpressionMethods, Connection, ExpressionMethods,
+    OptionalExtension, QueryDsl, RunQueryDsl, SqliteConnection,
 };
 use function_name::named;
 use move_core_types::language_storage::StructTag;
 use moveos_types::moveos_std::object::ObjectID;
 use prometheus::Registry;
+use rooch_types::indexer::custom_index::{
+    CustomIndexConfig, CustomIndexFieldType, CustomIndexFilter, CustomIndexID,
+};
 use rooch_types::indexer::event::{EventFilter, IndexerEvent, IndexerEventID};
 use rooch_types::indexer::state::{IndexerStateID, ObjectStateFilter, ObjectStateType};
 use rooch_types::indexer::transaction::{IndexerTransaction, TransactionFilter};
 use std::collections::HashMap;
 use std::ops::DerefMut;
 use std::path::PathBuf;
+use std::str::FromStr;
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
 use tokio::runtime::Handle;
@@ -473,6 +482,10 @@ impl IndexerReader {
                     .join(",");
                 format!("{OBJECT_ID_STR} IN ({object_ids_str})")
             }
+            ObjectStateFilter::CustomIndex(_) => return Err(IndexerError::NotSupportedError(
+                "The custom index filter should be queried via query_object_ids_by_custom_index"
+                    .to_string(),
+            )),
         };
 
         let has_main_where_clause = main_where_clause.ne(" ");
@@ -548,6 +561,181 @@ impl IndexerReader {
         Ok(result)
     }
 
+    /// Query the object ids in the custom index ordered by `(value, object_id)`,
+    /// the cursor is the position of the last object of the previous page.
+    #[named]
+    pub fn query_object_ids_by_custom_index(
+        &self,
+        filter: CustomIndexFilter,
+        cursor: Option<CustomIndexID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> IndexerResult<Vec<(ObjectID, CustomIndexID)>> {
+        let fn_name = function_name!();
+        let _timer = self
+            .metrics
+            .indexer_reader_query_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let CustomIndexFilter {
+            object_type,
+            field_path,
+            from,
+            to,
+        } = filter;
+        // The bounds are encoded by the type of the indexed field, the custom index is empty if the type is unknown.
+        let Some(field_type) =
+            self.query_custom_index_field_type(object_type.clone(), field_path.clone())?
+        else {
+            return Ok(vec![]);
+        };
+        let encode_bound = |bound: Option<String>| {
+            bound
+                .map(|bound| field_type.encode_bound(&bound))
+                .transpose()
+                .map_err(|e| {
+                    IndexerError::InvalidArgumentError(format!(
+                        "Invalid custom index bound: {:?}",
+                        e
+                    ))
+                })
+        };
+        let from = encode_bound(from)?;
+        let to = encode_bound(to)?;
+        let stored_custom_index_ids = self
+            .get_inner_indexer_reader(INDEXER_CUSTOM_INDEXES_TABLE_NAME)?
+            .run_query_with_timeout(move |conn| {
+                let mut query = custom_indexes::table
+                    .select((custom_indexes::value, custom_indexes::object_id))
+                    .filter(custom_indexes::object_type.eq(object_type.to_string()))
+                    .filter(custom_indexes::field_path.eq(field_path))
+                    .into_boxed();
+                if let Some(from) = from {
+                    query = query.filter(custom_indexes::value.ge(from));
+                }
+                if let Some(to) = to {
+                    query = query.filter(custom_indexes::value.lt(to));
+                }
+                if let Some(CustomIndexID { value, object_id }) = cursor {
+                    let object_id = object_id.to_string();
+                    query = if descending_order {
+                        query.filter(
+                            custom_indexes::value
+                                .lt(value.clone())
+                                .or(custom_indexes::value
+                                    .eq(value)
+                                    .and(custom_indexes::object_id.lt(object_id))),
+                        )
+                    } else {
+                        query.filter(
+                            custom_indexes::value
+                                .gt(value.clone())
+                                .or(custom_indexes::value
+                                    .eq(value)
+                                    .and(custom_indexes::object_id.gt(object_id))),
+                        )
+                    };
+                }
+                query = if descending_order {
+                    query.order_by((
+                        custom_indexes::value.desc(),
+                        custom_indexes::object_id.desc(),
+                    ))
+                } else {
+                    query.order_by((custom_indexes::value.asc(), custom_indexes::object_id.asc()))
+                };
+                query.limit(limit as i64).load::<StoredCustomIndexID>(conn)
+            })?;
+
+        let result = stored_custom_index_ids
+            .into_iter()
+            .map(|v| {
+                let custom_index_id = v.try_parse_id()?;
+                Ok((custom_index_id.object_id.clone(), custom_index_id))
+            })
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| {
+                IndexerError::SQLiteReadError(format!(
+                    "Cast indexer custom index ids failed: {:?}",
+                    e
+                ))
+            })?;
+
+        Ok(result)
+    }
+
+    fn query_custom_index_field_type(
+        &self,
+        object_type: StructTag,
+        field_path: String,
+    ) -> IndexerResult<Option<CustomIndexFieldType>> {
+        let field_type = self
+            .get_inner_indexer_reader(INDEXER_CUSTOM_INDEXES_TABLE_NAME)?
+            .run_query_with_timeout(move |conn| {
+                custom_indexes::table
+                    .select(custom_indexes::field_type)
+                    .filter(custom_indexes::object_type.eq(object_type.to_string()))
+                    .filter(custom_indexes::field_path.eq(field_path))
+                    .first::<String>(conn)
+                    .optional()
+            })?;
+        field_type
+            .map(|field_type| CustomIndexFieldType::from_str(&field_type))
+            .transpose()
+            .map_err(|e| {
+                IndexerError::SQLiteReadError(format!(
+                    "Cast indexer custom index field type failed: {:?}",
+                    e
+                ))
+            })
+    }
+
+    /// Query the custom indexes which are backfilled by the indexer.
+    pub fn query_custom_index_configs(&self) -> IndexerResult<Vec<CustomIndexConfig>> {
+        let stored_configs = self
+            .get_inner_indexer_reader(INDEXER_CUSTOM_INDEXES_TABLE_NAME)?
+            .run_query_with_timeout(|conn| {
+                custom_index_configs::table.load::<StoredCustomIndexConfig>(conn)
+            })?;
+        stored_configs
+            .iter()
+            .map(|config| config.try_parse_config())
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| {
+                IndexerError::SQLiteReadError(format!(
+                    "Cast indexer custom index configs failed: {:?}",
+                    e
+                ))
+            })
+    }
+
+    /// Query the max tx order of the indexed transactions, `None` if no transaction is indexed
+    pub fn query_last_indexed_tx_order(&self) -> IndexerResult<Option<u64>> {
+        let max_tx_order = self
+            .get_inner_indexer_reader(INDEXER_TRANSACTIONS_TABLE_NAME)?
+            .run_query_with_timeout(|conn| {
+                transactions::dsl::transactions
+                    .select(transactions::tx_order)
+                    .order_by(transactions::tx_order.desc())
+                    .first::<i64>(conn)
+                    .optional()
+            })?;
+        Ok(max_tx_order.map(|tx_order| tx_order as u64))
+    }
+
+    /// Query the current indexer generation, the generation is renewed by the indexer writer
+    pub fn query_generation(&self) -> IndexerResult<u64> {
+        let generation = self
+            .get_inner_indexer_reader(INDEXER_GENERATION_TABLE_NAME)?
+            .run_query_with_timeout(|conn| {
+                indexer_generation::table
+                    .select(indexer_generation::generation)
+                    .first::<i64>(conn)
+                    .optional()
+            })?;
+        Ok(generation.unwrap_or_default() as u64)
+    }
+
     pub fn query_last_indexer_state_id(
         &self,
         state_type: ObjectStateType,
@@ -615,6 +803,7 @@ fn get_table_name_by_state_type(state_type: ObjectStateType) -> IndexerTableName
         ObjectStateType::ObjectState => INDEXER_OBJECT_STATES_TABLE_NAME,
         ObjectStateType::UTXO => INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME,
         ObjectStateType::Inscription => INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME,
+        ObjectStateType::Rune => INDEXER_OBJECT_STATE_RUNES_TABLE_NAME,
     }
 }
 fn object_type_query(object_type: &StructTag) -> String {
@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::models::custom_indexes::StoredCustomIndexConfig;
 use crate::store::metrics::IndexerDBMetrics;
 use crate::store::sqlite_store::SqliteIndexerStore;
 use crate::store::traits::IndexerStoreTrait;
@@ -14,6 +15,9 @@ use diesel::RunQueryDsl;
 use errors::IndexerError;
 use once_cell::sync::Lazy;
 use prometheus::Registry;
+use rooch_types::indexer::custom_index::{
+    CustomIndexConfig, IndexerCustomIndex, IndexerCustomIndexChangeSet,
+};
 use rooch_types::indexer::event::IndexerEvent;
 use rooch_types::indexer::state::{
     IndexerObjectState, IndexerObjectStateChangeSet, IndexerObjectStateChanges, ObjectStateType,
@@ -21,7 +25,7 @@ use rooch_types::indexer::state::{
 use rooch_types::indexer::transaction::IndexerTransaction;
 use std::collections::HashMap;
 use std::fmt::{Debug, Display, Formatter};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::string::ToString;
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
@@ -47,7 +51,11 @@ pub const INDEXER_EVENTS_TABLE_NAME: IndexerTableName = "events";
 pub const INDEXER_OBJECT_STATES_TABLE_NAME: IndexerTableName = "object_states";
 pub const INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME: IndexerTableName = "utxos";
 pub const INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME: IndexerTableName = "inscriptions";
+pub const INDEXER_OBJECT_STATE_RUNES_TABLE_NAME: IndexerTableName = "runes";
 pub const INDEXER_TRANSACTIONS_TABLE_NAME: IndexerTableName = "transactions";
+pub const INDEXER_CUSTOM_INDEXES_TABLE_NAME: IndexerTableName = "custom_indexes";
+/// The indexer generation is recorded in the db of the object states table.
+pub const INDEXER_GENERATION_TABLE_NAME: IndexerTableName = INDEXER_OBJECT_STATES_TABLE_NAME;
 
 /// Please note that adding new indexer table needs to be added in vec simultaneously.
 static INDEXER_VEC_TABLE_NAME: Lazy<Vec<IndexerTableName>> = Lazy::new(|| {
@@ -56,7 +64,9 @@ static INDEXER_VEC_TABLE_NAME: Lazy<Vec<IndexerTableName>> = Lazy::new(|| {
         INDEXER_OBJECT_STATES_TABLE_NAME,
         INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME,
         INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME,
+        INDEXER_OBJECT_STATE_RUNES_TABLE_NAME,
         INDEXER_TRANSACTIONS_TABLE_NAME,
+        INDEXER_CUSTOM_INDEXES_TABLE_NAME,
     ]
 });
 
@@ -104,9 +114,27 @@ impl IndexerStore {
             sqlite_store_mapping,
         };
         store.create_all_tables_if_not_exists()?;
+        if store.generation()? == 0 {
+            store.renew_generation()?;
+        }
         Ok(store)
     }
 
+    /// The generation of the indexer, the state indexes are only comparable in the same generation.
+    pub fn generation(&self) -> Result<u64> {
+        Ok(self
+            .get_sqlite_store(INDEXER_GENERATION_TABLE_NAME)?
+            .query_generation()?)
+    }
+
+    /// Renew the generation after the indexer rows are rebuilt or reshuffled,
+    /// so the state cursors of the previous generation are rejected.
+    pub fn renew_generation(&self) -> Result<u64> {
+        Ok(self
+            .get_sqlite_store(INDEXER_GENERATION_TABLE_NAME)?
+            .renew_generation()?)
+    }
+
     pub fn get_sqlite_store(&self, table_name: &str) -> Result<SqliteIndexerStore> {
         Ok(self
             .sqlite_store_mapping
@@ -115,6 +143,32 @@ impl IndexerStore {
             .clone())
     }
 
+    /// Write a consistent copy of every indexer table db into `backup_dir` via `VACUUM INTO`,
+    /// the writers are not blocked while the copy is in progress.
+    pub fn backup_to(&self, backup_dir: &Path) -> Result<()> {
+        if !backup_dir.exists() {
+            std::fs::create_dir_all(backup_dir)?;
+        }
+        for (table, store) in &self.sqlite_store_mapping {
+            let backup_path = backup_dir.join(table);
+            if backup_path.exists() {
+                return Err(anyhow::anyhow!(
+                    "Indexer backup file {:?} already exists",
+                    backup_path
+                ));
+            }
+            let backup_path = backup_path
+                .to_str()
+                .ok_or(anyhow::anyhow!("Invalid indexer backup path"))?
+                .replace('\'', "''");
+            let mut connection = get_sqlite_pool_connection(&store.connection_pool)?;
+            connection
+                .batch_execute(&format!("VACUUM INTO '{}'", backup_path))
+                .map_err(|e| anyhow::anyhow!("Backup indexer table {} error: {:?}", table, e))?;
+        }
+        Ok(())
+    }
+
     fn create_all_tables_if_not_exists(&self) -> Result<()> {
         for (k, v) in &self.sqlite_store_mapping {
             let mut connection = get_sqlite_pool_connection(&v.connection_pool)?;
@@ -158,6 +212,11 @@ impl IndexerStoreTrait for IndexerStore {
             object_state_change_set.object_state_inscriptions,
             INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME,
             ObjectStateType::Inscription,
+        )?;
+        self.update_object_states_by_state_type(
+            object_state_change_set.object_state_runes,
+            INDEXER_OBJECT_STATE_RUNES_TABLE_NAME,
+            ObjectStateType::Rune,
         )
     }
 
@@ -200,6 +259,19 @@ impl IndexerStoreTrait for IndexerStore {
             .delete_object_state_inscriptions(state_pks)
     }
 
+    fn persist_or_update_object_state_runes(
+        &self,
+        states: Vec<IndexerObjectState>,
+    ) -> Result<(), IndexerError> {
+        self.get_sqlite_store(INDEXER_OBJECT_STATE_RUNES_TABLE_NAME)?
+            .persist_or_update_object_state_runes(states)
+    }
+
+    fn delete_object_state_runes(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
+        self.get_sqlite_store(INDEXER_OBJECT_STATE_RUNES_TABLE_NAME)?
+            .delete_object_state_runes(state_pks)
+    }
+
     fn persist_transactions(
         &self,
         transactions: Vec<IndexerTransaction>,
@@ -222,6 +294,49 @@ impl IndexerStoreTrait for IndexerStore {
         self.get_sqlite_store(INDEXER_EVENTS_TABLE_NAME)?
             .delete_events(tx_orders)
     }
+
+    fn apply_custom_indexes(
+        &self,
+        custom_index_change_set: IndexerCustomIndexChangeSet,
+    ) -> Result<(), IndexerError> {
+        // Remove the stale entries of the changed objects before writing the current ones
+        let object_ids = custom_index_change_set
+            .remove_object_ids
+            .into_iter()
+            .map(|object_id| object_id.to_string())
+            .collect();
+        self.delete_custom_indexes(object_ids)?;
+        self.persist_or_update_custom_indexes(custom_index_change_set.new_indexes)
+    }
+
+    fn persist_or_update_custom_indexes(
+        &self,
+        indexes: Vec<IndexerCustomIndex>,
+    ) -> Result<(), IndexerError> {
+        self.get_sqlite_store(INDEXER_CUSTOM_INDEXES_TABLE_NAME)?
+            .persist_or_update_custom_indexes(indexes)
+    }
+
+    fn delete_custom_indexes(&self, object_ids: Vec<String>) -> Result<(), IndexerError> {
+        self.get_sqlite_store(INDEXER_CUSTOM_INDEXES_TABLE_NAME)?
+            .delete_custom_indexes(object_ids)
+    }
+
+    fn persist_custom_index_configs(
+        &self,
+        configs: Vec<CustomIndexConfig>,
+    ) -> Result<(), IndexerError> {
+        // The configs are stored in the db of the custom indexes, so they are deleted together
+        self.get_sqlite_store(INDEXER_CUSTOM_INDEXES_TABLE_NAME)?
+            .persist_custom_index_configs(
+                configs.iter().map(StoredCustomIndexConfig::from).collect(),
+            )
+    }
+
+    fn delete_custom_index_config(&self, config: CustomIndexConfig) -> Result<(), IndexerError> {
+        self.get_sqlite_store(INDEXER_CUSTOM_INDEXES_TABLE_NAME)?
+            .delete_custom_index_config(StoredCustomIndexConfig::from(&config))
+    }
 }
 
 impl IndexerStore {
@@ -252,6 +367,12 @@ impl IndexerStore {
                 self.get_sqlite_store(table_name)?
                     .delete_object_state_inscriptions(object_state_change.remove_object_states)
             }
+            ObjectStateType::Rune => {
+                self.get_sqlite_store(table_name)?
+                    .persist_or_update_object_state_runes(object_states_new_and_update)?;
+                self.get_sqlite_store(table_name)?
+                    .delete_object_state_runes(object_state_change.remove_object_states)
+            }
         }
     }
 }
@@ -2,7 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use metrics::metrics_util::LATENCY_SEC_BUCKETS;
-use prometheus::{register_histogram_vec_with_registry, HistogramVec, Registry};
+use prometheus::{
+    register_histogram_vec_with_registry, register_histogram_with_registry,
+    register_int_gauge_with_registry, Histogram, HistogramVec, IntGauge, Registry,
+};
 
 #[derive(Debug)]
 pub struct IndexerReaderMetrics {
@@ -23,3 +26,56 @@ impl IndexerReaderMetrics {
         }
     }
 }
+
+#[derive(Debug)]
+pub struct IndexerMetrics {
+    pub indexer_update_indexer_latency_seconds: Histogram,
+}
+
+impl IndexerMetrics {
+    pub(crate) fn new(registry: &Registry) -> Self {
+        IndexerMetrics {
+            indexer_update_indexer_latency_seconds: register_histogram_with_registry!(
+                "indexer_update_indexer_latency_seconds",
+                "Indexer latency in seconds to index the transaction, events and states of a tx",
+                LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct IndexerCatchUpMetrics {
+    pub indexer_catch_up_last_indexed_tx_order: IntGauge,
+    pub indexer_catch_up_target_tx_order: IntGauge,
+    pub indexer_catch_up_batch_latency_seconds: HistogramVec,
+}
+
+impl IndexerCatchUpMetrics {
+    pub(crate) fn new(registry: &Registry) -> Self {
+        IndexerCatchUpMetrics {
+            indexer_catch_up_last_indexed_tx_order: register_int_gauge_with_registry!(
+                "indexer_catch_up_last_indexed_tx_order",
+                "The last tx order replayed to the indexer by the catch-up",
+                registry,
+            )
+            .unwrap(),
+            indexer_catch_up_target_tx_order: register_int_gauge_with_registry!(
+                "indexer_catch_up_target_tx_order",
+                "The tx order the indexer catch-up replays to",
+                registry,
+            )
+            .unwrap(),
+            indexer_catch_up_batch_latency_seconds: register_histogram_vec_with_registry!(
+                "indexer_catch_up_batch_latency_seconds",
+                "Indexer catch-up replay batch latency in seconds",
+                &["fn_name"],
+                LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::schema::{custom_index_configs, custom_indexes};
+use diesel::prelude::*;
+use moveos_types::moveos_std::object::ObjectID;
+use rooch_types::indexer::custom_index::{CustomIndexConfig, CustomIndexID, IndexerCustomIndex};
+use std::str::FromStr;
+
+#[derive(Queryable, QueryableByName, Insertable, Debug, Clone)]
+#[diesel(table_name = custom_indexes)]
+pub struct StoredCustomIndex {
+    /// The indexed object id
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub object_id: String,
+    /// The field path of the custom index
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub field_path: String,
+    /// The struct type of the indexed object
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub object_type: String,
+    /// The type of the indexed field
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub field_type: String,
+    /// The encoded field value
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub value: String,
+    /// The tx order of the transaction which updated the value
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub tx_order: i64,
+}
+
+impl From<IndexerCustomIndex> for StoredCustomIndex {
+    fn from(index: IndexerCustomIndex) -> Self {
+        Self {
+            object_id: index.object_id.to_string(),
+            field_path: index.field_path,
+            object_type: index.object_type.to_string(),
+            field_type: index.field_type.to_string(),
+            value: index.value,
+            tx_order: index.tx_order as i64,
+        }
+    }
+}
+
+#[derive(Queryable, Debug)]
+pub struct StoredCustomIndexID {
+    pub value: String,
+    pub object_id: String,
+}
+
+impl StoredCustomIndexID {
+    pub fn try_parse_id(&self) -> Result<CustomIndexID, anyhow::Error> {
+        Ok(CustomIndexID::new(
+            self.value.clone(),
+            ObjectID::from_str(self.object_id.as_str())?,
+        ))
+    }
+}
+
+/// The custom index defined in the indexer config, it is recorded after the custom index is backfilled.
+#[derive(Queryable, Insertable, Debug, Clone)]
+#[diesel(table_name = custom_index_configs)]
+pub struct StoredCustomIndexConfig {
+    pub object_type: String,
+    pub field_path: String,
+}
+
+impl From<&CustomIndexConfig> for StoredCustomIndexConfig {
+    fn from(config: &CustomIndexConfig) -> Self {
+        Self {
+            object_type: config.object_type.to_string(),
+            field_path: config.field_path_string(),
+        }
+    }
+}
+
+impl StoredCustomIndexConfig {
+    pub fn try_parse_config(&self) -> Result<CustomIndexConfig, anyhow::Error> {
+        CustomIndexConfig::from_str(&format!("{}.{}", self.object_type, self.field_path))
+    }
+}
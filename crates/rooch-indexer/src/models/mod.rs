@@ -1,8 +1,10 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod custom_indexes;
 pub mod events;
 pub mod inscriptions;
+pub mod runes;
 pub mod states;
 pub mod transactions;
 pub mod utxos;
@@ -3,10 +3,12 @@
 
 use crate::actor::indexer::IndexerActor;
 use crate::actor::messages::{
-    IndexerApplyObjectStatesMessage, IndexerDeleteAnyObjectStatesMessage, IndexerEventsMessage,
-    IndexerPersistOrUpdateAnyObjectStatesMessage, IndexerRevertMessage, IndexerStatesMessage,
-    IndexerTransactionMessage, QueryIndexerEventsMessage, QueryIndexerObjectIdsMessage,
-    QueryIndexerTransactionsMessage, QueryLastStateIndexByTxOrderMessage, UpdateIndexerMessage,
+    IndexerApplyObjectStatesMessage, IndexerBackupMessage, IndexerDeleteAnyObjectStatesMessage,
+    IndexerEventsMessage, IndexerPersistOrUpdateAnyObjectStatesMessage, IndexerRevertMessage,
+    IndexerStatesMessage, IndexerTransactionMessage, QueryIndexerEventsMessage,
+    QueryIndexerGenerationMessage, QueryIndexerObjectIdsByCustomIndexMessage,
+    QueryIndexerObjectIdsMessage, QueryIndexerStatusMessage, QueryIndexerTransactionsMessage,
+    QueryLastStateIndexByTxOrderMessage, RenewIndexerGenerationMessage, UpdateIndexerMessage,
 };
 use crate::actor::reader_indexer::IndexerReaderActor;
 use anyhow::{Ok, Result};
@@ -16,14 +18,18 @@ use moveos_types::moveos_std::object::{ObjectID, ObjectMeta};
 use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::state::{StateChangeSet, StateChangeSetExt};
 use moveos_types::transaction::{MoveAction, TransactionExecutionInfo, VerifiedMoveOSTransaction};
+use rooch_types::indexer::custom_index::{CustomIndexFilter, CustomIndexID};
 use rooch_types::indexer::event::{EventFilter, IndexerEvent, IndexerEventID};
 use rooch_types::indexer::state::{
     IndexerObjectState, IndexerObjectStateChangeSet, IndexerStateID, ObjectStateFilter,
     ObjectStateType,
 };
+use rooch_types::indexer::status::IndexerStatus;
 use rooch_types::indexer::transaction::{IndexerTransaction, TransactionFilter};
 use rooch_types::transaction::LedgerTransaction;
 use std::collections::HashMap;
+use std::path::PathBuf;
+use tracing::Span;
 
 #[derive(Clone)]
 pub struct IndexerProxy {
@@ -54,11 +60,16 @@ impl IndexerProxy {
                 moveos_tx,
                 events,
                 state_change_set,
+                span: Span::current(),
             })
             .await?;
         Ok(())
     }
 
+    pub async fn backup(&self, backup_dir: PathBuf) -> Result<()> {
+        self.actor.send(IndexerBackupMessage { backup_dir }).await?
+    }
+
     pub async fn indexer_states(
         &self,
         root: ObjectMeta,
@@ -164,6 +175,24 @@ impl IndexerProxy {
             .await?
     }
 
+    pub async fn query_object_ids_by_custom_index(
+        &self,
+        filter: CustomIndexFilter,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<CustomIndexID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<(ObjectID, CustomIndexID)>> {
+        self.reader_actor
+            .send(QueryIndexerObjectIdsByCustomIndexMessage {
+                filter,
+                cursor,
+                limit,
+                descending_order,
+            })
+            .await?
+    }
+
     pub async fn persist_or_update_object_states(
         &self,
         states: Vec<IndexerObjectState>,
@@ -211,6 +240,21 @@ impl IndexerProxy {
             .await?
     }
 
+    pub async fn query_generation(&self) -> Result<u64> {
+        self.reader_actor
+            .send(QueryIndexerGenerationMessage {})
+            .await?
+    }
+
+    pub async fn renew_generation(&self) -> Result<u64> {
+        self.actor.send(RenewIndexerGenerationMessage {}).await?
+    }
+
+    /// The status of the indexer writer, including the progress of the catch-up
+    pub async fn query_status(&self) -> Result<IndexerStatus> {
+        self.actor.send(QueryIndexerStatusMessage {}).await?
+    }
+
     pub async fn revert_indexer(
         &self,
         revert_tx_order: u64,
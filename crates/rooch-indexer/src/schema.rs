@@ -3,6 +3,24 @@
 
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    custom_index_configs (object_type, field_path) {
+        object_type -> Text,
+        field_path -> Text,
+    }
+}
+
+diesel::table! {
+    custom_indexes (object_id, field_path) {
+        object_id -> Text,
+        field_path -> Text,
+        object_type -> Text,
+        field_type -> Text,
+        value -> Text,
+        tx_order -> BigInt,
+    }
+}
+
 diesel::table! {
     events (event_index, tx_order) {
         event_handle_id -> Text,
@@ -39,6 +57,13 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    indexer_generation (id) {
+        id -> Integer,
+        generation -> BigInt,
+    }
+}
+
 diesel::table! {
     inscriptions (id) {
         id -> Text,
@@ -50,6 +75,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    runes (id) {
+        id -> Text,
+        owner -> Text,
+        tx_order -> BigInt,
+        state_index -> BigInt,
+        created_at -> BigInt,
+        updated_at -> BigInt,
+    }
+}
+
 diesel::table! {
     transactions (tx_order) {
         tx_order -> BigInt,
@@ -65,9 +101,13 @@ diesel::table! {
 }
 
 diesel::allow_tables_to_appear_in_same_query!(
+    custom_index_configs,
+    custom_indexes,
     events,
+    indexer_generation,
     object_states,
     utxos,
     inscriptions,
+    runes,
     transactions,
 );
@@ -4,24 +4,35 @@
 use crate::errors::{Context, IndexerError};
 use anyhow::Result;
 use diesel::QueryDsl;
-use diesel::{ExpressionMethods, RunQueryDsl};
+use diesel::{Connection, ExpressionMethods, OptionalExtension, RunQueryDsl};
 use function_name::named;
+use rooch_types::indexer::custom_index::IndexerCustomIndex;
 use rooch_types::indexer::event::IndexerEvent;
 use rooch_types::indexer::state::IndexerObjectState;
 use rooch_types::indexer::transaction::IndexerTransaction;
+use std::ops::DerefMut;
 use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
 use tracing::log;
 
+use crate::models::custom_indexes::{StoredCustomIndex, StoredCustomIndexConfig};
 use crate::models::events::StoredEvent;
 use crate::models::inscriptions::StoredInscription;
+use crate::models::runes::StoredRune;
 use crate::models::states::StoredObjectState;
 use crate::models::transactions::{escape_transaction, StoredTransaction};
 use crate::models::utxos::StoredUTXO;
-use crate::schema::{events, inscriptions, object_states, transactions, utxos};
+use crate::schema::{
+    custom_index_configs, custom_indexes, events, inscriptions, object_states, runes, transactions,
+    utxos,
+};
 use crate::store::metrics::IndexerDBMetrics;
 use crate::utils::escape_sql_string;
 use crate::{get_sqlite_pool_connection, SqliteConnectionPool};
 
+/// The id of the only row of the indexer generation table.
+const INDEXER_GENERATION_ROW_ID: i32 = 0;
+
 #[derive(Clone)]
 pub struct SqliteIndexerStore {
     pub(crate) connection_pool: SqliteConnectionPool,
@@ -242,6 +253,66 @@ impl SqliteIndexerStore {
         Ok(())
     }
 
+    #[named]
+    pub fn persist_or_update_object_state_runes(
+        &self,
+        states: Vec<IndexerObjectState>,
+    ) -> Result<(), IndexerError> {
+        if states.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+        let states = states.into_iter().map(StoredRune::from).collect::<Vec<_>>();
+
+        // Diesel for SQLite don't support batch update yet, so implements batch update directly via raw SQL
+        let values_clause = states
+            .into_iter()
+            .map(|state| {
+                format!(
+                    "('{}', '{}', {}, {}, {}, {})",
+                    escape_sql_string(state.id),
+                    escape_sql_string(state.owner),
+                    state.tx_order,
+                    state.state_index,
+                    state.created_at,
+                    state.updated_at,
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let query = format!(
+            "
+                INSERT INTO runes (id, owner, tx_order, state_index, created_at, updated_at) \
+                VALUES {} \
+                ON CONFLICT (id) DO UPDATE SET \
+                owner = excluded.owner, \
+                tx_order = excluded.tx_order, \
+                state_index = excluded.state_index, \
+                updated_at = excluded.updated_at
+            ",
+            values_clause
+        );
+
+        // Execute the raw SQL query
+        diesel::sql_query(query.clone())
+            .execute(&mut connection)
+            .map_err(|e| {
+                log::error!("Upsert object state runes Executing Query error: {}", query);
+                IndexerError::SQLiteWriteError(e.to_string())
+            })
+            .context("Failed to write or update object state runes to SQLiteDB")?;
+
+        Ok(())
+    }
+
     #[named]
     pub fn delete_object_states(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
         if state_pks.is_empty() {
@@ -314,6 +385,29 @@ impl SqliteIndexerStore {
         Ok(())
     }
 
+    #[named]
+    pub fn delete_object_state_runes(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
+        if state_pks.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+
+        diesel::delete(runes::table.filter(runes::id.eq_any(state_pks.as_slice())))
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
+            .context("Failed to delete object state runes to SQLiteDB")?;
+
+        Ok(())
+    }
+
     #[named]
     pub fn persist_transactions(
         &self,
@@ -422,4 +516,201 @@ impl SqliteIndexerStore {
 
         Ok(())
     }
+
+    #[named]
+    pub fn persist_or_update_custom_indexes(
+        &self,
+        indexes: Vec<IndexerCustomIndex>,
+    ) -> Result<(), IndexerError> {
+        if indexes.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+        let indexes = indexes
+            .into_iter()
+            .map(StoredCustomIndex::from)
+            .collect::<Vec<_>>();
+
+        // Diesel for SQLite don't support batch update yet, so implements batch update directly via raw SQL
+        let values_clause = indexes
+            .into_iter()
+            .map(|index| {
+                format!(
+                    "('{}', '{}', '{}', '{}', '{}', {})",
+                    escape_sql_string(index.object_id),
+                    escape_sql_string(index.field_path),
+                    escape_sql_string(index.object_type),
+                    escape_sql_string(index.field_type),
+                    escape_sql_string(index.value),
+                    index.tx_order,
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let query = format!(
+            "
+                INSERT INTO custom_indexes (object_id, field_path, object_type, field_type, value, tx_order) \
+                VALUES {} \
+                ON CONFLICT (object_id, field_path) DO UPDATE SET \
+                object_type = excluded.object_type, \
+                field_type = excluded.field_type, \
+                value = excluded.value, \
+                tx_order = excluded.tx_order
+            ",
+            values_clause
+        );
+
+        // Execute the raw SQL query
+        diesel::sql_query(query.clone())
+            .execute(&mut connection)
+            .map_err(|e| {
+                log::error!("Upsert custom indexes Executing Query error: {}", query);
+                IndexerError::SQLiteWriteError(e.to_string())
+            })
+            .context("Failed to write or update custom indexes to SQLiteDB")?;
+
+        Ok(())
+    }
+
+    #[named]
+    pub fn delete_custom_indexes(&self, object_ids: Vec<String>) -> Result<(), IndexerError> {
+        if object_ids.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+
+        diesel::delete(
+            custom_indexes::table.filter(custom_indexes::object_id.eq_any(object_ids.as_slice())),
+        )
+        .execute(&mut connection)
+        .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
+        .context("Failed to delete custom indexes to SQLiteDB")?;
+
+        Ok(())
+    }
+
+    #[named]
+    pub fn persist_custom_index_configs(
+        &self,
+        configs: Vec<StoredCustomIndexConfig>,
+    ) -> Result<(), IndexerError> {
+        if configs.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+
+        diesel::insert_or_ignore_into(custom_index_configs::table)
+            .values(configs.as_slice())
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
+            .context("Failed to write custom index configs to SQLiteDB")?;
+
+        Ok(())
+    }
+
+    /// Delete the custom index config and all the entries of the custom index in one transaction.
+    #[named]
+    pub fn delete_custom_index_config(
+        &self,
+        config: StoredCustomIndexConfig,
+    ) -> Result<(), IndexerError> {
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+
+        connection
+            .deref_mut()
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                diesel::delete(
+                    custom_indexes::table
+                        .filter(custom_indexes::object_type.eq(config.object_type.as_str()))
+                        .filter(custom_indexes::field_path.eq(config.field_path.as_str())),
+                )
+                .execute(conn)?;
+                diesel::delete(
+                    custom_index_configs::table
+                        .filter(custom_index_configs::object_type.eq(config.object_type.as_str()))
+                        .filter(custom_index_configs::field_path.eq(config.field_path.as_str())),
+                )
+                .execute(conn)?;
+                Ok(())
+            })
+            .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
+            .context("Failed to delete custom index config to SQLiteDB")?;
+
+        Ok(())
+    }
+
+    /// Query the indexer generation, returns 0 if the generation is not initialized.
+    pub fn query_generation(&self) -> Result<u64, IndexerError> {
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+        let generation = indexer_generation::table
+            .select(indexer_generation::generation)
+            .filter(indexer_generation::id.eq(INDEXER_GENERATION_ROW_ID))
+            .first::<i64>(&mut connection)
+            .optional()
+            .map_err(|e| IndexerError::SQLiteReadError(e.to_string()))?;
+        Ok(generation.unwrap_or_default() as u64)
+    }
+
+    /// Renew the indexer generation to the current timestamp in milliseconds,
+    /// it is always greater than the previous generation.
+    /// The generation is read and written in one immediate transaction, so the concurrent renewals are serialized.
+    pub fn renew_generation(&self) -> Result<u64, IndexerError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| IndexerError::GenericError(e.to_string()))?
+            .as_millis() as i64;
+        let mut connection = get_sqlite_pool_connection(&self.connection_pool)?;
+        let generation = connection
+            .deref_mut()
+            .immediate_transaction::<_, diesel::result::Error, _>(|conn| {
+                let previous = indexer_generation::table
+                    .select(indexer_generation::generation)
+                    .filter(indexer_generation::id.eq(INDEXER_GENERATION_ROW_ID))
+                    .first::<i64>(conn)
+                    .optional()?
+                    .unwrap_or_default();
+                let generation = std::cmp::max(now, previous + 1);
+                diesel::replace_into(indexer_generation::table)
+                    .values((
+                        indexer_generation::id.eq(INDEXER_GENERATION_ROW_ID),
+                        indexer_generation::generation.eq(generation),
+                    ))
+                    .execute(conn)?;
+                Ok(generation)
+            })
+            .map_err(|e| IndexerError::SQLiteWriteError(e.to_string()))
+            .context("Failed to renew the indexer generation in SQLiteDB")?;
+        Ok(generation as u64)
+    }
 }
@@ -2,6 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::errors::IndexerError;
+use rooch_types::indexer::custom_index::{
+    CustomIndexConfig, IndexerCustomIndex, IndexerCustomIndexChangeSet,
+};
 use rooch_types::indexer::event::IndexerEvent;
 use rooch_types::indexer::state::{IndexerObjectState, IndexerObjectStateChangeSet};
 use rooch_types::indexer::transaction::IndexerTransaction;
@@ -33,6 +36,13 @@ pub trait IndexerStoreTrait: Send + Sync {
 
     fn delete_object_state_inscriptions(&self, state_pks: Vec<String>) -> Result<(), IndexerError>;
 
+    fn persist_or_update_object_state_runes(
+        &self,
+        states: Vec<IndexerObjectState>,
+    ) -> Result<(), IndexerError>;
+
+    fn delete_object_state_runes(&self, state_pks: Vec<String>) -> Result<(), IndexerError>;
+
     fn persist_transactions(
         &self,
         transactions: Vec<IndexerTransaction>,
@@ -43,4 +53,25 @@ pub trait IndexerStoreTrait: Send + Sync {
     fn delete_transactions(&self, tx_orders: Vec<u64>) -> anyhow::Result<(), IndexerError>;
 
     fn delete_events(&self, tx_orders: Vec<u64>) -> anyhow::Result<(), IndexerError>;
+
+    fn apply_custom_indexes(
+        &self,
+        custom_index_change_set: IndexerCustomIndexChangeSet,
+    ) -> Result<(), IndexerError>;
+
+    fn persist_or_update_custom_indexes(
+        &self,
+        indexes: Vec<IndexerCustomIndex>,
+    ) -> Result<(), IndexerError>;
+
+    fn delete_custom_indexes(&self, object_ids: Vec<String>) -> Result<(), IndexerError>;
+
+    /// Record the custom indexes which are backfilled.
+    fn persist_custom_index_configs(
+        &self,
+        configs: Vec<CustomIndexConfig>,
+    ) -> Result<(), IndexerError>;
+
+    /// Delete the custom index and its entries after it is removed from the config.
+    fn delete_custom_index_config(&self, config: CustomIndexConfig) -> Result<(), IndexerError>;
 }
@@ -1,5 +1,6 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+mod test_catch_up;
 mod test_concurrence;
 mod test_indexer;
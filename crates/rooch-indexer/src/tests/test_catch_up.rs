@@ -0,0 +1,193 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::actor::indexer::IndexerActor;
+use crate::actor::messages::QueryIndexerStatusMessage;
+use crate::indexer_reader::IndexerReader;
+use crate::IndexerStore;
+use anyhow::Result;
+use coerce::actor::{system::ActorSystem, ActorRef, IntoActor};
+use metrics::RegistryService;
+use move_core_types::language_storage::StructTag;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_store::transaction_store::TransactionStore as TxExecutionInfoStore;
+use moveos_store::MoveOSStore;
+use moveos_types::h256::{ACCUMULATOR_PLACEHOLDER_HASH, H256};
+use moveos_types::moveos_std::event::TransactionEvent;
+use moveos_types::moveos_std::object::ObjectMeta;
+use moveos_types::state::{StateChangeSet, StateChangeSetExt};
+use moveos_types::transaction::TransactionExecutionInfo;
+use rooch_config::store_config::DEFAULT_DB_INDEXER_SUBDIR;
+use rooch_store::state_store::StateStore;
+use rooch_store::transaction_store::TransactionStore;
+use rooch_store::RoochStore;
+use rooch_types::indexer::event::EventFilter;
+use rooch_types::indexer::status::{IndexerStatus, IndexerSyncStatus};
+use rooch_types::indexer::transaction::TransactionFilter;
+use rooch_types::sequencer::SequencerInfo;
+use rooch_types::test_utils::{random_accumulator_info, random_ledger_transaction};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Save a sequenced and executed transaction to the stores, the event ids are recorded only if
+/// `events` is not empty, like the transactions executed before the event ids were recorded.
+fn save_executed_tx(
+    moveos_store: &MoveOSStore,
+    rooch_store: &RoochStore,
+    tx_order: u64,
+    events: Vec<TransactionEvent>,
+    event_root: H256,
+) -> Result<()> {
+    let mut ledger_transaction = random_ledger_transaction();
+    ledger_transaction.sequence_info.tx_order = tx_order;
+    let tx_hash = ledger_transaction.tx_hash();
+    rooch_store.save_transaction(ledger_transaction)?;
+    moveos_store.save_tx_execution_info(TransactionExecutionInfo::new(
+        tx_hash,
+        H256::random(),
+        0,
+        event_root,
+        0,
+        KeptVMStatus::Executed,
+    ))?;
+    if !events.is_empty() {
+        let event_ids = moveos_store.get_event_store().save_events(events)?;
+        moveos_store
+            .get_event_store()
+            .save_tx_event_ids(tx_hash, event_ids)?;
+    }
+    rooch_store.save_state_change_set(
+        tx_order,
+        StateChangeSetExt::new(StateChangeSet::new(H256::random(), 0), 0),
+    )?;
+    Ok(())
+}
+
+async fn start_indexer_with_catch_up(
+    moveos_store: MoveOSStore,
+    rooch_store: RoochStore,
+    last_order: u64,
+) -> Result<(
+    ActorRef<IndexerActor>,
+    IndexerReader,
+    moveos_config::DataDirPath,
+)> {
+    rooch_store
+        .get_meta_store()
+        .save_sequencer_info_ignore_check(SequencerInfo::new(
+            last_order,
+            random_accumulator_info(),
+        ))?;
+    let registry_service = RegistryService::default();
+    let registry = registry_service.default_registry();
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(DEFAULT_DB_INDEXER_SUBDIR);
+    let indexer_store = IndexerStore::new(indexer_db.clone(), &registry)?;
+    let indexer_reader = IndexerReader::new(indexer_db, &registry)?;
+    let actor = IndexerActor::new(ObjectMeta::genesis_root(), indexer_store, &registry)?
+        .with_catch_up(indexer_reader.clone(), moveos_store, rooch_store, &registry)
+        .into_actor(None, &ActorSystem::global_system())
+        .await?;
+    Ok((actor, indexer_reader, tmpdir))
+}
+
+/// Wait until the catch-up leaves the catching up status or indexes the `tx_order`.
+async fn wait_catch_up(actor: &ActorRef<IndexerActor>, tx_order: u64) -> Result<IndexerStatus> {
+    for _ in 0..50 {
+        let status = actor.send(QueryIndexerStatusMessage {}).await??;
+        if !status.is_catching_up() || status.last_indexed_tx_order == Some(tx_order) {
+            return Ok(status);
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    actor.send(QueryIndexerStatusMessage {}).await?
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_catch_up_over_gap() -> Result<()> {
+    let (moveos_store, _moveos_dir) = MoveOSStore::mock_moveos_store()?;
+    let (rooch_store, _rooch_dir) = RoochStore::mock_rooch_store()?;
+    let event_type = StructTag::from_str("0x42::test::TestEvent")?;
+
+    save_executed_tx(
+        &moveos_store,
+        &rooch_store,
+        0,
+        vec![TransactionEvent::new(event_type.clone(), vec![0], 0)],
+        H256::random(),
+    )?;
+    // The transaction without events has no event ids recorded
+    save_executed_tx(
+        &moveos_store,
+        &rooch_store,
+        1,
+        vec![],
+        *ACCUMULATOR_PLACEHOLDER_HASH,
+    )?;
+    // The tx order 2 is not persisted yet
+    save_executed_tx(
+        &moveos_store,
+        &rooch_store,
+        3,
+        vec![TransactionEvent::new(event_type.clone(), vec![3], 0)],
+        H256::random(),
+    )?;
+
+    let (actor, indexer_reader, _indexer_dir) =
+        start_indexer_with_catch_up(moveos_store.clone(), rooch_store.clone(), 3).await?;
+    // The catch-up stops before the gap and waits for the missing transaction
+    let status = wait_catch_up(&actor, 1).await?;
+    assert!(status.is_catching_up());
+    assert_eq!(status.last_indexed_tx_order, Some(1));
+    assert_eq!(indexer_reader.query_last_indexed_tx_order()?, Some(1));
+
+    save_executed_tx(
+        &moveos_store,
+        &rooch_store,
+        2,
+        vec![TransactionEvent::new(event_type.clone(), vec![2], 0)],
+        H256::random(),
+    )?;
+    let status = wait_catch_up(&actor, 3).await?;
+    assert!(matches!(status.sync_status, IndexerSyncStatus::Synced));
+    assert_eq!(status.last_indexed_tx_order, Some(3));
+
+    let transactions = indexer_reader.query_transactions_with_filter(
+        TransactionFilter::TxOrderRange {
+            from_order: 0,
+            to_order: 4,
+        },
+        None,
+        10,
+        false,
+    )?;
+    assert_eq!(transactions.len(), 4);
+    let events = indexer_reader.query_events_with_filter(
+        EventFilter::EventType(event_type),
+        None,
+        10,
+        false,
+    )?;
+    assert_eq!(events.len(), 3);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_catch_up_fail_on_unrecorded_events() -> Result<()> {
+    let (moveos_store, _moveos_dir) = MoveOSStore::mock_moveos_store()?;
+    let (rooch_store, _rooch_dir) = RoochStore::mock_rooch_store()?;
+
+    // The transaction emitted events, but the event ids are not recorded
+    save_executed_tx(&moveos_store, &rooch_store, 0, vec![], H256::random())?;
+
+    let (actor, indexer_reader, _indexer_dir) =
+        start_indexer_with_catch_up(moveos_store, rooch_store, 0).await?;
+    let status = wait_catch_up(&actor, 0).await?;
+    assert!(status.is_failed());
+    assert_eq!(status.last_indexed_tx_order, None);
+    assert!(status
+        .error
+        .is_some_and(|error| error.contains("are not recorded")));
+    assert_eq!(indexer_reader.query_last_indexed_tx_order()?, None);
+    Ok(())
+}
@@ -7,6 +7,7 @@ use crate::IndexerStore;
 use anyhow::Result;
 use metrics::RegistryService;
 use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::StructTag;
 use move_core_types::vm_status::KeptVMStatus;
 use moveos_types::h256::H256;
 use moveos_types::moveos_std::object::{ObjectEntity, ObjectID, ObjectMeta};
@@ -17,6 +18,10 @@ use rand::random;
 use rooch_config::store_config::DEFAULT_DB_INDEXER_SUBDIR;
 use rooch_types::framework::coin_store::CoinStore;
 use rooch_types::framework::gas_coin::RGas;
+use rooch_types::indexer::custom_index::{
+    CustomIndexConfig, CustomIndexFieldType, CustomIndexFilter, IndexerCustomIndex,
+    IndexerCustomIndexChangeSet,
+};
 use rooch_types::indexer::event::{EventFilter, IndexerEvent};
 use rooch_types::indexer::state::{IndexerObjectState, ObjectStateFilter, ObjectStateType};
 use rooch_types::indexer::transaction::{IndexerTransaction, TransactionFilter};
@@ -24,6 +29,7 @@ use rooch_types::test_utils::{
     random_event, random_ledger_transaction, random_new_object_states, random_remove_object_states,
     random_update_object_states, random_verified_move_action,
 };
+use std::str::FromStr;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_transaction_store() -> Result<()> {
@@ -256,3 +262,167 @@ async fn test_escape_transaction() -> Result<()> {
     assert_eq!(query_transactions.len(), 1);
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_indexer_generation() -> Result<()> {
+    let registry_service = RegistryService::default();
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(DEFAULT_DB_INDEXER_SUBDIR);
+    let indexer_store =
+        IndexerStore::new(indexer_db.clone(), &registry_service.default_registry())?;
+    let indexer_reader = IndexerReader::new(indexer_db, &registry_service.default_registry())?;
+
+    let generation = indexer_reader.query_generation()?;
+    assert!(generation > 0);
+    assert_eq!(indexer_store.generation()?, generation);
+
+    let new_generation = indexer_store.renew_generation()?;
+    assert!(new_generation > generation);
+    assert_eq!(indexer_reader.query_generation()?, new_generation);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_custom_index_store() -> Result<()> {
+    let registry_service = RegistryService::default();
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(DEFAULT_DB_INDEXER_SUBDIR);
+    let indexer_store =
+        IndexerStore::new(indexer_db.clone(), &registry_service.default_registry())?;
+    let indexer_reader = IndexerReader::new(indexer_db, &registry_service.default_registry())?;
+
+    let object_type = StructTag::from_str("0x42::market::Listing")?;
+    let object_ids = (0..3).map(|_| ObjectID::random()).collect::<Vec<_>>();
+    let new_indexes = object_ids
+        .iter()
+        .zip([100u64, 9, 20])
+        .map(|(object_id, price)| IndexerCustomIndex {
+            object_id: object_id.clone(),
+            object_type: object_type.clone(),
+            field_path: "price".to_string(),
+            field_type: CustomIndexFieldType::Integer,
+            value: CustomIndexFieldType::Integer
+                .encode_bound(&price.to_string())
+                .unwrap(),
+            tx_order: 1,
+        })
+        .collect();
+    indexer_store.apply_custom_indexes(IndexerCustomIndexChangeSet {
+        new_indexes,
+        remove_object_ids: vec![],
+    })?;
+
+    // The integer values are ordered numerically, and the range is [from, to)
+    let filter = CustomIndexFilter::new(
+        object_type.clone(),
+        "price".to_string(),
+        Some("9".to_string()),
+        Some("100".to_string()),
+    );
+    let result = indexer_reader.query_object_ids_by_custom_index(filter.clone(), None, 1, false)?;
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].0, object_ids[1]);
+    let cursor = result[0].1.clone();
+    let result =
+        indexer_reader.query_object_ids_by_custom_index(filter, Some(cursor), 10, false)?;
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].0, object_ids[2]);
+
+    // The entries of the removed objects are deleted
+    indexer_store.apply_custom_indexes(IndexerCustomIndexChangeSet {
+        new_indexes: vec![],
+        remove_object_ids: vec![object_ids[0].clone()],
+    })?;
+    let filter = CustomIndexFilter::new(object_type.clone(), "price".to_string(), None, None);
+    let result = indexer_reader.query_object_ids_by_custom_index(filter, None, 10, true)?;
+    assert_eq!(
+        result.into_iter().map(|(id, _)| id).collect::<Vec<_>>(),
+        vec![object_ids[2].clone(), object_ids[1].clone()]
+    );
+
+    // The invalid bound of the integer field is rejected
+    let filter = CustomIndexFilter::new(
+        object_type.clone(),
+        "price".to_string(),
+        Some("abc".to_string()),
+        None,
+    );
+    assert!(indexer_reader
+        .query_object_ids_by_custom_index(filter, None, 10, false)
+        .is_err());
+
+    // The digits bound of the string field is compared as the string
+    indexer_store.apply_custom_indexes(IndexerCustomIndexChangeSet {
+        new_indexes: vec![IndexerCustomIndex {
+            object_id: object_ids[0].clone(),
+            object_type: object_type.clone(),
+            field_path: "name".to_string(),
+            field_type: CustomIndexFieldType::String,
+            value: "9".to_string(),
+            tx_order: 2,
+        }],
+        remove_object_ids: vec![],
+    })?;
+    let filter = CustomIndexFilter::new(
+        object_type.clone(),
+        "name".to_string(),
+        Some("10".to_string()),
+        None,
+    );
+    let result = indexer_reader.query_object_ids_by_custom_index(filter, None, 10, false)?;
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].0, object_ids[0]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_custom_index_configs() -> Result<()> {
+    let registry_service = RegistryService::default();
+    let tmpdir = moveos_config::temp_dir();
+    let indexer_db = tmpdir.path().join(DEFAULT_DB_INDEXER_SUBDIR);
+    let indexer_store =
+        IndexerStore::new(indexer_db.clone(), &registry_service.default_registry())?;
+    let indexer_reader = IndexerReader::new(indexer_db, &registry_service.default_registry())?;
+
+    let price_config = CustomIndexConfig::from_str("0x42::market::Listing.price")?;
+    let owner_config = CustomIndexConfig::from_str("0x42::market::Listing.meta.owner")?;
+    indexer_store.persist_custom_index_configs(vec![price_config.clone()])?;
+    indexer_store.persist_custom_index_configs(vec![price_config.clone(), owner_config.clone()])?;
+    let mut configs = indexer_reader.query_custom_index_configs()?;
+    configs.sort_by_key(|config| config.to_string());
+    assert_eq!(configs, vec![owner_config.clone(), price_config.clone()]);
+
+    let index = |config: &CustomIndexConfig| IndexerCustomIndex {
+        object_id: ObjectID::random(),
+        object_type: config.object_type.clone(),
+        field_path: config.field_path_string(),
+        field_type: CustomIndexFieldType::Integer,
+        value: CustomIndexFieldType::Integer.encode_bound("1").unwrap(),
+        tx_order: 1,
+    };
+    indexer_store
+        .persist_or_update_custom_indexes(vec![index(&price_config), index(&owner_config)])?;
+
+    // The entries of the deleted custom index are removed with the config
+    indexer_store.delete_custom_index_config(price_config.clone())?;
+    assert_eq!(
+        indexer_reader.query_custom_index_configs()?,
+        vec![owner_config.clone()]
+    );
+    let query = |config: &CustomIndexConfig| {
+        indexer_reader.query_object_ids_by_custom_index(
+            CustomIndexFilter::new(
+                config.object_type.clone(),
+                config.field_path_string(),
+                None,
+                None,
+            ),
+            None,
+            10,
+            false,
+        )
+    };
+    assert!(query(&price_config)?.is_empty());
+    assert_eq!(query(&owner_config)?.len(), 1);
+    Ok(())
+}
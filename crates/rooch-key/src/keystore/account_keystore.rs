@@ -174,6 +174,17 @@ pub trait AccountKeystore {
         session_key: SessionKey,
     ) -> Result<(), anyhow::Error>;
 
+    /// Get the authentication keys of the local session keys of the address
+    fn get_session_key_authentication_keys(&self, address: &RoochAddress)
+        -> Vec<AuthenticationKey>;
+
+    /// Remove the local session key, it does nothing if the session key does not exist
+    fn remove_session_key(
+        &mut self,
+        address: &RoochAddress,
+        authentication_key: &AuthenticationKey,
+    ) -> Result<(), anyhow::Error>;
+
     fn sign_transaction_via_session_key(
         &self,
         address: &RoochAddress,
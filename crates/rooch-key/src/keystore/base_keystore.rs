@@ -206,6 +206,30 @@ impl AccountKeystore for BaseKeyStore {
         Ok(())
     }
 
+    fn get_session_key_authentication_keys(
+        &self,
+        address: &RoochAddress,
+    ) -> Vec<AuthenticationKey> {
+        self.session_keys
+            .get(address)
+            .map(|inner_map| inner_map.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    fn remove_session_key(
+        &mut self,
+        address: &RoochAddress,
+        authentication_key: &AuthenticationKey,
+    ) -> Result<(), anyhow::Error> {
+        if let Some(inner_map) = self.session_keys.get_mut(address) {
+            inner_map.remove(authentication_key);
+            if inner_map.is_empty() {
+                self.session_keys.remove(address);
+            }
+        }
+        Ok(())
+    }
+
     fn sign_transaction_via_session_key(
         &self,
         address: &RoochAddress,
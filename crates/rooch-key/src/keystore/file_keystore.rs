@@ -140,6 +140,24 @@ impl AccountKeystore for FileBasedKeystore {
         Ok(())
     }
 
+    fn get_session_key_authentication_keys(
+        &self,
+        address: &RoochAddress,
+    ) -> Vec<AuthenticationKey> {
+        self.keystore.get_session_key_authentication_keys(address)
+    }
+
+    fn remove_session_key(
+        &mut self,
+        address: &RoochAddress,
+        authentication_key: &AuthenticationKey,
+    ) -> Result<(), anyhow::Error> {
+        self.keystore
+            .remove_session_key(address, authentication_key)?;
+        self.save()?;
+        Ok(())
+    }
+
     fn sign_transaction_via_session_key(
         &self,
         address: &RoochAddress,
@@ -221,6 +221,35 @@ impl AccountKeystore for Keystore {
         }
     }
 
+    fn get_session_key_authentication_keys(
+        &self,
+        address: &RoochAddress,
+    ) -> Vec<AuthenticationKey> {
+        match self {
+            Keystore::File(file_keystore) => {
+                file_keystore.get_session_key_authentication_keys(address)
+            }
+            Keystore::InMem(inmem_keystore) => {
+                inmem_keystore.get_session_key_authentication_keys(address)
+            }
+        }
+    }
+
+    fn remove_session_key(
+        &mut self,
+        address: &RoochAddress,
+        authentication_key: &AuthenticationKey,
+    ) -> Result<(), anyhow::Error> {
+        match self {
+            Keystore::File(file_keystore) => {
+                file_keystore.remove_session_key(address, authentication_key)
+            }
+            Keystore::InMem(inmem_keystore) => {
+                inmem_keystore.remove_session_key(address, authentication_key)
+            }
+        }
+    }
+
     fn addresses(&self) -> Vec<RoochAddress> {
         match self {
             Keystore::File(file_keystore) => file_keystore.addresses(),
@@ -6,10 +6,14 @@ use coerce::actor::message::Message;
 use rooch_types::transaction::{
     ExecuteTransactionResponse, L1BlockWithBody, L1Transaction, RoochTransaction,
 };
+use std::path::PathBuf;
+use tracing::Span;
 
 #[derive(Clone)]
 pub struct ExecuteL2TxMessage {
     pub tx: RoochTransaction,
+    /// The tracing span of the tx lifecycle
+    pub span: Span,
 }
 
 impl Message for ExecuteL2TxMessage {
@@ -19,6 +23,8 @@ impl Message for ExecuteL2TxMessage {
 #[derive(Clone)]
 pub struct ExecuteL1BlockMessage {
     pub tx: L1BlockWithBody,
+    /// The tracing span of the tx lifecycle
+    pub span: Span,
 }
 
 impl Message for ExecuteL1BlockMessage {
@@ -28,8 +34,20 @@ impl Message for ExecuteL1BlockMessage {
 #[derive(Clone)]
 pub struct ExecuteL1TxMessage {
     pub tx: L1Transaction,
+    /// The tracing span of the tx lifecycle
+    pub span: Span,
 }
 
 impl Message for ExecuteL1TxMessage {
     type Result = Result<ExecuteTransactionResponse>;
 }
+
+/// Create the store checkpoint of a backup between two transactions, so the checkpoint is consistent at a tx order
+#[derive(Clone)]
+pub struct BackupStoreMessage {
+    pub backup_dir: PathBuf,
+}
+
+impl Message for BackupStoreMessage {
+    type Result = Result<()>;
+}
@@ -1,7 +1,9 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use super::messages::{ExecuteL1BlockMessage, ExecuteL1TxMessage, ExecuteL2TxMessage};
+use super::messages::{
+    BackupStoreMessage, ExecuteL1BlockMessage, ExecuteL1TxMessage, ExecuteL2TxMessage,
+};
 use crate::metrics::PipelineProcessorMetrics;
 use anyhow::{Error, Result};
 use async_trait::async_trait;
@@ -24,8 +26,17 @@ use rooch_types::{
         LedgerTxData, RoochTransaction,
     },
 };
+use std::future::Future;
 use std::sync::Arc;
-use tracing::{debug, info};
+use tracing::{debug, info, info_span, Instrument, Span};
+
+/// The stages of the tx lifecycle, used as the span names and the metrics labels
+const STAGE_VALIDATE: &str = "validate";
+const STAGE_SEQUENCE: &str = "sequence";
+const STAGE_EXECUTE: &str = "execute";
+const STAGE_PROPOSE: &str = "propose";
+const STAGE_STATE_COMMIT: &str = "state_commit";
+const STAGE_INDEX: &str = "index";
 
 /// PipelineProcessor aggregates the executor, sequencer, proposer, and indexer to process transactions.
 pub struct PipelineProcessorActor {
@@ -62,6 +73,18 @@ impl PipelineProcessorActor {
         }
     }
 
+    /// Run a stage of the tx lifecycle in a child span of the current tx span, and observe the stage latency.
+    async fn run_stage<T>(&self, stage: &'static str, future: impl Future<Output = T>) -> T {
+        let _timer = self
+            .metrics
+            .pipeline_processor_tx_stage_latency_seconds
+            .with_label_values(&[stage])
+            .start_timer();
+        future
+            .instrument(info_span!("tx_stage", otel.name = stage))
+            .await
+    }
+
     pub async fn process_sequenced_tx_on_startup(&mut self) -> Result<()> {
         let last_order = self.sequencer.get_sequencer_order().await.unwrap_or(0);
         debug!("process_sequenced_tx_on_startup last_order: {}", last_order);
@@ -135,10 +158,18 @@ impl PipelineProcessorActor {
             .pipeline_processor_execution_tx_latency_seconds
             .with_label_values(&[fn_name])
             .start_timer();
-        let moveos_tx = self.executor.validate_l1_block(l1_block.clone()).await?;
+        let moveos_tx = self
+            .run_stage(
+                STAGE_VALIDATE,
+                self.executor.validate_l1_block(l1_block.clone()),
+            )
+            .await?;
         let ledger_tx = self
-            .sequencer
-            .sequence_transaction(LedgerTxData::L1Block(l1_block.block))
+            .run_stage(
+                STAGE_SEQUENCE,
+                self.sequencer
+                    .sequence_transaction(LedgerTxData::L1Block(l1_block.block)),
+            )
             .await?;
         let size = moveos_tx.ctx.tx_size;
         let result = self.execute_tx(ledger_tx, moveos_tx).await?;
@@ -165,10 +196,15 @@ impl PipelineProcessorActor {
             .pipeline_processor_execution_tx_latency_seconds
             .with_label_values(&[fn_name])
             .start_timer();
-        let moveos_tx = self.executor.validate_l1_tx(l1_tx.clone()).await?;
+        let moveos_tx = self
+            .run_stage(STAGE_VALIDATE, self.executor.validate_l1_tx(l1_tx.clone()))
+            .await?;
         let ledger_tx = self
-            .sequencer
-            .sequence_transaction(LedgerTxData::L1Tx(l1_tx.clone()))
+            .run_stage(
+                STAGE_SEQUENCE,
+                self.sequencer
+                    .sequence_transaction(LedgerTxData::L1Tx(l1_tx.clone())),
+            )
             .await?;
         let size = moveos_tx.ctx.tx_size;
         let result = match self.execute_tx(ledger_tx, moveos_tx).await {
@@ -216,17 +252,22 @@ impl PipelineProcessorActor {
             .pipeline_processor_execution_tx_latency_seconds
             .with_label_values(&[fn_name])
             .start_timer();
-        let moveos_tx = self.executor.validate_l2_tx(tx.clone()).await?;
+        let moveos_tx = self
+            .run_stage(STAGE_VALIDATE, self.executor.validate_l2_tx(tx.clone()))
+            .await?;
         let ledger_tx = self
-            .sequencer
-            .sequence_transaction(LedgerTxData::L2Tx(tx.clone()))
+            .run_stage(
+                STAGE_SEQUENCE,
+                self.sequencer
+                    .sequence_transaction(LedgerTxData::L2Tx(tx.clone())),
+            )
             .await?;
         let size = moveos_tx.ctx.tx_size;
         let result = match self.execute_tx(ledger_tx, moveos_tx).await {
             Ok(v) => v,
             Err(err) => {
                 if is_vm_panic_error(&err) {
-                    let l2_tx_bcs_bytes = bcs::to_bytes(&tx)?;
+                    let l2_tx_bcs_bytes = tx.encode();
                     log::warn!(
                         "Execute L2 Tx failed while VM panic occurred and revert tx. error: {:?} tx info {}",
                         err, hex::encode(l2_tx_bcs_bytes)
@@ -253,7 +294,7 @@ impl PipelineProcessorActor {
     #[named]
     pub async fn execute_tx(
         &mut self,
-        tx: LedgerTransaction,
+        mut tx: LedgerTransaction,
         mut moveos_tx: VerifiedMoveOSTransaction,
     ) -> Result<ExecuteTransactionResponse> {
         let fn_name = function_name!();
@@ -262,26 +303,42 @@ impl PipelineProcessorActor {
             .pipeline_processor_execution_tx_latency_seconds
             .with_label_values(&[fn_name])
             .start_timer();
+        let span = Span::current();
+        if !span.is_disabled() {
+            span.record("tx_hash", tracing::field::display(tx.tx_hash()));
+            span.record("tx_order", tx.sequence_info.tx_order);
+        }
         // Add sequence info to tx context, let the Move contract can get the sequence info
         moveos_tx.ctx.add(tx.sequence_info.clone())?;
 
         // Then execute
         let size = moveos_tx.ctx.tx_size;
-        let (output, execution_info) = self.executor.execute_transaction(moveos_tx.clone()).await?;
-        self.proposer
-            .propose_transaction(tx.clone(), execution_info.clone())
+        let (output, execution_info) = self
+            .run_stage(
+                STAGE_EXECUTE,
+                self.executor.execute_transaction(moveos_tx.clone()),
+            )
             .await?;
+        self.run_stage(
+            STAGE_PROPOSE,
+            self.proposer
+                .propose_transaction(tx.clone(), execution_info.clone()),
+        )
+        .await?;
         let root = execution_info.root_metadata();
-        // Sync latest state root from writer executor to reader executor
-        self.executor
-            .refresh_state(root.clone(), output.is_upgrade)
-            .await?;
-        // Save state change set is a notify call, do not block current task
         let state_change_set_ext =
             StateChangeSetExt::new(output.changeset.clone(), moveos_tx.ctx.sequence_number);
-        self.executor
-            .save_state_change_set(tx.sequence_info.tx_order, state_change_set_ext)
-            .await?;
+        self.run_stage(STAGE_STATE_COMMIT, async {
+            // Sync latest state root from writer executor to reader executor
+            self.executor
+                .refresh_state(root.clone(), output.is_upgrade)
+                .await?;
+            // Save state change set is a notify call, do not block current task
+            self.executor
+                .save_state_change_set(tx.sequence_info.tx_order, state_change_set_ext)
+                .await
+        })
+        .await?;
 
         let indexer = self.indexer.clone();
         let sequence_info = tx.sequence_info.clone();
@@ -291,6 +348,7 @@ impl PipelineProcessorActor {
         // If bitcoin block data import, don't write all indexer
         if !self.service_status.is_date_import_mode() {
             //The update_indexer is a notify call, do not block current task
+            // The span is closed when the indexer finishes indexing the tx
             let result = indexer
                 .update_indexer(
                     tx,
@@ -299,6 +357,7 @@ impl PipelineProcessorActor {
                     output_clone.events,
                     output_clone.changeset,
                 )
+                .instrument(info_span!("tx_stage", otel.name = STAGE_INDEX))
                 .await;
             match result {
                 Ok(_) => {}
@@ -329,7 +388,8 @@ impl Handler<ExecuteL2TxMessage> for PipelineProcessorActor {
         msg: ExecuteL2TxMessage,
         _ctx: &mut ActorContext,
     ) -> Result<ExecuteTransactionResponse> {
-        self.execute_l2_tx(msg.tx).await
+        let span = tx_span(&msg.span);
+        self.execute_l2_tx(msg.tx).instrument(span).await
     }
 }
 
@@ -340,7 +400,8 @@ impl Handler<ExecuteL1BlockMessage> for PipelineProcessorActor {
         msg: ExecuteL1BlockMessage,
         _ctx: &mut ActorContext,
     ) -> Result<ExecuteTransactionResponse> {
-        self.execute_l1_block(msg.tx).await
+        let span = tx_span(&msg.span);
+        self.execute_l1_block(msg.tx).instrument(span).await
     }
 }
 
@@ -351,10 +412,32 @@ impl Handler<ExecuteL1TxMessage> for PipelineProcessorActor {
         msg: ExecuteL1TxMessage,
         _ctx: &mut ActorContext,
     ) -> Result<ExecuteTransactionResponse> {
-        self.execute_l1_tx(msg.tx).await
+        let span = tx_span(&msg.span);
+        self.execute_l1_tx(msg.tx).instrument(span).await
+    }
+}
+
+#[async_trait]
+impl Handler<BackupStoreMessage> for PipelineProcessorActor {
+    async fn handle(&mut self, msg: BackupStoreMessage, _ctx: &mut ActorContext) -> Result<()> {
+        // The actor handles the messages one by one, so no transaction is executing during the checkpoint.
+        // Only the checkpoint is created here, it is cheap, the slow parts of the backup are done outside the actor.
+        info!("Backup store to {:?}", msg.backup_dir);
+        self.rooch_db.backup_store(&msg.backup_dir)
     }
 }
 
+/// The span of the tx in the pipeline processor, it is a root span if the tx is not from the RPC.
+/// The tx hash and tx order are recorded after the tx is sequenced.
+fn tx_span(parent: &Span) -> Span {
+    info_span!(
+        parent: parent,
+        "pipeline_processor",
+        tx_hash = tracing::field::Empty,
+        tx_order = tracing::field::Empty
+    )
+}
+
 fn is_vm_panic_error(error: &Error) -> bool {
     if let Some(vm_error) = error.downcast_ref::<VMPanicError>() {
         match vm_error {
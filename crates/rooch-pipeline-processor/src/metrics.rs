@@ -11,6 +11,7 @@ use prometheus::{
 pub struct PipelineProcessorMetrics {
     pub pipeline_processor_execution_tx_latency_seconds: HistogramVec,
     pub pipeline_processor_execution_tx_bytes: HistogramVec,
+    pub pipeline_processor_tx_stage_latency_seconds: HistogramVec,
     pub pipeline_processor_l1_block_gas_used: IntCounter,
     pub pipeline_processor_l1_tx_gas_used: IntCounter,
     pub pipeline_processor_l2_tx_gas_used: IntCounter,
@@ -37,6 +38,14 @@ impl PipelineProcessorMetrics {
                 registry,
             )
             .unwrap(),
+            pipeline_processor_tx_stage_latency_seconds: register_histogram_vec_with_registry!(
+                "pipeline_processor_tx_stage_latency_seconds",
+                "Pipeline processor tx latency in seconds of each stage of the tx lifecycle",
+                &["stage"],
+                LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
             pipeline_processor_l1_block_gas_used: register_int_counter_with_registry!(
                 "pipeline_processor_l1_block_gas_used",
                 "Pipeline processor L1 block gas used total",
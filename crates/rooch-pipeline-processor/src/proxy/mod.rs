@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::actor::{
-    messages::{ExecuteL1BlockMessage, ExecuteL1TxMessage, ExecuteL2TxMessage},
+    messages::{BackupStoreMessage, ExecuteL1BlockMessage, ExecuteL1TxMessage, ExecuteL2TxMessage},
     processor::PipelineProcessorActor,
 };
 use anyhow::Result;
@@ -10,6 +10,8 @@ use coerce::actor::ActorRef;
 use rooch_types::transaction::{
     rooch::RoochTransaction, ExecuteTransactionResponse, L1BlockWithBody, L1Transaction,
 };
+use std::path::PathBuf;
+use tracing::Span;
 
 #[derive(Clone)]
 pub struct PipelineProcessorProxy {
@@ -22,18 +24,37 @@ impl PipelineProcessorProxy {
     }
 
     pub async fn execute_l2_tx(&self, tx: RoochTransaction) -> Result<ExecuteTransactionResponse> {
-        self.actor.send(ExecuteL2TxMessage { tx }).await?
+        self.actor
+            .send(ExecuteL2TxMessage {
+                tx,
+                span: Span::current(),
+            })
+            .await?
     }
 
     pub async fn execute_l1_block(
         &self,
         tx: L1BlockWithBody,
     ) -> Result<ExecuteTransactionResponse> {
-        self.actor.send(ExecuteL1BlockMessage { tx }).await?
+        self.actor
+            .send(ExecuteL1BlockMessage {
+                tx,
+                span: Span::current(),
+            })
+            .await?
     }
 
     pub async fn execute_l1_tx(&self, tx: L1Transaction) -> Result<ExecuteTransactionResponse> {
-        self.actor.send(ExecuteL1TxMessage { tx }).await?
+        self.actor
+            .send(ExecuteL1TxMessage {
+                tx,
+                span: Span::current(),
+            })
+            .await?
+    }
+
+    pub async fn backup_store(&self, backup_dir: PathBuf) -> Result<()> {
+        self.actor.send(BackupStoreMessage { backup_dir }).await?
     }
 }
 
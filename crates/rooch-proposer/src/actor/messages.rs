@@ -5,12 +5,15 @@ use anyhow::Result;
 use coerce::actor::{message::Message, scheduler::timer::TimerTick};
 use moveos_types::transaction::TransactionExecutionInfo;
 use rooch_types::transaction::LedgerTransaction;
+use tracing::Span;
 
 /// Transaction Propose Message
 #[derive(Debug)]
 pub struct TransactionProposeMessage {
     pub tx: LedgerTransaction,
     pub tx_execution_info: TransactionExecutionInfo,
+    /// The tracing span of the tx lifecycle
+    pub span: Span,
 }
 
 #[derive(Debug)]
@@ -30,3 +33,11 @@ impl Message for ProposeBlock {
 }
 
 impl TimerTick for ProposeBlock {}
+
+/// Resubmit the last proposed batch to the DA servers
+#[derive(Debug)]
+pub struct ResubmitBatchMessage {}
+
+impl Message for ResubmitBatchMessage {
+    type Result = Result<Option<u128>>;
+}
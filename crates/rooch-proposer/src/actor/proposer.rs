@@ -6,6 +6,8 @@ use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
 use prometheus::Registry;
 use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::{info_span, Instrument, Span};
 
 use crate::metrics::ProposerMetrics;
 use rooch_da::proxy::DAProxy;
@@ -13,7 +15,9 @@ use rooch_types::crypto::RoochKeyPair;
 
 use crate::scc::StateCommitmentChain;
 
-use super::messages::{ProposeBlock, TransactionProposeMessage, TransactionProposeResult};
+use super::messages::{
+    ProposeBlock, ResubmitBatchMessage, TransactionProposeMessage, TransactionProposeResult,
+};
 
 const TRANSACTION_PROPOSE_FN_NAME: &str = "transaction_propose";
 const PROPOSE_BLOCK_FN_NAME: &str = "propose_block";
@@ -40,7 +44,7 @@ impl Actor for ProposerActor {}
 impl Handler<TransactionProposeMessage> for ProposerActor {
     async fn handle(
         &mut self,
-        msg: TransactionProposeMessage,
+        mut msg: TransactionProposeMessage,
         _ctx: &mut ActorContext,
     ) -> Result<TransactionProposeResult> {
         let fn_name = TRANSACTION_PROPOSE_FN_NAME;
@@ -49,7 +53,9 @@ impl Handler<TransactionProposeMessage> for ProposerActor {
             .proposer_transaction_propose_latency_seconds
             .with_label_values(&[fn_name])
             .start_timer();
-        self.scc.append_transaction(msg);
+        // Do not keep the span in the buffer, otherwise the span is not closed until the block is proposed.
+        let span = std::mem::replace(&mut msg.span, Span::none());
+        span.in_scope(|| self.scc.append_transaction(msg));
         Ok(TransactionProposeResult {})
     }
 }
@@ -63,9 +69,32 @@ impl Handler<ProposeBlock> for ProposerActor {
             .proposer_propose_block_latency_seconds
             .with_label_values(&[fn_name])
             .start_timer();
-        let block = self.scc.propose_block().await;
+        let buffer = self.scc.buffer();
+        let tx_timestamps = buffer
+            .iter()
+            .map(|msg| msg.tx.sequence_info.tx_timestamp)
+            .collect::<Vec<_>>();
+        // The batch is submitted for multiple txs, so the span is keyed by the tx order range
+        let span = match (buffer.first(), buffer.last()) {
+            (Some(first), Some(last)) => info_span!(
+                "da_submit",
+                tx_order_start = first.tx.sequence_info.tx_order,
+                tx_order_end = last.tx.sequence_info.tx_order,
+            ),
+            _ => Span::none(),
+        };
+        let block = self.scc.propose_block().instrument(span).await;
         match block {
             Some(block) => {
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_millis() as u64;
+                for tx_timestamp in tx_timestamps {
+                    self.metrics
+                        .proposer_transaction_da_submit_latency_seconds
+                        .observe(now.saturating_sub(tx_timestamp) as f64 / 1000f64);
+                }
                 log::info!(
                     "[ProposeBlock] block_number: {}, batch_size: {:?}",
                     block.block_number,
@@ -84,3 +113,18 @@ impl Handler<ProposeBlock> for ProposerActor {
             .set(batch_size as i64);
     }
 }
+
+#[async_trait]
+impl Handler<ResubmitBatchMessage> for ProposerActor {
+    async fn handle(
+        &mut self,
+        _msg: ResubmitBatchMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Option<u128>> {
+        let block_number = self.scc.resubmit_last_batch().await?;
+        if let Some(block_number) = block_number {
+            log::info!("[ResubmitBatch] block_number: {}", block_number);
+        }
+        Ok(block_number)
+    }
+}
@@ -3,8 +3,8 @@
 
 use metrics::metrics_util::LATENCY_SEC_BUCKETS;
 use prometheus::{
-    register_histogram_vec_with_registry, register_int_gauge_with_registry, HistogramVec, IntGauge,
-    Registry,
+    register_histogram_vec_with_registry, register_histogram_with_registry,
+    register_int_gauge_with_registry, Histogram, HistogramVec, IntGauge, Registry,
 };
 
 #[derive(Debug)]
@@ -13,6 +13,7 @@ pub struct ProposerMetrics {
     pub proposer_transaction_propose_bytes: HistogramVec,
     pub proposer_propose_block_latency_seconds: HistogramVec,
     pub proposer_propose_block_batch_size: IntGauge,
+    pub proposer_transaction_da_submit_latency_seconds: Histogram,
 }
 
 impl ProposerMetrics {
@@ -50,6 +51,13 @@ impl ProposerMetrics {
                 registry,
             )
             .unwrap(),
+            proposer_transaction_da_submit_latency_seconds: register_histogram_with_registry!(
+                "proposer_transaction_da_submit_latency_seconds",
+                "Latency in seconds from the transaction is sequenced to the batch containing it is submitted to the DA servers",
+                LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
         }
     }
 }
@@ -2,13 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::actor::{
-    messages::{TransactionProposeMessage, TransactionProposeResult},
+    messages::{ResubmitBatchMessage, TransactionProposeMessage, TransactionProposeResult},
     proposer::ProposerActor,
 };
 use anyhow::Result;
 use coerce::actor::ActorRef;
 use moveos_types::transaction::TransactionExecutionInfo;
 use rooch_types::transaction::LedgerTransaction;
+use tracing::Span;
 
 #[derive(Clone)]
 pub struct ProposerProxy {
@@ -29,7 +30,12 @@ impl ProposerProxy {
             .send(TransactionProposeMessage {
                 tx,
                 tx_execution_info,
+                span: Span::current(),
             })
             .await?
     }
+
+    pub async fn resubmit_last_batch(&self) -> Result<Option<u128>> {
+        self.actor.send(ResubmitBatchMessage {}).await?
+    }
 }
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::actor::messages::TransactionProposeMessage;
+use anyhow::Result;
 use moveos_types::h256;
 use moveos_types::h256::H256;
 use rooch_da::messages::Batch;
@@ -13,6 +14,8 @@ use rooch_types::block::Block;
 pub struct StateCommitmentChain {
     //TODO save to the storage
     last_block: Option<Block>,
+    /// The last batch submitted to the DA servers, kept for resubmission
+    last_batch: Option<Batch>,
     buffer: Vec<TransactionProposeMessage>,
     da: DAProxy,
 }
@@ -22,6 +25,7 @@ impl StateCommitmentChain {
     pub fn new(da_proxy: DAProxy) -> Self {
         Self {
             last_block: None,
+            last_batch: None,
             buffer: Vec::new(),
             da: da_proxy,
         }
@@ -31,6 +35,11 @@ impl StateCommitmentChain {
         self.buffer.push(tx);
     }
 
+    /// Get the transactions waiting to be proposed in the next block
+    pub fn buffer(&self) -> &[TransactionProposeMessage] {
+        &self.buffer
+    }
+
     /// Update last block of the SCC
     fn update_last_block(&mut self, block: Block) {
         self.last_block = Some(block);
@@ -46,6 +55,17 @@ impl StateCommitmentChain {
         self.last_block.as_ref().map(|block| block.block_number)
     }
 
+    /// Resubmit the last batch to the DA servers, returns the block number of the batch
+    pub async fn resubmit_last_batch(&self) -> Result<Option<u128>> {
+        match &self.last_batch {
+            Some(batch) => {
+                self.da.submit_batch(batch.clone()).await?;
+                Ok(Some(batch.block_number))
+            }
+            None => Ok(None),
+        }
+    }
+
     /// Trigger the proposer to propose a new block
     pub async fn propose_block(&mut self) -> Option<&Block> {
         if self.buffer.is_empty() {
@@ -79,21 +99,19 @@ impl StateCommitmentChain {
         // TODO move batch submit out of proposer
         let batch_data: Vec<u8> = self.buffer.iter().flat_map(|tx| tx.tx.encode()).collect();
         let batch_hash = h256::sha2_256_of(&batch_data);
-        if let Err(e) = self
-            .da
-            .submit_batch(Batch {
-                block_number,
-                tx_count: batch_size,
-                prev_tx_accumulator_root,
-                tx_accumulator_root,
-                batch_hash,
-                data: batch_data,
-            })
-            .await
-        {
+        let batch = Batch {
+            block_number,
+            tx_count: batch_size,
+            prev_tx_accumulator_root,
+            tx_accumulator_root,
+            batch_hash,
+            data: batch_data,
+        };
+        if let Err(e) = self.da.submit_batch(batch.clone()).await {
             log::error!("submit batch to DA server failed: {}", e);
             return None;
         }
+        self.last_batch = Some(batch);
 
         let new_block = Block::new(
             block_number,
@@ -1,7 +1,10 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use super::messages::{GetChainTipsMessage, GetRawTransactionMessage, GetTxOutMessage};
+use super::messages::{
+    GetChainTipsMessage, GetRawMempoolMessage, GetRawTransactionMessage, GetTxOutMessage,
+    GetTxSpendingPrevoutMessage,
+};
 use crate::actor::messages::{
     BroadcastTransactionMessage, GetBestBlockHashMessage, GetBlockHashMessage,
     GetBlockHeaderInfoMessage, GetBlockMessage,
@@ -11,6 +14,7 @@ use async_trait::async_trait;
 use bitcoin::Transaction;
 use bitcoincore_rpc::{bitcoin::Txid, json, Auth, Client, RpcApi};
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
+use serde::Deserialize;
 use tokio::time::{sleep, Duration};
 use tracing::warn;
 
@@ -195,3 +199,53 @@ impl Handler<GetRawTransactionMessage> for BitcoinClientActor {
             .await?)
     }
 }
+
+#[async_trait]
+impl Handler<GetRawMempoolMessage> for BitcoinClientActor {
+    async fn handle(
+        &mut self,
+        _msg: GetRawMempoolMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<(Txid, u64)>> {
+        let entries = self
+            .retry(|| self.rpc_client.get_raw_mempool_verbose())
+            .await?;
+        Ok(entries
+            .into_iter()
+            .map(|(txid, entry)| (txid, entry.time))
+            .collect())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TxSpendingPrevoutResult {
+    spendingtxid: Option<Txid>,
+}
+
+#[async_trait]
+impl Handler<GetTxSpendingPrevoutMessage> for BitcoinClientActor {
+    async fn handle(
+        &mut self,
+        msg: GetTxSpendingPrevoutMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<Option<Txid>>> {
+        let GetTxSpendingPrevoutMessage { outpoints } = msg;
+        let outputs = outpoints
+            .iter()
+            .map(|outpoint| {
+                serde_json::json!({
+                    "txid": outpoint.txid,
+                    "vout": outpoint.vout,
+                })
+            })
+            .collect::<Vec<_>>();
+        let params = vec![serde_json::Value::Array(outputs)];
+        let results: Vec<TxSpendingPrevoutResult> = self
+            .retry(|| self.rpc_client.call("gettxspendingprevout", &params))
+            .await?;
+        Ok(results
+            .into_iter()
+            .map(|result| result.spendingtxid)
+            .collect())
+    }
+}
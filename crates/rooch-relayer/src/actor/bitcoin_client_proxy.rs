@@ -12,7 +12,10 @@ use bitcoincore_rpc::bitcoin::Txid;
 use bitcoincore_rpc::json;
 use coerce::actor::ActorRef;
 
-use super::messages::{GetChainTipsMessage, GetRawTransactionMessage, GetTxOutMessage};
+use super::messages::{
+    GetChainTipsMessage, GetRawMempoolMessage, GetRawTransactionMessage, GetTxOutMessage,
+    GetTxSpendingPrevoutMessage,
+};
 
 #[derive(Clone)]
 pub struct BitcoinClientProxy {
@@ -70,4 +73,19 @@ impl BitcoinClientProxy {
     pub async fn get_raw_transaction(&self, txid: Txid) -> Result<Transaction> {
         self.actor.send(GetRawTransactionMessage { txid }).await?
     }
+
+    /// Get the txids and the entry time in seconds of the mempool transactions
+    pub async fn get_raw_mempool(&self) -> Result<Vec<(Txid, u64)>> {
+        self.actor.send(GetRawMempoolMessage {}).await?
+    }
+
+    /// Find the mempool transactions which spend the given outpoints
+    pub async fn get_tx_spending_prevout(
+        &self,
+        outpoints: Vec<bitcoin::OutPoint>,
+    ) -> Result<Vec<Option<Txid>>> {
+        self.actor
+            .send(GetTxSpendingPrevoutMessage { outpoints })
+            .await?
+    }
 }
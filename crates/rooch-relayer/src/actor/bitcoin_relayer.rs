@@ -3,10 +3,10 @@
 
 use super::messages::{GetReadyL1BlockMessage, GetReadyL1TxsMessage, SyncTick};
 use crate::actor::bitcoin_client_proxy::BitcoinClientProxy;
-use anyhow::Result;
+use crate::block_file::BlockFileReader;
+use anyhow::{ensure, Result};
 use async_trait::async_trait;
 use bitcoin::{Block, BlockHash};
-use bitcoincore_rpc::bitcoincore_rpc_json::GetBlockHeaderResult;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
 use moveos_types::module_binding::MoveFunctionCaller;
 use rooch_config::BitcoinRelayerConfig;
@@ -24,7 +24,7 @@ pub struct BitcoinRelayer {
     genesis_block: BlockHeightHash,
     // only for data import
     end_block_height: Option<u64>,
-    rpc_client: BitcoinClientProxy,
+    block_source: BlockSource,
     move_caller: ExecutorProxy,
     buffer: Vec<BlockResult>,
     sync_block_interval: u64,
@@ -33,26 +33,51 @@ pub struct BitcoinRelayer {
     batch_size: usize,
 }
 
+/// Where the relayer reads the Bitcoin blocks from
+pub enum BlockSource {
+    /// The bitcoind JSON-RPC
+    Rpc(BitcoinClientProxy),
+    /// The blk*.dat files of the bitcoind blocks directory
+    BlockFiles(BlockFileReader),
+}
+
 #[derive(Debug, Clone)]
 pub struct BlockResult {
-    pub header_info: GetBlockHeaderResult,
+    pub block_height: u64,
+    pub block_hash: BlockHash,
     pub block: Block,
 }
 
 impl BitcoinRelayer {
     pub fn new(
         config: BitcoinRelayerConfig,
-        rpc_client: BitcoinClientProxy,
+        rpc_client: Option<BitcoinClientProxy>,
         executor: ExecutorProxy,
     ) -> Result<Self> {
         let bitcoin_module = executor.as_module_binding::<BitcoinModule>();
         let genesis_block = bitcoin_module.get_genesis_block()?;
         let sync_block_interval = config.btc_sync_block_interval.unwrap_or(60u64);
 
+        let block_source = match (&config.btc_blocks_dir, rpc_client) {
+            (Some(blocks_dir), _) => {
+                info!(
+                    "BitcoinRelayer read blocks from block files: {:?}",
+                    blocks_dir
+                );
+                BlockSource::BlockFiles(BlockFileReader::open(blocks_dir)?)
+            }
+            (None, Some(rpc_client)) => BlockSource::Rpc(rpc_client),
+            (None, None) => {
+                return Err(anyhow::anyhow!(
+                    "BitcoinRelayer requires the Bitcoin RPC or the blocks directory"
+                ))
+            }
+        };
+
         Ok(Self {
             genesis_block,
             end_block_height: config.btc_end_block_height,
-            rpc_client,
+            block_source,
             move_caller: executor,
             buffer: vec![],
             sync_block_interval,
@@ -80,14 +105,41 @@ impl BitcoinRelayer {
             pending_block_module.get_best_block()?
         } else {
             let last_block = self.buffer.last().unwrap();
-            let last_block_hash = last_block.header_info.hash;
-            let last_block_height = last_block.header_info.height;
             Some(BlockHeightHash {
-                block_hash: last_block_hash.into_address(),
-                block_height: last_block_height as u64,
+                block_hash: last_block.block_hash.into_address(),
+                block_height: last_block.block_height,
             })
         };
-        let best_block_hash_in_bitcoin = self.rpc_client.get_best_block_hash().await?;
+
+        match &mut self.block_source {
+            BlockSource::Rpc(rpc_client) => {
+                let rpc_client = rpc_client.clone();
+                self.sync_block_from_rpc(&rpc_client, best_block_in_rooch)
+                    .await
+            }
+            BlockSource::BlockFiles(reader) => {
+                let (blocks, sync_to_latest) = Self::sync_block_from_files(
+                    reader,
+                    &self.genesis_block,
+                    self.end_block_height,
+                    self.batch_size,
+                    best_block_in_rooch,
+                )?;
+                self.buffer.extend(blocks);
+                if sync_to_latest {
+                    self.sync_to_latest = true;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    async fn sync_block_from_rpc(
+        &mut self,
+        rpc_client: &BitcoinClientProxy,
+        best_block_in_rooch: Option<BlockHeightHash>,
+    ) -> Result<()> {
+        let best_block_hash_in_bitcoin = rpc_client.get_best_block_hash().await?;
 
         //The start block is included
         let start_block_hash = match best_block_in_rooch {
@@ -97,8 +149,7 @@ impl BitcoinRelayer {
                     return Ok(());
                 }
                 //We need to find the next block of the best block in rooch
-                let mut best_block_header_info = self
-                    .rpc_client
+                let mut best_block_header_info = rpc_client
                     .get_block_header_info(BlockHash::from_address(best_block_in_rooch.block_hash))
                     .await?;
 
@@ -111,8 +162,7 @@ impl BitcoinRelayer {
                                 best_block_header_info.hash
                             )
                         })?;
-                    best_block_header_info = self
-                        .rpc_client
+                    best_block_header_info = rpc_client
                         .get_block_header_info(previous_block_hash)
                         .await?;
                 }
@@ -130,8 +180,8 @@ impl BitcoinRelayer {
 
         let mut batch_count = 0;
         while let Some(next_hash) = next_block_hash {
-            let header_info = self.rpc_client.get_block_header_info(next_hash).await?;
-            let block = self.rpc_client.get_block(next_hash).await?;
+            let header_info = rpc_client.get_block_header_info(next_hash).await?;
+            let block = rpc_client.get_block(next_hash).await?;
             next_block_hash = header_info.next_block_hash;
             let next_block_height = header_info.height as u64;
 
@@ -147,7 +197,11 @@ impl BitcoinRelayer {
                 "BitcoinRelayer buffer block, height: {}, hash: {}",
                 next_block_height, header_info.hash
             );
-            self.buffer.push(BlockResult { header_info, block });
+            self.buffer.push(BlockResult {
+                block_height: next_block_height,
+                block_hash: header_info.hash,
+                block,
+            });
             if batch_count > self.batch_size {
                 break;
             }
@@ -157,22 +211,98 @@ impl BitcoinRelayer {
         Ok(())
     }
 
+    /// Read the next batch of blocks from the block files,
+    /// returns the blocks and whether the blocks are synced to the tip of the block files.
+    fn sync_block_from_files(
+        reader: &mut BlockFileReader,
+        genesis_block: &BlockHeightHash,
+        end_block_height: Option<u64>,
+        batch_size: usize,
+        best_block_in_rooch: Option<BlockHeightHash>,
+    ) -> Result<(Vec<BlockResult>, bool)> {
+        //The start block is included
+        let start_block_height = match best_block_in_rooch {
+            Some(best_block_in_rooch) => {
+                let best_block_hash = BlockHash::from_address(best_block_in_rooch.block_hash);
+                // The block files are used to import the historical blocks, so we do not handle the reorg here
+                ensure!(
+                    reader.block_height(&best_block_hash) == Some(best_block_in_rooch.block_height),
+                    "The best block in rooch(height: {}, hash: {}) is not in the main chain of the block files",
+                    best_block_in_rooch.block_height,
+                    best_block_hash
+                );
+                best_block_in_rooch.block_height + 1
+            }
+            None => {
+                // if the latest block in rooch is None, we start from the genesis block
+                let genesis_block_hash = BlockHash::from_address(genesis_block.block_hash);
+                ensure!(
+                    reader.block_hash(genesis_block.block_height) == Some(genesis_block_hash),
+                    "The genesis block(height: {}, hash: {}) is not in the block files",
+                    genesis_block.block_height,
+                    genesis_block_hash
+                );
+                genesis_block.block_height
+            }
+        };
+
+        // Index the blocks appended by bitcoind since the last sync
+        if reader.tip_height().unwrap_or(0) < start_block_height {
+            reader.refresh()?;
+        }
+
+        let end_block_height = end_block_height.unwrap_or(0);
+
+        let mut blocks = vec![];
+        let mut next_block_height = start_block_height;
+        let mut batch_count = 0;
+        while let Some(block_hash) = reader.block_hash(next_block_height) {
+            // only for bitcoin block data import
+            if end_block_height > 0 && next_block_height > end_block_height {
+                info!(
+                    "BitcoinRelayer process should exit at height {}, end_block_height is {} ",
+                    next_block_height, end_block_height
+                );
+                break;
+            }
+            let block = reader.read_block(next_block_height)?.ok_or_else(|| {
+                anyhow::anyhow!("The block at height {} should exist", next_block_height)
+            })?;
+            info!(
+                "BitcoinRelayer buffer block from block files, height: {}, hash: {}",
+                next_block_height, block_hash
+            );
+            blocks.push(BlockResult {
+                block_height: next_block_height,
+                block_hash,
+                block,
+            });
+            next_block_height += 1;
+            if batch_count > batch_size {
+                break;
+            }
+            batch_count += 1;
+        }
+        let sync_to_latest = reader.block_hash(next_block_height).is_none();
+
+        Ok((blocks, sync_to_latest))
+    }
+
     fn pop_buffer(&mut self) -> Result<Option<L1BlockWithBody>> {
         if self.buffer.is_empty() {
             Ok(None)
         } else {
             let block_result = self.buffer.remove(0);
-            let block_height = block_result.header_info.height;
-            let block_hash = block_result.header_info.hash;
+            let block_height = block_result.block_height;
+            let block_hash = block_result.block_hash;
             let time = block_result.block.header.time;
             let tx_size = block_result.block.txdata.len();
             info!(
                 "BitcoinRelayer process block, height: {}, hash: {}, tx_size: {}, time: {}",
                 block_height, block_hash, tx_size, time
             );
-            debug!("GetBlockHeaderResult: {:?}", block_result);
+            debug!("BlockResult: {:?}", block_result);
 
-            let block_height = block_result.header_info.height as u64;
             Ok(Some(L1BlockWithBody::new_bitcoin_block(
                 block_height,
                 block_result.block,
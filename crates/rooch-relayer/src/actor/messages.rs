@@ -118,3 +118,21 @@ pub struct GetRawTransactionMessage {
 impl Message for GetRawTransactionMessage {
     type Result = Result<Transaction>;
 }
+
+/// Find the mempool transactions which spend the given outpoints, require bitcoind v24.0 or later
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetTxSpendingPrevoutMessage {
+    pub outpoints: Vec<bitcoin::OutPoint>,
+}
+
+impl Message for GetTxSpendingPrevoutMessage {
+    type Result = Result<Vec<Option<Txid>>>;
+}
+
+/// Get the txids and the entry time in seconds of the bitcoind mempool transactions
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetRawMempoolMessage {}
+
+impl Message for GetRawMempoolMessage {
+    type Result = Result<Vec<(Txid, u64)>>;
+}
@@ -78,14 +78,18 @@ impl RelayerActor {
         }
 
         if let Some(bitcoin_config) = &self.bitcoin_config {
-            let bitcoin_client = BitcoinClientActor::new(
-                &bitcoin_config.btc_rpc_url,
-                &bitcoin_config.btc_rpc_user_name,
-                &bitcoin_config.btc_rpc_password,
-            )?;
-            let bitcoin_client_actor_ref =
-                ctx.spawn("bitcoin_client".into(), bitcoin_client).await?;
-            let bitcoin_client_proxy = BitcoinClientProxy::new(bitcoin_client_actor_ref.into());
+            let bitcoin_client_proxy = if bitcoin_config.has_rpc() {
+                let bitcoin_client = BitcoinClientActor::new(
+                    &bitcoin_config.btc_rpc_url,
+                    &bitcoin_config.btc_rpc_user_name,
+                    &bitcoin_config.btc_rpc_password,
+                )?;
+                let bitcoin_client_actor_ref =
+                    ctx.spawn("bitcoin_client".into(), bitcoin_client).await?;
+                Some(BitcoinClientProxy::new(bitcoin_client_actor_ref.into()))
+            } else {
+                None
+            };
             let bitcoin_relayer = BitcoinRelayer::new(
                 bitcoin_config.clone(),
                 bitcoin_client_proxy,
@@ -263,9 +267,18 @@ impl Handler<RelayTick> for RelayerActor {
 impl Handler<EventData> for RelayerActor {
     async fn handle(&mut self, message: EventData, _ctx: &mut ActorContext) -> Result<()> {
         if let Ok(service_status_event) = message.data.downcast::<ServiceStatusEvent>() {
-            if service_status_event.deref().status == ServiceStatus::Maintenance {
+            let service_status = service_status_event.deref().status;
+            if service_status == ServiceStatus::Maintenance {
                 log::warn!("RelayerActor: MoveVM panic occurs, set the status to paused...");
                 self.paused = true;
+            } else if self.paused
+                && (service_status.is_active() || service_status.is_date_import_mode())
+            {
+                log::info!(
+                    "RelayerActor: service status is set to {:?}, resume the relayer...",
+                    service_status
+                );
+                self.paused = false;
             }
         }
         Ok(())
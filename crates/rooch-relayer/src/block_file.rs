@@ -0,0 +1,800 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! Read Bitcoin blocks directly from the `blk*.dat` files of a bitcoind data directory.
+//!
+//! bitcoind stores the blocks in the order they are downloaded, not in the height order,
+//! the reader locates the blocks by the block index of bitcoind(`<blocks_dir>/index`, a LevelDB database),
+//! which records the validation status and the position in the block files of every block,
+//! so the block files do not need to be scanned.
+//! The blocks which are marked as invalid by bitcoind, or are not fully validated, are skipped,
+//! and the valid chain with the most work is selected as the main chain.
+//! LevelDB only allows one process to open the database, so bitcoind should be stopped while reading.
+
+use anyhow::{bail, ensure, Result};
+use bitcoin::block::Header;
+use bitcoin::blockdata::constants::genesis_block;
+use bitcoin::p2p::Magic;
+use bitcoin::pow::Work;
+use bitcoin::{Block, BlockHash, Network};
+use leveldb::database::Database;
+use leveldb::iterator::{Iterable, LevelDBIterator};
+use leveldb::options::{Options, ReadOptions};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{ErrorKind, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use tracing::{debug, info};
+
+/// The size of the magic and the block size prefix of each block record
+const RECORD_PREFIX_SIZE: u64 = 8;
+const HEADER_SIZE: usize = 80;
+/// The obfuscation key file of the block files, introduced in bitcoind v28
+const XOR_KEY_FILE: &str = "xor.dat";
+/// The block index database directory in the blocks directory
+const BLOCK_INDEX_DIR: &str = "index";
+/// The key prefix of the block index entries, followed by the block hash
+const BLOCK_INDEX_KEY_PREFIX: u8 = b'b';
+
+// The block status flags of bitcoind, see `BlockStatus` in bitcoind's chain.h
+const BLOCK_VALID_MASK: u64 = 7;
+/// All the scripts and signatures are valid, the block was connected to the chain
+const BLOCK_VALID_SCRIPTS: u64 = 5;
+const BLOCK_HAVE_DATA: u64 = 8;
+const BLOCK_HAVE_UNDO: u64 = 16;
+const BLOCK_FAILED_VALID: u64 = 32;
+const BLOCK_FAILED_CHILD: u64 = 64;
+const BLOCK_FAILED_MASK: u64 = BLOCK_FAILED_VALID | BLOCK_FAILED_CHILD;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockLocation {
+    /// The number of the blk file, blk00001.dat is 1
+    pub file: u32,
+    /// The offset of the block data in the file
+    pub offset: u64,
+}
+
+#[derive(Debug, Clone)]
+struct BlockIndexEntry {
+    location: BlockLocation,
+    header: Header,
+    // The height and the chain work are only available when the block is connected to the genesis block
+    height: Option<u64>,
+    chain_work: Option<Work>,
+}
+
+/// The block index entry stored by bitcoind, see `CDiskBlockIndex` in bitcoind's chain.h
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DiskBlockIndex {
+    status: u64,
+    file: Option<u32>,
+    data_pos: Option<u64>,
+    header: Header,
+}
+
+impl DiskBlockIndex {
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        let mut cursor = bytes;
+        // The client version
+        read_varint(&mut cursor)?;
+        // The height, it is recalculated when the block is connected
+        read_varint(&mut cursor)?;
+        let status = read_varint(&mut cursor)?;
+        // The number of the transactions
+        read_varint(&mut cursor)?;
+        let file = if status & (BLOCK_HAVE_DATA | BLOCK_HAVE_UNDO) != 0 {
+            Some(read_varint(&mut cursor)? as u32)
+        } else {
+            None
+        };
+        let data_pos = if status & BLOCK_HAVE_DATA != 0 {
+            Some(read_varint(&mut cursor)?)
+        } else {
+            None
+        };
+        if status & BLOCK_HAVE_UNDO != 0 {
+            read_varint(&mut cursor)?;
+        }
+        ensure!(
+            cursor.len() == HEADER_SIZE,
+            "Invalid block index entry, the header size is {}",
+            cursor.len()
+        );
+        let header: Header = bitcoin::consensus::deserialize(cursor)?;
+        Ok(Self {
+            status,
+            file,
+            data_pos,
+            header,
+        })
+    }
+
+    /// The block data is stored, and the block is fully validated and not marked as invalid
+    fn location_if_valid(&self) -> Option<BlockLocation> {
+        if self.status & BLOCK_FAILED_MASK != 0
+            || self.status & BLOCK_VALID_MASK < BLOCK_VALID_SCRIPTS
+        {
+            return None;
+        }
+        self.file
+            .zip(self.data_pos)
+            .map(|(file, offset)| BlockLocation { file, offset })
+    }
+}
+
+/// Read the VarInt of bitcoind, see `ReadVarInt` in bitcoind's serialize.h
+fn read_varint(bytes: &mut &[u8]) -> Result<u64> {
+    let mut n = 0u64;
+    loop {
+        let (byte, rest) = bytes
+            .split_first()
+            .ok_or_else(|| anyhow::anyhow!("Unexpected end of the VarInt"))?;
+        *bytes = rest;
+        ensure!(n <= u64::MAX >> 7, "The VarInt is too large");
+        n = (n << 7) | (byte & 0x7f) as u64;
+        if byte & 0x80 == 0 {
+            return Ok(n);
+        }
+        ensure!(n != u64::MAX, "The VarInt is too large");
+        n += 1;
+    }
+}
+
+/// The key of the block index database
+struct IndexKey(Vec<u8>);
+
+impl db_key::Key for IndexKey {
+    fn from_u8(key: &[u8]) -> Self {
+        IndexKey(key.to_vec())
+    }
+
+    fn as_slice<T, F: Fn(&[u8]) -> T>(&self, f: F) -> T {
+        f(&self.0)
+    }
+}
+
+pub struct BlockFileReader {
+    blocks_dir: PathBuf,
+    network: Network,
+    xor_key: Option<[u8; 8]>,
+    index: HashMap<BlockHash, BlockIndexEntry>,
+    // The blocks whose parent is not indexed yet, key is the parent block hash
+    orphans: HashMap<BlockHash, Vec<BlockHash>>,
+    // The block hashes of the main chain, the index is the block height
+    main_chain: Vec<BlockHash>,
+}
+
+impl BlockFileReader {
+    /// Open the blocks directory of bitcoind, usually `<datadir>/blocks` or `<datadir>/<network>/blocks`,
+    /// the network is detected from the magic of the first block file.
+    pub fn open<P: AsRef<Path>>(blocks_dir: P) -> Result<Self> {
+        let blocks_dir = blocks_dir.as_ref().to_path_buf();
+        let xor_key = read_xor_key(&blocks_dir)?;
+        let network = detect_network(&blocks_dir, xor_key)?;
+        let mut reader = Self {
+            blocks_dir,
+            network,
+            xor_key,
+            index: HashMap::new(),
+            orphans: HashMap::new(),
+            main_chain: vec![],
+        };
+        reader.refresh()?;
+        info!(
+            "BlockFileReader opened {:?}, network: {}, indexed blocks: {}, tip height: {:?}",
+            reader.blocks_dir,
+            reader.network,
+            reader.index.len(),
+            reader.tip_height()
+        );
+        Ok(reader)
+    }
+
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    /// The height of the best block in the block files, None if the genesis block is not found
+    pub fn tip_height(&self) -> Option<u64> {
+        (self.main_chain.len() as u64).checked_sub(1)
+    }
+
+    /// The block hash of the main chain at the `height`
+    pub fn block_hash(&self, height: u64) -> Option<BlockHash> {
+        self.main_chain.get(height as usize).cloned()
+    }
+
+    /// The height of the block if the block is in the main chain
+    pub fn block_height(&self, block_hash: &BlockHash) -> Option<u64> {
+        let height = self.index.get(block_hash)?.height?;
+        (self.block_hash(height).as_ref() == Some(block_hash)).then_some(height)
+    }
+
+    pub fn block_location(&self, block_hash: &BlockHash) -> Option<BlockLocation> {
+        self.index.get(block_hash).map(|entry| entry.location)
+    }
+
+    /// Read the block of the main chain at the `height`
+    pub fn read_block(&self, height: u64) -> Result<Option<Block>> {
+        match self.block_hash(height) {
+            Some(block_hash) => self.read_block_by_hash(&block_hash),
+            None => Ok(None),
+        }
+    }
+
+    pub fn read_block_by_hash(&self, block_hash: &BlockHash) -> Result<Option<Block>> {
+        let location = match self.block_location(block_hash) {
+            Some(location) => location,
+            None => return Ok(None),
+        };
+        ensure!(
+            location.offset >= RECORD_PREFIX_SIZE,
+            "Invalid block location {:?}",
+            location
+        );
+        let mut file = File::open(self.block_file_path(location.file))?;
+        let prefix = self.read_at(
+            &mut file,
+            location.offset - RECORD_PREFIX_SIZE,
+            RECORD_PREFIX_SIZE as usize,
+        )?;
+        ensure!(
+            prefix[..4] == self.network.magic().to_bytes(),
+            "Invalid magic {} at {:?}",
+            hex::encode(&prefix[..4]),
+            location
+        );
+        let size = u32::from_le_bytes([prefix[4], prefix[5], prefix[6], prefix[7]]);
+        let data = self.read_at(&mut file, location.offset, size as usize)?;
+        let block: Block = bitcoin::consensus::deserialize(&data)?;
+        ensure!(
+            block.block_hash() == *block_hash,
+            "The block at {:?} does not match the block hash {}",
+            location,
+            block_hash
+        );
+        Ok(Some(block))
+    }
+
+    /// Reload the block index of bitcoind to index the newly validated blocks,
+    /// returns the number of the newly indexed blocks.
+    pub fn refresh(&mut self) -> Result<usize> {
+        let entries = self.load_block_index()?;
+        let mut new_blocks = 0;
+        for (header, location) in entries {
+            if self.insert(header, location) {
+                new_blocks += 1;
+            }
+        }
+        if new_blocks > 0 {
+            self.update_main_chain();
+            debug!(
+                "BlockFileReader indexed {} new blocks, tip height: {:?}",
+                new_blocks,
+                self.tip_height()
+            );
+        }
+        Ok(new_blocks)
+    }
+
+    /// Load the valid blocks from the block index database, the database is closed after loading,
+    /// so the lock of the database is released.
+    fn load_block_index(&self) -> Result<Vec<(Header, BlockLocation)>> {
+        let path = self.blocks_dir.join(BLOCK_INDEX_DIR);
+        let db: Database<IndexKey> = Database::open(&path, Options::new()).map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to open the block index {:?}, please stop bitcoind before reading: {}",
+                path,
+                e
+            )
+        })?;
+        let start_key = IndexKey(vec![BLOCK_INDEX_KEY_PREFIX]);
+        let mut entries = vec![];
+        let mut skipped = 0;
+        for (key, value) in db.iter(ReadOptions::new()).from(&start_key) {
+            if key.0.first() != Some(&BLOCK_INDEX_KEY_PREFIX) {
+                break;
+            }
+            let disk_index = DiskBlockIndex::decode(&value)?;
+            match disk_index.location_if_valid() {
+                Some(location) => entries.push((disk_index.header, location)),
+                None => skipped += 1,
+            }
+        }
+        debug!(
+            "BlockFileReader loaded {} valid blocks from the block index, skipped {} blocks",
+            entries.len(),
+            skipped
+        );
+        Ok(entries)
+    }
+
+    fn insert(&mut self, header: Header, location: BlockLocation) -> bool {
+        let block_hash = header.block_hash();
+        if self.index.contains_key(&block_hash) {
+            return false;
+        }
+        self.index.insert(
+            block_hash,
+            BlockIndexEntry {
+                location,
+                header,
+                height: None,
+                chain_work: None,
+            },
+        );
+        if block_hash == genesis_block(self.network).block_hash() {
+            self.connect(block_hash, 0, header.work());
+            return true;
+        }
+        let parent = self
+            .index
+            .get(&header.prev_blockhash)
+            .and_then(|entry| entry.height.zip(entry.chain_work));
+        match parent {
+            Some((parent_height, parent_work)) => {
+                self.connect(block_hash, parent_height + 1, parent_work + header.work())
+            }
+            None => self
+                .orphans
+                .entry(header.prev_blockhash)
+                .or_default()
+                .push(block_hash),
+        }
+        true
+    }
+
+    /// Connect the block and its descendants which are waiting for it
+    fn connect(&mut self, block_hash: BlockHash, height: u64, chain_work: Work) {
+        let mut pending = vec![(block_hash, height, chain_work)];
+        while let Some((block_hash, height, chain_work)) = pending.pop() {
+            if let Some(entry) = self.index.get_mut(&block_hash) {
+                entry.height = Some(height);
+                entry.chain_work = Some(chain_work);
+            }
+            for child in self.orphans.remove(&block_hash).unwrap_or_default() {
+                let child_work = self.index[&child].header.work();
+                pending.push((child, height + 1, chain_work + child_work));
+            }
+        }
+    }
+
+    fn update_main_chain(&mut self) {
+        let tip = self
+            .index
+            .iter()
+            .filter_map(|(block_hash, entry)| {
+                entry
+                    .chain_work
+                    .zip(entry.height)
+                    .map(|(work, height)| (work, height, *block_hash))
+            })
+            .max();
+        let (_, tip_height, tip_hash) = match tip {
+            Some(tip) => tip,
+            None => return,
+        };
+        // Walk back from the tip until the block which is already in the main chain
+        let mut new_hashes = vec![];
+        let mut cursor = tip_hash;
+        let mut height = tip_height;
+        loop {
+            if self.block_hash(height) == Some(cursor) {
+                height += 1;
+                break;
+            }
+            new_hashes.push(cursor);
+            if height == 0 {
+                break;
+            }
+            cursor = self.index[&cursor].header.prev_blockhash;
+            height -= 1;
+        }
+        self.main_chain.truncate(height as usize);
+        self.main_chain.extend(new_hashes.into_iter().rev());
+    }
+
+    fn block_file_path(&self, file_number: u32) -> PathBuf {
+        block_file_path(&self.blocks_dir, file_number)
+    }
+
+    fn read_at(&self, file: &mut File, offset: u64, len: usize) -> Result<Vec<u8>> {
+        read_at(file, offset, len, self.xor_key)
+    }
+}
+
+fn block_file_path(blocks_dir: &Path, file_number: u32) -> PathBuf {
+    blocks_dir.join(format!("blk{:05}.dat", file_number))
+}
+
+fn read_at(file: &mut File, offset: u64, len: usize, xor_key: Option<[u8; 8]>) -> Result<Vec<u8>> {
+    let mut buf = vec![0u8; len];
+    file.seek(SeekFrom::Start(offset))?;
+    file.read_exact(&mut buf)?;
+    if let Some(key) = xor_key {
+        for (i, byte) in buf.iter_mut().enumerate() {
+            *byte ^= key[((offset + i as u64) % 8) as usize];
+        }
+    }
+    Ok(buf)
+}
+
+fn read_xor_key(blocks_dir: &Path) -> Result<Option<[u8; 8]>> {
+    let mut key = [0u8; 8];
+    match File::open(blocks_dir.join(XOR_KEY_FILE)) {
+        Ok(mut file) => {
+            file.read_exact(&mut key)?;
+            // The all zero key means the block files are not obfuscated
+            Ok((key != [0u8; 8]).then_some(key))
+        }
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn detect_network(blocks_dir: &Path, xor_key: Option<[u8; 8]>) -> Result<Network> {
+    let path = block_file_path(blocks_dir, 0);
+    if !path.exists() {
+        bail!("The block file {:?} does not exist", path);
+    }
+    let mut file = File::open(&path)?;
+    let bytes = read_at(&mut file, 0, 4, xor_key)?;
+    let magic = Magic::from_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    Network::from_magic(magic)
+        .ok_or_else(|| anyhow::anyhow!("Unknown network magic {} in {:?}", magic, path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::absolute::LockTime;
+    use bitcoin::block::Version;
+    use bitcoin::hashes::Hash;
+    use bitcoin::transaction::Version as TxVersion;
+    use bitcoin::{
+        Amount, CompactTarget, OutPoint, ScriptBuf, Sequence, Transaction, TxIn, TxMerkleNode,
+        TxOut, Witness,
+    };
+    use leveldb::kv::KV;
+    use leveldb::options::WriteOptions;
+    use std::io::Write;
+
+    fn mine_block(prev: &Block, height: u64, tag: u8) -> Block {
+        let coinbase = Transaction {
+            version: TxVersion::ONE,
+            lock_time: LockTime::ZERO,
+            input: vec![TxIn {
+                previous_output: OutPoint::null(),
+                script_sig: ScriptBuf::from_bytes(vec![
+                    8,
+                    height as u8,
+                    (height >> 8) as u8,
+                    (height >> 16) as u8,
+                    (height >> 24) as u8,
+                    tag,
+                    0,
+                    0,
+                    0,
+                ]),
+                sequence: Sequence::MAX,
+                witness: Witness::new(),
+            }],
+            output: vec![TxOut {
+                value: Amount::from_sat(50_0000_0000),
+                script_pubkey: ScriptBuf::new(),
+            }],
+        };
+        let mut block = Block {
+            header: Header {
+                version: Version::TWO,
+                prev_blockhash: prev.block_hash(),
+                merkle_root: TxMerkleNode::all_zeros(),
+                time: prev.header.time + 600,
+                bits: CompactTarget::from_consensus(0x207fffff),
+                nonce: 0,
+            },
+            txdata: vec![coinbase],
+        };
+        block.header.merkle_root = block.compute_merkle_root().unwrap();
+        while block.header.validate_pow(block.header.target()).is_err() {
+            block.header.nonce += 1;
+        }
+        block
+    }
+
+    fn mine_chain(from: &Block, from_height: u64, count: usize, tag: u8) -> Vec<Block> {
+        let mut blocks: Vec<Block> = vec![];
+        for i in 0..count {
+            let prev = blocks.last().unwrap_or(from);
+            blocks.push(mine_block(prev, from_height + 1 + i as u64, tag));
+        }
+        blocks
+    }
+
+    const VALID_STATUS: u64 = BLOCK_VALID_SCRIPTS | BLOCK_HAVE_DATA | BLOCK_HAVE_UNDO;
+
+    fn append_blocks(
+        dir: &Path,
+        file_number: u32,
+        blocks: &[&Block],
+        xor_key: Option<[u8; 8]>,
+    ) -> Vec<BlockLocation> {
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(block_file_path(dir, file_number))
+            .unwrap();
+        let mut offset = file.metadata().unwrap().len();
+        let mut locations = vec![];
+        for block in blocks {
+            let data = bitcoin::consensus::serialize(*block);
+            let mut record = Network::Regtest.magic().to_bytes().to_vec();
+            record.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            record.extend_from_slice(&data);
+            if let Some(key) = xor_key {
+                for (i, byte) in record.iter_mut().enumerate() {
+                    *byte ^= key[((offset + i as u64) % 8) as usize];
+                }
+            }
+            file.write_all(&record).unwrap();
+            locations.push(BlockLocation {
+                file: file_number,
+                offset: offset + RECORD_PREFIX_SIZE,
+            });
+            offset += record.len() as u64;
+        }
+        locations
+    }
+
+    fn write_varint(mut n: u64, buf: &mut Vec<u8>) {
+        let mut tmp = vec![];
+        loop {
+            let mark = if tmp.is_empty() { 0 } else { 0x80 };
+            tmp.push((n & 0x7f) as u8 | mark);
+            if n <= 0x7f {
+                break;
+            }
+            n = (n >> 7) - 1;
+        }
+        buf.extend(tmp.into_iter().rev());
+    }
+
+    fn encode_disk_index(
+        height: u64,
+        status: u64,
+        location: Option<BlockLocation>,
+        header: &Header,
+    ) -> Vec<u8> {
+        let mut buf = vec![];
+        // The client version
+        write_varint(270000, &mut buf);
+        write_varint(height, &mut buf);
+        write_varint(status, &mut buf);
+        write_varint(1, &mut buf);
+        if let Some(location) = location {
+            write_varint(location.file as u64, &mut buf);
+            write_varint(location.offset, &mut buf);
+            if status & BLOCK_HAVE_UNDO != 0 {
+                write_varint(location.offset, &mut buf);
+            }
+        }
+        buf.extend(bitcoin::consensus::serialize(header));
+        buf
+    }
+
+    /// Write the block index entries like bitcoind, `(block, height, location, status)`
+    fn write_block_index(dir: &Path, entries: &[(&Block, u64, Option<BlockLocation>, u64)]) {
+        let mut options = Options::new();
+        options.create_if_missing = true;
+        let db: Database<IndexKey> = Database::open(&dir.join(BLOCK_INDEX_DIR), options).unwrap();
+        for (block, height, location, status) in entries {
+            let mut key = vec![BLOCK_INDEX_KEY_PREFIX];
+            key.extend(block.block_hash().to_byte_array());
+            let value = encode_disk_index(*height, *status, *location, &block.header);
+            db.put(WriteOptions::new(), IndexKey(key), &value).unwrap();
+        }
+        // The entries of the other types should be ignored
+        db.put(WriteOptions::new(), IndexKey(b"R".to_vec()), &[0])
+            .unwrap();
+        db.put(WriteOptions::new(), IndexKey(b"l".to_vec()), &[0])
+            .unwrap();
+    }
+
+    fn valid_entries<'a>(
+        blocks: &[&'a Block],
+        from_height: u64,
+        locations: &[BlockLocation],
+    ) -> Vec<(&'a Block, u64, Option<BlockLocation>, u64)> {
+        blocks
+            .iter()
+            .zip(locations)
+            .enumerate()
+            .map(|(i, (block, location))| {
+                (
+                    *block,
+                    from_height + i as u64,
+                    Some(*location),
+                    VALID_STATUS,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_read_varint() {
+        // The examples in bitcoind's serialize.h
+        let cases: Vec<(u64, Vec<u8>)> = vec![
+            (0, vec![0x00]),
+            (1, vec![0x01]),
+            (127, vec![0x7f]),
+            (128, vec![0x80, 0x00]),
+            (255, vec![0x80, 0x7f]),
+            (256, vec![0x81, 0x00]),
+            (16383, vec![0xfe, 0x7f]),
+            (16384, vec![0xff, 0x00]),
+            (16511, vec![0xff, 0x7f]),
+            (65535, vec![0x82, 0xfe, 0x7f]),
+            (1 << 32, vec![0x8e, 0xfe, 0xfe, 0xff, 0x00]),
+        ];
+        for (n, bytes) in cases {
+            let mut buf = vec![];
+            write_varint(n, &mut buf);
+            assert_eq!(buf, bytes);
+            let mut cursor = bytes.as_slice();
+            assert_eq!(read_varint(&mut cursor).unwrap(), n);
+            assert!(cursor.is_empty());
+        }
+        assert!(read_varint(&mut [0x80u8].as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_read_out_of_order_blocks() {
+        let dir = tempfile::tempdir().unwrap();
+        let genesis = genesis_block(Network::Regtest);
+        let chain = mine_chain(&genesis, 0, 5, 0);
+        // A fork from height 2 with less work
+        let fork = mine_chain(&chain[1], 2, 2, 1);
+
+        let file0 = append_blocks(
+            dir.path(),
+            0,
+            &[&genesis, &chain[0], &chain[2], &fork[0]],
+            None,
+        );
+        let file1 = append_blocks(
+            dir.path(),
+            1,
+            &[&chain[1], &fork[1], &chain[4], &chain[3]],
+            None,
+        );
+        let mut entries = valid_entries(&[&genesis, &chain[0]], 0, &file0[..2]);
+        entries.push((&chain[2], 3, Some(file0[2]), VALID_STATUS));
+        entries.push((&fork[0], 3, Some(file0[3]), VALID_STATUS));
+        entries.push((&chain[1], 2, Some(file1[0]), VALID_STATUS));
+        entries.push((&fork[1], 4, Some(file1[1]), VALID_STATUS));
+        entries.push((&chain[4], 5, Some(file1[2]), VALID_STATUS));
+        entries.push((&chain[3], 4, Some(file1[3]), VALID_STATUS));
+        write_block_index(dir.path(), &entries);
+
+        let reader = BlockFileReader::open(dir.path()).unwrap();
+        assert_eq!(reader.network(), Network::Regtest);
+        assert_eq!(reader.tip_height(), Some(5));
+        assert_eq!(reader.block_hash(0), Some(genesis.block_hash()));
+        for (i, block) in chain.iter().enumerate() {
+            let height = i as u64 + 1;
+            assert_eq!(reader.block_hash(height), Some(block.block_hash()));
+            assert_eq!(reader.block_height(&block.block_hash()), Some(height));
+            assert_eq!(reader.read_block(height).unwrap().as_ref(), Some(block));
+        }
+        assert_eq!(reader.block_height(&fork[0].block_hash()), None);
+        assert_eq!(
+            reader.read_block_by_hash(&fork[0].block_hash()).unwrap(),
+            Some(fork[0].clone())
+        );
+        assert!(reader.read_block(6).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_skip_invalid_blocks() {
+        let dir = tempfile::tempdir().unwrap();
+        let genesis = genesis_block(Network::Regtest);
+        let chain = mine_chain(&genesis, 0, 3, 0);
+        // A fork from height 1 with more work, but the second block of the fork is invalid
+        let fork = mine_chain(&chain[0], 1, 4, 1);
+        // The blocks after the tip which are not fully validated yet
+        let pending = mine_chain(&chain[2], 3, 2, 2);
+
+        let chain_blocks = [&genesis, &chain[0], &chain[1], &chain[2]];
+        let chain_locations = append_blocks(dir.path(), 0, &chain_blocks, None);
+        let fork_locations = append_blocks(dir.path(), 0, &fork.iter().collect::<Vec<_>>(), None);
+        let pending_locations = append_blocks(dir.path(), 0, &[&pending[0]], None);
+
+        let mut entries = valid_entries(&chain_blocks, 0, &chain_locations);
+        entries.push((&fork[0], 2, Some(fork_locations[0]), VALID_STATUS));
+        entries.push((
+            &fork[1],
+            3,
+            Some(fork_locations[1]),
+            VALID_STATUS | BLOCK_FAILED_VALID,
+        ));
+        entries.push((
+            &fork[2],
+            4,
+            Some(fork_locations[2]),
+            VALID_STATUS | BLOCK_FAILED_CHILD,
+        ));
+        entries.push((
+            &fork[3],
+            5,
+            Some(fork_locations[3]),
+            VALID_STATUS | BLOCK_FAILED_CHILD,
+        ));
+        // Only the transactions are validated, the block is not connected
+        entries.push((
+            &pending[0],
+            4,
+            Some(pending_locations[0]),
+            3 | BLOCK_HAVE_DATA,
+        ));
+        // Only the header is received, no block data
+        entries.push((&pending[1], 5, None, 2));
+        write_block_index(dir.path(), &entries);
+
+        let reader = BlockFileReader::open(dir.path()).unwrap();
+        assert_eq!(reader.tip_height(), Some(3));
+        for (height, block) in chain_blocks.iter().enumerate() {
+            assert_eq!(reader.block_hash(height as u64), Some(block.block_hash()));
+        }
+        assert_eq!(reader.block_height(&fork[0].block_hash()), None);
+        for block in fork[1..].iter().chain(pending.iter()) {
+            assert_eq!(reader.block_location(&block.block_hash()), None);
+            assert!(reader
+                .read_block_by_hash(&block.block_hash())
+                .unwrap()
+                .is_none());
+        }
+    }
+
+    #[test]
+    fn test_refresh_and_reorg() {
+        let dir = tempfile::tempdir().unwrap();
+        let xor_key = [1u8, 2, 3, 4, 5, 6, 7, 8];
+        std::fs::write(dir.path().join(XOR_KEY_FILE), xor_key).unwrap();
+        let genesis = genesis_block(Network::Regtest);
+        let chain = mine_chain(&genesis, 0, 3, 0);
+        let chain_blocks = [&genesis, &chain[0], &chain[1], &chain[2]];
+        let locations = append_blocks(dir.path(), 0, &chain_blocks, Some(xor_key));
+        write_block_index(dir.path(), &valid_entries(&chain_blocks, 0, &locations));
+
+        let mut reader = BlockFileReader::open(dir.path()).unwrap();
+        assert_eq!(reader.tip_height(), Some(3));
+        assert_eq!(reader.refresh().unwrap(), 0);
+
+        // A longer fork from height 1 replaces the blocks after it
+        let fork = mine_chain(&chain[0], 1, 4, 1);
+        let fork_blocks = fork.iter().collect::<Vec<_>>();
+        let locations = append_blocks(dir.path(), 1, &fork_blocks, Some(xor_key));
+        write_block_index(dir.path(), &valid_entries(&fork_blocks, 2, &locations));
+        assert_eq!(reader.refresh().unwrap(), 4);
+        assert_eq!(reader.tip_height(), Some(5));
+        assert_eq!(reader.block_hash(1), Some(chain[0].block_hash()));
+        assert_eq!(reader.block_hash(2), Some(fork[0].block_hash()));
+        assert_eq!(reader.block_height(&chain[1].block_hash()), None);
+        assert_eq!(reader.read_block(5).unwrap().as_ref(), Some(&fork[3]));
+    }
+
+    #[test]
+    fn test_missing_block_index() {
+        let dir = tempfile::tempdir().unwrap();
+        let genesis = genesis_block(Network::Regtest);
+        append_blocks(dir.path(), 0, &[&genesis], None);
+        assert!(BlockFileReader::open(dir.path()).is_err());
+    }
+
+    #[test]
+    fn test_unknown_magic() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(block_file_path(dir.path(), 0), [0xffu8; 96]).unwrap();
+        assert!(BlockFileReader::open(dir.path()).is_err());
+    }
+}
@@ -0,0 +1,62 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::admin_view::{BackupManifestView, IndexerStatusView, ServiceStatusView};
+use crate::jsonrpc_types::repair_view::{RepairIndexerParamsView, RepairIndexerTypeView};
+use crate::jsonrpc_types::{QueryOptions, StateChangeSetPageView, StrView, SyncStateFilterView};
+use crate::RpcResult;
+use jsonrpsee::proc_macros::rpc;
+use rooch_open_rpc_macros::open_rpc;
+
+#[open_rpc(namespace = "admin")]
+#[rpc(server, client, namespace = "admin")]
+#[async_trait]
+pub trait AdminAPI {
+    /// Repair indexer by sync from states
+    #[method(name = "repairIndexer")]
+    async fn repair_indexer(
+        &self,
+        repair_type: RepairIndexerTypeView,
+        repair_params: RepairIndexerParamsView,
+    ) -> RpcResult<()>;
+
+    /// Get the status of the indexer, including the progress of the catch-up on startup
+    #[method(name = "getIndexerStatus")]
+    async fn get_indexer_status(&self) -> RpcResult<IndexerStatusView>;
+
+    /// Sync state change sets
+    #[method(name = "syncStates")]
+    async fn sync_states(
+        &self,
+        filter: SyncStateFilterView,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<StrView<u64>>,
+        limit: Option<StrView<u64>>,
+        query_option: Option<QueryOptions>,
+    ) -> RpcResult<StateChangeSetPageView>;
+
+    /// Get the current service status
+    #[method(name = "getServiceStatus")]
+    async fn get_service_status(&self) -> RpcResult<ServiceStatusView>;
+
+    /// Switch the service status without restarting the server
+    #[method(name = "setServiceStatus")]
+    async fn set_service_status(&self, status: ServiceStatusView) -> RpcResult<()>;
+
+    /// Resubmit the last proposed batch to the DA servers, returns the block number of the batch
+    #[method(name = "resubmitDABatch")]
+    async fn resubmit_da_batch(&self) -> RpcResult<Option<StrView<u128>>>;
+
+    /// Flush the Move module cache of the executor
+    #[method(name = "flushCache")]
+    async fn flush_cache(&self) -> RpcResult<()>;
+
+    /// Reload the gas config from the chain state
+    #[method(name = "refreshGasConfig")]
+    async fn refresh_gas_config(&self) -> RpcResult<()>;
+
+    /// Backup the db of the node to `backup_dir` on the node's host, the backup is taken
+    /// between two transactions, so it is consistent at the returned tx order
+    #[method(name = "backupDB")]
+    async fn backup_db(&self, backup_dir: String) -> RpcResult<BackupManifestView>;
+}
@@ -2,9 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::jsonrpc_types::btc::ord::InscriptionFilterView;
+use crate::jsonrpc_types::btc::runes::RuneFilterView;
+use crate::jsonrpc_types::btc::transaction::PendingTransactionView;
 use crate::jsonrpc_types::btc::utxo::UTXOFilterView;
 use crate::jsonrpc_types::{
-    BytesView, IndexerStateIDView, InscriptionPageView, StrView, UTXOPageView,
+    BytesView, InscriptionPageView, PageCursorView, RuneBalancesPageView, StrView, UTXOPageView,
 };
 use crate::RpcResult;
 use jsonrpsee::proc_macros::rpc;
@@ -14,13 +16,14 @@ use rooch_open_rpc_macros::open_rpc;
 #[rpc(server, client, namespace = "btc")]
 #[async_trait]
 pub trait BtcAPI {
-    /// Query the UTXO via global index by UTXO filter
+    /// Query the UTXO via global index by UTXO filter.
+    /// The UTXOs spent by the pending transactions are excluded when query by owner or all.
     #[method(name = "queryUTXOs")]
     async fn query_utxos(
         &self,
         filter: UTXOFilterView,
         // exclusive cursor if `Some`, otherwise start from the beginning
-        cursor: Option<IndexerStateIDView>,
+        cursor: Option<PageCursorView>,
         limit: Option<StrView<u64>>,
         descending_order: Option<bool>,
     ) -> RpcResult<UTXOPageView>;
@@ -31,11 +34,22 @@ pub trait BtcAPI {
         &self,
         filter: InscriptionFilterView,
         // exclusive cursor if `Some`, otherwise start from the beginning
-        cursor: Option<IndexerStateIDView>,
+        cursor: Option<PageCursorView>,
         limit: Option<StrView<u64>>,
         descending_order: Option<bool>,
     ) -> RpcResult<InscriptionPageView>;
 
+    /// Query the Rune balances of UTXOs via global index by Rune filter
+    #[method(name = "queryRunes")]
+    async fn query_runes(
+        &self,
+        filter: RuneFilterView,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<PageCursorView>,
+        limit: Option<StrView<u64>>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<RuneBalancesPageView>;
+
     /// Broadcast a Bitcoin transaction
     #[method(name = "broadcastTX")]
     async fn broadcast_tx(
@@ -44,4 +58,8 @@ pub trait BtcAPI {
         maxfeerate: Option<f64>,
         maxburnamount: Option<f64>,
     ) -> RpcResult<String>;
+
+    /// Query the pending Bitcoin transactions, which are broadcast via this node or found in the bitcoind mempool but not confirmed yet
+    #[method(name = "queryPendingTransactions")]
+    async fn query_pending_transactions(&self) -> RpcResult<Vec<PendingTransactionView>>;
 }
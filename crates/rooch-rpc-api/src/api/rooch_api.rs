@@ -3,17 +3,19 @@
 
 use crate::jsonrpc_types::account_view::BalanceInfoView;
 use crate::jsonrpc_types::address::UnitedAddressView;
-use crate::jsonrpc_types::event_view::{EventFilterView, IndexerEventIDView};
-use crate::jsonrpc_types::repair_view::{RepairIndexerParamsView, RepairIndexerTypeView};
-use crate::jsonrpc_types::transaction_view::{TransactionFilterView, TransactionWithInfoView};
+use crate::jsonrpc_types::event_view::EventFilterView;
+use crate::jsonrpc_types::gas_view::{GasEstimateView, GasPriceView};
+use crate::jsonrpc_types::session_key_view::SessionKeyView;
+use crate::jsonrpc_types::transaction_view::{
+    TransactionFilterView, TransactionWithInfoView, TxPoolTransactionView,
+};
 use crate::jsonrpc_types::{
     AccessPathView, AnnotatedFunctionResultView, BalanceInfoPageView, BytesView,
     DryRunTransactionResponseView, EventOptions, EventPageView, ExecuteTransactionResponseView,
     FieldKeyView, FunctionCallView, H256View, IndexerEventPageView, IndexerObjectStatePageView,
-    IndexerStateIDView, ModuleABIView, ObjectIDVecView, ObjectIDView, ObjectStateFilterView,
-    ObjectStateView, QueryOptions, RoochAddressView, StateChangeSetPageView, StateOptions,
-    StatePageView, StrView, StructTagView, SyncStateFilterView, TransactionWithInfoPageView,
-    TxOptions,
+    IndexerStateIDView, IndexerTransactionPageView, ModuleABIView, ObjectIDVecView, ObjectIDView,
+    ObjectStateFilterView, ObjectStateView, PageCursorView, QueryOptions, RoochAddressView,
+    StateOptions, StatePageView, StrView, StructTagView, TransactionWithInfoPageView, TxOptions,
 };
 use crate::RpcResult;
 use jsonrpsee::proc_macros::rpc;
@@ -44,6 +46,12 @@ pub trait RoochAPI {
     #[method(name = "dryRunRawTransaction")]
     async fn dry_run(&self, tx_bcs_hex: BytesView) -> RpcResult<DryRunTransactionResponseView>;
 
+    /// Estimate the gas of the transaction data in bcs hex format,
+    /// it dry runs the transaction with a high max gas amount and ignores the max gas amount of the transaction.
+    /// Returns the gas used breakdown and the recommended max gas amount.
+    #[method(name = "estimateGas")]
+    async fn estimate_gas(&self, tx_bcs_hex: BytesView) -> RpcResult<GasEstimateView>;
+
     /// Execute a read-only function call
     /// The function do not change the state of Application
     #[method(name = "executeViewFunction")]
@@ -151,6 +159,26 @@ pub trait RoochAPI {
         limit: Option<StrView<u64>>,
     ) -> RpcResult<BalanceInfoPageView>;
 
+    /// get the session keys of the account, with the expired flag at the current on-chain time
+    #[method(name = "getSessionKeys")]
+    async fn get_session_keys(
+        &self,
+        account_addr: UnitedAddressView,
+    ) -> RpcResult<Vec<SessionKeyView>>;
+
+    /// get the transactions of the sender waiting in the txpool for the previous sequence numbers,
+    /// ordered by sequence number
+    #[method(name = "getPendingTransactions")]
+    async fn get_pending_transactions(
+        &self,
+        sender: UnitedAddressView,
+    ) -> RpcResult<Vec<TxPoolTransactionView>>;
+
+    /// get the current base fee and the suggested priority fee and max gas price,
+    /// the suggested priority fee is above the ready transactions in the txpool when there is a backlog
+    #[method(name = "estimateGasPrice")]
+    async fn estimate_gas_price(&self) -> RpcResult<GasPriceView>;
+
     /// get module ABI by module id
     #[method(name = "getModuleABI")]
     async fn get_module_abi(
@@ -165,10 +193,10 @@ pub trait RoochAPI {
         &self,
         filter: TransactionFilterView,
         // exclusive cursor if `Some`, otherwise start from the beginning
-        cursor: Option<StrView<u64>>,
+        cursor: Option<PageCursorView>,
         limit: Option<StrView<u64>>,
         query_option: Option<QueryOptions>,
-    ) -> RpcResult<TransactionWithInfoPageView>;
+    ) -> RpcResult<IndexerTransactionPageView>;
 
     /// Query the events indexer by event filter
     #[method(name = "queryEvents")]
@@ -176,7 +204,7 @@ pub trait RoochAPI {
         &self,
         filter: EventFilterView,
         // exclusive cursor if `Some`, otherwise start from the beginning
-        cursor: Option<IndexerEventIDView>,
+        cursor: Option<PageCursorView>,
         limit: Option<StrView<u64>>,
         query_option: Option<QueryOptions>,
     ) -> RpcResult<IndexerEventPageView>;
@@ -187,27 +215,15 @@ pub trait RoochAPI {
         &self,
         filter: ObjectStateFilterView,
         // exclusive cursor if `Some`, otherwise start from the beginning
-        cursor: Option<IndexerStateIDView>,
+        cursor: Option<PageCursorView>,
         limit: Option<StrView<u64>>,
         query_option: Option<QueryOptions>,
     ) -> RpcResult<IndexerObjectStatePageView>;
 
-    /// Repair indexer by sync from states
-    #[method(name = "repairIndexer")]
-    async fn repair_indexer(
-        &self,
-        repair_type: RepairIndexerTypeView,
-        repair_params: RepairIndexerParamsView,
-    ) -> RpcResult<()>;
-
-    /// Sync state change sets
-    #[method(name = "syncStates")]
-    async fn sync_states(
+    /// Get the raw Sparse Merkle Tree nodes by node hashes, it is used by the fork mode node to read the remote state
+    #[method(name = "getStateNodes")]
+    async fn get_state_nodes(
         &self,
-        filter: SyncStateFilterView,
-        // exclusive cursor if `Some`, otherwise start from the beginning
-        cursor: Option<StrView<u64>>,
-        limit: Option<StrView<u64>>,
-        query_option: Option<QueryOptions>,
-    ) -> RpcResult<StateChangeSetPageView>;
+        node_hashes: Vec<H256View>,
+    ) -> RpcResult<Vec<Option<BytesView>>>;
 }
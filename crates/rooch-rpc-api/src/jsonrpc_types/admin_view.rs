@@ -0,0 +1,69 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::{H256View, StrView};
+use rooch_types::backup::BackupManifest;
+use rooch_types::indexer::status::{IndexerStatus, IndexerSyncStatus};
+use rooch_types::service_status::ServiceStatus;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+pub type ServiceStatusView = StrView<ServiceStatus>;
+
+impl std::fmt::Display for ServiceStatusView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for ServiceStatusView {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> anyhow::Result<Self, Self::Err> {
+        Ok(StrView(ServiceStatus::from_str(s)?))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BackupManifestView {
+    pub tx_order: StrView<u64>,
+    pub tx_hash: H256View,
+    pub state_root: H256View,
+    pub size: StrView<u64>,
+    pub accumulator_root: H256View,
+    pub indexer_tx_order: Option<StrView<u64>>,
+    pub timestamp: StrView<u64>,
+}
+
+impl From<BackupManifest> for BackupManifestView {
+    fn from(manifest: BackupManifest) -> Self {
+        Self {
+            tx_order: StrView(manifest.tx_order),
+            tx_hash: manifest.tx_hash.into(),
+            state_root: manifest.state_root.into(),
+            size: StrView(manifest.size),
+            accumulator_root: manifest.accumulator_root.into(),
+            indexer_tx_order: manifest.indexer_tx_order.map(StrView),
+            timestamp: StrView(manifest.timestamp),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IndexerStatusView {
+    pub sync_status: IndexerSyncStatus,
+    pub last_indexed_tx_order: Option<StrView<u64>>,
+    pub target_tx_order: Option<StrView<u64>>,
+    pub error: Option<String>,
+}
+
+impl From<IndexerStatus> for IndexerStatusView {
+    fn from(status: IndexerStatus) -> Self {
+        Self {
+            sync_status: status.sync_status,
+            last_indexed_tx_order: status.last_indexed_tx_order.map(StrView),
+            target_tx_order: status.target_tx_order.map(StrView),
+            error: status.error,
+        }
+    }
+}
@@ -2,5 +2,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod ord;
+pub mod runes;
 pub mod transaction;
 pub mod utxo;
@@ -1,9 +1,13 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::jsonrpc_types::StrView;
+use super::utxo::OutPointView;
+use crate::jsonrpc_types::{BytesView, StrView};
 use anyhow::Result;
-use bitcoin::Txid;
+use bitcoin::consensus::Encodable;
+use bitcoin::{Transaction, Txid};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
 
@@ -28,6 +32,48 @@ impl From<TxidView> for Txid {
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct PendingTransactionView {
+    pub txid: TxidView,
+    /// The consensus encoded bytes of the transaction
+    pub raw_tx: BytesView,
+    /// The outpoints spent by the transaction
+    pub inputs: Vec<OutPointView>,
+    /// The virtual size of the transaction
+    pub vsize: StrView<u64>,
+    /// Where the node found the transaction, `broadcast` or `mempool`
+    pub source: String,
+    /// The timestamp in milliseconds when the node found the transaction
+    pub timestamp: StrView<u64>,
+}
+
+impl PendingTransactionView {
+    pub fn new(tx: &Transaction, source: String, timestamp: u64) -> Self {
+        let mut raw_tx = vec![];
+        tx.consensus_encode(&mut raw_tx)
+            .expect("encode tx should success");
+        PendingTransactionView {
+            txid: tx.compute_txid().into(),
+            raw_tx: raw_tx.into(),
+            inputs: tx
+                .input
+                .iter()
+                .map(|input| OutPointView {
+                    txid: input.previous_output.txid.into(),
+                    vout: input.previous_output.vout,
+                })
+                .collect(),
+            vsize: (tx.vsize() as u64).into(),
+            source,
+            timestamp: timestamp.into(),
+        }
+    }
+
+    pub fn transaction(&self) -> Result<Transaction> {
+        Ok(bitcoin::consensus::deserialize(&self.raw_tx.0)?)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -44,4 +90,34 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_pending_transaction_view() -> Result<()> {
+        let txid_str = "5fddcbdc3eb21a93e8dd1dd3f9087c3677f422b82d5ba39a6b1ec37338154af6";
+        let tx = Transaction {
+            version: bitcoin::transaction::Version::TWO,
+            lock_time: bitcoin::absolute::LockTime::ZERO,
+            input: vec![bitcoin::TxIn {
+                previous_output: bitcoin::OutPoint::new(Txid::from_str(txid_str)?, 1),
+                script_sig: bitcoin::ScriptBuf::new(),
+                sequence: bitcoin::Sequence::ENABLE_RBF_NO_LOCKTIME,
+                witness: bitcoin::Witness::new(),
+            }],
+            output: vec![bitcoin::TxOut {
+                value: bitcoin::Amount::from_sat(1000),
+                script_pubkey: bitcoin::ScriptBuf::new(),
+            }],
+        };
+        let view = PendingTransactionView::new(&tx, "broadcast".to_string(), 1);
+        assert_eq!(view.txid.0, tx.compute_txid());
+        assert_eq!(view.inputs.len(), 1);
+        assert_eq!(view.inputs[0].txid.to_string(), txid_str);
+        assert_eq!(view.inputs[0].vout, 1);
+        assert_eq!(view.transaction()?, tx);
+
+        let json = serde_json::to_string(&view)?;
+        let view2: PendingTransactionView = serde_json::from_str(&json)?;
+        assert_eq!(view2.transaction()?, tx);
+        Ok(())
+    }
 }
@@ -136,6 +136,8 @@ impl From<TransactionSequenceInfo> for TransactionSequenceInfoView {
 pub struct TransactionExecutionInfoView {
     pub tx_hash: H256View,
     pub state_root: H256View,
+    /// The root Object count of the state after this transaction
+    pub size: StrView<u64>,
     pub event_root: H256View,
     pub gas_used: StrView<u64>,
     pub status: KeptVMStatusView,
@@ -145,6 +147,7 @@ impl TransactionExecutionInfoView {
     fn new(
         tx_hash: H256,
         state_root: H256,
+        size: u64,
         event_root: H256,
         gas_used: StrView<u64>,
         status: KeptVMStatusView,
@@ -152,6 +155,7 @@ impl TransactionExecutionInfoView {
         Self {
             tx_hash: tx_hash.into(),
             state_root: state_root.into(),
+            size: size.into(),
             event_root: event_root.into(),
             gas_used,
             status,
@@ -164,6 +168,7 @@ impl From<TransactionExecutionInfo> for TransactionExecutionInfoView {
         Self {
             tx_hash: transaction_execution_info.tx_hash.into(),
             state_root: transaction_execution_info.state_root.into(),
+            size: transaction_execution_info.size.into(),
             event_root: transaction_execution_info.event_root.into(),
             gas_used: transaction_execution_info.gas_used.into(),
             status: KeptVMStatusView::from(transaction_execution_info.status),
@@ -259,6 +264,7 @@ impl From<DryRunTransactionResponseView> for ExecuteTransactionResponseView {
             execution_info: TransactionExecutionInfoView::new(
                 H256::random(),
                 H256::random(),
+                u64::MIN,
                 H256::random(),
                 response.raw_output.gas_used,
                 response.raw_output.status.clone(),
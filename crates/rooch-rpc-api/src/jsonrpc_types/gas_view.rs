@@ -0,0 +1,116 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::{KeptVMStatusView, StrView};
+use moveos_types::transaction::{RawTransactionOutput, VMErrorInfo};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The padding percent of the recommended max gas amount,
+/// the gas used may change if the state changes between the estimation and the execution
+pub const GAS_ESTIMATE_PADDING_PERCENT: u64 = 20;
+
+/// The gas price suggestion of the fee market
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GasPriceView {
+    /// The current base fee per gas
+    pub base_fee: StrView<u64>,
+    /// The suggested priority fee(tip) per gas, it is above all the ready transactions in the txpool when there is a backlog
+    pub priority_fee: StrView<u64>,
+    /// The suggested max gas price, it leaves room for the base fee to rise in the next blocks
+    pub max_gas_price: StrView<u64>,
+    /// The number of the transactions in the txpool which are waiting to be executed
+    pub backlog: StrView<u64>,
+}
+
+impl GasPriceView {
+    pub fn new(base_fee: u64, max_backlog_priority_fee: Option<u64>, backlog: u64) -> Self {
+        let priority_fee = max_backlog_priority_fee
+            .map(|fee| fee.saturating_add(1))
+            .unwrap_or_default();
+        Self {
+            base_fee: base_fee.into(),
+            priority_fee: priority_fee.into(),
+            max_gas_price: base_fee
+                .saturating_mul(2)
+                .saturating_add(priority_fee)
+                .into(),
+            backlog: backlog.into(),
+        }
+    }
+}
+
+/// The gas estimation of a transaction, it is the result of a dry run with a high max gas amount
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GasEstimateView {
+    pub status: KeptVMStatusView,
+    pub gas_used: StrView<u64>,
+    pub execution_gas_used: StrView<u64>,
+    /// The gas for writing the transaction bytes
+    pub io_gas_used: StrView<u64>,
+    /// The gas of the global storage operations and the object storage natives
+    pub storage_gas_used: StrView<u64>,
+    /// The gas used with padding, not more than the max gas amount of the dry run
+    pub recommended_max_gas_amount: StrView<u64>,
+    pub vm_error_info: Option<VMErrorInfo>,
+}
+
+impl GasEstimateView {
+    pub fn new(
+        raw_output: &RawTransactionOutput,
+        vm_error_info: Option<VMErrorInfo>,
+        max_gas_amount: u64,
+    ) -> Self {
+        let gas_used = raw_output.gas_used;
+        let breakdown = raw_output.gas_used_breakdown;
+        let recommended_max_gas_amount = gas_used
+            .saturating_add(gas_used.saturating_mul(GAS_ESTIMATE_PADDING_PERCENT) / 100)
+            .min(max_gas_amount);
+        Self {
+            status: raw_output.status.clone().into(),
+            gas_used: gas_used.into(),
+            execution_gas_used: breakdown.execution_gas_used.into(),
+            io_gas_used: breakdown.io_gas_used.into(),
+            storage_gas_used: breakdown.storage_gas_used.into(),
+            recommended_max_gas_amount: recommended_max_gas_amount.into(),
+            vm_error_info,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_core_types::vm_status::KeptVMStatus;
+    use moveos_types::state::StateChangeSet;
+    use moveos_types::transaction::GasUsedBreakdown;
+
+    fn raw_output(gas_used: u64) -> RawTransactionOutput {
+        RawTransactionOutput {
+            status: KeptVMStatus::Executed,
+            changeset: StateChangeSet::default(),
+            events: vec![],
+            gas_used,
+            gas_used_breakdown: GasUsedBreakdown {
+                execution_gas_used: gas_used / 2,
+                io_gas_used: gas_used - gas_used / 2,
+                storage_gas_used: 0,
+            },
+            is_upgrade: false,
+            is_gas_upgrade: false,
+        }
+    }
+
+    #[test]
+    fn test_gas_estimate_padding() {
+        let estimation = GasEstimateView::new(&raw_output(1000), None, 10000);
+        assert_eq!(estimation.gas_used.0, 1000);
+        assert_eq!(estimation.execution_gas_used.0, 500);
+        assert_eq!(estimation.io_gas_used.0, 500);
+        assert_eq!(estimation.recommended_max_gas_amount.0, 1200);
+
+        // The recommended max gas amount does not exceed the max gas amount of the dry run
+        let estimation = GasEstimateView::new(&raw_output(1000), None, 1100);
+        assert_eq!(estimation.recommended_max_gas_amount.0, 1100);
+    }
+}
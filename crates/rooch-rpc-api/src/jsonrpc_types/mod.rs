@@ -17,13 +17,16 @@ mod tests;
 mod transaction_argument_view;
 
 pub mod account_view;
+pub mod admin_view;
 pub mod event_view;
 pub mod export_view;
+pub mod gas_view;
 pub mod transaction_view;
 
 pub mod address;
 pub mod btc;
 pub mod repair_view;
+pub mod session_key_view;
 
 pub use self::rooch_types::*;
 pub use address::*;
@@ -1,10 +1,10 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use super::event_view::IndexerEventIDView;
 use super::{HumanReadableDisplay, IndexerStateIDView, StateChangeSetWithTxOrderView};
 use crate::jsonrpc_types::account_view::BalanceInfoView;
 use crate::jsonrpc_types::btc::ord::InscriptionStateView;
+use crate::jsonrpc_types::btc::runes::RuneBalancesStateView;
 use crate::jsonrpc_types::btc::utxo::UTXOStateView;
 use crate::jsonrpc_types::event_view::{EventView, IndexerEventView};
 use crate::jsonrpc_types::transaction_view::TransactionWithInfoView;
@@ -14,21 +14,29 @@ use crate::jsonrpc_types::{
 };
 use move_core_types::u256::U256;
 use rooch_types::framework::coin::CoinInfo;
+use rooch_types::indexer::cursor::PageCursor;
 use rooch_types::transaction::rooch::RoochTransaction;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::string::String;
 
+impl_str_view_for! {PageCursor}
+
+/// The opaque cursor of the indexer query APIs
+pub type PageCursorView = StrView<PageCursor>;
+
 pub type EventPageView = PageView<EventView, StrView<u64>>;
 pub type TransactionWithInfoPageView = PageView<TransactionWithInfoView, StrView<u64>>;
+pub type IndexerTransactionPageView = PageView<TransactionWithInfoView, PageCursorView>;
 pub type StatePageView = PageView<StateKVView, String>;
 pub type BalanceInfoPageView = PageView<BalanceInfoView, IndexerStateIDView>;
-pub type IndexerEventPageView = PageView<IndexerEventView, IndexerEventIDView>;
+pub type IndexerEventPageView = PageView<IndexerEventView, PageCursorView>;
 
-pub type IndexerObjectStatePageView = PageView<IndexerObjectStateView, IndexerStateIDView>;
+pub type IndexerObjectStatePageView = PageView<IndexerObjectStateView, PageCursorView>;
 
-pub type UTXOPageView = PageView<UTXOStateView, IndexerStateIDView>;
-pub type InscriptionPageView = PageView<InscriptionStateView, IndexerStateIDView>;
+pub type UTXOPageView = PageView<UTXOStateView, PageCursorView>;
+pub type InscriptionPageView = PageView<InscriptionStateView, PageCursorView>;
+pub type RuneBalancesPageView = PageView<RuneBalancesStateView, PageCursorView>;
 pub type StateChangeSetPageView = PageView<StateChangeSetWithTxOrderView, StrView<u64>>;
 
 /// `next_cursor` points to the last item in the page;
@@ -71,6 +79,7 @@ pub struct TransactionView {
     pub sequence_number: StrView<u64>,
     pub sender: String,
     pub sender_bitcoin_address: Option<String>,
+    pub fee_payer: Option<String>,
     pub action_type: MoveActionTypeView,
     pub action: MoveActionView,
     pub raw: BytesView,
@@ -85,6 +94,9 @@ impl TransactionView {
             sequence_number: transaction.sequence_number().into(),
             sender: transaction.sender().to_string(),
             sender_bitcoin_address,
+            fee_payer: transaction
+                .fee_payer()
+                .map(|fee_payer| fee_payer.to_string()),
             action: transaction.action().clone().into(),
             action_type: transaction.action().clone().into(),
             raw: transaction.encode().into(),
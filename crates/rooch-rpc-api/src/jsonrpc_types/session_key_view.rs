@@ -0,0 +1,44 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::{BytesView, StrView};
+use rooch_types::framework::session_key::SessionKey;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SessionKeyView {
+    pub app_name: String,
+    pub app_url: String,
+    pub authentication_key: BytesView,
+    /// The session scopes, format: address::module_name::function_name
+    pub scopes: Vec<String>,
+    /// The create time of the session key, in seconds
+    pub create_time: StrView<u64>,
+    /// The last active time of the session key, in seconds
+    pub last_active_time: StrView<u64>,
+    /// The max inactive interval of the session key, in seconds, 0 means never expire
+    pub max_inactive_interval: StrView<u64>,
+    /// Whether the session key is expired at the current on-chain time
+    pub expired: bool,
+}
+
+impl SessionKeyView {
+    pub fn new(session_key: SessionKey, now_seconds: u64) -> Self {
+        let expired = session_key.is_expired_at(now_seconds);
+        Self {
+            app_name: session_key.app_name.to_string(),
+            app_url: session_key.app_url.to_string(),
+            authentication_key: StrView(session_key.authentication_key),
+            scopes: session_key
+                .scopes
+                .iter()
+                .map(|scope| scope.to_string())
+                .collect(),
+            create_time: StrView(session_key.create_time),
+            last_active_time: StrView(session_key.last_active_time),
+            max_inactive_interval: StrView(session_key.max_inactive_interval),
+            expired,
+        }
+    }
+}
@@ -15,6 +15,7 @@ use moveos_types::{
     moveos_std::object::{human_readable_flag, ObjectID},
     state::{AnnotatedState, ObjectState, StateChangeSet},
 };
+use rooch_types::indexer::custom_index::CustomIndexFilter;
 use rooch_types::indexer::state::{IndexerStateID, ObjectStateFilter};
 use rooch_types::state::{StateChangeSetWithTxOrder, SyncStateFilter};
 use schemars::JsonSchema;
@@ -381,6 +382,16 @@ pub enum ObjectStateFilterView {
     Owner(UnitedAddressView),
     /// Query by object ids.
     ObjectId(ObjectIDVecView),
+    /// Query by a custom index configured in the indexer, the objects are ordered by the indexed field value.
+    /// The bounds are parsed by the type of the indexed field, the bounds of the integer fields are compared numerically.
+    CustomIndex {
+        object_type: StructTagView,
+        field_path: String,
+        /// The inclusive lower bound of the field value
+        from: Option<String>,
+        /// The exclusive upper bound of the field value
+        to: Option<String>,
+    },
 }
 
 impl ObjectStateFilterView {
@@ -403,6 +414,17 @@ impl ObjectStateFilterView {
             ObjectStateFilterView::ObjectId(object_id_vec_view) => {
                 ObjectStateFilter::ObjectId(object_id_vec_view.into())
             }
+            ObjectStateFilterView::CustomIndex {
+                object_type,
+                field_path,
+                from,
+                to,
+            } => ObjectStateFilter::CustomIndex(CustomIndexFilter::new(
+                object_type.into(),
+                field_path,
+                from,
+                to,
+            )),
         })
     }
 }
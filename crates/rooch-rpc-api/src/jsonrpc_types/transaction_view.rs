@@ -7,9 +7,10 @@ use crate::jsonrpc_types::{
     UnitedAddressView,
 };
 use bitcoin::hashes::Hash;
+use moveos_types::h256::H256;
 use rooch_types::indexer::transaction::TransactionFilter;
 use rooch_types::transaction::{
-    L1Block, L1Transaction, LedgerTransaction, LedgerTxData, TransactionWithInfo,
+    L1Block, L1Transaction, LedgerTransaction, LedgerTxData, RoochTransaction, TransactionWithInfo,
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -182,3 +183,30 @@ impl From<TransactionFilterView> for TransactionFilter {
         }
     }
 }
+
+/// A transaction waiting in the txpool for the previous sequence numbers of its sender
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TxPoolTransactionView {
+    pub tx_hash: H256View,
+    pub transaction: TransactionView,
+    /// The time when the transaction is added to the txpool, in milliseconds
+    pub timestamp: StrView<u64>,
+}
+
+impl TxPoolTransactionView {
+    pub fn new(
+        tx_hash: H256,
+        transaction: RoochTransaction,
+        timestamp: u64,
+        sender_bitcoin_address: Option<String>,
+    ) -> Self {
+        Self {
+            tx_hash: tx_hash.into(),
+            transaction: TransactionView::new_from_rooch_transaction(
+                transaction,
+                sender_bitcoin_address,
+            ),
+            timestamp: timestamp.into(),
+        }
+    }
+}
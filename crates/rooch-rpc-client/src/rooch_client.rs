@@ -14,11 +14,15 @@ use moveos_types::{access_path::AccessPath, state::ObjectState, transaction::Fun
 use rooch_rpc_api::api::btc_api::BtcAPIClient;
 use rooch_rpc_api::api::rooch_api::RoochAPIClient;
 use rooch_rpc_api::jsonrpc_types::btc::ord::{InscriptionFilterView, InscriptionObjectView};
+use rooch_rpc_api::jsonrpc_types::btc::runes::RuneFilterView;
+use rooch_rpc_api::jsonrpc_types::btc::transaction::PendingTransactionView;
 use rooch_rpc_api::jsonrpc_types::btc::utxo::{UTXOFilterView, UTXOObjectView};
+use rooch_rpc_api::jsonrpc_types::gas_view::GasEstimateView;
+use rooch_rpc_api::jsonrpc_types::session_key_view::SessionKeyView;
 use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionFilterView;
 use rooch_rpc_api::jsonrpc_types::{
     account_view::BalanceInfoView, transaction_view::TransactionWithInfoView,
-    DryRunTransactionResponseView, InscriptionPageView, UTXOPageView,
+    DryRunTransactionResponseView, InscriptionPageView, RuneBalancesPageView, UTXOPageView,
 };
 use rooch_rpc_api::jsonrpc_types::{
     AccessPathView, AnnotatedFunctionResultView, BalanceInfoPageView, BytesView, EventOptions,
@@ -27,12 +31,13 @@ use rooch_rpc_api::jsonrpc_types::{
 };
 use rooch_rpc_api::jsonrpc_types::{ExecuteTransactionResponseView, ObjectStateView};
 use rooch_rpc_api::jsonrpc_types::{
-    IndexerObjectStatePageView, ObjectStateFilterView, QueryOptions,
+    IndexerObjectStatePageView, IndexerTransactionPageView, ObjectStateFilterView, QueryOptions,
 };
 use rooch_rpc_api::jsonrpc_types::{TransactionWithInfoPageView, TxOptions};
 use rooch_types::address::BitcoinAddress;
 use rooch_types::bitcoin::multisign_account::MultisignAccountInfo;
 use rooch_types::framework::address_mapping::RoochToBitcoinAddressMapping;
+use rooch_types::indexer::cursor::PageCursor;
 use rooch_types::indexer::state::IndexerStateID;
 use rooch_types::transaction::RoochTransactionData;
 use rooch_types::{address::RoochAddress, transaction::rooch::RoochTransaction};
@@ -61,7 +66,7 @@ impl RoochRpcClient {
         tx: RoochTransaction,
         tx_option: Option<TxOptions>,
     ) -> Result<ExecuteTransactionResponseView> {
-        let tx_payload = bcs::to_bytes(&tx)?;
+        let tx_payload = tx.encode();
         self.http
             .execute_raw_transaction(tx_payload.into(), tx_option)
             .await
@@ -72,13 +77,21 @@ impl RoochRpcClient {
         &self,
         tx: RoochTransactionData,
     ) -> Result<DryRunTransactionResponseView> {
-        let tx_payload = bcs::to_bytes(&tx)?;
+        let tx_payload = tx.encode();
         self.http
             .dry_run(tx_payload.into())
             .await
             .map_err(|e| anyhow::anyhow!(e))
     }
 
+    pub async fn estimate_gas(&self, tx: RoochTransactionData) -> Result<GasEstimateView> {
+        let tx_payload = tx.encode();
+        self.http
+            .estimate_gas(tx_payload.into())
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
     pub async fn execute_view_function(
         &self,
         function_call: FunctionCall,
@@ -146,13 +159,23 @@ impl RoochRpcClient {
             .await?)
     }
 
+    pub async fn get_state_nodes(&self, node_hashes: Vec<H256>) -> Result<Vec<Option<Vec<u8>>>> {
+        Ok(self
+            .http
+            .get_state_nodes(node_hashes.into_iter().map(Into::into).collect())
+            .await?
+            .into_iter()
+            .map(|node| node.map(|node| node.0))
+            .collect())
+    }
+
     pub async fn query_transactions(
         &self,
         filter: TransactionFilterView,
-        cursor: Option<u64>,
+        cursor: Option<PageCursor>,
         limit: Option<u64>,
         query_options: Option<QueryOptions>,
-    ) -> Result<TransactionWithInfoPageView> {
+    ) -> Result<IndexerTransactionPageView> {
         Ok(self
             .http
             .query_transactions(
@@ -311,6 +334,13 @@ impl RoochRpcClient {
             .await?)
     }
 
+    pub async fn get_session_keys(
+        &self,
+        account_addr: RoochAddressView,
+    ) -> Result<Vec<SessionKeyView>> {
+        Ok(self.http.get_session_keys(account_addr.into()).await?)
+    }
+
     pub async fn get_object_states(
         &self,
         object_ids: Vec<ObjectID>,
@@ -328,7 +358,7 @@ impl RoochRpcClient {
     pub async fn query_object_states(
         &self,
         filter: ObjectStateFilterView,
-        cursor: Option<IndexerStateID>,
+        cursor: Option<PageCursor>,
         limit: Option<u64>,
         query_options: Option<QueryOptions>,
     ) -> Result<IndexerObjectStatePageView> {
@@ -346,7 +376,7 @@ impl RoochRpcClient {
     pub async fn query_utxos(
         &self,
         filter: UTXOFilterView,
-        cursor: Option<IndexerStateID>,
+        cursor: Option<PageCursor>,
         limit: Option<u64>,
         descending_order: Option<bool>,
     ) -> Result<UTXOPageView> {
@@ -364,7 +394,7 @@ impl RoochRpcClient {
     pub async fn query_inscriptions(
         &self,
         filter: InscriptionFilterView,
-        cursor: Option<IndexerStateID>,
+        cursor: Option<PageCursor>,
         limit: Option<u64>,
         query_options: Option<QueryOptions>,
     ) -> Result<InscriptionPageView> {
@@ -379,6 +409,24 @@ impl RoochRpcClient {
             .await?)
     }
 
+    pub async fn query_runes(
+        &self,
+        filter: RuneFilterView,
+        cursor: Option<PageCursor>,
+        limit: Option<u64>,
+        query_options: Option<QueryOptions>,
+    ) -> Result<RuneBalancesPageView> {
+        Ok(self
+            .http
+            .query_runes(
+                filter,
+                cursor.map(Into::into),
+                limit.map(Into::into),
+                query_options.map(|v| v.descending),
+            )
+            .await?)
+    }
+
     pub async fn get_resource<T: MoveStructState>(
         &self,
         account: RoochAddress,
@@ -424,6 +472,10 @@ impl RoochRpcClient {
             .await?)
     }
 
+    pub async fn query_pending_bitcoin_transactions(&self) -> Result<Vec<PendingTransactionView>> {
+        Ok(self.http.query_pending_transactions().await?)
+    }
+
     pub async fn get_utxo_object(&self, utxo_obj_id: ObjectID) -> Result<Option<UTXOObjectView>> {
         let objects = self.get_object_states(vec![utxo_obj_id], None).await?;
         let obj_state = objects.into_iter().next().flatten();
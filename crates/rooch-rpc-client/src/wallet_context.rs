@@ -215,14 +215,32 @@ impl WalletContext {
                 .map_err(RoochError::from)?,
         );
         log::debug!("use sequence_number: {}", sequence_number);
-        //TODO max gas amount from cli option or dry run estimate
-        let tx_data = RoochTransactionData::new(
+        let mut tx_data = RoochTransactionData::new(
             sender,
             sequence_number,
             chain_id,
             max_gas_amount.unwrap_or(GasScheduleConfig::CLI_DEFAULT_MAX_GAS_AMOUNT),
             action,
         );
+        // Estimate the max gas amount if the user does not specify it,
+        // fallback to the default max gas amount if the estimation fails, so the execution reports the error.
+        if max_gas_amount.is_none() {
+            match client.rooch.estimate_gas(tx_data.clone()).await {
+                Ok(estimation) if estimation.status == KeptVMStatusView::Executed => {
+                    tx_data.max_gas_amount = estimation.recommended_max_gas_amount.0;
+                    log::debug!(
+                        "use estimated max_gas_amount: {}, gas_used: {}",
+                        tx_data.max_gas_amount,
+                        estimation.gas_used
+                    );
+                }
+                Ok(estimation) => log::debug!(
+                    "estimate gas failed with status {:?}, use default max_gas_amount",
+                    estimation.status
+                ),
+                Err(e) => log::debug!("estimate gas failed: {}, use default max_gas_amount", e),
+            }
+        }
         Ok(tx_data)
     }
 
@@ -0,0 +1,358 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use accumulator::accumulator_info::AccumulatorInfo;
+use anyhow::{anyhow, bail, ensure, Result};
+use lru::LruCache;
+use moveos_types::h256::H256;
+use moveos_types::startup_info::StartupInfo;
+use rooch_db::RoochDB;
+use rooch_genesis::RoochGenesis;
+use rooch_rpc_api::api::MAX_RESULT_LIMIT_USIZE;
+use rooch_rpc_client::{Client, ClientBuilder};
+use rooch_types::rooch_network::RoochNetwork;
+use rooch_types::sequencer::SequencerInfo;
+use smt::NodeReader;
+use std::collections::{HashSet, VecDeque};
+use std::num::NonZeroUsize;
+use std::sync::{mpsc, Arc};
+use tracing::{info, warn};
+
+/// The max number of remote transactions to scan when searching the `--fork-state-root`
+const FORK_STATE_ROOT_SEARCH_LIMIT: u64 = 10000;
+const FORK_TX_PAGE_SIZE: u64 = 100;
+/// The max number of the prefetched state nodes kept in memory
+const FORK_NODE_PREFETCH_CACHE_SIZE: usize = 100_000;
+
+struct NodeRequest {
+    hash: H256,
+    responder: mpsc::Sender<Result<Option<Vec<u8>>>>,
+}
+
+/// Read the missing state tree nodes from the remote node via `rooch_getStateNodes`.
+/// The requests are served by a dedicated thread with its own runtime, so the reader can be called
+/// from both sync and async contexts. The queued requests are fetched in one batch, and the batch is
+/// filled up with the children of the fetched internal nodes, which are usually read next.
+pub struct ClientNodeReader {
+    sender: mpsc::Sender<NodeRequest>,
+}
+
+impl ClientNodeReader {
+    pub async fn new(fork_url: &str) -> Result<Self> {
+        let fork_url = fork_url.to_string();
+        let (sender, receiver) = mpsc::channel::<NodeRequest>();
+        let (init_sender, init_receiver) = tokio::sync::oneshot::channel();
+        std::thread::Builder::new()
+            .name("fork-node-reader".to_string())
+            .spawn(move || {
+                let init = tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .map_err(anyhow::Error::from)
+                    .and_then(|runtime| {
+                        let client = runtime.block_on(ClientBuilder::default().build(&fork_url))?;
+                        Ok((runtime, client))
+                    });
+                let (runtime, client) = match init {
+                    Ok(init) => {
+                        let _ = init_sender.send(Ok(()));
+                        init
+                    }
+                    Err(e) => {
+                        let _ = init_sender.send(Err(e));
+                        return;
+                    }
+                };
+                serve_node_requests(receiver, |hashes| {
+                    runtime.block_on(client.rooch.get_state_nodes(hashes))
+                });
+            })?;
+        init_receiver
+            .await
+            .map_err(|_| anyhow!("The fork node reader exited unexpectedly"))??;
+        Ok(Self { sender })
+    }
+}
+
+impl NodeReader for ClientNodeReader {
+    fn get(&self, hash: &H256) -> Result<Option<Vec<u8>>> {
+        let (responder, response) = mpsc::channel();
+        self.sender
+            .send(NodeRequest {
+                hash: *hash,
+                responder,
+            })
+            .map_err(|_| anyhow!("The fork node reader is stopped"))?;
+        response
+            .recv()
+            .map_err(|_| anyhow!("The fork node reader is stopped"))?
+    }
+}
+
+/// Serve the node requests until all the readers are dropped.
+fn serve_node_requests<F>(receiver: mpsc::Receiver<NodeRequest>, mut fetch: F)
+where
+    F: FnMut(Vec<H256>) -> Result<Vec<Option<Vec<u8>>>>,
+{
+    let cache_size =
+        NonZeroUsize::new(FORK_NODE_PREFETCH_CACHE_SIZE).expect("The cache size must not be zero");
+    let mut prefetched: LruCache<H256, Vec<u8>> = LruCache::new(cache_size);
+    let mut prefetch_queue: VecDeque<H256> = VecDeque::new();
+
+    while let Ok(request) = receiver.recv() {
+        let mut requests = vec![request];
+        requests.extend(
+            receiver
+                .try_iter()
+                .take(MAX_RESULT_LIMIT_USIZE.saturating_sub(1)),
+        );
+
+        let mut pending = vec![];
+        for request in requests {
+            match prefetched.pop(&request.hash) {
+                Some(node) => {
+                    let _ = request.responder.send(Ok(Some(node)));
+                }
+                None => pending.push(request),
+            }
+        }
+        if pending.is_empty() {
+            continue;
+        }
+
+        let mut hashes = vec![];
+        let mut seen = HashSet::new();
+        for request in pending.iter() {
+            if seen.insert(request.hash) {
+                hashes.push(request.hash);
+            }
+        }
+        let requested = hashes.len();
+        while hashes.len() < MAX_RESULT_LIMIT_USIZE {
+            let Some(hash) = prefetch_queue.pop_front() else {
+                break;
+            };
+            if !prefetched.contains(&hash) && seen.insert(hash) {
+                hashes.push(hash);
+            }
+        }
+
+        let nodes = fetch(hashes.clone()).and_then(|nodes| {
+            ensure!(
+                nodes.len() == hashes.len(),
+                "The fork node returned {} state nodes for {} hashes",
+                nodes.len(),
+                hashes.len()
+            );
+            Ok(nodes)
+        });
+        let nodes = match nodes {
+            Ok(nodes) => nodes,
+            Err(e) => {
+                warn!(
+                    "Failed to fetch the state nodes from the fork node: {:?}",
+                    e
+                );
+                for request in pending {
+                    let _ = request.responder.send(Err(anyhow!(
+                        "Failed to fetch the state node {:?} from the fork node: {}",
+                        request.hash,
+                        e
+                    )));
+                }
+                continue;
+            }
+        };
+
+        let mut fetched = hashes.into_iter().zip(nodes).collect::<Vec<_>>();
+        for (_, node) in fetched.iter() {
+            if let Some(node) = node {
+                // The children of the newly fetched nodes are the most likely to be read next.
+                for child in smt::node_children(node)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .rev()
+                {
+                    prefetch_queue.push_front(child);
+                }
+            }
+        }
+        prefetch_queue.truncate(FORK_NODE_PREFETCH_CACHE_SIZE);
+        for (hash, node) in fetched.split_off(requested) {
+            if let Some(node) = node {
+                prefetched.put(hash, node);
+            }
+        }
+        for request in pending {
+            let node = fetched
+                .iter()
+                .find(|(hash, _)| *hash == request.hash)
+                .and_then(|(_, node)| node.clone());
+            let _ = request.responder.send(Ok(node));
+        }
+    }
+}
+
+/// Init the local store to fork the remote node state.
+/// The state tree nodes are lazily fetched from the remote node and cached in the local store,
+/// the transactions, events and indexer data before the fork point are not available locally.
+pub async fn init_fork(
+    fork_url: &str,
+    fork_state_root: Option<H256>,
+    network: &RoochNetwork,
+    rooch_db: &RoochDB,
+) -> Result<()> {
+    let client = ClientBuilder::default().build(fork_url).await?;
+    let remote_chain_id = client.rooch.get_chain_id().await?;
+    ensure!(
+        remote_chain_id == network.chain_id.id,
+        "The chain id of the fork node({}) is not equal to the local chain id({}), please start the server with --chain-id {}",
+        remote_chain_id,
+        network.chain_id.id,
+        remote_chain_id
+    );
+
+    rooch_db
+        .moveos_store
+        .get_state_node_store()
+        .set_remote_reader(Arc::new(ClientNodeReader::new(fork_url).await?))?;
+
+    if let Some(root) = rooch_db.latest_root()? {
+        info!(
+            "Resume the fork of {} from local state root: {:?}",
+            fork_url,
+            root.state_root()
+        );
+        return Ok(());
+    }
+
+    let startup_info = resolve_fork_point(&client, fork_state_root).await?;
+    info!(
+        "Fork the state of {} at state root: {:?}, size: {}",
+        fork_url, startup_info.state_root, startup_info.size
+    );
+
+    // The genesis is the same as the remote node, save it to skip the genesis init.
+    let genesis = RoochGenesis::load_or_build(network.clone())?;
+    rooch_db
+        .moveos_store
+        .get_config_store()
+        .save_genesis(genesis.genesis_info())?;
+    rooch_db
+        .moveos_store
+        .get_config_store()
+        .save_startup_info(startup_info)?;
+    // The local transactions start from order 1 on top of the fork point.
+    rooch_db
+        .rooch_store
+        .save_sequencer_info(SequencerInfo::new(0, AccumulatorInfo::default()))?;
+    Ok(())
+}
+
+async fn resolve_fork_point(client: &Client, fork_state_root: Option<H256>) -> Result<StartupInfo> {
+    let mut cursor = None;
+    let mut scanned = 0u64;
+    loop {
+        let page = client
+            .rooch
+            .get_transactions_by_order(cursor, Some(FORK_TX_PAGE_SIZE), Some(true))
+            .await?;
+        for tx in page.data.iter() {
+            let Some(execution_info) = &tx.execution_info else {
+                continue;
+            };
+            let state_root = execution_info.state_root.0;
+            match fork_state_root {
+                Some(fork_state_root) if fork_state_root != state_root => {}
+                _ => return Ok(StartupInfo::new(state_root, execution_info.size.0)),
+            }
+        }
+        scanned += page.data.len() as u64;
+        if !page.has_next_page || scanned >= FORK_STATE_ROOT_SEARCH_LIMIT {
+            break;
+        }
+        cursor = page.next_cursor.map(|cursor| cursor.0);
+    }
+    match fork_state_root {
+        Some(fork_state_root) => bail!(
+            "Can not find the state root {:?} in the latest {} transactions of the fork node",
+            fork_state_root,
+            scanned
+        ),
+        None => bail!("No executed transaction found in the fork node"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use smt::{InMemoryNodeStore, SMTree, SPARSE_MERKLE_PLACEHOLDER_HASH};
+    use std::sync::Mutex;
+
+    fn spawn_reader<F>(fetch: F) -> ClientNodeReader
+    where
+        F: FnMut(Vec<H256>) -> Result<Vec<Option<Vec<u8>>>> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || serve_node_requests(receiver, fetch));
+        ClientNodeReader { sender }
+    }
+
+    #[test]
+    fn test_batch_queued_requests() {
+        let (sender, receiver) = mpsc::channel();
+        let hashes = vec![H256::random(), H256::random()];
+        let mut responses = vec![];
+        for hash in [hashes[0], hashes[1], hashes[0]] {
+            let (responder, response) = mpsc::channel();
+            sender.send(NodeRequest { hash, responder }).unwrap();
+            responses.push((hash, response));
+        }
+        drop(sender);
+
+        let mut batches = vec![];
+        serve_node_requests(receiver, |batch| {
+            batches.push(batch.clone());
+            Ok(batch.iter().map(|hash| Some(hash.0.to_vec())).collect())
+        });
+        assert_eq!(batches, vec![hashes]);
+        for (hash, response) in responses {
+            assert_eq!(response.recv().unwrap().unwrap(), Some(hash.0.to_vec()));
+        }
+    }
+
+    #[test]
+    fn test_prefetch_children() {
+        let store = InMemoryNodeStore::default();
+        let registry = prometheus::Registry::new();
+        let local: SMTree<H256, String, _> = SMTree::new(store.clone(), &registry);
+        let mut state_root = *SPARSE_MERKLE_PLACEHOLDER_HASH;
+        for i in 0..100 {
+            let change_set = local
+                .put(state_root, H256::random(), i.to_string())
+                .unwrap();
+            store.write_nodes(change_set.nodes).unwrap();
+            state_root = change_set.state_root;
+        }
+
+        let fetch_count = Arc::new(Mutex::new(0));
+        let fetch_count_clone = fetch_count.clone();
+        let remote = SMTree::<H256, String, _>::new(
+            spawn_reader(move |hashes| {
+                *fetch_count_clone.lock().unwrap() += 1;
+                hashes.iter().map(|hash| store.get(hash)).collect()
+            }),
+            &registry,
+        );
+        assert_eq!(remote.dump(state_root).unwrap().len(), 100);
+        // The nodes of the same level are fetched in one batch instead of one request per node.
+        assert!(*fetch_count.lock().unwrap() < 10);
+    }
+
+    #[test]
+    fn test_fetch_error() {
+        let reader = spawn_reader(|_| bail!("connection refused"));
+        let err = reader.get(&H256::random()).unwrap_err();
+        assert!(err.to_string().contains("connection refused"));
+    }
+}
@@ -2,12 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::metrics_server::{init_metrics, start_basic_prometheus_server};
+use crate::server::admin_server::AdminServer;
 use crate::server::btc_server::BtcServer;
 use crate::server::rooch_server::RoochServer;
+use crate::service::admin_auth::admin_auth;
 use crate::service::aggregate_service::AggregateService;
 use crate::service::blocklist::{BlockListLayer, BlocklistConfig};
 use crate::service::error::ErrorHandler;
-use crate::service::metrics::ServiceMetrics;
+use crate::service::metrics::{ServiceMetrics, TxLifecycleMetrics};
 use crate::service::rpc_service::RpcService;
 use anyhow::{ensure, Error, Result};
 use axum::http::{HeaderValue, Method};
@@ -42,6 +44,10 @@ use rooch_rpc_api::api::RoochRpcModule;
 use rooch_rpc_api::RpcError;
 use rooch_sequencer::actor::sequencer::SequencerActor;
 use rooch_sequencer::proxy::SequencerProxy;
+use rooch_txpool::actor::messages::EvictExpiredTxsMessage;
+use rooch_txpool::actor::txpool::TxPoolActor;
+use rooch_txpool::pool::{DEFAULT_MAX_PENDING_PER_SENDER, DEFAULT_TX_EXPIRATION_SECS};
+use rooch_txpool::proxy::TxPoolProxy;
 use rooch_types::address::RoochAddress;
 use rooch_types::error::{GenesisError, RoochError};
 use rooch_types::rooch_network::BuiltinChainID;
@@ -58,16 +64,21 @@ use tokio::sync::broadcast::Sender;
 use tower_governor::{governor::GovernorConfigBuilder, GovernorLayer};
 use tower_http::cors::{AllowOrigin, CorsLayer};
 use tower_http::trace::TraceLayer;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 mod axum_router;
+pub mod fork;
 pub mod metrics_server;
 pub mod server;
 pub mod service;
+pub mod telemetry;
 
 /// This exit code means is that the server failed to start and required human intervention.
 static R_EXIT_CODE_NEED_HELP: i32 = 120;
 
+/// The interval to sync the pending Bitcoin transactions with the Rooch state and the bitcoind mempool.
+const PENDING_BTC_TXS_SYNC_INTERVAL_SECS: u64 = 10;
+
 pub struct ServerHandle {
     shutdown_tx: Sender<()>,
     timers: Vec<Timer>,
@@ -81,6 +92,7 @@ impl ServerHandle {
             timer.stop();
         }
         let _ = self.shutdown_tx.send(());
+        telemetry::shutdown_tracing();
         Ok(())
     }
 }
@@ -172,9 +184,9 @@ pub async fn start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Server
 
 // run json-rpc server
 pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<ServerHandle> {
-    // We may call `start_server` multiple times in testing scenarios
-    // tracing_subscriber can only be inited once.
-    let _ = tracing_subscriber::fmt::try_init();
+    // We may call `start_server` multiple times in testing scenarios,
+    // the tracing subscriber is only inited once, and the OTLP exporter is enabled if the endpoint is set.
+    telemetry::init_tracing(opt.otlp_endpoint.as_deref())?;
 
     //Exit the process when some thread panic
     // take_hook() returns the default hook in case when a custom one is not set
@@ -219,7 +231,10 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
     let service_status = opt.service_status;
 
     let mut network = opt.network();
-    if network.chain_id == BuiltinChainID::Local.chain_id() {
+    if let Some(fork_url) = &opt.fork_url {
+        fork::init_fork(fork_url, opt.fork_state_root, &network, &rooch_db).await?;
+        info!("Fork mode: the indexer only contains the local transactions after the fork point");
+    } else if network.chain_id == BuiltinChainID::Local.chain_id() {
         // local chain use current active account as sequencer account
         let rooch_dao_bitcoin_address = network.mock_genesis_account(&sequencer_keypair)?;
         let rooch_dao_address = rooch_dao_bitcoin_address.to_rooch_address();
@@ -321,7 +336,26 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
     timers.push(proposer_timer);
 
     // Init indexer
-    let indexer_executor = IndexerActor::new(root, indexer_store)?
+    let mut indexer_actor = IndexerActor::new(root, indexer_store, &prometheus_registry)?;
+    // The indexer is not written in the date import mode
+    if !service_status.is_date_import_mode() {
+        indexer_actor = indexer_actor.with_custom_indexes(
+            opt.custom_indexes.clone(),
+            rooch_db.moveos_store.clone(),
+            &indexer_reader,
+        )?;
+    }
+    // The indexer is not written in the date import mode, and the transactions before the fork point
+    // are not available locally in the fork mode, so there is nothing to catch up.
+    if !service_status.is_date_import_mode() && opt.fork_url.is_none() {
+        indexer_actor = indexer_actor.with_catch_up(
+            indexer_reader.clone(),
+            rooch_db.moveos_store.clone(),
+            rooch_db.rooch_store.clone(),
+            &prometheus_registry,
+        );
+    }
+    let indexer_executor = indexer_actor
         .into_actor(Some("Indexer"), &actor_system)
         .await?;
     let indexer_reader_executor = IndexerReaderActor::new(indexer_reader)?
@@ -349,10 +383,31 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         .await?;
     let processor_proxy = PipelineProcessorProxy::new(processor_actor.into());
 
+    // Init txpool
+    let txpool_actor = TxPoolActor::new(
+        executor_proxy.clone(),
+        processor_proxy.clone(),
+        opt.txpool_max_pending_per_sender
+            .unwrap_or(DEFAULT_MAX_PENDING_PER_SENDER),
+        opt.txpool_tx_expiration_secs
+            .unwrap_or(DEFAULT_TX_EXPIRATION_SECS),
+    )
+    .into_actor(Some("TxPool"), &actor_system)
+    .await?;
+    let txpool_proxy = TxPoolProxy::new(txpool_actor.clone().into());
+    let txpool_evict_interval_in_seconds: u64 = 10;
+    let txpool_timer = Timer::start(
+        txpool_actor,
+        Duration::from_secs(txpool_evict_interval_in_seconds),
+        EvictExpiredTxsMessage {},
+    );
+    timers.push(txpool_timer);
+
     let ethereum_relayer_config = opt.ethereum_relayer_config();
     let bitcoin_relayer_config = opt.bitcoin_relayer_config();
 
-    if service_status.is_active()
+    // The relayer is also required by the data import mode to feed the L1 blocks
+    if (service_status.is_active() || service_status.is_date_import_mode())
         && (ethereum_relayer_config.is_some() || bitcoin_relayer_config.is_some())
     {
         let relayer = RelayerActor::new(
@@ -360,7 +415,7 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
             processor_proxy.clone(),
             ethereum_relayer_config,
             bitcoin_relayer_config.clone(),
-            Some(event_actor_ref),
+            Some(event_actor_ref.clone()),
         )
         .await?
         .into_actor(Some("Relayer"), &actor_system)
@@ -374,8 +429,9 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         timers.push(relayer_timer);
     }
 
-    let bitcoin_client_proxy = if service_status.is_active() && bitcoin_relayer_config.is_some() {
-        let bitcoin_config = bitcoin_relayer_config.unwrap();
+    let bitcoin_rpc_config = bitcoin_relayer_config.filter(|config| config.has_rpc());
+    let bitcoin_client_proxy = if service_status.is_active() && bitcoin_rpc_config.is_some() {
+        let bitcoin_config = bitcoin_rpc_config.unwrap();
         let bitcoin_client = BitcoinClientActor::new(
             &bitcoin_config.btc_rpc_url,
             &bitcoin_config.btc_rpc_user_name,
@@ -397,6 +453,7 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         sequencer_proxy,
         indexer_proxy,
         processor_proxy,
+        txpool_proxy,
         bitcoin_client_proxy,
     );
     let aggregate_service = AggregateService::new(rpc_service.clone());
@@ -424,6 +481,33 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
     let (shutdown_tx, mut governor_rx): (broadcast::Sender<()>, broadcast::Receiver<()>) =
         broadcast::channel(16);
 
+    // Rebuild the pending Bitcoin transactions from the bitcoind mempool, then sync them with
+    // the Rooch state and the bitcoind mempool in background, so the UTXO queries do not wait for the bitcoind.
+    if rpc_service.bitcoin_client.is_some() {
+        let rpc_service = rpc_service.clone();
+        let mut pending_btc_txs_rx = shutdown_tx.subscribe();
+        tokio::spawn(async move {
+            if let Err(e) = rpc_service.rebuild_pending_bitcoin_transactions().await {
+                warn!(
+                    "Failed to rebuild the pending Bitcoin transactions from the mempool: {:?}",
+                    e
+                );
+            }
+            let mut interval =
+                tokio::time::interval(Duration::from_secs(PENDING_BTC_TXS_SYNC_INTERVAL_SECS));
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        if let Err(e) = rpc_service.sync_pending_bitcoin_transactions().await {
+                            warn!("Failed to sync the pending Bitcoin transactions: {:?}", e);
+                        }
+                    }
+                    _ = pending_btc_txs_rx.recv() => break,
+                }
+            }
+        });
+    }
+
     let traffic_burst_size: u32;
     let traffic_per_second: u64;
 
@@ -484,6 +568,7 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
     rpc_module_builder.register_module(RoochServer::new(
         rpc_service.clone(),
         aggregate_service.clone(),
+        TxLifecycleMetrics::new(&prometheus_registry),
     ))?;
     rpc_module_builder.register_module(BtcServer::new(rpc_service.clone()).await?)?;
     rpc_module_builder
@@ -494,12 +579,40 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
             )
         })?;
 
+    let mut admin_module_builder = RpcModuleBuilder::new();
+    admin_module_builder.register_module(AdminServer::new(
+        rpc_service.clone(),
+        proposer_proxy,
+        event_actor_ref,
+    ))?;
+
     let methods_names = rpc_module_builder.module.method_names().collect::<Vec<_>>();
+    let admin_methods_names = admin_module_builder
+        .module
+        .method_names()
+        .collect::<Vec<_>>();
+    let service_metrics = ServiceMetrics::new(
+        &prometheus_registry,
+        &[methods_names.as_slice(), admin_methods_names.as_slice()].concat(),
+    );
 
     let ser = axum_router::JsonRpcService::new(
         rpc_module_builder.module.clone().into(),
-        ServiceMetrics::new(&prometheus_registry, &methods_names),
+        service_metrics.clone(),
+    );
+    let admin_ser = axum_router::JsonRpcService::new(
+        admin_module_builder.module.clone().into(),
+        service_metrics,
     );
+    let mut admin_router = axum::Router::new()
+        .route("/", axum::routing::post(axum_router::json_rpc_handler))
+        .with_state(admin_ser);
+    if let Some(admin_token) = opt.admin_token.clone() {
+        admin_router = admin_router.layer(axum::middleware::from_fn_with_state(
+            Arc::new(admin_token),
+            admin_auth,
+        ));
+    }
 
     let mut router = axum::Router::new();
     match opt.service_type {
@@ -534,7 +647,37 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         }
     }
 
-    let app = router.with_state(ser).layer(middleware);
+    let mut app = router.with_state(ser);
+    match opt.admin_port {
+        Some(admin_port) => {
+            let admin_addr: SocketAddr = format!("127.0.0.1:{}", admin_port).parse()?;
+            let admin_listener = tokio::net::TcpListener::bind(&admin_addr).await?;
+            let mut admin_rx = shutdown_tx.subscribe();
+            tokio::spawn(async move {
+                axum::serve(admin_listener, admin_router.into_make_service())
+                    .with_graceful_shutdown(async move {
+                        let _ = admin_rx.recv().await;
+                    })
+                    .await
+                    .unwrap();
+            });
+            info!("Admin JSON-RPC Server start listening {:?}", admin_addr);
+            if opt.admin_token.is_none() {
+                warn!(
+                    "Admin JSON-RPC Server on {:?} is not authenticated, set the admin token to require it",
+                    admin_addr
+                );
+            }
+        }
+        None if opt.admin_token.is_some() => {
+            app = app.nest("/admin", admin_router);
+            info!("Admin JSON-RPC methods are served at /admin");
+        }
+        None => {
+            info!("Admin JSON-RPC is disabled, set the admin port or admin token to enable it");
+        }
+    }
+    let app = app.layer(middleware);
 
     let listener = tokio::net::TcpListener::bind(&addr).await?;
     let addr = listener.local_addr()?;
@@ -559,6 +702,10 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
 
     info!("JSON-RPC HTTP Server start listening {:?}", addr);
     info!("Available JSON-RPC methods : {:?}", methods_names);
+    info!(
+        "Available admin JSON-RPC methods : {:?}",
+        admin_methods_names
+    );
 
     Ok(ServerHandle {
         shutdown_tx,
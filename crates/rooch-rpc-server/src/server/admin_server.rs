@@ -0,0 +1,175 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::service::rpc_service::RpcService;
+use coerce::actor::LocalActorRef;
+use jsonrpsee::{core::async_trait, RpcModule};
+use rooch_event::actor::{EventActor, GasUpgradeMessage, ServiceStatusMessage};
+use rooch_proposer::proxy::ProposerProxy;
+use rooch_rpc_api::api::admin_api::AdminAPIServer;
+use rooch_rpc_api::api::{RoochRpcModule, DEFAULT_RESULT_LIMIT_USIZE, MAX_RESULT_LIMIT_USIZE};
+use rooch_rpc_api::jsonrpc_types::admin_view::{
+    BackupManifestView, IndexerStatusView, ServiceStatusView,
+};
+use rooch_rpc_api::jsonrpc_types::repair_view::{RepairIndexerParamsView, RepairIndexerTypeView};
+use rooch_rpc_api::jsonrpc_types::{
+    QueryOptions, StateChangeSetPageView, StateChangeSetWithTxOrderView, StrView,
+    SyncStateFilterView,
+};
+use rooch_rpc_api::{RpcError, RpcResult};
+use std::cmp::min;
+use std::path::PathBuf;
+use tracing::info;
+
+/// The admin JSON-RPC server for the node operators, it is served on the admin port
+/// or protected by the admin token, see `RoochOpt::admin_port` and `RoochOpt::admin_token`.
+pub struct AdminServer {
+    rpc_service: RpcService,
+    proposer: ProposerProxy,
+    event_actor: LocalActorRef<EventActor>,
+}
+
+impl AdminServer {
+    pub fn new(
+        rpc_service: RpcService,
+        proposer: ProposerProxy,
+        event_actor: LocalActorRef<EventActor>,
+    ) -> Self {
+        Self {
+            rpc_service,
+            proposer,
+            event_actor,
+        }
+    }
+}
+
+#[async_trait]
+impl AdminAPIServer for AdminServer {
+    async fn repair_indexer(
+        &self,
+        repair_type: RepairIndexerTypeView,
+        repair_params: RepairIndexerParamsView,
+    ) -> RpcResult<()> {
+        self.rpc_service
+            .repair_indexer(repair_type.0, repair_params.into())
+            .await?;
+        Ok(())
+    }
+
+    async fn get_indexer_status(&self) -> RpcResult<IndexerStatusView> {
+        let status = self.rpc_service.indexer_status().await?;
+        Ok(status.into())
+    }
+
+    async fn sync_states(
+        &self,
+        filter: SyncStateFilterView,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<StrView<u64>>,
+        limit: Option<StrView<u64>>,
+        query_option: Option<QueryOptions>,
+    ) -> RpcResult<StateChangeSetPageView> {
+        let limit_of = min(
+            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
+            MAX_RESULT_LIMIT_USIZE,
+        ) as u64;
+        let cursor_of = cursor.map(|v| v.0);
+        // Sync from asc by default
+        let descending_order = query_option.map(|v| v.descending).unwrap_or(false);
+
+        let last_sequencer_order = self.rpc_service.get_sequencer_order().await?;
+        let tx_orders = if descending_order {
+            let start = cursor_of.unwrap_or(last_sequencer_order + 1);
+            let end = if start >= limit_of {
+                start - limit_of
+            } else {
+                0
+            };
+
+            (end..start).rev().collect::<Vec<_>>()
+        } else {
+            let start = cursor_of.unwrap_or(0);
+            let end_check = start
+                .checked_add(limit_of + 1)
+                .ok_or(RpcError::UnexpectedError(
+                    "cursor value is overflow".to_string(),
+                ))?;
+            let end = min(end_check, last_sequencer_order + 1);
+
+            (start..end).collect::<Vec<_>>()
+        };
+
+        let mut data = self
+            .rpc_service
+            .sync_states(tx_orders, filter.into())
+            .await?
+            .into_iter()
+            .map(StateChangeSetWithTxOrderView::from)
+            .collect::<Vec<_>>();
+
+        let has_next_page = data.len() > limit_of as usize;
+        data.truncate(limit_of as usize);
+        let next_cursor = data.last().cloned().map_or(cursor, |t| Some(t.tx_order));
+
+        Ok(StateChangeSetPageView {
+            data,
+            next_cursor,
+            has_next_page,
+        })
+    }
+
+    async fn get_service_status(&self) -> RpcResult<ServiceStatusView> {
+        let service_status = self.rpc_service.sequencer.get_service_status().await?;
+        Ok(service_status.into())
+    }
+
+    async fn set_service_status(&self, status: ServiceStatusView) -> RpcResult<()> {
+        let status = status.0;
+        if status.is_date_import_mode() {
+            return Err(RpcError::UnexpectedError(
+                "The date import mode can only be set on startup".to_string(),
+            ));
+        }
+        info!("Admin set the service status to {:?}", status);
+        self.event_actor
+            .send(ServiceStatusMessage { status })
+            .await
+            .map_err(anyhow::Error::from)??;
+        Ok(())
+    }
+
+    async fn resubmit_da_batch(&self) -> RpcResult<Option<StrView<u128>>> {
+        let block_number = self.proposer.resubmit_last_batch().await?;
+        Ok(block_number.map(Into::into))
+    }
+
+    async fn flush_cache(&self) -> RpcResult<()> {
+        info!("Admin flush the module cache");
+        self.rpc_service.executor.flush_module_cache().await?;
+        Ok(())
+    }
+
+    async fn refresh_gas_config(&self) -> RpcResult<()> {
+        info!("Admin refresh the gas config");
+        self.event_actor
+            .send(GasUpgradeMessage {})
+            .await
+            .map_err(anyhow::Error::from)??;
+        Ok(())
+    }
+
+    async fn backup_db(&self, backup_dir: String) -> RpcResult<BackupManifestView> {
+        info!("Admin backup the db to {}", backup_dir);
+        let manifest = self
+            .rpc_service
+            .backup_db(PathBuf::from(backup_dir))
+            .await?;
+        Ok(manifest.into())
+    }
+}
+
+impl RoochRpcModule for AdminServer {
+    fn rpc(self) -> RpcModule<Self> {
+        self.into_rpc()
+    }
+}
@@ -1,18 +1,22 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::service::pending_btc_txs::PendingTxSource;
 use crate::service::rpc_service::RpcService;
 use anyhow::Result;
-use bitcoincore_rpc::bitcoin::Txid;
+use bitcoincore_rpc::bitcoin::{self, Txid};
 use jsonrpsee::{core::async_trait, RpcModule};
 use rooch_rpc_api::api::btc_api::BtcAPIServer;
 use rooch_rpc_api::api::{RoochRpcModule, DEFAULT_RESULT_LIMIT_USIZE, MAX_RESULT_LIMIT_USIZE};
 use rooch_rpc_api::jsonrpc_types::btc::ord::{InscriptionFilterView, InscriptionStateView};
+use rooch_rpc_api::jsonrpc_types::btc::runes::{RuneBalancesStateView, RuneFilterView};
+use rooch_rpc_api::jsonrpc_types::btc::transaction::PendingTransactionView;
 use rooch_rpc_api::jsonrpc_types::btc::utxo::{UTXOFilterView, UTXOStateView};
 use rooch_rpc_api::jsonrpc_types::{
-    BytesView, IndexerStateIDView, InscriptionPageView, StrView, UTXOPageView,
+    BytesView, InscriptionPageView, PageCursorView, RuneBalancesPageView, StrView, UTXOPageView,
 };
 use rooch_rpc_api::RpcResult;
+use rooch_types::indexer::cursor::PageCursor;
 use rooch_types::indexer::state::ObjectStateType;
 use std::cmp::min;
 
@@ -32,7 +36,7 @@ impl BtcAPIServer for BtcServer {
         &self,
         filter: UTXOFilterView,
         // exclusive cursor if `Some`, otherwise start from the beginning
-        cursor: Option<IndexerStateIDView>,
+        cursor: Option<PageCursorView>,
         limit: Option<StrView<u64>>,
         descending_order: Option<bool>,
     ) -> RpcResult<UTXOPageView> {
@@ -41,75 +45,175 @@ impl BtcAPIServer for BtcServer {
             MAX_RESULT_LIMIT_USIZE,
         );
         let descending_order = descending_order.unwrap_or(true);
+        let generation = self.rpc_service.indexer_generation().await?;
+        let cursor = cursor.map(|v| v.0.into_state(generation)).transpose()?;
 
+        // The UTXOs queried by outpoint or object id are returned even if they are spent by pending transactions, for RBF.
+        let exclude_pending_spent =
+            matches!(filter, UTXOFilterView::Owner(_) | UTXOFilterView::All);
         let global_state_filter = UTXOFilterView::into_global_state_filter(filter)?;
+
+        // Exclude the UTXOs spent by the pending transactions before paging,
+        // keep querying the next batch until the page is full or there are no more UTXOs.
+        let mut data = Vec::with_capacity(limit_of + 1);
+        let mut query_cursor = cursor.clone();
+        loop {
+            let object_states = self
+                .rpc_service
+                .query_object_states(
+                    global_state_filter.clone(),
+                    query_cursor.clone(),
+                    limit_of + 1,
+                    descending_order,
+                    false,
+                    false,
+                    ObjectStateType::UTXO,
+                )
+                .await?;
+            let fetched = object_states.len();
+            let utxos = object_states
+                .into_iter()
+                .map(UTXOStateView::try_from)
+                .collect::<Result<Vec<_>, _>>()?;
+            query_cursor = utxos
+                .last()
+                .map(|utxo| utxo.indexer_id.clone().into())
+                .or(query_cursor);
+            if !exclude_pending_spent {
+                data.extend(utxos);
+                break;
+            }
+            let outpoints = utxos
+                .iter()
+                .map(|utxo| bitcoin::OutPoint::new(utxo.value.txid(), utxo.value.vout))
+                .collect::<Vec<_>>();
+            let pending_btc_txs = &self.rpc_service.pending_btc_txs;
+            pending_btc_txs.request_mempool_check(outpoints.iter().cloned());
+            data.extend(
+                utxos
+                    .into_iter()
+                    .zip(outpoints)
+                    .filter_map(|(utxo, outpoint)| {
+                        pending_btc_txs
+                            .spent_by(&outpoint)
+                            .is_none()
+                            .then_some(utxo)
+                    }),
+            );
+            if fetched <= limit_of || data.len() > limit_of {
+                break;
+            }
+        }
+
+        let has_next_page = data.len() > limit_of;
+        data.truncate(limit_of);
+        let next_cursor = data
+            .last()
+            .cloned()
+            .map_or(cursor, |t| Some(t.indexer_id.into()))
+            .map(|v| StrView(PageCursor::state(generation, v)));
+
+        Ok(UTXOPageView {
+            data,
+            next_cursor,
+            has_next_page,
+        })
+    }
+
+    async fn query_inscriptions(
+        &self,
+        filter: InscriptionFilterView,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<PageCursorView>,
+        limit: Option<StrView<u64>>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<InscriptionPageView> {
+        let limit_of = min(
+            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
+            MAX_RESULT_LIMIT_USIZE,
+        );
+        let descending_order = descending_order.unwrap_or(true);
+        let generation = self.rpc_service.indexer_generation().await?;
+        let cursor = cursor.map(|v| v.0.into_state(generation)).transpose()?;
+
+        let global_state_filter = InscriptionFilterView::into_global_state_filter(filter)?;
         let object_states = self
             .rpc_service
             .query_object_states(
                 global_state_filter,
-                cursor.map(|c| c.into()),
+                cursor.clone(),
                 limit_of + 1,
                 descending_order,
                 false,
                 false,
-                ObjectStateType::UTXO,
+                ObjectStateType::Inscription,
             )
             .await?;
 
         let mut data = object_states
             .into_iter()
-            .map(UTXOStateView::try_from)
+            .map(InscriptionStateView::try_from)
             .collect::<Result<Vec<_>, _>>()?;
 
         let has_next_page = data.len() > limit_of;
         data.truncate(limit_of);
-        let next_cursor = data.last().cloned().map_or(cursor, |t| Some(t.indexer_id));
+        let next_cursor = data
+            .last()
+            .cloned()
+            .map_or(cursor, |t| Some(t.indexer_id.into()))
+            .map(|v| StrView(PageCursor::state(generation, v)));
 
-        Ok(UTXOPageView {
+        Ok(InscriptionPageView {
             data,
             next_cursor,
             has_next_page,
         })
     }
 
-    async fn query_inscriptions(
+    async fn query_runes(
         &self,
-        filter: InscriptionFilterView,
+        filter: RuneFilterView,
         // exclusive cursor if `Some`, otherwise start from the beginning
-        cursor: Option<IndexerStateIDView>,
+        cursor: Option<PageCursorView>,
         limit: Option<StrView<u64>>,
         descending_order: Option<bool>,
-    ) -> RpcResult<InscriptionPageView> {
+    ) -> RpcResult<RuneBalancesPageView> {
         let limit_of = min(
             limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
             MAX_RESULT_LIMIT_USIZE,
         );
         let descending_order = descending_order.unwrap_or(true);
+        let generation = self.rpc_service.indexer_generation().await?;
+        let cursor = cursor.map(|v| v.0.into_state(generation)).transpose()?;
 
-        let global_state_filter = InscriptionFilterView::into_global_state_filter(filter)?;
+        let global_state_filter = RuneFilterView::into_global_state_filter(filter)?;
         let object_states = self
             .rpc_service
             .query_object_states(
                 global_state_filter,
-                cursor.map(Into::into),
+                cursor.clone(),
                 limit_of + 1,
                 descending_order,
                 false,
                 false,
-                ObjectStateType::Inscription,
+                ObjectStateType::Rune,
             )
             .await?;
 
         let mut data = object_states
             .into_iter()
-            .map(InscriptionStateView::try_from)
+            .map(RuneBalancesStateView::try_from)
             .collect::<Result<Vec<_>, _>>()?;
 
         let has_next_page = data.len() > limit_of;
         data.truncate(limit_of);
-        let next_cursor = data.last().cloned().map_or(cursor, |t| Some(t.indexer_id));
+        let next_cursor = data
+            .last()
+            .cloned()
+            .map_or(cursor, |t| Some(t.indexer_id.into()))
+            .map(|v| StrView(PageCursor::state(generation, v)));
 
-        Ok(InscriptionPageView {
+        Ok(RuneBalancesPageView {
             data,
             next_cursor,
             has_next_page,
@@ -130,6 +234,27 @@ impl BtcAPIServer for BtcServer {
 
         Ok(txid.to_string())
     }
+
+    async fn query_pending_transactions(&self) -> RpcResult<Vec<PendingTransactionView>> {
+        let pending_txs = self
+            .rpc_service
+            .query_pending_bitcoin_transactions()
+            .await?;
+        Ok(pending_txs
+            .into_iter()
+            .map(|pending_tx| {
+                let source = match pending_tx.source {
+                    PendingTxSource::Broadcast => "broadcast",
+                    PendingTxSource::Mempool => "mempool",
+                };
+                PendingTransactionView::new(
+                    &pending_tx.tx,
+                    source.to_string(),
+                    pending_tx.timestamp,
+                )
+            })
+            .collect())
+    }
 }
 
 impl RoochRpcModule for BtcServer {
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::service::aggregate_service::AggregateService;
+use crate::service::metrics::TxLifecycleMetrics;
 use crate::service::rpc_service::RpcService;
 use anyhow::Result;
 use jsonrpsee::{core::async_trait, RpcModule};
@@ -15,18 +16,19 @@ use moveos_types::{
     moveos_std::{move_module::MoveModule, object::ObjectID},
     state::{AnnotatedState, FieldKey},
 };
-use rooch_rpc_api::jsonrpc_types::repair_view::{RepairIndexerParamsView, RepairIndexerTypeView};
 use rooch_rpc_api::jsonrpc_types::{
     account_view::BalanceInfoView,
-    event_view::{EventFilterView, EventView, IndexerEventIDView, IndexerEventView},
-    transaction_view::{TransactionFilterView, TransactionWithInfoView},
+    event_view::{EventFilterView, EventView, IndexerEventView},
+    gas_view::{GasEstimateView, GasPriceView},
+    session_key_view::SessionKeyView,
+    transaction_view::{TransactionFilterView, TransactionWithInfoView, TxPoolTransactionView},
     AccessPathView, BalanceInfoPageView, DryRunTransactionResponseView, EventOptions,
     EventPageView, ExecuteTransactionResponseView, FunctionCallView, H256View,
-    IndexerEventPageView, IndexerObjectStatePageView, IndexerStateIDView, ModuleABIView,
-    ObjectIDVecView, ObjectStateFilterView, ObjectStateView, QueryOptions,
-    RawTransactionOutputView, RoochAddressView, StateChangeSetPageView,
-    StateChangeSetWithTxOrderView, StateKVView, StateOptions, StatePageView, StrView,
-    StructTagView, SyncStateFilterView, TransactionWithInfoPageView, TxOptions, UnitedAddressView,
+    IndexerEventPageView, IndexerObjectStatePageView, IndexerStateIDView,
+    IndexerTransactionPageView, ModuleABIView, ObjectIDVecView, ObjectStateFilterView,
+    ObjectStateView, PageCursorView, QueryOptions, RawTransactionOutputView, RoochAddressView,
+    StateKVView, StateOptions, StatePageView, StrView, StructTagView, TransactionWithInfoPageView,
+    TxOptions, UnitedAddressView,
 };
 use rooch_rpc_api::{
     api::rooch_api::RoochAPIServer,
@@ -37,25 +39,62 @@ use rooch_rpc_api::{
     jsonrpc_types::BytesView,
     RpcError, RpcResult,
 };
-use rooch_types::indexer::state::{IndexerStateID, ObjectStateType};
+use rooch_types::address::RoochAddress;
+use rooch_types::indexer::cursor::PageCursor;
+use rooch_types::indexer::state::{IndexerStateID, ObjectStateFilter, ObjectStateType};
 use rooch_types::transaction::{RoochTransaction, RoochTransactionData, TransactionWithInfo};
 use std::cmp::min;
 use std::str::FromStr;
-use tracing::{debug, info};
+use std::time::Instant;
+use tracing::{debug, info, info_span, Instrument, Span};
+
+const SEND_RAW_TRANSACTION_ROUTE: &str = "send_raw_transaction";
+const EXECUTE_RAW_TRANSACTION_ROUTE: &str = "execute_raw_transaction";
 
 pub struct RoochServer {
     rpc_service: RpcService,
     aggregate_service: AggregateService,
+    tx_metrics: TxLifecycleMetrics,
 }
 
 impl RoochServer {
-    pub fn new(rpc_service: RpcService, aggregate_service: AggregateService) -> Self {
+    pub fn new(
+        rpc_service: RpcService,
+        aggregate_service: AggregateService,
+        tx_metrics: TxLifecycleMetrics,
+    ) -> Self {
         Self {
             rpc_service,
             aggregate_service,
+            tx_metrics,
         }
     }
 
+    /// Decode the tx payload in the tx span, the tx hash is recorded to the span after the tx is decoded.
+    fn decode_tx(
+        &self,
+        route: &str,
+        span: &Span,
+        payload: &BytesView,
+    ) -> RpcResult<RoochTransaction> {
+        let _timer = self
+            .tx_metrics
+            .tx_rpc_decode_latency_seconds
+            .with_label_values(&[route])
+            .start_timer();
+        let mut tx = info_span!(parent: span, "rpc_decode")
+            .in_scope(|| RoochTransaction::decode(&payload.0))?;
+        span.record("tx_hash", tracing::field::display(tx.tx_hash()));
+        Ok(tx)
+    }
+
+    fn observe_tx_e2e_latency(&self, route: &str, started_at: Instant) {
+        self.tx_metrics
+            .tx_e2e_latency_seconds
+            .with_label_values(&[route])
+            .observe(started_at.elapsed().as_secs_f64());
+    }
+
     async fn transactions_to_view(
         &self,
         data: Vec<TransactionWithInfo>,
@@ -99,7 +138,13 @@ impl RoochAPIServer for RoochServer {
 
     async fn send_raw_transaction(&self, payload: BytesView) -> RpcResult<H256View> {
         debug!("send_raw_transaction payload: {:?}", payload);
-        let mut tx = bcs::from_bytes::<RoochTransaction>(&payload.0)?;
+        let started_at = Instant::now();
+        let span = info_span!(
+            "rpc_tx",
+            route = SEND_RAW_TRANSACTION_ROUTE,
+            tx_hash = tracing::field::Empty
+        );
+        let mut tx = self.decode_tx(SEND_RAW_TRANSACTION_ROUTE, &span, &payload)?;
         info!(
             "send_raw_transaction tx sender:{:?}, hash:{}",
             tx.sender(),
@@ -107,7 +152,8 @@ impl RoochAPIServer for RoochServer {
         );
 
         let hash = tx.tx_hash();
-        self.rpc_service.queue_tx(tx).await?;
+        self.rpc_service.queue_tx(tx).instrument(span).await?;
+        self.observe_tx_e2e_latency(SEND_RAW_TRANSACTION_ROUTE, started_at);
         Ok(hash.into())
     }
 
@@ -117,8 +163,15 @@ impl RoochAPIServer for RoochServer {
         tx_options: Option<TxOptions>,
     ) -> RpcResult<ExecuteTransactionResponseView> {
         let tx_options = tx_options.unwrap_or_default();
-        let tx = bcs::from_bytes::<RoochTransaction>(&payload.0)?;
-        let tx_response = self.rpc_service.execute_tx(tx).await?;
+        let started_at = Instant::now();
+        let span = info_span!(
+            "rpc_tx",
+            route = EXECUTE_RAW_TRANSACTION_ROUTE,
+            tx_hash = tracing::field::Empty
+        );
+        let tx = self.decode_tx(EXECUTE_RAW_TRANSACTION_ROUTE, &span, &payload)?;
+        let tx_response = self.rpc_service.execute_tx(tx).instrument(span).await?;
+        self.observe_tx_e2e_latency(EXECUTE_RAW_TRANSACTION_ROUTE, started_at);
 
         let result = if tx_options.with_output {
             let mut txn_resp_view = ExecuteTransactionResponseView::from(tx_response.clone());
@@ -164,7 +217,7 @@ impl RoochAPIServer for RoochServer {
     }
 
     async fn dry_run(&self, payload: BytesView) -> RpcResult<DryRunTransactionResponseView> {
-        let tx = bcs::from_bytes::<RoochTransactionData>(&payload.0)?;
+        let tx = RoochTransactionData::decode(&payload.0)?;
         let tx_result = self.rpc_service.dry_run_tx(tx).await?;
         let raw_output = tx_result.raw_output;
 
@@ -182,6 +235,16 @@ impl RoochAPIServer for RoochServer {
         Ok(tx_response)
     }
 
+    async fn estimate_gas(&self, tx_bcs_hex: BytesView) -> RpcResult<GasEstimateView> {
+        let tx = RoochTransactionData::decode(&tx_bcs_hex.0)?;
+        let (tx_result, max_gas_amount) = self.rpc_service.estimate_gas(tx).await?;
+        Ok(GasEstimateView::new(
+            &tx_result.raw_output,
+            tx_result.vm_error_info,
+            max_gas_amount,
+        ))
+    }
+
     async fn execute_view_function(
         &self,
         function_call: FunctionCallView,
@@ -613,6 +676,60 @@ impl RoochAPIServer for RoochServer {
         })
     }
 
+    async fn get_session_keys(
+        &self,
+        account_addr: UnitedAddressView,
+    ) -> RpcResult<Vec<SessionKeyView>> {
+        Ok(self
+            .aggregate_service
+            .get_session_keys(account_addr.into())
+            .await?)
+    }
+
+    async fn get_pending_transactions(
+        &self,
+        sender: UnitedAddressView,
+    ) -> RpcResult<Vec<TxPoolTransactionView>> {
+        let sender: RoochAddress = sender.into();
+        let pending_txs = self
+            .rpc_service
+            .get_pending_transactions(Some(sender))
+            .await?;
+        if pending_txs.is_empty() {
+            return Ok(vec![]);
+        }
+        let bitcoin_network = self.rpc_service.get_bitcoin_network();
+        let sender_bitcoin_address = self
+            .rpc_service
+            .get_bitcoin_addresses(vec![sender])
+            .await?
+            .remove(&sender)
+            .flatten()
+            .map(|addr| addr.format(bitcoin_network))
+            .transpose()?;
+        Ok(pending_txs
+            .into_iter()
+            .map(|pending_tx| {
+                TxPoolTransactionView::new(
+                    pending_tx.tx_hash,
+                    pending_tx.tx,
+                    pending_tx.timestamp,
+                    sender_bitcoin_address.clone(),
+                )
+            })
+            .collect())
+    }
+
+    async fn estimate_gas_price(&self) -> RpcResult<GasPriceView> {
+        let base_fee = self.rpc_service.get_base_fee().await?;
+        let status = self.rpc_service.get_txpool_status().await?;
+        Ok(GasPriceView::new(
+            base_fee,
+            status.max_ready_priority_fee,
+            status.ready_count as u64,
+        ))
+    }
+
     async fn get_module_abi(
         &self,
         module_addr: RoochAddressView,
@@ -645,15 +762,15 @@ impl RoochAPIServer for RoochServer {
         &self,
         filter: TransactionFilterView,
         // exclusive cursor if `Some`, otherwise start from the beginning
-        cursor: Option<StrView<u64>>,
+        cursor: Option<PageCursorView>,
         limit: Option<StrView<u64>>,
         query_option: Option<QueryOptions>,
-    ) -> RpcResult<TransactionWithInfoPageView> {
+    ) -> RpcResult<IndexerTransactionPageView> {
         let limit_of = min(
             limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
             MAX_RESULT_LIMIT_USIZE,
         );
-        let cursor = cursor.map(|v| v.0);
+        let cursor = cursor.map(|v| v.0.into_transaction()).transpose()?;
         let query_option = query_option.unwrap_or_default();
         let descending_order = query_option.descending;
 
@@ -677,9 +794,9 @@ impl RoochAPIServer for RoochServer {
             .cloned()
             .map_or(cursor, |t| Some(t.transaction.sequence_info.tx_order.0));
 
-        Ok(TransactionWithInfoPageView {
+        Ok(IndexerTransactionPageView {
             data,
-            next_cursor: next_cursor.map(StrView),
+            next_cursor: next_cursor.map(|v| StrView(PageCursor::transaction(v))),
             has_next_page,
         })
     }
@@ -688,7 +805,7 @@ impl RoochAPIServer for RoochServer {
         &self,
         filter: EventFilterView,
         // exclusive cursor if `Some`, otherwise start from the beginning
-        cursor: Option<IndexerEventIDView>,
+        cursor: Option<PageCursorView>,
         limit: Option<StrView<u64>>,
         query_option: Option<QueryOptions>,
     ) -> RpcResult<IndexerEventPageView> {
@@ -696,29 +813,20 @@ impl RoochAPIServer for RoochServer {
             limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
             MAX_RESULT_LIMIT_USIZE,
         );
+        let cursor = cursor.map(|v| v.0.into_event()).transpose()?;
         let query_option = query_option.unwrap_or_default();
         let descending_order = query_option.descending;
 
         let mut data = if query_option.decode {
             self.rpc_service
-                .query_annotated_events(
-                    filter.into(),
-                    cursor.map(Into::into),
-                    limit_of + 1,
-                    descending_order,
-                )
+                .query_annotated_events(filter.into(), cursor, limit_of + 1, descending_order)
                 .await?
                 .into_iter()
                 .map(IndexerEventView::from)
                 .collect::<Vec<_>>()
         } else {
             self.rpc_service
-                .query_events(
-                    filter.into(),
-                    cursor.map(Into::into),
-                    limit_of + 1,
-                    descending_order,
-                )
+                .query_events(filter.into(), cursor, limit_of + 1, descending_order)
                 .await?
                 .into_iter()
                 .map(IndexerEventView::from)
@@ -730,11 +838,11 @@ impl RoochAPIServer for RoochServer {
         let next_cursor = data
             .last()
             .cloned()
-            .map_or(cursor, |e| Some(e.indexer_event_id));
+            .map_or(cursor, |e| Some(e.indexer_event_id.into()));
 
         Ok(IndexerEventPageView {
             data,
-            next_cursor,
+            next_cursor: next_cursor.map(|v| StrView(PageCursor::event(v))),
             has_next_page,
         })
     }
@@ -743,7 +851,7 @@ impl RoochAPIServer for RoochServer {
         &self,
         filter: ObjectStateFilterView,
         // exclusive cursor if `Some`, otherwise start from the beginning
-        cursor: Option<IndexerStateIDView>,
+        cursor: Option<PageCursorView>,
         limit: Option<StrView<u64>>,
         query_option: Option<QueryOptions>,
     ) -> RpcResult<IndexerObjectStatePageView> {
@@ -756,11 +864,53 @@ impl RoochAPIServer for RoochServer {
 
         let global_state_filter =
             ObjectStateFilterView::try_into_object_state_filter(filter, query_option.clone())?;
+        if let ObjectStateFilter::CustomIndex(custom_index_filter) = global_state_filter {
+            let cursor = cursor.map(|v| v.0.into_custom_index()).transpose()?;
+            let mut object_ids = self
+                .rpc_service
+                .query_object_ids_by_custom_index(
+                    custom_index_filter,
+                    cursor.clone(),
+                    limit_of + 1,
+                    descending_order,
+                )
+                .await?;
+
+            let has_next_page = object_ids.len() > limit_of;
+            object_ids.truncate(limit_of);
+
+            // Page by the custom index ids, the objects not found in the state are skipped
+            let next_cursor = object_ids
+                .last()
+                .map_or(cursor, |(_, custom_index_id)| Some(custom_index_id.clone()));
+            let indexer_ids = object_ids
+                .into_iter()
+                .map(|(object_id, _)| (object_id, IndexerStateID::default()))
+                .collect();
+            let object_states = self
+                .rpc_service
+                .get_indexer_object_states(
+                    indexer_ids,
+                    query_option.decode,
+                    query_option.show_display,
+                )
+                .await?;
+
+            return Ok(IndexerObjectStatePageView {
+                data: object_states,
+                next_cursor: next_cursor.map(|v| StrView(PageCursor::custom_index(v))),
+                has_next_page,
+            });
+        }
+
+        // The state cursor is only valid in the indexer generation it is issued
+        let generation = self.rpc_service.indexer_generation().await?;
+        let cursor = cursor.map(|v| v.0.into_state(generation)).transpose()?;
         let mut object_states = self
             .rpc_service
             .query_object_states(
                 global_state_filter,
-                cursor.map(Into::into),
+                cursor.clone(),
                 limit_of + 1,
                 descending_order,
                 query_option.decode,
@@ -775,81 +925,31 @@ impl RoochAPIServer for RoochServer {
         let next_cursor = object_states
             .last()
             .cloned()
-            .map_or(cursor, |t| Some(t.indexer_id));
+            .map_or(cursor, |t| Some(t.indexer_id.into()));
 
         Ok(IndexerObjectStatePageView {
             data: object_states,
-            next_cursor,
+            next_cursor: next_cursor.map(|v| StrView(PageCursor::state(generation, v))),
             has_next_page,
         })
     }
 
-    async fn repair_indexer(
-        &self,
-        repair_type: RepairIndexerTypeView,
-        repair_params: RepairIndexerParamsView,
-    ) -> RpcResult<()> {
-        self.rpc_service
-            .repair_indexer(repair_type.0, repair_params.into())
-            .await?;
-        Ok(())
-    }
-
-    async fn sync_states(
+    async fn get_state_nodes(
         &self,
-        filter: SyncStateFilterView,
-        // exclusive cursor if `Some`, otherwise start from the beginning
-        cursor: Option<StrView<u64>>,
-        limit: Option<StrView<u64>>,
-        query_option: Option<QueryOptions>,
-    ) -> RpcResult<StateChangeSetPageView> {
-        let limit_of = min(
-            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
-            MAX_RESULT_LIMIT_USIZE,
-        ) as u64;
-        let cursor_of = cursor.map(|v| v.0);
-        // Sync from asc by default
-        let descending_order = query_option.map(|v| v.descending).unwrap_or(false);
-
-        let last_sequencer_order = self.rpc_service.get_sequencer_order().await?;
-        let tx_orders = if descending_order {
-            let start = cursor_of.unwrap_or(last_sequencer_order + 1);
-            let end = if start >= limit_of {
-                start - limit_of
-            } else {
-                0
-            };
-
-            (end..start).rev().collect::<Vec<_>>()
-        } else {
-            let start = cursor_of.unwrap_or(0);
-            let end_check = start
-                .checked_add(limit_of + 1)
-                .ok_or(RpcError::UnexpectedError(
-                    "cursor value is overflow".to_string(),
-                ))?;
-            let end = min(end_check, last_sequencer_order + 1);
-
-            (start..end).collect::<Vec<_>>()
-        };
-
-        let mut data = self
-            .rpc_service
-            .sync_states(tx_orders, filter.into())
-            .await?
+        node_hashes: Vec<H256View>,
+    ) -> RpcResult<Vec<Option<BytesView>>> {
+        if node_hashes.len() > MAX_RESULT_LIMIT_USIZE {
+            return Err(RpcError::UnexpectedError(format!(
+                "node_hashes size should not be greater than {}",
+                MAX_RESULT_LIMIT_USIZE
+            )));
+        }
+        let node_hashes = node_hashes.into_iter().map(Into::into).collect();
+        let nodes = self.rpc_service.get_state_nodes(node_hashes).await?;
+        Ok(nodes
             .into_iter()
-            .map(StateChangeSetWithTxOrderView::from)
-            .collect::<Vec<_>>();
-
-        let has_next_page = data.len() > limit_of as usize;
-        data.truncate(limit_of as usize);
-        let next_cursor = data.last().cloned().map_or(cursor, |t| Some(t.tx_order));
-
-        Ok(StateChangeSetPageView {
-            data,
-            next_cursor,
-            has_next_page,
-        })
+            .map(|node| node.map(BytesView::from))
+            .collect())
     }
 }
 
@@ -0,0 +1,33 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use http::header::AUTHORIZATION;
+use http::StatusCode;
+use std::sync::Arc;
+use subtle::ConstantTimeEq;
+
+const BEARER_PREFIX: &str = "Bearer ";
+
+/// Reject the admin requests without the `Authorization: Bearer <admin_token>` header
+pub async fn admin_auth(
+    State(admin_token): State<Arc<String>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let authorized = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix(BEARER_PREFIX))
+        // Compare in constant time to not leak the token via the response timing
+        .map(|token| bool::from(token.as_bytes().ct_eq(admin_token.as_bytes())))
+        .unwrap_or(false);
+    if authorized {
+        next.run(request).await
+    } else {
+        StatusCode::UNAUTHORIZED.into_response()
+    }
+}
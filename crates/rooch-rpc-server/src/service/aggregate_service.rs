@@ -7,14 +7,20 @@ use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::StructTag;
 use moveos_types::access_path::AccessPath;
 use moveos_types::h256::H256;
+use moveos_types::move_std::option::MoveOption;
 use moveos_types::moveos_std::object::ObjectID;
+use moveos_types::moveos_std::timestamp::Timestamp;
+use moveos_types::state::FieldKey;
 use moveos_types::state::PlaceholderStruct;
+use rooch_rpc_api::api::MAX_RESULT_LIMIT_USIZE;
 use rooch_rpc_api::jsonrpc_types::account_view::BalanceInfoView;
+use rooch_rpc_api::jsonrpc_types::session_key_view::SessionKeyView;
 use rooch_rpc_api::jsonrpc_types::CoinInfoView;
 use rooch_types::address::RoochAddress;
 use rooch_types::framework::account_coin_store::AccountCoinStoreModule;
 use rooch_types::framework::coin::{CoinInfo, CoinModule};
 use rooch_types::framework::coin_store::{CoinStore, CoinStoreInfo};
+use rooch_types::framework::session_key::{SessionKey, SessionKeyModule};
 use rooch_types::indexer::state::{IndexerStateID, ObjectStateFilter, ObjectStateType};
 use rooch_types::indexer::transaction::IndexerTransaction;
 use rooch_types::transaction::TransactionWithInfo;
@@ -169,6 +175,72 @@ impl AggregateService {
         Ok(result)
     }
 
+    pub async fn get_now_seconds(&self) -> Result<u64> {
+        let timestamp = self
+            .rpc_service
+            .get_states(AccessPath::object(Timestamp::object_id()), None)
+            .await?
+            .pop()
+            .flatten()
+            .ok_or_else(|| anyhow::anyhow!("Can not find Timestamp object"))?
+            .into_object::<Timestamp>()?
+            .value;
+        Ok(timestamp.milliseconds / 1000)
+    }
+
+    pub async fn get_session_keys(
+        &self,
+        account_addr: RoochAddress,
+    ) -> Result<Vec<SessionKeyView>> {
+        let call = SessionKeyModule::get_session_keys_handle_call(account_addr.into());
+        let return_values = self
+            .rpc_service
+            .execute_view_function(call)
+            .await?
+            .return_values
+            .ok_or_else(|| {
+                anyhow::anyhow!("Failed to get session keys handle of {}", account_addr)
+            })?;
+        let handle: Option<ObjectID> = match return_values.first() {
+            Some(return_value) => {
+                bcs::from_bytes::<MoveOption<ObjectID>>(&return_value.value.value)?.into()
+            }
+            None => None,
+        };
+        let handle = match handle {
+            Some(handle) => handle,
+            None => return Ok(vec![]),
+        };
+
+        let mut session_keys = vec![];
+        let mut cursor: Option<FieldKey> = None;
+        loop {
+            let states = self
+                .rpc_service
+                .list_states(
+                    None,
+                    AccessPath::fields_without_keys(handle.clone()),
+                    cursor,
+                    MAX_RESULT_LIMIT_USIZE,
+                )
+                .await?;
+            let has_next_page = states.len() == MAX_RESULT_LIMIT_USIZE;
+            cursor = states.last().map(|(key, _)| *key);
+            for (_, state) in states {
+                session_keys.push(state.value_as_df::<Vec<u8>, SessionKey>()?.value);
+            }
+            if !has_next_page {
+                break;
+            }
+        }
+
+        let now_seconds = self.get_now_seconds().await?;
+        Ok(session_keys
+            .into_iter()
+            .map(|session_key| SessionKeyView::new(session_key, now_seconds))
+            .collect())
+    }
+
     pub async fn get_transaction_with_info(
         &self,
         tx_hashes: Vec<H256>,
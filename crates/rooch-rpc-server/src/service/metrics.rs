@@ -192,3 +192,35 @@ impl ServiceMetrics {
         }
     }
 }
+
+/// The latency of the txs submitted via RPC, the later stages of the tx lifecycle are observed by the actors.
+#[derive(Debug, Clone)]
+pub struct TxLifecycleMetrics {
+    /// The latency to decode the tx payload, route is a label
+    pub tx_rpc_decode_latency_seconds: HistogramVec,
+    /// The latency from the tx payload is received to the tx is executed or queued, route is a label
+    pub tx_e2e_latency_seconds: HistogramVec,
+}
+
+impl TxLifecycleMetrics {
+    pub fn new(registry: &prometheus::Registry) -> Self {
+        Self {
+            tx_rpc_decode_latency_seconds: register_histogram_vec_with_registry!(
+                "tx_rpc_decode_latency_seconds",
+                "Latency in seconds to decode the tx payload by route",
+                &["route"],
+                LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
+            tx_e2e_latency_seconds: register_histogram_vec_with_registry!(
+                "tx_e2e_latency_seconds",
+                "End-to-end latency in seconds from the tx payload is received to the tx is executed or queued by route",
+                &["route"],
+                LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
+        }
+    }
+}
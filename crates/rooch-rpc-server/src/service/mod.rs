@@ -1,9 +1,11 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod admin_auth;
 pub mod aggregate_service;
 // pub mod rpc_logger;
 pub mod error;
+pub mod pending_btc_txs;
 pub mod rpc_service;
 
 pub mod routing;
@@ -0,0 +1,429 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use bitcoincore_rpc::bitcoin::{OutPoint, Transaction, Txid};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The max number of the tracked pending transactions, the oldest one is evicted when it is full.
+pub const DEFAULT_MAX_PENDING_TXS: usize = 10_000;
+/// The pending transactions are expired after the bitcoind default mempool expiry (336 hours).
+pub const DEFAULT_PENDING_TX_EXPIRY: Duration = Duration::from_secs(336 * 60 * 60);
+/// The max number of the outpoints waiting for the bitcoind mempool check.
+pub const MAX_MEMPOOL_CHECK_OUTPOINTS: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PendingTxSource {
+    /// The transaction is broadcast via the `btc_broadcastTX`
+    Broadcast,
+    /// The transaction is found in the bitcoind mempool
+    Mempool,
+}
+
+#[derive(Debug, Clone)]
+pub struct PendingBitcoinTransaction {
+    pub tx: Transaction,
+    pub source: PendingTxSource,
+    /// The timestamp in milliseconds when the transaction is added
+    pub timestamp: u64,
+    /// The inputs which have been seen in the Rooch state.
+    /// An input missing from the Rooch state may be not relayed yet,
+    /// only the disappearance of a seen input means it is spent on the Bitcoin chain.
+    seen_inputs: HashSet<OutPoint>,
+}
+
+impl PendingBitcoinTransaction {
+    pub fn txid(&self) -> Txid {
+        self.tx.compute_txid()
+    }
+}
+
+#[derive(Debug, Default)]
+struct PendingTxsInner {
+    txs: HashMap<Txid, PendingBitcoinTransaction>,
+    spent_outpoints: HashMap<OutPoint, Txid>,
+    /// The pending transactions ordered by the added timestamp, for the expiry and eviction.
+    by_timestamp: BTreeSet<(u64, Txid)>,
+    mempool_check_outpoints: HashSet<OutPoint>,
+}
+
+impl PendingTxsInner {
+    fn remove(&mut self, txid: &Txid) -> Option<PendingBitcoinTransaction> {
+        let pending_tx = self.txs.remove(txid)?;
+        for input in pending_tx.tx.input.iter() {
+            if self.spent_outpoints.get(&input.previous_output) == Some(txid) {
+                self.spent_outpoints.remove(&input.previous_output);
+            }
+        }
+        self.by_timestamp.remove(&(pending_tx.timestamp, *txid));
+        Some(pending_tx)
+    }
+
+    fn remove_expired(&mut self, expire_before: u64) -> Vec<Txid> {
+        let expired = self
+            .by_timestamp
+            .iter()
+            .take_while(|(timestamp, _)| *timestamp < expire_before)
+            .map(|(_, txid)| *txid)
+            .collect::<Vec<_>>();
+        for txid in expired.iter() {
+            self.remove(txid);
+        }
+        expired
+    }
+}
+
+/// The Bitcoin transactions which are broadcast but not confirmed yet,
+/// it is used to avoid selecting the UTXOs spent by the unconfirmed transactions.
+#[derive(Debug, Clone)]
+pub struct PendingBitcoinTransactions {
+    inner: Arc<RwLock<PendingTxsInner>>,
+    max_txs: usize,
+    expiry: Duration,
+}
+
+impl Default for PendingBitcoinTransactions {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_PENDING_TXS, DEFAULT_PENDING_TX_EXPIRY)
+    }
+}
+
+impl PendingBitcoinTransactions {
+    pub fn new(max_txs: usize, expiry: Duration) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(PendingTxsInner::default())),
+            max_txs,
+            expiry,
+        }
+    }
+
+    /// Add a pending transaction, the pending transactions which spend the same outpoints are replaced (RBF).
+    /// Returns the txids of the replaced transactions.
+    pub fn add(&self, tx: Transaction, source: PendingTxSource) -> Vec<Txid> {
+        self.add_at(tx, source, now_millis())
+    }
+
+    /// Add a pending transaction with the timestamp in milliseconds when it is added,
+    /// such as the time the transaction entered the bitcoind mempool.
+    pub fn add_at(&self, tx: Transaction, source: PendingTxSource, timestamp: u64) -> Vec<Txid> {
+        let txid = tx.compute_txid();
+        let mut inner = self
+            .inner
+            .write()
+            .expect("pending txs lock should not be poisoned");
+        if inner.txs.contains_key(&txid) {
+            return vec![];
+        }
+        let mut replaced = tx
+            .input
+            .iter()
+            .filter_map(|input| inner.spent_outpoints.get(&input.previous_output).cloned())
+            .collect::<Vec<_>>();
+        replaced.sort();
+        replaced.dedup();
+        for replaced_txid in replaced.iter() {
+            inner.remove(replaced_txid);
+        }
+        inner.remove_expired(self.expire_before(timestamp));
+        while inner.txs.len() >= self.max_txs {
+            match inner.by_timestamp.first().map(|(_, txid)| *txid) {
+                Some(oldest) => {
+                    inner.remove(&oldest);
+                }
+                None => break,
+            }
+        }
+        for input in tx.input.iter() {
+            inner.spent_outpoints.insert(input.previous_output, txid);
+            inner.mempool_check_outpoints.remove(&input.previous_output);
+        }
+        inner.by_timestamp.insert((timestamp, txid));
+        inner.txs.insert(
+            txid,
+            PendingBitcoinTransaction {
+                tx,
+                source,
+                timestamp,
+                seen_inputs: HashSet::new(),
+            },
+        );
+        replaced
+    }
+
+    pub fn max_txs(&self) -> usize {
+        self.max_txs
+    }
+
+    pub fn remove(&self, txid: &Txid) -> Option<PendingBitcoinTransaction> {
+        self.inner
+            .write()
+            .expect("pending txs lock should not be poisoned")
+            .remove(txid)
+    }
+
+    pub fn contains(&self, txid: &Txid) -> bool {
+        self.inner
+            .read()
+            .expect("pending txs lock should not be poisoned")
+            .txs
+            .contains_key(txid)
+    }
+
+    /// Get the pending transaction which spends the outpoint
+    pub fn spent_by(&self, outpoint: &OutPoint) -> Option<Txid> {
+        self.inner
+            .read()
+            .expect("pending txs lock should not be poisoned")
+            .spent_outpoints
+            .get(outpoint)
+            .cloned()
+    }
+
+    /// Get all pending transactions, ordered by the added timestamp
+    pub fn list(&self) -> Vec<PendingBitcoinTransaction> {
+        let inner = self
+            .inner
+            .read()
+            .expect("pending txs lock should not be poisoned");
+        inner
+            .by_timestamp
+            .iter()
+            .filter_map(|(_, txid)| inner.txs.get(txid).cloned())
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner
+            .read()
+            .expect("pending txs lock should not be poisoned")
+            .txs
+            .is_empty()
+    }
+
+    /// Remove the expired pending transactions, returns the removed txids.
+    pub fn remove_expired(&self) -> Vec<Txid> {
+        self.remove_expired_at(now_millis())
+    }
+
+    fn remove_expired_at(&self, now: u64) -> Vec<Txid> {
+        self.inner
+            .write()
+            .expect("pending txs lock should not be poisoned")
+            .remove_expired(self.expire_before(now))
+    }
+
+    /// Get the inputs of the pending transactions which should be checked against the Rooch state.
+    /// The outputs of other pending transactions are skipped, they do not exist in the Rooch state yet.
+    pub fn inputs_to_check(&self) -> Vec<(Txid, OutPoint)> {
+        let inner = self
+            .inner
+            .read()
+            .expect("pending txs lock should not be poisoned");
+        inner
+            .txs
+            .iter()
+            .flat_map(|(txid, pending_tx)| {
+                pending_tx
+                    .tx
+                    .input
+                    .iter()
+                    .filter(|input| !inner.txs.contains_key(&input.previous_output.txid))
+                    .map(move |input| (*txid, input.previous_output))
+            })
+            .collect()
+    }
+
+    /// Update the pending transactions with whether their inputs exist in the Rooch state.
+    /// A pending transaction is removed when a seen input disappears,
+    /// because the transaction or its conflicting transaction is confirmed.
+    /// Returns the removed txids.
+    pub fn update_inputs(&self, inputs: Vec<(Txid, OutPoint, bool)>) -> Vec<Txid> {
+        let mut inner = self
+            .inner
+            .write()
+            .expect("pending txs lock should not be poisoned");
+        let mut removed = vec![];
+        for (txid, outpoint, exists) in inputs {
+            let Some(pending_tx) = inner.txs.get_mut(&txid) else {
+                continue;
+            };
+            if exists {
+                pending_tx.seen_inputs.insert(outpoint);
+            } else if pending_tx.seen_inputs.contains(&outpoint) {
+                inner.remove(&txid);
+                removed.push(txid);
+            }
+        }
+        removed
+    }
+
+    /// Request to check whether the outpoints are spent in the bitcoind mempool,
+    /// the check is done in background, so the query does not wait for the bitcoind.
+    pub fn request_mempool_check(&self, outpoints: impl IntoIterator<Item = OutPoint>) {
+        let mut inner = self
+            .inner
+            .write()
+            .expect("pending txs lock should not be poisoned");
+        for outpoint in outpoints {
+            if inner.mempool_check_outpoints.len() >= MAX_MEMPOOL_CHECK_OUTPOINTS {
+                break;
+            }
+            if !inner.spent_outpoints.contains_key(&outpoint) {
+                inner.mempool_check_outpoints.insert(outpoint);
+            }
+        }
+    }
+
+    /// Take the outpoints waiting for the bitcoind mempool check.
+    pub fn take_mempool_check_outpoints(&self) -> Vec<OutPoint> {
+        self.inner
+            .write()
+            .expect("pending txs lock should not be poisoned")
+            .mempool_check_outpoints
+            .drain()
+            .collect()
+    }
+
+    fn expire_before(&self, now: u64) -> u64 {
+        now.saturating_sub(self.expiry.as_millis() as u64)
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoincore_rpc::bitcoin::absolute::LockTime;
+    use bitcoincore_rpc::bitcoin::hashes::Hash;
+    use bitcoincore_rpc::bitcoin::transaction::Version;
+    use bitcoincore_rpc::bitcoin::{Amount, ScriptBuf, Sequence, TxIn, TxOut, Witness};
+
+    fn outpoint(n: u8) -> OutPoint {
+        OutPoint::new(Txid::from_byte_array([n; 32]), 0)
+    }
+
+    fn new_tx(inputs: Vec<OutPoint>, value: u64) -> Transaction {
+        Transaction {
+            version: Version::TWO,
+            lock_time: LockTime::ZERO,
+            input: inputs
+                .into_iter()
+                .map(|previous_output| TxIn {
+                    previous_output,
+                    script_sig: ScriptBuf::new(),
+                    sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+                    witness: Witness::new(),
+                })
+                .collect(),
+            output: vec![TxOut {
+                value: Amount::from_sat(value),
+                script_pubkey: ScriptBuf::new(),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_replace_by_fee() {
+        let pending_txs = PendingBitcoinTransactions::default();
+        let tx1 = new_tx(vec![outpoint(1), outpoint(2)], 1000);
+        let tx2 = new_tx(vec![outpoint(2)], 900);
+        let txid1 = tx1.compute_txid();
+        let txid2 = tx2.compute_txid();
+        assert!(pending_txs.add(tx1, PendingTxSource::Broadcast).is_empty());
+        assert_eq!(pending_txs.spent_by(&outpoint(1)), Some(txid1));
+
+        assert_eq!(
+            pending_txs.add(tx2, PendingTxSource::Broadcast),
+            vec![txid1]
+        );
+        assert!(!pending_txs.contains(&txid1));
+        assert_eq!(pending_txs.spent_by(&outpoint(1)), None);
+        assert_eq!(pending_txs.spent_by(&outpoint(2)), Some(txid2));
+    }
+
+    #[test]
+    fn test_expiry_and_eviction() {
+        let pending_txs = PendingBitcoinTransactions::new(2, Duration::from_millis(100));
+        let tx1 = new_tx(vec![outpoint(1)], 1000);
+        let tx2 = new_tx(vec![outpoint(2)], 1000);
+        let tx3 = new_tx(vec![outpoint(3)], 1000);
+        let (txid1, txid2, txid3) = (tx1.compute_txid(), tx2.compute_txid(), tx3.compute_txid());
+        pending_txs.add_at(tx1, PendingTxSource::Broadcast, 1000);
+        pending_txs.add_at(tx2, PendingTxSource::Mempool, 1050);
+
+        // The oldest transaction is evicted when the set is full
+        pending_txs.add_at(tx3, PendingTxSource::Broadcast, 1060);
+        assert!(!pending_txs.contains(&txid1));
+        assert_eq!(pending_txs.spent_by(&outpoint(1)), None);
+        assert_eq!(
+            pending_txs
+                .list()
+                .iter()
+                .map(|pending_tx| pending_tx.txid())
+                .collect::<Vec<_>>(),
+            vec![txid2, txid3]
+        );
+
+        assert_eq!(pending_txs.remove_expired_at(1155), vec![txid2]);
+        assert!(pending_txs.contains(&txid3));
+        assert_eq!(pending_txs.remove_expired_at(1161), vec![txid3]);
+        assert!(pending_txs.is_empty());
+    }
+
+    #[test]
+    fn test_update_inputs() {
+        let pending_txs = PendingBitcoinTransactions::default();
+        let tx1 = new_tx(vec![outpoint(1), outpoint(2)], 1000);
+        let txid1 = tx1.compute_txid();
+        // The child spends the output of the pending parent
+        let tx2 = new_tx(vec![OutPoint::new(txid1, 0)], 900);
+        let txid2 = tx2.compute_txid();
+        pending_txs.add(tx1, PendingTxSource::Broadcast);
+        pending_txs.add(tx2, PendingTxSource::Broadcast);
+
+        let mut inputs = pending_txs.inputs_to_check();
+        inputs.sort();
+        let mut expected = vec![(txid1, outpoint(1)), (txid1, outpoint(2))];
+        expected.sort();
+        assert_eq!(inputs, expected);
+
+        // The inputs which are not relayed yet do not remove the transaction
+        assert!(pending_txs
+            .update_inputs(vec![
+                (txid1, outpoint(1), false),
+                (txid1, outpoint(2), true)
+            ])
+            .is_empty());
+        assert!(pending_txs.contains(&txid1));
+
+        // The seen input is spent, the transaction is confirmed
+        assert_eq!(
+            pending_txs.update_inputs(vec![(txid1, outpoint(2), false)]),
+            vec![txid1]
+        );
+        assert!(!pending_txs.contains(&txid1));
+        assert_eq!(
+            pending_txs.inputs_to_check(),
+            vec![(txid2, OutPoint::new(txid1, 0))]
+        );
+    }
+
+    #[test]
+    fn test_mempool_check_outpoints() {
+        let pending_txs = PendingBitcoinTransactions::default();
+        pending_txs.add(new_tx(vec![outpoint(1)], 1000), PendingTxSource::Broadcast);
+        pending_txs.request_mempool_check(vec![outpoint(1), outpoint(2), outpoint(2)]);
+        assert_eq!(
+            pending_txs.take_mempool_check_outpoints(),
+            vec![outpoint(2)]
+        );
+        assert!(pending_txs.take_mempool_check_outpoints().is_empty());
+    }
+}
@@ -1,43 +1,63 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::{format_err, Ok, Result};
-use bitcoincore_rpc::bitcoin::Txid;
+use crate::service::pending_btc_txs::{
+    PendingBitcoinTransaction, PendingBitcoinTransactions, PendingTxSource,
+};
+use anyhow::{ensure, format_err, Ok, Result};
+use bitcoincore_rpc::bitcoin::{self, Transaction, Txid};
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::{ModuleId, StructTag};
+use move_core_types::u256::U256;
 use moveos_types::access_path::AccessPath;
 use moveos_types::function_return_value::AnnotatedFunctionResult;
 use moveos_types::h256::H256;
 use moveos_types::move_types::type_tag_match;
 use moveos_types::moveos_std::display::{get_object_display_id, RawDisplay};
 use moveos_types::moveos_std::event::{AnnotatedEvent, Event, EventID};
+use moveos_types::moveos_std::gas_schedule::GasSchedule;
 use moveos_types::moveos_std::object::ObjectID;
-use moveos_types::state::{AnnotatedState, FieldKey, ObjectState, StateChangeSet};
+use moveos_types::state::{AnnotatedState, FieldKey, MoveStructType, ObjectState, StateChangeSet};
 use moveos_types::state_resolver::{AnnotatedStateKV, StateKV};
 use moveos_types::transaction::{FunctionCall, TransactionExecutionInfo};
+use rooch_db::RoochDB;
 use rooch_executor::actor::messages::DryRunTransactionResult;
 use rooch_executor::proxy::ExecutorProxy;
 use rooch_indexer::proxy::IndexerProxy;
 use rooch_pipeline_processor::proxy::PipelineProcessorProxy;
 use rooch_relayer::actor::bitcoin_client_proxy::BitcoinClientProxy;
+use rooch_rpc_api::api::MAX_RESULT_LIMIT_USIZE;
 use rooch_rpc_api::jsonrpc_types::{DisplayFieldsView, IndexerObjectStateView, ObjectMetaView};
 use rooch_sequencer::proxy::SequencerProxy;
+use rooch_txpool::pool::{PendingTransaction, TxPoolStatus};
+use rooch_txpool::proxy::TxPoolProxy;
 use rooch_types::address::{BitcoinAddress, RoochAddress};
+use rooch_types::backup::{BackupManifest, BACKUP_INDEXER_SUBDIR};
+use rooch_types::bitcoin::utxo::derive_utxo_id;
+use rooch_types::framework::account_coin_store::AccountCoinStoreModule;
 use rooch_types::framework::address_mapping::RoochToBitcoinAddressMapping;
+use rooch_types::framework::coin_store::CoinStoreInfo;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::framework::transaction_fee::TransactionFeeModule;
+use rooch_types::indexer::custom_index::{CustomIndexFilter, CustomIndexID};
 use rooch_types::indexer::event::{
     AnnotatedIndexerEvent, EventFilter, IndexerEvent, IndexerEventID,
 };
 use rooch_types::indexer::state::{
     IndexerObjectState, IndexerStateID, ObjectStateFilter, ObjectStateType, INSCRIPTION_TYPE_TAG,
-    UTXO_TYPE_TAG,
+    RUNE_BALANCES_TYPE_TAG, UTXO_TYPE_TAG,
 };
+use rooch_types::indexer::status::IndexerStatus;
 use rooch_types::indexer::transaction::{IndexerTransaction, TransactionFilter};
 use rooch_types::repair::{RepairIndexerParams, RepairIndexerType};
+use rooch_types::rooch_network::BuiltinChainID;
 use rooch_types::state::{StateChangeSetWithTxOrder, SyncStateFilter};
 use rooch_types::transaction::{
     ExecuteTransactionResponse, LedgerTransaction, RoochTransaction, RoochTransactionData,
 };
 use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use tracing::{debug, warn};
 
 /// RpcService is the implementation of the RPC service.
 /// It is the glue between the RPC server(EthAPIServer,RoochApiServer) and the rooch's actors.
@@ -50,7 +70,9 @@ pub struct RpcService {
     pub(crate) sequencer: SequencerProxy,
     pub(crate) indexer: IndexerProxy,
     pub(crate) pipeline_processor: PipelineProcessorProxy,
+    pub(crate) txpool: TxPoolProxy,
     pub(crate) bitcoin_client: Option<BitcoinClientProxy>,
+    pub(crate) pending_btc_txs: PendingBitcoinTransactions,
 }
 
 impl RpcService {
@@ -61,6 +83,7 @@ impl RpcService {
         sequencer: SequencerProxy,
         indexer: IndexerProxy,
         pipeline_processor: PipelineProcessorProxy,
+        txpool: TxPoolProxy,
         bitcoin_client: Option<BitcoinClientProxy>,
     ) -> Self {
         Self {
@@ -70,7 +93,9 @@ impl RpcService {
             sequencer,
             indexer,
             pipeline_processor,
+            txpool,
             bitcoin_client,
+            pending_btc_txs: PendingBitcoinTransactions::default(),
         }
     }
 }
@@ -84,14 +109,36 @@ impl RpcService {
         self.bitcoin_network
     }
 
+    /// Submit the tx to the txpool, do not wait to execute if the tx is waiting for the previous sequence numbers
     pub async fn queue_tx(&self, tx: RoochTransaction) -> Result<()> {
-        //TODO implement queue tx and do not wait to execute
-        let _ = self.execute_tx(tx).await?;
+        let _ = self.txpool.queue_tx(tx).await?;
         Ok(())
     }
 
     pub async fn execute_tx(&self, tx: RoochTransaction) -> Result<ExecuteTransactionResponse> {
-        self.pipeline_processor.execute_l2_tx(tx).await
+        self.txpool.execute_tx(tx).await
+    }
+
+    pub async fn get_pending_transactions(
+        &self,
+        sender: Option<RoochAddress>,
+    ) -> Result<Vec<PendingTransaction>> {
+        self.txpool.get_pending_transactions(sender).await
+    }
+
+    pub async fn get_txpool_status(&self) -> Result<TxPoolStatus> {
+        self.txpool.get_status().await
+    }
+
+    /// Get the current base fee per gas of the fee market
+    pub async fn get_base_fee(&self) -> Result<u64> {
+        let return_value = self
+            .execute_view_function(TransactionFeeModule::base_fee_call())
+            .await?
+            .return_values
+            .and_then(|mut values| values.pop())
+            .ok_or_else(|| format_err!("Failed to get the base fee"))?;
+        Ok(bcs::from_bytes::<u64>(&return_value.value.value)?)
     }
 
     pub async fn dry_run_tx(&self, tx: RoochTransactionData) -> Result<DryRunTransactionResult> {
@@ -99,6 +146,69 @@ impl RpcService {
         self.executor.dry_run_transaction(verified_tx).await
     }
 
+    /// Dry run the tx with the max gas amount the gas payment account can afford,
+    /// but not more than the max gas amount of the gas schedule, the max gas amount of the tx is ignored.
+    /// The gas is paid by the fee payer if the tx is sponsored, otherwise by the sender.
+    /// The gas balance is not checked in the local or dev chain, so the max gas amount of the gas schedule is used.
+    /// Returns the dry run result and the max gas amount used to dry run.
+    pub async fn estimate_gas(
+        &self,
+        mut tx: RoochTransactionData,
+    ) -> Result<(DryRunTransactionResult, u64)> {
+        let gas_schedule = self
+            .get_states(
+                AccessPath::object(GasSchedule::gas_schedule_object_id()),
+                None,
+            )
+            .await?
+            .pop()
+            .flatten()
+            .ok_or_else(|| format_err!("Failed to get the gas schedule"))?
+            .value_as::<GasSchedule>()?;
+        let is_balance_enforced = BuiltinChainID::try_from(self.chain_id)
+            .map_or(true, |chain_id| !chain_id.is_local() && !chain_id.is_dev());
+        tx.max_gas_amount = if is_balance_enforced {
+            let gas_payment_account = tx.fee_payer.unwrap_or(tx.sender);
+            let base_fee = self.get_base_fee().await?;
+            let gas_price = tx
+                .gas_price
+                .map(|gas_price| gas_price.gas_price(base_fee))
+                .unwrap_or(base_fee)
+                .max(1);
+            let coin_store_id = AccountCoinStoreModule::account_coin_store_id(
+                gas_payment_account.into(),
+                RGas::struct_tag(),
+            );
+            let balance = self
+                .get_states(AccessPath::object(coin_store_id), None)
+                .await?
+                .pop()
+                .flatten()
+                .map(CoinStoreInfo::try_from)
+                .transpose()?
+                .map(|coin_store| coin_store.balance())
+                .unwrap_or_default();
+            let affordable_gas_amount = balance / U256::from(gas_price);
+            let max_gas_amount = if affordable_gas_amount < U256::from(gas_schedule.max_gas_amount)
+            {
+                affordable_gas_amount.unchecked_as_u64()
+            } else {
+                gas_schedule.max_gas_amount
+            };
+            ensure!(
+                max_gas_amount > 0,
+                "The gas balance of {} is not enough to pay the gas",
+                gas_payment_account
+            );
+            max_gas_amount
+        } else {
+            gas_schedule.max_gas_amount
+        };
+        let max_gas_amount = tx.max_gas_amount;
+        let result = self.dry_run_tx(tx).await?;
+        Ok((result, max_gas_amount))
+    }
+
     pub async fn execute_view_function(
         &self,
         function_call: FunctionCall,
@@ -120,6 +230,10 @@ impl RpcService {
         self.executor.get_states(access_path, state_root).await
     }
 
+    pub async fn get_state_nodes(&self, node_hashes: Vec<H256>) -> Result<Vec<Option<Vec<u8>>>> {
+        self.executor.get_state_nodes(node_hashes).await
+    }
+
     pub async fn exists_module(&self, module_id: ModuleId) -> Result<bool> {
         let mut resp = self
             .get_states(AccessPath::module(&module_id), None)
@@ -342,6 +456,15 @@ impl RpcService {
         Ok(result)
     }
 
+    /// The current indexer generation, the state cursors are only valid in the same generation
+    pub async fn indexer_generation(&self) -> Result<u64> {
+        self.indexer.query_generation().await
+    }
+
+    pub async fn indexer_status(&self) -> Result<IndexerStatus> {
+        self.indexer.query_status().await
+    }
+
     pub async fn query_object_states(
         &self,
         filter: ObjectStateFilter,
@@ -366,6 +489,32 @@ impl RpcService {
                     .await?
             }
         };
+        self.get_indexer_object_states(indexer_ids, decode, show_display)
+            .await
+    }
+
+    /// Query the object ids in the custom index, the caller pages by the returned custom index ids
+    /// as the objects not found in the state are skipped by `get_indexer_object_states`.
+    pub async fn query_object_ids_by_custom_index(
+        &self,
+        filter: CustomIndexFilter,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<CustomIndexID>,
+        limit: usize,
+        descending_order: bool,
+    ) -> Result<Vec<(ObjectID, CustomIndexID)>> {
+        self.indexer
+            .query_object_ids_by_custom_index(filter, cursor, limit, descending_order)
+            .await
+    }
+
+    /// Get the object states of the indexer ids, the objects not found in the state are skipped.
+    pub async fn get_indexer_object_states(
+        &self,
+        indexer_ids: Vec<(ObjectID, IndexerStateID)>,
+        decode: bool,
+        show_display: bool,
+    ) -> Result<Vec<IndexerObjectStateView>> {
         let object_ids = indexer_ids.iter().map(|m| m.0.clone()).collect::<Vec<_>>();
 
         let access_path = AccessPath::objects(object_ids.clone());
@@ -559,9 +708,130 @@ impl RpcService {
             .as_ref()
             .ok_or_else(|| anyhow::anyhow!("Bitcoin client is not configured"))?;
 
-        bitcoin_client
+        let tx: Transaction = bitcoin::consensus::encode::deserialize_hex(&hex)?;
+        let txid = bitcoin_client
             .broadcast_transaction(hex, maxfeerate, maxburnamount)
-            .await
+            .await?;
+        let replaced = self.pending_btc_txs.add(tx, PendingTxSource::Broadcast);
+        if !replaced.is_empty() {
+            debug!("Bitcoin transaction {} replaces {:?}", txid, replaced);
+        }
+        Ok(txid)
+    }
+
+    /// Get the pending Bitcoin transactions, the expired transactions are removed before return
+    pub async fn query_pending_bitcoin_transactions(
+        &self,
+    ) -> Result<Vec<PendingBitcoinTransaction>> {
+        self.pending_btc_txs.remove_expired();
+        Ok(self.pending_btc_txs.list())
+    }
+
+    /// The pending Bitcoin transactions are kept in memory, so they are rebuilt from the bitcoind mempool on startup.
+    /// The transactions broadcast before the restart are still in the mempool if they are not confirmed,
+    /// the newest transactions are loaded if the mempool is larger than the pending set.
+    pub async fn rebuild_pending_bitcoin_transactions(&self) -> Result<()> {
+        let Some(bitcoin_client) = &self.bitcoin_client else {
+            return Ok(());
+        };
+        let mut entries = bitcoin_client.get_raw_mempool().await?;
+        entries.sort_by(|(_, a), (_, b)| b.cmp(a));
+        entries.truncate(self.pending_btc_txs.max_txs());
+        // Add the older transactions first, so the newer ones are kept when they replace each other
+        for (txid, time) in entries.into_iter().rev() {
+            match bitcoin_client.get_raw_transaction(txid).await {
+                std::result::Result::Ok(tx) => {
+                    self.pending_btc_txs
+                        .add_at(tx, PendingTxSource::Mempool, time * 1000);
+                }
+                // The transaction may be confirmed or evicted from the mempool
+                Err(e) => debug!("Failed to get mempool transaction {}: {:?}", txid, e),
+            }
+        }
+        Ok(())
+    }
+
+    /// Sync the pending Bitcoin transactions in background:
+    /// remove the expired and confirmed transactions,
+    /// and add the bitcoind mempool transactions which spend the requested outpoints.
+    pub async fn sync_pending_bitcoin_transactions(&self) -> Result<()> {
+        let expired = self.pending_btc_txs.remove_expired();
+        if !expired.is_empty() {
+            debug!("Remove expired pending Bitcoin transactions {:?}", expired);
+        }
+        self.prune_pending_bitcoin_transactions().await?;
+        self.check_mempool_spending_transactions().await
+    }
+
+    /// Remove the pending Bitcoin transactions which seen input UTXOs are spent in the Rooch state,
+    /// the transaction or its conflicting transaction is confirmed.
+    async fn prune_pending_bitcoin_transactions(&self) -> Result<()> {
+        let inputs = self.pending_btc_txs.inputs_to_check();
+        if inputs.is_empty() {
+            return Ok(());
+        }
+        let utxo_ids = inputs
+            .iter()
+            .map(|(_, outpoint)| derive_utxo_id(&(*outpoint).into()))
+            .collect::<Vec<_>>();
+        let utxo_states = self.get_states(AccessPath::objects(utxo_ids), None).await?;
+        let removed = self.pending_btc_txs.update_inputs(
+            inputs
+                .into_iter()
+                .zip(utxo_states)
+                .map(|((txid, outpoint), utxo_state)| (txid, outpoint, utxo_state.is_some()))
+                .collect(),
+        );
+        if !removed.is_empty() {
+            debug!(
+                "Remove confirmed pending Bitcoin transactions {:?}",
+                removed
+            );
+        }
+        Ok(())
+    }
+
+    /// Check the outpoints requested by the UTXO queries in the bitcoind mempool,
+    /// and add the spending transactions to the pending set.
+    async fn check_mempool_spending_transactions(&self) -> Result<()> {
+        let Some(bitcoin_client) = &self.bitcoin_client else {
+            return Ok(());
+        };
+        let outpoints = self.pending_btc_txs.take_mempool_check_outpoints();
+        for outpoints in outpoints.chunks(MAX_RESULT_LIMIT_USIZE) {
+            let spending_txids = bitcoin_client
+                .get_tx_spending_prevout(outpoints.to_vec())
+                .await?;
+            for spending_txid in spending_txids.into_iter().flatten() {
+                if self.pending_btc_txs.contains(&spending_txid) {
+                    continue;
+                }
+                match bitcoin_client.get_raw_transaction(spending_txid).await {
+                    std::result::Result::Ok(tx) => {
+                        self.pending_btc_txs.add(tx, PendingTxSource::Mempool);
+                    }
+                    Err(e) => warn!(
+                        "Failed to get mempool transaction {}: {:?}",
+                        spending_txid, e
+                    ),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Backup the db of the running node, the indexer is copied first by the indexer actor,
+    /// then the pipeline processor creates the store checkpoint between two transactions,
+    /// so the indexer backup is never ahead of the store backup.
+    pub async fn backup_db(&self, backup_dir: PathBuf) -> Result<BackupManifest> {
+        RoochDB::prepare_backup_dir(&backup_dir)?;
+        self.indexer
+            .backup(backup_dir.join(BACKUP_INDEXER_SUBDIR))
+            .await?;
+        self.pipeline_processor
+            .backup_store(backup_dir.clone())
+            .await?;
+        tokio::task::spawn_blocking(move || RoochDB::finish_backup(&backup_dir)).await?
     }
 
     pub async fn repair_indexer(
@@ -583,7 +853,12 @@ impl RpcService {
                             ObjectStateType::UTXO,
                         )
                         .await?;
-                        self.repair_indexer_object_states(object_ids, ObjectStateType::Inscription)
+                        self.repair_indexer_object_states(
+                            object_ids.clone(),
+                            ObjectStateType::Inscription,
+                        )
+                        .await?;
+                        self.repair_indexer_object_states(object_ids, ObjectStateType::Rune)
                             .await
                     }
                     _ => Err(format_err!(
@@ -635,6 +910,12 @@ impl RpcService {
                                 object_states_mapping.insert(state.metadata.id.clone(), state);
                             }
                         }
+                        ObjectStateType::Rune => {
+                            if type_tag_match(&state.metadata.object_type, &RUNE_BALANCES_TYPE_TAG)
+                            {
+                                object_states_mapping.insert(state.metadata.id.clone(), state);
+                            }
+                        }
                     },
                     None => remove_object_ids.push(object_ids[idx].clone()),
                 }
@@ -706,12 +987,17 @@ impl RpcService {
                 vec![]
             };
 
+            // The new object states are inserted at the genesis tx order, they reshuffle the state cursors
+            let reindexed = !new_object_states.is_empty();
             update_object_states.append(&mut new_object_states);
             if !update_object_states.is_empty() {
                 self.indexer
                     .persist_or_update_object_states(update_object_states, state_type.clone())
                     .await?;
             }
+            if reindexed {
+                self.indexer.renew_generation().await?;
+            }
 
             if !remove_object_ids.is_empty() {
                 self.indexer
@@ -0,0 +1,112 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{anyhow, Result};
+use once_cell::sync::OnceCell;
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{runtime, trace, Resource};
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{reload, Registry};
+
+const SERVICE_NAME: &str = "rooch";
+
+type OtlpLayer = OpenTelemetryLayer<Registry, trace::Tracer>;
+type OtlpLayerHandle = reload::Handle<Option<OtlpLayer>, Registry>;
+
+static OTLP_LAYER_HANDLE: OnceCell<OtlpLayerHandle> = OnceCell::new();
+
+/// Create the OpenTelemetry layer which is disabled until a tracer is set via the handle
+fn reloadable_otlp_layer() -> (reload::Layer<Option<OtlpLayer>, Registry>, OtlpLayerHandle) {
+    reload::Layer::new(None)
+}
+
+fn set_otlp_tracer(handle: &OtlpLayerHandle, tracer: trace::Tracer) -> Result<()> {
+    handle
+        .reload(Some(tracing_opentelemetry::layer().with_tracer(tracer)))
+        .map_err(|e| anyhow!("Reload the OpenTelemetry layer error: {}", e))
+}
+
+/// Init the global tracing subscriber with the fmt layer at the info level and a disabled OpenTelemetry layer,
+/// the OpenTelemetry layer is enabled later by `init_tracing` once the OTLP endpoint is known.
+/// The tracing subscriber can only be inited once, the later calls return an error.
+pub fn init_subscriber() -> Result<()> {
+    let (otlp_layer, handle) = reloadable_otlp_layer();
+    tracing_subscriber::registry()
+        .with(otlp_layer)
+        .with(tracing_subscriber::fmt::layer())
+        .with(LevelFilter::INFO)
+        .try_init()?;
+    let _ = OTLP_LAYER_HANDLE.set(handle);
+    Ok(())
+}
+
+/// Export the spans to the OpenTelemetry collector via OTLP gRPC if the `otlp_endpoint` is set,
+/// such as `http://localhost:4317`. The global tracing subscriber is inited if it is not inited yet,
+/// an error is returned if the endpoint is set but the subscriber is not inited by `init_subscriber`.
+pub fn init_tracing(otlp_endpoint: Option<&str>) -> Result<()> {
+    // The subscriber may already be inited by the main function or a previous server in the tests
+    let _ = init_subscriber();
+    let Some(otlp_endpoint) = otlp_endpoint else {
+        return Ok(());
+    };
+    let handle = OTLP_LAYER_HANDLE.get().ok_or_else(|| {
+        anyhow!(
+            "The global tracing subscriber is not inited by telemetry::init_subscriber, can not export the spans to {}",
+            otlp_endpoint
+        )
+    })?;
+    let tracer = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .tonic()
+                .with_endpoint(otlp_endpoint),
+        )
+        .with_trace_config(
+            trace::config().with_resource(Resource::new(vec![KeyValue::new(
+                "service.name",
+                SERVICE_NAME,
+            )])),
+        )
+        .install_batch(runtime::Tokio)?;
+    set_otlp_tracer(handle, tracer)
+}
+
+/// Flush the pending spans and shutdown the OpenTelemetry tracer provider.
+pub fn shutdown_tracing() {
+    opentelemetry::global::shutdown_tracer_provider();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::trace::TracerProvider as _;
+    use opentelemetry_sdk::testing::trace::InMemorySpanExporter;
+    use opentelemetry_sdk::trace::TracerProvider;
+
+    #[test]
+    fn test_enable_otlp_layer_after_subscriber_inited() {
+        let (otlp_layer, handle) = reloadable_otlp_layer();
+        let subscriber = tracing_subscriber::registry()
+            .with(otlp_layer)
+            .with(tracing_subscriber::fmt::layer());
+        let exporter = InMemorySpanExporter::default();
+        let provider = TracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info_span!("before_enabled").in_scope(|| {});
+            set_otlp_tracer(&handle, provider.tracer(SERVICE_NAME)).unwrap();
+            tracing::info_span!("after_enabled").in_scope(|| {});
+        });
+
+        let spans = exporter.get_finished_spans().unwrap();
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].name, "after_enabled");
+    }
+}
@@ -6,8 +6,8 @@ use std::sync::Arc;
 use std::time::SystemTime;
 
 use crate::messages::{
-    GetSequencerOrderMessage, GetTransactionByHashMessage, GetTransactionsByHashMessage,
-    GetTxHashsMessage, TransactionSequenceMessage,
+    GetSequencerOrderMessage, GetServiceStatusMessage, GetTransactionByHashMessage,
+    GetTransactionsByHashMessage, GetTxHashsMessage, TransactionSequenceMessage,
 };
 use crate::metrics::SequencerMetrics;
 use accumulator::{Accumulator, MerkleAccumulator};
@@ -182,7 +182,7 @@ impl Handler<TransactionSequenceMessage> for SequencerActor {
         msg: TransactionSequenceMessage,
         _ctx: &mut ActorContext,
     ) -> Result<LedgerTransaction> {
-        self.sequence(msg.tx)
+        msg.span.in_scope(|| self.sequence(msg.tx))
     }
 }
 
@@ -231,6 +231,17 @@ impl Handler<GetSequencerOrderMessage> for SequencerActor {
     }
 }
 
+#[async_trait]
+impl Handler<GetServiceStatusMessage> for SequencerActor {
+    async fn handle(
+        &mut self,
+        _msg: GetServiceStatusMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<ServiceStatus> {
+        Ok(self.service_status)
+    }
+}
+
 #[async_trait]
 impl Handler<EventData> for SequencerActor {
     async fn handle(&mut self, msg: EventData, _ctx: &mut ActorContext) -> Result<()> {
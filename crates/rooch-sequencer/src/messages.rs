@@ -4,13 +4,18 @@
 use anyhow::Result;
 use coerce::actor::message::Message;
 use moveos_types::h256::H256;
+use rooch_types::service_status::ServiceStatus;
 use rooch_types::transaction::{LedgerTransaction, LedgerTxData};
 use serde::{Deserialize, Serialize};
+use tracing::Span;
 
 /// Transaction Sequence Message
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TransactionSequenceMessage {
     pub tx: LedgerTxData,
+    /// The tracing span of the tx lifecycle
+    #[serde(skip, default = "Span::none")]
+    pub span: Span,
 }
 
 impl Message for TransactionSequenceMessage {
@@ -52,3 +57,10 @@ pub struct GetSequencerOrderMessage {}
 impl Message for GetSequencerOrderMessage {
     type Result = Result<u64>;
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetServiceStatusMessage {}
+
+impl Message for GetServiceStatusMessage {
+    type Result = Result<ServiceStatus>;
+}
@@ -2,14 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::messages::{
-    GetSequencerOrderMessage, GetTransactionByHashMessage, GetTransactionsByHashMessage,
-    GetTxHashsMessage,
+    GetSequencerOrderMessage, GetServiceStatusMessage, GetTransactionByHashMessage,
+    GetTransactionsByHashMessage, GetTxHashsMessage,
 };
 use crate::{actor::sequencer::SequencerActor, messages::TransactionSequenceMessage};
 use anyhow::Result;
 use coerce::actor::ActorRef;
 use moveos_types::h256::H256;
+use rooch_types::service_status::ServiceStatus;
 use rooch_types::transaction::{LedgerTransaction, LedgerTxData};
+use tracing::Span;
 
 #[derive(Clone)]
 pub struct SequencerProxy {
@@ -22,7 +24,12 @@ impl SequencerProxy {
     }
 
     pub async fn sequence_transaction(&self, tx: LedgerTxData) -> Result<LedgerTransaction> {
-        self.actor.send(TransactionSequenceMessage { tx }).await?
+        self.actor
+            .send(TransactionSequenceMessage {
+                tx,
+                span: Span::current(),
+            })
+            .await?
     }
 
     pub async fn get_transaction_by_hash(&self, hash: H256) -> Result<Option<LedgerTransaction>> {
@@ -47,4 +54,8 @@ impl SequencerProxy {
     pub async fn get_sequencer_order(&self) -> Result<u64> {
         self.actor.send(GetSequencerOrderMessage {}).await?
     }
+
+    pub async fn get_service_status(&self) -> Result<ServiceStatus> {
+        self.actor.send(GetServiceStatusMessage {}).await?
+    }
 }
@@ -0,0 +1,64 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::pool::{PendingTransaction, TxPoolStatus};
+use anyhow::Result;
+use coerce::actor::{message::Message, scheduler::timer::TimerTick};
+use moveos_types::h256::H256;
+use rooch_types::address::RoochAddress;
+use rooch_types::transaction::{ExecuteTransactionResponse, RoochTransaction};
+use tokio::sync::oneshot;
+use tracing::Span;
+
+/// Submit a tx to the txpool, the tx is executed once all the previous sequence numbers of its sender are executed.
+#[derive(Clone)]
+pub struct SubmitTxMessage {
+    pub tx: RoochTransaction,
+    /// The tracing span of the tx lifecycle
+    pub span: Span,
+}
+
+impl Message for SubmitTxMessage {
+    type Result = Result<TxPoolReceipt>;
+}
+
+/// The receipt of a submitted tx, the execution result is sent to the `receiver`
+pub struct TxPoolReceipt {
+    pub tx_hash: H256,
+    /// The tx is waiting in the txpool for the previous sequence numbers
+    pub pending: bool,
+    pub receiver: oneshot::Receiver<Result<ExecuteTransactionResponse>>,
+}
+
+/// Execute the ready tx with the highest priority fee
+#[derive(Clone)]
+pub struct ProcessReadyTxMessage {}
+
+impl Message for ProcessReadyTxMessage {
+    type Result = ();
+}
+
+#[derive(Debug, Clone)]
+pub struct GetPendingTxsMessage {
+    pub sender: Option<RoochAddress>,
+}
+
+impl Message for GetPendingTxsMessage {
+    type Result = Result<Vec<PendingTransaction>>;
+}
+
+#[derive(Debug, Clone)]
+pub struct GetTxPoolStatusMessage {}
+
+impl Message for GetTxPoolStatusMessage {
+    type Result = Result<TxPoolStatus>;
+}
+
+#[derive(Clone)]
+pub struct EvictExpiredTxsMessage {}
+
+impl Message for EvictExpiredTxsMessage {
+    type Result = ();
+}
+
+impl TimerTick for EvictExpiredTxsMessage {}
@@ -0,0 +1,158 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::messages::{
+    EvictExpiredTxsMessage, GetPendingTxsMessage, GetTxPoolStatusMessage, ProcessReadyTxMessage,
+    SubmitTxMessage, TxPoolReceipt,
+};
+use crate::pool::{PendingTransaction, TxPool, TxPoolStatus};
+use anyhow::Result;
+use async_trait::async_trait;
+use coerce::actor::{context::ActorContext, message::Handler, Actor};
+use rooch_executor::proxy::ExecutorProxy;
+use rooch_pipeline_processor::proxy::PipelineProcessorProxy;
+use rooch_types::address::RoochAddress;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::oneshot;
+use tracing::{debug, info, warn, Instrument};
+
+/// TxPoolActor holds the L2 txs whose sequence numbers are ahead of the sender's on-chain sequence number,
+/// and sends them to the pipeline processor in sequence number order once the gaps are filled.
+/// When there is a backlog of ready txs, the tx with the higher priority fee is executed first.
+pub struct TxPoolActor {
+    pool: TxPool,
+    executor: ExecutorProxy,
+    pipeline_processor: PipelineProcessorProxy,
+}
+
+impl TxPoolActor {
+    pub fn new(
+        executor: ExecutorProxy,
+        pipeline_processor: PipelineProcessorProxy,
+        max_pending_per_sender: usize,
+        tx_expiration_secs: u64,
+    ) -> Self {
+        Self {
+            pool: TxPool::new(max_pending_per_sender, tx_expiration_secs),
+            executor,
+            pipeline_processor,
+        }
+    }
+
+    async fn get_sequence_number(&self, sender: RoochAddress) -> Result<u64> {
+        self.executor.get_sequence_number(sender.into()).await
+    }
+
+    fn notify_process_ready_tx(ctx: &mut ActorContext) {
+        if let Err(e) = ctx.actor_ref::<Self>().notify(ProcessReadyTxMessage {}) {
+            warn!("TxPool notify process ready tx error: {:?}", e);
+        }
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+impl Actor for TxPoolActor {}
+
+#[async_trait]
+impl Handler<SubmitTxMessage> for TxPoolActor {
+    async fn handle(
+        &mut self,
+        msg: SubmitTxMessage,
+        ctx: &mut ActorContext,
+    ) -> Result<TxPoolReceipt> {
+        let mut tx = msg.tx;
+        let tx_hash = tx.tx_hash();
+        let sender = tx.sender();
+        let tx_sequence_number = tx.sequence_number();
+        let sequence_number = self.get_sequence_number(sender).await?;
+        // Only the txs signed by the sender can take the sequence numbers of the sender in the txpool
+        self.executor
+            .validate_l2_tx_authenticator(tx.clone(), sequence_number)
+            .await?;
+        let (waiter, receiver) = oneshot::channel();
+        self.pool
+            .add(tx, tx_hash, sequence_number, waiter, msg.span, now_millis())?;
+        // The submitted txs in the mailbox are added to the txpool before the ready tx is chosen,
+        // so the backlog is sequenced by priority fee.
+        Self::notify_process_ready_tx(ctx);
+        Ok(TxPoolReceipt {
+            tx_hash,
+            pending: tx_sequence_number > sequence_number,
+            receiver,
+        })
+    }
+}
+
+#[async_trait]
+impl Handler<ProcessReadyTxMessage> for TxPoolActor {
+    async fn handle(&mut self, _msg: ProcessReadyTxMessage, ctx: &mut ActorContext) {
+        let Some(pending_tx) = self.pool.pop_ready() else {
+            return;
+        };
+        let sender = pending_tx.tx.sender();
+        debug!(
+            "TxPool execute tx {} of sender {} with sequence number {}",
+            pending_tx.tx_hash,
+            sender,
+            pending_tx.tx.sequence_number()
+        );
+        let result = self
+            .pipeline_processor
+            .execute_l2_tx(pending_tx.tx.clone())
+            .instrument(pending_tx.span.clone())
+            .await;
+        pending_tx.notify(result);
+        match self.get_sequence_number(sender).await {
+            Ok(sequence_number) => self.pool.update_sequence_number(&sender, sequence_number),
+            Err(e) => warn!(
+                "TxPool get sequence number of sender {} error: {:?}",
+                sender, e
+            ),
+        }
+        if self.pool.status().ready_count > 0 {
+            Self::notify_process_ready_tx(ctx);
+        }
+    }
+}
+
+#[async_trait]
+impl Handler<GetPendingTxsMessage> for TxPoolActor {
+    async fn handle(
+        &mut self,
+        msg: GetPendingTxsMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<PendingTransaction>> {
+        Ok(self.pool.pending_txs(msg.sender))
+    }
+}
+
+#[async_trait]
+impl Handler<GetTxPoolStatusMessage> for TxPoolActor {
+    async fn handle(
+        &mut self,
+        _msg: GetTxPoolStatusMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<TxPoolStatus> {
+        Ok(self.pool.status())
+    }
+}
+
+#[async_trait]
+impl Handler<EvictExpiredTxsMessage> for TxPoolActor {
+    async fn handle(&mut self, _msg: EvictExpiredTxsMessage, _ctx: &mut ActorContext) {
+        let evicted = self.pool.evict_expired(now_millis());
+        if evicted > 0 {
+            info!(
+                "TxPool evicted {} expired txs, {} txs are pending",
+                evicted,
+                self.pool.len()
+            );
+        }
+    }
+}
@@ -0,0 +1,440 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{anyhow, bail, Result};
+use moveos_types::h256::H256;
+use rooch_types::address::RoochAddress;
+use rooch_types::transaction::{ExecuteTransactionResponse, RoochTransaction};
+use std::collections::{BTreeMap, HashMap};
+use tokio::sync::oneshot;
+use tracing::Span;
+
+pub const DEFAULT_MAX_PENDING_PER_SENDER: usize = 64;
+pub const DEFAULT_TX_EXPIRATION_SECS: u64 = 600;
+
+pub type TxResultSender = oneshot::Sender<Result<ExecuteTransactionResponse>>;
+
+/// A transaction waiting in the txpool for the previous sequence numbers of its sender.
+pub struct PendingTx {
+    pub tx: RoochTransaction,
+    pub tx_hash: H256,
+    /// The timestamp in milliseconds when the tx is added to the txpool
+    pub timestamp: u64,
+    /// The tracing span of the tx lifecycle, the tx is executed in the span
+    pub span: Span,
+    waiters: Vec<TxResultSender>,
+}
+
+impl PendingTx {
+    /// Send the execution result to all the waiters of the tx
+    pub fn notify(mut self, result: Result<ExecuteTransactionResponse>) {
+        let Some(first_waiter) = self.waiters.pop() else {
+            return;
+        };
+        for waiter in self.waiters {
+            let result = match &result {
+                Ok(response) => Ok(response.clone()),
+                Err(e) => Err(anyhow!("{:?}", e)),
+            };
+            let _ = waiter.send(result);
+        }
+        let _ = first_waiter.send(result);
+    }
+}
+
+/// The view of a pending transaction
+#[derive(Debug, Clone)]
+pub struct PendingTransaction {
+    pub tx: RoochTransaction,
+    pub tx_hash: H256,
+    pub timestamp: u64,
+}
+
+impl From<&PendingTx> for PendingTransaction {
+    fn from(pending_tx: &PendingTx) -> Self {
+        Self {
+            tx: pending_tx.tx.clone(),
+            tx_hash: pending_tx.tx_hash,
+            timestamp: pending_tx.timestamp,
+        }
+    }
+}
+
+/// The status of the txpool
+#[derive(Debug, Clone, Default)]
+pub struct TxPoolStatus {
+    /// The number of all the txs in the txpool
+    pub pending_count: usize,
+    /// The number of the txs which can be executed now, they are the backlog of the txpool
+    pub ready_count: usize,
+    /// The max priority fee of the ready txs
+    pub max_ready_priority_fee: Option<u64>,
+}
+
+struct SenderQueue {
+    /// The sequence number of the sender on chain, the tx with this sequence number is ready
+    next_sequence_number: u64,
+    txs: BTreeMap<u64, PendingTx>,
+}
+
+impl SenderQueue {
+    fn ready_tx(&self) -> Option<&PendingTx> {
+        self.txs.get(&self.next_sequence_number)
+    }
+
+    /// Drop the stale txs whose sequence numbers are already used
+    fn drop_stale(&mut self, sender: &RoochAddress) {
+        while let Some(entry) = self.txs.first_entry() {
+            let sequence_number = *entry.key();
+            if sequence_number >= self.next_sequence_number {
+                break;
+            }
+            entry.remove().notify(Err(anyhow!(
+                "The sequence number {} is already used by another tx of the sender {}",
+                sequence_number,
+                sender
+            )));
+        }
+    }
+}
+
+/// The per-sender queues of the transactions ordered by sequence number.
+/// The ready txs of different senders are released in priority fee order.
+pub struct TxPool {
+    max_pending_per_sender: usize,
+    /// The expiration of the pending txs in milliseconds
+    tx_expiration: u64,
+    queues: HashMap<RoochAddress, SenderQueue>,
+}
+
+impl TxPool {
+    pub fn new(max_pending_per_sender: usize, tx_expiration_secs: u64) -> Self {
+        Self {
+            max_pending_per_sender,
+            tx_expiration: tx_expiration_secs * 1000,
+            queues: HashMap::new(),
+        }
+    }
+
+    /// Add the tx to the queue of its sender, `chain_sequence_number` is the current sequence number
+    /// of the sender on chain. The authenticator of the tx must be verified before it is added.
+    /// A pending tx is only replaced by a different tx with the same sequence number and a higher priority fee.
+    pub fn add(
+        &mut self,
+        tx: RoochTransaction,
+        tx_hash: H256,
+        chain_sequence_number: u64,
+        waiter: TxResultSender,
+        span: Span,
+        now: u64,
+    ) -> Result<()> {
+        let sender = tx.sender();
+        let sequence_number = tx.sequence_number();
+        if sequence_number < chain_sequence_number {
+            bail!(
+                "The sequence number {} of tx {} is too old, the sender {} expects {}",
+                sequence_number,
+                tx_hash,
+                sender,
+                chain_sequence_number
+            );
+        }
+        if sequence_number - chain_sequence_number >= self.max_pending_per_sender as u64 {
+            bail!(
+                "The sequence number {} of tx {} is too new, the sender {} expects {}, at most {} txs can be pending",
+                sequence_number,
+                tx_hash,
+                sender,
+                chain_sequence_number,
+                self.max_pending_per_sender
+            );
+        }
+
+        let queue = self.queues.entry(sender).or_insert_with(|| SenderQueue {
+            next_sequence_number: chain_sequence_number,
+            txs: BTreeMap::new(),
+        });
+        queue.next_sequence_number = chain_sequence_number;
+        queue.drop_stale(&sender);
+        if let Some(pending_tx) = queue.txs.get_mut(&sequence_number) {
+            if pending_tx.tx_hash == tx_hash {
+                pending_tx.waiters.push(waiter);
+                return Ok(());
+            }
+            if tx.priority_fee() <= pending_tx.tx.priority_fee() {
+                bail!(
+                    "The tx {} with sequence number {} of the sender {} is pending, the replacement tx {} must have a higher priority fee than {}",
+                    pending_tx.tx_hash,
+                    sequence_number,
+                    sender,
+                    tx_hash,
+                    pending_tx.tx.priority_fee()
+                );
+            }
+        } else if queue.txs.len() >= self.max_pending_per_sender {
+            bail!(
+                "Too many pending txs of the sender {}, the limit is {}",
+                sender,
+                self.max_pending_per_sender
+            );
+        }
+        let replaced = queue.txs.insert(
+            sequence_number,
+            PendingTx {
+                tx,
+                tx_hash,
+                timestamp: now,
+                span,
+                waiters: vec![waiter],
+            },
+        );
+        if let Some(replaced) = replaced {
+            replaced.notify(Err(anyhow!(
+                "The tx is replaced by the tx {} with the same sequence number {}",
+                tx_hash,
+                sequence_number
+            )));
+        }
+        Ok(())
+    }
+
+    /// Take the ready tx with the highest priority fee, the earlier tx wins if the priority fees are equal.
+    pub fn pop_ready(&mut self) -> Option<PendingTx> {
+        let sender = self
+            .queues
+            .iter()
+            .filter_map(|(sender, queue)| queue.ready_tx().map(|pending_tx| (sender, pending_tx)))
+            .max_by(|(_, a), (_, b)| {
+                a.tx.priority_fee()
+                    .cmp(&b.tx.priority_fee())
+                    .then_with(|| b.timestamp.cmp(&a.timestamp))
+            })
+            .map(|(sender, _)| *sender)?;
+        let queue = self.queues.get_mut(&sender)?;
+        let ready = queue.txs.remove(&queue.next_sequence_number);
+        if queue.txs.is_empty() {
+            self.queues.remove(&sender);
+        }
+        ready
+    }
+
+    /// Update the sequence number of the sender on chain after a tx of the sender is executed
+    pub fn update_sequence_number(&mut self, sender: &RoochAddress, chain_sequence_number: u64) {
+        if let Some(queue) = self.queues.get_mut(sender) {
+            queue.next_sequence_number = chain_sequence_number;
+            queue.drop_stale(sender);
+            if queue.txs.is_empty() {
+                self.queues.remove(sender);
+            }
+        }
+    }
+
+    /// Evict the txs which are pending longer than the expiration, returns the number of evicted txs
+    pub fn evict_expired(&mut self, now: u64) -> usize {
+        let mut evicted = 0;
+        let tx_expiration = self.tx_expiration;
+        self.queues.retain(|sender, queue| {
+            let expired = queue
+                .txs
+                .iter()
+                .filter(|(_, pending_tx)| pending_tx.timestamp + tx_expiration <= now)
+                .map(|(sequence_number, _)| *sequence_number)
+                .collect::<Vec<_>>();
+            for sequence_number in expired {
+                if let Some(pending_tx) = queue.txs.remove(&sequence_number) {
+                    pending_tx.notify(Err(anyhow!(
+                        "The tx of the sender {} with sequence number {} is expired in the txpool",
+                        sender,
+                        sequence_number
+                    )));
+                    evicted += 1;
+                }
+            }
+            !queue.txs.is_empty()
+        });
+        evicted
+    }
+
+    pub fn contains(&self, sender: &RoochAddress, tx_hash: &H256) -> bool {
+        self.queues
+            .get(sender)
+            .map(|queue| {
+                queue
+                    .txs
+                    .values()
+                    .any(|pending_tx| pending_tx.tx_hash == *tx_hash)
+            })
+            .unwrap_or(false)
+    }
+
+    /// Get the pending txs of the sender ordered by sequence number, or of all the senders if `sender` is `None`
+    pub fn pending_txs(&self, sender: Option<RoochAddress>) -> Vec<PendingTransaction> {
+        match sender {
+            Some(sender) => self
+                .queues
+                .get(&sender)
+                .map(|queue| queue.txs.values().map(PendingTransaction::from).collect())
+                .unwrap_or_default(),
+            None => self
+                .queues
+                .values()
+                .flat_map(|queue| queue.txs.values().map(PendingTransaction::from))
+                .collect(),
+        }
+    }
+
+    pub fn status(&self) -> TxPoolStatus {
+        let ready_txs = self
+            .queues
+            .values()
+            .filter_map(|queue| queue.ready_tx())
+            .collect::<Vec<_>>();
+        TxPoolStatus {
+            pending_count: self.len(),
+            ready_count: ready_txs.len(),
+            max_ready_priority_fee: ready_txs
+                .iter()
+                .map(|pending_tx| pending_tx.tx.priority_fee())
+                .max(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.queues.values().map(|queue| queue.txs.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queues.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use moveos_types::moveos_std::tx_gas_price::TxGasPrice;
+    use rooch_types::address::RoochSupportedAddress;
+    use rooch_types::transaction::{Authenticator, RoochTransactionData};
+
+    fn mock_tx(
+        sender: RoochAddress,
+        sequence_number: u64,
+        priority_fee: u64,
+    ) -> (RoochTransaction, H256) {
+        let mock = RoochTransaction::mock();
+        let data =
+            RoochTransactionData::new_for_test(sender, sequence_number, mock.action().clone())
+                .with_gas_price(TxGasPrice::new(u64::MAX, priority_fee));
+        let mut tx = RoochTransaction::new(data, Authenticator::genesis());
+        let tx_hash = tx.tx_hash();
+        (tx, tx_hash)
+    }
+
+    #[test]
+    fn test_release_when_gap_filled() {
+        let mut pool = TxPool::new(DEFAULT_MAX_PENDING_PER_SENDER, DEFAULT_TX_EXPIRATION_SECS);
+        let sender = RoochAddress::random();
+        let (tx1, hash1) = mock_tx(sender, 1, 0);
+        let (tx0, hash0) = mock_tx(sender, 0, 0);
+
+        pool.add(tx1, hash1, 0, oneshot::channel().0, Span::none(), 0)
+            .unwrap();
+        assert!(pool.pop_ready().is_none());
+        assert!(pool.contains(&sender, &hash1));
+
+        pool.add(tx0, hash0, 0, oneshot::channel().0, Span::none(), 0)
+            .unwrap();
+        assert_eq!(pool.pop_ready().unwrap().tx_hash, hash0);
+        assert!(pool.pop_ready().is_none());
+        pool.update_sequence_number(&sender, 1);
+        assert_eq!(pool.pop_ready().unwrap().tx_hash, hash1);
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn test_prefer_higher_priority_fee() {
+        let mut pool = TxPool::new(DEFAULT_MAX_PENDING_PER_SENDER, DEFAULT_TX_EXPIRATION_SECS);
+        let (low_tx, low_hash) = mock_tx(RoochAddress::random(), 0, 1);
+        let (high_tx, high_hash) = mock_tx(RoochAddress::random(), 0, 10);
+        pool.add(low_tx, low_hash, 0, oneshot::channel().0, Span::none(), 0)
+            .unwrap();
+        pool.add(high_tx, high_hash, 0, oneshot::channel().0, Span::none(), 1)
+            .unwrap();
+
+        let status = pool.status();
+        assert_eq!(status.ready_count, 2);
+        assert_eq!(status.max_ready_priority_fee, Some(10));
+        assert_eq!(pool.pop_ready().unwrap().tx_hash, high_hash);
+        assert_eq!(pool.pop_ready().unwrap().tx_hash, low_hash);
+    }
+
+    #[test]
+    fn test_replace_with_higher_priority_fee() {
+        let mut pool = TxPool::new(DEFAULT_MAX_PENDING_PER_SENDER, DEFAULT_TX_EXPIRATION_SECS);
+        let sender = RoochAddress::random();
+        let (tx, hash) = mock_tx(sender, 1, 5);
+        let (waiter, mut receiver) = oneshot::channel();
+        pool.add(tx, hash, 0, waiter, Span::none(), 0).unwrap();
+
+        let (same_fee_tx, same_fee_hash) = mock_tx(sender, 1, 5);
+        let (low_fee_tx, low_fee_hash) = mock_tx(sender, 1, 4);
+        assert_ne!(same_fee_hash, hash);
+        assert!(pool
+            .add(
+                same_fee_tx,
+                same_fee_hash,
+                0,
+                oneshot::channel().0,
+                Span::none(),
+                0
+            )
+            .is_err());
+        assert!(pool
+            .add(
+                low_fee_tx,
+                low_fee_hash,
+                0,
+                oneshot::channel().0,
+                Span::none(),
+                0
+            )
+            .is_err());
+        assert!(pool.contains(&sender, &hash));
+
+        let (high_fee_tx, high_fee_hash) = mock_tx(sender, 1, 6);
+        pool.add(
+            high_fee_tx,
+            high_fee_hash,
+            0,
+            oneshot::channel().0,
+            Span::none(),
+            0,
+        )
+        .unwrap();
+        assert!(receiver.try_recv().unwrap().is_err());
+        assert!(!pool.contains(&sender, &hash));
+        assert!(pool.contains(&sender, &high_fee_hash));
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[test]
+    fn test_reject_and_evict() {
+        let mut pool = TxPool::new(2, 1);
+        let sender = RoochAddress::random();
+        let (tx0, hash0) = mock_tx(sender, 0, 0);
+        assert!(pool
+            .add(tx0, hash0, 1, oneshot::channel().0, Span::none(), 0)
+            .is_err());
+        let (tx3, hash3) = mock_tx(sender, 3, 0);
+        assert!(pool
+            .add(tx3, hash3, 1, oneshot::channel().0, Span::none(), 0)
+            .is_err());
+
+        let (tx2, hash2) = mock_tx(sender, 2, 0);
+        let (waiter, mut receiver) = oneshot::channel();
+        pool.add(tx2, hash2, 1, waiter, Span::none(), 0).unwrap();
+        assert_eq!(pool.evict_expired(999), 0);
+        assert_eq!(pool.evict_expired(1000), 1);
+        assert!(receiver.try_recv().unwrap().is_err());
+        assert_eq!(pool.len(), 0);
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::actor::{
+    messages::{GetPendingTxsMessage, GetTxPoolStatusMessage, SubmitTxMessage, TxPoolReceipt},
+    txpool::TxPoolActor,
+};
+use crate::pool::{PendingTransaction, TxPoolStatus};
+use anyhow::{anyhow, Result};
+use coerce::actor::ActorRef;
+use moveos_types::h256::H256;
+use rooch_types::address::RoochAddress;
+use rooch_types::transaction::{ExecuteTransactionResponse, RoochTransaction};
+use tracing::Span;
+
+#[derive(Clone)]
+pub struct TxPoolProxy {
+    pub actor: ActorRef<TxPoolActor>,
+}
+
+impl TxPoolProxy {
+    pub fn new(actor: ActorRef<TxPoolActor>) -> Self {
+        Self { actor }
+    }
+
+    pub async fn submit_tx(&self, tx: RoochTransaction) -> Result<TxPoolReceipt> {
+        self.actor
+            .send(SubmitTxMessage {
+                tx,
+                span: Span::current(),
+            })
+            .await?
+    }
+
+    /// Submit the tx and wait for the execution result, the tx may wait in the txpool until it expires
+    pub async fn execute_tx(&self, tx: RoochTransaction) -> Result<ExecuteTransactionResponse> {
+        let receipt = self.submit_tx(tx).await?;
+        receipt
+            .receiver
+            .await
+            .map_err(|_| anyhow!("The tx {} is dropped by the txpool", receipt.tx_hash))?
+    }
+
+    /// Submit the tx, return the tx hash without waiting if the tx is pending in the txpool,
+    /// otherwise wait for the execution result so the execution error is returned.
+    pub async fn queue_tx(&self, tx: RoochTransaction) -> Result<H256> {
+        let receipt = self.submit_tx(tx).await?;
+        if receipt.pending {
+            return Ok(receipt.tx_hash);
+        }
+        receipt
+            .receiver
+            .await
+            .map_err(|_| anyhow!("The tx {} is dropped by the txpool", receipt.tx_hash))??;
+        Ok(receipt.tx_hash)
+    }
+
+    pub async fn get_pending_transactions(
+        &self,
+        sender: Option<RoochAddress>,
+    ) -> Result<Vec<PendingTransaction>> {
+        self.actor.send(GetPendingTxsMessage { sender }).await?
+    }
+
+    pub async fn get_status(&self) -> Result<TxPoolStatus> {
+        self.actor.send(GetTxPoolStatusMessage {}).await?
+    }
+}
+
+impl From<ActorRef<TxPoolActor>> for TxPoolProxy {
+    fn from(actor: ActorRef<TxPoolActor>) -> Self {
+        Self::new(actor)
+    }
+}
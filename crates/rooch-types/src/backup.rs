@@ -0,0 +1,47 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{anyhow, Result};
+use moveos_types::h256::H256;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+pub const BACKUP_MANIFEST_FILE: &str = "backup.json";
+pub const BACKUP_STORE_SUBDIR: &str = "store";
+pub const BACKUP_INDEXER_SUBDIR: &str = "indexer";
+
+/// The manifest of a backup, it records the tx order and roots at which the backup is taken,
+/// and is used to validate the backup before restoring it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BackupManifest {
+    pub tx_order: u64,
+    pub tx_hash: H256,
+    pub state_root: H256,
+    pub size: u64,
+    pub accumulator_root: H256,
+    /// The last indexed tx order of the indexer backup, `None` if no transaction is indexed.
+    /// It may be behind the `tx_order`, the indexer catches up the gap on startup.
+    pub indexer_tx_order: Option<u64>,
+    /// The backup timestamp in milliseconds
+    pub timestamp: u64,
+}
+
+impl BackupManifest {
+    pub fn load(backup_dir: &Path) -> Result<Self> {
+        let manifest_path = backup_dir.join(BACKUP_MANIFEST_FILE);
+        let content = std::fs::read_to_string(&manifest_path).map_err(|e| {
+            anyhow!(
+                "Read backup manifest {:?} error: {:?}",
+                manifest_path.display(),
+                e
+            )
+        })?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    pub fn save(&self, backup_dir: &Path) -> Result<()> {
+        let manifest_path = backup_dir.join(BACKUP_MANIFEST_FILE);
+        std::fs::write(manifest_path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
@@ -34,6 +34,7 @@ pub mod multisign_account;
 pub mod network;
 pub mod ord;
 pub mod pending_block;
+pub mod runes;
 pub mod types;
 pub mod utxo;
 
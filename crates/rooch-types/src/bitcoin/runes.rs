@@ -0,0 +1,563 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::network::Network;
+use super::types::OutPoint;
+use crate::addresses::BITCOIN_MOVE_ADDRESS;
+use anyhow::{bail, Result};
+use move_core_types::{account_address::AccountAddress, ident_str, identifier::IdentStr};
+use moveos_types::{
+    move_std::option::MoveOption,
+    moveos_std::{
+        object::{self, ObjectID},
+        simple_map::SimpleMap,
+    },
+    state::{MoveState, MoveStructState, MoveStructType},
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+pub const MODULE_NAME: &IdentStr = ident_str!("runes");
+
+/// The number of blocks in a subsidy halving interval, the rune name unlock period.
+const SUBSIDY_HALVING_INTERVAL: u32 = 210_000;
+
+/// The rune values of the first name with `i + 1` letters.
+const STEPS: &[u128] = &[
+    0,
+    26,
+    702,
+    18278,
+    475254,
+    12356630,
+    321272406,
+    8353082582,
+    217180147158,
+    5646683826134,
+    146813779479510,
+    3817158266467286,
+    99246114928149462,
+    2580398988131886038,
+    67090373691429037014,
+    1744349715977154962390,
+    45353092615406029022166,
+    1179180408000556754576342,
+    30658690608014475618984918,
+    797125955808376366093607894,
+    20725274851017785518433805270,
+    538857146126462423479278937046,
+    14010285799288023010461252363222,
+    364267430781488598271992561443798,
+    9470953200318703555071806597538774,
+    246244783208286292431866971536008150,
+    6402364363415443603228541259936211926,
+    166461473448801533683942072758341510102,
+];
+
+/// The rune name, the modified base-26 integer representation of the name.
+/// Port from https://github.com/ordinals/ord/blob/0.19.1/crates/ordinals/src/rune.rs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Rune(pub u128);
+
+impl Rune {
+    /// The names greater than or equal to `RESERVED` are reserved for the runes without a specified name.
+    pub const RESERVED: u128 = 6402364363415443603228541259936211926;
+
+    pub fn n(self) -> u128 {
+        self.0
+    }
+
+    /// The height at which the runes protocol is activated.
+    pub fn first_rune_height(network: Network) -> u32 {
+        SUBSIDY_HALVING_INTERVAL
+            * match network {
+                Network::Bitcoin => 4,
+                Network::Regtest => 0,
+                Network::Signet => 0,
+                Network::Testnet => 12,
+            }
+    }
+
+    /// The minimum rune name which can be etched at the `height`.
+    pub fn minimum_at_height(network: Network, height: u32) -> Self {
+        const INTERVAL: u32 = SUBSIDY_HALVING_INTERVAL / 12;
+
+        let offset = height.saturating_add(1);
+        let start = Self::first_rune_height(network);
+        let end = start + SUBSIDY_HALVING_INTERVAL;
+
+        if offset < start {
+            return Rune(STEPS[12]);
+        }
+        if offset >= end {
+            return Rune(0);
+        }
+
+        let progress = offset.saturating_sub(start);
+        let length = 12u32.saturating_sub(progress / INTERVAL) as usize;
+        let end = STEPS[length - 1];
+        let start = STEPS[length];
+        let remainder = u128::from(progress % INTERVAL);
+
+        Rune(start - ((start - end) * remainder / u128::from(INTERVAL)))
+    }
+
+    pub fn is_reserved(self) -> bool {
+        self.0 >= Self::RESERVED
+    }
+
+    /// The reserved name assigned to the rune etched at `block:tx` without a specified name.
+    pub fn reserved(block: u64, tx: u32) -> Self {
+        Self(
+            Self::RESERVED
+                .checked_add(u128::from(block) << 32 | u128::from(tx))
+                .expect("Reserved rune should not overflow"),
+        )
+    }
+
+    /// The commitment of the rune name, it must be pushed in the tapscript of an input of the etching transaction.
+    pub fn commitment(self) -> Vec<u8> {
+        let bytes = self.0.to_le_bytes();
+        let mut end = bytes.len();
+        while end > 0 && bytes[end - 1] == 0 {
+            end -= 1;
+        }
+        bytes[..end].to_vec()
+    }
+}
+
+impl Display for Rune {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut n = self.0;
+        if n == u128::MAX {
+            return write!(f, "BCGDENLQRQWDSLRUGSNLBTMFIJAV");
+        }
+
+        n += 1;
+        let mut symbol = String::new();
+        while n > 0 {
+            symbol.push(char::from(b'A' + ((n - 1) % 26) as u8));
+            n = (n - 1) / 26;
+        }
+
+        for c in symbol.chars().rev() {
+            write!(f, "{c}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Rune {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut x = 0u128;
+        for (i, c) in s.chars().enumerate() {
+            if i > 0 {
+                x = x
+                    .checked_add(1)
+                    .ok_or_else(|| anyhow::anyhow!("Rune name overflow: {}", s))?;
+            }
+            x = x
+                .checked_mul(26)
+                .ok_or_else(|| anyhow::anyhow!("Rune name overflow: {}", s))?;
+            match c {
+                'A'..='Z' => {
+                    x = x
+                        .checked_add(c as u128 - 'A' as u128)
+                        .ok_or_else(|| anyhow::anyhow!("Rune name overflow: {}", s))?;
+                }
+                _ => bail!("Invalid character in rune name: {}", c),
+            }
+        }
+        Ok(Rune(x))
+    }
+}
+
+/// The rune name with the spacers, the `•` is inserted after the `i`th letter if the `i`th bit of the spacers is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpacedRune {
+    pub rune: Rune,
+    pub spacers: u32,
+}
+
+impl SpacedRune {
+    pub fn new(rune: Rune, spacers: u32) -> Self {
+        Self { rune, spacers }
+    }
+}
+
+impl Display for SpacedRune {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let rune = self.rune.to_string();
+        for (i, c) in rune.chars().enumerate() {
+            write!(f, "{c}")?;
+            if i < rune.len() - 1 && self.spacers & (1 << i) != 0 {
+                write!(f, "•")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+pub struct RuneId {
+    /// The height of the block in which the rune is etched
+    pub block: u64,
+    /// The index of the etching transaction in the block
+    pub tx: u32,
+}
+
+impl RuneId {
+    pub fn new(block: u64, tx: u32) -> Self {
+        Self { block, tx }
+    }
+
+    pub fn object_id(&self) -> ObjectID {
+        derive_rune_info_id(self)
+    }
+}
+
+impl Display for RuneId {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.block, self.tx)
+    }
+}
+
+impl FromStr for RuneId {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (block, tx) = s
+            .split_once(':')
+            .ok_or_else(|| anyhow::anyhow!("Invalid RuneId format: {}", s))?;
+        Ok(Self {
+            block: block.parse()?,
+            tx: tx.parse()?,
+        })
+    }
+}
+
+impl MoveStructType for RuneId {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("RuneId");
+}
+
+impl MoveStructState for RuneId {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![u64::type_layout(), u32::type_layout()])
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Terms {
+    /// The amount of runes each mint transaction receives
+    pub amount: MoveOption<u128>,
+    /// The number of times the rune can be minted
+    pub cap: MoveOption<u128>,
+    /// The absolute block height at which the mint opens
+    pub height_start: MoveOption<u64>,
+    /// The absolute block height at which the mint closes
+    pub height_end: MoveOption<u64>,
+    /// The block height relative to the etching block at which the mint opens
+    pub offset_start: MoveOption<u64>,
+    /// The block height relative to the etching block at which the mint closes
+    pub offset_end: MoveOption<u64>,
+}
+
+impl MoveStructType for Terms {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Terms");
+}
+
+impl MoveStructState for Terms {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            MoveOption::<u128>::type_layout(),
+            MoveOption::<u128>::type_layout(),
+            MoveOption::<u64>::type_layout(),
+            MoveOption::<u64>::type_layout(),
+            MoveOption::<u64>::type_layout(),
+            MoveOption::<u64>::type_layout(),
+        ])
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Etching {
+    pub divisibility: MoveOption<u8>,
+    pub premine: MoveOption<u128>,
+    pub rune: MoveOption<u128>,
+    pub spacers: MoveOption<u32>,
+    /// The unicode code point of the currency symbol
+    pub symbol: MoveOption<u32>,
+    pub terms: MoveOption<Terms>,
+    pub turbo: bool,
+}
+
+impl MoveStructType for Etching {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Etching");
+}
+
+impl MoveStructState for Etching {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            MoveOption::<u8>::type_layout(),
+            MoveOption::<u128>::type_layout(),
+            MoveOption::<u128>::type_layout(),
+            MoveOption::<u32>::type_layout(),
+            MoveOption::<u32>::type_layout(),
+            MoveOption::<Terms>::type_layout(),
+            bool::type_layout(),
+        ])
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Edict {
+    pub id: RuneId,
+    pub amount: u128,
+    pub output: u32,
+}
+
+impl MoveStructType for Edict {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Edict");
+}
+
+impl MoveStructState for Edict {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            RuneId::type_layout(),
+            u128::type_layout(),
+            u32::type_layout(),
+        ])
+    }
+}
+
+/// The deciphered runestone of a transaction.
+/// If the runestone is a cenotaph, the `edicts` and `pointer` are empty,
+/// and the `etching` only contains the rune name.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Runestone {
+    pub edicts: Vec<Edict>,
+    pub etching: MoveOption<Etching>,
+    pub mint: MoveOption<RuneId>,
+    pub pointer: MoveOption<u32>,
+    pub is_cenotaph: bool,
+}
+
+impl MoveStructType for Runestone {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Runestone");
+}
+
+impl MoveStructState for Runestone {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            Vec::<Edict>::type_layout(),
+            MoveOption::<Etching>::type_layout(),
+            MoveOption::<RuneId>::type_layout(),
+            MoveOption::<u32>::type_layout(),
+            bool::type_layout(),
+        ])
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuneInfo {
+    pub id: RuneId,
+    /// The sequence number of the rune in the etching order
+    pub number: u64,
+    pub rune: u128,
+    pub spacers: u32,
+    pub divisibility: u8,
+    pub symbol: MoveOption<u32>,
+    pub premine: u128,
+    pub terms: MoveOption<Terms>,
+    pub mints: u128,
+    pub burned: u128,
+    /// The txid of the etching transaction
+    pub etching: AccountAddress,
+    pub turbo: bool,
+}
+
+impl RuneInfo {
+    pub fn spaced_rune(&self) -> SpacedRune {
+        SpacedRune::new(Rune(self.rune), self.spacers)
+    }
+}
+
+impl MoveStructType for RuneInfo {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("RuneInfo");
+}
+
+impl MoveStructState for RuneInfo {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            RuneId::type_layout(),
+            u64::type_layout(),
+            u128::type_layout(),
+            u32::type_layout(),
+            u8::type_layout(),
+            MoveOption::<u32>::type_layout(),
+            u128::type_layout(),
+            MoveOption::<Terms>::type_layout(),
+            u128::type_layout(),
+            u128::type_layout(),
+            AccountAddress::type_layout(),
+            bool::type_layout(),
+        ])
+    }
+}
+
+/// The rune balances of a UTXO, the object is sealed to the UTXO.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuneBalances {
+    pub outpoint: OutPoint,
+    pub balances: SimpleMap<RuneId, u128>,
+}
+
+impl MoveStructType for RuneBalances {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("RuneBalances");
+}
+
+impl MoveStructState for RuneBalances {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            OutPoint::type_layout(),
+            SimpleMap::<RuneId, u128>::type_layout(),
+        ])
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuneStore {
+    /// The number of etched runes
+    pub rune_count: u64,
+}
+
+impl RuneStore {
+    pub fn object_id() -> ObjectID {
+        object::named_object_id(&Self::struct_tag())
+    }
+}
+
+impl MoveStructType for RuneStore {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("RuneStore");
+}
+
+impl MoveStructState for RuneStore {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![u64::type_layout()])
+    }
+}
+
+pub fn derive_rune_info_id(rune_id: &RuneId) -> ObjectID {
+    object::custom_object_id_with_parent::<RuneId, RuneInfo>(RuneStore::object_id(), rune_id)
+}
+
+pub fn derive_rune_balances_id(outpoint: &OutPoint) -> ObjectID {
+    object::custom_object_id_with_parent::<OutPoint, RuneBalances>(RuneStore::object_id(), outpoint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rune_name() {
+        for (n, name) in [
+            (0u128, "A"),
+            (25, "Z"),
+            (26, "AA"),
+            (2055900680524219742, "UNCOMMONGOODS"),
+            (u128::MAX, "BCGDENLQRQWDSLRUGSNLBTMFIJAV"),
+        ] {
+            assert_eq!(Rune(n).to_string(), name);
+            assert_eq!(Rune::from_str(name).unwrap(), Rune(n));
+        }
+        assert!(Rune::from_str("BCGDENLQRQWDSLRUGSNLBTMFIJAW").is_err());
+        assert!(Rune::from_str("a").is_err());
+    }
+
+    #[test]
+    fn test_spaced_rune() {
+        let rune = Rune::from_str("UNCOMMONGOODS").unwrap();
+        assert_eq!(SpacedRune::new(rune, 0).to_string(), "UNCOMMONGOODS");
+        assert_eq!(SpacedRune::new(rune, 128).to_string(), "UNCOMMON•GOODS");
+    }
+
+    #[test]
+    fn test_minimum_at_height() {
+        const START: u32 = 840_000;
+        const END: u32 = START + SUBSIDY_HALVING_INTERVAL;
+        for (height, minimum) in [
+            (0, "AAAAAAAAAAAAA"),
+            (START - 1, "AAAAAAAAAAAAA"),
+            (START, "ZZYZXBRKWXVA"),
+            (START + 1, "ZZXZUDIVTVQA"),
+            (END - 1, "A"),
+            (END, "A"),
+            (u32::MAX, "A"),
+        ] {
+            assert_eq!(
+                Rune::minimum_at_height(Network::Bitcoin, height).to_string(),
+                minimum
+            );
+        }
+        assert_eq!(
+            Rune::minimum_at_height(Network::Regtest, SUBSIDY_HALVING_INTERVAL - 1),
+            Rune(0)
+        );
+    }
+
+    #[test]
+    fn test_reserved_and_commitment() {
+        assert!(!Rune(Rune::RESERVED - 1).is_reserved());
+        assert!(Rune(Rune::RESERVED).is_reserved());
+        assert_eq!(
+            Rune::reserved(840000, 1),
+            Rune(6402364363415443603232149032464851927)
+        );
+        // The test vectors of ord
+        for (n, commitment) in [
+            (0, vec![]),
+            (1, vec![1]),
+            (255, vec![255]),
+            (256, vec![0, 1]),
+            (65535, vec![255, 255]),
+            (65536, vec![0, 0, 1]),
+            (u128::MAX, vec![255; 16]),
+        ] {
+            assert_eq!(Rune(n).commitment(), commitment);
+        }
+        assert_eq!(
+            Rune::from_str("UNCOMMONGOODS").unwrap().commitment(),
+            hex::decode("5e4521bcc606881c").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_rune_id() {
+        let id = RuneId::new(840000, 1);
+        assert_eq!(id.to_string(), "840000:1");
+        assert_eq!(RuneId::from_str("840000:1").unwrap(), id);
+        assert!(RuneId::from_str("840000").is_err());
+    }
+}
@@ -16,6 +16,10 @@ use bitcoin::secp256k1::SecretKey;
 use derive_more::{AsMut, AsRef, From};
 pub use enum_dispatch::enum_dispatch;
 use eyre::eyre;
+use fastcrypto::secp256r1::{
+    Secp256r1KeyPair, Secp256r1PublicKey, Secp256r1PublicKeyAsBytes, Secp256r1Signature,
+    Secp256r1SignatureAsBytes,
+};
 pub use fastcrypto::traits::KeyPair as KeypairTraits;
 pub use fastcrypto::traits::Signer;
 pub use fastcrypto::traits::{
@@ -50,6 +54,7 @@ pub type DefaultHash = Blake2b256;
 pub enum SignatureScheme {
     Ed25519,
     Secp256k1,
+    Secp256r1,
 }
 
 impl SignatureScheme {
@@ -57,6 +62,7 @@ impl SignatureScheme {
         match self {
             SignatureScheme::Ed25519 => 0,
             SignatureScheme::Secp256k1 => 1,
+            SignatureScheme::Secp256r1 => 2,
         }
     }
 
@@ -64,6 +70,7 @@ impl SignatureScheme {
         match byte_int {
             0 => Ok(SignatureScheme::Ed25519),
             1 => Ok(SignatureScheme::Secp256k1),
+            2 => Ok(SignatureScheme::Secp256r1),
             _ => Err(RoochError::InvalidSignatureScheme),
         }
     }
@@ -76,6 +83,8 @@ pub enum RoochKeyPair {
     Ed25519(Ed25519KeyPair),
     ///For Bitcoin
     Secp256k1(Secp256k1KeyPair),
+    ///For WebAuthn passkey
+    Secp256r1(Secp256r1KeyPair),
 }
 
 impl RoochKeyPair {
@@ -91,6 +100,12 @@ impl RoochKeyPair {
         RoochKeyPair::Secp256k1(secp256k1_keypair)
     }
 
+    pub fn generate_secp256r1() -> Self {
+        let rng = &mut rand::thread_rng();
+        let secp256r1_keypair = Secp256r1KeyPair::generate(rng);
+        RoochKeyPair::Secp256r1(secp256r1_keypair)
+    }
+
     pub fn from_ed25519_bytes(bytes: &[u8]) -> Result<Self, FastCryptoError> {
         Ok(RoochKeyPair::Ed25519(Ed25519KeyPair::from_bytes(bytes)?))
     }
@@ -101,6 +116,12 @@ impl RoochKeyPair {
         )?))
     }
 
+    pub fn from_secp256r1_bytes(bytes: &[u8]) -> Result<Self, FastCryptoError> {
+        Ok(RoochKeyPair::Secp256r1(Secp256r1KeyPair::from_bytes(
+            bytes,
+        )?))
+    }
+
     pub fn sign(&self, msg: &[u8]) -> Signature {
         Signer::sign(self, msg)
     }
@@ -116,6 +137,7 @@ impl RoochKeyPair {
         match self {
             RoochKeyPair::Ed25519(kp) => PublicKey::Ed25519(kp.public().into()),
             RoochKeyPair::Secp256k1(kp) => PublicKey::Secp256k1(kp.public().into()),
+            RoochKeyPair::Secp256r1(kp) => PublicKey::Secp256r1(kp.public().into()),
         }
     }
 
@@ -132,6 +154,7 @@ impl RoochKeyPair {
         match self {
             RoochKeyPair::Ed25519(kp) => kp.as_bytes(),
             RoochKeyPair::Secp256k1(kp) => kp.as_bytes(),
+            RoochKeyPair::Secp256r1(kp) => kp.as_bytes(),
         }
     }
 
@@ -171,6 +194,7 @@ impl RoochKeyPair {
         match self {
             RoochKeyPair::Ed25519(kp) => RoochKeyPair::Ed25519(kp.copy()),
             RoochKeyPair::Secp256k1(kp) => RoochKeyPair::Secp256k1(kp.copy()),
+            RoochKeyPair::Secp256r1(kp) => RoochKeyPair::Secp256r1(kp.copy()),
         }
     }
 
@@ -191,6 +215,7 @@ impl Signer<Signature> for RoochKeyPair {
         match self {
             RoochKeyPair::Ed25519(kp) => kp.sign(msg),
             RoochKeyPair::Secp256k1(kp) => kp.sign(msg),
+            RoochKeyPair::Secp256r1(kp) => kp.sign(msg),
         }
     }
 }
@@ -217,6 +242,9 @@ impl EncodeDecodeBase64 for RoochKeyPair {
             RoochKeyPair::Secp256k1(kp) => {
                 bytes.extend_from_slice(kp.as_bytes());
             }
+            RoochKeyPair::Secp256r1(kp) => {
+                bytes.extend_from_slice(kp.as_bytes());
+            }
         }
         Base64::encode(&bytes[..])
     }
@@ -237,6 +265,11 @@ impl EncodeDecodeBase64 for RoochKeyPair {
                         bytes.get(1..).ok_or_else(|| eyre!("Invalid length"))?,
                     )?))
                 }
+                SignatureScheme::Secp256r1 => {
+                    Ok(RoochKeyPair::Secp256r1(Secp256r1KeyPair::from_bytes(
+                        bytes.get(1..).ok_or_else(|| eyre!("Invalid length"))?,
+                    )?))
+                }
             },
             _ => Err(eyre!("Invalid bytes")),
         }
@@ -269,6 +302,7 @@ impl<'de> Deserialize<'de> for RoochKeyPair {
 pub enum PublicKey {
     Ed25519(Ed25519PublicKeyAsBytes),
     Secp256k1(Secp256k1PublicKeyAsBytes),
+    Secp256r1(Secp256r1PublicKeyAsBytes),
 }
 
 impl AsRef<[u8]> for PublicKey {
@@ -276,6 +310,7 @@ impl AsRef<[u8]> for PublicKey {
         match self {
             PublicKey::Ed25519(pk) => &pk.0,
             PublicKey::Secp256k1(pk) => &pk.0,
+            PublicKey::Secp256r1(pk) => &pk.0,
         }
     }
 }
@@ -306,6 +341,12 @@ impl EncodeDecodeBase64 for PublicKey {
                     )?;
                     Ok(PublicKey::Secp256k1((&pk).into()))
                 }
+                SignatureScheme::Secp256r1 => {
+                    let pk: Secp256r1PublicKey = Secp256r1PublicKey::from_bytes(
+                        bytes.get(1..).ok_or_else(|| eyre!("Invalid length"))?,
+                    )?;
+                    Ok(PublicKey::Secp256r1((&pk).into()))
+                }
             },
             Err(e) => Err(eyre!("Invalid bytes :{}", e)),
         }
@@ -342,6 +383,7 @@ impl PublicKey {
         match self {
             PublicKey::Ed25519(_) => Ed25519RoochSignature::SCHEME,
             PublicKey::Secp256k1(_) => Secp256k1RoochSignature::SCHEME,
+            PublicKey::Secp256r1(_) => Secp256r1RoochSignature::SCHEME,
         }
     }
 
@@ -419,6 +461,14 @@ impl PublicKey {
                     )?;
                     Ok(PublicKey::Secp256k1((&pk).into()))
                 }
+                SignatureScheme::Secp256r1 => {
+                    let pk: Secp256r1PublicKey = Secp256r1PublicKey::from_bytes(
+                        bytes
+                            .get(1..)
+                            .ok_or_else(|| anyhow!("Invalid public key length"))?,
+                    )?;
+                    Ok(PublicKey::Secp256r1((&pk).into()))
+                }
             },
             Err(e) => Err(anyhow!("Invalid bytes :{}", e)),
         }
@@ -515,6 +565,7 @@ pub trait RoochSignatureInner: Sized + ToFromBytes + PartialEq + Eq + Hash {
 pub enum Signature {
     Ed25519RoochSignature,
     Secp256k1RoochSignature,
+    Secp256r1RoochSignature,
 }
 
 impl Serialize for Signature {
@@ -590,6 +641,14 @@ impl Signature {
                 })?)
                     .into(),
             )),
+            Signature::Secp256r1RoochSignature(sig) => Ok(CompressedSignature::Secp256r1(
+                (&Secp256r1Signature::from_bytes(sig.signature_bytes()).map_err(|_| {
+                    RoochError::InvalidSignature {
+                        error: "Cannot parse sig".to_owned(),
+                    }
+                })?)
+                    .into(),
+            )),
         }
     }
 
@@ -611,6 +670,7 @@ impl AsRef<[u8]> for Signature {
         match self {
             Signature::Ed25519RoochSignature(sig) => sig.as_ref(),
             Signature::Secp256k1RoochSignature(sig) => sig.as_ref(),
+            Signature::Secp256r1RoochSignature(sig) => sig.as_ref(),
         }
     }
 }
@@ -619,6 +679,7 @@ impl AsMut<[u8]> for Signature {
         match self {
             Signature::Ed25519RoochSignature(sig) => sig.as_mut(),
             Signature::Secp256k1RoochSignature(sig) => sig.as_mut(),
+            Signature::Secp256r1RoochSignature(sig) => sig.as_mut(),
         }
     }
 }
@@ -631,6 +692,8 @@ impl ToFromBytes for Signature {
                     Ok(<Ed25519RoochSignature as ToFromBytes>::from_bytes(bytes)?.into())
                 } else if x == &Secp256k1RoochSignature::SCHEME.flag() {
                     Ok(<Secp256k1RoochSignature as ToFromBytes>::from_bytes(bytes)?.into())
+                } else if x == &Secp256r1RoochSignature::SCHEME.flag() {
+                    Ok(<Secp256r1RoochSignature as ToFromBytes>::from_bytes(bytes)?.into())
                 } else {
                     Err(FastCryptoError::InvalidInput)
                 }
@@ -645,6 +708,7 @@ impl ToFromBytes for Signature {
 pub enum CompressedSignature {
     Ed25519(Ed25519SignatureAsBytes),
     Secp256k1(Secp256k1SignatureAsBytes),
+    Secp256r1(Secp256r1SignatureAsBytes),
 }
 
 impl AsRef<[u8]> for CompressedSignature {
@@ -652,6 +716,7 @@ impl AsRef<[u8]> for CompressedSignature {
         match self {
             CompressedSignature::Ed25519(sig) => &sig.0,
             CompressedSignature::Secp256k1(sig) => &sig.0,
+            CompressedSignature::Secp256r1(sig) => &sig.0,
         }
     }
 }
@@ -785,6 +850,47 @@ impl Signer<Signature> for Secp256k1KeyPair {
     }
 }
 
+//
+// Secp256r1 Signature port
+//
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash, AsRef, AsMut)]
+#[as_ref(forward)]
+#[as_mut(forward)]
+pub struct Secp256r1RoochSignature(
+    #[schemars(with = "Base64")]
+    #[serde_as(as = "Readable<Base64, Bytes>")]
+    [u8; Secp256r1PublicKey::LENGTH + Secp256r1Signature::LENGTH + 1],
+);
+
+impl RoochSignatureInner for Secp256r1RoochSignature {
+    type Sig = Secp256r1Signature;
+    type PubKey = Secp256r1PublicKey;
+    type KeyPair = Secp256r1KeyPair;
+    const LENGTH: usize = Secp256r1PublicKey::LENGTH + Secp256r1Signature::LENGTH + 1;
+}
+
+impl RoochPublicKey for Secp256r1PublicKey {
+    const SIGNATURE_SCHEME: SignatureScheme = SignatureScheme::Secp256r1;
+}
+
+impl ToFromBytes for Secp256r1RoochSignature {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, FastCryptoError> {
+        if bytes.len() != Self::LENGTH {
+            return Err(FastCryptoError::InputLengthWrong(Self::LENGTH));
+        }
+        let mut sig_bytes = [0; Self::LENGTH];
+        sig_bytes.copy_from_slice(bytes);
+        Ok(Self(sig_bytes))
+    }
+}
+
+impl Signer<Signature> for Secp256r1KeyPair {
+    fn sign(&self, msg: &[u8]) -> Signature {
+        Secp256r1RoochSignature::new(self, msg).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -856,6 +962,24 @@ mod tests {
         assert!(signature.verify_secure(&value).is_ok());
     }
 
+    #[test]
+    fn test_secp256r1_signature() {
+        let kp = RoochKeyPair::generate_secp256r1();
+        let message = b"hello world";
+        let signature = kp.sign(message);
+        assert!(signature.verify(message).is_ok());
+        assert_eq!(signature.scheme(), SignatureScheme::Secp256r1);
+
+        let value = SignData {
+            value: message.to_vec(),
+        };
+        let signature = kp.sign_secure(&value);
+        assert!(signature.verify_secure(&value).is_ok());
+
+        let decoded = RoochKeyPair::decode_base64(&kp.encode_base64()).unwrap();
+        assert_eq!(decoded.public(), kp.public());
+    }
+
     #[test]
     fn test_ed25519_signature() {
         let kp = RoochKeyPair::generate_ed25519();
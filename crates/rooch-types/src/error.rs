@@ -30,6 +30,10 @@ pub enum RoochError {
     MoveTestError,
     #[error("Move Prover failed: {0}")]
     MoveProverError(String),
+    #[error("Move compatibility check failed: {0}")]
+    MoveCompatibilityError(String),
+    #[error("Move source verification failed: {0}")]
+    MoveSourceVerificationError(String),
     #[error("Unable to parse '{0}': error: {1}")]
     UnableToParse(&'static str, String),
     #[error("Unable to read file '{0}', error: {1}")]
@@ -134,6 +138,9 @@ pub enum RoochError {
     #[error("The onchain gas schedule is empty.")]
     OnchainGasScheduleIsEmpty,
 
+    #[error("The onchain gas schedule misses {0} entries required by the gas schedule version {1}, please upgrade the gas config first")]
+    OnchainGasScheduleIsOutdated(usize, u64),
+
     #[error("VM error: {0}")]
     VMError(VMError),
 }
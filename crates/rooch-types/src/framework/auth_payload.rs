@@ -30,6 +30,7 @@ pub const MODULE_NAME: &IdentStr = ident_str!("auth_payload");
 /// We remove the length because the bitcoin consensus codec serialization format already contains the length information
 pub const MESSAGE_INFO_PREFIX: &[u8] = b"Bitcoin Signed Message:\n";
 pub const MESSAGE_INFO: &[u8] = b"Rooch Transaction:\n";
+pub const FEE_PAYER_MESSAGE_INFO: &[u8] = b"Rooch Transaction:\nFee Payer:\n";
 
 const TX_HASH_HEX_LENGTH: usize = 64;
 
@@ -44,9 +45,22 @@ impl SignData {
         message_prefix: Vec<u8>,
         message_info_without_tx_hash: Vec<u8>,
         tx_data: &RoochTransactionData,
+    ) -> Self {
+        Self::new_with_tx_hash(
+            message_prefix,
+            message_info_without_tx_hash,
+            tx_data.tx_hash(),
+        )
+    }
+
+    /// Create the sign data with the given hash, the hash is appended to the message info in hex.
+    pub fn new_with_tx_hash(
+        message_prefix: Vec<u8>,
+        message_info_without_tx_hash: Vec<u8>,
+        tx_hash: H256,
     ) -> Self {
         let message_info = {
-            let tx_hash_hex = hex::encode(tx_data.tx_hash().as_bytes()).into_bytes();
+            let tx_hash_hex = hex::encode(tx_hash.as_bytes()).into_bytes();
             let mut message_info = message_info_without_tx_hash;
             message_info.extend_from_slice(&tx_hash_hex);
             message_info
@@ -71,6 +85,15 @@ impl SignData {
         Self::new(MESSAGE_INFO_PREFIX.to_vec(), MESSAGE_INFO.to_vec(), tx_data)
     }
 
+    /// The sign data of the fee payer, the fee payer signs the fee payer signing hash instead of the tx hash.
+    pub fn new_fee_payer_with_default(tx_data: &RoochTransactionData) -> Self {
+        Self::new_with_tx_hash(
+            MESSAGE_INFO_PREFIX.to_vec(),
+            FEE_PAYER_MESSAGE_INFO.to_vec(),
+            tx_data.fee_payer_signing_hash(),
+        )
+    }
+
     pub fn new_without_tx_hash_with_default() -> Self {
         Self::new_without_tx_hash(MESSAGE_INFO_PREFIX.to_vec(), MESSAGE_INFO.to_vec())
     }
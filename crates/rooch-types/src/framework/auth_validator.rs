@@ -48,6 +48,7 @@ pub enum BuiltinAuthValidator {
     Session,
     Bitcoin,
     BitcoinMultisign,
+    Webauthn,
     Ethereum,
 }
 
@@ -55,13 +56,15 @@ impl BuiltinAuthValidator {
     const SESSION_FLAG: u8 = 0x00;
     const BITCOIN_FLAG: u8 = 0x01;
     const BITCOIN_MULTISIGN: u8 = 0x02;
-    const ETHEREUM_FLAG: u8 = 0x03;
+    const WEBAUTHN_FLAG: u8 = 0x03;
+    const ETHEREUM_FLAG: u8 = 0x04;
 
     pub fn flag(&self) -> u8 {
         match self {
             BuiltinAuthValidator::Session => Self::SESSION_FLAG,
             BuiltinAuthValidator::Bitcoin => Self::BITCOIN_FLAG,
             BuiltinAuthValidator::BitcoinMultisign => Self::BITCOIN_MULTISIGN,
+            BuiltinAuthValidator::Webauthn => Self::WEBAUTHN_FLAG,
             BuiltinAuthValidator::Ethereum => Self::ETHEREUM_FLAG,
         }
     }
@@ -78,6 +81,7 @@ impl BuiltinAuthValidator {
             Self::SESSION_FLAG => Ok(BuiltinAuthValidator::Session),
             Self::BITCOIN_FLAG => Ok(BuiltinAuthValidator::Bitcoin),
             Self::BITCOIN_MULTISIGN => Ok(BuiltinAuthValidator::BitcoinMultisign),
+            Self::WEBAUTHN_FLAG => Ok(BuiltinAuthValidator::Webauthn),
             Self::ETHEREUM_FLAG => Ok(BuiltinAuthValidator::Ethereum),
             _ => Err(RoochError::KeyConversionError(
                 "Invalid key auth validator".to_owned(),
@@ -103,6 +107,11 @@ impl BuiltinAuthValidator {
                 module_name: MoveString::from_str("bitcoin_multisign_validator")
                     .expect("Should be valid"),
             },
+            BuiltinAuthValidator::Webauthn => AuthValidator {
+                id: self.flag().into(),
+                module_address: ROOCH_FRAMEWORK_ADDRESS,
+                module_name: MoveString::from_str("webauthn_validator").expect("Should be valid"),
+            },
             BuiltinAuthValidator::Ethereum => AuthValidator {
                 id: self.flag().into(),
                 module_address: ROOCH_NURSERY_ADDRESS,
@@ -26,8 +26,10 @@ pub mod onchain_config;
 pub mod session_key;
 pub mod session_validator;
 pub mod timestamp;
+pub mod transaction_fee;
 pub mod transaction_validator;
 pub mod transfer;
+pub mod webauthn_validator;
 
 /// MoveOS system pre_execute functions registry.
 /// The registry is used to filter out system pre_execute functions.
@@ -138,14 +138,20 @@ impl SessionKey {
         AuthenticationKey::new(self.authentication_key.clone())
     }
     pub fn is_expired(&self) -> bool {
-        if self.max_inactive_interval == 0 {
-            return false;
-        }
         let now = std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
             .expect("should get system time")
             .as_secs();
-        now - self.last_active_time > self.max_inactive_interval
+        self.is_expired_at(now)
+    }
+
+    /// Check if the session key is expired at the `now_seconds`, same as the `session_key::is_expired` in Move
+    pub fn is_expired_at(&self, now_seconds: u64) -> bool {
+        self.max_inactive_interval > 0
+            && self
+                .last_active_time
+                .saturating_add(self.max_inactive_interval)
+                < now_seconds
     }
 
     pub fn is_scope_match(&self, function: &FunctionCall) -> bool {
@@ -172,6 +178,8 @@ impl MoveStructType for SessionKey {
 impl MoveStructState for SessionKey {
     fn struct_layout() -> move_core_types::value::MoveStructLayout {
         move_core_types::value::MoveStructLayout::new(vec![
+            MoveString::type_layout(),
+            MoveString::type_layout(),
             move_core_types::value::MoveTypeLayout::Vector(Box::new(
                 move_core_types::value::MoveTypeLayout::U8,
             )),
@@ -194,6 +202,8 @@ impl<'a> SessionKeyModule<'a> {
         ident_str!("create_session_key_entry");
     pub const GET_SESSION_KEYS_HANDLE_FUNCTION_NAME: &'static IdentStr =
         ident_str!("get_session_keys_handle");
+    pub const REMOVE_SESSION_KEY_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("remove_session_key_entry");
 
     pub fn get_session_key(
         &self,
@@ -226,17 +236,21 @@ impl<'a> SessionKeyModule<'a> {
         Ok(session_key)
     }
 
-    pub fn get_session_keys_handle(
-        &self,
-        account_address: AccountAddress,
-    ) -> Result<Option<ObjectID>> {
-        let call = FunctionCall::new(
+    pub fn get_session_keys_handle_call(account_address: AccountAddress) -> FunctionCall {
+        FunctionCall::new(
             Self::function_id(Self::GET_SESSION_KEYS_HANDLE_FUNCTION_NAME),
             vec![],
             vec![MoveValue::Address(account_address)
                 .simple_serialize()
                 .unwrap()],
-        );
+        )
+    }
+
+    pub fn get_session_keys_handle(
+        &self,
+        account_address: AccountAddress,
+    ) -> Result<Option<ObjectID>> {
+        let call = Self::get_session_keys_handle_call(account_address);
         let ctx = TxContext::new_readonly_ctx(account_address);
         let obj_id = self
             .caller
@@ -272,6 +286,14 @@ impl<'a> SessionKeyModule<'a> {
             ],
         )
     }
+
+    pub fn remove_session_key_action(authentication_key: Vec<u8>) -> MoveAction {
+        Self::create_move_action(
+            Self::REMOVE_SESSION_KEY_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::vector_u8(authentication_key)],
+        )
+    }
 }
 
 impl<'a> ModuleBinding<'a> for SessionKeyModule<'a> {
@@ -290,7 +312,7 @@ impl<'a> ModuleBinding<'a> for SessionKeyModule<'a> {
 mod tests {
     use std::str::FromStr;
 
-    use super::SessionScope;
+    use super::{SessionKey, SessionScope};
     use move_core_types::{account_address::AccountAddress, ident_str, language_storage::ModuleId};
     use moveos_types::{
         move_std::string::MoveString, move_types::FunctionId, transaction::FunctionCall,
@@ -421,4 +443,22 @@ mod tests {
             .into_iter()
             .for_each(|c| do_test_scope_match(&session_scope, &c.0, c.1));
     }
+
+    #[test]
+    fn test_session_key_is_expired_at() {
+        let mut session_key = SessionKey {
+            app_name: MoveString::from_str("test").unwrap(),
+            app_url: MoveString::from_str("https://test.rooch.network").unwrap(),
+            authentication_key: vec![1u8; 32],
+            scopes: vec![],
+            create_time: 100,
+            last_active_time: 200,
+            max_inactive_interval: 100,
+        };
+        assert!(!session_key.is_expired_at(200));
+        assert!(!session_key.is_expired_at(300));
+        assert!(session_key.is_expired_at(301));
+        session_key.max_inactive_interval = 0;
+        assert!(!session_key.is_expired_at(u64::MAX));
+    }
 }
@@ -0,0 +1,141 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::auth_validator::BuiltinAuthValidator;
+use crate::{
+    addresses::ROOCH_FRAMEWORK_ADDRESS,
+    crypto::{RoochKeyPair, RoochSignature, SignatureScheme},
+};
+use fastcrypto::encoding::{Base64, Encoding};
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    identifier::IdentStr,
+    value::{MoveStructLayout, MoveTypeLayout},
+};
+use moveos_types::{
+    h256::{sha2_256_of, H256},
+    state::{MoveStructState, MoveStructType},
+};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("webauthn_validator");
+
+/// The relying party id of the simulated passkey
+pub const DEFAULT_RP_ID: &str = "rooch.network";
+
+/// The `type` field of the client data json for the assertion
+pub const CLIENT_DATA_TYPE_GET: &str = "webauthn.get";
+
+/// The authenticator data flags: user present(UP) and user verified(UV)
+const AUTHENTICATOR_DATA_FLAGS: u8 = 0x05;
+
+pub struct WebauthnValidator {}
+
+impl WebauthnValidator {
+    pub fn auth_validator_id() -> u64 {
+        BuiltinAuthValidator::Webauthn.flag().into()
+    }
+}
+
+impl MoveStructType for WebauthnValidator {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("WebauthnValidator");
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebauthnAuthPayload {
+    pub scheme: u8,
+    pub signature: Vec<u8>,
+    pub public_key: Vec<u8>,
+    pub authenticator_data: Vec<u8>,
+    pub client_data_json: Vec<u8>,
+}
+
+impl MoveStructType for WebauthnAuthPayload {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("WebauthnAuthPayload");
+}
+
+impl MoveStructState for WebauthnAuthPayload {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![
+            MoveTypeLayout::U8,
+            MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+            MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+            MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+            MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+        ])
+    }
+}
+
+impl WebauthnAuthPayload {
+    /// Simulate a passkey assertion of the tx hash, the same as `navigator.credentials.get` in the browser.
+    /// The challenge of the client data json is the base64url encoded tx hash,
+    /// and the authenticator signs `authenticator_data || sha256(client_data_json)`.
+    pub fn sign(kp: &RoochKeyPair, tx_hash: H256, sign_count: u32) -> Self {
+        debug_assert_eq!(kp.public().scheme(), SignatureScheme::Secp256r1);
+        let mut authenticator_data = sha2_256_of(DEFAULT_RP_ID.as_bytes()).0.to_vec();
+        authenticator_data.push(AUTHENTICATOR_DATA_FLAGS);
+        authenticator_data.extend_from_slice(&sign_count.to_be_bytes());
+
+        let client_data_json = serde_json::json!({
+            "type": CLIENT_DATA_TYPE_GET,
+            "challenge": base64url_encode(tx_hash.as_bytes()),
+            "origin": format!("https://{}", DEFAULT_RP_ID),
+            "crossOrigin": false,
+        })
+        .to_string()
+        .into_bytes();
+
+        let mut message = authenticator_data.clone();
+        message.extend_from_slice(sha2_256_of(&client_data_json).as_bytes());
+        let signature = kp.sign(&message);
+        WebauthnAuthPayload {
+            scheme: SignatureScheme::Secp256r1.flag(),
+            signature: signature.signature_bytes().to_vec(),
+            public_key: signature.public_key_bytes().to_vec(),
+            authenticator_data,
+            client_data_json,
+        }
+    }
+}
+
+/// Encode the bytes with the base64url alphabet and without padding, as the WebAuthn challenge encoding
+pub fn base64url_encode(bytes: &[u8]) -> String {
+    Base64::encode(bytes)
+        .trim_end_matches('=')
+        .replace('+', "-")
+        .replace('/', "_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64url_encode() {
+        assert_eq!(base64url_encode(b""), "");
+        assert_eq!(base64url_encode(b"f"), "Zg");
+        assert_eq!(base64url_encode(b"fo"), "Zm8");
+        assert_eq!(base64url_encode(b"foo"), "Zm9v");
+        assert_eq!(base64url_encode(&[0xfb, 0xff]), "-_8");
+    }
+
+    #[test]
+    fn test_webauthn_payload_sign() {
+        let kp = RoochKeyPair::generate_secp256r1();
+        let tx_hash = H256::random();
+        let payload = WebauthnAuthPayload::sign(&kp, tx_hash, 1);
+        let client_data: serde_json::Value =
+            serde_json::from_slice(&payload.client_data_json).unwrap();
+        assert_eq!(client_data["type"], CLIENT_DATA_TYPE_GET);
+        assert_eq!(
+            client_data["challenge"],
+            base64url_encode(tx_hash.as_bytes())
+        );
+        assert_eq!(payload.public_key, kp.public().as_ref());
+    }
+}
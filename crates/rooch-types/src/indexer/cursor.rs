@@ -0,0 +1,174 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::indexer::custom_index::CustomIndexID;
+use crate::indexer::event::IndexerEventID;
+use crate::indexer::state::IndexerStateID;
+use anyhow::{bail, ensure, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The version of the page cursor encoding, bump it when the layout of `PageCursor` changes.
+pub const PAGE_CURSOR_VERSION: u8 = 1;
+
+/// The position of the last item of a page in the indexer.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum CursorPosition {
+    Transaction(u64),
+    Event(IndexerEventID),
+    State(IndexerStateID),
+    CustomIndex(CustomIndexID),
+}
+
+impl CursorPosition {
+    fn kind(&self) -> &'static str {
+        match self {
+            CursorPosition::Transaction(_) => "transaction",
+            CursorPosition::Event(_) => "event",
+            CursorPosition::State(_) => "state",
+            CursorPosition::CustomIndex(_) => "custom index",
+        }
+    }
+}
+
+/// The opaque cursor of the indexer query APIs, it is encoded as the hex of the BCS bytes.
+/// The transaction, event and custom index positions are derived from the chain, so they are stable across the indexer rebuild.
+/// The state index is assigned by the indexer, so the state cursor is bound to the indexer generation,
+/// and the cursor from another generation is rejected.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PageCursor {
+    pub version: u8,
+    pub generation: u64,
+    pub position: CursorPosition,
+}
+
+impl PageCursor {
+    pub fn transaction(tx_order: u64) -> Self {
+        Self::new(0, CursorPosition::Transaction(tx_order))
+    }
+
+    pub fn event(event_id: IndexerEventID) -> Self {
+        Self::new(0, CursorPosition::Event(event_id))
+    }
+
+    pub fn state(generation: u64, state_id: IndexerStateID) -> Self {
+        Self::new(generation, CursorPosition::State(state_id))
+    }
+
+    pub fn custom_index(custom_index_id: CustomIndexID) -> Self {
+        Self::new(0, CursorPosition::CustomIndex(custom_index_id))
+    }
+
+    fn new(generation: u64, position: CursorPosition) -> Self {
+        Self {
+            version: PAGE_CURSOR_VERSION,
+            generation,
+            position,
+        }
+    }
+
+    pub fn into_transaction(self) -> Result<u64> {
+        match self.position {
+            CursorPosition::Transaction(tx_order) => Ok(tx_order),
+            other => bail!(
+                "Expect a transaction cursor, but got a {} cursor",
+                other.kind()
+            ),
+        }
+    }
+
+    pub fn into_event(self) -> Result<IndexerEventID> {
+        match self.position {
+            CursorPosition::Event(event_id) => Ok(event_id),
+            other => bail!("Expect an event cursor, but got a {} cursor", other.kind()),
+        }
+    }
+
+    /// Check the cursor is from the `current_generation` of the indexer and return the state id.
+    pub fn into_state(self, current_generation: u64) -> Result<IndexerStateID> {
+        match self.position {
+            CursorPosition::State(state_id) => {
+                ensure!(
+                    self.generation == current_generation,
+                    "The cursor is from the indexer generation {}, but the current generation is {}, the indexer has been rebuilt or repaired, please restart the pagination without cursor",
+                    self.generation,
+                    current_generation
+                );
+                Ok(state_id)
+            }
+            other => bail!("Expect a state cursor, but got a {} cursor", other.kind()),
+        }
+    }
+
+    pub fn into_custom_index(self) -> Result<CustomIndexID> {
+        match self.position {
+            CursorPosition::CustomIndex(custom_index_id) => Ok(custom_index_id),
+            other => bail!(
+                "Expect a custom index cursor, but got a {} cursor",
+                other.kind()
+            ),
+        }
+    }
+}
+
+impl Display for PageCursor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let bytes = bcs::to_bytes(self).map_err(|_| std::fmt::Error)?;
+        write!(f, "{}", hex::encode(bytes))
+    }
+}
+
+impl FromStr for PageCursor {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let bytes = hex::decode(s).map_err(|e| anyhow::anyhow!("Invalid cursor {}: {}", s, e))?;
+        // Check the version before decoding, the layout of other versions may be different
+        match bytes.first() {
+            Some(version) if *version == PAGE_CURSOR_VERSION => {}
+            Some(version) => bail!(
+                "Unsupported cursor version {}, the supported version is {}, please restart the pagination without cursor",
+                version,
+                PAGE_CURSOR_VERSION
+            ),
+            None => bail!("Invalid cursor: empty"),
+        }
+        bcs::from_bytes(&bytes).map_err(|e| anyhow::anyhow!("Invalid cursor {}: {}", s, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use moveos_types::moveos_std::object::ObjectID;
+
+    #[test]
+    fn test_page_cursor() {
+        let cursor = PageCursor::state(1, IndexerStateID::new(10, 2));
+        let parsed = PageCursor::from_str(&cursor.to_string()).unwrap();
+        assert_eq!(parsed, cursor);
+        assert_eq!(
+            parsed.clone().into_state(1).unwrap(),
+            IndexerStateID::new(10, 2)
+        );
+        assert!(parsed.clone().into_state(2).is_err());
+        assert!(parsed.into_transaction().is_err());
+
+        let custom_index_id = CustomIndexID::new("10".to_string(), ObjectID::random());
+        let cursor = PageCursor::custom_index(custom_index_id.clone());
+        let parsed = PageCursor::from_str(&cursor.to_string()).unwrap();
+        assert_eq!(parsed.clone().into_custom_index().unwrap(), custom_index_id);
+        assert!(parsed.into_state(0).is_err());
+
+        let cursor = PageCursor::transaction(10);
+        let parsed = PageCursor::from_str(&cursor.to_string()).unwrap();
+        assert_eq!(parsed.into_transaction().unwrap(), 10);
+
+        let mut bytes = bcs::to_bytes(&cursor).unwrap();
+        bytes[0] = PAGE_CURSOR_VERSION + 1;
+        assert!(PageCursor::from_str(&hex::encode(bytes)).is_err());
+        assert!(PageCursor::from_str("").is_err());
+        assert!(PageCursor::from_str("zz").is_err());
+    }
+}
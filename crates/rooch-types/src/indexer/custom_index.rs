@@ -0,0 +1,483 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{bail, Result};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::effects::Op;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::{StructTag, TypeTag};
+use move_core_types::u256::U256;
+use move_resource_viewer::{AnnotatedMoveStruct, AnnotatedMoveValue};
+use moveos_types::move_std::ascii::MoveAsciiString;
+use moveos_types::move_std::string::MoveString;
+use moveos_types::moveos_std::object::{is_dynamic_field_type, ObjectID, ObjectMeta};
+use moveos_types::state::{MoveStructType, ObjectChange};
+use moveos_types::state_resolver::{AnnotatedStateReader, StateResolver};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The width of the encoded integer values, the max u256 has 78 decimal digits.
+const INTEGER_VALUE_WIDTH: usize = 78;
+
+/// A custom secondary index of the indexer, in the format `<struct_tag>.<field>[.<field>]*`,
+/// such as `0x42::market::Listing.price`.
+/// The indexer extracts the field of the objects of the struct type and indexes it as `(value, object_id)`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CustomIndexConfig {
+    pub object_type: StructTag,
+    pub field_path: Vec<Identifier>,
+}
+
+impl CustomIndexConfig {
+    pub fn field_path_string(&self) -> String {
+        self.field_path
+            .iter()
+            .map(|field| field.as_str())
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    pub fn match_object_type(&self, object_type: &TypeTag) -> bool {
+        match object_type {
+            TypeTag::Struct(struct_tag) => struct_tag.as_ref() == &self.object_type,
+            _ => false,
+        }
+    }
+
+    /// Extract and encode the field value from the object value, return `None` if the field does not exist,
+    /// or the field is not an integer, bool, address, string or ObjectID.
+    pub fn extract_value(
+        &self,
+        value: &AnnotatedMoveStruct,
+    ) -> Result<Option<(CustomIndexFieldType, String)>> {
+        let mut current = value;
+        let Some((last, parents)) = self.field_path.split_last() else {
+            return Ok(None);
+        };
+        for field in parents {
+            match find_field(current, field) {
+                Some(AnnotatedMoveValue::Struct(inner)) => current = inner,
+                _ => return Ok(None),
+            }
+        }
+        match find_field(current, last) {
+            Some(value) => encode_custom_index_value(value.clone()),
+            None => Ok(None),
+        }
+    }
+}
+
+fn find_field<'a>(
+    value: &'a AnnotatedMoveStruct,
+    field: &Identifier,
+) -> Option<&'a AnnotatedMoveValue> {
+    value
+        .value
+        .iter()
+        .find(|(name, _)| name == field)
+        .map(|(_, value)| value)
+}
+
+impl Display for CustomIndexConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}.{}",
+            self.object_type.to_canonical_string(),
+            self.field_path_string()
+        )
+    }
+}
+
+impl FromStr for CustomIndexConfig {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        // The struct tag does not contain `.`, so the first `.` splits the type and the field path
+        let Some((object_type, field_path)) = s.split_once('.') else {
+            bail!(
+                "Invalid custom index {}, expect the format <struct_tag>.<field>[.<field>]*",
+                s
+            );
+        };
+        let object_type = StructTag::from_str(object_type)
+            .map_err(|e| anyhow::anyhow!("Invalid struct tag of custom index {}: {}", s, e))?;
+        let field_path = field_path
+            .split('.')
+            .map(Identifier::new)
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| anyhow::anyhow!("Invalid field path of custom index {}: {}", s, e))?;
+        Ok(Self {
+            object_type,
+            field_path,
+        })
+    }
+}
+
+impl Serialize for CustomIndexConfig {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomIndexConfig {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        CustomIndexConfig::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// The type of the indexed field, the range bounds of the custom index filter are encoded by the field type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum CustomIndexFieldType {
+    Integer,
+    Bool,
+    Address,
+    String,
+    ObjectID,
+}
+
+impl CustomIndexFieldType {
+    /// Encode the range bound of the custom index filter as the indexed value of the field type,
+    /// so the integer fields are compared numerically.
+    pub fn encode_bound(&self, bound: &str) -> Result<String> {
+        let encoded = match self {
+            CustomIndexFieldType::Integer => encode_integer(
+                U256::from_str(bound)
+                    .map_err(|e| anyhow::anyhow!("Invalid integer bound {}: {}", bound, e))?,
+            ),
+            CustomIndexFieldType::Bool => bool::from_str(bound)?.to_string(),
+            CustomIndexFieldType::Address => AccountAddress::from_str(bound)?.to_hex_literal(),
+            CustomIndexFieldType::String => bound.to_string(),
+            CustomIndexFieldType::ObjectID => ObjectID::from_str(bound)?.to_string(),
+        };
+        Ok(encoded)
+    }
+}
+
+impl Display for CustomIndexFieldType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let field_type = match self {
+            CustomIndexFieldType::Integer => "integer",
+            CustomIndexFieldType::Bool => "bool",
+            CustomIndexFieldType::Address => "address",
+            CustomIndexFieldType::String => "string",
+            CustomIndexFieldType::ObjectID => "object_id",
+        };
+        write!(f, "{}", field_type)
+    }
+}
+
+impl FromStr for CustomIndexFieldType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "integer" => Ok(CustomIndexFieldType::Integer),
+            "bool" => Ok(CustomIndexFieldType::Bool),
+            "address" => Ok(CustomIndexFieldType::Address),
+            "string" => Ok(CustomIndexFieldType::String),
+            "object_id" => Ok(CustomIndexFieldType::ObjectID),
+            _ => bail!("Invalid custom index field type {}", s),
+        }
+    }
+}
+
+/// Encode the indexed value as a string which sorts in the same order as the value,
+/// the integers are zero padded to the same width.
+pub fn encode_custom_index_value(
+    value: AnnotatedMoveValue,
+) -> Result<Option<(CustomIndexFieldType, String)>> {
+    let encoded = match value {
+        AnnotatedMoveValue::U8(v) => (CustomIndexFieldType::Integer, encode_integer(v)),
+        AnnotatedMoveValue::U16(v) => (CustomIndexFieldType::Integer, encode_integer(v)),
+        AnnotatedMoveValue::U32(v) => (CustomIndexFieldType::Integer, encode_integer(v)),
+        AnnotatedMoveValue::U64(v) => (CustomIndexFieldType::Integer, encode_integer(v)),
+        AnnotatedMoveValue::U128(v) => (CustomIndexFieldType::Integer, encode_integer(v)),
+        AnnotatedMoveValue::U256(v) => (CustomIndexFieldType::Integer, encode_integer(v)),
+        AnnotatedMoveValue::Bool(v) => (CustomIndexFieldType::Bool, v.to_string()),
+        AnnotatedMoveValue::Address(v) => (CustomIndexFieldType::Address, v.to_hex_literal()),
+        AnnotatedMoveValue::Struct(v) if MoveString::struct_tag_match(&v.type_) => (
+            CustomIndexFieldType::String,
+            MoveString::try_from(v)?.to_string(),
+        ),
+        AnnotatedMoveValue::Struct(v) if MoveAsciiString::struct_tag_match(&v.type_) => (
+            CustomIndexFieldType::String,
+            MoveAsciiString::try_from(v)?.to_string(),
+        ),
+        AnnotatedMoveValue::Struct(v) if ObjectID::struct_tag_match(&v.type_) => (
+            CustomIndexFieldType::ObjectID,
+            ObjectID::try_from(v)?.to_string(),
+        ),
+        _ => return Ok(None),
+    };
+    Ok(Some(encoded))
+}
+
+fn encode_integer<T: Display>(v: T) -> String {
+    // Pad the formatted string, the width may be ignored by the Display of the integer type
+    format!("{:0>width$}", v.to_string(), width = INTEGER_VALUE_WIDTH)
+}
+
+/// The custom index entry of an object field.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct IndexerCustomIndex {
+    pub object_id: ObjectID,
+    pub object_type: StructTag,
+    pub field_path: String,
+    pub field_type: CustomIndexFieldType,
+    /// The encoded field value
+    pub value: String,
+    // The tx order of the transaction which updated the value
+    pub tx_order: u64,
+}
+
+/// The position of an object in a custom index, the custom index is ordered by `(value, object_id)`.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CustomIndexID {
+    pub value: String,
+    pub object_id: ObjectID,
+}
+
+impl CustomIndexID {
+    pub fn new(value: String, object_id: ObjectID) -> Self {
+        CustomIndexID { value, object_id }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CustomIndexFilter {
+    pub object_type: StructTag,
+    pub field_path: String,
+    /// The inclusive lower bound of the value, it is encoded by the indexed field type when querying
+    pub from: Option<String>,
+    /// The exclusive upper bound of the value, it is encoded by the indexed field type when querying
+    pub to: Option<String>,
+}
+
+impl CustomIndexFilter {
+    pub fn new(
+        object_type: StructTag,
+        field_path: String,
+        from: Option<String>,
+        to: Option<String>,
+    ) -> Self {
+        Self {
+            object_type,
+            field_path,
+            from,
+            to,
+        }
+    }
+}
+
+/// The custom index changes of a transaction, the removed objects are deleted before the new entries are written,
+/// so a modified object removes its stale entries and writes the current ones.
+#[derive(Debug, Clone, Default)]
+pub struct IndexerCustomIndexChangeSet {
+    pub new_indexes: Vec<IndexerCustomIndex>,
+    pub remove_object_ids: Vec<ObjectID>,
+}
+
+impl IndexerCustomIndexChangeSet {
+    pub fn is_empty(&self) -> bool {
+        self.new_indexes.is_empty() && self.remove_object_ids.is_empty()
+    }
+}
+
+fn configs_of_object_type<'a>(
+    custom_indexes: &'a [CustomIndexConfig],
+    object_type: &TypeTag,
+) -> Vec<&'a CustomIndexConfig> {
+    custom_indexes
+        .iter()
+        .filter(|config| config.match_object_type(object_type))
+        .collect()
+}
+
+/// Extract the custom index entries of the object value.
+pub fn extract_custom_indexes<R: AnnotatedStateReader>(
+    configs: Vec<&CustomIndexConfig>,
+    resolver: &R,
+    tx_order: u64,
+    change_set: &mut IndexerCustomIndexChangeSet,
+    metadata: &ObjectMeta,
+    value: &[u8],
+) -> Result<()> {
+    let object_type = metadata.object_struct_tag().clone();
+    let annotated = resolver.view_resource(&object_type, value)?;
+    for config in configs {
+        if let Some((field_type, value)) = config.extract_value(&annotated)? {
+            change_set.new_indexes.push(IndexerCustomIndex {
+                object_id: metadata.id.clone(),
+                object_type: object_type.clone(),
+                field_path: config.field_path_string(),
+                field_type,
+                value,
+                tx_order,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Collect the custom index changes of the object and its child objects,
+/// the `resolver` resolves the struct layouts of the object values.
+pub fn handle_custom_index_object_change<R: AnnotatedStateReader>(
+    custom_indexes: &[CustomIndexConfig],
+    resolver: &R,
+    tx_order: u64,
+    change_set: &mut IndexerCustomIndexChangeSet,
+    object_change: &ObjectChange,
+) -> Result<()> {
+    let ObjectChange {
+        metadata,
+        value,
+        fields,
+    } = object_change;
+
+    // Do not index dynamic field object
+    if is_dynamic_field_type(&metadata.object_type) {
+        return Ok(());
+    }
+    let configs = configs_of_object_type(custom_indexes, &metadata.object_type);
+    if let Some(op) = value.as_ref().filter(|_| !configs.is_empty()) {
+        change_set.remove_object_ids.push(metadata.id.clone());
+        if let Op::New(bytes) | Op::Modify(bytes) = op {
+            extract_custom_indexes(configs, resolver, tx_order, change_set, metadata, bytes)?;
+        }
+    }
+
+    for change in fields.values() {
+        handle_custom_index_object_change(custom_indexes, resolver, tx_order, change_set, change)?;
+    }
+    Ok(())
+}
+
+/// Collect the custom index changes to revert the object change and its child objects,
+/// the `previous_resolver` reads the object states before the reverted transaction.
+pub fn handle_revert_custom_index_object_change<R: AnnotatedStateReader>(
+    custom_indexes: &[CustomIndexConfig],
+    previous_resolver: &R,
+    tx_order: u64,
+    change_set: &mut IndexerCustomIndexChangeSet,
+    object_change: &ObjectChange,
+) -> Result<()> {
+    let ObjectChange {
+        metadata,
+        value,
+        fields,
+    } = object_change;
+
+    // Do not index dynamic field object
+    if is_dynamic_field_type(&metadata.object_type) {
+        return Ok(());
+    }
+    let configs = configs_of_object_type(custom_indexes, &metadata.object_type);
+    if value.is_some() && !configs.is_empty() {
+        change_set.remove_object_ids.push(metadata.id.clone());
+        // Restore the entries of the modified or deleted object from its previous state
+        if let Some(previous_state) = previous_resolver.get_object(&metadata.id)? {
+            extract_custom_indexes(
+                configs,
+                previous_resolver,
+                tx_order,
+                change_set,
+                &previous_state.metadata,
+                &previous_state.value,
+            )?;
+        }
+    }
+
+    for change in fields.values() {
+        handle_revert_custom_index_object_change(
+            custom_indexes,
+            previous_resolver,
+            tx_order,
+            change_set,
+            change,
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_index_config() {
+        let config = CustomIndexConfig::from_str("0x42::market::Listing.price").unwrap();
+        assert_eq!(config.object_type.module.as_str(), "market");
+        assert_eq!(config.field_path_string(), "price");
+        assert_eq!(
+            CustomIndexConfig::from_str(&config.to_string()).unwrap(),
+            config
+        );
+
+        let config = CustomIndexConfig::from_str("0x42::market::Listing<u64>.meta.owner").unwrap();
+        assert_eq!(config.field_path_string(), "meta.owner");
+
+        assert!(CustomIndexConfig::from_str("0x42::market::Listing").is_err());
+        assert!(CustomIndexConfig::from_str("0x42::market::Listing.").is_err());
+        assert!(CustomIndexConfig::from_str("market.price").is_err());
+    }
+
+    #[test]
+    fn test_encode_custom_index_value() {
+        let (field_type, small) = encode_custom_index_value(AnnotatedMoveValue::U64(9))
+            .unwrap()
+            .unwrap();
+        let (_, large) = encode_custom_index_value(AnnotatedMoveValue::U8(10))
+            .unwrap()
+            .unwrap();
+        assert_eq!(field_type, CustomIndexFieldType::Integer);
+        assert!(small < large);
+        assert_eq!(field_type.encode_bound("9").unwrap(), small);
+        assert!(field_type.encode_bound("abc").is_err());
+        assert!(field_type.encode_bound("-1").is_err());
+
+        let (field_type, value) =
+            encode_custom_index_value(AnnotatedMoveValue::Address(AccountAddress::ONE))
+                .unwrap()
+                .unwrap();
+        assert_eq!(field_type, CustomIndexFieldType::Address);
+        assert_eq!(value, "0x1");
+        assert_eq!(field_type.encode_bound("0x01").unwrap(), value);
+        assert!(
+            encode_custom_index_value(AnnotatedMoveValue::Bytes(vec![1]))
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_encode_bound_by_field_type() {
+        // The digits of a string field are compared as the string, not as the integer
+        assert_eq!(CustomIndexFieldType::String.encode_bound("9").unwrap(), "9");
+        assert_eq!(
+            CustomIndexFieldType::Bool.encode_bound("true").unwrap(),
+            "true"
+        );
+        assert!(CustomIndexFieldType::Bool.encode_bound("1").is_err());
+        for field_type in [
+            CustomIndexFieldType::Integer,
+            CustomIndexFieldType::Bool,
+            CustomIndexFieldType::Address,
+            CustomIndexFieldType::String,
+            CustomIndexFieldType::ObjectID,
+        ] {
+            assert_eq!(
+                CustomIndexFieldType::from_str(&field_type.to_string()).unwrap(),
+                field_type
+            );
+        }
+    }
+}
@@ -1,8 +1,11 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod cursor;
+pub mod custom_index;
 pub mod event;
 pub mod state;
+pub mod status;
 pub mod transaction;
 
 pub trait Filter<T> {
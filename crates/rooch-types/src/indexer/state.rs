@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::bitcoin::ord::Inscription;
+use crate::bitcoin::runes::RuneBalances;
 use crate::bitcoin::utxo::UTXO;
+use crate::indexer::custom_index::CustomIndexFilter;
 use crate::indexer::Filter;
 use anyhow::Result;
 use move_core_types::account_address::AccountAddress;
@@ -20,6 +22,8 @@ pub static UTXO_TYPE_TAG: Lazy<TypeTag> = Lazy::new(UTXO::type_tag);
 
 pub static INSCRIPTION_TYPE_TAG: Lazy<TypeTag> = Lazy::new(Inscription::type_tag);
 
+pub static RUNE_BALANCES_TYPE_TAG: Lazy<TypeTag> = Lazy::new(RuneBalances::type_tag);
+
 /// Index all Object state, include child object
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IndexerObjectState {
@@ -54,9 +58,25 @@ impl IndexerObjectState {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ObjectStateType {
-    ObjectState, //all object states exclude utxo and inscription
+    ObjectState, //all object states exclude utxo, inscription and rune balances
     UTXO,
     Inscription,
+    Rune,
+}
+
+impl ObjectStateType {
+    /// The object states are indexed in the table of the object type.
+    pub fn from_object_type(object_type: &TypeTag) -> Self {
+        if type_tag_match(object_type, &UTXO_TYPE_TAG) {
+            ObjectStateType::UTXO
+        } else if type_tag_match(object_type, &INSCRIPTION_TYPE_TAG) {
+            ObjectStateType::Inscription
+        } else if type_tag_match(object_type, &RUNE_BALANCES_TYPE_TAG) {
+            ObjectStateType::Rune
+        } else {
+            ObjectStateType::ObjectState
+        }
+    }
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -64,6 +84,7 @@ pub struct IndexerObjectStateChangeSet {
     pub object_states: IndexerObjectStateChanges,
     pub object_state_utxos: IndexerObjectStateChanges,
     pub object_state_inscriptions: IndexerObjectStateChanges,
+    pub object_state_runes: IndexerObjectStateChanges,
 }
 
 impl IndexerObjectStateChangeSet {
@@ -74,6 +95,8 @@ impl IndexerObjectStateChangeSet {
             self.object_state_inscriptions
                 .update_object_states
                 .push(state)
+        } else if type_tag_match(&state.metadata.object_type, &RUNE_BALANCES_TYPE_TAG) {
+            self.object_state_runes.update_object_states.push(state)
         } else {
             self.object_states.update_object_states.push(state)
         }
@@ -84,6 +107,8 @@ impl IndexerObjectStateChangeSet {
             self.object_state_utxos.new_object_states.push(state)
         } else if type_tag_match(&state.metadata.object_type, &INSCRIPTION_TYPE_TAG) {
             self.object_state_inscriptions.new_object_states.push(state)
+        } else if type_tag_match(&state.metadata.object_type, &RUNE_BALANCES_TYPE_TAG) {
+            self.object_state_runes.new_object_states.push(state)
         } else {
             self.object_states.new_object_states.push(state)
         }
@@ -98,6 +123,10 @@ impl IndexerObjectStateChangeSet {
             self.object_state_inscriptions
                 .remove_object_states
                 .push(object_id.to_string())
+        } else if type_tag_match(object_type, &RUNE_BALANCES_TYPE_TAG) {
+            self.object_state_runes
+                .remove_object_states
+                .push(object_id.to_string())
         } else {
             self.object_states
                 .remove_object_states
@@ -118,6 +147,7 @@ pub struct IndexerObjectStatesIndexGenerator {
     pub object_states_index_generator: u64,
     pub object_state_utxos_index_generator: u64,
     pub object_state_inscriptions_generator: u64,
+    pub object_state_runes_generator: u64,
 }
 
 impl IndexerObjectStatesIndexGenerator {
@@ -126,6 +156,8 @@ impl IndexerObjectStatesIndexGenerator {
             self.object_state_utxos_index_generator += 1;
         } else if type_tag_match(object_type, &INSCRIPTION_TYPE_TAG) {
             self.object_state_inscriptions_generator += 1;
+        } else if type_tag_match(object_type, &RUNE_BALANCES_TYPE_TAG) {
+            self.object_state_runes_generator += 1;
         } else {
             self.object_states_index_generator += 1;
         }
@@ -136,6 +168,8 @@ impl IndexerObjectStatesIndexGenerator {
             self.object_state_utxos_index_generator
         } else if type_tag_match(object_type, &INSCRIPTION_TYPE_TAG) {
             self.object_state_inscriptions_generator
+        } else if type_tag_match(object_type, &RUNE_BALANCES_TYPE_TAG) {
+            self.object_state_runes_generator
         } else {
             self.object_states_index_generator
         }
@@ -332,6 +366,8 @@ pub enum ObjectStateFilter {
     Owner(AccountAddress),
     /// Query by object ids.
     ObjectId(Vec<ObjectID>),
+    /// Query by a configured custom index, the objects are ordered by the indexed field value.
+    CustomIndex(CustomIndexFilter),
 }
 
 impl ObjectStateFilter {
@@ -345,6 +381,8 @@ impl ObjectStateFilter {
             ObjectStateFilter::ObjectId(object_ids) => {
                 object_ids.len() == 1 && object_ids[0] == item.metadata.id
             }
+            // The indexed field value is not in the object metadata
+            ObjectStateFilter::CustomIndex(_) => false,
         })
     }
 }
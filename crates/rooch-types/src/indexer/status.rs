@@ -0,0 +1,38 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum IndexerSyncStatus {
+    /// The indexer is replaying the stored transactions to catch up with the executed transactions.
+    CatchingUp,
+    /// The indexer indexes the executed transactions as they are executed.
+    #[default]
+    Synced,
+    /// The catch-up is stopped by an error, the indexer will not index new transactions until the node restarts.
+    Failed,
+}
+
+/// The status of the indexer writer.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct IndexerStatus {
+    pub sync_status: IndexerSyncStatus,
+    /// The last tx order written to the indexer, `None` if unknown or no transaction is indexed.
+    pub last_indexed_tx_order: Option<u64>,
+    /// The tx order the catch-up replays to, it grows with the transactions executed during the catch-up.
+    pub target_tx_order: Option<u64>,
+    pub error: Option<String>,
+}
+
+impl IndexerStatus {
+    pub fn is_catching_up(&self) -> bool {
+        matches!(self.sync_status, IndexerSyncStatus::CatchingUp)
+    }
+
+    pub fn is_failed(&self) -> bool {
+        matches!(self.sync_status, IndexerSyncStatus::Failed)
+    }
+}
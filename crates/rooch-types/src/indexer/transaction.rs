@@ -30,10 +30,24 @@ pub struct IndexerTransaction {
 
 impl IndexerTransaction {
     pub fn new(
-        mut transaction: LedgerTransaction,
+        transaction: LedgerTransaction,
         execution_info: TransactionExecutionInfo,
         move_action: MoveAction,
         tx_context: TxContext,
+    ) -> Result<Self> {
+        Self::new_with_action_type(
+            transaction,
+            execution_info,
+            move_action.action_type(),
+            tx_context,
+        )
+    }
+
+    pub fn new_with_action_type(
+        mut transaction: LedgerTransaction,
+        execution_info: TransactionExecutionInfo,
+        action_type: u8,
+        tx_context: TxContext,
     ) -> Result<Self> {
         let status = serde_json::to_string(&execution_info.status)?;
         let (auth_validator_id, _authenticator_payload) = match &transaction.data {
@@ -53,7 +67,7 @@ impl IndexerTransaction {
             sequence_number: tx_context.sequence_number,
             // the account address of sender who send the transaction
             sender: tx_context.sender,
-            action_type: move_action.action_type(),
+            action_type,
             auth_validator_id,
             // the amount of gas used.
             gas_used: execution_info.gas_used,
@@ -63,6 +77,33 @@ impl IndexerTransaction {
         };
         Ok(indexer_transaction)
     }
+
+    /// Rebuild the tx context fields and the action type used by the indexer from the ledger transaction,
+    /// for replaying the stored transactions into the indexer without re-executing them.
+    /// The L1 block and L1 tx are executed as system calls of a function.
+    pub fn replay_context(transaction: &mut LedgerTransaction) -> (TxContext, u8) {
+        let tx_hash = transaction.tx_hash();
+        match &transaction.data {
+            LedgerTxData::L1Block(block) => (
+                TxContext::new_system_call_ctx(tx_hash, block.tx_size()),
+                MoveAction::FUNCTION_ACTION_TYPE,
+            ),
+            LedgerTxData::L1Tx(tx) => (
+                TxContext::new_system_call_ctx(tx_hash, tx.tx_size()),
+                MoveAction::FUNCTION_ACTION_TYPE,
+            ),
+            LedgerTxData::L2Tx(tx) => (
+                TxContext::new(
+                    tx.sender().into(),
+                    tx.sequence_number(),
+                    tx.max_gas_amount(),
+                    tx_hash,
+                    tx.tx_size(),
+                ),
+                tx.action().action_type(),
+            ),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
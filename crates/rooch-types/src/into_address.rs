@@ -73,6 +73,12 @@ impl IntoAddress for bitcoin::TxMerkleNode {
     }
 }
 
+impl FromAddress for bitcoin::TxMerkleNode {
+    fn from_address(addr: AccountAddress) -> Self {
+        bitcoin::TxMerkleNode::from_byte_array(addr.into())
+    }
+}
+
 impl IntoAddress for H256 {
     fn into_address(self) -> AccountAddress {
         AccountAddress::new(self.0)
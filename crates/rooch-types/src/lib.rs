@@ -4,6 +4,7 @@
 pub mod address;
 pub mod addresses;
 pub mod authentication_key;
+pub mod backup;
 pub mod bitcoin;
 pub mod block;
 pub mod coin_type;
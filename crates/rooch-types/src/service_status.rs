@@ -1,7 +1,10 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use anyhow::format_err;
 use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
 
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, clap::ValueEnum, PartialEq)]
 #[serde(rename_all = "kebab-case")]
@@ -34,3 +37,28 @@ impl ServiceStatus {
         matches!(self, ServiceStatus::DateImportMode)
     }
 }
+
+impl Display for ServiceStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ServiceStatus::Active => write!(f, "active"),
+            ServiceStatus::Maintenance => write!(f, "maintenance"),
+            ServiceStatus::ReadOnlyMode => write!(f, "read-only-mode"),
+            ServiceStatus::DateImportMode => write!(f, "date-import-mode"),
+        }
+    }
+}
+
+impl FromStr for ServiceStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "active" => Ok(ServiceStatus::Active),
+            "maintenance" => Ok(ServiceStatus::Maintenance),
+            "read-only-mode" => Ok(ServiceStatus::ReadOnlyMode),
+            "date-import-mode" => Ok(ServiceStatus::DateImportMode),
+            s => Err(format_err!("Invalid service status str: {}", s)),
+        }
+    }
+}
@@ -24,6 +24,7 @@ use crate::{
     framework::{
         auth_payload::{AuthPayload, MultisignAuthPayload, SignData},
         auth_validator::BuiltinAuthValidator,
+        webauthn_validator::WebauthnAuthPayload,
     },
     rooch_network::{BuiltinChainID, RoochNetwork},
 };
@@ -112,8 +113,20 @@ impl BitcoinAuthenticator {
     }
 
     pub fn sign(kp: &RoochKeyPair, tx_data: &RoochTransactionData) -> Self {
+        Self::sign_with_data(kp, tx_data, SignData::new_with_default(tx_data))
+    }
+
+    /// Sign the transaction data as the fee payer
+    pub fn sign_fee_payer(kp: &RoochKeyPair, tx_data: &RoochTransactionData) -> Self {
+        Self::sign_with_data(kp, tx_data, SignData::new_fee_payer_with_default(tx_data))
+    }
+
+    fn sign_with_data(
+        kp: &RoochKeyPair,
+        tx_data: &RoochTransactionData,
+        sign_data: SignData,
+    ) -> Self {
         assert_eq!(kp.public().scheme(), SignatureScheme::Secp256k1);
-        let sign_data = SignData::new_with_default(tx_data);
         let data_hash = sign_data.data_hash();
         let signature = kp.sign(data_hash.as_bytes());
         let bitcoin_address = kp
@@ -173,6 +186,33 @@ impl BitcoinMultisignAuthenticator {
     }
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct WebauthnAuthenticator {
+    pub payload: WebauthnAuthPayload,
+}
+
+impl WebauthnAuthenticator {
+    pub fn new(payload: WebauthnAuthPayload) -> Self {
+        Self { payload }
+    }
+
+    /// We simulate the passkey assertion, the sign count is always 1
+    pub fn sign(kp: &RoochKeyPair, tx_data: &RoochTransactionData) -> Self {
+        assert_eq!(kp.public().scheme(), SignatureScheme::Secp256r1);
+        let payload = WebauthnAuthPayload::sign(kp, tx_data.tx_hash(), 1);
+        Self { payload }
+    }
+}
+
+impl BuiltinAuthenticator for WebauthnAuthenticator {
+    fn auth_validator_id(&self) -> u64 {
+        BuiltinAuthValidator::Webauthn.flag().into()
+    }
+    fn payload(&self) -> Vec<u8> {
+        bcs::to_bytes(&self.payload).expect("Serialize WebauthnAuthenticator should success")
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct Authenticator {
     pub auth_validator_id: u64,
@@ -196,6 +236,7 @@ impl Authenticator {
         match kp.public().scheme() {
             SignatureScheme::Ed25519 => Self::session(kp, tx_data),
             SignatureScheme::Secp256k1 => Self::bitcoin(kp, tx_data),
+            SignatureScheme::Secp256r1 => Self::webauthn(kp, tx_data),
         }
     }
 
@@ -210,6 +251,18 @@ impl Authenticator {
         BitcoinAuthenticator::sign(kp, tx_data).into()
     }
 
+    /// Create a bitcoin authenticator for the fee payer of a sponsored RoochTransaction
+    /// The fee payer signs the fee payer signing hash, so the sender's signature can not be used as the fee payer's
+    pub fn bitcoin_fee_payer(kp: &RoochKeyPair, tx_data: &RoochTransactionData) -> Self {
+        BitcoinAuthenticator::sign_fee_payer(kp, tx_data).into()
+    }
+
+    /// Create a WebAuthn authenticator for RoochTransaction
+    /// We simulate the passkey assertion of the browser
+    pub fn webauthn(kp: &RoochKeyPair, tx_data: &RoochTransactionData) -> Self {
+        WebauthnAuthenticator::sign(kp, tx_data).into()
+    }
+
     /// Create a bitcoin multisign authenticator for RoochTransaction
     pub fn bitcoin_multisign(authenticators: Vec<BitcoinAuthenticator>) -> Result<Self> {
         BitcoinMultisignAuthenticator::build_multisig_authenticator(authenticators).map(Into::into)
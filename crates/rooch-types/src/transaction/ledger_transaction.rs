@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use super::rooch::RoochTransactionV1;
 use super::{RoochTransaction, TransactionSequenceInfo};
 use crate::{
     address::RoochAddress,
@@ -116,12 +117,47 @@ impl L1Transaction {
 }
 
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(into = "LedgerTxDataWire", try_from = "LedgerTxDataWire")]
 pub enum LedgerTxData {
     L1Block(L1Block),
     L1Tx(L1Transaction),
     L2Tx(RoochTransaction),
 }
 
+/// The BCS layout of the `LedgerTxData`, the L2 transaction with the v1 layout is appended as a new variant,
+/// so the stored ledger transactions with the v0 layout keep decodable.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+enum LedgerTxDataWire {
+    L1Block(L1Block),
+    L1Tx(L1Transaction),
+    L2Tx(RoochTransaction),
+    L2TxV1(RoochTransactionV1),
+}
+
+impl From<LedgerTxData> for LedgerTxDataWire {
+    fn from(data: LedgerTxData) -> Self {
+        match data {
+            LedgerTxData::L1Block(block) => LedgerTxDataWire::L1Block(block),
+            LedgerTxData::L1Tx(tx) => LedgerTxDataWire::L1Tx(tx),
+            LedgerTxData::L2Tx(tx) if tx.is_v0() => LedgerTxDataWire::L2Tx(tx),
+            LedgerTxData::L2Tx(tx) => LedgerTxDataWire::L2TxV1(tx.into()),
+        }
+    }
+}
+
+impl TryFrom<LedgerTxDataWire> for LedgerTxData {
+    type Error = anyhow::Error;
+
+    fn try_from(data: LedgerTxDataWire) -> Result<Self> {
+        Ok(match data {
+            LedgerTxDataWire::L1Block(block) => LedgerTxData::L1Block(block),
+            LedgerTxDataWire::L1Tx(tx) => LedgerTxData::L1Tx(tx),
+            LedgerTxDataWire::L2Tx(tx) => LedgerTxData::L2Tx(tx),
+            LedgerTxDataWire::L2TxV1(tx) => LedgerTxData::L2Tx(tx.try_into()?),
+        })
+    }
+}
+
 impl LedgerTxData {
     pub fn tx_hash(&mut self) -> H256 {
         match self {
@@ -172,12 +172,50 @@ pub struct ExecuteTransactionResponse {
 
 #[cfg(test)]
 mod tests {
-    use super::rooch::RoochTransaction;
+    use super::rooch::{
+        PartiallySignedRoochTransaction, RoochTransaction, RoochTransactionData,
+        FEE_PAYER_SIGNING_PREFIX,
+    };
+    use crate::address::{RoochAddress, RoochSupportedAddress};
+    use crate::crypto::RoochKeyPair;
     use crate::test_utils::random_accumulator_info;
-    use crate::transaction::TransactionSequenceInfo;
+    use crate::transaction::authenticator::BitcoinAuthenticator;
+    use crate::transaction::{
+        Authenticator, LedgerTransaction, LedgerTxData, TransactionSequenceInfo,
+    };
     use ethers::types::H256;
+    use moveos_types::moveos_std::tx_gas_price::TxGasPrice;
     use moveos_types::state::MoveState;
     use moveos_types::test_utils::random_bytes;
+    use moveos_types::transaction::MoveAction;
+    use serde::Serialize;
+
+    /// The v0 layout of the transaction data, before the extension is introduced.
+    #[derive(Serialize)]
+    struct LegacyRoochTransactionData {
+        sender: RoochAddress,
+        sequence_number: u64,
+        chain_id: u64,
+        max_gas_amount: u64,
+        action: MoveAction,
+    }
+
+    /// The v0 layout of the transaction, before the fee payer is introduced.
+    #[derive(Serialize)]
+    struct LegacyRoochTransaction {
+        data: LegacyRoochTransactionData,
+        authenticator: Authenticator,
+    }
+
+    fn legacy_tx_data(tx_data: &RoochTransactionData) -> LegacyRoochTransactionData {
+        LegacyRoochTransactionData {
+            sender: tx_data.sender,
+            sequence_number: tx_data.sequence_number,
+            chain_id: tx_data.chain_id,
+            max_gas_amount: tx_data.max_gas_amount,
+            action: tx_data.action.clone(),
+        }
+    }
 
     fn test_serialize_deserialize_roundtrip(tx: RoochTransaction) {
         let bytes = tx.encode();
@@ -191,6 +229,206 @@ mod tests {
         test_serialize_deserialize_roundtrip(tx)
     }
 
+    #[test]
+    fn test_decode_legacy_tx_data() {
+        let tx_data = RoochTransaction::mock().data;
+        let legacy_bytes = bcs::to_bytes(&legacy_tx_data(&tx_data)).unwrap();
+        assert_eq!(tx_data.encode(), legacy_bytes);
+        assert_eq!(
+            tx_data.tx_hash(),
+            moveos_types::h256::sha3_256_of(legacy_bytes.as_slice())
+        );
+        let decoded = RoochTransactionData::decode(&legacy_bytes).unwrap();
+        assert_eq!(decoded, tx_data);
+        assert!(decoded.gas_price.is_none());
+    }
+
+    #[test]
+    fn test_decode_legacy_tx() {
+        let tx = RoochTransaction::mock();
+        let legacy_bytes = bcs::to_bytes(&LegacyRoochTransaction {
+            data: legacy_tx_data(&tx.data),
+            authenticator: tx.authenticator().clone(),
+        })
+        .unwrap();
+        assert_eq!(tx.encode(), legacy_bytes);
+        let mut decoded = RoochTransaction::decode(&legacy_bytes).unwrap();
+        assert_eq!(decoded, tx);
+        assert!(!decoded.is_sponsored());
+        assert_eq!(
+            decoded.tx_hash(),
+            moveos_types::h256::sha3_256_of(
+                bcs::to_bytes(&legacy_tx_data(&tx.data)).unwrap().as_slice()
+            )
+        );
+    }
+
+    #[test]
+    fn test_sponsored_tx() {
+        let sender_kp = RoochKeyPair::generate_secp256k1();
+        let sender = sender_kp.public().rooch_address().unwrap();
+        let sponsor_kp = RoochKeyPair::generate_secp256k1();
+        let sponsor = sponsor_kp.public().rooch_address().unwrap();
+        let tx_data =
+            RoochTransactionData::new_for_test(sender, 0, RoochTransaction::mock().data.action);
+        let sponsored_data = tx_data.clone().with_fee_payer(sponsor);
+        // The sender commits to the fee payer
+        assert_ne!(tx_data.tx_hash(), sponsored_data.tx_hash());
+
+        let mut signing_data = FEE_PAYER_SIGNING_PREFIX.to_vec();
+        signing_data.extend_from_slice(sponsored_data.tx_hash().as_bytes());
+        assert_eq!(
+            sponsored_data.fee_payer_signing_hash(),
+            moveos_types::h256::sha3_256_of(signing_data.as_slice())
+        );
+
+        // The fee payer can not co-sign the transaction without the fee payer
+        assert!(tx_data
+            .sign(&sender_kp)
+            .sign_fee_payer(&sponsor_kp)
+            .is_err());
+
+        let unsigned_tx = sponsored_data.sign(&sender_kp);
+        assert!(unsigned_tx.fee_payer_authenticator_info().is_err());
+        // The transaction waiting for the fee payer's signature can be encoded and decoded
+        test_serialize_deserialize_roundtrip(unsigned_tx.clone());
+        assert!(unsigned_tx.clone().sign_fee_payer(&sender_kp).is_err());
+
+        let tx = unsigned_tx.sign_fee_payer(&sponsor_kp).unwrap();
+        let (fee_payer, _) = tx.fee_payer_authenticator_info().unwrap().unwrap();
+        assert_eq!(fee_payer, sponsor);
+        assert_ne!(tx.fee_payer_authenticator(), Some(tx.authenticator()));
+        test_serialize_deserialize_roundtrip(tx.clone());
+
+        let ledger_tx_data = LedgerTxData::L2Tx(tx);
+        let bytes = bcs::to_bytes(&ledger_tx_data).unwrap();
+        assert_eq!(
+            bcs::from_bytes::<LedgerTxData>(&bytes).unwrap(),
+            ledger_tx_data
+        );
+    }
+
+    #[test]
+    fn test_sponsored_tx_serde_roundtrip() {
+        let sender_kp = RoochKeyPair::generate_secp256k1();
+        let sender = sender_kp.public().rooch_address().unwrap();
+        let sponsor_kp = RoochKeyPair::generate_secp256k1();
+        let sponsor = sponsor_kp.public().rooch_address().unwrap();
+        let tx =
+            RoochTransactionData::new_for_test(sender, 0, RoochTransaction::mock().data.action)
+                .with_fee_payer(sponsor)
+                .sign(&sender_kp)
+                .sign_fee_payer(&sponsor_kp)
+                .unwrap();
+        // The fee payer authenticator can not be dropped silently when the tx is nested by serde
+        assert!(bcs::to_bytes(&tx).is_err());
+        assert!(serde_json::to_string(&tx).is_err());
+
+        let sequence_info =
+            TransactionSequenceInfo::new(1, random_bytes(), random_accumulator_info(), 0);
+        let ledger_tx = LedgerTransaction::new(LedgerTxData::L2Tx(tx.clone()), sequence_info);
+        let bytes = bcs::to_bytes(&ledger_tx).unwrap();
+        let decoded = bcs::from_bytes::<LedgerTransaction>(&bytes).unwrap();
+        assert_eq!(decoded, ledger_tx);
+        let LedgerTxData::L2Tx(decoded_tx) = decoded.data else {
+            panic!("expect a L2 transaction");
+        };
+        assert_eq!(decoded_tx.fee_payer(), Some(sponsor));
+        assert_eq!(
+            decoded_tx.fee_payer_authenticator(),
+            tx.fee_payer_authenticator()
+        );
+
+        // The v0 transaction keeps the v0 serde layout
+        let v0_tx = RoochTransaction::mock();
+        let bytes = bcs::to_bytes(&v0_tx).unwrap();
+        assert_eq!(bytes, v0_tx.encode());
+        assert_eq!(bcs::from_bytes::<RoochTransaction>(&bytes).unwrap(), v0_tx);
+    }
+
+    #[test]
+    fn test_psrt_layout() {
+        let tx_data = RoochTransaction::mock().data;
+
+        /// The PSRT layout before the fee payer is introduced.
+        #[derive(Serialize)]
+        struct LegacyPartiallySignedRoochTransaction {
+            data: LegacyRoochTransactionData,
+            threshold: u64,
+            authenticators: Vec<BitcoinAuthenticator>,
+        }
+        let legacy_bytes = bcs::to_bytes(&LegacyPartiallySignedRoochTransaction {
+            data: legacy_tx_data(&tx_data),
+            threshold: 2,
+            authenticators: vec![],
+        })
+        .unwrap();
+        let psrt = PartiallySignedRoochTransaction::new(tx_data.clone(), 2);
+        assert_eq!(psrt.encode(), legacy_bytes);
+        assert_eq!(
+            PartiallySignedRoochTransaction::decode(&legacy_bytes).unwrap(),
+            psrt
+        );
+
+        let sponsor = RoochAddress::random();
+        let psrt = PartiallySignedRoochTransaction::new(tx_data.with_fee_payer(sponsor), 2);
+        let bytes = psrt.encode();
+        assert_ne!(bytes, legacy_bytes);
+        let decoded = PartiallySignedRoochTransaction::decode(&bytes).unwrap();
+        assert_eq!(decoded, psrt);
+        assert_eq!(decoded.data.fee_payer, Some(sponsor));
+    }
+
+    #[test]
+    fn test_tx_data_with_gas_price() {
+        let tx_data = RoochTransaction::mock()
+            .data
+            .with_gas_price(TxGasPrice::new(100, 10));
+        let bytes = tx_data.encode();
+        let legacy_bytes = bcs::to_bytes(&legacy_tx_data(&tx_data)).unwrap();
+        assert!(bytes.starts_with(&legacy_bytes));
+        assert_ne!(
+            tx_data.tx_hash(),
+            moveos_types::h256::sha3_256_of(legacy_bytes.as_slice())
+        );
+        assert_eq!(RoochTransactionData::decode(&bytes).unwrap(), tx_data);
+        // The data with extension can not be nested by serde
+        assert!(bcs::to_bytes(&tx_data).is_err());
+        // The empty extension must be omitted
+        let mut non_canonical = legacy_bytes.clone();
+        non_canonical.push(0);
+        assert!(RoochTransactionData::decode(&non_canonical).is_err());
+    }
+
+    #[test]
+    fn test_tx_with_gas_price_roundtrip() {
+        let kp = RoochKeyPair::generate_secp256k1();
+        let sender = kp.public().rooch_address().unwrap();
+        let tx_data =
+            RoochTransactionData::new_for_test(sender, 0, RoochTransaction::mock().data.action)
+                .with_gas_price(TxGasPrice::new(100, 10));
+        let tx = tx_data.sign(&kp);
+        assert!(!tx.is_v0());
+        test_serialize_deserialize_roundtrip(tx.clone());
+
+        let ledger_tx_data = LedgerTxData::L2Tx(tx);
+        let bytes = bcs::to_bytes(&ledger_tx_data).unwrap();
+        // The v1 transaction is appended as a new variant
+        assert_eq!(bytes[0], 3);
+        assert_eq!(
+            bcs::from_bytes::<LedgerTxData>(&bytes).unwrap(),
+            ledger_tx_data
+        );
+
+        let v0_ledger_tx_data = LedgerTxData::L2Tx(RoochTransaction::mock());
+        let bytes = bcs::to_bytes(&v0_ledger_tx_data).unwrap();
+        assert_eq!(bytes[0], 2);
+        assert_eq!(
+            bcs::from_bytes::<LedgerTxData>(&bytes).unwrap(),
+            v0_ledger_tx_data
+        );
+    }
+
     #[test]
     fn test_serialize_deserialize_transaction_sequence_info() {
         let tx_order_signature = random_bytes();
@@ -9,8 +9,10 @@ use crate::crypto::RoochKeyPair;
 use crate::rooch_network::BuiltinChainID;
 use anyhow::Result;
 use moveos_types::h256::H256;
+use moveos_types::moveos_std::gas_payment_account::GasPaymentAccount;
 use moveos_types::moveos_std::gas_schedule::GasScheduleConfig;
 use moveos_types::moveos_std::object::ObjectMeta;
+use moveos_types::moveos_std::tx_gas_price::TxGasPrice;
 use moveos_types::{
     moveos_std::tx_context::TxContext,
     transaction::{MoveAction, MoveOSTransaction},
@@ -18,7 +20,14 @@ use moveos_types::{
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
-#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+/// The domain separator of the hash signed by the fee payer.
+pub const FEE_PAYER_SIGNING_PREFIX: &[u8] = b"ROOCH::FeePayer::";
+
+/// The transaction data signed by the sender.
+/// The BCS layout of the first five fields is the v0 layout and must not be changed,
+/// the fields introduced later are carried by the `RoochTransactionDataExt` which is appended
+/// to the v0 bytes only if it is not empty, so the v0 transactions keep the same bytes and hash.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub struct RoochTransactionData {
     /// Sender's address.
     pub sender: RoochAddress,
@@ -30,6 +39,94 @@ pub struct RoochTransactionData {
     pub max_gas_amount: u64,
     // The MoveAction to execute.
     pub action: MoveAction,
+    // The optional max gas price and priority fee, if it is absent, the transaction pays the base fee.
+    pub gas_price: Option<TxGasPrice>,
+    // The optional fee payer(sponsor) who pays the gas on behalf of the sender, it is committed by the sender's signature.
+    pub fee_payer: Option<RoochAddress>,
+}
+
+/// The v0 BCS layout of the `RoochTransactionData`.
+#[derive(Serialize)]
+struct RoochTransactionDataV0Ref<'a> {
+    sender: &'a RoochAddress,
+    sequence_number: u64,
+    chain_id: u64,
+    max_gas_amount: u64,
+    action: &'a MoveAction,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct RoochTransactionDataV0 {
+    sender: RoochAddress,
+    sequence_number: u64,
+    chain_id: u64,
+    max_gas_amount: u64,
+    action: MoveAction,
+}
+
+/// The extension of the transaction data, appended to the v0 transaction data bytes.
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RoochTransactionDataExt {
+    pub gas_price: Option<TxGasPrice>,
+    pub fee_payer: Option<RoochAddress>,
+}
+
+impl RoochTransactionDataExt {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+impl RoochTransactionDataV0 {
+    pub(crate) fn into_data(self, ext: RoochTransactionDataExt) -> RoochTransactionData {
+        RoochTransactionData {
+            sender: self.sender,
+            sequence_number: self.sequence_number,
+            chain_id: self.chain_id,
+            max_gas_amount: self.max_gas_amount,
+            action: self.action,
+            gas_price: ext.gas_price,
+            fee_payer: ext.fee_payer,
+        }
+    }
+}
+
+impl From<RoochTransactionData> for RoochTransactionDataV0 {
+    fn from(data: RoochTransactionData) -> Self {
+        Self {
+            sender: data.sender,
+            sequence_number: data.sequence_number,
+            chain_id: data.chain_id,
+            max_gas_amount: data.max_gas_amount,
+            action: data.action,
+        }
+    }
+}
+
+/// The serde layout of the `RoochTransactionData` is always the v0 layout, so it can be nested in other types.
+/// The transaction data with extension can not be nested, use `encode` and `decode` instead.
+impl Serialize for RoochTransactionData {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if !self.ext().is_empty() {
+            return Err(serde::ser::Error::custom(
+                "The transaction data with extension can only be encoded by RoochTransactionData::encode",
+            ));
+        }
+        self.v0_ref().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for RoochTransactionData {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let data = RoochTransactionDataV0::deserialize(deserializer)?;
+        Ok(data.into_data(RoochTransactionDataExt::default()))
+    }
 }
 
 impl RoochTransactionData {
@@ -46,6 +143,8 @@ impl RoochTransactionData {
             chain_id,
             max_gas_amount,
             action,
+            gas_price: None,
+            fee_payer: None,
         }
     }
 
@@ -56,26 +155,88 @@ impl RoochTransactionData {
             chain_id: BuiltinChainID::Local.chain_id().id(),
             max_gas_amount: GasScheduleConfig::INITIAL_MAX_GAS_AMOUNT,
             action,
+            gas_price: None,
+            fee_payer: None,
         }
     }
 
+    /// Set the max gas price and the priority fee(tip) of the transaction
+    pub fn with_gas_price(mut self, gas_price: TxGasPrice) -> Self {
+        self.gas_price = Some(gas_price);
+        self
+    }
+
+    /// Set the fee payer(sponsor) of the transaction, the fee payer must co-sign the transaction
+    pub fn with_fee_payer(mut self, fee_payer: RoochAddress) -> Self {
+        self.fee_payer = Some(fee_payer);
+        self
+    }
+
+    /// The priority fee(tip) per gas of the transaction, 0 if the transaction does not set it
+    pub fn priority_fee(&self) -> u64 {
+        self.gas_price
+            .map(|gas_price| gas_price.priority_fee)
+            .unwrap_or_default()
+    }
+
+    pub fn ext(&self) -> RoochTransactionDataExt {
+        RoochTransactionDataExt {
+            gas_price: self.gas_price,
+            fee_payer: self.fee_payer,
+        }
+    }
+
+    fn v0_ref(&self) -> RoochTransactionDataV0Ref<'_> {
+        RoochTransactionDataV0Ref {
+            sender: &self.sender,
+            sequence_number: self.sequence_number,
+            chain_id: self.chain_id,
+            max_gas_amount: self.max_gas_amount,
+            action: &self.action,
+        }
+    }
+
+    /// Encode the transaction data, the v0 bytes are followed by the extension if it is not empty.
     pub fn encode(&self) -> Vec<u8> {
-        bcs::to_bytes(self).expect("encode transaction should success")
+        let mut bytes = bcs::to_bytes(&self.v0_ref()).expect("encode transaction should success");
+        let ext = self.ext();
+        if !ext.is_empty() {
+            bytes.extend(bcs::to_bytes(&ext).expect("encode transaction should success"));
+        }
+        bytes
     }
 
+    /// Decode the transaction data from the v0 bytes or the v0 bytes followed by the extension.
     pub fn decode(bytes: &[u8]) -> Result<Self>
     where
         Self: std::marker::Sized,
     {
-        bcs::from_bytes::<Self>(bytes).map_err(Into::into)
+        if let Ok(data) = bcs::from_bytes::<RoochTransactionDataV0>(bytes) {
+            return Ok(data.into_data(RoochTransactionDataExt::default()));
+        }
+        let (data, ext) =
+            bcs::from_bytes::<(RoochTransactionDataV0, RoochTransactionDataExt)>(bytes)?;
+        if ext.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Invalid transaction data, the empty extension should be omitted"
+            ));
+        }
+        Ok(data.into_data(ext))
     }
 
     pub fn tx_hash(&self) -> H256 {
         moveos_types::h256::sha3_256_of(self.encode().as_slice())
     }
 
+    /// The hash signed by the fee payer, it is different from the tx hash signed by the sender.
+    pub fn fee_payer_signing_hash(&self) -> H256 {
+        let mut data = FEE_PAYER_SIGNING_PREFIX.to_vec();
+        data.extend_from_slice(self.tx_hash().as_bytes());
+        moveos_types::h256::sha3_256_of(data.as_slice())
+    }
+
     pub fn tx_size(&self) -> u64 {
-        bcs::serialized_size(self).expect("serialize transaction size should success") as u64
+        self.encode().len() as u64
     }
 
     pub fn sign(&self, kp: &RoochKeyPair) -> RoochTransaction {
@@ -88,14 +249,46 @@ impl Display for RoochTransactionData {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ sender: {}, sequence_number {}, chain_id: {}, max_gas_amount: {}, action: {} }}",
-            self.sender, self.sequence_number, self.chain_id, self.max_gas_amount, self.action
+            "{{ sender: {}, sequence_number {}, chain_id: {}, max_gas_amount: {}, action: {}, gas_price: {}, fee_payer: {} }}",
+            self.sender,
+            self.sequence_number,
+            self.chain_id,
+            self.max_gas_amount,
+            self.action,
+            self.gas_price
+                .map(|gas_price| gas_price.to_string())
+                .unwrap_or_else(|| "None".to_string()),
+            self.fee_payer
+                .map(|fee_payer| fee_payer.to_string())
+                .unwrap_or_else(|| "None".to_string())
         )
     }
 }
 
+/// Serialize the transaction data as the encoded bytes, so the transaction data with extension can be nested.
+mod tx_data_bytes {
+    use super::RoochTransactionData;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(data: &RoochTransactionData, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&data.encode())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<RoochTransactionData, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = Vec::<u8>::deserialize(deserializer)?;
+        RoochTransactionData::decode(&bytes).map_err(serde::de::Error::custom)
+    }
+}
+
 /// PartiallySignedRoochTransaction(PSRT) is a transaction that has been signed by partial signers.
 /// It can be used for multi-signatures.
+/// The serde layout is the v0 layout, use `encode` and `decode` to carry the transaction data with extension.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct PartiallySignedRoochTransaction {
     pub data: RoochTransactionData,
@@ -105,6 +298,16 @@ pub struct PartiallySignedRoochTransaction {
     pub authenticators: Vec<BitcoinAuthenticator>,
 }
 
+/// The v1 layout of the `PartiallySignedRoochTransaction`, the transaction data is carried as the encoded bytes.
+/// It is only used for the transaction data with extension, so the v0 PSRT files keep the same bytes.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct PartiallySignedRoochTransactionV1 {
+    #[serde(with = "tx_data_bytes")]
+    data: RoochTransactionData,
+    threshold: u64,
+    authenticators: Vec<BitcoinAuthenticator>,
+}
+
 impl PartiallySignedRoochTransaction {
     pub fn new(data: RoochTransactionData, threshold: u64) -> Self {
         Self {
@@ -135,10 +338,59 @@ impl PartiallySignedRoochTransaction {
                 authenticator.payload.from_address()
             ));
         }
+        self.verify_authenticator(&authenticator)?;
         self.authenticators.push(authenticator);
         Ok(())
     }
 
+    /// Verify the authenticator is a valid signature of the transaction data.
+    pub fn verify_authenticator(&self, authenticator: &BitcoinAuthenticator) -> Result<()> {
+        authenticator.payload.verify(&self.data).map_err(|e| {
+            anyhow::anyhow!(
+                "Invalid signature from address {:?}: {}",
+                authenticator.payload.from_address(),
+                e
+            )
+        })
+    }
+
+    /// Merge the signatures collected by another signer for the same transaction, the duplicated signatures are ignored.
+    /// The signatures are verified before merging, an invalid signature fails the merge.
+    /// The threshold is not compared, it should be verified against the multisign account on chain.
+    pub fn merge(&mut self, other: PartiallySignedRoochTransaction) -> Result<()> {
+        if self.data.tx_hash() != other.data.tx_hash() {
+            return Err(anyhow::anyhow!(
+                "Cannot merge the partially signed transaction of different transaction data"
+            ));
+        }
+        for authenticator in other.authenticators {
+            if !self.contains_authenticator(&authenticator) {
+                self.verify_authenticator(&authenticator)?;
+                self.authenticators.push(authenticator);
+            }
+        }
+        Ok(())
+    }
+
+    /// Verify the signatures against the participants and the threshold of the multisign account on chain,
+    /// the threshold in the partially signed transaction can not be trusted.
+    /// The authenticators which are not valid signatures of the participants are removed and returned.
+    pub fn verify_participants(
+        &mut self,
+        participant_public_keys: &[Vec<u8>],
+        threshold: u64,
+    ) -> Vec<BitcoinAuthenticator> {
+        self.threshold = threshold;
+        let (valid, invalid): (Vec<_>, Vec<_>) = std::mem::take(&mut self.authenticators)
+            .into_iter()
+            .partition(|authenticator| {
+                participant_public_keys.contains(&authenticator.payload.public_key)
+                    && self.verify_authenticator(authenticator).is_ok()
+            });
+        self.authenticators = valid;
+        invalid
+    }
+
     pub fn threshold(&self) -> u64 {
         self.threshold
     }
@@ -160,29 +412,186 @@ impl PartiallySignedRoochTransaction {
         Ok(RoochTransaction::new(self.data, authenticator))
     }
 
+    /// Encode the PSRT with the v0 layout, or the v1 layout if the transaction data has extension.
     pub fn encode(&self) -> Vec<u8> {
-        bcs::to_bytes(self).expect("encode transaction should success")
+        if self.data.ext().is_empty() {
+            bcs::to_bytes(self).expect("encode transaction should success")
+        } else {
+            bcs::to_bytes(&PartiallySignedRoochTransactionV1 {
+                data: self.data.clone(),
+                threshold: self.threshold,
+                authenticators: self.authenticators.clone(),
+            })
+            .expect("encode transaction should success")
+        }
+    }
+
+    /// Decode the PSRT from the v0 layout or the v1 layout.
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        if let Ok(psrt) = bcs::from_bytes::<Self>(bytes) {
+            return Ok(psrt);
+        }
+        let psrt = bcs::from_bytes::<PartiallySignedRoochTransactionV1>(bytes)?;
+        if psrt.data.ext().is_empty() {
+            return Err(anyhow::anyhow!(
+                "Invalid v1 partially signed transaction, the transaction data without extension should use the v0 layout"
+            ));
+        }
+        Ok(Self {
+            data: psrt.data,
+            threshold: psrt.threshold,
+            authenticators: psrt.authenticators,
+        })
     }
 }
 
-#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub struct RoochTransaction {
     pub data: RoochTransactionData,
     pub authenticator: Authenticator,
+    /// The authenticator of the fee payer designated by the transaction data, it is absent before the fee payer signs.
+    /// It is only carried by the v1 layout, because the sponsored transaction data always has the extension.
+    fee_payer_authenticator: Option<Authenticator>,
 
-    #[serde(skip_serializing, skip_deserializing)]
     data_hash: Option<H256>,
 }
 
+/// The v0 BCS layout of the `RoochTransaction`.
+#[derive(Serialize)]
+struct RoochTransactionV0Ref<'a> {
+    data: RoochTransactionDataV0Ref<'a>,
+    authenticator: &'a Authenticator,
+}
+
+#[derive(Deserialize)]
+struct RoochTransactionV0 {
+    data: RoochTransactionDataV0,
+    authenticator: Authenticator,
+}
+
+/// The serde layout of the `RoochTransaction` is always the v0 layout, like the `RoochTransactionData`.
+/// The transaction with extension can not be nested, otherwise the fee payer authenticator would be lost,
+/// use `encode` and `decode` instead, or nest it in the `LedgerTxData` which selects the layout.
+impl Serialize for RoochTransaction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if !self.is_v0() {
+            return Err(serde::ser::Error::custom(
+                "The transaction with extension can only be encoded by RoochTransaction::encode",
+            ));
+        }
+        RoochTransactionV0Ref {
+            data: self.data.v0_ref(),
+            authenticator: &self.authenticator,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for RoochTransaction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let tx = RoochTransactionV0::deserialize(deserializer)?;
+        Ok(Self::new(
+            tx.data.into_data(RoochTransactionDataExt::default()),
+            tx.authenticator,
+        ))
+    }
+}
+
+/// The v1 layout of the `RoochTransaction`, the v0 layout followed by the extension of the transaction data
+/// and the authenticator of the fee payer.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct RoochTransactionV1 {
+    data: RoochTransactionDataV0,
+    authenticator: Authenticator,
+    ext: RoochTransactionDataExt,
+    fee_payer_authenticator: Option<Authenticator>,
+}
+
+impl From<RoochTransaction> for RoochTransactionV1 {
+    fn from(tx: RoochTransaction) -> Self {
+        let ext = tx.data.ext();
+        Self {
+            data: tx.data.into(),
+            authenticator: tx.authenticator,
+            ext,
+            fee_payer_authenticator: tx.fee_payer_authenticator,
+        }
+    }
+}
+
+impl TryFrom<RoochTransactionV1> for RoochTransaction {
+    type Error = anyhow::Error;
+
+    fn try_from(tx: RoochTransactionV1) -> Result<Self> {
+        if tx.ext.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Invalid v1 transaction, the transaction without extension should use the v0 layout"
+            ));
+        }
+        if tx.ext.fee_payer.is_none() && tx.fee_payer_authenticator.is_some() {
+            return Err(anyhow::anyhow!(
+                "Invalid v1 transaction, the fee payer authenticator without fee payer"
+            ));
+        }
+        Ok(Self {
+            data: tx.data.into_data(tx.ext),
+            authenticator: tx.authenticator,
+            fee_payer_authenticator: tx.fee_payer_authenticator,
+            data_hash: None,
+        })
+    }
+}
+
 impl RoochTransaction {
     pub fn new(data: RoochTransactionData, authenticator: Authenticator) -> Self {
         Self {
             data,
             authenticator,
+            fee_payer_authenticator: None,
             data_hash: None,
         }
     }
 
+    /// Create a sponsored transaction, the gas is paid by the fee payer designated by the transaction data.
+    pub fn new_sponsored(
+        data: RoochTransactionData,
+        authenticator: Authenticator,
+        fee_payer_authenticator: Authenticator,
+    ) -> Result<Self> {
+        Self::new(data, authenticator).with_fee_payer_authenticator(fee_payer_authenticator)
+    }
+
+    /// Add or replace the authenticator of the fee payer designated by the transaction data.
+    pub fn with_fee_payer_authenticator(mut self, authenticator: Authenticator) -> Result<Self> {
+        if self.data.fee_payer.is_none() {
+            return Err(anyhow::anyhow!(
+                "The transaction data does not designate a fee payer"
+            ));
+        }
+        self.fee_payer_authenticator = Some(authenticator);
+        Ok(self)
+    }
+
+    /// Co-sign the transaction as the fee payer designated by the transaction data,
+    /// only the bitcoin authenticator is supported for the fee payer.
+    pub fn sign_fee_payer(self, kp: &RoochKeyPair) -> Result<Self> {
+        let address = kp.public().rooch_address()?;
+        if self.data.fee_payer != Some(address) {
+            return Err(anyhow::anyhow!(
+                "The signer {} is not the fee payer of the transaction",
+                address
+            ));
+        }
+        let authenticator = Authenticator::bitcoin_fee_payer(kp, &self.data);
+        self.with_fee_payer_authenticator(authenticator)
+    }
+
     pub fn new_genesis_tx(
         genesis_address: RoochAddress,
         chain_id: u64,
@@ -191,6 +600,7 @@ impl RoochTransaction {
         Self {
             data: RoochTransactionData::new(genesis_address, 0, chain_id, u64::max_value(), action),
             authenticator: Authenticator::genesis(),
+            fee_payer_authenticator: None,
             data_hash: None,
         }
     }
@@ -211,19 +621,41 @@ impl RoochTransaction {
         self.data.max_gas_amount
     }
 
+    pub fn gas_price(&self) -> Option<TxGasPrice> {
+        self.data.gas_price
+    }
+
+    pub fn priority_fee(&self) -> u64 {
+        self.data.priority_fee()
+    }
+
     pub fn action(&self) -> &MoveAction {
         &self.data.action
     }
 
+    /// Whether the transaction uses the v0 layout, the v0 transaction can be nested in other types by serde.
+    pub fn is_v0(&self) -> bool {
+        self.data.ext().is_empty()
+    }
+
+    /// Decode the transaction from the v0 layout or the v1 layout.
     pub fn decode(bytes: &[u8]) -> Result<Self>
     where
         Self: std::marker::Sized,
     {
-        bcs::from_bytes::<Self>(bytes).map_err(Into::into)
+        if let Ok(tx) = bcs::from_bytes::<Self>(bytes) {
+            return Ok(tx);
+        }
+        bcs::from_bytes::<RoochTransactionV1>(bytes)?.try_into()
     }
 
     pub fn encode(&self) -> Vec<u8> {
-        bcs::to_bytes(self).expect("encode transaction should success")
+        if self.is_v0() {
+            bcs::to_bytes(self).expect("encode transaction should success")
+        } else {
+            bcs::to_bytes(&RoochTransactionV1::from(self.clone()))
+                .expect("encode transaction should success")
+        }
     }
 
     pub fn tx_hash(&mut self) -> H256 {
@@ -244,8 +676,40 @@ impl RoochTransaction {
         &self.authenticator
     }
 
+    pub fn fee_payer(&self) -> Option<RoochAddress> {
+        self.data.fee_payer
+    }
+
+    pub fn fee_payer_authenticator(&self) -> Option<&Authenticator> {
+        self.fee_payer_authenticator.as_ref()
+    }
+
+    pub fn is_sponsored(&self) -> bool {
+        self.data.fee_payer.is_some()
+    }
+
+    /// Return the fee payer and its authenticator info if the transaction is sponsored.
+    /// Return an error if the transaction is sponsored but the fee payer has not signed it.
+    pub fn fee_payer_authenticator_info(
+        &self,
+    ) -> Result<Option<(RoochAddress, AuthenticatorInfo)>> {
+        let Some(fee_payer) = self.data.fee_payer else {
+            return Ok(None);
+        };
+        let authenticator = self.fee_payer_authenticator.clone().ok_or_else(|| {
+            anyhow::anyhow!(
+                "The transaction is not signed by the fee payer {}",
+                fee_payer
+            )
+        })?;
+        Ok(Some((
+            fee_payer,
+            AuthenticatorInfo::new(self.chain_id(), authenticator),
+        )))
+    }
+
     pub fn tx_size(&self) -> u64 {
-        bcs::serialized_size(self).expect("serialize transaction size should success") as u64
+        self.encode().len() as u64
     }
 
     //TODO use protest Arbitrary to generate mock data
@@ -278,13 +742,23 @@ impl RoochTransaction {
     pub fn into_moveos_transaction(mut self, root: ObjectMeta) -> MoveOSTransaction {
         let tx_hash = self.tx_hash();
         let tx_size = self.tx_size();
-        let tx_ctx = TxContext::new(
+        let mut tx_ctx = TxContext::new(
             self.data.sender.into(),
             self.data.sequence_number,
             self.data.max_gas_amount,
             tx_hash,
             tx_size,
         );
+        if let Some(fee_payer) = self.data.fee_payer {
+            tx_ctx
+                .add(GasPaymentAccount::new(fee_payer.into()))
+                .expect("add gas payment account to tx context should success");
+        }
+        if let Some(gas_price) = self.data.gas_price {
+            tx_ctx
+                .add(gas_price)
+                .expect("add gas price to tx context should success");
+        }
         MoveOSTransaction::new(root, tx_ctx, self.data.action)
     }
 }
@@ -302,8 +776,14 @@ impl Display for RoochTransaction {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "RoochTransaction {{ data: {}, authenticator: {}, data_hash {:?} }}",
-            self.data, self.authenticator, self.data_hash
+            "RoochTransaction {{ data: {}, authenticator: {}, fee_payer_authenticator: {}, data_hash {:?} }}",
+            self.data,
+            self.authenticator,
+            self.fee_payer_authenticator
+                .as_ref()
+                .map(|authenticator| authenticator.to_string())
+                .unwrap_or_else(|| "None".to_string()),
+            self.data_hash
         )
     }
 }
@@ -12,9 +12,7 @@ use move_core_types::u256::U256;
 use rooch_rpc_api::api::MAX_RESULT_LIMIT;
 use rooch_rpc_api::jsonrpc_types::account_view::BalanceInfoView;
 use rooch_rpc_api::jsonrpc_types::btc::utxo::UTXOFilterView;
-use rooch_rpc_api::jsonrpc_types::{
-    IndexerStateIDView, RoochAddressView, StrView, UnitedAddressView,
-};
+use rooch_rpc_api::jsonrpc_types::{PageCursorView, RoochAddressView, StrView, UnitedAddressView};
 use rooch_rpc_client::Client;
 use rooch_types::address::ParsedAddress;
 use rooch_types::error::RoochResult;
@@ -184,7 +182,7 @@ async fn get_total_utxo_value(
     address: UnitedAddressView,
 ) -> Result<u64, anyhow::Error> {
     let mut total_value: u64 = 0;
-    let mut cursor: Option<IndexerStateIDView> = None;
+    let mut cursor: Option<PageCursorView> = None;
 
     loop {
         let page = client
@@ -0,0 +1,220 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use crate::commands::server::commands::start::build_server_opt;
+use async_trait::async_trait;
+use clap::Parser;
+use moveos_types::module_binding::MoveFunctionCaller;
+use rooch_config::RoochOpt;
+use rooch_relayer::block_file::BlockFileReader;
+use rooch_rpc_client::ClientBuilder;
+use rooch_rpc_server::Service;
+use rooch_types::bitcoin::pending_block::PendingBlockModule;
+use rooch_types::bitcoin::BitcoinModule;
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::service_status::ServiceStatus;
+use std::time::{Duration, Instant};
+use tracing::info;
+
+/// Import the Bitcoin blocks from the bitcoind blk*.dat files, bitcoind should be stopped while importing.
+/// The server is started in data import mode, and stopped once the blocks are imported,
+/// the last `reorg_block_count` blocks stay pending and are processed when the later blocks are relayed.
+#[derive(Debug, Parser)]
+pub struct ImportBlocks {
+    #[clap(flatten)]
+    opt: RoochOpt,
+
+    /// The interval in seconds to check the import progress
+    #[clap(long, default_value = "10")]
+    check_interval: u64,
+
+    /// The timeout in seconds to stop the import if no block is relayed or processed,
+    /// for example the relayer fails to read the blocks files.
+    #[clap(long, default_value = "600")]
+    stall_timeout: u64,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<String> for ImportBlocks {
+    async fn execute(mut self) -> RoochResult<String> {
+        let blocks_dir = self.opt.btc_blocks_dir.clone().ok_or_else(|| {
+            RoochError::CommandArgumentError("The --btc-blocks-dir is required".to_owned())
+        })?;
+        self.opt.service_status = ServiceStatus::DateImportMode;
+
+        let context = self.context_options.build()?;
+        self.opt.init()?;
+
+        let reader = BlockFileReader::open(&blocks_dir)?;
+        let tip_height = reader.tip_height().ok_or_else(|| {
+            RoochError::CommandArgumentError(format!(
+                "No block found in the blocks dir {:?}",
+                blocks_dir
+            ))
+        })?;
+        let target_height = match self.opt.btc_end_block_height {
+            Some(end_block_height) => end_block_height.min(tip_height),
+            None => tip_height,
+        };
+        info!(
+            "Import blocks from {:?}, network: {}, target height: {}",
+            blocks_dir,
+            reader.network(),
+            target_height
+        );
+        drop(reader);
+
+        let server_opt = build_server_opt(&self.opt, &context)?;
+        let mut service = Service::new();
+        service
+            .start(self.opt.clone(), server_opt)
+            .await
+            .map_err(RoochError::from)?;
+
+        let client = ClientBuilder::default()
+            .build(format!("http://127.0.0.1:{}", self.opt.port()))
+            .await?;
+        // The blocks are kept pending for the reorg, and the block is processed only when
+        // there are `reorg_block_count` blocks after it, so the blocks after
+        // `target_height - reorg_block_count` stay pending after the import.
+        let (genesis_block, reorg_block_count) = tokio::task::block_in_place(|| {
+            let genesis_block = client
+                .as_module_binding::<BitcoinModule>()
+                .get_genesis_block()?;
+            let reorg_block_count = client
+                .as_module_binding::<PendingBlockModule>()
+                .get_reorg_block_count()?;
+            anyhow::Ok((genesis_block, reorg_block_count))
+        })?;
+        let processed_height = target_height
+            .checked_sub(reorg_block_count)
+            .filter(|height| *height >= genesis_block.block_height);
+        info!(
+            "Wait for the blocks to be relayed to height {} and processed to height {:?}, reorg block count: {}",
+            target_height, processed_height, reorg_block_count
+        );
+        let stall_timeout = Duration::from_secs(self.stall_timeout);
+        let mut last_progress = (None, None);
+        let mut last_progress_at = Instant::now();
+        let latest_block = loop {
+            let (best_block, latest_block) = tokio::task::block_in_place(|| {
+                let best_block = client
+                    .as_module_binding::<PendingBlockModule>()
+                    .get_best_block()?;
+                let latest_block = client
+                    .as_module_binding::<BitcoinModule>()
+                    .get_latest_block()?;
+                anyhow::Ok((best_block, latest_block))
+            })?;
+            let best_block_height = best_block.map(|block| block.block_height);
+            let latest_block_height = latest_block.as_ref().map(|block| block.block_height);
+            info!(
+                "Relayed blocks to height {:?}, processed blocks to height {:?}",
+                best_block_height, latest_block_height
+            );
+            if is_import_finished(
+                best_block_height,
+                latest_block_height,
+                target_height,
+                processed_height,
+            ) {
+                break latest_block;
+            }
+            if (best_block_height, latest_block_height) != last_progress {
+                last_progress = (best_block_height, latest_block_height);
+                last_progress_at = Instant::now();
+            } else if last_progress_at.elapsed() > stall_timeout {
+                service.stop().map_err(RoochError::from)?;
+                return Err(RoochError::from(anyhow::anyhow!(
+                    "No block is relayed or processed in {} seconds, relayed to height {:?}, processed to height {:?}, please check the server log",
+                    self.stall_timeout,
+                    best_block_height,
+                    latest_block_height
+                )));
+            }
+            tokio::time::sleep(Duration::from_secs(self.check_interval)).await;
+        };
+
+        service.stop().map_err(RoochError::from)?;
+
+        Ok(match latest_block {
+            Some(latest_block) => format!(
+                "Imported the Bitcoin blocks to height {}, processed to height {}, block hash: {}",
+                target_height, latest_block.block_height, latest_block.block_hash
+            ),
+            None => format!(
+                "Imported the Bitcoin blocks to height {}, no block is processed, reorg block count: {}",
+                target_height, reorg_block_count
+            ),
+        })
+    }
+}
+
+/// All the blocks to the `target_height` are relayed, and processed to the `processed_height`,
+/// the `processed_height` is None if no block can be processed before more blocks are relayed.
+fn is_import_finished(
+    best_block_height: Option<u64>,
+    latest_block_height: Option<u64>,
+    target_height: u64,
+    processed_height: Option<u64>,
+) -> bool {
+    let relayed = best_block_height.is_some_and(|height| height >= target_height);
+    let processed = match processed_height {
+        Some(processed_height) => {
+            latest_block_height.is_some_and(|height| height >= processed_height)
+        }
+        None => true,
+    };
+    relayed && processed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_import_finished() {
+        let target_height = 100;
+        let processed_height = Some(target_height - 6);
+        // The blocks are still being relayed
+        assert!(!is_import_finished(
+            None,
+            None,
+            target_height,
+            processed_height
+        ));
+        assert!(!is_import_finished(
+            Some(99),
+            Some(93),
+            target_height,
+            processed_height
+        ));
+        // All the blocks are relayed, but the ready blocks are not processed yet
+        assert!(!is_import_finished(
+            Some(100),
+            None,
+            target_height,
+            processed_height
+        ));
+        assert!(!is_import_finished(
+            Some(100),
+            Some(93),
+            target_height,
+            processed_height
+        ));
+        // The blocks after the processed height wait for more blocks, they are not required
+        assert!(is_import_finished(
+            Some(100),
+            Some(94),
+            target_height,
+            processed_height
+        ));
+        // The target height is less than the genesis height plus the reorg block count
+        assert!(!is_import_finished(Some(2), None, 3, None));
+        assert!(is_import_finished(Some(3), None, 3, None));
+    }
+}
@@ -8,6 +8,7 @@ use bitcoin::{consensus::Encodable, Psbt, Transaction, Txid};
 use broadcast_tx::BroadcastTx;
 use build_tx::BuildTx;
 use clap::{Parser, Subcommand};
+use import_blocks::ImportBlocks;
 use rooch_types::error::RoochResult;
 use serde::{Deserialize, Serialize};
 use sign_tx::SignTx;
@@ -16,6 +17,7 @@ use transfer::Transfer;
 
 pub mod broadcast_tx;
 pub mod build_tx;
+pub mod import_blocks;
 pub mod sign_tx;
 pub mod transaction_builder;
 pub mod transfer;
@@ -34,6 +36,7 @@ pub enum BitcoinCommands {
     SignTx(SignTx),
     BroadcastTx(BroadcastTx),
     Transfer(Transfer),
+    ImportBlocks(ImportBlocks),
 }
 
 #[async_trait]
@@ -44,6 +47,9 @@ impl CommandAction<String> for Bitcoin {
             BitcoinCommands::SignTx(sign_tx) => sign_tx.execute_serialized().await,
             BitcoinCommands::BroadcastTx(broadcast_tx) => broadcast_tx.execute_serialized().await,
             BitcoinCommands::Transfer(transfer) => transfer.execute_serialized().await,
+            BitcoinCommands::ImportBlocks(import_blocks) => {
+                import_blocks.execute_serialized().await
+            }
         }
     }
 }
@@ -5,10 +5,14 @@ use super::sign_tx::SignOutput;
 use super::transaction_builder::TransactionBuilder;
 use crate::cli_types::{CommandAction, WalletContextOptions};
 use crate::commands::bitcoin::sign_tx::sign_psbt;
+use anyhow::anyhow;
 use async_trait::async_trait;
-use bitcoin::{Amount, FeeRate};
+use bitcoin::{Amount, FeeRate, Txid};
 use clap::Parser;
+use moveos_types::moveos_std::object::ObjectID;
+use rooch_rpc_client::Client;
 use rooch_types::address::ParsedAddress;
+use rooch_types::bitcoin::utxo::derive_utxo_id;
 use rooch_types::error::{RoochError, RoochResult};
 use tracing::debug;
 
@@ -35,6 +39,11 @@ pub struct Transfer {
     #[clap(long)]
     skip_check_seal: bool,
 
+    /// Replace the pending transaction by fee (RBF), the inputs of the pending transaction are spent again.
+    /// If the fee rate is not specified, the fee rate of the pending transaction plus 1 sat/vB will be used.
+    #[clap(long)]
+    replace: Option<Txid>,
+
     #[clap(flatten)]
     pub(crate) context_options: WalletContextOptions,
 }
@@ -51,16 +60,38 @@ impl CommandAction<String> for Transfer {
         let to = context.resolve_bitcoin_address(self.to).await?;
         let amount = Amount::from_sat(self.amount);
 
+        let replaced_tx = match self.replace {
+            Some(txid) => Some(ReplacedTransaction::load(&client, txid).await?),
+            None => None,
+        };
+        let fee_rate = match (&replaced_tx, self.fee_rate) {
+            (Some(replaced_tx), Some(fee_rate)) => {
+                if fee_rate <= replaced_tx.fee_rate {
+                    return Err(RoochError::CommandArgumentError(format!(
+                        "The fee rate {} should be greater than the fee rate {} of the replaced transaction {}",
+                        fee_rate, replaced_tx.fee_rate, replaced_tx.txid
+                    )));
+                }
+                Some(fee_rate)
+            }
+            (Some(replaced_tx), None) => Some(replaced_tx.bumped_fee_rate()?),
+            (None, fee_rate) => fee_rate,
+        };
+        let inputs = replaced_tx
+            .as_ref()
+            .map(|replaced_tx| replaced_tx.inputs.clone())
+            .unwrap_or_default();
+
         let mut tx_builder = TransactionBuilder::new(
             &context,
             client.clone(),
             sender.to_bitcoin_address(bitcoin_network)?,
-            vec![],
+            inputs,
             self.skip_check_seal,
         )
         .await?;
 
-        if let Some(fee_rate) = self.fee_rate {
+        if let Some(fee_rate) = fee_rate {
             tx_builder = tx_builder.with_fee_rate(fee_rate);
         }
 
@@ -68,6 +99,19 @@ impl CommandAction<String> for Transfer {
             .build_transfer(to.to_bitcoin_address(bitcoin_network)?, amount)
             .await?;
         debug!("PSBT: {}", serde_json::to_string_pretty(&psbt).unwrap());
+        if let Some(replaced_tx) = &replaced_tx {
+            // BIP125: the replacement should pay for its own bandwidth at the minimum relay fee rate
+            let fee = psbt
+                .fee()
+                .map_err(|e| anyhow!("Failed to get fee: {}", e))?;
+            let min_fee = replaced_tx.fee + Amount::from_sat(psbt.unsigned_tx.vsize() as u64);
+            if fee < min_fee {
+                return Err(RoochError::CommandArgumentError(format!(
+                    "The fee {} is less than {}, the minimum fee to replace the transaction {}, please increase the fee rate",
+                    fee, min_fee, replaced_tx.txid
+                )));
+            }
+        }
         let sign_out = sign_psbt(psbt, None, &context, &client).await?;
         match sign_out {
             SignOutput::Psbt(_psbt) => {
@@ -79,3 +123,55 @@ impl CommandAction<String> for Transfer {
         }
     }
 }
+
+/// The pending transaction to be replaced by fee
+struct ReplacedTransaction {
+    txid: Txid,
+    inputs: Vec<ObjectID>,
+    fee: Amount,
+    fee_rate: FeeRate,
+}
+
+impl ReplacedTransaction {
+    async fn load(client: &Client, txid: Txid) -> anyhow::Result<Self> {
+        let pending_tx = client
+            .rooch
+            .query_pending_bitcoin_transactions()
+            .await?
+            .into_iter()
+            .find(|pending_tx| pending_tx.txid.0 == txid)
+            .ok_or_else(|| anyhow!("The transaction {} is not a pending transaction", txid))?;
+        let tx = pending_tx.transaction()?;
+        let mut inputs = vec![];
+        let mut total_input = Amount::ZERO;
+        for input in pending_tx.inputs {
+            let utxo_id = derive_utxo_id(&input.clone().into());
+            let utxo = client
+                .rooch
+                .get_utxo_object(utxo_id.clone())
+                .await?
+                .ok_or_else(|| {
+                    anyhow!("The input {:?} of transaction {} not found", input, txid)
+                })?;
+            total_input += utxo.amount();
+            inputs.push(utxo_id);
+        }
+        let total_output = tx.output.iter().map(|output| output.value).sum::<Amount>();
+        let fee = total_input
+            .checked_sub(total_output)
+            .ok_or_else(|| anyhow!("The output of transaction {} exceeds the input", txid))?;
+        let fee_rate = FeeRate::from_sat_per_vb(fee.to_sat().div_ceil(tx.vsize() as u64))
+            .ok_or_else(|| anyhow!("Invalid fee rate of transaction {}", txid))?;
+        Ok(Self {
+            txid,
+            inputs,
+            fee,
+            fee_rate,
+        })
+    }
+
+    fn bumped_fee_rate(&self) -> anyhow::Result<FeeRate> {
+        FeeRate::from_sat_per_vb(self.fee_rate.to_sat_per_vb_ceil() + 1)
+            .ok_or_else(|| anyhow!("Invalid fee rate to replace transaction {}", self.txid))
+    }
+}
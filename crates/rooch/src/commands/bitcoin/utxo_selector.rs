@@ -1,14 +1,14 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::VecDeque;
+use std::collections::{HashSet, VecDeque};
 
 use anyhow::{bail, Result};
 use bitcoin::{Address, Amount};
 use moveos_types::moveos_std::object::{ObjectID, GENESIS_STATE_ROOT};
 use rooch_rpc_api::jsonrpc_types::{
     btc::utxo::{UTXOFilterView, UTXOObjectView, UTXOStateView},
-    IndexerStateIDView,
+    PageCursorView,
 };
 use rooch_rpc_client::Client;
 use rooch_types::bitcoin::{types::OutPoint, utxo::derive_utxo_id};
@@ -19,9 +19,10 @@ pub struct UTXOSelector {
     client: Client,
     sender: Address,
     specific_utxos: Vec<ObjectID>,
-    loaded_page: Option<(Option<IndexerStateIDView>, bool)>,
+    loaded_page: Option<(Option<PageCursorView>, bool)>,
     candidate_utxos: VecDeque<UTXOObjectView>,
     skip_seal_check: bool,
+    pending_spent_outpoints: HashSet<OutPoint>,
 }
 
 impl UTXOSelector {
@@ -38,11 +39,33 @@ impl UTXOSelector {
             loaded_page: None,
             candidate_utxos: VecDeque::new(),
             skip_seal_check,
+            pending_spent_outpoints: HashSet::new(),
         };
+        selector.load_pending_spent_outpoints().await?;
         selector.load_specific_utxos().await?;
         Ok(selector)
     }
 
+    /// Load the outpoints spent by the pending transactions, to avoid double spending the unconfirmed UTXOs.
+    /// The specific UTXOs are not checked, so the pending transaction can be replaced by RBF.
+    async fn load_pending_spent_outpoints(&mut self) -> Result<()> {
+        let pending_txs = self
+            .client
+            .rooch
+            .query_pending_bitcoin_transactions()
+            .await?;
+        self.pending_spent_outpoints = pending_txs
+            .into_iter()
+            .flat_map(|pending_tx| pending_tx.inputs)
+            .map(Into::into)
+            .collect();
+        debug!(
+            "loaded pending spent outpoints: {:?}",
+            self.pending_spent_outpoints.len()
+        );
+        Ok(())
+    }
+
     async fn load_specific_utxos(&mut self) -> Result<()> {
         if self.specific_utxos.is_empty() {
             return Ok(());
@@ -78,7 +101,7 @@ impl UTXOSelector {
     }
 
     async fn load_utxos(&mut self) -> Result<()> {
-        let (next_cursor, has_next_page) = self.loaded_page.unwrap_or((None, true));
+        let (next_cursor, has_next_page) = self.loaded_page.clone().unwrap_or((None, true));
         if !has_next_page {
             return Ok(());
         }
@@ -99,6 +122,13 @@ impl UTXOSelector {
             if !self.skip_seal_check && skip_utxo(&utxo_view, minimal_non_dust) {
                 continue;
             }
+            if self.pending_spent_outpoints.contains(&utxo.outpoint()) {
+                debug!(
+                    "UTXO {} is spent by pending transaction, skip.",
+                    utxo.outpoint()
+                );
+                continue;
+            }
             if utxo_view.metadata.owner_bitcoin_address.is_none() {
                 debug!(
                     "Can not recognize the owner of UTXO {}, metadata: {:?}, skip.",
@@ -0,0 +1,82 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::anyhow;
+use clap::Parser;
+use jsonrpsee::http_client::{HeaderMap, HeaderValue, HttpClientBuilder};
+use metrics::RegistryService;
+use rooch_config::{RoochOpt, R_OPT_NET_HELP};
+use rooch_db::RoochDB;
+use rooch_rpc_api::api::admin_api::AdminAPIClient;
+use rooch_rpc_api::jsonrpc_types::admin_view::BackupManifestView;
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::rooch_network::RoochChainID;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Backup the moveos store, rooch store and indexer to a dir.
+/// If `--admin-url` is set, the backup is taken online by the running node through the admin JSON-RPC,
+/// the `output_dir` is a path on the node's host. Otherwise the node must be stopped.
+#[derive(Debug, Parser)]
+pub struct BackupCommand {
+    #[clap(long, short = 'o')]
+    /// The backup dir, it must not exist or be empty
+    pub output_dir: PathBuf,
+
+    #[clap(long)]
+    /// The admin JSON-RPC url of the running node, eg: http://127.0.0.1:6768 or http://127.0.0.1:6767/admin
+    pub admin_url: Option<String>,
+
+    #[clap(long, env = "ROOCH_ADMIN_TOKEN")]
+    /// The admin token of the running node
+    pub admin_token: Option<String>,
+
+    #[clap(long = "data-dir", short = 'd')]
+    /// Path to data dir, this dir is base dir, the final data_dir is base_dir/chain_network_name
+    pub base_data_dir: Option<PathBuf>,
+
+    /// If local chainid, start the service with a temporary data store.
+    /// All data will be deleted when the service is stopped.
+    #[clap(long, short = 'n', help = R_OPT_NET_HELP)]
+    pub chain_id: Option<RoochChainID>,
+}
+
+impl BackupCommand {
+    pub async fn execute(self) -> RoochResult<BackupManifestView> {
+        let manifest = match self.admin_url {
+            Some(admin_url) => {
+                let mut headers = HeaderMap::new();
+                if let Some(admin_token) = self.admin_token {
+                    headers.insert(
+                        "Authorization",
+                        HeaderValue::from_str(&format!("Bearer {}", admin_token))
+                            .map_err(|e| anyhow!("Invalid admin token: {}", e))?,
+                    );
+                }
+                let client = HttpClientBuilder::default()
+                    .set_headers(headers)
+                    // The checkpoint is cheap, but the indexer copy may take a while
+                    .request_timeout(Duration::from_secs(3600))
+                    .build(admin_url)
+                    .map_err(|e| RoochError::from(anyhow!(e)))?;
+                let output_dir = self
+                    .output_dir
+                    .to_str()
+                    .ok_or_else(|| anyhow!("Invalid backup dir"))?
+                    .to_string();
+                client
+                    .backup_db(output_dir)
+                    .await
+                    .map_err(|e| RoochError::from(anyhow!(e)))?
+            }
+            None => {
+                let opt = RoochOpt::new_with_default(self.base_data_dir, self.chain_id, None)?;
+                let registry_service = RegistryService::default();
+                let rooch_db =
+                    RoochDB::init(opt.store_config(), &registry_service.default_registry())?;
+                rooch_db.backup(&self.output_dir)?.into()
+            }
+        };
+        Ok(manifest)
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use rooch_config::R_OPT_NET_HELP;
+use rooch_db::check::DBCheckReport;
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::rooch_network::RoochChainID;
+use std::path::PathBuf;
+
+use crate::commands::db::commands::init;
+
+/// Check the integrity of the rooch store, moveos store and indexer, and suggest repair actions.
+/// The node must be stopped. The command prints the report and exits with an error if the check fails.
+#[derive(Debug, Parser)]
+pub struct CheckCommand {
+    #[clap(long = "data-dir", short = 'd')]
+    /// Path to data dir, this dir is base dir, the final data_dir is base_dir/chain_network_name
+    pub base_data_dir: Option<PathBuf>,
+
+    /// If local chainid, start the service with a temporary data store.
+    /// All data will be deleted when the service is stopped.
+    #[clap(long, short = 'n', help = R_OPT_NET_HELP)]
+    pub chain_id: Option<RoochChainID>,
+}
+
+impl CheckCommand {
+    pub async fn execute(self) -> RoochResult<DBCheckReport> {
+        let (_root, rooch_db, start_time) = init(self.base_data_dir, self.chain_id);
+        let report = rooch_db.check()?;
+        println!(
+            "check db {}, last order {}, cost {:?}",
+            if report.is_ok() { "succ" } else { "failed" },
+            report.last_order,
+            start_time.elapsed().unwrap_or_default()
+        );
+        if !report.is_ok() {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&report).expect("Failed to serialize response")
+            );
+            return Err(RoochError::UnexpectedError(format!(
+                "check db failed with {} errors",
+                report.errors.len()
+            )));
+        }
+        Ok(report)
+    }
+}
@@ -9,6 +9,9 @@ use rooch_types::rooch_network::RoochChainID;
 use std::path::PathBuf;
 use std::time::SystemTime;
 
+pub mod backup;
+pub mod check;
+pub mod restore;
 pub mod revert;
 pub mod rollback;
 
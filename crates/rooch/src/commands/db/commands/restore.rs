@@ -0,0 +1,46 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use rooch_config::{RoochOpt, R_OPT_NET_HELP};
+use rooch_db::RoochDB;
+use rooch_rpc_api::jsonrpc_types::admin_view::BackupManifestView;
+use rooch_types::error::RoochResult;
+use rooch_types::rooch_network::RoochChainID;
+use std::path::PathBuf;
+
+/// Restore the db from a backup created by `rooch db backup`.
+/// The sequencer info, startup info and accumulator root of the backup are validated before restoring,
+/// the node must be stopped and the store and indexer dirs must be empty.
+#[derive(Debug, Parser)]
+pub struct RestoreCommand {
+    #[clap(long, short = 'i')]
+    /// The backup dir
+    pub input_dir: PathBuf,
+
+    #[clap(long = "data-dir", short = 'd')]
+    /// Path to data dir, this dir is base dir, the final data_dir is base_dir/chain_network_name
+    pub base_data_dir: Option<PathBuf>,
+
+    /// If local chainid, start the service with a temporary data store.
+    /// All data will be deleted when the service is stopped.
+    #[clap(long, short = 'n', help = R_OPT_NET_HELP)]
+    pub chain_id: Option<RoochChainID>,
+}
+
+impl RestoreCommand {
+    pub async fn execute(self) -> RoochResult<BackupManifestView> {
+        let opt = RoochOpt::new_with_default(self.base_data_dir, self.chain_id, None)?;
+        let store_config = opt.store_config();
+        let manifest = RoochDB::restore(
+            &self.input_dir,
+            &store_config.get_store_dir(),
+            &store_config.get_indexer_dir(),
+        )?;
+        println!(
+            "restore succ, tx_order: {}, state_root: {:?}",
+            manifest.tx_order, manifest.state_root
+        );
+        Ok(manifest.into())
+    }
+}
@@ -2,6 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::cli_types::CommandAction;
+use crate::commands::db::commands::backup::BackupCommand;
+use crate::commands::db::commands::check::CheckCommand;
+use crate::commands::db::commands::restore::RestoreCommand;
 use crate::commands::db::commands::revert::RevertCommand;
 use async_trait::async_trait;
 use clap::Parser;
@@ -27,6 +30,15 @@ impl CommandAction<String> for DB {
             DBCommand::Rollback(rollback) => rollback.execute().await.map(|resp| {
                 serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
             }),
+            DBCommand::Backup(backup) => backup.execute().await.map(|resp| {
+                serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
+            }),
+            DBCommand::Restore(restore) => restore.execute().await.map(|resp| {
+                serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
+            }),
+            DBCommand::Check(check) => check.execute().await.map(|resp| {
+                serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
+            }),
         }
     }
 }
@@ -36,4 +48,7 @@ impl CommandAction<String> for DB {
 pub enum DBCommand {
     Revert(RevertCommand),
     Rollback(RollbackCommand),
+    Backup(BackupCommand),
+    Restore(RestoreCommand),
+    Check(CheckCommand),
 }
@@ -101,10 +101,14 @@ fn produce_updates(
     let inscription_state_index_start = indexer_reader
         .query_last_state_index_by_tx_order(tx_order, ObjectStateType::Inscription)?
         .map_or(0, |x| x + 1);
+    let rune_state_index_start = indexer_reader
+        .query_last_state_index_by_tx_order(tx_order, ObjectStateType::Rune)?
+        .map_or(0, |x| x + 1);
     let mut state_index_generator = IndexerObjectStatesIndexGenerator {
         object_states_index_generator: state_index_start,
         object_state_utxos_index_generator: utxo_state_index_start,
         object_state_inscriptions_generator: inscription_state_index_start,
+        object_state_runes_generator: rune_state_index_start,
     };
 
     println!(
@@ -161,6 +165,11 @@ fn produce_updates(
                 .object_state_inscriptions
                 .new_object_states
                 .is_empty()
+            && updates
+                .object_state_change_set
+                .object_state_runes
+                .new_object_states
+                .is_empty()
         {
             break;
         }
@@ -194,7 +203,12 @@ fn apply_updates(
             .object_state_inscriptions
             .new_object_states
             .len();
-        let count = object_states_len + utxos_len + inscriptions_len;
+        let runes_len = batch
+            .object_state_change_set
+            .object_state_runes
+            .new_object_states
+            .len();
+        let count = object_states_len + utxos_len + inscriptions_len + runes_len;
         indexer_store.persist_or_update_object_states(
             batch
                 .object_state_change_set
@@ -213,21 +227,31 @@ fn apply_updates(
                 .object_state_inscriptions
                 .new_object_states,
         )?;
+        indexer_store.persist_or_update_object_state_runes(
+            batch
+                .object_state_change_set
+                .object_state_runes
+                .new_object_states,
+        )?;
         ok_count += count;
         println!(
-            "Total {} updates applied. this batch process object states count {}, utxo count {}, inscription count {}. this batch cost: {:?}",
+            "Total {} updates applied. this batch process object states count {}, utxo count {}, inscription count {}, rune count {}. this batch cost: {:?}",
             ok_count,
             object_states_len,
             utxos_len,
             inscriptions_len,
+            runes_len,
             loop_start_time.elapsed()
         );
     }
 
+    // The state indexes are reassigned, invalidate the state cursors of the previous generation
+    let generation = indexer_store.renew_generation()?;
     println!(
-        "Indexer rebuild task finished({} updates applied) in: {:?}",
+        "Indexer rebuild task finished({} updates applied) in: {:?}, indexer generation: {}",
         ok_count,
-        task_start_time.elapsed()
+        task_start_time.elapsed(),
+        generation
     );
 
     Ok(())
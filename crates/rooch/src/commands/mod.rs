@@ -13,6 +13,7 @@ pub mod genesis;
 pub mod indexer;
 pub mod init;
 pub mod move_cli;
+pub mod multisign;
 pub mod object;
 pub mod resource;
 pub mod rpc;
@@ -0,0 +1,183 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use crate::commands::move_cli::commands::publish::MemoryModuleResolver;
+use crate::commands::move_cli::get_onchain_package_modules;
+use async_trait::async_trait;
+use clap::Parser;
+use move_binary_format::errors::Location;
+use move_binary_format::CompiledModule;
+use move_cli::Move;
+use moveos_compiler::dependency_order::sort_by_dependency_order;
+use moveos_verifier::build::run_verifier;
+use moveos_verifier::verifier::{check_metadata_compatibility, check_module_compatibility};
+use rooch_types::error::{RoochError, RoochResult};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::stderr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModuleUpgradeStatus {
+    /// The module is not deployed on chain
+    New,
+    /// The bytecode is the same as the deployed module
+    Unchanged,
+    /// The module can be upgraded
+    Compatible,
+    /// The module can not be upgraded
+    Incompatible,
+    /// The module is deployed on chain but not in the package, it is kept on chain after upgrade
+    Missing,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModuleUpgradeCheck {
+    pub module_name: String,
+    pub status: ModuleUpgradeStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckUpgradeOutput {
+    pub package_address: String,
+    pub modules: Vec<ModuleUpgradeCheck>,
+}
+
+/// Check whether the package can be upgraded before publishing it.
+/// The deployed package is downloaded from chain, and the Move compatibility rules
+/// and the MoveOS verifier rules are checked locally.
+#[derive(Parser)]
+#[clap(name = "check-upgrade")]
+pub struct CheckUpgradeCommand {
+    #[clap(flatten)]
+    context_options: WalletContextOptions,
+
+    #[clap(flatten)]
+    move_args: Move,
+
+    /// Named addresses for the move binary
+    ///
+    /// Example: alice=0x1234, bob=default, alice2=alice
+    ///
+    /// Note: This will fail if there are duplicates in the Move.toml file remove those first.
+    #[clap(long, value_parser=crate::utils::parse_map::<String, String>, default_value = "")]
+    pub(crate) named_addresses: BTreeMap<String, String>,
+}
+
+#[async_trait]
+impl CommandAction<CheckUpgradeOutput> for CheckUpgradeCommand {
+    async fn execute(self) -> RoochResult<CheckUpgradeOutput> {
+        let context = self.context_options.build()?;
+
+        let package_path = self
+            .move_args
+            .package_path
+            .unwrap_or_else(|| std::env::current_dir().unwrap());
+        let mut config = self.move_args.build_config;
+        config.additional_named_addresses =
+            context.parse_and_resolve_addresses(self.named_addresses)?;
+        let config_cloned = config.clone();
+
+        let mut package = config.compile_package_no_exit(&package_path, &mut stderr())?;
+        run_verifier(package_path, config_cloned, &mut package)?;
+
+        let modules = package.root_modules_map();
+        let sorted_modules = sort_by_dependency_order(modules.iter_modules())?;
+        let pkg_address = match sorted_modules.first() {
+            Some(module) => *module.self_id().address(),
+            None => {
+                return Err(RoochError::MoveCompilationError(
+                    "compiling move modules error! Is the project or module empty".to_owned(),
+                ))
+            }
+        };
+
+        let client = context.get_client().await?;
+        let all_module_ids = package
+            .all_modules_map()
+            .get_map()
+            .iter()
+            .map(|(mid, _)| mid.clone())
+            .collect::<Vec<_>>();
+        let mut resolver = MemoryModuleResolver::new(client.clone());
+        resolver.download(all_module_ids)?;
+        moveos_verifier::verifier::verify_modules(&sorted_modules, &resolver)?;
+
+        let mut onchain_modules = get_onchain_package_modules(&client, pkg_address).await?;
+        let mut results = vec![];
+        for module in sorted_modules.iter() {
+            let module_id = module.self_id();
+            let module_name = module_id.name().to_string();
+            if module_id.address() != &pkg_address {
+                return Err(RoochError::MoveCompilationError(format!(
+                    "module's address ({:?}) not same as package module address {:?}",
+                    module_id.address(),
+                    pkg_address,
+                )));
+            }
+            let (status, error) = match onchain_modules.remove(&module_name) {
+                None => (ModuleUpgradeStatus::New, None),
+                Some(old_bytecodes) => check_upgrade(&old_bytecodes, module)?,
+            };
+            results.push(ModuleUpgradeCheck {
+                module_name,
+                status,
+                error,
+            });
+        }
+        results.extend(
+            onchain_modules
+                .into_keys()
+                .map(|module_name| ModuleUpgradeCheck {
+                    module_name,
+                    status: ModuleUpgradeStatus::Missing,
+                    error: None,
+                }),
+        );
+
+        let incompatible_modules = results
+            .iter()
+            .filter(|result| result.status == ModuleUpgradeStatus::Incompatible)
+            .map(|result| {
+                format!(
+                    "{}: {}",
+                    result.module_name,
+                    result.error.clone().unwrap_or_default()
+                )
+            })
+            .collect::<Vec<_>>();
+        if !incompatible_modules.is_empty() {
+            return Err(RoochError::MoveCompatibilityError(format!(
+                "Modules are incompatible with the deployed package {}:\n{}",
+                pkg_address.to_hex_literal(),
+                incompatible_modules.join("\n")
+            )));
+        }
+
+        Ok(CheckUpgradeOutput {
+            package_address: pkg_address.to_hex_literal(),
+            modules: results,
+        })
+    }
+}
+
+fn check_upgrade(
+    old_bytecodes: &[u8],
+    new_module: &CompiledModule,
+) -> RoochResult<(ModuleUpgradeStatus, Option<String>)> {
+    let old_module =
+        CompiledModule::deserialize(old_bytecodes).map_err(|e| e.finish(Location::Undefined))?;
+    if &old_module == new_module {
+        return Ok((ModuleUpgradeStatus::Unchanged, None));
+    }
+    if let Err(e) = check_module_compatibility(&old_module, new_module) {
+        return Ok((ModuleUpgradeStatus::Incompatible, Some(e.to_string())));
+    }
+    if let Err(e) = check_metadata_compatibility(&old_module, new_module) {
+        return Ok((ModuleUpgradeStatus::Incompatible, Some(e.to_string())));
+    }
+    Ok((ModuleUpgradeStatus::Compatible, None))
+}
@@ -0,0 +1,931 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use crate::commands::move_cli::print_serialized_success;
+use anyhow::Result;
+use async_trait::async_trait;
+use clap::Parser;
+use move_binary_format::access::ModuleAccess;
+use move_binary_format::file_format::{
+    AbilitySet, FunctionDefinition, SignatureToken, StructDefinition, StructFieldInformation,
+    StructHandleIndex, Visibility,
+};
+use move_binary_format::CompiledModule;
+use move_cli::{base::reroot_path, Move};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::ModuleId;
+use rooch_types::error::{RoochError, RoochResult};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const GENERATED_HEADER: &str =
+    "// This file is generated by `rooch move gen-bindings`, do not edit it manually.";
+
+/// Generate the Rust bindings of the Move modules.
+/// The bindings include the `MoveStructType`/`MoveStructState` for the structs,
+/// the `ModuleBinding` and the typed function call builders for the public and entry functions.
+#[derive(Parser)]
+#[clap(name = "gen-bindings")]
+pub struct GenBindingsCommand {
+    /// Named addresses for the move binary
+    ///
+    /// Example: alice=0x1234, bob=default, alice2=alice
+    ///
+    /// Note: This will fail if there are duplicates in the Move.toml file remove those first.
+    #[clap(long, value_parser = crate::utils::parse_map::<String, String>, default_value = "")]
+    pub(crate) named_addresses: BTreeMap<String, String>,
+
+    /// The compiled module files(.mv) to generate the bindings from,
+    /// if not set, the package at the package path is compiled.
+    #[clap(long, num_args = 1..)]
+    module_files: Vec<PathBuf>,
+
+    /// Only generate the bindings for the given modules, default is all modules of the package
+    #[clap(long, num_args = 1..)]
+    modules: Vec<String>,
+
+    /// The directory to write the generated Rust source files, default is `<package_path>/bindings`
+    #[clap(long)]
+    output_dir: Option<PathBuf>,
+
+    #[clap(flatten)]
+    config_options: WalletContextOptions,
+
+    #[clap(flatten)]
+    move_args: Move,
+
+    /// Return command outputs in json format
+    #[clap(long, default_value = "false")]
+    json: bool,
+}
+
+#[async_trait]
+impl CommandAction<Option<Value>> for GenBindingsCommand {
+    async fn execute(self) -> RoochResult<Option<Value>> {
+        let rerooted_path = reroot_path(self.move_args.package_path)?;
+        let mut modules = if self.module_files.is_empty() {
+            let context = self.config_options.build()?;
+            let mut config = self.move_args.build_config;
+            config
+                .additional_named_addresses
+                .extend(context.parse_and_resolve_addresses(self.named_addresses)?);
+            let original_current_dir = std::env::current_dir()?;
+            let package = config.compile_package_no_exit(&rerooted_path, &mut std::io::stdout())?;
+            std::env::set_current_dir(original_current_dir)?;
+            package.root_modules_map().iter_modules_owned()
+        } else {
+            self.module_files
+                .iter()
+                .map(|path| {
+                    let bytes = std::fs::read(path)?;
+                    CompiledModule::deserialize(&bytes).map_err(|e| {
+                        RoochError::MoveCompilationError(format!(
+                            "Deserialize the module file {:?} error: {:?}",
+                            path, e
+                        ))
+                    })
+                })
+                .collect::<RoochResult<Vec<_>>>()?
+        };
+        if !self.modules.is_empty() {
+            modules.retain(|module| self.modules.contains(&module.self_id().name().to_string()));
+        }
+        if modules.is_empty() {
+            return Err(RoochError::CommandArgumentError(
+                "No module found to generate the bindings".to_owned(),
+            ));
+        }
+
+        let output_dir = self
+            .output_dir
+            .unwrap_or_else(|| rerooted_path.join("bindings"));
+        let files = generate_bindings(&modules)?;
+        write_bindings(&output_dir, files)?;
+        if !self.json {
+            println!("Generated the bindings to {}", output_dir.display());
+        }
+
+        print_serialized_success(self.json)
+    }
+}
+
+/// Generate the bindings of the modules, returns the file names and the Rust source code,
+/// the `mod.rs` which declares all the modules is included.
+pub fn generate_bindings(modules: &[CompiledModule]) -> Result<BTreeMap<String, String>> {
+    let module_ids = modules
+        .iter()
+        .map(|module| module.self_id())
+        .collect::<BTreeSet<_>>();
+    let mut files = BTreeMap::new();
+    let mut mod_rs = format!("{}\n\n", GENERATED_HEADER);
+    for module in modules {
+        let module_name = module.self_id().name().to_string();
+        let source = ModuleBindingGenerator::new(module, &module_ids).generate()?;
+        files.insert(format!("{}.rs", module_name), source);
+        writeln!(mod_rs, "pub mod {};", rust_ident(&module_name))?;
+    }
+    files.insert("mod.rs".to_owned(), mod_rs);
+    Ok(files)
+}
+
+fn write_bindings(output_dir: &Path, files: BTreeMap<String, String>) -> Result<()> {
+    std::fs::create_dir_all(output_dir)?;
+    let mut paths = vec![];
+    for (file_name, source) in files {
+        let path = output_dir.join(file_name);
+        std::fs::write(&path, source)?;
+        paths.push(path);
+    }
+    // The generated code is readable without formatting, so ignore the rustfmt error.
+    match Command::new("rustfmt")
+        .arg("--edition")
+        .arg("2021")
+        .args(&paths)
+        .status()
+    {
+        Ok(status) if status.success() => {}
+        _ => tracing::warn!("Failed to format the generated bindings with rustfmt"),
+    }
+    Ok(())
+}
+
+struct ModuleBindingGenerator<'a> {
+    module: &'a CompiledModule,
+    /// The modules which bindings are generated together, the structs of them can be referenced
+    module_ids: &'a BTreeSet<ModuleId>,
+}
+
+impl<'a> ModuleBindingGenerator<'a> {
+    fn new(module: &'a CompiledModule, module_ids: &'a BTreeSet<ModuleId>) -> Self {
+        Self { module, module_ids }
+    }
+
+    fn generate(&self) -> Result<String> {
+        let module_id = self.module.self_id();
+        let mut out = String::new();
+        writeln!(out, "{}", GENERATED_HEADER)?;
+        writeln!(out, "#![allow(unused_imports, dead_code, clippy::all)]")?;
+        writeln!(out)?;
+        writeln!(out, "use anyhow::Result;")?;
+        writeln!(out, "use move_core_types::ident_str;")?;
+        writeln!(out, "use move_core_types::identifier::IdentStr;")?;
+        writeln!(
+            out,
+            "use moveos_types::module_binding::{{ModuleBinding, MoveFunctionCaller}};"
+        )?;
+        writeln!(
+            out,
+            "use moveos_types::state::{{MoveState, MoveStructState, MoveStructType, MoveType}};"
+        )?;
+        writeln!(out, "use serde::{{Deserialize, Serialize}};")?;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "pub const MODULE_ADDRESS: move_core_types::account_address::AccountAddress = {};",
+            address_literal(module_id.address())
+        )?;
+        writeln!(
+            out,
+            "pub const MODULE_NAME: &IdentStr = ident_str!(\"{}\");",
+            module_id.name()
+        )?;
+
+        for struct_def in self.module.struct_defs() {
+            writeln!(out)?;
+            self.generate_struct(&mut out, struct_def)?;
+        }
+
+        writeln!(out)?;
+        self.generate_module_binding(&mut out)?;
+        Ok(out)
+    }
+
+    fn generate_struct(&self, out: &mut String, struct_def: &StructDefinition) -> Result<()> {
+        let handle = self.module.struct_handle_at(struct_def.struct_handle);
+        let struct_name = self.module.identifier_at(handle.name).to_string();
+        let fields = match &struct_def.field_information {
+            StructFieldInformation::Native => {
+                writeln!(out, "// Skip the native struct `{}`", struct_name)?;
+                return Ok(());
+            }
+            StructFieldInformation::Declared(fields) => fields,
+        };
+        let type_params = (0..handle.type_parameters.len())
+            .map(|idx| format!("T{}", idx))
+            .collect::<Vec<_>>();
+        let mut rust_fields = vec![];
+        for field in fields {
+            let field_name = self.module.identifier_at(field.name).to_string();
+            match self.rust_type(&field.signature.0, &type_params) {
+                Some(field_type) => rust_fields.push((field_name, field_type)),
+                None => {
+                    writeln!(
+                        out,
+                        "// Skip the struct `{}`: unsupported type of the field `{}`",
+                        struct_name, field_name
+                    )?;
+                    return Ok(());
+                }
+            }
+        }
+
+        let phantom_params = handle
+            .type_parameters
+            .iter()
+            .zip(type_params.iter())
+            .filter(|(param, _)| param.is_phantom)
+            .map(|(_, name)| name.clone())
+            .collect::<Vec<_>>();
+        let bounds = handle
+            .type_parameters
+            .iter()
+            .zip(type_params.iter())
+            .map(|(param, name)| {
+                format!(
+                    "{}: {}",
+                    name,
+                    type_param_bounds(param.constraints, !param.is_phantom)
+                )
+            })
+            .collect::<Vec<_>>();
+        // The serde derive bounds every type parameter used in the fields, but the phantom type parameters
+        // are never serialized, such as the `T` of `Object<T>`, so only bound the non-phantom ones.
+        let serde_bounds = handle
+            .type_parameters
+            .iter()
+            .zip(type_params.iter())
+            .filter(|(param, _)| !param.is_phantom)
+            .map(|(_, name)| format!("{}: MoveState", name))
+            .collect::<Vec<_>>();
+        let generics = angle_bracketed(&type_params);
+        let impl_generics = angle_bracketed(&bounds);
+
+        writeln!(out, "#[derive(Debug, Clone, Serialize, Deserialize)]")?;
+        if !type_params.is_empty() {
+            writeln!(out, "#[serde(bound = \"{}\")]", serde_bounds.join(", "))?;
+        }
+        writeln!(out, "pub struct {}{} {{", struct_name, generics)?;
+        for (field_name, field_type) in rust_fields.iter() {
+            writeln!(out, "    pub {}: {},", rust_ident(field_name), field_type)?;
+        }
+        if !phantom_params.is_empty() {
+            writeln!(
+                out,
+                "    pub phantom: std::marker::PhantomData<({},)>,",
+                phantom_params.join(", ")
+            )?;
+        }
+        writeln!(out, "}}")?;
+        writeln!(out)?;
+
+        writeln!(
+            out,
+            "impl{} MoveStructType for {}{} {{",
+            impl_generics, struct_name, generics
+        )?;
+        writeln!(
+            out,
+            "    const ADDRESS: move_core_types::account_address::AccountAddress = MODULE_ADDRESS;"
+        )?;
+        writeln!(
+            out,
+            "    const MODULE_NAME: &'static IdentStr = MODULE_NAME;"
+        )?;
+        writeln!(
+            out,
+            "    const STRUCT_NAME: &'static IdentStr = ident_str!(\"{}\");",
+            struct_name
+        )?;
+        if !type_params.is_empty() {
+            writeln!(out)?;
+            writeln!(
+                out,
+                "    fn type_params() -> Vec<move_core_types::language_storage::TypeTag> {{"
+            )?;
+            let type_tags = type_params
+                .iter()
+                .map(|name| format!("{}::type_tag()", name))
+                .collect::<Vec<_>>();
+            writeln!(out, "        vec![{}]", type_tags.join(", "))?;
+            writeln!(out, "    }}")?;
+        }
+        writeln!(out, "}}")?;
+        writeln!(out)?;
+
+        writeln!(
+            out,
+            "impl{} MoveStructState for {}{} {{",
+            impl_generics, struct_name, generics
+        )?;
+        writeln!(
+            out,
+            "    fn struct_layout() -> move_core_types::value::MoveStructLayout {{"
+        )?;
+        writeln!(
+            out,
+            "        move_core_types::value::MoveStructLayout::new(vec!["
+        )?;
+        for (_, field_type) in rust_fields.iter() {
+            writeln!(
+                out,
+                "            <{} as MoveState>::type_layout(),",
+                field_type
+            )?;
+        }
+        writeln!(out, "        ])")?;
+        writeln!(out, "    }}")?;
+        writeln!(out, "}}")?;
+        Ok(())
+    }
+
+    fn generate_module_binding(&self, out: &mut String) -> Result<()> {
+        let module_id = self.module.self_id();
+        let binding_name = format!("{}Module", camel_case(module_id.name().as_str()));
+        writeln!(
+            out,
+            "/// Rust bindings for the `{}` module",
+            module_id.short_str_lossless()
+        )?;
+        writeln!(out, "pub struct {}<'a> {{", binding_name)?;
+        writeln!(out, "    caller: &'a dyn MoveFunctionCaller,")?;
+        writeln!(out, "}}")?;
+        writeln!(out)?;
+        writeln!(out, "impl<'a> {}<'a> {{", binding_name)?;
+        let mut first = true;
+        for function_def in self.module.function_defs() {
+            if function_def.visibility != Visibility::Public && !function_def.is_entry {
+                continue;
+            }
+            if !first {
+                writeln!(out)?;
+            }
+            first = false;
+            self.generate_function(out, function_def)?;
+        }
+        writeln!(out, "}}")?;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "impl<'a> ModuleBinding<'a> for {}<'a> {{",
+            binding_name
+        )?;
+        writeln!(
+            out,
+            "    const MODULE_NAME: &'static IdentStr = MODULE_NAME;"
+        )?;
+        writeln!(
+            out,
+            "    const MODULE_ADDRESS: move_core_types::account_address::AccountAddress = MODULE_ADDRESS;"
+        )?;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "    fn new(caller: &'a impl MoveFunctionCaller) -> Self\n    where\n        Self: Sized,\n    {{"
+        )?;
+        writeln!(out, "        Self {{ caller }}")?;
+        writeln!(out, "    }}")?;
+        writeln!(out, "}}")?;
+        Ok(())
+    }
+
+    fn generate_function(&self, out: &mut String, function_def: &FunctionDefinition) -> Result<()> {
+        let handle = self.module.function_handle_at(function_def.function);
+        let function_name = self.module.identifier_at(handle.name).to_string();
+        let type_params = (0..handle.type_parameters.len())
+            .map(|idx| format!("T{}", idx))
+            .collect::<Vec<_>>();
+
+        let parameters = &self.module.signature_at(handle.parameters).0;
+        // The signer arguments are provided by the transaction sender
+        let has_signer = parameters.iter().any(is_signer);
+        let mut args = vec![];
+        for (idx, parameter) in parameters.iter().filter(|p| !is_signer(p)).enumerate() {
+            match self.rust_type(parameter, &type_params) {
+                Some(arg_type) => args.push((format!("arg{}", idx), arg_type)),
+                None => {
+                    writeln!(
+                        out,
+                        "    // Skip the function `{}`: unsupported parameter type",
+                        function_name
+                    )?;
+                    return Ok(());
+                }
+            }
+        }
+        let returns = self
+            .module
+            .signature_at(handle.return_)
+            .0
+            .iter()
+            .map(|ret| self.rust_type(ret, &type_params))
+            .collect::<Option<Vec<_>>>();
+
+        let value_types = args
+            .iter()
+            .map(|(_, arg_type)| arg_type.clone())
+            .chain(returns.clone().unwrap_or_default())
+            .collect::<Vec<_>>();
+        let bounds = handle
+            .type_parameters
+            .iter()
+            .zip(type_params.iter())
+            .map(|(constraints, name)| {
+                let is_value = value_types
+                    .iter()
+                    .any(|value_type| contains_type_param(value_type, name));
+                format!("{}: {}", name, type_param_bounds(*constraints, is_value))
+            })
+            .collect::<Vec<_>>();
+        let impl_generics = angle_bracketed(&bounds);
+        let turbofish = if type_params.is_empty() {
+            String::new()
+        } else {
+            format!("::{}", angle_bracketed(&type_params))
+        };
+        let arg_decls = args
+            .iter()
+            .map(|(name, arg_type)| format!("{}: {}", name, arg_type))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let arg_names = args
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let const_name = format!("{}_FUNCTION_NAME", function_name.to_uppercase());
+
+        writeln!(
+            out,
+            "    pub const {}: &'static IdentStr = ident_str!(\"{}\");",
+            const_name, function_name
+        )?;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "    pub fn {}_call{}({}) -> moveos_types::transaction::FunctionCall {{",
+            function_name, impl_generics, arg_decls
+        )?;
+        writeln!(out, "        Self::create_function_call(")?;
+        writeln!(out, "            Self::{},", const_name)?;
+        let type_tags = type_params
+            .iter()
+            .map(|name| format!("{}::type_tag()", name))
+            .collect::<Vec<_>>();
+        writeln!(out, "            vec![{}],", type_tags.join(", "))?;
+        let move_values = args
+            .iter()
+            .map(|(name, _)| format!("{}.to_move_value()", name))
+            .collect::<Vec<_>>();
+        writeln!(out, "            vec![{}],", move_values.join(", "))?;
+        writeln!(out, "        )")?;
+        writeln!(out, "    }}")?;
+
+        if function_def.is_entry {
+            writeln!(out)?;
+            writeln!(
+                out,
+                "    pub fn {}_action{}({}) -> moveos_types::transaction::MoveAction {{",
+                function_name, impl_generics, arg_decls
+            )?;
+            writeln!(
+                out,
+                "        moveos_types::transaction::MoveAction::Function(Self::{}_call{}({}))",
+                function_name, turbofish, arg_names
+            )?;
+            writeln!(out, "    }}")?;
+        }
+
+        // The public functions with return values can be called via the MoveFunctionCaller
+        let returns = match returns {
+            Some(returns) if !returns.is_empty() && !has_signer && !function_def.is_entry => {
+                returns
+            }
+            _ => return Ok(()),
+        };
+        let return_type = if returns.len() == 1 {
+            returns[0].clone()
+        } else {
+            format!("({})", returns.join(", "))
+        };
+        writeln!(out)?;
+        let self_arg = if arg_decls.is_empty() {
+            "&self".to_owned()
+        } else {
+            format!("&self, {}", arg_decls)
+        };
+        writeln!(
+            out,
+            "    pub fn {}{}({}) -> Result<{}> {{",
+            rust_ident(&function_name),
+            impl_generics,
+            self_arg,
+            return_type
+        )?;
+        writeln!(
+            out,
+            "        let call = Self::{}_call{}({});",
+            function_name, turbofish, arg_names
+        )?;
+        writeln!(
+            out,
+            "        let ctx = moveos_types::moveos_std::tx_context::TxContext::new_readonly_ctx(move_core_types::account_address::AccountAddress::ZERO);"
+        )?;
+        writeln!(
+            out,
+            "        let mut values = self.caller.call_function(&ctx, call)?.into_result()?.into_iter();"
+        )?;
+        let decoded = returns
+            .iter()
+            .map(|ret| {
+                format!(
+                    "<{} as MoveState>::from_bytes(&values.next().ok_or_else(|| anyhow::anyhow!(\"Missing return value\"))?.value)?",
+                    ret
+                )
+            })
+            .collect::<Vec<_>>();
+        if decoded.len() == 1 {
+            writeln!(out, "        Ok({})", decoded[0])?;
+        } else {
+            writeln!(out, "        Ok(({}))", decoded.join(", "))?;
+        }
+        writeln!(out, "    }}")?;
+        Ok(())
+    }
+
+    /// Map the Move type to the Rust type, returns None if the type is not supported
+    fn rust_type(&self, token: &SignatureToken, type_params: &[String]) -> Option<String> {
+        match token {
+            SignatureToken::Bool => Some("bool".to_owned()),
+            SignatureToken::U8 => Some("u8".to_owned()),
+            SignatureToken::U16 => Some("u16".to_owned()),
+            SignatureToken::U32 => Some("u32".to_owned()),
+            SignatureToken::U64 => Some("u64".to_owned()),
+            SignatureToken::U128 => Some("u128".to_owned()),
+            SignatureToken::U256 => Some("move_core_types::u256::U256".to_owned()),
+            SignatureToken::Address => {
+                Some("move_core_types::account_address::AccountAddress".to_owned())
+            }
+            SignatureToken::Vector(element) => self
+                .rust_type(element, type_params)
+                .map(|element| format!("Vec<{}>", element)),
+            SignatureToken::Struct(idx) => self.struct_rust_type(*idx, vec![]),
+            SignatureToken::StructInstantiation(idx, ty_args) => {
+                let ty_args = ty_args
+                    .iter()
+                    .map(|ty_arg| self.rust_type(ty_arg, type_params))
+                    .collect::<Option<Vec<_>>>()?;
+                self.struct_rust_type(*idx, ty_args)
+            }
+            SignatureToken::TypeParameter(idx) => type_params.get(*idx as usize).cloned(),
+            SignatureToken::Signer
+            | SignatureToken::Reference(_)
+            | SignatureToken::MutableReference(_) => None,
+        }
+    }
+
+    fn struct_rust_type(&self, idx: StructHandleIndex, ty_args: Vec<String>) -> Option<String> {
+        let handle = self.module.struct_handle_at(idx);
+        let module_handle = self.module.module_handle_at(handle.module);
+        let address = *self.module.address_identifier_at(module_handle.address);
+        let module_name = self.module.identifier_at(module_handle.name).to_string();
+        let struct_name = self.module.identifier_at(handle.name).to_string();
+        let well_known = match (address, module_name.as_str(), struct_name.as_str()) {
+            (AccountAddress::ONE, "string", "String") => {
+                Some("moveos_types::move_std::string::MoveString".to_owned())
+            }
+            (AccountAddress::ONE, "ascii", "String") => {
+                Some("moveos_types::move_std::ascii::MoveAsciiString".to_owned())
+            }
+            (AccountAddress::ONE, "option", "Option") => Some(format!(
+                "moveos_types::move_std::option::MoveOption{}",
+                angle_bracketed(&ty_args)
+            )),
+            (AccountAddress::TWO, "object", "ObjectID") => {
+                Some("moveos_types::moveos_std::object::ObjectID".to_owned())
+            }
+            (AccountAddress::TWO, "object", "Object") => Some(format!(
+                "moveos_types::moveos_std::object::Object{}",
+                angle_bracketed(&ty_args)
+            )),
+            _ => None,
+        };
+        if well_known.is_some() {
+            return well_known;
+        }
+        let module_id = ModuleId::new(address, Identifier::new(module_name.clone()).ok()?);
+        if module_id == self.module.self_id() {
+            Some(format!("{}{}", struct_name, angle_bracketed(&ty_args)))
+        } else if self.module_ids.contains(&module_id) {
+            Some(format!(
+                "super::{}::{}{}",
+                rust_ident(&module_name),
+                struct_name,
+                angle_bracketed(&ty_args)
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+fn is_signer(token: &SignatureToken) -> bool {
+    match token {
+        SignatureToken::Signer => true,
+        SignatureToken::Reference(inner) => matches!(inner.as_ref(), SignatureToken::Signer),
+        _ => false,
+    }
+}
+
+/// The Rust trait bounds of the Move type parameter,
+/// the `key` constraint means the type argument must be a struct.
+fn type_param_bounds(constraints: AbilitySet, is_value: bool) -> String {
+    let mut bounds = vec![if is_value { "MoveState" } else { "MoveType" }];
+    if constraints.has_key() {
+        bounds.push("MoveStructType");
+    }
+    bounds.join(" + ")
+}
+
+fn contains_type_param(rust_type: &str, type_param: &str) -> bool {
+    rust_type
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .any(|segment| segment == type_param)
+}
+
+fn angle_bracketed(items: &[String]) -> String {
+    if items.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", items.join(", "))
+    }
+}
+
+fn address_literal(address: &AccountAddress) -> String {
+    let bytes = address
+        .to_vec()
+        .iter()
+        .map(|b| format!("{:#04x}", b))
+        .collect::<Vec<_>>();
+    format!(
+        "move_core_types::account_address::AccountAddress::new([{}])",
+        bytes.join(", ")
+    )
+}
+
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn rust_ident(name: &str) -> String {
+    const RUST_KEYWORDS: &[&str] = &[
+        "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+        "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+        "mut", "pub", "ref", "return", "static", "struct", "trait", "true", "type", "unsafe",
+        "use", "where", "while", "abstract", "become", "box", "do", "final", "macro", "override",
+        "priv", "try", "typeof", "unsized", "virtual", "yield",
+    ];
+    if RUST_KEYWORDS.contains(&name) {
+        format!("r#{}", name)
+    } else {
+        name.to_owned()
+    }
+}
+
+/// The bindings generated from the test module, it is compiled with the tests to make sure the generated code compiles.
+#[cfg(test)]
+#[rustfmt::skip]
+mod example_bindings;
+
+#[cfg(test)]
+mod tests {
+    use super::example_bindings::{Counter, ExampleModule, Holder, Wrapper};
+    use super::*;
+    use move_binary_format::file_format::{
+        Ability, AddressIdentifierIndex, FieldDefinition, FunctionHandle, FunctionHandleIndex,
+        IdentifierIndex, ModuleHandle, ModuleHandleIndex, Signature, SignatureIndex, StructHandle,
+        StructTypeParameter, TypeSignature,
+    };
+    use move_core_types::language_storage::TypeTag;
+    use moveos_types::moveos_std::object::{Object, ObjectID};
+    use moveos_types::state::{MoveState, MoveStructType, MoveType};
+    use std::marker::PhantomData;
+
+    fn struct_handle(
+        module: u16,
+        name: u16,
+        constraints: AbilitySet,
+        is_phantom: bool,
+    ) -> StructHandle {
+        StructHandle {
+            module: ModuleHandleIndex(module),
+            name: IdentifierIndex(name),
+            abilities: AbilitySet::EMPTY,
+            type_parameters: vec![StructTypeParameter {
+                constraints,
+                is_phantom,
+            }],
+        }
+    }
+
+    fn function_handle(
+        name: u16,
+        parameters: u16,
+        return_: u16,
+        type_parameters: Vec<AbilitySet>,
+    ) -> FunctionHandle {
+        FunctionHandle {
+            module: ModuleHandleIndex(0),
+            name: IdentifierIndex(name),
+            parameters: SignatureIndex(parameters),
+            return_: SignatureIndex(return_),
+            type_parameters,
+        }
+    }
+
+    fn function_def(function: u16, visibility: Visibility, is_entry: bool) -> FunctionDefinition {
+        FunctionDefinition {
+            function: FunctionHandleIndex(function),
+            visibility,
+            is_entry,
+            acquires_global_resources: vec![],
+            code: None,
+        }
+    }
+
+    fn struct_def(
+        struct_handle: u16,
+        field_name: u16,
+        field_type: SignatureToken,
+    ) -> StructDefinition {
+        StructDefinition {
+            struct_handle: StructHandleIndex(struct_handle),
+            field_information: StructFieldInformation::Declared(vec![FieldDefinition {
+                name: IdentifierIndex(field_name),
+                signature: TypeSignature(field_type),
+            }]),
+        }
+    }
+
+    /// The module of the `example_bindings`:
+    /// struct Counter<phantom T> { value: u64 }
+    /// struct Holder<phantom T: key> { obj: Object<T> }
+    /// struct Wrapper<T: store> { value: T }
+    /// entry fun increase(&signer, u64)
+    /// public fun name(): vector<u8>
+    /// public fun wrap<T: store>(T): Wrapper<T>
+    fn test_module() -> CompiledModule {
+        let key = AbilitySet::singleton(Ability::Key);
+        let store = AbilitySet::singleton(Ability::Store);
+        CompiledModule {
+            version: move_binary_format::file_format_common::VERSION_MAX,
+            self_module_handle_idx: ModuleHandleIndex(0),
+            module_handles: vec![
+                ModuleHandle {
+                    address: AddressIdentifierIndex(0),
+                    name: IdentifierIndex(0),
+                },
+                ModuleHandle {
+                    address: AddressIdentifierIndex(1),
+                    name: IdentifierIndex(7),
+                },
+            ],
+            struct_handles: vec![
+                struct_handle(0, 1, AbilitySet::EMPTY, true),
+                struct_handle(0, 5, key, true),
+                struct_handle(1, 8, key, true),
+                struct_handle(0, 9, store, false),
+            ],
+            function_handles: vec![
+                function_handle(3, 1, 0, vec![]),
+                function_handle(4, 0, 2, vec![]),
+                function_handle(10, 3, 4, vec![store]),
+            ],
+            field_handles: vec![],
+            friend_decls: vec![],
+            struct_def_instantiations: vec![],
+            function_instantiations: vec![],
+            field_instantiations: vec![],
+            signatures: vec![
+                Signature(vec![]),
+                Signature(vec![
+                    SignatureToken::Reference(Box::new(SignatureToken::Signer)),
+                    SignatureToken::U64,
+                ]),
+                Signature(vec![SignatureToken::Vector(Box::new(SignatureToken::U8))]),
+                Signature(vec![SignatureToken::TypeParameter(0)]),
+                Signature(vec![SignatureToken::StructInstantiation(
+                    StructHandleIndex(3),
+                    vec![SignatureToken::TypeParameter(0)],
+                )]),
+            ],
+            identifiers: vec![
+                Identifier::new("example").unwrap(),
+                Identifier::new("Counter").unwrap(),
+                Identifier::new("value").unwrap(),
+                Identifier::new("increase").unwrap(),
+                Identifier::new("name").unwrap(),
+                Identifier::new("Holder").unwrap(),
+                Identifier::new("obj").unwrap(),
+                Identifier::new("object").unwrap(),
+                Identifier::new("Object").unwrap(),
+                Identifier::new("Wrapper").unwrap(),
+                Identifier::new("wrap").unwrap(),
+            ],
+            address_identifiers: vec![
+                AccountAddress::from_hex_literal("0x42").unwrap(),
+                AccountAddress::TWO,
+            ],
+            constant_pool: vec![],
+            metadata: vec![],
+            struct_defs: vec![
+                struct_def(0, 2, SignatureToken::U64),
+                struct_def(
+                    1,
+                    6,
+                    SignatureToken::StructInstantiation(
+                        StructHandleIndex(2),
+                        vec![SignatureToken::TypeParameter(0)],
+                    ),
+                ),
+                struct_def(3, 2, SignatureToken::TypeParameter(0)),
+            ],
+            function_defs: vec![
+                function_def(0, Visibility::Private, true),
+                function_def(1, Visibility::Public, false),
+                function_def(2, Visibility::Public, false),
+            ],
+        }
+    }
+
+    fn normalize_whitespace(source: &str) -> String {
+        source.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    #[test]
+    fn test_generate_bindings() {
+        let files = generate_bindings(&[test_module()]).unwrap();
+        assert_eq!(files["mod.rs"].lines().last(), Some("pub mod example;"));
+        // The compiled `example_bindings` must be the generated code
+        assert_eq!(
+            normalize_whitespace(&files["example.rs"]),
+            normalize_whitespace(include_str!("gen_bindings/example_bindings.rs"))
+        );
+    }
+
+    #[test]
+    fn test_generated_bindings() {
+        // The phantom type parameters are not serialized
+        let counter = Counter::<u64> {
+            value: 1,
+            phantom: PhantomData,
+        };
+        let counter = Counter::<u64>::from_bytes(counter.to_bytes()).unwrap();
+        assert_eq!(counter.value, 1);
+        assert_eq!(Counter::<u64>::type_params(), vec![TypeTag::U64]);
+
+        let holder = Holder::<Counter<u64>> {
+            obj: Object {
+                id: ObjectID::random(),
+                ty: PhantomData,
+            },
+            phantom: PhantomData,
+        };
+        let decoded = Holder::<Counter<u64>>::from_bytes(holder.to_bytes()).unwrap();
+        assert_eq!(decoded.obj.id, holder.obj.id);
+        assert_eq!(
+            Holder::<Counter<u64>>::type_params(),
+            vec![Counter::<u64>::type_tag()]
+        );
+
+        let wrapper = Wrapper::<u64> { value: 7 };
+        assert_eq!(wrapper.to_move_value(), 7u64.to_move_value());
+
+        let call = ExampleModule::wrap_call::<u64>(7);
+        assert_eq!(call.ty_args, vec![TypeTag::U64]);
+        assert_eq!(call.args, vec![7u64.to_bytes()]);
+        // The signer parameter is skipped
+        let call = ExampleModule::increase_call(1);
+        assert_eq!(call.args, vec![1u64.to_bytes()]);
+    }
+
+    #[test]
+    fn test_rust_ident() {
+        assert_eq!(camel_case("coin_store"), "CoinStore");
+        assert_eq!(rust_ident("type"), "r#type");
+        assert_eq!(rust_ident("value"), "value");
+    }
+}
@@ -0,0 +1,153 @@
+// This file is generated by `rooch move gen-bindings`, do not edit it manually.
+#![allow(unused_imports, dead_code, clippy::all)]
+
+use anyhow::Result;
+use move_core_types::ident_str;
+use move_core_types::identifier::IdentStr;
+use moveos_types::module_binding::{ModuleBinding, MoveFunctionCaller};
+use moveos_types::state::{MoveState, MoveStructState, MoveStructType, MoveType};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_ADDRESS: move_core_types::account_address::AccountAddress = move_core_types::account_address::AccountAddress::new([0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x42]);
+pub const MODULE_NAME: &IdentStr = ident_str!("example");
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Counter<T0> {
+    pub value: u64,
+    pub phantom: std::marker::PhantomData<(T0,)>,
+}
+
+impl<T0: MoveType> MoveStructType for Counter<T0> {
+    const ADDRESS: move_core_types::account_address::AccountAddress = MODULE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Counter");
+
+    fn type_params() -> Vec<move_core_types::language_storage::TypeTag> {
+        vec![T0::type_tag()]
+    }
+}
+
+impl<T0: MoveType> MoveStructState for Counter<T0> {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            <u64 as MoveState>::type_layout(),
+        ])
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Holder<T0> {
+    pub obj: moveos_types::moveos_std::object::Object<T0>,
+    pub phantom: std::marker::PhantomData<(T0,)>,
+}
+
+impl<T0: MoveType + MoveStructType> MoveStructType for Holder<T0> {
+    const ADDRESS: move_core_types::account_address::AccountAddress = MODULE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Holder");
+
+    fn type_params() -> Vec<move_core_types::language_storage::TypeTag> {
+        vec![T0::type_tag()]
+    }
+}
+
+impl<T0: MoveType + MoveStructType> MoveStructState for Holder<T0> {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            <moveos_types::moveos_std::object::Object<T0> as MoveState>::type_layout(),
+        ])
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound = "T0: MoveState")]
+pub struct Wrapper<T0> {
+    pub value: T0,
+}
+
+impl<T0: MoveState> MoveStructType for Wrapper<T0> {
+    const ADDRESS: move_core_types::account_address::AccountAddress = MODULE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Wrapper");
+
+    fn type_params() -> Vec<move_core_types::language_storage::TypeTag> {
+        vec![T0::type_tag()]
+    }
+}
+
+impl<T0: MoveState> MoveStructState for Wrapper<T0> {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            <T0 as MoveState>::type_layout(),
+        ])
+    }
+}
+
+/// Rust bindings for the `0x42::example` module
+pub struct ExampleModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> ExampleModule<'a> {
+    pub const INCREASE_FUNCTION_NAME: &'static IdentStr = ident_str!("increase");
+
+    pub fn increase_call(arg0: u64) -> moveos_types::transaction::FunctionCall {
+        Self::create_function_call(
+            Self::INCREASE_FUNCTION_NAME,
+            vec![],
+            vec![arg0.to_move_value()],
+        )
+    }
+
+    pub fn increase_action(arg0: u64) -> moveos_types::transaction::MoveAction {
+        moveos_types::transaction::MoveAction::Function(Self::increase_call(arg0))
+    }
+
+    pub const NAME_FUNCTION_NAME: &'static IdentStr = ident_str!("name");
+
+    pub fn name_call() -> moveos_types::transaction::FunctionCall {
+        Self::create_function_call(
+            Self::NAME_FUNCTION_NAME,
+            vec![],
+            vec![],
+        )
+    }
+
+    pub fn name(&self) -> Result<Vec<u8>> {
+        let call = Self::name_call();
+        let ctx = moveos_types::moveos_std::tx_context::TxContext::new_readonly_ctx(move_core_types::account_address::AccountAddress::ZERO);
+        let mut values = self.caller.call_function(&ctx, call)?.into_result()?.into_iter();
+        Ok(<Vec<u8> as MoveState>::from_bytes(&values.next().ok_or_else(|| anyhow::anyhow!("Missing return value"))?.value)?)
+    }
+
+    pub const WRAP_FUNCTION_NAME: &'static IdentStr = ident_str!("wrap");
+
+    pub fn wrap_call<T0: MoveState>(arg0: T0) -> moveos_types::transaction::FunctionCall {
+        Self::create_function_call(
+            Self::WRAP_FUNCTION_NAME,
+            vec![T0::type_tag()],
+            vec![arg0.to_move_value()],
+        )
+    }
+
+    pub fn wrap<T0: MoveState>(&self, arg0: T0) -> Result<Wrapper<T0>> {
+        let call = Self::wrap_call::<T0>(arg0);
+        let ctx = moveos_types::moveos_std::tx_context::TxContext::new_readonly_ctx(move_core_types::account_address::AccountAddress::ZERO);
+        let mut values = self.caller.call_function(&ctx, call)?.into_result()?.into_iter();
+        Ok(<Wrapper<T0> as MoveState>::from_bytes(&values.next().ok_or_else(|| anyhow::anyhow!("Missing return value"))?.value)?)
+    }
+}
+
+impl<'a> ModuleBinding<'a> for ExampleModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: move_core_types::account_address::AccountAddress = MODULE_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
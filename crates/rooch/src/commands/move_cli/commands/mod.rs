@@ -2,11 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod build;
+pub mod check_upgrade;
 pub mod coverage;
 pub mod disassemble;
 pub mod docgen;
 pub mod errmap;
 pub mod explain;
+pub mod gen_bindings;
 pub mod info;
 pub mod integration_test;
 pub mod new;
@@ -15,3 +17,4 @@ pub mod publish;
 pub mod run_function;
 pub mod run_view_function;
 pub mod unit_test;
+pub mod verify_source;
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::cli_types::{CommandAction, TransactionOptions, WalletContextOptions};
+use crate::commands::upgrade::commands::upgrade_gas_config::ensure_onchain_gas_schedule_upgraded;
 use async_trait::async_trait;
 use clap::Parser;
 use move_cli::Move;
@@ -16,7 +17,9 @@ use moveos_types::moveos_std::module_store::{ModuleStore, PackageData};
 use moveos_types::moveos_std::move_module::MoveModule;
 use moveos_types::moveos_std::object::ObjectMeta;
 use moveos_types::{
-    addresses::MOVEOS_STD_ADDRESS, move_types::FunctionId, state::ObjectState,
+    addresses::{is_system_reserved_address, MOVEOS_STD_ADDRESS},
+    move_types::FunctionId,
+    state::ObjectState,
     transaction::MoveAction,
 };
 use moveos_verifier::build::run_verifier;
@@ -33,20 +36,20 @@ use std::collections::BTreeMap;
 use std::io::stderr;
 use tokio::runtime::Handle;
 
-struct MemoryModuleResolver {
+pub(crate) struct MemoryModuleResolver {
     packages: BTreeMap<AccountAddress, BTreeMap<String, Vec<u8>>>,
     client: Client,
 }
 
 impl MemoryModuleResolver {
-    fn new(client: Client) -> Self {
+    pub(crate) fn new(client: Client) -> Self {
         Self {
             packages: BTreeMap::new(),
             client,
         }
     }
 
-    fn download(&mut self, module_ids: Vec<ModuleId>) -> Result<(), anyhow::Error> {
+    pub(crate) fn download(&mut self, module_ids: Vec<ModuleId>) -> Result<(), anyhow::Error> {
         // group module_ids by ModuleId.address
         let mut package_group = BTreeMap::new();
         module_ids.into_iter().for_each(|mid| {
@@ -199,6 +202,11 @@ impl CommandAction<ExecuteTransactionResponseView> for Publish {
         let mut resolver = MemoryModuleResolver::new(context.get_client().await?);
         resolver.download(all_module_ids)?;
         moveos_verifier::verifier::verify_modules(&sorted_modules, &resolver)?;
+        // The framework may call the natives added by the latest gas schedule,
+        // so the onchain gas config must be upgraded before the framework.
+        if is_system_reserved_address(pkg_address) {
+            ensure_onchain_gas_schedule_upgraded(&context.get_client().await?).await?;
+        }
         for module in sorted_modules {
             let module_address = module.self_id().address().to_owned();
             if module_address != pkg_address {
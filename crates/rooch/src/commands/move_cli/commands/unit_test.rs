@@ -21,6 +21,7 @@ use moveos_types::moveos_std::object::ObjectMeta;
 use moveos_types::{moveos_std::tx_context::TxContext, state_resolver::RootObjectResolver};
 use moveos_verifier::build::build_model_with_test_attr;
 use moveos_verifier::metadata::run_extended_checks;
+use moveos_wasm::wasm::NativeWASMContext;
 use once_cell::sync::Lazy;
 use parking_lot::RwLock;
 use rooch_genesis::FrameworksGasParameters;
@@ -139,8 +140,10 @@ static RESOLVER: Lazy<Box<RootObjectResolver<MoveOSStore>>> = Lazy::new(|| {
 #[allow(clippy::arc_with_non_send_sync)]
 fn new_moveos_natives_runtime(ext: &mut NativeContextExtensions) {
     let resolver = Lazy::force(&RESOLVER).as_ref();
+    let tx_context = TxContext::random_for_testing_only();
+    let max_gas_amount = tx_context.max_gas_amount;
     let object_runtime = Rc::new(RwLock::new(ObjectRuntime::genesis(
-        TxContext::random_for_testing_only(),
+        tx_context,
         ObjectMeta::genesis_root(),
         resolver,
         genesis_config::G_LOCAL_CONFIG.genesis_objects.clone(),
@@ -148,7 +151,9 @@ fn new_moveos_natives_runtime(ext: &mut NativeContextExtensions) {
     let table_ext = ObjectRuntimeContext::new(object_runtime);
     let module_ext = NativeModuleContext::new(resolver);
     let event_ext = NativeEventContext::default();
+    let wasm_ext = NativeWASMContext::new(max_gas_amount);
     ext.add(table_ext);
     ext.add(module_ext);
     ext.add(event_ext);
+    ext.add(wasm_ext);
 }
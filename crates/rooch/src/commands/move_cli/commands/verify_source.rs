@@ -0,0 +1,153 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use crate::commands::move_cli::get_onchain_package_modules;
+use async_trait::async_trait;
+use clap::Parser;
+use move_cli::Move;
+use move_core_types::account_address::AccountAddress;
+use moveos_verifier::build::run_verifier;
+use rooch_types::address::ParsedAddress;
+use rooch_types::error::{RoochError, RoochResult};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::stderr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModuleSourceStatus {
+    /// The bytecode built from source is the same as the on-chain module
+    Matched,
+    /// The bytecode built from source is different from the on-chain module
+    Mismatched,
+    /// The module is in the source but not deployed on chain
+    NotOnChain,
+    /// The module is deployed on chain but not in the source
+    NotInSource,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModuleSourceCheck {
+    pub module_name: String,
+    pub status: ModuleSourceStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifySourceOutput {
+    pub package_address: String,
+    pub modules: Vec<ModuleSourceCheck>,
+}
+
+/// Rebuild the package from source and compare the bytecode with the package deployed on chain.
+/// The package must be built with the same compiler and named addresses as the deployed one.
+#[derive(Parser)]
+#[clap(name = "verify-source")]
+pub struct VerifySourceCommand {
+    #[clap(flatten)]
+    context_options: WalletContextOptions,
+
+    #[clap(flatten)]
+    move_args: Move,
+
+    /// Named addresses for the move binary
+    ///
+    /// Example: alice=0x1234, bob=default, alice2=alice
+    ///
+    /// Note: This will fail if there are duplicates in the Move.toml file remove those first.
+    #[clap(long, value_parser=crate::utils::parse_map::<String, String>, default_value = "")]
+    pub(crate) named_addresses: BTreeMap<String, String>,
+
+    /// The address of the deployed package, default is the address of the modules in the package
+    #[clap(long, value_parser=ParsedAddress::parse)]
+    package_address: Option<ParsedAddress>,
+}
+
+#[async_trait]
+impl CommandAction<VerifySourceOutput> for VerifySourceCommand {
+    async fn execute(self) -> RoochResult<VerifySourceOutput> {
+        let context = self.context_options.build()?;
+
+        let package_path = self
+            .move_args
+            .package_path
+            .unwrap_or_else(|| std::env::current_dir().unwrap());
+        let mut config = self.move_args.build_config;
+        config.additional_named_addresses =
+            context.parse_and_resolve_addresses(self.named_addresses)?;
+        let config_cloned = config.clone();
+
+        // The verifier writes the metadata into the modules, the same as `rooch move publish`
+        let mut package = config.compile_package_no_exit(&package_path, &mut stderr())?;
+        run_verifier(package_path, config_cloned, &mut package)?;
+
+        let package_address: Option<AccountAddress> = self
+            .package_address
+            .map(|address| context.resolve_address(address))
+            .transpose()?
+            .map(Into::into);
+        let modules = package.root_modules_map().iter_modules_owned();
+        let pkg_address = match (package_address, modules.first()) {
+            (Some(package_address), _) => package_address,
+            (None, Some(module)) => *module.self_id().address(),
+            (None, None) => {
+                return Err(RoochError::MoveCompilationError(
+                    "compiling move modules error! Is the project or module empty".to_owned(),
+                ))
+            }
+        };
+
+        let client = context.get_client().await?;
+        let mut onchain_modules = get_onchain_package_modules(&client, pkg_address).await?;
+        if onchain_modules.is_empty() {
+            return Err(RoochError::MoveSourceVerificationError(format!(
+                "The package {} is not deployed on chain",
+                pkg_address.to_hex_literal()
+            )));
+        }
+
+        let mut results = vec![];
+        for module in modules.iter() {
+            let module_name = module.self_id().name().to_string();
+            let mut bytecodes = vec![];
+            module.serialize(&mut bytecodes)?;
+            let status = match onchain_modules.remove(&module_name) {
+                None => ModuleSourceStatus::NotOnChain,
+                Some(onchain_bytecodes) if onchain_bytecodes == bytecodes => {
+                    ModuleSourceStatus::Matched
+                }
+                Some(_) => ModuleSourceStatus::Mismatched,
+            };
+            results.push(ModuleSourceCheck {
+                module_name,
+                status,
+            });
+        }
+        results.extend(
+            onchain_modules
+                .into_keys()
+                .map(|module_name| ModuleSourceCheck {
+                    module_name,
+                    status: ModuleSourceStatus::NotInSource,
+                }),
+        );
+
+        let unverified_modules = results
+            .iter()
+            .filter(|result| result.status != ModuleSourceStatus::Matched)
+            .map(|result| format!("{}: {:?}", result.module_name, result.status))
+            .collect::<Vec<_>>();
+        if !unverified_modules.is_empty() {
+            return Err(RoochError::MoveSourceVerificationError(format!(
+                "The source does not match the deployed package {}:\n{}",
+                pkg_address.to_hex_literal(),
+                unverified_modules.join("\n")
+            )));
+        }
+
+        Ok(VerifySourceOutput {
+            package_address: pkg_address.to_hex_literal(),
+            modules: results,
+        })
+    }
+}
@@ -4,14 +4,21 @@
 use async_trait::async_trait;
 use clap::{Parser, Subcommand};
 use commands::{
-    build::BuildCommand, coverage::CoverageCommand, disassemble::DisassembleCommand,
-    docgen::DocgenCommand, errmap::ErrmapCommand, info::InfoCommand,
-    integration_test::IntegrationTestCommand, new::NewCommand, prove::ProveCommand,
-    publish::Publish, run_function::RunFunction, run_view_function::RunViewFunction,
-    unit_test::TestCommand,
+    build::BuildCommand, check_upgrade::CheckUpgradeCommand, coverage::CoverageCommand,
+    disassemble::DisassembleCommand, docgen::DocgenCommand, errmap::ErrmapCommand,
+    gen_bindings::GenBindingsCommand, info::InfoCommand, integration_test::IntegrationTestCommand,
+    new::NewCommand, prove::ProveCommand, publish::Publish, run_function::RunFunction,
+    run_view_function::RunViewFunction, unit_test::TestCommand, verify_source::VerifySourceCommand,
 };
+use move_core_types::account_address::AccountAddress;
+use moveos_types::move_std::string::MoveString;
+use moveos_types::moveos_std::module_store::Package;
+use moveos_types::moveos_std::move_module::MoveModule;
+use moveos_types::state::ObjectState;
+use rooch_rpc_client::Client;
 use rooch_types::error::RoochResult;
 use serde_json::{json, Value};
+use std::collections::BTreeMap;
 
 use crate::commands::move_cli::commands::explain::ExplainCommand;
 use crate::CommandAction;
@@ -41,6 +48,9 @@ pub enum MoveCommand {
     View(RunViewFunction),
     IntegrationTest(IntegrationTestCommand),
     Explain(ExplainCommand),
+    GenBindings(GenBindingsCommand),
+    CheckUpgrade(CheckUpgradeCommand),
+    VerifySource(VerifySourceCommand),
 }
 
 #[async_trait]
@@ -61,6 +71,9 @@ impl CommandAction<String> for MoveCli {
             MoveCommand::View(c) => c.execute_serialized().await,
             MoveCommand::IntegrationTest(c) => c.execute_serialized().await,
             MoveCommand::Explain(c) => c.execute_serialized().await,
+            MoveCommand::GenBindings(c) => c.execute_serialized().await,
+            MoveCommand::CheckUpgrade(c) => c.execute_serialized().await,
+            MoveCommand::VerifySource(c) => c.execute_serialized().await,
         }
     }
 }
@@ -83,3 +96,30 @@ pub fn print_serialized_success(json: bool) -> RoochResult<Option<Value>> {
         Ok(None)
     }
 }
+
+/// Download the bytecodes of all the modules of the package deployed on chain,
+/// returns an empty map if the package is not published.
+pub(crate) async fn get_onchain_package_modules(
+    client: &Client,
+    package_address: AccountAddress,
+) -> RoochResult<BTreeMap<String, Vec<u8>>> {
+    let package_id = Package::package_id(&package_address);
+    let mut modules = BTreeMap::new();
+    let mut cursor = None;
+    loop {
+        let page = client
+            .rooch
+            .list_field_states(package_id.clone().into(), cursor, None, None)
+            .await?;
+        for state_kv in page.data {
+            let state = ObjectState::from(state_kv.state);
+            let module = state.value_as_df::<MoveString, MoveModule>()?;
+            modules.insert(module.name.as_str().to_owned(), module.value.byte_codes);
+        }
+        if !page.has_next_page {
+            break;
+        }
+        cursor = page.next_cursor;
+    }
+    Ok(modules)
+}
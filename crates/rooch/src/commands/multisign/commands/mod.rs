@@ -0,0 +1,260 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::is_file_path;
+use anyhow::Result;
+use moveos_types::h256::H256;
+use rooch_types::{
+    address::RoochAddress,
+    bitcoin::multisign_account::MultisignAccountModule,
+    transaction::{authenticator::BitcoinAuthenticator, rooch::PartiallySignedRoochTransaction},
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+pub mod propose;
+pub mod sign;
+pub mod status;
+pub mod submit;
+
+const PROPOSAL_DIR: &str = "multisign";
+const PROPOSAL_FILE_SUFFIX: &str = "psrt";
+const PROPOSAL_FILE_NAME: &str = "proposal";
+
+/// The proposal store saves the partially signed transactions in the directory,
+/// each proposal is a sub directory named by the transaction hash, and each signer writes its own file,
+/// so the directory can be shared by the participants(such as a network drive or a git repository).
+/// ```text
+/// <dir>/<tx_hash>/proposal.psrt
+/// <dir>/<tx_hash>/<signer_address>.psrt
+/// ```
+pub(crate) struct ProposalStore {
+    dir: PathBuf,
+}
+
+impl ProposalStore {
+    /// Use the `dir` if specified, otherwise use the `multisign` directory in the rooch config directory.
+    pub fn new(dir: Option<PathBuf>, config_dir: Option<PathBuf>) -> Result<Self> {
+        let dir = match dir {
+            Some(dir) => dir,
+            None => match config_dir {
+                Some(config_dir) => config_dir,
+                None => rooch_config::rooch_config_dir()?,
+            }
+            .join(PROPOSAL_DIR),
+        };
+        Ok(Self { dir })
+    }
+
+    fn proposal_dir(&self, tx_hash: H256) -> PathBuf {
+        self.dir.join(hex::encode(tx_hash))
+    }
+
+    /// Save the proposal created by the proposer.
+    pub fn save_proposal(&self, psrt: &PartiallySignedRoochTransaction) -> Result<PathBuf> {
+        self.write(psrt, PROPOSAL_FILE_NAME)
+    }
+
+    /// Save the signatures collected by the signer.
+    pub fn save_signatures(
+        &self,
+        psrt: &PartiallySignedRoochTransaction,
+        signer: RoochAddress,
+    ) -> Result<PathBuf> {
+        self.write(psrt, &signer.to_bech32())
+    }
+
+    fn write(&self, psrt: &PartiallySignedRoochTransaction, name: &str) -> Result<PathBuf> {
+        let proposal_dir = self.proposal_dir(psrt.data.tx_hash());
+        fs::create_dir_all(&proposal_dir)?;
+        let path = proposal_dir.join(format!("{}.{}", name, PROPOSAL_FILE_SUFFIX));
+        // Write the hex encoded data as `rooch tx sign` does, so the files can be used by each other
+        fs::write(&path, hex::encode(psrt.encode()))?;
+        Ok(path)
+    }
+
+    /// Load the proposal and merge the signatures of all the signers.
+    pub fn load(&self, tx_hash: H256) -> Result<PartiallySignedRoochTransaction> {
+        let proposal_dir = self.proposal_dir(tx_hash);
+        if !proposal_dir.exists() {
+            return Err(anyhow::anyhow!(
+                "The proposal {:?} does not exist in {:?}",
+                tx_hash,
+                self.dir
+            ));
+        }
+        let mut proposal: Option<PartiallySignedRoochTransaction> = None;
+        for entry in fs::read_dir(&proposal_dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some(PROPOSAL_FILE_SUFFIX) {
+                continue;
+            }
+            let psrt = read_psrt(&path)?;
+            match proposal.as_mut() {
+                Some(proposal) => proposal.merge(psrt)?,
+                None => proposal = Some(psrt),
+            }
+        }
+        proposal.ok_or_else(|| anyhow::anyhow!("No proposal file found in {:?}", proposal_dir))
+    }
+}
+
+fn read_psrt(path: &Path) -> Result<PartiallySignedRoochTransaction> {
+    let hex_str = fs::read_to_string(path)?;
+    let data = hex::decode(hex_str.trim().strip_prefix("0x").unwrap_or(hex_str.trim()))?;
+    PartiallySignedRoochTransaction::decode(&data).map_err(|e| {
+        anyhow::anyhow!(
+            "Invalid partially signed transaction file: {:?}, err: {:?}",
+            path,
+            e
+        )
+    })
+}
+
+/// Verify the proposal against the participants and the threshold of the multisign account on chain,
+/// returns the authenticators which are not valid signatures of the participants, they are removed from the proposal.
+pub(crate) fn verify_proposal(
+    psrt: &mut PartiallySignedRoochTransaction,
+    multisign_account_module: &MultisignAccountModule,
+) -> Result<Vec<BitcoinAuthenticator>> {
+    let sender = psrt.sender();
+    if !multisign_account_module.is_multisign_account(sender.into())? {
+        return Err(anyhow::anyhow!(
+            "The sender {} is not a multisign account",
+            sender
+        ));
+    }
+    let threshold = multisign_account_module.threshold(sender.into())?;
+    let participant_public_keys = multisign_account_module
+        .participants(sender.into())?
+        .into_iter()
+        .map(|participant| participant.public_key)
+        .collect::<Vec<_>>();
+    Ok(psrt.verify_participants(&participant_public_keys, threshold))
+}
+
+/// The proposal to operate, the transaction hash of the proposal in the store,
+/// or a partially signed transaction file(such as the output of `rooch tx sign`) which is imported to the store.
+#[derive(Debug, Clone)]
+pub(crate) enum ProposalInput {
+    TxHash(H256),
+    File(PathBuf),
+}
+
+impl FromStr for ProposalInput {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if is_file_path(s) {
+            Ok(ProposalInput::File(PathBuf::from(s)))
+        } else {
+            let tx_hash = H256::from_str(s)
+                .map_err(|e| anyhow::anyhow!("Invalid transaction hash: {}, err: {:?}", s, e))?;
+            Ok(ProposalInput::TxHash(tx_hash))
+        }
+    }
+}
+
+impl ProposalInput {
+    /// Load the proposal from the store, the proposal file is merged into the store first.
+    pub fn load(&self, store: &ProposalStore) -> Result<PartiallySignedRoochTransaction> {
+        match self {
+            ProposalInput::TxHash(tx_hash) => store.load(*tx_hash),
+            ProposalInput::File(path) => {
+                let psrt = read_psrt(path)?;
+                let tx_hash = psrt.data.tx_hash();
+                let mut proposal = match store.load(tx_hash) {
+                    Ok(proposal) => proposal,
+                    Err(_) => {
+                        store.save_proposal(&psrt)?;
+                        return Ok(psrt);
+                    }
+                };
+                proposal.merge(psrt)?;
+                Ok(proposal)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rooch_types::{
+        addresses::ROOCH_FRAMEWORK_ADDRESS,
+        crypto::RoochKeyPair,
+        framework::empty::Empty,
+        transaction::{authenticator::BitcoinAuthenticator, RoochTransactionData},
+    };
+
+    #[test]
+    fn test_proposal_store() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let store = ProposalStore::new(Some(tmp_dir.path().to_path_buf()), None).unwrap();
+        let tx_data = RoochTransactionData::new_for_test(
+            ROOCH_FRAMEWORK_ADDRESS.into(),
+            0,
+            Empty::empty_function_call().into(),
+        );
+        let tx_hash = tx_data.tx_hash();
+        let psrt = PartiallySignedRoochTransaction::new(tx_data, 2);
+        store.save_proposal(&psrt).unwrap();
+
+        // Two signers sign the proposal and save the signatures separately
+        for _ in 0..2 {
+            let kp = RoochKeyPair::generate_secp256k1();
+            let signer = kp.public().rooch_address().unwrap();
+            let mut signed = store.load(tx_hash).unwrap();
+            signed
+                .add_authenticator(BitcoinAuthenticator::sign(&kp, &signed.data))
+                .unwrap();
+            store.save_signatures(&signed, signer).unwrap();
+        }
+
+        let proposal = store.load(tx_hash).unwrap();
+        assert_eq!(proposal.signatories(), 2);
+        assert!(proposal.is_fully_signed());
+        assert!(store.load(H256::random()).is_err());
+    }
+
+    #[test]
+    fn test_verify_signatures() {
+        let tx_data = RoochTransactionData::new_for_test(
+            ROOCH_FRAMEWORK_ADDRESS.into(),
+            0,
+            Empty::empty_function_call().into(),
+        );
+        let other_tx_data = RoochTransactionData::new_for_test(
+            ROOCH_FRAMEWORK_ADDRESS.into(),
+            1,
+            Empty::empty_function_call().into(),
+        );
+        let participant = RoochKeyPair::generate_secp256k1();
+        let outsider = RoochKeyPair::generate_secp256k1();
+
+        // The signature of another transaction is rejected
+        let forged = BitcoinAuthenticator::sign(&participant, &other_tx_data);
+        let mut psrt = PartiallySignedRoochTransaction::new(tx_data.clone(), 1);
+        assert!(psrt.add_authenticator(forged.clone()).is_err());
+        let mut other = PartiallySignedRoochTransaction::new(tx_data.clone(), 1);
+        other.authenticators.push(forged);
+        assert!(psrt.merge(other).is_err());
+        assert_eq!(psrt.signatories(), 0);
+
+        // The signature not from the participants is removed, and the threshold on chain is used
+        let participant_auth = BitcoinAuthenticator::sign(&participant, &tx_data);
+        let participant_public_key = participant_auth.payload.public_key.clone();
+        psrt.add_authenticator(participant_auth).unwrap();
+        psrt.add_authenticator(BitcoinAuthenticator::sign(&outsider, &tx_data))
+            .unwrap();
+        assert!(psrt.is_fully_signed());
+        let invalid = psrt.verify_participants(&[participant_public_key], 2);
+        assert_eq!(invalid.len(), 1);
+        assert_eq!(psrt.signatories(), 1);
+        assert_eq!(psrt.threshold(), 2);
+        assert!(!psrt.is_fully_signed());
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::ProposalStore;
+use crate::cli_types::{CommandAction, FileOrHexInput, WalletContextOptions};
+use async_trait::async_trait;
+use moveos_types::{h256::H256, module_binding::MoveFunctionCaller};
+use rooch_types::{
+    address::RoochAddress,
+    bitcoin::multisign_account::MultisignAccountModule,
+    error::{RoochError, RoochResult},
+    transaction::{rooch::PartiallySignedRoochTransaction, RoochTransactionData},
+};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Create a proposal of the multisign account transaction, and save it to the proposal directory.
+#[derive(Debug, clap::Parser)]
+pub struct ProposeCommand {
+    /// The transaction data hex or the file path of the transaction data, such as the output of `rooch tx build`.
+    /// The sender of the transaction must be a multisign account.
+    input: FileOrHexInput,
+
+    /// The directory to store the proposals, it can be a directory shared by the participants.
+    /// If not specified, the `multisign` directory in the rooch config directory is used.
+    #[clap(long)]
+    dir: Option<PathBuf>,
+
+    #[clap(flatten)]
+    context: WalletContextOptions,
+
+    /// Return command outputs in json format
+    #[clap(long, default_value = "false")]
+    json: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProposalOutput {
+    pub tx_hash: H256,
+    pub multisign_address: RoochAddress,
+    pub threshold: u64,
+    pub signatures: usize,
+    pub path: String,
+}
+
+#[async_trait]
+impl CommandAction<Option<ProposalOutput>> for ProposeCommand {
+    async fn execute(self) -> RoochResult<Option<ProposalOutput>> {
+        let context = self.context.build()?;
+        let client = context.get_client().await?;
+        let multisign_account_module = client.as_module_binding::<MultisignAccountModule>();
+
+        let tx_data = RoochTransactionData::decode(&self.input.data).map_err(|e| {
+            RoochError::CommandArgumentError(format!("Invalid transaction data, err: {:?}", e))
+        })?;
+        let sender = tx_data.sender;
+        if !multisign_account_module.is_multisign_account(sender.into())? {
+            return Err(RoochError::CommandArgumentError(format!(
+                "The sender {} is not a multisign account",
+                sender
+            )));
+        }
+        let threshold = multisign_account_module.threshold(sender.into())?;
+
+        let store = ProposalStore::new(self.dir, self.context.config_dir.clone())?;
+        let psrt = PartiallySignedRoochTransaction::new(tx_data, threshold);
+        let path = store.save_proposal(&psrt)?;
+
+        let output = ProposalOutput {
+            tx_hash: psrt.data.tx_hash(),
+            multisign_address: sender,
+            threshold,
+            signatures: psrt.signatories(),
+            path: path.to_string_lossy().to_string(),
+        };
+        if self.json {
+            Ok(Some(output))
+        } else {
+            println!("Proposal is written to {:?}", output.path);
+            println!(
+                "The participants can sign the proposal with `rooch multisign sign {:?}`",
+                output.tx_hash
+            );
+            Ok(None)
+        }
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{verify_proposal, ProposalInput, ProposalStore};
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use moveos_types::{h256::H256, module_binding::MoveFunctionCaller};
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_types::{
+    address::{ParsedAddress, RoochAddress},
+    bitcoin::multisign_account::MultisignAccountModule,
+    error::{RoochError, RoochResult},
+    transaction::authenticator::BitcoinAuthenticator,
+};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Sign the multisign proposal with the participant keys in the keystore.
+#[derive(Debug, clap::Parser)]
+pub struct SignCommand {
+    /// The transaction hash of the proposal in the proposal directory,
+    /// or the file path of the partially signed transaction, the file is imported to the proposal directory.
+    proposal: ProposalInput,
+
+    /// The address of the participant to sign the proposal.
+    /// If not specified, all the participants in the keystore sign the proposal.
+    #[clap(short = 's', long, value_parser=ParsedAddress::parse)]
+    signer: Option<ParsedAddress>,
+
+    /// The directory to store the proposals, it can be a directory shared by the participants.
+    /// If not specified, the `multisign` directory in the rooch config directory is used.
+    #[clap(long)]
+    dir: Option<PathBuf>,
+
+    #[clap(flatten)]
+    context: WalletContextOptions,
+
+    /// Return command outputs in json format
+    #[clap(long, default_value = "false")]
+    json: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignOutput {
+    pub tx_hash: H256,
+    pub signers: Vec<RoochAddress>,
+    pub signatures: usize,
+    pub threshold: u64,
+    pub paths: Vec<String>,
+}
+
+#[async_trait]
+impl CommandAction<Option<SignOutput>> for SignCommand {
+    async fn execute(self) -> RoochResult<Option<SignOutput>> {
+        let context = self.context.build_require_password()?;
+        let client = context.get_client().await?;
+        let multisign_account_module = client.as_module_binding::<MultisignAccountModule>();
+
+        let store = ProposalStore::new(self.dir, self.context.config_dir.clone())?;
+        let mut psrt = self.proposal.load(&store)?;
+        let sender = psrt.sender();
+        verify_proposal(&mut psrt, &multisign_account_module)?;
+
+        let participants = multisign_account_module.participants(sender.into())?;
+        let signers: Vec<RoochAddress> = match self.signer {
+            Some(signer) => {
+                let signer = context.resolve_address(signer)?;
+                if !multisign_account_module.is_participant(sender.into(), signer)? {
+                    return Err(RoochError::CommandArgumentError(format!(
+                        "The signer address {} is not a participant in the multisign account",
+                        signer
+                    )));
+                }
+                vec![signer.into()]
+            }
+            None => participants
+                .iter()
+                .map(|participant| participant.participant_address.into())
+                .filter(|address| context.keystore.contains_address(address))
+                .collect(),
+        };
+        if signers.is_empty() {
+            return Err(RoochError::CommandArgumentError(format!(
+                "No participant found in the multisign account from the keystore, participants: {:?}",
+                participants
+            )));
+        }
+
+        let mut new_signers = vec![];
+        let mut paths = vec![];
+        for signer in signers {
+            let kp = context.get_key_pair(&signer)?;
+            let authenticator = BitcoinAuthenticator::sign(&kp, &psrt.data);
+            if psrt.contains_authenticator(&authenticator) {
+                continue;
+            }
+            psrt.add_authenticator(authenticator)?;
+            let path = store.save_signatures(&psrt, signer)?;
+            new_signers.push(signer);
+            paths.push(path.to_string_lossy().to_string());
+        }
+
+        let output = SignOutput {
+            tx_hash: psrt.data.tx_hash(),
+            signers: new_signers,
+            signatures: psrt.signatories(),
+            threshold: psrt.threshold(),
+            paths,
+        };
+        if self.json {
+            Ok(Some(output))
+        } else {
+            if output.signers.is_empty() {
+                println!(
+                    "The proposal has already been signed by the participants in the keystore"
+                );
+            }
+            for (signer, path) in output.signers.iter().zip(output.paths.iter()) {
+                println!("Signatures of {} is written to {:?}", signer, path);
+            }
+            println!(
+                "Collected signatures: {}/{}",
+                output.signatures, output.threshold
+            );
+            if psrt.is_fully_signed() {
+                println!(
+                    "You can submit the proposal with `rooch multisign submit {:?}`",
+                    output.tx_hash
+                );
+            }
+            Ok(None)
+        }
+    }
+}
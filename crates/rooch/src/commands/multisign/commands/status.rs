@@ -0,0 +1,134 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{verify_proposal, ProposalInput, ProposalStore};
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use moveos_types::{h256::H256, module_binding::MoveFunctionCaller};
+use rooch_types::{
+    address::RoochAddress, bitcoin::multisign_account::MultisignAccountModule, error::RoochResult,
+};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Show the signing status of the multisign proposal.
+#[derive(Debug, clap::Parser)]
+pub struct StatusCommand {
+    /// The transaction hash of the proposal in the proposal directory,
+    /// or the file path of the partially signed transaction, the file is imported to the proposal directory.
+    proposal: ProposalInput,
+
+    /// The directory to store the proposals, it can be a directory shared by the participants.
+    /// If not specified, the `multisign` directory in the rooch config directory is used.
+    #[clap(long)]
+    dir: Option<PathBuf>,
+
+    #[clap(flatten)]
+    context: WalletContextOptions,
+
+    /// Return command outputs in json format
+    #[clap(long, default_value = "false")]
+    json: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParticipantStatus {
+    pub participant_address: RoochAddress,
+    pub participant_bitcoin_address: String,
+    pub signed: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProposalStatusOutput {
+    pub tx_hash: H256,
+    pub multisign_address: RoochAddress,
+    pub sequence_number: u64,
+    pub action: String,
+    pub threshold: u64,
+    pub signatures: usize,
+    /// The number of the signatures which are invalid or not from the participants, they are ignored
+    pub invalid_signatures: usize,
+    pub participants: Vec<ParticipantStatus>,
+    /// Whether the signatures reach the threshold and the proposal can be submitted
+    pub ready: bool,
+}
+
+#[async_trait]
+impl CommandAction<Option<ProposalStatusOutput>> for StatusCommand {
+    async fn execute(self) -> RoochResult<Option<ProposalStatusOutput>> {
+        let context = self.context.build()?;
+        let client = context.get_client().await?;
+        let bitcoin_network = context.get_bitcoin_network().await?;
+        let multisign_account_module = client.as_module_binding::<MultisignAccountModule>();
+
+        let store = ProposalStore::new(self.dir, self.context.config_dir.clone())?;
+        let mut psrt = self.proposal.load(&store)?;
+        let sender = psrt.sender();
+        // Only count the valid signatures of the participants, and use the threshold on chain
+        let invalid_authenticators = verify_proposal(&mut psrt, &multisign_account_module)?;
+
+        let participants = multisign_account_module
+            .participants(sender.into())?
+            .into_iter()
+            .map(|participant| {
+                let signed = psrt.authenticators.iter().any(|authenticator| {
+                    authenticator.payload.public_key == participant.public_key
+                });
+                ParticipantStatus {
+                    participant_address: participant.participant_address.into(),
+                    participant_bitcoin_address: participant
+                        .participant_bitcoin_address
+                        .format(bitcoin_network)
+                        .expect("format participant address should success"),
+                    signed,
+                }
+            })
+            .collect();
+
+        let output = ProposalStatusOutput {
+            tx_hash: psrt.data.tx_hash(),
+            multisign_address: sender,
+            sequence_number: psrt.data.sequence_number,
+            action: psrt.data.action.to_string(),
+            threshold: psrt.threshold(),
+            signatures: psrt.signatories(),
+            invalid_signatures: invalid_authenticators.len(),
+            participants,
+            ready: psrt.is_fully_signed(),
+        };
+        if self.json {
+            Ok(Some(output))
+        } else {
+            println!("Transaction hash: {:?}", output.tx_hash);
+            println!("Multisign address: {}", output.multisign_address);
+            println!("Sequence number: {}", output.sequence_number);
+            println!("Action: {}", output.action);
+            println!(
+                "Collected signatures: {}/{}",
+                output.signatures, output.threshold
+            );
+            if output.invalid_signatures > 0 {
+                println!(
+                    "Ignored {} invalid signatures or signatures not from the participants",
+                    output.invalid_signatures
+                );
+            }
+            println!("Participants:");
+            for participant in output.participants.iter() {
+                println!(
+                    " [{}] {} {}",
+                    if participant.signed { "x" } else { " " },
+                    participant.participant_address,
+                    participant.participant_bitcoin_address
+                );
+            }
+            if output.ready {
+                println!(
+                    "The proposal is ready, submit it with `rooch multisign submit {:?}`",
+                    output.tx_hash
+                );
+            }
+            Ok(None)
+        }
+    }
+}
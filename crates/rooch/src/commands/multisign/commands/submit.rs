@@ -0,0 +1,52 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{verify_proposal, ProposalInput, ProposalStore};
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use moveos_types::module_binding::MoveFunctionCaller;
+use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_types::bitcoin::multisign_account::MultisignAccountModule;
+use rooch_types::error::{RoochError, RoochResult};
+use std::path::PathBuf;
+
+/// Merge the signatures of the multisign proposal and submit the transaction once the threshold is met.
+#[derive(Debug, clap::Parser)]
+pub struct SubmitCommand {
+    /// The transaction hash of the proposal in the proposal directory,
+    /// or the file path of the partially signed transaction, the file is imported to the proposal directory.
+    proposal: ProposalInput,
+
+    /// The directory to store the proposals, it can be a directory shared by the participants.
+    /// If not specified, the `multisign` directory in the rooch config directory is used.
+    #[clap(long)]
+    dir: Option<PathBuf>,
+
+    #[clap(flatten)]
+    context: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<ExecuteTransactionResponseView> for SubmitCommand {
+    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+        let context = self.context.build()?;
+        let client = context.get_client().await?;
+        let multisign_account_module = client.as_module_binding::<MultisignAccountModule>();
+
+        let store = ProposalStore::new(self.dir, self.context.config_dir.clone())?;
+        let mut psrt = self.proposal.load(&store)?;
+        // Only submit the valid signatures of the participants, and use the threshold on chain
+        verify_proposal(&mut psrt, &multisign_account_module)?;
+        if !psrt.is_fully_signed() {
+            return Err(RoochError::CommandArgumentError(format!(
+                "The proposal is not ready to submit, collected signatures: {}/{}",
+                psrt.signatories(),
+                psrt.threshold()
+            )));
+        }
+
+        let signed_tx = psrt.try_into_rooch_transaction()?;
+        let response = context.execute(signed_tx).await?;
+        Ok(response)
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::CommandAction;
+use async_trait::async_trait;
+use clap::Parser;
+use commands::{
+    propose::ProposeCommand, sign::SignCommand, status::StatusCommand, submit::SubmitCommand,
+};
+use rooch_types::error::RoochResult;
+
+pub mod commands;
+
+/// Multisign account proposal commands, collect the signatures of the participants and submit the transaction.
+#[derive(Parser)]
+pub struct Multisign {
+    #[clap(subcommand)]
+    cmd: MultisignCommand,
+}
+
+#[async_trait]
+impl CommandAction<String> for Multisign {
+    async fn execute(self) -> RoochResult<String> {
+        match self.cmd {
+            MultisignCommand::Propose(cmd) => cmd.execute_serialized().await,
+            MultisignCommand::Sign(cmd) => cmd.execute_serialized().await,
+            MultisignCommand::Status(cmd) => cmd.execute_serialized().await,
+            MultisignCommand::Submit(cmd) => cmd.execute_serialized().await,
+        }
+    }
+}
+
+#[derive(clap::Subcommand)]
+#[clap(name = "multisign")]
+pub enum MultisignCommand {
+    Propose(ProposeCommand),
+    Sign(SignCommand),
+    Status(StatusCommand),
+    Submit(SubmitCommand),
+}
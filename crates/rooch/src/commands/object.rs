@@ -7,17 +7,18 @@ use async_trait::async_trait;
 use clap::Parser;
 use move_command_line_common::types::ParsedStructType;
 use move_core_types::language_storage::TypeTag;
-use moveos_types::move_types::type_tag_match;
 use rooch_rpc_api::jsonrpc_types::btc::ord::InscriptionFilterView;
+use rooch_rpc_api::jsonrpc_types::btc::runes::RuneFilterView;
 use rooch_rpc_api::jsonrpc_types::btc::utxo::UTXOFilterView;
 use rooch_rpc_api::jsonrpc_types::{ObjectStateFilterView, QueryOptions, RoochAddressView};
 use rooch_types::address::ParsedAddress;
-use rooch_types::indexer::state::{ObjectStateType, INSCRIPTION_TYPE_TAG, UTXO_TYPE_TAG};
+use rooch_types::indexer::state::ObjectStateType;
 use rooch_types::{error::RoochResult, function_arg::ParsedObjectID};
 
 pub const QUERY_OBJECT_STATES_METHOD: &str = "rooch_queryObjectStates";
 pub const QUERY_UTXOS_METHOD: &str = "btc_queryUTXOs";
 pub const QUERY_INSCRIPTIONS_METHOD: &str = "btc_queryInscriptions";
+pub const QUERY_RUNES_METHOD: &str = "btc_queryRunes";
 #[derive(Parser)]
 pub struct ObjectCommand {
     /// Object ids. Separate multiple IDs with a space.
@@ -32,6 +33,19 @@ pub struct ObjectCommand {
     #[clap(short = 'o', long, value_parser=ParsedAddress::parse)]
     owner: Option<ParsedAddress>,
 
+    /// Query the objects of the `object_type` by the custom index of the field path, such as `price` or `meta.owner`.
+    /// The custom index must be configured in the indexer of the node.
+    #[clap(long, requires = "object_type")]
+    custom_index: Option<String>,
+
+    /// The inclusive lower bound of the custom index field value
+    #[clap(long, requires = "custom_index")]
+    from: Option<String>,
+
+    /// The exclusive upper bound of the custom index field value
+    #[clap(long, requires = "custom_index")]
+    to: Option<String>,
+
     /// Max number of items returned per page
     #[clap(long)]
     limit: Option<u64>,
@@ -68,6 +82,16 @@ impl CommandAction<String> for ObjectCommand {
                 .map(|id| id.into_object_id(&address_mapping))
                 .collect::<Result<Vec<_>>>()?;
             filter = Some(ObjectStateFilterView::ObjectId(obj_ids.into()));
+        } else if self.custom_index.is_some() && self.object_type.is_some() {
+            let object_type = self.object_type.clone().unwrap();
+
+            let obj_type = object_type.into_struct_tag(&address_mapping)?;
+            filter = Some(ObjectStateFilterView::CustomIndex {
+                object_type: obj_type.into(),
+                field_path: self.custom_index.clone().unwrap(),
+                from: self.from.clone(),
+                to: self.to.clone(),
+            });
         } else if self.owner.is_some() && self.object_type.is_some() {
             let owner = self.owner.clone().unwrap();
             let object_type = self.object_type.clone().unwrap();
@@ -90,16 +114,11 @@ impl CommandAction<String> for ObjectCommand {
             filter = Some(ObjectStateFilterView::ObjectType(obj_type.into()));
         }
 
-        let object_state_type = if self.object_type.is_some() {
+        // The custom indexes are maintained for the object states
+        let object_state_type = if self.object_type.is_some() && self.custom_index.is_none() {
             let object_type = self.object_type.clone().unwrap();
             let obj_type = TypeTag::from(object_type.into_struct_tag(&address_mapping)?);
-            if type_tag_match(&obj_type, &UTXO_TYPE_TAG) {
-                ObjectStateType::UTXO
-            } else if type_tag_match(&obj_type, &INSCRIPTION_TYPE_TAG) {
-                ObjectStateType::Inscription
-            } else {
-                ObjectStateType::ObjectState
-            }
+            ObjectStateType::from_object_type(&obj_type)
         } else {
             ObjectStateType::ObjectState
         };
@@ -125,7 +144,8 @@ impl CommandAction<String> for ObjectCommand {
                         object_type: _,
                         owner,
                     } => UTXOFilterView::Owner(owner),
-                    ObjectStateFilterView::ObjectType(_object_type) => UTXOFilterView::All,
+                    ObjectStateFilterView::ObjectType(_object_type)
+                    | ObjectStateFilterView::CustomIndex { .. } => UTXOFilterView::All,
                     ObjectStateFilterView::Owner(owner) => UTXOFilterView::Owner(owner),
                     ObjectStateFilterView::ObjectId(object_id) => {
                         UTXOFilterView::ObjectId(object_id)
@@ -148,7 +168,8 @@ impl CommandAction<String> for ObjectCommand {
                         object_type: _,
                         owner,
                     } => InscriptionFilterView::Owner(owner),
-                    ObjectStateFilterView::ObjectType(_object_type) => InscriptionFilterView::All,
+                    ObjectStateFilterView::ObjectType(_object_type)
+                    | ObjectStateFilterView::CustomIndex { .. } => InscriptionFilterView::All,
                     ObjectStateFilterView::Owner(owner) => InscriptionFilterView::Owner(owner),
                     ObjectStateFilterView::ObjectId(object_id) => {
                         InscriptionFilterView::ObjectId(object_id)
@@ -160,6 +181,25 @@ impl CommandAction<String> for ObjectCommand {
                     .await?;
                 serde_json::to_string_pretty(&result).unwrap()
             }
+            ObjectStateType::Rune => {
+                let rune_fitler = match filter.unwrap() {
+                    ObjectStateFilterView::ObjectTypeWithOwner {
+                        object_type: _,
+                        owner,
+                    } => RuneFilterView::Owner(owner),
+                    ObjectStateFilterView::ObjectType(_object_type)
+                    | ObjectStateFilterView::CustomIndex { .. } => RuneFilterView::All,
+                    ObjectStateFilterView::Owner(owner) => RuneFilterView::Owner(owner),
+                    ObjectStateFilterView::ObjectId(object_id) => {
+                        RuneFilterView::ObjectId(object_id)
+                    }
+                };
+                let result = client
+                    .rooch
+                    .query_runes(rune_fitler, None, self.limit, Some(query_options))
+                    .await?;
+                serde_json::to_string_pretty(&result).unwrap()
+            }
             ObjectStateType::ObjectState => {
                 let result = client
                     .rooch
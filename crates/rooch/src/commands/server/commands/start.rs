@@ -7,6 +7,7 @@ use clap::Parser;
 use rooch_config::{RoochOpt, ServerOpt};
 use rooch_key::key_derive::verify_password;
 use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_rpc_client::wallet_context::WalletContext;
 use rooch_rpc_server::Service;
 use rooch_types::address::RoochAddress;
 use rooch_types::error::{RoochError, RoochResult};
@@ -34,82 +35,8 @@ impl CommandAction<()> for StartCommand {
         let mut context = self.context_options.build()?;
         self.opt.init()?;
 
-        //Parse key pair from Rooch opt
-        let sequencer_account = if self.opt.sequencer_account.is_none() {
-            let active_address_opt = context.client_config.active_address;
-            if active_address_opt.is_none() {
-                return Err(RoochError::ActiveAddressDoesNotExistError);
-            }
-            active_address_opt.unwrap()
-        } else {
-            RoochAddress::from_str(self.opt.sequencer_account.clone().unwrap().as_str()).map_err(
-                |e| {
-                    RoochError::CommandArgumentError(format!(
-                        "Invalid sequencer account address: {}",
-                        e
-                    ))
-                },
-            )?
-        };
-        let proposer_account = if self.opt.proposer_account.is_none() {
-            let active_address_opt = context.client_config.active_address;
-            if active_address_opt.is_none() {
-                return Err(RoochError::ActiveAddressDoesNotExistError);
-            }
-            active_address_opt.unwrap()
-        } else {
-            RoochAddress::from_str(self.opt.proposer_account.clone().unwrap().as_str()).map_err(
-                |e| {
-                    RoochError::CommandArgumentError(format!(
-                        "Invalid proposer account address: {}",
-                        e
-                    ))
-                },
-            )?
-        };
-
-        let (sequencer_keypair, proposer_keypair) = if context.keystore.get_if_password_is_empty() {
-            let sequencer_keypair = context
-                .keystore
-                .get_key_pair(&sequencer_account, None)
-                .map_err(|e| RoochError::SequencerKeyPairDoesNotExistError(e.to_string()))?;
-
-            let proposer_keypair = context
-                .keystore
-                .get_key_pair(&proposer_account, None)
-                .map_err(|e| RoochError::ProposerKeyPairDoesNotExistError(e.to_string()))?;
-
-            (sequencer_keypair, proposer_keypair)
-        } else {
-            let password = prompt_password("Enter the password:").unwrap_or_default();
-            let is_verified =
-                verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
-
-            if !is_verified {
-                return Err(RoochError::InvalidPasswordError(
-                    "Password is invalid".to_owned(),
-                ));
-            }
-
-            let sequencer_keypair = context
-                .keystore
-                .get_key_pair(&sequencer_account, Some(password.clone()))
-                .map_err(|e| RoochError::SequencerKeyPairDoesNotExistError(e.to_string()))?;
-
-            let proposer_keypair = context
-                .keystore
-                .get_key_pair(&proposer_account, Some(password.clone()))
-                .map_err(|e| RoochError::ProposerKeyPairDoesNotExistError(e.to_string()))?;
-
-            (sequencer_keypair, proposer_keypair)
-        };
-        // Construct sequencer, proposer and relayer keypair
-        let mut server_opt = ServerOpt::new();
-        server_opt.sequencer_keypair = Some(sequencer_keypair.copy());
-        server_opt.proposer_keypair = Some(proposer_keypair.copy());
-
+        let server_opt = build_server_opt(&self.opt, &context)?;
         let active_env = context.client_config.get_active_env()?;
-        server_opt.active_env = Some(active_env.clone().alias);
 
         let mut service = Service::new();
         service
@@ -179,3 +106,74 @@ impl CommandAction<()> for StartCommand {
         Ok(())
     }
 }
+
+/// Load the sequencer and proposer keypairs from the keystore, and construct the ServerOpt
+pub(crate) fn build_server_opt(opt: &RoochOpt, context: &WalletContext) -> RoochResult<ServerOpt> {
+    //Parse key pair from Rooch opt
+    let sequencer_account = if opt.sequencer_account.is_none() {
+        let active_address_opt = context.client_config.active_address;
+        if active_address_opt.is_none() {
+            return Err(RoochError::ActiveAddressDoesNotExistError);
+        }
+        active_address_opt.unwrap()
+    } else {
+        RoochAddress::from_str(opt.sequencer_account.clone().unwrap().as_str()).map_err(|e| {
+            RoochError::CommandArgumentError(format!("Invalid sequencer account address: {}", e))
+        })?
+    };
+    let proposer_account = if opt.proposer_account.is_none() {
+        let active_address_opt = context.client_config.active_address;
+        if active_address_opt.is_none() {
+            return Err(RoochError::ActiveAddressDoesNotExistError);
+        }
+        active_address_opt.unwrap()
+    } else {
+        RoochAddress::from_str(opt.proposer_account.clone().unwrap().as_str()).map_err(|e| {
+            RoochError::CommandArgumentError(format!("Invalid proposer account address: {}", e))
+        })?
+    };
+
+    let (sequencer_keypair, proposer_keypair) = if context.keystore.get_if_password_is_empty() {
+        let sequencer_keypair = context
+            .keystore
+            .get_key_pair(&sequencer_account, None)
+            .map_err(|e| RoochError::SequencerKeyPairDoesNotExistError(e.to_string()))?;
+
+        let proposer_keypair = context
+            .keystore
+            .get_key_pair(&proposer_account, None)
+            .map_err(|e| RoochError::ProposerKeyPairDoesNotExistError(e.to_string()))?;
+
+        (sequencer_keypair, proposer_keypair)
+    } else {
+        let password = prompt_password("Enter the password:").unwrap_or_default();
+        let is_verified =
+            verify_password(Some(password.clone()), context.keystore.get_password_hash())?;
+
+        if !is_verified {
+            return Err(RoochError::InvalidPasswordError(
+                "Password is invalid".to_owned(),
+            ));
+        }
+
+        let sequencer_keypair = context
+            .keystore
+            .get_key_pair(&sequencer_account, Some(password.clone()))
+            .map_err(|e| RoochError::SequencerKeyPairDoesNotExistError(e.to_string()))?;
+
+        let proposer_keypair = context
+            .keystore
+            .get_key_pair(&proposer_account, Some(password.clone()))
+            .map_err(|e| RoochError::ProposerKeyPairDoesNotExistError(e.to_string()))?;
+
+        (sequencer_keypair, proposer_keypair)
+    };
+    // Construct sequencer, proposer and relayer keypair
+    let mut server_opt = ServerOpt::new();
+    server_opt.sequencer_keypair = Some(sequencer_keypair.copy());
+    server_opt.proposer_keypair = Some(proposer_keypair.copy());
+
+    let active_env = context.client_config.get_active_env()?;
+    server_opt.active_env = Some(active_env.clone().alias);
+    Ok(server_opt)
+}
@@ -0,0 +1,33 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::clean_expired_session_keys;
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_types::address::ParsedAddress;
+use rooch_types::authentication_key::AuthenticationKey;
+use rooch_types::error::RoochResult;
+
+/// Remove the session keys which are expired on-chain from the local keystore, return the removed authentication keys
+#[derive(Debug, Parser)]
+pub struct CleanCommand {
+    #[clap(short = 'a', long = "address", value_parser=ParsedAddress::parse, default_value = "default")]
+    /// The account's address to clean session keys, if absent, use the default active account.
+    address: ParsedAddress,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<Vec<AuthenticationKey>> for CleanCommand {
+    async fn execute(self) -> RoochResult<Vec<AuthenticationKey>> {
+        let mut context = self.context_options.build()?;
+        let address = context.resolve_address(self.address)?;
+
+        let client = context.get_client().await?;
+        let session_keys = client.rooch.get_session_keys(address.into()).await?;
+        clean_expired_session_keys(&mut context, address.into(), &session_keys)
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_rpc_api::jsonrpc_types::session_key_view::SessionKeyView;
+use rooch_types::address::ParsedAddress;
+use rooch_types::authentication_key::AuthenticationKey;
+use rooch_types::error::{RoochError, RoochResult};
+
+/// Inspect the session key, show the scopes, max inactive interval, last active time and whether it is expired
+#[derive(Debug, Parser)]
+pub struct InspectCommand {
+    #[clap(short = 'a', long = "address", value_parser=ParsedAddress::parse, default_value = "default")]
+    /// The account's address of the session key, if absent, use the default active account.
+    address: ParsedAddress,
+
+    /// The authentication key of the session key
+    #[clap(long)]
+    authentication_key: AuthenticationKey,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<SessionKeyView> for InspectCommand {
+    async fn execute(self) -> RoochResult<SessionKeyView> {
+        let context = self.context_options.build()?;
+        let address = context.resolve_address(self.address)?;
+
+        let client = context.get_client().await?;
+        let session_keys = client.rooch.get_session_keys(address.into()).await?;
+
+        session_keys
+            .into_iter()
+            .find(|session_key| {
+                session_key.authentication_key.0.as_slice() == self.authentication_key.as_ref()
+            })
+            .ok_or_else(|| {
+                RoochError::CommandArgumentError(format!(
+                    "Can not find session key {} of address {}",
+                    self.authentication_key, address
+                ))
+            })
+    }
+}
@@ -1,5 +1,42 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_rpc_api::jsonrpc_types::session_key_view::SessionKeyView;
+use rooch_rpc_client::wallet_context::WalletContext;
+use rooch_types::address::RoochAddress;
+use rooch_types::authentication_key::AuthenticationKey;
+use rooch_types::error::RoochResult;
+
+pub mod clean;
 pub mod create;
+pub mod inspect;
 pub mod list;
+pub mod remove;
+
+/// Remove the local session keys of the address which the chain reports as expired.
+/// The local session keys which are not found on-chain are kept, they may not be created on this chain yet.
+/// Return the authentication keys of the removed session keys.
+pub(crate) fn clean_expired_session_keys(
+    context: &mut WalletContext,
+    address: RoochAddress,
+    on_chain_session_keys: &[SessionKeyView],
+) -> RoochResult<Vec<AuthenticationKey>> {
+    let mut removed = vec![];
+    for authentication_key in context
+        .keystore
+        .get_session_key_authentication_keys(&address)
+    {
+        let is_expired = on_chain_session_keys.iter().any(|session_key| {
+            session_key.authentication_key.0.as_slice() == authentication_key.as_ref()
+                && session_key.expired
+        });
+        if is_expired {
+            context
+                .keystore
+                .remove_session_key(&address, &authentication_key)?;
+            removed.push(authentication_key);
+        }
+    }
+    Ok(removed)
+}
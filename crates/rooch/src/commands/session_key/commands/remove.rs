@@ -0,0 +1,47 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, TransactionOptions, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_types::address::RoochAddress;
+use rooch_types::authentication_key::AuthenticationKey;
+use rooch_types::error::RoochResult;
+use rooch_types::framework::session_key::SessionKeyModule;
+
+/// Remove the session key on-chain, and remove it from the local keystore
+#[derive(Debug, Parser)]
+pub struct RemoveCommand {
+    /// The authentication key of the session key to remove
+    #[clap(long)]
+    authentication_key: AuthenticationKey,
+
+    #[clap(flatten)]
+    tx_options: TransactionOptions,
+
+    #[clap(flatten)]
+    context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<ExecuteTransactionResponseView> for RemoveCommand {
+    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+        let mut context = self.context_options.build_require_password()?;
+        let sender: RoochAddress = context.resolve_address(self.tx_options.sender)?.into();
+        let max_gas_amount: Option<u64> = self.tx_options.max_gas_amount;
+
+        let action =
+            SessionKeyModule::remove_session_key_action(self.authentication_key.as_ref().to_vec());
+        let result = context
+            .sign_and_execute(sender, action, context.get_password(), max_gas_amount)
+            .await?;
+        let result = context.assert_execute_success(result)?;
+
+        context
+            .keystore
+            .remove_session_key(&sender, &self.authentication_key)?;
+        Ok(result)
+    }
+}
@@ -4,8 +4,11 @@
 use crate::cli_types::CommandAction;
 use async_trait::async_trait;
 use clap::Parser;
+use commands::clean::CleanCommand;
 use commands::create::CreateCommand;
+use commands::inspect::InspectCommand;
 use commands::list::ListCommand;
+use commands::remove::RemoveCommand;
 use rooch_types::error::RoochResult;
 
 pub mod commands;
@@ -25,6 +28,9 @@ impl CommandAction<String> for SessionKey {
                 serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
             }),
             SessionKeyCommand::List(list) => list.execute_serialized().await,
+            SessionKeyCommand::Inspect(inspect) => inspect.execute_serialized().await,
+            SessionKeyCommand::Remove(remove) => remove.execute_serialized().await,
+            SessionKeyCommand::Clean(clean) => clean.execute_serialized().await,
         }
     }
 }
@@ -34,4 +40,7 @@ impl CommandAction<String> for SessionKey {
 pub enum SessionKeyCommand {
     Create(Box<CreateCommand>),
     List(ListCommand),
+    Inspect(InspectCommand),
+    Remove(Box<RemoveCommand>),
+    Clean(CleanCommand),
 }
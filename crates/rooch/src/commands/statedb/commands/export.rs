@@ -9,26 +9,33 @@ use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Instant;
 
-use anyhow::Result;
+use anyhow::{ensure, Result};
 use clap::Parser;
 use rustc_hash::FxHashSet;
 use serde::{Deserialize, Serialize};
 
+use moveos_store::transaction_store::TransactionStore as TxExecutionInfoStore;
 use moveos_store::MoveOSStore;
 use moveos_types::h256::H256;
 use moveos_types::moveos_std::object::ObjectID;
-use moveos_types::state::FieldKey;
+use moveos_types::state::{FieldKey, ObjectState};
 use moveos_types::state_resolver::StatelessResolver;
 use rooch_config::R_OPT_NET_HELP;
+use rooch_db::RoochDB;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::transaction_store::TransactionStore;
 use rooch_types::bitcoin::ord::InscriptionStore;
 use rooch_types::bitcoin::utxo::BitcoinUTXOStore;
-use rooch_types::error::RoochResult;
+use rooch_types::error::{RoochError, RoochResult};
 use rooch_types::framework::address_mapping::RoochToBitcoinAddressMapping;
 use rooch_types::rooch_network::RoochChainID;
 
 use crate::commands::statedb::commands::inscription::{
     gen_inscription_id_update, InscriptionSource,
 };
+use crate::commands::statedb::commands::snapshot::{
+    SnapshotHeader, SnapshotWriter, SNAPSHOT_VERSION,
+};
 use crate::commands::statedb::commands::utxo::UTXORawData;
 use crate::commands::statedb::commands::{init_job, ExportWriter, OutpointInscriptionsMap};
 
@@ -38,9 +45,9 @@ use crate::commands::statedb::commands::{init_job, ExportWriter, OutpointInscrip
 pub enum ExportMode {
     #[default]
     Genesis, // dump InscriptionStore, BitcoinUTXOStore, RoochToBitcoinAddressMapping for genesis start-up
-    Full,
-    Snapshot,
-    Indexer, // dump Full Objects, include InscriptionStore, BitcoinUTXOStore for rebuild indexer
+    Full,     // dump all Objects and Fields recursively with ExportID of each Object to csv
+    Snapshot, // dump all Objects and Fields recursively to compressed binary snapshot for `statedb import`
+    Indexer,  // dump Full Objects, include InscriptionStore, BitcoinUTXOStore for rebuild indexer
     Object,
 }
 
@@ -199,7 +206,7 @@ pub struct ExportCommand {
     #[clap(long, short = 's')]
     pub state_root: Option<H256>,
     #[clap(long, short = 'o')]
-    /// export output file. like ~/.rooch/local/statedb.csv or ./statedb.csv
+    /// export output file. like ~/.rooch/local/statedb.csv or ./statedb.csv, snapshot mode is a binary file
     pub output: Option<PathBuf>,
     #[clap(long, help = "path to ord source path")]
     pub ord_source_path: Option<PathBuf>,
@@ -233,8 +240,9 @@ pub struct ExportCommand {
 
 impl ExportCommand {
     pub async fn execute(self) -> RoochResult<()> {
-        let (root, moveos_store, start_time) =
+        let (root, rooch_db, start_time) =
             init_job(self.base_data_dir.clone(), self.chain_id.clone());
+        let moveos_store = rooch_db.moveos_store.clone();
 
         let output = self.output.clone();
         let mut writer = ExportWriter::new(output, None);
@@ -247,10 +255,15 @@ impl ExportCommand {
                 // TODO: export genesis from resolver
             }
             ExportMode::Full => {
-                todo!()
+                Self::export_full(&moveos_store, root_state_root, root.updated_at, &mut writer)?;
             }
             ExportMode::Snapshot => {
-                todo!()
+                let output = self.output.clone().ok_or_else(|| {
+                    RoochError::CommandArgumentError(
+                        "--output is required for snapshot mode".to_owned(),
+                    )
+                })?;
+                Self::export_snapshot(&rooch_db, root_state_root, root.updated_at, output)?;
             }
             ExportMode::Indexer => {
                 self.export_indexer(&moveos_store, root_state_root, &mut writer)?;
@@ -568,6 +581,152 @@ impl ExportCommand {
         Ok(())
     }
 
+    // export all objects and fields of the state tree with ExportID of each object to csv
+    fn export_full(
+        moveos_store: &MoveOSStore,
+        root_state_root: H256,
+        timestamp: u64,
+        writer: &mut ExportWriter,
+    ) -> Result<()> {
+        Self::export_state_tree(
+            moveos_store,
+            root_state_root,
+            timestamp,
+            |item| match item {
+                StateTreeItem::Object(export_id) => writer.write_export_id(&export_id),
+                StateTreeItem::Field(k, v) => writer.write_record(&k, &v),
+            },
+        )
+    }
+
+    // export all objects and fields of the state tree to a compressed and checksummed snapshot,
+    // it could be imported by `rooch statedb import`
+    // the snapshot must be exported at the state root of the last sequenced tx,
+    // so the sequencer info and the last tx could be imported with the state
+    pub(crate) fn export_snapshot(
+        rooch_db: &RoochDB,
+        root_state_root: H256,
+        timestamp: u64,
+        output: PathBuf,
+    ) -> Result<()> {
+        let moveos_store = &rooch_db.moveos_store;
+        let sequencer_info = rooch_db
+            .rooch_store
+            .get_sequencer_info()?
+            .ok_or_else(|| anyhow::anyhow!("sequencer info not found"))?;
+        let last_tx_hash = rooch_db
+            .rooch_store
+            .get_tx_hashes(vec![sequencer_info.last_order])?
+            .pop()
+            .flatten()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "the tx of last order {} not found",
+                    sequencer_info.last_order
+                )
+            })?;
+        let last_ledger_tx = rooch_db
+            .rooch_store
+            .get_transaction_by_hash(last_tx_hash)?
+            .ok_or_else(|| anyhow::anyhow!("the ledger tx {:?} not found", last_tx_hash))?;
+        let last_execution_info = moveos_store
+            .get_tx_execution_info(last_tx_hash)?
+            .ok_or_else(|| {
+                anyhow::anyhow!("the execution info of tx {:?} not found", last_tx_hash)
+            })?;
+        ensure!(
+            last_execution_info.state_root == root_state_root,
+            "snapshot must be exported at the state root {:?} of the last tx order {}, got: {:?}",
+            last_execution_info.state_root,
+            sequencer_info.last_order,
+            root_state_root
+        );
+        let header = SnapshotHeader {
+            version: SNAPSHOT_VERSION,
+            state_root: root_state_root,
+            genesis_info: moveos_store.config_store.get_genesis()?,
+            sequencer_info,
+            last_ledger_tx,
+            last_execution_info,
+        };
+        let mut writer = SnapshotWriter::new(&output, header)?;
+        Self::export_state_tree(
+            moveos_store,
+            root_state_root,
+            timestamp,
+            |item| match item {
+                StateTreeItem::Object(export_id) => writer.write_object(export_id),
+                StateTreeItem::Field(k, v) => writer.write_field(k, v),
+            },
+        )?;
+        let (object_count, field_count, checksum) = writer.finish()?;
+        println!(
+            "snapshot of state_root: {:?} exported to {:?}, objects: {}, fields: {}, checksum: {:x}",
+            root_state_root, output, object_count, field_count, checksum
+        );
+        Ok(())
+    }
+
+    // traverse the state tree from root, visit each object's ExportID followed by its top level fields,
+    // the child objects are visited after all the fields of the parent object
+    fn export_state_tree<F>(
+        moveos_store: &MoveOSStore,
+        root_state_root: H256,
+        timestamp: u64,
+        mut visit: F,
+    ) -> Result<()>
+    where
+        F: FnMut(StateTreeItem) -> Result<()>,
+    {
+        let start_time = Instant::now();
+        let mut object_count: u64 = 0;
+        let mut field_count: u64 = 0;
+
+        let mut pending = vec![ExportID::new(
+            ObjectID::root(),
+            root_state_root,
+            root_state_root,
+            timestamp,
+        )];
+        while let Some(export_id) = pending.pop() {
+            let state_root = export_id.state_root;
+            visit(StateTreeItem::Object(export_id))?;
+            object_count += 1;
+
+            let iter = moveos_store.get_state_store().iter(state_root, None)?;
+            for item in iter {
+                let (k, v) = item?;
+                if v.metadata.has_fields() {
+                    pending.push(ExportID::new(
+                        v.id().clone(),
+                        v.state_root(),
+                        state_root,
+                        v.metadata.updated_at,
+                    ));
+                }
+                visit(StateTreeItem::Field(k, v))?;
+                field_count += 1;
+                if field_count % 1_000_000 == 0 {
+                    println!(
+                        "exporting state tree, exported objects: {}, fields: {}. cost: {:?}",
+                        object_count,
+                        field_count,
+                        start_time.elapsed()
+                    );
+                }
+            }
+        }
+
+        println!(
+            "Done. export state tree of state_root: {:?}, exported objects: {}, fields: {}. cost: {:?}",
+            root_state_root,
+            object_count,
+            field_count,
+            start_time.elapsed()
+        );
+        Ok(())
+    }
+
     // export top level fields of an object, no recursive export child field
     fn export_top_level_fields(
         moveos_store: &MoveOSStore,
@@ -614,6 +773,11 @@ impl ExportCommand {
     }
 }
 
+enum StateTreeItem {
+    Object(ExportID),
+    Field(FieldKey, ObjectState),
+}
+
 fn get_state_root(
     moveos_store: &MoveOSStore,
     state_root: H256,
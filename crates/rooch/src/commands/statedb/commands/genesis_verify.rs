@@ -94,8 +94,9 @@ pub struct GenesisVerifyCommand {
 
 impl GenesisVerifyCommand {
     pub async fn execute(self) -> RoochResult<()> {
-        let (root, moveos_store, start_time) =
+        let (root, rooch_db, start_time) =
             init_job(self.base_data_dir.clone(), self.chain_id.clone());
+        let moveos_store = rooch_db.moveos_store;
         let outpoint_inscriptions_map = if self.outpoint_inscriptions_map_dump_path.is_some() {
             Some(Arc::new(OutpointInscriptionsMap::load_or_index(
                 self.outpoint_inscriptions_map_dump_path.clone().unwrap(),
@@ -0,0 +1,337 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use anyhow::{ensure, Result};
+use clap::Parser;
+use smt::UpdateSet;
+
+use moveos_store::transaction_store::TransactionStore as TxExecutionInfoStore;
+use moveos_store::MoveOSStore;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::object::{ObjectID, GENESIS_STATE_ROOT};
+use moveos_types::startup_info::StartupInfo;
+use moveos_types::state::{FieldKey, ObjectState};
+use rooch_config::R_OPT_NET_HELP;
+use rooch_db::RoochDB;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::transaction_store::TransactionStore;
+use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::rooch_network::RoochChainID;
+
+use crate::commands::statedb::commands::export::ExportID;
+use crate::commands::statedb::commands::snapshot::{SnapshotReader, SnapshotRecord};
+use crate::commands::statedb::commands::{apply_fields, apply_nodes, init_rooch_db};
+
+/// Import the snapshot exported by `rooch statedb export --mode snapshot` to a fresh statedb,
+/// verify the state root and set the startup info, so the node could start from the snapshot state.
+#[derive(Debug, Parser)]
+pub struct ImportCommand {
+    /// snapshot file path
+    #[clap(long, short = 'i')]
+    pub input: PathBuf,
+    #[clap(
+        long,
+        default_value = "1048576",
+        help = "batch size submitted to state db. Set it smaller if memory is limited."
+    )]
+    pub batch_size: Option<usize>,
+
+    #[clap(long = "data-dir", short = 'd')]
+    /// Path to data dir, this dir is base dir, the final data_dir is base_dir/chain_network_name
+    pub base_data_dir: Option<PathBuf>,
+    /// If local chainid, start the service with a temporary data store.
+    /// All data will be deleted when the service is stopped.
+    #[clap(long, short = 'n', help = R_OPT_NET_HELP)]
+    pub chain_id: Option<RoochChainID>,
+}
+
+impl ImportCommand {
+    pub async fn execute(self) -> RoochResult<()> {
+        let start_time = Instant::now();
+        let rooch_db = init_rooch_db(self.base_data_dir.clone(), self.chain_id.clone());
+        if rooch_db
+            .moveos_store
+            .config_store
+            .get_startup_info()?
+            .is_some()
+            || rooch_db.rooch_store.get_sequencer_info()?.is_some()
+        {
+            return Err(RoochError::CommandArgumentError(
+                "statedb is not empty, snapshot must be imported to a fresh statedb".to_owned(),
+            ));
+        }
+        let (startup_info, object_count) =
+            import_snapshot(&rooch_db, &self.input, self.batch_size.unwrap_or(1048576))?;
+        println!(
+            "Done. snapshot imported, {}, objects: {}. cost: {:?}",
+            startup_info,
+            object_count,
+            start_time.elapsed()
+        );
+        Ok(())
+    }
+}
+
+// import the state tree, then the genesis info, the last tx and the sequencer info,
+// the startup info is saved at last, so an interrupted import leaves the statedb without startup info.
+// return the startup info and the imported object count
+pub(crate) fn import_snapshot(
+    rooch_db: &RoochDB,
+    input: &Path,
+    batch_size: usize,
+) -> Result<(StartupInfo, u64)> {
+    let start_time = Instant::now();
+    let moveos_store = &rooch_db.moveos_store;
+    let mut reader = SnapshotReader::open(input)?;
+    let header = reader.header().clone();
+    let mut last_ledger_tx = header.last_ledger_tx;
+    let last_tx_hash = last_ledger_tx.tx_hash();
+    ensure!(
+        last_ledger_tx.sequence_info.tx_order == header.sequencer_info.last_order
+            && last_ledger_tx.sequence_info.tx_accumulator_info()
+                == header.sequencer_info.last_accumulator_info,
+        "invalid snapshot: the last tx does not match the sequencer info {}",
+        header.sequencer_info
+    );
+    ensure!(
+        header.last_execution_info.tx_hash == last_tx_hash
+            && header.last_execution_info.state_root == header.state_root,
+        "invalid snapshot: the execution info of the last tx {:?} does not match the state root {:?}",
+        last_tx_hash,
+        header.state_root
+    );
+
+    let mut importer: Option<ObjectImporter> = None;
+    let mut root_size = None;
+    let mut object_count: u64 = 0;
+    while let Some(record) = reader.next_record()? {
+        match record {
+            SnapshotRecord::Object(export_id) => {
+                if let Some(importer) = importer.take() {
+                    if let Some(size) = importer.finish(moveos_store)? {
+                        root_size = Some(size);
+                    }
+                }
+                ensure!(
+                    export_id.object_id != ObjectID::root()
+                        || export_id.state_root == header.state_root,
+                    "invalid snapshot: root state root mismatch, header: {:?}, root object: {:?}",
+                    header.state_root,
+                    export_id.state_root
+                );
+                importer = Some(ObjectImporter::new(export_id));
+                object_count += 1;
+                if object_count % 100_000 == 0 {
+                    println!(
+                        "importing snapshot, imported objects: {}. cost: {:?}",
+                        object_count,
+                        start_time.elapsed()
+                    );
+                }
+            }
+            SnapshotRecord::Field(k, v) => {
+                let importer = importer
+                    .as_mut()
+                    .ok_or_else(|| anyhow::anyhow!("invalid snapshot: field before object"))?;
+                importer.put(k, v);
+                if importer.update_set.len() >= batch_size {
+                    importer.apply(moveos_store)?;
+                }
+            }
+            _ => unreachable!("reader only returns object or field records"),
+        }
+    }
+    if let Some(importer) = importer.take() {
+        if let Some(size) = importer.finish(moveos_store)? {
+            root_size = Some(size);
+        }
+    }
+
+    let root_size =
+        root_size.ok_or_else(|| anyhow::anyhow!("invalid snapshot: root object not found"))?;
+    ensure!(
+        header.last_execution_info.size == root_size,
+        "invalid snapshot: root size mismatch, execution info: {}, imported: {}",
+        header.last_execution_info.size,
+        root_size
+    );
+
+    if let Some(genesis_info) = header.genesis_info {
+        moveos_store.config_store.save_genesis(genesis_info)?;
+    }
+    moveos_store.save_tx_execution_info(header.last_execution_info)?;
+    rooch_db.rooch_store.save_transaction(last_ledger_tx)?;
+    rooch_db
+        .rooch_store
+        .get_meta_store()
+        .save_sequencer_info_ignore_check(header.sequencer_info)?;
+    let startup_info = StartupInfo::new(header.state_root, root_size);
+    moveos_store
+        .config_store
+        .save_startup_info(startup_info.clone())?;
+    Ok((startup_info, object_count))
+}
+
+// rebuild the state tree of an object from its top level fields
+struct ObjectImporter {
+    export_id: ExportID,
+    state_root: H256,
+    update_set: UpdateSet<FieldKey, ObjectState>,
+    count: u64,
+}
+
+impl ObjectImporter {
+    fn new(export_id: ExportID) -> Self {
+        ObjectImporter {
+            export_id,
+            state_root: *GENESIS_STATE_ROOT,
+            update_set: UpdateSet::new(),
+            count: 0,
+        }
+    }
+
+    fn put(&mut self, k: FieldKey, v: ObjectState) {
+        self.update_set.put(k, v);
+        self.count += 1;
+    }
+
+    fn apply(&mut self, moveos_store: &MoveOSStore) -> Result<()> {
+        if self.update_set.is_empty() {
+            return Ok(());
+        }
+        let update_set = std::mem::take(&mut self.update_set);
+        let tree_change_set = apply_fields(moveos_store, self.state_root, update_set)?;
+        apply_nodes(moveos_store, tree_change_set.nodes)?;
+        self.state_root = tree_change_set.state_root;
+        Ok(())
+    }
+
+    // apply the remaining fields and verify the state root, return the fields count if it is the root object
+    fn finish(mut self, moveos_store: &MoveOSStore) -> Result<Option<u64>> {
+        self.apply(moveos_store)?;
+        ensure!(
+            self.state_root == self.export_id.state_root,
+            "state root mismatch for object {:?}, expected: {:?}, imported: {:?}",
+            self.export_id.object_id,
+            self.export_id.state_root,
+            self.state_root
+        );
+        if self.export_id.object_id == ObjectID::root() {
+            Ok(Some(self.count))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::statedb::commands::export::ExportCommand;
+    use metrics::RegistryService;
+    use rooch_config::RoochOpt;
+    use rooch_genesis::RoochGenesis;
+    use rooch_sequencer::actor::sequencer::SequencerActor;
+    use rooch_types::crypto::RoochKeyPair;
+    use rooch_types::rooch_network::RoochNetwork;
+    use rooch_types::service_status::ServiceStatus;
+    use rooch_types::transaction::{LedgerTxData, RoochTransaction};
+    use tempfile::tempdir;
+
+    fn temp_rooch_db() -> (RoochOpt, RoochDB) {
+        let opt = RoochOpt::new_with_temp_store().unwrap();
+        let registry_service = RegistryService::default();
+        let rooch_db =
+            RoochDB::init(opt.store_config(), &registry_service.default_registry()).unwrap();
+        (opt, rooch_db)
+    }
+
+    fn sequence_tx(
+        rooch_db: &RoochDB,
+        kp: &RoochKeyPair,
+        tx: RoochTransaction,
+    ) -> LedgerTransaction {
+        let registry_service = RegistryService::default();
+        let mut sequencer = SequencerActor::new(
+            kp.copy(),
+            rooch_db.rooch_store.clone(),
+            ServiceStatus::Active,
+            &registry_service.default_registry(),
+            None,
+        )
+        .unwrap();
+        sequencer.sequence(LedgerTxData::L2Tx(tx)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_export_import_and_start() {
+        let kp = RoochKeyPair::generate_secp256k1();
+        let mut network = RoochNetwork::local();
+        network.mock_genesis_account(&kp).unwrap();
+
+        let (_source_opt, source_db) = temp_rooch_db();
+        let genesis = RoochGenesis::build(network.clone()).unwrap();
+        let root = genesis.init_genesis(&source_db).unwrap();
+
+        let snapshot_dir = tempdir().unwrap();
+        let snapshot = snapshot_dir.path().join("snapshot");
+        ExportCommand::export_snapshot(
+            &source_db,
+            root.state_root(),
+            root.updated_at,
+            snapshot.clone(),
+        )
+        .unwrap();
+
+        let (_target_opt, target_db) = temp_rooch_db();
+        let (startup_info, _) = import_snapshot(&target_db, &snapshot, 1024).unwrap();
+        assert_eq!(startup_info.state_root, root.state_root());
+        assert_eq!(startup_info.size, root.size);
+        let sequencer_info = target_db.rooch_store.get_sequencer_info().unwrap().unwrap();
+        let source_sequencer_info = source_db.rooch_store.get_sequencer_info().unwrap().unwrap();
+        assert_eq!(sequencer_info.last_order, source_sequencer_info.last_order);
+        assert_eq!(
+            sequencer_info.last_accumulator_info,
+            source_sequencer_info.last_accumulator_info
+        );
+
+        // The node starts from the imported statedb and continues to sequence txs
+        RoochGenesis::load_or_init(network, &target_db).unwrap();
+        assert_eq!(
+            target_db.latest_root().unwrap().unwrap().state_root(),
+            root.state_root()
+        );
+        let tx = RoochTransaction::mock();
+        let source_tx = sequence_tx(&source_db, &kp, tx.clone());
+        let target_tx = sequence_tx(&target_db, &kp, tx);
+        assert_eq!(target_tx.sequence_info.tx_order, 1);
+        assert_eq!(
+            target_tx.sequence_info.tx_accumulator_info(),
+            source_tx.sequence_info.tx_accumulator_info()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_snapshot_requires_last_state_root() {
+        let kp = RoochKeyPair::generate_secp256k1();
+        let mut network = RoochNetwork::local();
+        network.mock_genesis_account(&kp).unwrap();
+        let (_opt, rooch_db) = temp_rooch_db();
+        let root = RoochGenesis::build(network)
+            .unwrap()
+            .init_genesis(&rooch_db)
+            .unwrap();
+
+        let snapshot_dir = tempdir().unwrap();
+        assert!(ExportCommand::export_snapshot(
+            &rooch_db,
+            H256::random(),
+            root.updated_at,
+            snapshot_dir.path().join("snapshot"),
+        )
+        .is_err());
+    }
+}
@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::commands::statedb::commands::export::ExportID;
 use crate::commands::statedb::commands::inscription::{derive_inscription_ids, InscriptionSource};
 use anyhow::{Error, Result};
 use bitcoin::hashes::Hash;
@@ -36,9 +37,11 @@ pub mod genesis;
 pub mod genesis_ord;
 pub mod genesis_utxo;
 pub mod genesis_verify;
+pub mod import;
 pub mod re_genesis;
 
 mod inscription;
+mod snapshot;
 mod utxo;
 
 pub const GLOBAL_STATE_TYPE_PREFIX: &str = "states";
@@ -61,7 +64,7 @@ fn init_rooch_db(base_data_dir: Option<PathBuf>, chain_id: Option<RoochChainID>)
 fn init_job(
     base_data_dir: Option<PathBuf>,
     chain_id: Option<RoochChainID>,
-) -> (ObjectMeta, MoveOSStore, Instant) {
+) -> (ObjectMeta, RoochDB, Instant) {
     let start_time = Instant::now();
 
     let rooch_db = init_rooch_db(base_data_dir, chain_id);
@@ -73,7 +76,7 @@ fn init_job(
 
     log::info!("job progress started");
 
-    (root, rooch_db.moveos_store, start_time)
+    (root, rooch_db, start_time)
 }
 
 fn convert_option_string_to_move_type(opt: Option<String>) -> MoveOption<MoveString> {
@@ -439,6 +442,13 @@ impl ExportWriter {
             preprocessor,
         }
     }
+    // write the ExportID line before the fields of an object, c1: states_object, c2: ExportID
+    fn write_export_id(&mut self, export_id: &ExportID) -> Result<()> {
+        if let Some(writer) = &mut self.writer {
+            writer.write_record([GLOBAL_STATE_TYPE_OBJECT, export_id.to_string().as_str()])?;
+        }
+        Ok(())
+    }
     fn write_record(&mut self, k: &FieldKey, v: &ObjectState) -> Result<()> {
         let (k, v) = match &mut self.preprocessor {
             Some(preprocessor) => preprocessor.process(k, v),
@@ -0,0 +1,290 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::Path;
+
+use anyhow::{bail, ensure, Result};
+use lz4::{Decoder, Encoder, EncoderBuilder};
+use serde::{Deserialize, Serialize};
+use xxhash_rust::xxh3::Xxh3;
+
+use moveos_types::genesis_info::GenesisInfo;
+use moveos_types::h256::H256;
+use moveos_types::state::{FieldKey, ObjectState};
+use moveos_types::transaction::TransactionExecutionInfo;
+use rooch_types::sequencer::SequencerInfo;
+use rooch_types::transaction::LedgerTransaction;
+
+use crate::commands::statedb::commands::export::ExportID;
+
+pub const SNAPSHOT_VERSION: u8 = 2;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotHeader {
+    pub version: u8,
+    /// The root state root of the snapshot
+    pub state_root: H256,
+    pub genesis_info: Option<GenesisInfo>,
+    /// The sequencer info at the state root, the node continues to sequence txs from it after import
+    pub sequencer_info: SequencerInfo,
+    /// The last sequenced tx and its execution info, the state root of the execution info is the snapshot state root
+    pub last_ledger_tx: LedgerTransaction,
+    pub last_execution_info: TransactionExecutionInfo,
+}
+
+// Snapshot file format:
+// lz4 compressed stream of records, each record is a u32(little endian) length prefixed bcs bytes.
+// The first record is the Header, the last record is the End.
+// Every Object record is followed by the top level fields of the object until the next Object record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SnapshotRecord {
+    Header(SnapshotHeader),
+    Object(ExportID),
+    Field(FieldKey, ObjectState),
+    /// checksum is the xxh3 of all records before End
+    End {
+        object_count: u64,
+        field_count: u64,
+        checksum: u64,
+    },
+}
+
+pub(crate) struct SnapshotWriter {
+    encoder: Encoder<BufWriter<File>>,
+    hasher: Xxh3,
+    object_count: u64,
+    field_count: u64,
+}
+
+impl SnapshotWriter {
+    pub fn new(output: &Path, header: SnapshotHeader) -> Result<Self> {
+        let file = BufWriter::with_capacity(8 * 1024 * 1024, File::create(output)?);
+        let encoder = EncoderBuilder::new().build(file)?;
+        let mut writer = SnapshotWriter {
+            encoder,
+            hasher: Xxh3::default(),
+            object_count: 0,
+            field_count: 0,
+        };
+        writer.write_record(&SnapshotRecord::Header(header))?;
+        Ok(writer)
+    }
+
+    pub fn write_object(&mut self, export_id: ExportID) -> Result<()> {
+        self.object_count += 1;
+        self.write_record(&SnapshotRecord::Object(export_id))
+    }
+
+    pub fn write_field(&mut self, k: FieldKey, v: ObjectState) -> Result<()> {
+        self.field_count += 1;
+        self.write_record(&SnapshotRecord::Field(k, v))
+    }
+
+    fn write_record(&mut self, record: &SnapshotRecord) -> Result<()> {
+        let bytes = bcs::to_bytes(record)?;
+        self.hasher.update(&bytes);
+        self.encoder
+            .write_all(&(bytes.len() as u32).to_le_bytes())?;
+        self.encoder.write_all(&bytes)?;
+        Ok(())
+    }
+
+    // write the End record and flush, return (object_count, field_count, checksum)
+    pub fn finish(mut self) -> Result<(u64, u64, u64)> {
+        let checksum = self.hasher.digest();
+        let (object_count, field_count) = (self.object_count, self.field_count);
+        self.write_record(&SnapshotRecord::End {
+            object_count,
+            field_count,
+            checksum,
+        })?;
+        let (mut file, result) = self.encoder.finish();
+        result?;
+        file.flush()?;
+        file.get_ref().sync_all()?;
+        Ok((object_count, field_count, checksum))
+    }
+}
+
+pub(crate) struct SnapshotReader {
+    decoder: Decoder<BufReader<File>>,
+    hasher: Xxh3,
+    header: SnapshotHeader,
+    object_count: u64,
+    field_count: u64,
+    finished: bool,
+}
+
+impl SnapshotReader {
+    pub fn open(input: &Path) -> Result<Self> {
+        let file = BufReader::with_capacity(8 * 1024 * 1024, File::open(input)?);
+        let mut decoder = Decoder::new(file)?;
+        let mut hasher = Xxh3::default();
+        let header = match Self::read_record(&mut decoder, &mut hasher)? {
+            Some(SnapshotRecord::Header(header)) => header,
+            _ => bail!("invalid snapshot: the first record must be the header"),
+        };
+        ensure!(
+            header.version == SNAPSHOT_VERSION,
+            "unsupported snapshot version: {}, expected: {}",
+            header.version,
+            SNAPSHOT_VERSION
+        );
+        Ok(SnapshotReader {
+            decoder,
+            hasher,
+            header,
+            object_count: 0,
+            field_count: 0,
+            finished: false,
+        })
+    }
+
+    pub fn header(&self) -> &SnapshotHeader {
+        &self.header
+    }
+
+    fn read_record(
+        decoder: &mut Decoder<BufReader<File>>,
+        hasher: &mut Xxh3,
+    ) -> Result<Option<SnapshotRecord>> {
+        let mut len_bytes = [0u8; 4];
+        match decoder.read_exact(&mut len_bytes) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e.into()),
+        }
+        let mut bytes = vec![0u8; u32::from_le_bytes(len_bytes) as usize];
+        decoder.read_exact(&mut bytes)?;
+        let record = bcs::from_bytes::<SnapshotRecord>(&bytes)?;
+        if !matches!(record, SnapshotRecord::End { .. }) {
+            hasher.update(&bytes);
+        }
+        Ok(Some(record))
+    }
+
+    /// Read the next Object or Field record, return None after the End record is read and verified.
+    pub fn next_record(&mut self) -> Result<Option<SnapshotRecord>> {
+        if self.finished {
+            return Ok(None);
+        }
+        match Self::read_record(&mut self.decoder, &mut self.hasher)? {
+            Some(SnapshotRecord::Object(export_id)) => {
+                self.object_count += 1;
+                Ok(Some(SnapshotRecord::Object(export_id)))
+            }
+            Some(SnapshotRecord::Field(k, v)) => {
+                self.field_count += 1;
+                Ok(Some(SnapshotRecord::Field(k, v)))
+            }
+            Some(SnapshotRecord::End {
+                object_count,
+                field_count,
+                checksum,
+            }) => {
+                ensure!(
+                    object_count == self.object_count && field_count == self.field_count,
+                    "snapshot count mismatch, expected objects: {}, fields: {}, got objects: {}, fields: {}",
+                    object_count,
+                    field_count,
+                    self.object_count,
+                    self.field_count
+                );
+                ensure!(
+                    checksum == self.hasher.digest(),
+                    "snapshot checksum mismatch"
+                );
+                self.finished = true;
+                Ok(None)
+            }
+            Some(SnapshotRecord::Header(_)) => bail!("invalid snapshot: duplicate header"),
+            None => bail!("invalid snapshot: unexpected end of file, the snapshot is truncated"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use accumulator::accumulator_info::AccumulatorInfo;
+    use move_core_types::account_address::AccountAddress;
+    use move_core_types::language_storage::TypeTag;
+    use move_core_types::vm_status::KeptVMStatus;
+    use moveos_types::moveos_std::object::{ObjectID, ObjectMeta};
+    use rooch_types::transaction::{LedgerTxData, RoochTransaction, TransactionSequenceInfo};
+    use tempfile::tempdir;
+
+    #[test]
+    fn snapshot_write_and_read() {
+        let tempdir = tempdir().unwrap();
+        let path = tempdir.path().join("snapshot");
+        let state_root = H256::random();
+        let last_ledger_tx = LedgerTransaction::new(
+            LedgerTxData::L2Tx(RoochTransaction::mock()),
+            TransactionSequenceInfo::new(0, vec![], AccumulatorInfo::default(), 0),
+        );
+        let header = SnapshotHeader {
+            version: SNAPSHOT_VERSION,
+            state_root,
+            genesis_info: None,
+            sequencer_info: SequencerInfo::new(0, AccumulatorInfo::default()),
+            last_execution_info: TransactionExecutionInfo::new(
+                H256::random(),
+                state_root,
+                0,
+                H256::random(),
+                0,
+                KeptVMStatus::Executed,
+            ),
+            last_ledger_tx,
+        };
+        let export_id = ExportID::new(ObjectID::root(), state_root, state_root, 0);
+        let fields = (0..10u64)
+            .map(|i| {
+                let object_id = ObjectID::random();
+                let metadata = ObjectMeta::new(
+                    object_id.clone(),
+                    AccountAddress::ONE,
+                    0,
+                    None,
+                    0,
+                    i,
+                    i,
+                    TypeTag::U64,
+                );
+                (
+                    object_id.field_key(),
+                    ObjectState::new(metadata, bcs::to_bytes(&i).unwrap()),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let header_last_ledger_tx = header.last_ledger_tx.clone();
+        let mut writer = SnapshotWriter::new(&path, header).unwrap();
+        writer.write_object(export_id.clone()).unwrap();
+        for (k, v) in fields.clone() {
+            writer.write_field(k, v).unwrap();
+        }
+        assert_eq!(writer.finish().unwrap().0, 1);
+
+        let mut reader = SnapshotReader::open(&path).unwrap();
+        assert_eq!(reader.header().state_root, state_root);
+        assert_eq!(reader.header().last_ledger_tx, header_last_ledger_tx);
+        match reader.next_record().unwrap() {
+            Some(SnapshotRecord::Object(id)) => assert_eq!(id, export_id),
+            other => panic!("unexpected record: {:?}", other),
+        }
+        for (k, v) in fields {
+            match reader.next_record().unwrap() {
+                Some(SnapshotRecord::Field(rk, rv)) => {
+                    assert_eq!(rk, k);
+                    assert_eq!(rv, v);
+                }
+                other => panic!("unexpected record: {:?}", other),
+            }
+        }
+        assert!(reader.next_record().unwrap().is_none());
+    }
+}
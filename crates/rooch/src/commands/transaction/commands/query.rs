@@ -6,10 +6,11 @@ use async_trait::async_trait;
 use clap::Parser;
 use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionFilterView;
 use rooch_rpc_api::jsonrpc_types::{
-    H256View, QueryOptions, RoochAddressView, TransactionWithInfoPageView,
+    H256View, IndexerTransactionPageView, QueryOptions, RoochAddressView,
 };
 use rooch_rpc_client::wallet_context::WalletContext;
 use rooch_types::error::{RoochError, RoochResult};
+use rooch_types::indexer::cursor::PageCursor;
 
 /// Query transactions
 #[derive(Debug, Parser)]
@@ -17,8 +18,9 @@ pub struct QueryCommand {
     #[clap(flatten)]
     pub filter: TransactionFilterOptions,
 
+    /// The `next_cursor` of the previous page
     #[clap(long)]
-    pub cursor: Option<u64>,
+    pub cursor: Option<PageCursor>,
 
     #[clap(long)]
     pub limit: Option<u64>,
@@ -40,8 +42,8 @@ pub struct QueryCommand {
 }
 
 #[async_trait]
-impl CommandAction<TransactionWithInfoPageView> for QueryCommand {
-    async fn execute(self) -> RoochResult<TransactionWithInfoPageView> {
+impl CommandAction<IndexerTransactionPageView> for QueryCommand {
+    async fn execute(self) -> RoochResult<IndexerTransactionPageView> {
         let context = self.context_options.build()?;
         let client = context.get_client().await?;
 
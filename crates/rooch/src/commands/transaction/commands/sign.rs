@@ -21,23 +21,27 @@ use rooch_types::{
 pub enum SignInput {
     RoochTransactionData(RoochTransactionData),
     PartiallySignedRoochTransaction(PartiallySignedRoochTransaction),
+    /// A transaction signed by the sender, waiting for the fee payer(sponsor) to co-sign
+    SignedRoochTransaction(RoochTransaction),
 }
 
 impl TryFrom<FileOrHexInput> for SignInput {
     type Error = anyhow::Error;
 
     fn try_from(value: FileOrHexInput) -> Result<Self, Self::Error> {
-        let input = match bcs::from_bytes::<RoochTransactionData>(&value.data) {
+        let input = match RoochTransactionData::decode(&value.data) {
             Ok(tx_data) => SignInput::RoochTransactionData(tx_data),
-            Err(_) => {
-                let psrt: PartiallySignedRoochTransaction = match bcs::from_bytes(&value.data) {
-                    Ok(psrt) => psrt,
+            Err(_) => match PartiallySignedRoochTransaction::decode(&value.data) {
+                Ok(psrt) => SignInput::PartiallySignedRoochTransaction(psrt),
+                Err(_) => match RoochTransaction::decode(&value.data) {
+                    Ok(tx) => SignInput::SignedRoochTransaction(tx),
                     Err(_) => {
-                        return Err(anyhow::anyhow!("Invalid tx data or psrt data"));
+                        return Err(anyhow::anyhow!(
+                            "Invalid tx data, psrt data or signed tx data"
+                        ));
                     }
-                };
-                SignInput::PartiallySignedRoochTransaction(psrt)
-            }
+                },
+            },
         };
         Ok(input)
     }
@@ -48,6 +52,7 @@ impl SignInput {
         match self {
             SignInput::RoochTransactionData(tx_data) => tx_data.sender,
             SignInput::PartiallySignedRoochTransaction(psrt) => psrt.sender(),
+            SignInput::SignedRoochTransaction(tx) => tx.sender(),
         }
     }
 }
@@ -86,6 +91,14 @@ pub struct SignCommand {
     #[clap(short = 's', long, value_parser=ParsedAddress::parse)]
     signer: Option<ParsedAddress>,
 
+    /// The address of the fee payer(sponsor) who pays the gas on behalf of the sender.
+    /// If the input is a transaction data, the sender designates the sponsor as fee payer and signs it,
+    /// then the sponsor co-signs it if the sponsor's key is in the keystore.
+    /// If the input is a transaction signed by the sender, the designated fee payer co-signs it,
+    /// the sponsor must be the fee payer designated by the sender if it is specified.
+    #[clap(long, value_parser=ParsedAddress::parse)]
+    sponsor: Option<ParsedAddress>,
+
     /// The output file path for the signed transaction
     /// If not specified, the signed output will write to temp directory.
     #[clap(long, short = 'o')]
@@ -110,14 +123,38 @@ impl SignCommand {
         let multisign_account_module = client.as_module_binding::<MultisignAccountModule>();
         let sign_input = SignInput::try_from(self.input)?;
         let sender = sign_input.sender();
-        let output = if multisign_account_module.is_multisign_account(sender.into())? {
+        let sponsor = self
+            .sponsor
+            .map(|sponsor| context.resolve_rooch_address(sponsor))
+            .transpose()?;
+        let output = if let SignInput::SignedRoochTransaction(tx) = sign_input {
+            let fee_payer = tx.fee_payer().ok_or_else(|| {
+                anyhow::anyhow!("The transaction is already signed and the sender does not designate a fee payer")
+            })?;
+            if let Some(sponsor) = sponsor {
+                if sponsor != fee_payer {
+                    return Err(anyhow::anyhow!(
+                        "The sponsor {} is not the fee payer {} designated by the sender",
+                        sponsor,
+                        fee_payer
+                    ));
+                }
+            }
+            let kp = context.get_key_pair(&fee_payer)?;
+            SignOutput::SignedRoochTransaction(tx.sign_fee_payer(&kp)?)
+        } else if multisign_account_module.is_multisign_account(sender.into())? {
             let threshold = multisign_account_module.threshold(sender.into())?;
 
             let mut psrt = match sign_input {
                 SignInput::RoochTransactionData(tx_data) => {
+                    let tx_data = match sponsor {
+                        Some(sponsor) => tx_data.with_fee_payer(sponsor),
+                        None => tx_data,
+                    };
                     PartiallySignedRoochTransaction::new(tx_data, threshold)
                 }
                 SignInput::PartiallySignedRoochTransaction(psrt) => psrt,
+                SignInput::SignedRoochTransaction(_) => unreachable!(),
             };
             match self.signer {
                 Some(signer) => {
@@ -174,8 +211,20 @@ impl SignCommand {
                         "Cannot sign a partially signed transaction with a single signer"
                     ))
                 }
+                SignInput::SignedRoochTransaction(_) => unreachable!(),
+            };
+            let tx_data = match sponsor {
+                Some(sponsor) => tx_data.with_fee_payer(sponsor),
+                None => tx_data,
             };
-            SignOutput::SignedRoochTransaction(context.sign_transaction(sender, tx_data).await?)
+            let tx = context.sign_transaction(sender, tx_data).await?;
+            match sponsor {
+                Some(sponsor) if context.keystore.contains_address(&sponsor) => {
+                    let kp = context.get_key_pair(&sponsor)?;
+                    SignOutput::SignedRoochTransaction(tx.sign_fee_payer(&kp)?)
+                }
+                _ => SignOutput::SignedRoochTransaction(tx),
+            }
         };
         Ok(output)
     }
@@ -208,6 +257,16 @@ impl SignCommand {
                     pstx.threshold
                 );
             }
+            SignInput::SignedRoochTransaction(tx) => {
+                println!("Signed transaction data:\n{}", tx_data(&tx.data));
+                if let Some(fee_payer) = tx.fee_payer() {
+                    println!(
+                        " Fee payer: {}, signed: {}",
+                        fee_payer,
+                        tx.fee_payer_authenticator().is_some()
+                    );
+                }
+            }
         }
     }
 }
@@ -28,7 +28,7 @@ impl CommandAction<ExecuteTransactionResponseView> for SubmitCommand {
     async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
         let context = self.context.build()?;
 
-        let signed_tx = bcs::from_bytes::<RoochTransaction>(&self.input.data).map_err(|e| {
+        let signed_tx = RoochTransaction::decode(&self.input.data).map_err(|e| {
             RoochError::CommandArgumentError(format!(
                 "Invalid signed transaction hex, err: {:?}, hex: {}",
                 e,
@@ -15,6 +15,7 @@ use moveos_types::transaction::MoveAction;
 use rooch_framework::natives::gas_parameter::gas_member::ToOnChainGasSchedule;
 use rooch_genesis::{FrameworksGasParameters, LATEST_GAS_SCHEDULE_VERSION};
 use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_rpc_client::Client;
 use rooch_types::error::{RoochError, RoochResult};
 use rooch_types::rooch_network::BuiltinChainID;
 use rooch_types::transaction::RoochTransaction;
@@ -176,3 +177,31 @@ impl CommandAction<ExecuteTransactionResponseView> for UpgradeGasConfigCommand {
         }
     }
 }
+
+/// The natives added by a gas schedule release are only registered when their gas entries are onchain,
+/// so the framework which calls them must be upgraded after the gas config.
+pub(crate) async fn ensure_onchain_gas_schedule_upgraded(client: &Client) -> RoochResult<()> {
+    let chain_id = client.rooch.get_chain_id().await?;
+    let gas_schedule = client
+        .as_module_binding::<moveos_types::moveos_std::gas_schedule::GasScheduleModule>()
+        .gas_schedule()
+        .map_err(|_| RoochError::OnchainGasScheduleIsEmpty)?;
+    let onchain_entries = gas_schedule
+        .entries
+        .iter()
+        .map(|gas_entry| (gas_entry.key.to_string(), gas_entry.val))
+        .collect::<BTreeMap<_, _>>();
+
+    let missing_entries =
+        FrameworksGasParameters::latest().missing_gas_entries(chain_id.into(), &onchain_entries);
+    if !missing_entries.is_empty() {
+        for gas_key in missing_entries.iter() {
+            println!("missing gas: {:}", gas_key);
+        }
+        return Err(RoochError::OnchainGasScheduleIsOutdated(
+            missing_entries.len(),
+            LATEST_GAS_SCHEDULE_VERSION,
+        ));
+    }
+    Ok(())
+}
@@ -12,9 +12,10 @@ use clap::builder::{
 use cli_types::CommandAction;
 use commands::{
     abi::ABI, account::Account, bitcoin::Bitcoin, bitseed::Bitseed, dynamic_field::DynamicField,
-    env::Env, genesis::Genesis, init::Init, move_cli::MoveCli, object::ObjectCommand,
-    resource::ResourceCommand, rpc::Rpc, server::Server, session_key::SessionKey,
-    state::StateCommand, transaction::Transaction, upgrade::Upgrade, util::Util, version::Version,
+    env::Env, genesis::Genesis, init::Init, move_cli::MoveCli, multisign::Multisign,
+    object::ObjectCommand, resource::ResourceCommand, rpc::Rpc, server::Server,
+    session_key::SessionKey, state::StateCommand, transaction::Transaction, upgrade::Upgrade,
+    util::Util, version::Version,
 };
 use once_cell::sync::Lazy;
 use rooch_types::error::RoochResult;
@@ -52,6 +53,7 @@ pub enum Command {
     Bitseed(Bitseed),
     Init(Init),
     Move(MoveCli),
+    Multisign(Multisign),
     Server(Server),
     State(StateCommand),
     Object(ObjectCommand),
@@ -79,6 +81,7 @@ pub async fn run_cli(opt: RoochCli) -> RoochResult<String> {
         Command::Bitcoin(bitcoin) => bitcoin.execute().await,
         Command::Bitseed(bitseed) => bitseed.execute().await,
         Command::Move(move_cli) => move_cli.execute().await,
+        Command::Multisign(multisign) => multisign.execute().await,
         Command::Server(server) => server.execute().await,
         Command::Init(init) => init.execute_serialized().await,
         Command::State(state) => state.execute_serialized().await,
@@ -15,7 +15,8 @@ static GLOBAL: Jemalloc = Jemalloc;
 /// rooch is a command line tools for Rooch Network
 #[tokio::main]
 async fn main() {
-    let _ = tracing_subscriber::fmt::try_init();
+    // The OpenTelemetry layer of the subscriber is enabled when the server starts with an OTLP endpoint
+    let _ = rooch_rpc_server::telemetry::init_subscriber();
 
     let opt = RoochCli::parse();
     let result = rooch::run_cli(opt).await;
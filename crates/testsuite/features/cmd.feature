@@ -67,14 +67,34 @@ Feature: Rooch CLI integration tests
       Then cmd: "session-key create  --app-name test --app-url https:://test.rooch.network --scope 0x3::empty::empty"
       Then cmd: "session-key list"
       Then assert: "'{{$.session-key[-1]}}' not_contains error"
-      Then cmd: "move run --function 0x3::empty::empty  --session-key {{$.session-key[-1][0].name}} --json"
-      Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
+      Then cmd: "move run --function 0x3::empty::empty  --session-key {{$.session-key[-1][0].authentication_key}} --json"
+      Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
+      Then cmd: "session-key inspect --authentication-key {{$.session-key[-1][0].authentication_key}}"
+      Then assert: "{{$.session-key[-1].expired}} == false"
+      Then cmd: "rpc request --method rooch_getSessionKeys --params '["{{$.address_mapping.default}}"]' --json"
+      Then assert: "'{{$.rpc[-1]}}' not_contains error"
+      Then cmd: "session-key remove --authentication-key {{$.session-key[-1].authentication_key}}"
+      Then assert: "{{$.session-key[-1].execution_info.status.type}} == executed"
+      Then cmd: "session-key clean"
+      Then assert: "'{{$.session-key[-1]}}' not_contains error"
+
+      # txpool
+      Then cmd: "rpc request --method rooch_getPendingTransactions --params '["{{$.address_mapping.default}}"]' --json"
+      Then assert: "'{{$.rpc[-1]}}' not_contains error"
+      Then cmd: "rpc request --method rooch_estimateGasPrice --json"
+      Then assert: "{{$.rpc[-1].base_fee}} == 1"
 
       # transaction
       Then cmd: "transaction get-transactions-by-order --cursor 0 --limit 1 --descending-order false"
       Then cmd: "transaction get-transactions-by-hash --hashes {{$.transaction[-1].data[0].execution_info.tx_hash}}"
       Then cmd: "transaction build --function rooch_framework::empty::empty --json"
       Then assert: "'{{$.transaction[-1]}}' not_contains error"
+      Then cmd: "rpc request --method rooch_estimateGas --params '["{{$.transaction[-1].content}}"]' --json"
+      Then assert: "'{{$.rpc[-1]}}' not_contains error"
+      Then assert: "{{$.rpc[-1].status.type}} == executed"
+      Then assert: "{{$.rpc[-1].gas_used}} != 0"
+      Then assert: "{{$.rpc[-1].execution_gas_used}} != 0"
+      Then assert: "{{$.rpc[-1].io_gas_used}} != 0"
       Then cmd: "transaction sign {{$.transaction[-1].path}} --json -y"
       Then assert: "'{{$.transaction[-1]}}' not_contains error"
       Then cmd: "transaction submit {{$.transaction[-1].path}}"
@@ -160,15 +180,15 @@ Feature: Rooch CLI integration tests
     # genesis tx does not write indexer
     Then cmd: "rpc request --method rooch_queryTransactions --params '[{"tx_order_range":{"from_order":0,"to_order":2}}, null, "1", {"descending": true,"showDisplay":false}]' --json"
     Then assert: "{{$.rpc[-1].data[0].transaction.sequence_info.tx_order}} == 1"
-    Then assert: "{{$.rpc[-1].next_cursor}} == 1"
+    Then assert: "{{$.rpc[-1].next_cursor}} == 010000000000000000000100000000000000"
     Then assert: "{{$.rpc[-1].has_next_page}} == true"
-    Then cmd: "rpc request --method rooch_queryTransactions --params '[{"tx_order_range":{"from_order":0,"to_order":2}}, "1", "1", {"descending": true,"showDisplay":false}]' --json"
+    Then cmd: "rpc request --method rooch_queryTransactions --params '[{"tx_order_range":{"from_order":0,"to_order":2}}, "{{$.rpc[-1].next_cursor}}", "1", {"descending": true,"showDisplay":false}]' --json"
     Then assert: "{{$.rpc[-1].data[0].transaction.sequence_info.tx_order}} == 0"
-    Then assert: "{{$.rpc[-1].next_cursor}} == 0"
+    Then assert: "{{$.rpc[-1].next_cursor}} == 010000000000000000000000000000000000"
     Then assert: "{{$.rpc[-1].has_next_page}} == false"
     Then cmd: "rpc request --method rooch_queryEvents --params '[{"tx_order_range":{"from_order":0, "to_order":2}}, null, "20", {"descending": true,"showDisplay":false}]' --json"
     Then assert: "{{$.rpc[-1].data[0].indexer_event_id.tx_order}} == 1"
-    Then assert: "{{$.rpc[-1].next_cursor.tx_order}} == 0"
+    Then assert: "{{$.rpc[-1].data[-1].indexer_event_id.tx_order}} == 0"
     Then assert: "{{$.rpc[-1].has_next_page}} == false"
 
     # Sync states
@@ -180,11 +200,6 @@ Feature: Rooch CLI integration tests
     Then cmd: "rpc request --method rooch_listFieldStates --params '["{{$.address_mapping.default}}", null, "10", {"descending": true,"showDisplay":false}]' --json"
     Then assert: "{{$.rpc[-1].has_next_page}} == false"
 
-#    Then cmd: "rpc request --method rooch_syncStates --params '[null, null, "2", false]' --json"
-#    Then assert: "{{$.rpc[-1].data[0].tx_order}} == 0"
-#    Then assert: "{{$.rpc[-1].next_cursor.state_index}} == 1"
-#    Then assert: "{{$.rpc[-1].has_next_page}} == true"
-
     Then stop the server
 
   @serial
@@ -270,12 +285,27 @@ Feature: Rooch CLI integration tests
       Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
       Then cmd: "move run --function default::entry_function::emit_mix --args 3u8 --args "vector<object_id>:0x2342,0x3132"  --json"
       Then assert: "'{{$.move[-1]}}' contains FUNCTION_RESOLUTION_FAILURE"
+
+      # verify the source and check the upgrade before publishing
+      Then cmd: "move verify-source -p ../../examples/entry_function_arguments_old/  --named-addresses rooch_examples=default --json"
+      Then assert: "{{$.move[-1].modules[0].status}} == matched"
+      Then cmd: "move verify-source -p ../../examples/entry_function_arguments/  --named-addresses rooch_examples=default --json"
+      Then assert: "'{{$.move[-1]}}' contains 'entry_function: Mismatched'"
+      Then cmd: "move check-upgrade -p ../../examples/entry_function_arguments/  --named-addresses rooch_examples=default --json"
+      Then assert: "{{$.move[-1].modules[0].status}} == compatible"
+
       Then cmd: "move publish -p ../../examples/entry_function_arguments/  --named-addresses rooch_examples=default --json"
       Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
+      Then cmd: "move verify-source -p ../../examples/entry_function_arguments/  --named-addresses rooch_examples=default --json"
+      Then assert: "{{$.move[-1].modules[0].status}} == matched"
+      Then cmd: "move check-upgrade -p ../../examples/entry_function_arguments/  --named-addresses rooch_examples=default --json"
+      Then assert: "{{$.move[-1].modules[0].status}} == unchanged"
       Then cmd: "move run --function default::entry_function::emit_mix --args 3u8 --args "vector<object_id>:0x2342,0x3132"  --json"
       Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
 
       # check compatibility
+      Then cmd: "move check-upgrade -p ../../examples/entry_function_arguments_old/  --named-addresses rooch_examples=default --json"
+      Then assert: "'{{$.move[-1]}}' contains 'Modules are incompatible'"
       Then cmd: "move publish -p ../../examples/entry_function_arguments_old/  --named-addresses rooch_examples=default --json"
       Then assert: "'{{$.move[-1].execution_info.status.type}}' == 'moveabort'"
 
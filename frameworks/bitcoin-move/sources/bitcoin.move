@@ -19,8 +19,10 @@ module bitcoin_move::bitcoin{
     use rooch_framework::bitcoin_address::BitcoinAddress;
     
     use bitcoin_move::network;
+    use bitcoin_move::pow;
     use bitcoin_move::types::{Self, Block, Header, Transaction, BlockHeightHash, OutPoint};
-    use bitcoin_move::utxo::{Self, UTXOSeal};
+    use bitcoin_move::utxo::{Self, UTXO, UTXOSeal, SealOut};
+    use bitcoin_move::runes;
     use bitcoin_move::pending_block::{Self, PendingBlock};
     use bitcoin_move::script_buf;
 
@@ -32,6 +34,20 @@ module bitcoin_move::bitcoin{
     /// The reorg is too deep, we need to stop the system and fix the issue
     const ErrorReorgTooDeep:u64 = 3;
     const ErrorUTXONotExists:u64 = 4;
+    /// The block hash is not the hash of the block header
+    const ErrorBlockHashMismatch:u64 = 5;
+    /// The block hash is greater than the target of the block header
+    const ErrorInvalidProofOfWork:u64 = 6;
+    /// The bits of the block header does not follow the difficulty adjustment rules
+    const ErrorInvalidDifficulty:u64 = 7;
+    /// The block time is not greater than the median time of the previous blocks
+    const ErrorInvalidBlockTime:u64 = 8;
+    /// The previous block of the block is unknown
+    const ErrorUnknownPrevBlock:u64 = 9;
+    /// The block height is not the height of the previous block plus one
+    const ErrorInvalidBlockHeight:u64 = 10;
+    /// The first block of the previous difficulty adjustment period is unknown
+    const ErrorUnknownPeriodFirstBlock:u64 = 11;
 
     const ORDINAL_GENESIS_HEIGHT:u64 = 767430;
     /// https://github.com/bitcoin/bips/blob/master/bip-0034.mediawiki
@@ -109,7 +125,7 @@ module bitcoin_move::bitcoin{
     fun process_tx(btc_block_store: &mut BitcoinBlockStore, pblock: &mut Object<PendingBlock>, tx: &Transaction, is_coinbase: bool){
         let block_height = pending_block::block_height(pblock);
         let txid = types::tx_id(tx);
-        let repeat_txid = process_utxo(block_height, pblock, tx, is_coinbase);
+        let repeat_txid = process_utxo(freeze(btc_block_store), block_height, pblock, tx, is_coinbase);
         
         if (repeat_txid) {
             table::upsert(&mut btc_block_store.txs, txid, *tx);
@@ -123,7 +139,7 @@ module bitcoin_move::bitcoin{
         }
     }
 
-    fun process_utxo(block_height: u64, pending_block: &mut Object<PendingBlock>, tx: &Transaction, is_coinbase: bool) : bool{
+    fun process_utxo(btc_block_store: &BitcoinBlockStore, block_height: u64, pending_block: &mut Object<PendingBlock>, tx: &Transaction, is_coinbase: bool) : bool{
         let txinput = types::tx_input(tx);
         let input_utxos = vector::empty();
 
@@ -166,17 +182,22 @@ module bitcoin_move::bitcoin{
         };
         //temporary pause the ordinals process for the performance reason
         let skip_ordinals = block_height >= ORDINALS_PAUSE_HEIGHT && network::is_mainnet() && rooch_framework::chain_id::is_main();
-        if(!skip_ordinals){
-            let seal_outs = bitcoin_move::inscription_updater::process_tx(pending_block, tx, &mut input_utxos);
-            let seal_outs_len = vector::length(&seal_outs);
-            if (seal_outs_len > 0) {
-                let seal_out_idx = 0;
-                while (seal_out_idx < seal_outs_len) {
-                    let seal_out = vector::pop_back(&mut seal_outs);
-                    let (output_index, utxo_seal) = utxo::unpack_seal_out(seal_out);
-                    simple_multimap::add(&mut output_seals, output_index, utxo_seal);
-                    seal_out_idx = seal_out_idx + 1;
-                };
+        let seal_outs = if(!skip_ordinals){
+            bitcoin_move::inscription_updater::process_tx(pending_block, tx, &mut input_utxos)
+        }else{
+            vector::empty()
+        };
+        //The runes are not paused with the ordinals, the rune balances can not be recovered if the transactions are skipped
+        let rune_seal_outs = process_runes(btc_block_store, block_height, pending_block, tx, &mut input_utxos);
+        vector::append(&mut seal_outs, rune_seal_outs);
+        let seal_outs_len = vector::length(&seal_outs);
+        if (seal_outs_len > 0) {
+            let seal_out_idx = 0;
+            while (seal_out_idx < seal_outs_len) {
+                let seal_out = vector::pop_back(&mut seal_outs);
+                let (output_index, utxo_seal) = utxo::unpack_seal_out(seal_out);
+                simple_multimap::add(&mut output_seals, output_index, utxo_seal);
+                seal_out_idx = seal_out_idx + 1;
             };
         };
     
@@ -251,11 +272,101 @@ module bitcoin_move::bitcoin{
     }
 
 
+    /// Get the header via block_hash from the pending blocks or the processed blocks
+    fun get_header(btc_block_store: &BitcoinBlockStore, block_hash: address): Option<Header>{
+        let header = pending_block::get_pending_block_header(block_hash);
+        if(option::is_none(&header) && table::contains(&btc_block_store.blocks, block_hash)){
+            header = option::some(*table::borrow(&btc_block_store.blocks, block_hash));
+        };
+        header
+    }
+
+    /// Get the height of the block via block_hash from the pending blocks or the processed blocks
+    fun get_block_height(btc_block_store: &BitcoinBlockStore, block_hash: address): Option<u64>{
+        let block_height = pending_block::get_pending_block_height(block_hash);
+        if(option::is_none(&block_height) && table::contains(&btc_block_store.hash_to_height, block_hash)){
+            block_height = option::some(*table::borrow(&btc_block_store.hash_to_height, block_hash));
+        };
+        block_height
+    }
+
+    /// Walk back from the block at `block_height` to the last block which is not a min difficulty block in the difficulty period,
+    /// like the GetNextWorkRequired of Bitcoin Core on testnet, and return its bits.
+    /// Return none if the walk reaches the start block or an unknown block.
+    fun last_non_min_difficulty_bits(btc_block_store: &BitcoinBlockStore, network: u8, block_height: u64, header: Header, start_height: u64): Option<u32>{
+        while(pow::is_min_difficulty_walk_back(network, block_height, &header)){
+            if(block_height == start_height){
+                return option::none()
+            };
+            let prev_header = get_header(btc_block_store, types::prev_blockhash(&header));
+            if(option::is_none(&prev_header)){
+                return option::none()
+            };
+            header = option::destroy_some(prev_header);
+            block_height = block_height - 1;
+        };
+        option::some(types::bits(&header))
+    }
+
+    /// Validate the block header before accepting the block.
+    /// The proof-of-work and the pow limit are always checked.
+    /// The previous block must be known except the genesis block and the start block of the chain(the genesis block of the BitcoinBlockStore),
+    /// and the difficulty and median-time-past rules are checked with the previous blocks since the start block.
+    fun validate_block_header(block_height: u64, block_hash: address, header: &Header){
+        assert!(types::header_to_hash(header) == block_hash, ErrorBlockHashMismatch);
+        let network = network::network();
+        assert!(pow::check_proof_of_work(network, header), ErrorInvalidProofOfWork);
+
+        let btc_block_store_obj = borrow_block_store();
+        let btc_block_store = object::borrow(btc_block_store_obj);
+        let (start_height, _) = types::unpack_block_height_hash(btc_block_store.genesis_block);
+        if(block_height == 0 || block_height == start_height){
+            return
+        };
+        let prev_blockhash = types::prev_blockhash(header);
+        let prev_height = get_block_height(btc_block_store, prev_blockhash);
+        assert!(option::is_some(&prev_height), ErrorUnknownPrevBlock);
+        assert!(option::destroy_some(prev_height) + 1 == block_height, ErrorInvalidBlockHeight);
+        let prev_header = option::destroy_some(get_header(btc_block_store, prev_blockhash));
+
+        let interval = pow::difficulty_adjustment_interval();
+        // The first block of the previous period is only unknown if the period starts before the start block
+        let period_first_header = if(pow::is_retarget_height(block_height) && block_height - interval >= start_height){
+            assert!(table::contains(&btc_block_store.height_to_hash, block_height - interval), ErrorUnknownPeriodFirstBlock);
+            let first_block_hash = *table::borrow(&btc_block_store.height_to_hash, block_height - interval);
+            option::some(*table::borrow(&btc_block_store.blocks, first_block_hash))
+        }else{
+            option::none()
+        };
+        let last_bits = if(network == network::network_testnet() && !pow::is_retarget_height(block_height)){
+            last_non_min_difficulty_bits(btc_block_store, network, block_height - 1, prev_header, start_height)
+        }else{
+            option::none()
+        };
+        assert!(pow::check_difficulty(network, block_height, header, &prev_header, period_first_header, last_bits), ErrorInvalidDifficulty);
+
+        // The previous blocks are known back to the start block
+        let prev_times = vector::empty<u64>();
+        let current = option::some(prev_header);
+        while(option::is_some(&current) && vector::length(&prev_times) < pow::median_time_span()){
+            let current_header = option::destroy_some(current);
+            vector::push_back(&mut prev_times, (types::time(&current_header) as u64));
+            current = get_header(btc_block_store, types::prev_blockhash(&current_header));
+        };
+        let required_times = block_height - start_height;
+        if(required_times > pow::median_time_span()){
+            required_times = pow::median_time_span();
+        };
+        assert!(vector::length(&prev_times) >= required_times, ErrorUnknownPrevBlock);
+        assert!(pow::check_median_time_past(header, prev_times), ErrorInvalidBlockTime);
+    }
+
     /// The the sequencer submit a new Bitcoin block to execute
     /// This function is a system function, is the execute_l1_block entry point
     fun execute_l1_block(block_height: u64, block_hash: address, block_bytes: vector<u8>){
         let block = bcs::from_bytes<Block>(block_bytes);
         let block_header = types::header(&block);
+        validate_block_header(block_height, block_hash, block_header);
         let time = types::time(block_header);
         pending_block::add_pending_block(block_height, block_hash, block);
         //We directly update the global time do not wait the pending block to be confirmed
@@ -283,6 +394,38 @@ module bitcoin_move::bitcoin{
         };
     }
 
+    fun process_runes(btc_block_store: &BitcoinBlockStore, block_height: u64, pending_block: &mut Object<PendingBlock>, tx: &Transaction, input_utxos: &mut vector<UTXO>): vector<SealOut>{
+        // There are no runes before the activation height, skip deciphering the runestone
+        if (block_height < network::first_rune_height()) {
+            return vector::empty()
+        };
+        let runestone = runes::decipher_runestone(tx);
+        // Find the outputs spent by the inputs which commit to the etched rune,
+        // the runes module checks the output type and the confirmations of the commitment.
+        let commit_inputs = runes::etching_commitment_inputs(tx, &runestone);
+        let txinput = types::tx_input(tx);
+        let commit_outputs = vector::empty();
+        let idx = 0;
+        let len = vector::length(&commit_inputs);
+        while (idx < len) {
+            let input_idx = *vector::borrow(&commit_inputs, idx);
+            let outpoint = *types::txin_previous_output(vector::borrow(txinput, input_idx));
+            let commit_txid = types::outpoint_txid(&outpoint);
+            let commit_output = if (table::contains(&btc_block_store.txs, commit_txid)) {
+                let commit_tx = table::borrow(&btc_block_store.txs, commit_txid);
+                let txout = vector::borrow(types::tx_output(commit_tx), (types::outpoint_vout(&outpoint) as u64));
+                let commit_height = *table::borrow(&btc_block_store.tx_to_height, commit_txid);
+                runes::new_commit_output(option::some(*types::txout_script_pubkey(txout)), option::some(commit_height))
+            } else {
+                // The commit transaction is before the block that Rooch starts to sync
+                runes::new_commit_output(option::none(), option::none())
+            };
+            vector::push_back(&mut commit_outputs, commit_output);
+            idx = idx + 1;
+        };
+        runes::process_tx(pending_block, tx, input_utxos, runestone, commit_outputs)
+    }
+
     public fun get_tx(txid: address): Option<Transaction>{
         let btc_block_store_obj = borrow_block_store();
         let btc_block_store = object::borrow(btc_block_store_obj);
@@ -6,8 +6,10 @@ module bitcoin_move::genesis{
     use moveos_std::tx_context;
     use moveos_std::signer;
     use rooch_framework::bitcoin_address::{Self, BitcoinAddress};
+    use rooch_framework::webauthn_validator;
     use bitcoin_move::bitcoin;
     use bitcoin_move::ord;
+    use bitcoin_move::runes;
     use bitcoin_move::utxo;
     use bitcoin_move::network;
     use bitcoin_move::pending_block;
@@ -40,9 +42,11 @@ module bitcoin_move::genesis{
         network::genesis_init(genesis_context.network);
         utxo::genesis_init();
         ord::genesis_init();
+        runes::genesis_init();
         bitcoin::genesis_init(&genesis_account, genesis_context.genesis_block_height, genesis_context.genesis_block_hash);
         pending_block::genesis_init(genesis_context.reorg_block_count);
         bitcoin_multisign_validator::genesis_init();
+        webauthn_validator::init_for_upgrade();
         let rooch_dao_address = multisign_account::initialize_multisig_account(genesis_context.rooch_dao.threshold, genesis_context.rooch_dao.participant_public_keys);
         assert!(rooch_dao_address == bitcoin_address::to_rooch_address(&genesis_context.rooch_dao.multisign_bitcoin_address), ErrorInvalidRoochDaoAddress);
     }
@@ -13,8 +13,10 @@ module bitcoin_move::multisign_account{
     use moveos_std::sort;
     use moveos_std::simple_map::{Self, SimpleMap};
     use moveos_std::result;
+    use moveos_std::hash;
     
     use rooch_framework::ecdsa_k1;
+    use rooch_framework::schnorr;
     use rooch_framework::bitcoin_address::{Self, BitcoinAddress};
     use rooch_framework::address_mapping;
     
@@ -235,16 +237,23 @@ module bitcoin_move::multisign_account{
         participant.participant_address
     }
 
-    fun verify_bitcoin_signature(tx_id: address, signature: &vector<u8>, public_key: &vector<u8>) {
-        assert!(
-            ecdsa_k1::verify(
-                signature,
-                public_key,
-                &bcs::to_bytes(&tx_id),
-                ecdsa_k1::sha256()
-            ),
-            ErrorInvalidSignature
-        );
+    /// Verify the ECDSA signature or the BIP-340 Schnorr signature of the taproot wallet.
+    /// Both signatures are signed against the sha256 digest of the tx_id.
+    public fun verify_bitcoin_signature(tx_id: address, signature: &vector<u8>, public_key: &vector<u8>): bool {
+        if (vector::length(public_key) != BITCOIN_COMPRESSED_PUBLIC_KEY_LEN) {
+            return false
+        };
+        let message = bcs::to_bytes(&tx_id);
+        ecdsa_k1::verify(
+            signature,
+            public_key,
+            &message,
+            ecdsa_k1::sha256()
+        ) || schnorr::verify(
+            signature,
+            &vector::slice(public_key, 1, BITCOIN_COMPRESSED_PUBLIC_KEY_LEN),
+            &hash::sha2_256(message)
+        )
     }
 
     fun borrow_mut_or_create_account(multisign_address: address, multisign_bitcoin_address: BitcoinAddress) : &mut Object<Account> {
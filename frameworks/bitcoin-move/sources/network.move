@@ -146,6 +146,23 @@ module bitcoin_move::network{
         }
     }
 
+    /// Runes protocol activation height.
+    /// https://github.com/ordinals/ord/blob/0.19.1/crates/ordinals/src/rune.rs#L46-L58
+    public fun first_rune_height() : u64 {
+        let n = network();
+        if (n == NETWORK_BITCOIN) {
+            840000
+        } else if (n == NETWORK_REGTEST) {
+            0
+        } else if (n == NETWORK_SIGNET) {
+            0
+        } else if (n == NETWORK_TESTNET) {
+            2520000
+        } else {
+            abort ErrorUnknownNetwork
+        }
+    }
+
     /// Block Rewards
     public fun subsidy_by_height(height: u64): u64 {
         let epoch = (height as u32) / SUBSIDY_HALVING_INTERVAL;
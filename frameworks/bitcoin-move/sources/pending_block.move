@@ -20,6 +20,7 @@ module bitcoin_move::pending_block{
     friend bitcoin_move::genesis;
     friend bitcoin_move::bitcoin;
     friend bitcoin_move::inscription_updater;
+    friend bitcoin_move::runes;
 
     const ErrorBlockAlreadyProcessed:u64 = 1;
     const ErrorPendingBlockNotFound:u64 = 2;
@@ -212,6 +213,14 @@ module bitcoin_move::pending_block{
         block.block_height
     }
 
+    /// Returns the index of the tx in the block
+    public(friend) fun tx_index(pending_block: &Object<PendingBlock>, txid: address): u32{
+        let tx_ids: &vector<address> = object::borrow_field(pending_block, TX_IDS_KEY);
+        let (found, idx) = vector::index_of(tx_ids, &txid);
+        assert!(found, ErrorPendingTxNotFound);
+        (idx as u32)
+    }
+
     /// The intermediate is used to store the intermediate state during the tx processing
     public(friend) fun take_intermediate<I: store>(pending_block: &mut Object<PendingBlock>): I{
         let intermediate_name = type_info::type_name<I>();
@@ -319,6 +328,24 @@ module bitcoin_move::pending_block{
         option::some(pending_txs)
     }
 
+    /// Get the header of the pending block via block_hash
+    public fun get_pending_block_header(block_hash: address): Option<Header>{
+        if(!exists_pending_block(block_hash)){
+            return option::none()
+        };
+        let block_obj = borrow_pending_block(block_hash);
+        option::some(object::borrow(block_obj).header)
+    }
+
+    /// Get the height of the pending block via block_hash
+    public fun get_pending_block_height(block_hash: address): Option<u64>{
+        if(!exists_pending_block(block_hash)){
+            return option::none()
+        };
+        let block_obj = borrow_pending_block(block_hash);
+        option::some(object::borrow(block_obj).block_height)
+    }
+
     public fun get_best_block(): Option<BlockHeightHash>{
         let store = borrow_store();
         *&store.best_block
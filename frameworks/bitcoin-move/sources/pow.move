@@ -0,0 +1,265 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// Bitcoin proof-of-work and difficulty rules, used to validate the block headers submitted by the relayer.
+module bitcoin_move::pow{
+    use std::option::{Self, Option};
+    use std::vector;
+    use moveos_std::sort;
+    use bitcoin_move::network;
+    use bitcoin_move::types::{Self, Header};
+
+    /// The number of blocks between two difficulty adjustments.
+    const DIFFICULTY_ADJUSTMENT_INTERVAL: u64 = 2016;
+    /// The number of previous blocks used to calculate the median time past.
+    const MEDIAN_TIME_SPAN: u64 = 11;
+    /// The expected time between two blocks, in seconds.
+    const TARGET_SPACING: u64 = 600;
+
+    const ErrorEmptyTimes: u64 = 1;
+
+    public fun difficulty_adjustment_interval(): u64 {
+        DIFFICULTY_ADJUSTMENT_INTERVAL
+    }
+
+    public fun median_time_span(): u64 {
+        MEDIAN_TIME_SPAN
+    }
+
+    public fun target_spacing(): u64 {
+        TARGET_SPACING
+    }
+
+    /// Returns true if the block at `block_height` is the first block of a difficulty adjustment period
+    public fun is_retarget_height(block_height: u64): bool {
+        block_height % DIFFICULTY_ADJUSTMENT_INTERVAL == 0
+    }
+
+    /// Check the `bits` of the header at `block_height` follows the difficulty rules of the network.
+    /// `period_first_header` is the header at `block_height - 2016`, it is only used at the retarget height.
+    /// If it is not available(the chain is synced from a later block), the retarget check is skipped.
+    /// `last_bits` is the bits of the last block which is not a min difficulty block in the current period,
+    /// it is only used by the testnet, see `is_min_difficulty_walk_back`. If it is none(the blocks are unknown back to the start block),
+    /// the transition from or to the min difficulty is accepted.
+    public fun check_difficulty(network: u8, block_height: u64, header: &Header, prev_header: &Header, period_first_header: Option<Header>, last_bits: Option<u32>): bool {
+        // The regtest network does not retarget, and allows min difficulty blocks
+        if (network == network::network_regtest()) {
+            return true
+        };
+        let bits = types::bits(header);
+        let prev_bits = types::bits(prev_header);
+        if (is_retarget_height(block_height)) {
+            if (option::is_none(&period_first_header)) {
+                return true
+            };
+            let first_header = option::destroy_some(period_first_header);
+            let last_time = (types::time(prev_header) as u64);
+            let first_time = (types::time(&first_header) as u64);
+            let timespan = if (last_time > first_time) { last_time - first_time } else { 0 };
+            return bits == calculate_next_bits(network, prev_bits, timespan)
+        };
+        if (network == network::network_testnet()) {
+            let pow_limit = pow_limit_bits(network);
+            // The testnet requires a min difficulty block if no block was mined in 20 minutes
+            if ((types::time(header) as u64) > (types::time(prev_header) as u64) + 2 * TARGET_SPACING) {
+                return bits == pow_limit
+            };
+            // Otherwise the block returns to the difficulty of the last block which is not a min difficulty block
+            if (option::is_none(&last_bits)) {
+                return bits == prev_bits || bits == pow_limit || prev_bits == pow_limit
+            };
+            return bits == option::destroy_some(last_bits)
+        };
+        bits == prev_bits
+    }
+
+    /// Returns true if the walk back for the `last_bits` of `check_difficulty` should continue from the block at `block_height` to its previous block,
+    /// like the GetNextWorkRequired of Bitcoin Core, the walk stops at the first block of the period or a block which is not a min difficulty block.
+    public fun is_min_difficulty_walk_back(network: u8, block_height: u64, header: &Header): bool {
+        !is_retarget_height(block_height) && types::bits(header) == pow_limit_bits(network)
+    }
+
+    /// Check the time of the header is greater than the median time of the previous `MEDIAN_TIME_SPAN` blocks.
+    /// `prev_times` is the time of the previous blocks, it contains less blocks only near the start of the chain.
+    public fun check_median_time_past(header: &Header, prev_times: vector<u64>): bool {
+        if (vector::is_empty(&prev_times)) {
+            return true
+        };
+        (types::time(header) as u64) > median_time(prev_times)
+    }
+
+    /// Returns the median of the `times`
+    public fun median_time(times: vector<u64>): u64 {
+        let len = vector::length(&times);
+        assert!(len > 0, ErrorEmptyTimes);
+        sort::sort(&mut times);
+        *vector::borrow(&times, len / 2)
+    }
+
+    /// Returns the target of the compact `bits`
+    public native fun bits_to_target(bits: u32): u256;
+
+    /// Returns true if the target of the header's `bits` is valid(not negative, zero or overflow) and not greater than
+    /// the pow limit of the network, and the block hash of the header is not greater than the target.
+    public native fun check_proof_of_work(network: u8, header: &Header): bool;
+
+    /// Calculate the `bits` of the first block in a new difficulty adjustment period
+    /// `last_bits` is the bits of the last block of the previous period
+    /// `timespan` is the time between the first and the last block of the previous period, in seconds
+    public native fun calculate_next_bits(network: u8, last_bits: u32, timespan: u64): u32;
+
+    /// Returns the `bits` of the max attainable target(the lowest difficulty) of the network
+    public native fun pow_limit_bits(network: u8): u32;
+
+    #[test_only]
+    use bitcoin_move::bitcoin_hash;
+
+    #[test_only]
+    fun mainnet_header_91812(): Header {
+        //https://mempool.space/block/00000000000af0aed4792b1acee3d966af36cf5def14935db8de83d6f9306f2f
+        types::new_header_for_test(
+            1,
+            bitcoin_hash::from_ascii_bytes(&b"000000000002afe839294d4e038b5c831bc09632fd717c0980f8f216dc2b360f"),
+            bitcoin_hash::from_ascii_bytes(&b"49991d7653bec6efebee7d11f27ca2dffcc35ebe95ee5eebd602916b2f2fa665"),
+            1289757588,
+            0x1b0e7256,
+            1166692788,
+        )
+    }
+
+    #[test]
+    fun test_check_proof_of_work() {
+        let header = mainnet_header_91812();
+        assert!(check_proof_of_work(network::network_bitcoin(), &header), 1);
+        assert!(types::header_to_hash(&header) == bitcoin_hash::from_ascii_bytes(&b"00000000000af0aed4792b1acee3d966af36cf5def14935db8de83d6f9306f2f"), 2);
+
+        //https://mempool.space/block/000000000000000000020750f322f4e72e99c2f0b9738fb4f46607860bd18c13
+        let header = types::new_header_for_test(
+            536870912,
+            bitcoin_hash::from_ascii_bytes(&b"00000000000000000001a2dd0a20944de8b464ac51af80d7cfdab3a69791e13d"),
+            bitcoin_hash::from_ascii_bytes(&b"2b0ea021b4c16409402d7969da590907389a8042dd4b6545077f6f37e76f54c6"),
+            1701075201,
+            0x17042450,
+            1616659018,
+        );
+        assert!(check_proof_of_work(network::network_bitcoin(), &header), 3);
+
+        let bad_header = types::new_header_for_test(
+            536870912,
+            bitcoin_hash::from_ascii_bytes(&b"00000000000000000001a2dd0a20944de8b464ac51af80d7cfdab3a69791e13d"),
+            bitcoin_hash::from_ascii_bytes(&b"2b0ea021b4c16409402d7969da590907389a8042dd4b6545077f6f37e76f54c6"),
+            1701075201,
+            0x17042450,
+            1616659019,
+        );
+        assert!(!check_proof_of_work(network::network_bitcoin(), &bad_header), 4);
+    }
+
+    #[test]
+    fun test_check_proof_of_work_pow_limit() {
+        // The hash meets the target, but the target is above the pow limit of mainnet
+        let header = types::new_header_for_test(1, @0x1, @0x2, 1289757600, 0x207fffff, 0);
+        let nonce = 0;
+        while (!check_proof_of_work(network::network_regtest(), &header)) {
+            nonce = nonce + 1;
+            header = types::new_header_for_test(1, @0x1, @0x2, 1289757600, 0x207fffff, nonce);
+        };
+        assert!(!check_proof_of_work(network::network_bitcoin(), &header), 1);
+
+        // The negative target is invalid
+        let header = types::new_header_for_test(1, @0x1, @0x2, 1289757600, 0x20ffffff, 0);
+        assert!(!check_proof_of_work(network::network_regtest(), &header), 2);
+        // The zero target is invalid
+        let header = types::new_header_for_test(1, @0x1, @0x2, 1289757600, 0, 0);
+        assert!(!check_proof_of_work(network::network_regtest(), &header), 3);
+    }
+
+    #[test]
+    fun test_bits_to_target() {
+        assert!(bits_to_target(0x1d00ffff) == 0xffff << 208, 1);
+        assert!(pow_limit_bits(network::network_bitcoin()) == 0x1d00ffff, 2);
+        assert!(pow_limit_bits(network::network_regtest()) == 0x207fffff, 3);
+    }
+
+    #[test]
+    fun test_check_difficulty() {
+        let prev_header = mainnet_header_91812();
+        let header = types::new_header_for_test(1, @0x1, @0x2, 1289757600, 0x1b0e7256, 0);
+        assert!(check_difficulty(network::network_bitcoin(), 91813, &header, &prev_header, option::none(), option::none()), 1);
+
+        let easier_header = types::new_header_for_test(1, @0x1, @0x2, 1289757600, 0x1d00ffff, 0);
+        assert!(!check_difficulty(network::network_bitcoin(), 91813, &easier_header, &prev_header, option::none(), option::none()), 2);
+        // The last block which is not a min difficulty block is unknown, the transition to the min difficulty is accepted
+        assert!(check_difficulty(network::network_testnet(), 91813, &easier_header, &prev_header, option::none(), option::none()), 3);
+
+        // The blocks of the previous period were mined twice as fast as expected, the difficulty doubles
+        let first_header = types::new_header_for_test(1, @0x1, @0x2, 1000000000, 0x1d00ffff, 0);
+        let last_header = types::new_header_for_test(1, @0x1, @0x2, 1000000000 + 604800, 0x1d00ffff, 0);
+        let retarget_header = types::new_header_for_test(1, @0x1, @0x2, 1000000000 + 605400, 0x1c7fff80, 0);
+        assert!(check_difficulty(network::network_bitcoin(), 4032, &retarget_header, &last_header, option::some(first_header), option::none()), 4);
+        assert!(!check_difficulty(network::network_bitcoin(), 4032, &header, &last_header, option::some(first_header), option::none()), 5);
+    }
+
+    #[test_only]
+    fun testnet_header_2821527(): Header {
+        //https://mempool.space/testnet/block/0000000016412abe1778a347da773ff8bc087ad1a91ae5daad349bc268285c2d
+        //A min difficulty block, it is also used by the block fixtures of the framework tests
+        types::new_header_for_test(
+            536870912,
+            bitcoin_hash::from_ascii_bytes(&b"0000000075b92a77d6603c7783e5658e301fa768b658d30401823ac43efd4371"),
+            bitcoin_hash::from_ascii_bytes(&b"f33318fb3a9ff4b38b911af7532e0639a3ad97399231c0c5f35debcba202edb5"),
+            1718597798,
+            0x1d00ffff,
+            1366389009,
+        )
+    }
+
+    #[test]
+    fun test_check_difficulty_testnet() {
+        let network = network::network_testnet();
+        let min_header = testnet_header_2821527();
+        assert!(types::header_to_hash(&min_header) == bitcoin_hash::from_ascii_bytes(&b"0000000016412abe1778a347da773ff8bc087ad1a91ae5daad349bc268285c2d"), 1);
+        assert!(check_proof_of_work(network, &min_header), 2);
+        assert!(is_min_difficulty_walk_back(network, 2821527, &min_header), 3);
+        assert!(!is_min_difficulty_walk_back(network, 2822400, &min_header), 4);
+
+        // The previous and the next blocks only carry the time and the bits used by the rules
+        let last_bits = 0x1927fc5f;
+        let time = (types::time(&min_header) as u64);
+        let prev_header = types::new_header_for_test(536870912, @0x1, @0x2, ((time - 1201) as u32), last_bits, 0);
+        assert!(!is_min_difficulty_walk_back(network, 2821526, &prev_header), 5);
+        // The min difficulty block is mined more than 20 minutes after the previous block
+        assert!(check_difficulty(network, 2821527, &min_header, &prev_header, option::none(), option::some(last_bits)), 6);
+        // The normal difficulty is not allowed after 20 minutes
+        let late_header = types::new_header_for_test(536870912, @0x1, @0x2, (time as u32), last_bits, 0);
+        assert!(!check_difficulty(network, 2821527, &late_header, &prev_header, option::none(), option::some(last_bits)), 7);
+        // The min difficulty is not allowed within 20 minutes
+        let prev_header = types::new_header_for_test(536870912, @0x1, @0x2, ((time - 1200) as u32), last_bits, 0);
+        assert!(!check_difficulty(network, 2821527, &min_header, &prev_header, option::none(), option::some(last_bits)), 8);
+
+        // The block after the min difficulty block returns to the bits of the last block which is not a min difficulty block
+        let next_header = types::new_header_for_test(536870912, @0x1, @0x2, ((time + 60) as u32), last_bits, 0);
+        assert!(check_difficulty(network, 2821528, &next_header, &min_header, option::none(), option::some(last_bits)), 9);
+        let other_header = types::new_header_for_test(536870912, @0x1, @0x2, ((time + 60) as u32), 0x1927fc60, 0);
+        assert!(!check_difficulty(network, 2821528, &other_header, &min_header, option::none(), option::some(last_bits)), 10);
+        let next_min_header = types::new_header_for_test(536870912, @0x1, @0x2, ((time + 60) as u32), 0x1d00ffff, 0);
+        assert!(!check_difficulty(network, 2821528, &next_min_header, &min_header, option::none(), option::some(last_bits)), 11);
+        // Another min difficulty block after 20 minutes
+        let next_min_header = types::new_header_for_test(536870912, @0x1, @0x2, ((time + 1201) as u32), 0x1d00ffff, 0);
+        assert!(check_difficulty(network, 2821528, &next_min_header, &min_header, option::none(), option::some(last_bits)), 12);
+    }
+
+    #[test]
+    fun test_median_time_past() {
+        let times = vector[10, 2, 8, 4, 6, 1, 3, 5, 7, 9, 11];
+        assert!(median_time(times) == 6, 1);
+        let header = types::new_header_for_test(1, @0x1, @0x2, 7, 0x1d00ffff, 0);
+        assert!(check_median_time_past(&header, times), 2);
+        let header = types::new_header_for_test(1, @0x1, @0x2, 6, 0x1d00ffff, 0);
+        assert!(!check_median_time_past(&header, times), 3);
+        // The median of the available previous blocks near the start of the chain
+        assert!(check_median_time_past(&header, vector[1, 2, 3]), 4);
+        assert!(!check_median_time_past(&header, vector[10, 11]), 5);
+        assert!(check_median_time_past(&header, vector[]), 6);
+    }
+}
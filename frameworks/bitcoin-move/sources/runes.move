@@ -0,0 +1,934 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The Runes protocol, the move version of the ord rune updater
+/// https://github.com/ordinals/ord/blob/0.19.1/src/index/updater/rune_updater.rs
+/// The rune balances of an output are stored in a RuneBalances Object, which is sealed to the UTXO.
+/// Note:
+/// 1. The commitment of an etching is checked like ord, the input tapscript pushes the commitment of the rune,
+///    and the spent output is a taproot output with at least `COMMIT_CONFIRMATIONS` confirmations.
+///    If the commit transaction is before the Bitcoin block that Rooch starts to sync, the spent output
+///    is unknown, the commitment is accepted as the commit transaction is confirmed before the sync.
+/// 2. The rune balances are tracked from the Bitcoin block that Rooch starts to sync,
+///    the balances created before it are not available.
+module bitcoin_move::runes{
+    use std::option::{Self, Option};
+    use std::vector;
+
+    use moveos_std::object::{Self, Object, ObjectID};
+    use moveos_std::simple_map::{Self, SimpleMap};
+    use moveos_std::type_info;
+    use moveos_std::event;
+
+    use bitcoin_move::network;
+    use bitcoin_move::types::{Self, Transaction, TxOut, OutPoint};
+    use bitcoin_move::pending_block::{Self, PendingBlock};
+    use bitcoin_move::utxo::{Self, UTXO, SealOut};
+    use bitcoin_move::script_buf::{Self, ScriptBuf};
+
+    friend bitcoin_move::genesis;
+    friend bitcoin_move::bitcoin;
+
+    /// The rune names greater than or equal to `RESERVED` are reserved for the runes etched without a name.
+    const RESERVED: u128 = 6402364363415443603228541259936211926;
+    const MAX_U64: u128 = 18446744073709551615;
+    /// The number of the confirmations of the commit transaction required to etch a named rune
+    const COMMIT_CONFIRMATIONS: u64 = 6;
+
+    const ErrorRuneNotFound: u64 = 1;
+
+    struct RuneId has store, copy, drop {
+        /// The height of the block in which the rune is etched
+        block: u64,
+        /// The index of the etching transaction in the block
+        tx: u32,
+    }
+
+    struct Terms has store, copy, drop {
+        /// The amount of runes each mint transaction receives
+        amount: Option<u128>,
+        /// The number of times the rune can be minted
+        cap: Option<u128>,
+        /// The absolute block height at which the mint opens
+        height_start: Option<u64>,
+        /// The absolute block height at which the mint closes
+        height_end: Option<u64>,
+        /// The block height relative to the etching block at which the mint opens
+        offset_start: Option<u64>,
+        /// The block height relative to the etching block at which the mint closes
+        offset_end: Option<u64>,
+    }
+
+    struct Etching has store, copy, drop {
+        divisibility: Option<u8>,
+        premine: Option<u128>,
+        rune: Option<u128>,
+        spacers: Option<u32>,
+        /// The unicode code point of the currency symbol
+        symbol: Option<u32>,
+        terms: Option<Terms>,
+        turbo: bool,
+    }
+
+    struct Edict has store, copy, drop {
+        id: RuneId,
+        amount: u128,
+        output: u32,
+    }
+
+    /// The deciphered runestone of a transaction.
+    /// If the runestone is a cenotaph, the `edicts` and `pointer` are empty,
+    /// and the `etching` only contains the rune name.
+    struct Runestone has store, copy, drop {
+        edicts: vector<Edict>,
+        etching: Option<Etching>,
+        mint: Option<RuneId>,
+        pointer: Option<u32>,
+        is_cenotaph: bool,
+    }
+
+    /// The output spent by an input which pushes the commitment of the etched rune in its tapscript
+    struct CommitOutput has copy, drop {
+        /// The script pubkey of the spent output, none if the commit transaction is not synced
+        script_pubkey: Option<ScriptBuf>,
+        /// The block height of the commit transaction, none if the commit transaction is not synced
+        block_height: Option<u64>,
+    }
+
+    /// The etched rune, it is a child object of the RuneStore, and owned by the system
+    struct RuneInfo has key {
+        id: RuneId,
+        /// The sequence number of the rune in the etching order
+        number: u64,
+        rune: u128,
+        spacers: u32,
+        divisibility: u8,
+        symbol: Option<u32>,
+        premine: u128,
+        terms: Option<Terms>,
+        mints: u128,
+        burned: u128,
+        /// The txid of the etching transaction
+        etching: address,
+        turbo: bool,
+    }
+
+    /// The rune balances of an output, it is a child object of the RuneStore, and sealed to the UTXO
+    struct RuneBalances has key {
+        outpoint: OutPoint,
+        balances: SimpleMap<RuneId, u128>,
+    }
+
+    struct RuneStore has key {
+        /// The number of etched runes
+        rune_count: u64,
+    }
+
+    struct RuneEtchedEvent has copy, drop, store {
+        block_height: u64,
+        txid: address,
+        rune_id: RuneId,
+    }
+
+    struct RuneMintedEvent has copy, drop, store {
+        block_height: u64,
+        txid: address,
+        rune_id: RuneId,
+        amount: u128,
+    }
+
+    struct RuneBurnedEvent has copy, drop, store {
+        block_height: u64,
+        txid: address,
+        rune_id: RuneId,
+        amount: u128,
+    }
+
+    public(friend) fun genesis_init() {
+        let store_id = object::named_object_id<RuneStore>();
+        // The RuneStore maybe not exist if the chain is started before the runes module is added,
+        // so this function is also called when processing the transaction.
+        if (!object::exists_object(store_id)) {
+            let store_obj = object::new_named_object(RuneStore{
+                rune_count: 0,
+            });
+            object::to_shared(store_obj);
+        }
+    }
+
+    fun borrow_store_obj(): &Object<RuneStore> {
+        let store_id = object::named_object_id<RuneStore>();
+        object::borrow_object<RuneStore>(store_id)
+    }
+
+    fun borrow_mut_store_obj(): &mut Object<RuneStore> {
+        let store_id = object::named_object_id<RuneStore>();
+        object::borrow_mut_object_shared<RuneStore>(store_id)
+    }
+
+    public fun rune_count(): u64 {
+        let store_id = object::named_object_id<RuneStore>();
+        if (!object::exists_object(store_id)) {
+            return 0
+        };
+        object::borrow(borrow_store_obj()).rune_count
+    }
+
+    // ==== RuneId ==== //
+
+    public fun new_rune_id(block: u64, tx: u32): RuneId {
+        RuneId { block, tx }
+    }
+
+    public fun rune_id_block(self: &RuneId): u64 {
+        self.block
+    }
+
+    public fun rune_id_tx(self: &RuneId): u32 {
+        self.tx
+    }
+
+    public fun derive_rune_info_id(id: RuneId): ObjectID {
+        let parent_id = object::named_object_id<RuneStore>();
+        object::custom_object_id_with_parent<RuneId, RuneInfo>(parent_id, id)
+    }
+
+    public fun derive_rune_balances_id(outpoint: OutPoint): ObjectID {
+        let parent_id = object::named_object_id<RuneStore>();
+        object::custom_object_id_with_parent<OutPoint, RuneBalances>(parent_id, outpoint)
+    }
+
+    // ==== Rune ==== //
+
+    public fun is_reserved(rune: u128): bool {
+        rune >= RESERVED
+    }
+
+    /// The reserved name assigned to the rune etched at `block:tx` without a name
+    public fun reserved_rune(block: u64, tx: u32): u128 {
+        RESERVED + (((block as u128) << 32) | (tx as u128))
+    }
+
+    /// Get the RuneId via the rune name
+    public fun get_rune_id(rune: u128): Option<RuneId> {
+        let store_id = object::named_object_id<RuneStore>();
+        if (!object::exists_object(store_id)) {
+            return option::none()
+        };
+        let store_obj = borrow_store_obj();
+        if (object::contains_field(store_obj, rune)) {
+            option::some(*object::borrow_field(store_obj, rune))
+        } else {
+            option::none()
+        }
+    }
+
+    public fun exists_rune_info(id: RuneId): bool {
+        object::exists_object_with_type<RuneInfo>(derive_rune_info_id(id))
+    }
+
+    public fun borrow_rune_info(id: RuneId): &RuneInfo {
+        let object_id = derive_rune_info_id(id);
+        assert!(object::exists_object_with_type<RuneInfo>(object_id), ErrorRuneNotFound);
+        object::borrow(object::borrow_object<RuneInfo>(object_id))
+    }
+
+    public fun info_id(self: &RuneInfo): RuneId {
+        self.id
+    }
+
+    public fun info_number(self: &RuneInfo): u64 {
+        self.number
+    }
+
+    public fun info_rune(self: &RuneInfo): u128 {
+        self.rune
+    }
+
+    public fun info_spacers(self: &RuneInfo): u32 {
+        self.spacers
+    }
+
+    public fun info_divisibility(self: &RuneInfo): u8 {
+        self.divisibility
+    }
+
+    public fun info_symbol(self: &RuneInfo): Option<u32> {
+        self.symbol
+    }
+
+    public fun info_premine(self: &RuneInfo): u128 {
+        self.premine
+    }
+
+    public fun info_terms(self: &RuneInfo): Option<Terms> {
+        self.terms
+    }
+
+    public fun info_mints(self: &RuneInfo): u128 {
+        self.mints
+    }
+
+    public fun info_burned(self: &RuneInfo): u128 {
+        self.burned
+    }
+
+    public fun info_etching(self: &RuneInfo): address {
+        self.etching
+    }
+
+    public fun info_turbo(self: &RuneInfo): bool {
+        self.turbo
+    }
+
+    /// The block height at which the mint of the rune opens
+    public fun mint_start(self: &RuneInfo): Option<u64> {
+        if (option::is_none(&self.terms)) {
+            return option::none()
+        };
+        let terms = option::borrow(&self.terms);
+        let relative = if (option::is_some(&terms.offset_start)) {
+            option::some(saturating_add(self.id.block, *option::borrow(&terms.offset_start)))
+        } else {
+            option::none()
+        };
+        let absolute = terms.height_start;
+        if (option::is_some(&relative) && option::is_some(&absolute)) {
+            let relative = option::destroy_some(relative);
+            let absolute = option::destroy_some(absolute);
+            option::some(if (relative > absolute) { relative } else { absolute })
+        } else if (option::is_some(&relative)) {
+            relative
+        } else {
+            absolute
+        }
+    }
+
+    /// The block height at which the mint of the rune closes
+    public fun mint_end(self: &RuneInfo): Option<u64> {
+        if (option::is_none(&self.terms)) {
+            return option::none()
+        };
+        let terms = option::borrow(&self.terms);
+        let relative = if (option::is_some(&terms.offset_end)) {
+            option::some(saturating_add(self.id.block, *option::borrow(&terms.offset_end)))
+        } else {
+            option::none()
+        };
+        let absolute = terms.height_end;
+        if (option::is_some(&relative) && option::is_some(&absolute)) {
+            let relative = option::destroy_some(relative);
+            let absolute = option::destroy_some(absolute);
+            option::some(if (relative < absolute) { relative } else { absolute })
+        } else if (option::is_some(&relative)) {
+            relative
+        } else {
+            absolute
+        }
+    }
+
+    /// Returns the amount of a mint at the `block_height`, none if the rune is not mintable
+    public fun mintable(self: &RuneInfo, block_height: u64): Option<u128> {
+        if (option::is_none(&self.terms)) {
+            return option::none()
+        };
+        let start = mint_start(self);
+        if (option::is_some(&start) && block_height < *option::borrow(&start)) {
+            return option::none()
+        };
+        let end = mint_end(self);
+        if (option::is_some(&end) && block_height >= *option::borrow(&end)) {
+            return option::none()
+        };
+        let terms = option::borrow(&self.terms);
+        let cap = option::get_with_default(&terms.cap, 0);
+        if (self.mints >= cap) {
+            return option::none()
+        };
+        option::some(option::get_with_default(&terms.amount, 0))
+    }
+
+    // ==== RuneBalances ==== //
+
+    public fun exists_rune_balances(outpoint: OutPoint): bool {
+        object::exists_object_with_type<RuneBalances>(derive_rune_balances_id(outpoint))
+    }
+
+    /// Get the rune balances of the output, the balances are empty if the output does not hold any runes
+    public fun get_rune_balances(outpoint: OutPoint): SimpleMap<RuneId, u128> {
+        let object_id = derive_rune_balances_id(outpoint);
+        if (!object::exists_object_with_type<RuneBalances>(object_id)) {
+            return simple_map::new()
+        };
+        object::borrow(object::borrow_object<RuneBalances>(object_id)).balances
+    }
+
+    public fun get_rune_balance(outpoint: OutPoint, id: RuneId): u128 {
+        let balances = get_rune_balances(outpoint);
+        *simple_map::borrow_with_default(&balances, &id, &0)
+    }
+
+    public fun balances_outpoint(self: &RuneBalances): OutPoint {
+        self.outpoint
+    }
+
+    public fun balances(self: &RuneBalances): &SimpleMap<RuneId, u128> {
+        &self.balances
+    }
+
+    // ==== Commitment ==== //
+
+    public(friend) fun new_commit_output(script_pubkey: Option<ScriptBuf>, block_height: Option<u64>): CommitOutput {
+        CommitOutput { script_pubkey, block_height }
+    }
+
+    /// Returns the indexes of the inputs which may commit to the rune etched in the runestone,
+    /// the outputs spent by these inputs should be passed to `process_tx` to check the commitment.
+    public fun etching_commitment_inputs(tx: &Transaction, runestone: &Option<Runestone>): vector<u64> {
+        if (option::is_none(runestone)) {
+            return vector::empty()
+        };
+        let etching = &option::borrow(runestone).etching;
+        if (option::is_none(etching)) {
+            return vector::empty()
+        };
+        let rune = option::borrow(etching).rune;
+        if (option::is_none(&rune)) {
+            return vector::empty()
+        };
+        rune_commitment_inputs(tx, option::destroy_some(rune))
+    }
+
+    /// Returns true if any of the spent outputs is a taproot output,
+    /// and the commit transaction has at least `COMMIT_CONFIRMATIONS` confirmations at the `block_height`.
+    public fun commits_to_rune(block_height: u64, commit_outputs: &vector<CommitOutput>): bool {
+        let idx = 0;
+        let len = vector::length(commit_outputs);
+        while (idx < len) {
+            let commit_output = vector::borrow(commit_outputs, idx);
+            idx = idx + 1;
+            if (option::is_some(&commit_output.script_pubkey)
+                && !script_buf::is_p2tr(option::borrow(&commit_output.script_pubkey))) {
+                continue
+            };
+            if (option::is_some(&commit_output.block_height)) {
+                let commit_height = *option::borrow(&commit_output.block_height);
+                if (commit_height > block_height || block_height - commit_height + 1 < COMMIT_CONFIRMATIONS) {
+                    continue
+                };
+            };
+            return true
+        };
+        false
+    }
+
+    // ==== Process ==== //
+
+    /// Process the runes of the transaction, the `runestone` is deciphered from the transaction,
+    /// and the `commit_outputs` are the outputs spent by the `etching_commitment_inputs`.
+    public(friend) fun process_tx(
+        pending_block: &mut Object<PendingBlock>,
+        tx: &Transaction,
+        input_utxos: &mut vector<UTXO>,
+        runestone_option: Option<Runestone>,
+        commit_outputs: vector<CommitOutput>
+    ): vector<SealOut> {
+        let seal_outs = vector::empty();
+        let block_height = pending_block::block_height(pending_block);
+        // There are no runes before the activation height
+        if (block_height < network::first_rune_height()) {
+            return seal_outs
+        };
+        genesis_init();
+
+        let txid = types::tx_id(tx);
+        let txoutput = types::tx_output(tx);
+        let output_len = vector::length(txoutput);
+
+        // Collect the rune balances of the inputs
+        let unallocated = simple_map::new<RuneId, u128>();
+        let input_idx = 0;
+        let input_len = vector::length(input_utxos);
+        while (input_idx < input_len) {
+            let utxo = vector::borrow_mut(input_utxos, input_idx);
+            let seals = utxo::remove_seals_internal<RuneBalances>(utxo);
+            vector::for_each(seals, |seal_object_id| {
+                let RuneBalances{outpoint: _, balances} = take_rune_balances(seal_object_id);
+                merge_balances(&mut unallocated, balances);
+            });
+            input_idx = input_idx + 1;
+        };
+
+        let allocated = vector::empty<SimpleMap<RuneId, u128>>();
+        let output_idx = 0;
+        while (output_idx < output_len) {
+            vector::push_back(&mut allocated, simple_map::new());
+            output_idx = output_idx + 1;
+        };
+        let burned = simple_map::new<RuneId, u128>();
+
+        let is_cenotaph = false;
+        let pointer = option::none<u32>();
+        if (option::is_some(&runestone_option)) {
+            let runestone = option::destroy_some(runestone_option);
+            is_cenotaph = runestone.is_cenotaph;
+            pointer = runestone.pointer;
+
+            if (option::is_some(&runestone.mint)) {
+                let mint_id = *option::borrow(&runestone.mint);
+                let amount = mint(block_height, txid, mint_id);
+                if (option::is_some(&amount)) {
+                    add_balance(&mut unallocated, mint_id, option::destroy_some(amount));
+                };
+            };
+
+            let (is_etched, etched_id, etched_rune) = etched(pending_block, block_height, tx, &runestone, &commit_outputs);
+
+            if (!is_cenotaph) {
+                if (is_etched) {
+                    let premine = option::get_with_default(&option::borrow(&runestone.etching).premine, 0);
+                    add_balance(&mut unallocated, etched_id, premine);
+                };
+                apply_edicts(&runestone.edicts, is_etched, etched_id, txoutput, &mut unallocated, &mut allocated);
+            };
+
+            if (is_etched) {
+                create_rune_info(block_height, txid, &runestone, etched_id, etched_rune);
+            };
+        };
+
+        if (is_cenotaph) {
+            // A cenotaph burns all the input runes
+            merge_balances(&mut burned, unallocated);
+        } else {
+            // Assign all the unallocated runes to the pointer output,
+            // or the first non OP_RETURN output if there is no pointer
+            let vout = if (option::is_some(&pointer)) {
+                option::some((option::destroy_some(pointer) as u64))
+            } else {
+                first_non_op_return_output(txoutput)
+            };
+            if (option::is_some(&vout)) {
+                merge_balances(vector::borrow_mut(&mut allocated, option::destroy_some(vout)), unallocated);
+            } else {
+                merge_balances(&mut burned, unallocated);
+            };
+        };
+
+        // Update the output balances
+        let vout = 0;
+        while (vout < output_len) {
+            let balances = vector::pop_back(&mut allocated);
+            let current_vout = output_len - vout - 1;
+            vout = vout + 1;
+            if (simple_map::length(&balances) == 0) {
+                continue
+            };
+            let txout = vector::borrow(txoutput, current_vout);
+            if (script_buf::is_op_return(types::txout_script_pubkey(txout))) {
+                merge_balances(&mut burned, balances);
+                continue
+            };
+            let seal_out = create_rune_balances(txid, (current_vout as u32), txout, balances);
+            vector::push_back(&mut seal_outs, seal_out);
+        };
+
+        // Increment the burned amount of the runes
+        let (burned_ids, burned_amounts) = simple_map::to_vec_pair(burned);
+        vector::zip(burned_ids, burned_amounts, |id, amount| {
+            burn(block_height, txid, id, amount);
+        });
+        seal_outs
+    }
+
+    fun apply_edicts(
+        edicts: &vector<Edict>,
+        is_etched: bool,
+        etched_id: RuneId,
+        txoutput: &vector<TxOut>,
+        unallocated: &mut SimpleMap<RuneId, u128>,
+        allocated: &mut vector<SimpleMap<RuneId, u128>>,
+    ) {
+        let output_len = vector::length(txoutput);
+        let idx = 0;
+        let len = vector::length(edicts);
+        while (idx < len) {
+            let edict = *vector::borrow(edicts, idx);
+            idx = idx + 1;
+            // The RuneId 0:0 means the rune etched in this transaction
+            let id = edict.id;
+            if (id == new_rune_id(0, 0)) {
+                if (!is_etched) {
+                    continue
+                };
+                id = etched_id;
+            };
+            if (!simple_map::contains_key(unallocated, &id)) {
+                continue
+            };
+            let output = (edict.output as u64);
+            if (output == output_len) {
+                // The output equal to the number of outputs means to allocate the runes to all non OP_RETURN outputs
+                let destinations = non_op_return_outputs(txoutput);
+                let destinations_len = vector::length(&destinations);
+                if (destinations_len == 0) {
+                    continue
+                };
+                if (edict.amount == 0) {
+                    // Divide the balance between the outputs
+                    let balance = *simple_map::borrow(unallocated, &id);
+                    let amount = balance / (destinations_len as u128);
+                    let remainder = ((balance % (destinations_len as u128)) as u64);
+                    let i = 0;
+                    while (i < destinations_len) {
+                        let output_amount = if (i < remainder) { amount + 1 } else { amount };
+                        allocate(unallocated, allocated, id, output_amount, *vector::borrow(&destinations, i));
+                        i = i + 1;
+                    };
+                } else {
+                    // Allocate the amount to each output
+                    let i = 0;
+                    while (i < destinations_len) {
+                        let balance = *simple_map::borrow(unallocated, &id);
+                        let output_amount = if (edict.amount < balance) { edict.amount } else { balance };
+                        allocate(unallocated, allocated, id, output_amount, *vector::borrow(&destinations, i));
+                        i = i + 1;
+                    };
+                };
+            } else {
+                let balance = *simple_map::borrow(unallocated, &id);
+                let amount = if (edict.amount == 0 || edict.amount > balance) { balance } else { edict.amount };
+                allocate(unallocated, allocated, id, amount, output);
+            };
+        };
+    }
+
+    fun allocate(unallocated: &mut SimpleMap<RuneId, u128>, allocated: &mut vector<SimpleMap<RuneId, u128>>, id: RuneId, amount: u128, output: u64) {
+        if (amount == 0) {
+            return
+        };
+        let balance = simple_map::borrow_mut(unallocated, &id);
+        *balance = *balance - amount;
+        add_balance(vector::borrow_mut(allocated, output), id, amount);
+    }
+
+    fun mint(block_height: u64, txid: address, id: RuneId): Option<u128> {
+        let object_id = derive_rune_info_id(id);
+        if (!object::exists_object_with_type<RuneInfo>(object_id)) {
+            return option::none()
+        };
+        let info = object::borrow_mut(object::borrow_mut_object_extend<RuneInfo>(object_id));
+        let amount = mintable(info, block_height);
+        if (option::is_some(&amount)) {
+            info.mints = info.mints + 1;
+            event::emit(RuneMintedEvent{
+                block_height,
+                txid,
+                rune_id: id,
+                amount: *option::borrow(&amount),
+            });
+        };
+        amount
+    }
+
+    fun burn(block_height: u64, txid: address, id: RuneId, amount: u128) {
+        if (amount == 0) {
+            return
+        };
+        let object_id = derive_rune_info_id(id);
+        if (object::exists_object_with_type<RuneInfo>(object_id)) {
+            let info = object::borrow_mut(object::borrow_mut_object_extend<RuneInfo>(object_id));
+            info.burned = info.burned + amount;
+        };
+        event::emit(RuneBurnedEvent{
+            block_height,
+            txid,
+            rune_id: id,
+            amount,
+        });
+    }
+
+    /// Returns (is_etched, rune_id, rune) of the etching in the runestone
+    fun etched(pending_block: &Object<PendingBlock>, block_height: u64, tx: &Transaction, runestone: &Runestone, commit_outputs: &vector<CommitOutput>): (bool, RuneId, u128) {
+        if (option::is_none(&runestone.etching)) {
+            return (false, new_rune_id(0, 0), 0)
+        };
+        let tx_index = pending_block::tx_index(pending_block, types::tx_id(tx));
+        let rune = option::borrow(&runestone.etching).rune;
+        let rune = if (option::is_some(&rune)) {
+            let rune = option::destroy_some(rune);
+            if (rune < minimum_at_height(network::network(), block_height)
+                || is_reserved(rune)
+                || option::is_some(&get_rune_id(rune))
+                || !commits_to_rune(block_height, commit_outputs)) {
+                return (false, new_rune_id(0, 0), 0)
+            };
+            rune
+        } else {
+            reserved_rune(block_height, tx_index)
+        };
+        (true, new_rune_id(block_height, tx_index), rune)
+    }
+
+    fun create_rune_info(block_height: u64, txid: address, runestone: &Runestone, id: RuneId, rune: u128) {
+        let store_obj = borrow_mut_store_obj();
+        let store = object::borrow_mut(store_obj);
+        let number = store.rune_count;
+        store.rune_count = number + 1;
+
+        let info = if (runestone.is_cenotaph) {
+            RuneInfo {
+                id,
+                number,
+                rune,
+                spacers: 0,
+                divisibility: 0,
+                symbol: option::none(),
+                premine: 0,
+                terms: option::none(),
+                mints: 0,
+                burned: 0,
+                etching: txid,
+                turbo: false,
+            }
+        } else {
+            let etching = option::borrow(&runestone.etching);
+            RuneInfo {
+                id,
+                number,
+                rune,
+                spacers: option::get_with_default(&etching.spacers, 0),
+                divisibility: option::get_with_default(&etching.divisibility, 0),
+                symbol: etching.symbol,
+                premine: option::get_with_default(&etching.premine, 0),
+                terms: etching.terms,
+                mints: 0,
+                burned: 0,
+                etching: txid,
+                turbo: etching.turbo,
+            }
+        };
+        // record a rune name to RuneId mapping
+        object::add_field(store_obj, rune, id);
+        let obj = object::new_with_parent_and_id(store_obj, id, info);
+        object::transfer_extend(obj, @bitcoin_move);
+        event::emit(RuneEtchedEvent{
+            block_height,
+            txid,
+            rune_id: id,
+        });
+    }
+
+    fun create_rune_balances(txid: address, vout: u32, txout: &TxOut, balances: SimpleMap<RuneId, u128>): SealOut {
+        let outpoint = types::new_outpoint(txid, vout);
+        let store_obj = borrow_mut_store_obj();
+        let obj = object::new_with_parent_and_id(store_obj, outpoint, RuneBalances{
+            outpoint,
+            balances,
+        });
+        let object_id = object::id(&obj);
+        object::transfer_extend(obj, types::txout_object_address(txout));
+        let seal = utxo::new_utxo_seal(type_info::type_name<RuneBalances>(), object_id);
+        utxo::new_seal_out(vout, seal)
+    }
+
+    fun take_rune_balances(object_id: ObjectID): RuneBalances {
+        let obj = object::take_object_extend<RuneBalances>(object_id);
+        object::remove(obj)
+    }
+
+    fun add_balance(balances: &mut SimpleMap<RuneId, u128>, id: RuneId, amount: u128) {
+        if (simple_map::contains_key(balances, &id)) {
+            let balance = simple_map::borrow_mut(balances, &id);
+            *balance = *balance + amount;
+        } else {
+            simple_map::add(balances, id, amount);
+        };
+    }
+
+    fun merge_balances(to: &mut SimpleMap<RuneId, u128>, from: SimpleMap<RuneId, u128>) {
+        let (ids, amounts) = simple_map::to_vec_pair(from);
+        vector::zip(ids, amounts, |id, amount| {
+            if (amount > 0) {
+                add_balance(to, id, amount);
+            };
+        });
+    }
+
+    fun non_op_return_outputs(txoutput: &vector<TxOut>): vector<u64> {
+        let outputs = vector::empty();
+        let idx = 0;
+        let len = vector::length(txoutput);
+        while (idx < len) {
+            let txout = vector::borrow(txoutput, idx);
+            if (!script_buf::is_op_return(types::txout_script_pubkey(txout))) {
+                vector::push_back(&mut outputs, idx);
+            };
+            idx = idx + 1;
+        };
+        outputs
+    }
+
+    fun first_non_op_return_output(txoutput: &vector<TxOut>): Option<u64> {
+        let idx = 0;
+        let len = vector::length(txoutput);
+        while (idx < len) {
+            let txout = vector::borrow(txoutput, idx);
+            if (!script_buf::is_op_return(types::txout_script_pubkey(txout))) {
+                return option::some(idx)
+            };
+            idx = idx + 1;
+        };
+        option::none()
+    }
+
+    fun saturating_add(a: u64, b: u64): u64 {
+        let sum = (a as u128) + (b as u128);
+        if (sum > MAX_U64) {
+            (MAX_U64 as u64)
+        } else {
+            (sum as u64)
+        }
+    }
+
+    /// Decipher the runestone from the OP_RETURN output of the transaction
+    public native fun decipher_runestone(tx: &Transaction): Option<Runestone>;
+
+    /// Returns the indexes of the inputs whose tapscript pushes the commitment of the rune
+    public native fun rune_commitment_inputs(tx: &Transaction, rune: u128): vector<u64>;
+
+    /// Returns the minimum rune name which can be etched at the `block_height`
+    public native fun minimum_at_height(network: u8, block_height: u64): u128;
+
+    // ==== Runestone Getter ==== //
+
+    public fun runestone_edicts(self: &Runestone): &vector<Edict> {
+        &self.edicts
+    }
+
+    public fun runestone_etching(self: &Runestone): &Option<Etching> {
+        &self.etching
+    }
+
+    public fun runestone_mint(self: &Runestone): Option<RuneId> {
+        self.mint
+    }
+
+    public fun runestone_pointer(self: &Runestone): Option<u32> {
+        self.pointer
+    }
+
+    public fun runestone_is_cenotaph(self: &Runestone): bool {
+        self.is_cenotaph
+    }
+
+    public fun unpack_edict(self: &Edict): (RuneId, u128, u32) {
+        (self.id, self.amount, self.output)
+    }
+
+    public fun etching_rune(self: &Etching): Option<u128> {
+        self.rune
+    }
+
+    public fun etching_premine(self: &Etching): Option<u128> {
+        self.premine
+    }
+
+    public fun etching_terms(self: &Etching): Option<Terms> {
+        self.terms
+    }
+
+    #[test_only]
+    fun new_rune_info_for_test(block: u64, cap: Option<u128>, height_start: Option<u64>, height_end: Option<u64>, offset_start: Option<u64>, offset_end: Option<u64>): RuneInfo {
+        RuneInfo {
+            id: new_rune_id(block, 1),
+            number: 0,
+            rune: 0,
+            spacers: 0,
+            divisibility: 0,
+            symbol: option::none(),
+            premine: 0,
+            terms: option::some(Terms {
+                amount: option::some(1000),
+                cap,
+                height_start,
+                height_end,
+                offset_start,
+                offset_end,
+            }),
+            mints: 0,
+            burned: 0,
+            etching: @0x1,
+            turbo: false,
+        }
+    }
+
+    #[test_only]
+    fun drop_rune_info_for_test(info: RuneInfo) {
+        let RuneInfo{id: _, number: _, rune: _, spacers: _, divisibility: _, symbol: _, premine: _, terms: _, mints: _, burned: _, etching: _, turbo: _} = info;
+    }
+
+    #[test]
+    fun test_reserved_rune() {
+        assert!(!is_reserved(RESERVED - 1), 1);
+        assert!(is_reserved(RESERVED), 2);
+        assert!(reserved_rune(840000, 1) == 6402364363415443603232149032464851927, 3);
+    }
+
+    #[test]
+    fun test_minimum_at_height() {
+        // AAAAAAAAAAAAA
+        assert!(minimum_at_height(network::network_bitcoin(), 0) == 99246114928149462, 1);
+        // ZZYZXBRKWXVA
+        assert!(minimum_at_height(network::network_bitcoin(), 840000) < 99246114928149462, 2);
+        assert!(minimum_at_height(network::network_bitcoin(), 1050000) == 0, 3);
+    }
+
+    #[test]
+    fun test_mintable() {
+        let info = new_rune_info_for_test(840000, option::some(1), option::some(840010), option::none(), option::none(), option::some(20));
+        assert!(mint_start(&info) == option::some(840010), 1);
+        assert!(mint_end(&info) == option::some(840020), 2);
+        assert!(option::is_none(&mintable(&info, 840009)), 3);
+        assert!(mintable(&info, 840010) == option::some(1000), 4);
+        assert!(option::is_none(&mintable(&info, 840020)), 5);
+        info.mints = 1;
+        assert!(option::is_none(&mintable(&info, 840010)), 6);
+        drop_rune_info_for_test(info);
+
+        // The later start and the earlier end are used
+        let info = new_rune_info_for_test(840000, option::some(10), option::some(840005), option::some(840100), option::some(10), option::some(50));
+        assert!(mint_start(&info) == option::some(840010), 7);
+        assert!(mint_end(&info) == option::some(840050), 8);
+        drop_rune_info_for_test(info);
+
+        // No cap means the rune can not be minted
+        let info = new_rune_info_for_test(840000, option::none(), option::none(), option::none(), option::none(), option::none());
+        assert!(option::is_none(&mintable(&info, 840000)), 9);
+        drop_rune_info_for_test(info);
+    }
+
+    #[test]
+    fun test_commits_to_rune() {
+        let p2tr = script_buf::new(x"51204a0e5c7f2d5e1b3a9c8d7e6f5a4b3c2d1e0f9a8b7c6d5e4f3a2b1c0d9e8f7a6b");
+        let p2wsh = script_buf::new(x"00204a0e5c7f2d5e1b3a9c8d7e6f5a4b3c2d1e0f9a8b7c6d5e4f3a2b1c0d9e8f7a6b");
+        // The cases of ord's rune updater tests
+        // immature_commits_are_not_valid: the commit transaction has COMMIT_CONFIRMATIONS - 1 confirmations
+        assert!(!commits_to_rune(840005, &vector[new_commit_output(option::some(p2tr), option::some(840001))]), 1);
+        // The commit transaction has COMMIT_CONFIRMATIONS confirmations
+        assert!(commits_to_rune(840005, &vector[new_commit_output(option::some(p2tr), option::some(840000))]), 2);
+        // The commit transaction in the same block
+        assert!(!commits_to_rune(840005, &vector[new_commit_output(option::some(p2tr), option::some(840005))]), 3);
+        // non_taproot_commits_are_not_valid
+        assert!(!commits_to_rune(840010, &vector[new_commit_output(option::some(p2wsh), option::some(840000))]), 4);
+        // commits_can_be_in_second_input
+        assert!(commits_to_rune(840010, &vector[
+            new_commit_output(option::some(p2wsh), option::some(840000)),
+            new_commit_output(option::some(p2tr), option::some(840000))
+        ]), 5);
+        // etching_with_non_commitment: no input commits to the rune
+        assert!(!commits_to_rune(840010, &vector[]), 6);
+        // The commit transaction is before the sync
+        assert!(commits_to_rune(840000, &vector[new_commit_output(option::none(), option::none())]), 7);
+    }
+}
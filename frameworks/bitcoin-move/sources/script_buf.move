@@ -81,6 +81,13 @@ module bitcoin_move::script_buf{
             && push_opbyte == ((script_len - 2) as u8)
     }
 
+    /// Checks if the given script is a P2TR(witness v1 with a 32 bytes program) script.
+    public fun is_p2tr(self: &ScriptBuf): bool{
+        vector::length(&self.bytes) == 34 &&
+            *vector::borrow(&self.bytes,0) == opcode::op_pushnum_1() &&
+            *vector::borrow(&self.bytes,1) == opcode::op_pushbytes_32()
+    }
+
     /// Get the witness program from a witness program script.
     public fun witness_program(self: &ScriptBuf): vector<u8>{
         vector::slice(&self.bytes, 2, vector::length(&self.bytes))
@@ -0,0 +1,212 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// Bitcoin signature hash, used to verify the taproot signatures of the Bitcoin transaction in Move.
+module bitcoin_move::sighash{
+    use std::vector;
+    use rooch_framework::schnorr;
+    use bitcoin_move::types::{Self, Transaction, TxOut};
+    use bitcoin_move::script_buf;
+
+    /// The sighash type of the taproot signature is invalid
+    const ErrorInvalidSighashType: u64 = 1;
+    /// The prevouts do not match the inputs of the transaction, or the input index is out of range
+    const ErrorInvalidPrevouts: u64 = 2;
+
+    /// The default sighash type of taproot, the signature is 64 bytes without the sighash type byte.
+    const TAP_SIGHASH_DEFAULT: u8 = 0x00;
+    const TAP_SIGHASH_ALL: u8 = 0x01;
+    const TAP_SIGHASH_NONE: u8 = 0x02;
+    const TAP_SIGHASH_SINGLE: u8 = 0x03;
+    const TAP_SIGHASH_ANYONECANPAY: u8 = 0x80;
+    const TAP_SIGHASH_OUTPUT_MASK: u8 = 0x03;
+
+    public fun tap_sighash_default(): u8 {
+        TAP_SIGHASH_DEFAULT
+    }
+
+    public fun tap_sighash_all(): u8 {
+        TAP_SIGHASH_ALL
+    }
+
+    /// Check whether the `sighash_type` is one of the sighash types defined by BIP-341.
+    public fun is_valid_tap_sighash_type(sighash_type: u8): bool {
+        let base_type = if (sighash_type & TAP_SIGHASH_ANYONECANPAY == 0) {
+            sighash_type
+        } else {
+            // ANYONECANPAY must be combined with ALL, NONE or SINGLE
+            sighash_type ^ TAP_SIGHASH_ANYONECANPAY
+        };
+        base_type == TAP_SIGHASH_ALL
+            || base_type == TAP_SIGHASH_NONE
+            || base_type == TAP_SIGHASH_SINGLE
+            || sighash_type == TAP_SIGHASH_DEFAULT
+    }
+
+    /// Compute the BIP-341 signature hash of the taproot key path spending of the input at `input_index`.
+    /// The `prevouts` are the outputs spent by all the inputs of the transaction, in the order of the inputs.
+    public native fun taproot_key_spend_signature_hash(tx: &Transaction, input_index: u64, prevouts: &vector<TxOut>, sighash_type: u8): vector<u8>;
+
+    /// Verify the taproot key path `signature` of the input at `input_index` against the output key of the spent P2TR output.
+    /// The `signature` is 64 bytes with the default sighash type, or 65 bytes with the sighash type appended.
+    /// Return false instead of aborting if the prevouts or the sighash type do not match the transaction.
+    public fun verify_taproot_key_spend(tx: &Transaction, input_index: u64, prevouts: &vector<TxOut>, signature: vector<u8>): bool {
+        let input_len = vector::length(types::tx_input(tx));
+        if (vector::length(prevouts) != input_len || input_index >= input_len) {
+            return false
+        };
+        let prevout = vector::borrow(prevouts, input_index);
+        let script_pubkey = types::txout_script_pubkey(prevout);
+        if (!script_buf::is_p2tr(script_pubkey)) {
+            return false
+        };
+        let output_key = script_buf::witness_program(script_pubkey);
+
+        let sig_len = vector::length(&signature);
+        let sighash_type = if (sig_len == schnorr::signature_length()) {
+            TAP_SIGHASH_DEFAULT
+        } else if (sig_len == schnorr::signature_length() + 1) {
+            let sighash_type = vector::pop_back(&mut signature);
+            // The default sighash type must not be appended explicitly
+            if (sighash_type == TAP_SIGHASH_DEFAULT) {
+                return false
+            };
+            sighash_type
+        } else {
+            return false
+        };
+        if (!is_valid_tap_sighash_type(sighash_type)) {
+            return false
+        };
+        // SIGHASH_SINGLE commits to the output with the same index as the input
+        if (sighash_type & TAP_SIGHASH_OUTPUT_MASK == TAP_SIGHASH_SINGLE
+            && input_index >= vector::length(types::tx_output(tx))) {
+            return false
+        };
+        let sighash = taproot_key_spend_signature_hash(tx, input_index, prevouts, sighash_type);
+        schnorr::verify(&signature, &output_key, &sighash)
+    }
+
+    /// Verify the taproot key path spending signatures in the witness of the input at `input_index`.
+    public fun verify_taproot_key_spend_input(tx: &Transaction, input_index: u64, prevouts: &vector<TxOut>): bool {
+        let inputs = types::tx_input(tx);
+        if (input_index >= vector::length(inputs)) {
+            return false
+        };
+        let witness = types::txin_witness(vector::borrow(inputs, input_index));
+        // The key path spending witness only contains the signature, ignore the annex
+        if (types::witness_len(witness) != 1) {
+            return false
+        };
+        verify_taproot_key_spend(tx, input_index, prevouts, *types::witness_nth(witness, 0))
+    }
+
+    #[test_only]
+    use moveos_std::bcs;
+
+    #[test_only]
+    /// The unsigned transaction and the spent outputs of the keyPathSpending test vector of BIP-341
+    fun bip341_tx_and_prevouts(): (Transaction, vector<TxOut>) {
+        let tx = bcs::from_bytes<Transaction>(x"e3ea200036ccbcda7c5d24dcec1e5014e615027b7d519d156f8029ab84e98403020000000065cd1d097de20cbff686da83a54981d2b9bab3586f4ca7e48f57f5b55963115f3b334e9c01000000000000000000d7b7cab57b1393ace2d064f4d4a2cb8af6def61273e127517d44759b6dafdd990000000000ffffffff00f8e1f583384333689228c5d28eac13366be082dc57441760d957275419a418420000000000ffffffff00f0689180aa63b30cb162a73c6d2a38b7eeda2a83ece74310fda0843ad604853b0100000000feffffff00aa5202bdf6d8ccd2ee0f0202afbbb7461d9264a25e5bfd3c5a52ee1239e0ba6c0000000000feffffff00956149bdc66faa968eb2be2d2faa29718acbfe3941215893a2a3446d32acd05000000000000000000000e664b9773b88c09c32cb70a2a3e4da0ced63b7ba3b22f848531bbb1d5d5f4c9401000000000000000000e9aa6b8e6c9de67619e6a3924ae25696bb7b694bb677a632a74ef7eadfd4eabf0000000000ffffffff00a778eb6a263dc090464cd125c466b5a99667720b1c110468831d058aa1b82af10100000000ffffffff000200ca9a3b000000001976a91406afd46bcdfd22ef94ac122aa11f241244a37ecc88ac00807840cb0000000020ac9a87f5594be208f8532db38cff670c450ed2fea8fcdefcc9a663f78bab962b00");
+        let prevouts = bcs::from_bytes<vector<TxOut>>(x"0900b108190000000022512053a1f6e454df1aa2776a2814a721372d6258050de330b3c6d10ee8f4e0dda34300808f891b00000000225120147c9c57132f6e7ecddba9800bb0c4449251c92a1e60371ee77557b6620f3ea30080158611000000001976a914751e76e8199196d454941c45d1b3a323f1433bd688ac00006e0a1e00000000225120e4d810fd50586274face62b8a807eb9719cef49c04177cc6b76a9a4251d5450e0080098d250000000022512091b64d5324723a985170e4dc5a0f84c041804f2cd12660fa5dec09fc217836050080d28716000000001600147dd65592d0ab2fe0d0257d571abf032cd9db93dc0000e80d280000000022512075169f4001aa68f15bbed28b218df1d0a62cbbcf1188c6665110c293c907b83100804c8b2000000000225120712447206d7a5238acc7ff53fbe94a3b64539ad291c7cdbc490b7577e4b17df500002b0c230000000022512077e30a5522dd9f894c3f8b8bd4c4b2cf82ca7da8a3ea6a239655c39c050ab22000");
+        (tx, prevouts)
+    }
+
+    #[test]
+    fun test_bip341_key_path_spending() {
+        let (tx, prevouts) = bip341_tx_and_prevouts();
+        let input_indexes = vector[
+            0,
+            1,
+            3,
+            4,
+            6,
+            7,
+            8
+        ];
+        let sighash_types = vector[
+            0x03,
+            0x83,
+            0x01,
+            0x00,
+            0x02,
+            0x82,
+            0x81
+        ];
+        let sighashes = vector[
+            x"2514a6272f85cfa0f45eb907fcb0d121b808ed37c6ea160a5a9046ed5526d555",
+            x"325a644af47e8a5a2591cda0ab0723978537318f10e6a63d4eed783b96a71a4d",
+            x"bf013ea93474aa67815b1b6cc441d23b64fa310911d991e713cd34c7f5d46669",
+            x"4f900a0bae3f1446fd48490c2958b5a023228f01661cda3496a11da502a7f7ef",
+            x"15f25c298eb5cdc7eb1d638dd2d45c97c4c59dcaec6679cfc16ad84f30876b85",
+            x"cd292de50313804dabe4685e83f923d2969577191a3e1d2882220dca88cbeb10",
+            x"cccb739eca6c13a8a89e6e5cd317ffe55669bbda23f2fd37b0f18755e008edd2"
+        ];
+        let signatures = vector[
+            x"ed7c1647cb97379e76892be0cacff57ec4a7102aa24296ca39af7541246d8ff14d38958d4cc1e2e478e4d4a764bbfd835b16d4e314b72937b29833060b87276c03",
+            x"052aedffc554b41f52b521071793a6b88d6dbca9dba94cf34c83696de0c1ec35ca9c5ed4ab28059bd606a4f3a657eec0bb96661d42921b5f50a95ad33675b54f83",
+            x"ff45f742a876139946a149ab4d9185574b98dc919d2eb6754f8abaa59d18b025637a3aa043b91817739554f4ed2026cf8022dbd83e351ce1fabc272841d2510a01",
+            x"b4010dd48a617db09926f729e79c33ae0b4e94b79f04a1ae93ede6315eb3669de185a17d2b0ac9ee09fd4c64b678a0b61a0a86fa888a273c8511be83bfd6810f",
+            x"a3785919a2ce3c4ce26f298c3d51619bc474ae24014bcdd31328cd8cfbab2eff3395fa0a16fe5f486d12f22a9cedded5ae74feb4bbe5351346508c5405bcfee002",
+            x"ea0c6ba90763c2d3a296ad82ba45881abb4f426b3f87af162dd24d5109edc1cdd11915095ba47c3a9963dc1e6c432939872bc49212fe34c632cd3ab9fed429c482",
+            x"bbc9584a11074e83bc8c6759ec55401f0ae7b03ef290c3139814f545b58a9f8127258000874f44bc46db7646322107d4d86aec8e73b8719a61fff761d75b5dd981"
+        ];
+        let i = 0;
+        let len = vector::length(&input_indexes);
+        while (i < len) {
+            let input_index = *vector::borrow(&input_indexes, i);
+            let sighash_type = *vector::borrow(&sighash_types, i);
+            assert!(is_valid_tap_sighash_type(sighash_type), 1);
+            let sighash = taproot_key_spend_signature_hash(&tx, input_index, &prevouts, sighash_type);
+            assert!(sighash == *vector::borrow(&sighashes, i), 2);
+            assert!(verify_taproot_key_spend(&tx, input_index, &prevouts, *vector::borrow(&signatures, i)), 3);
+            i = i + 1;
+        };
+    }
+
+    #[test]
+    fun test_verify_invalid_sighash_type() {
+        let (tx, prevouts) = bip341_tx_and_prevouts();
+        assert!(!is_valid_tap_sighash_type(0x04), 1);
+        assert!(!is_valid_tap_sighash_type(0x80), 2);
+        assert!(!is_valid_tap_sighash_type(0x84), 3);
+        // The signature of the input 4 is 64 bytes with the default sighash type
+        let signature = x"b4010dd48a617db09926f729e79c33ae0b4e94b79f04a1ae93ede6315eb3669de185a17d2b0ac9ee09fd4c64b678a0b61a0a86fa888a273c8511be83bfd6810f";
+        vector::push_back(&mut signature, 0x04);
+        assert!(!verify_taproot_key_spend(&tx, 4, &prevouts, signature), 4);
+        vector::pop_back(&mut signature);
+        vector::push_back(&mut signature, 0x80);
+        assert!(!verify_taproot_key_spend(&tx, 4, &prevouts, signature), 5);
+        vector::pop_back(&mut signature);
+        vector::push_back(&mut signature, TAP_SIGHASH_DEFAULT);
+        assert!(!verify_taproot_key_spend(&tx, 4, &prevouts, signature), 6);
+    }
+
+    #[test]
+    fun test_verify_invalid_prevouts() {
+        let (tx, prevouts) = bip341_tx_and_prevouts();
+        let signature = x"b4010dd48a617db09926f729e79c33ae0b4e94b79f04a1ae93ede6315eb3669de185a17d2b0ac9ee09fd4c64b678a0b61a0a86fa888a273c8511be83bfd6810f";
+        assert!(!verify_taproot_key_spend(&tx, 9, &prevouts, signature), 1);
+        vector::pop_back(&mut prevouts);
+        assert!(!verify_taproot_key_spend(&tx, 4, &prevouts, signature), 2);
+    }
+
+    #[test]
+    fun test_verify_sighash_single_without_output() {
+        let (tx, prevouts) = bip341_tx_and_prevouts();
+        // The transaction only has 2 outputs, SIGHASH_SINGLE of the input 4 has no corresponding output
+        let signature = x"b4010dd48a617db09926f729e79c33ae0b4e94b79f04a1ae93ede6315eb3669de185a17d2b0ac9ee09fd4c64b678a0b61a0a86fa888a273c8511be83bfd6810f";
+        vector::push_back(&mut signature, TAP_SIGHASH_SINGLE);
+        assert!(!verify_taproot_key_spend(&tx, 4, &prevouts, signature), 1);
+        vector::pop_back(&mut signature);
+        vector::push_back(&mut signature, TAP_SIGHASH_SINGLE | TAP_SIGHASH_ANYONECANPAY);
+        assert!(!verify_taproot_key_spend(&tx, 4, &prevouts, signature), 2);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorInvalidSighashType, location = Self)]
+    fun test_signature_hash_invalid_sighash_type() {
+        let (tx, prevouts) = bip341_tx_and_prevouts();
+        taproot_key_spend_signature_hash(&tx, 0, &prevouts, 0x04);
+    }
+}
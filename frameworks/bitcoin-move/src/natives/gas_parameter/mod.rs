@@ -2,3 +2,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod ord;
+mod pow;
+mod runes;
+mod sighash;
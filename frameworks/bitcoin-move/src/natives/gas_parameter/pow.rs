@@ -0,0 +1,12 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::pow::GasParameters;
+use rooch_framework::natives::gas_parameter::native::MUL;
+
+rooch_framework::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "pow", [
+    [.bits_to_target.base, optional "bits_to_target.base", 1000 * MUL],
+    [.check_proof_of_work.base, optional "check_proof_of_work.base", 2000 * MUL],
+    [.calculate_next_bits.base, optional "calculate_next_bits.base", 1000 * MUL],
+    [.pow_limit_bits.base, optional "pow_limit_bits.base", 1000 * MUL],
+]);
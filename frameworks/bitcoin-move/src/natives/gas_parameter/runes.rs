@@ -0,0 +1,13 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::runes::GasParameters;
+use rooch_framework::natives::gas_parameter::native::MUL;
+
+rooch_framework::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "runes", [
+    [.decipher_runestone.base, optional "decipher_runestone.base", 1000 * MUL],
+    [.decipher_runestone.per_byte, optional "decipher_runestone.per_byte", 30 * MUL],
+    [.rune_commitment_inputs.base, optional "rune_commitment_inputs.base", 1000 * MUL],
+    [.rune_commitment_inputs.per_byte, optional "rune_commitment_inputs.per_byte", 30 * MUL],
+    [.minimum_at_height.base, optional "minimum_at_height.base", 1000 * MUL],
+]);
@@ -0,0 +1,10 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::sighash::GasParameters;
+use rooch_framework::natives::gas_parameter::native::MUL;
+
+rooch_framework::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "sighash", [
+    [.taproot_key_spend_signature_hash.base, optional "taproot_key_spend_signature_hash.base", 2000 * MUL],
+    [.taproot_key_spend_signature_hash.per_byte, optional "taproot_key_spend_signature_hash.per_byte", 20 * MUL],
+]);
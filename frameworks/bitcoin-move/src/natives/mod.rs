@@ -14,23 +14,36 @@ use std::collections::BTreeMap;
 
 mod gas_parameter;
 pub mod ord;
+pub mod pow;
+pub mod runes;
+pub mod sighash;
 
 #[derive(Clone, Debug, Serialize, PartialEq, Eq, Deserialize)]
 pub struct GasParameters {
     ord: ord::GasParameters,
+    pow: pow::GasParameters,
+    runes: runes::GasParameters,
+    sighash: sighash::GasParameters,
 }
 
 impl FromOnChainGasSchedule for GasParameters {
     fn from_on_chain_gas_schedule(gas_schedule: &BTreeMap<String, u64>) -> Option<Self> {
         Some(Self {
             ord: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
+            pow: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
+            runes: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
+            sighash: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).unwrap(),
         })
     }
 }
 
 impl ToOnChainGasSchedule for GasParameters {
     fn to_on_chain_gas_schedule(&self) -> Vec<(String, u64)> {
-        self.ord.to_on_chain_gas_schedule()
+        let mut entries = self.ord.to_on_chain_gas_schedule();
+        entries.extend(self.pow.to_on_chain_gas_schedule());
+        entries.extend(self.runes.to_on_chain_gas_schedule());
+        entries.extend(self.sighash.to_on_chain_gas_schedule());
+        entries
     }
 }
 
@@ -38,6 +51,9 @@ impl InitialGasSchedule for GasParameters {
     fn initial() -> Self {
         Self {
             ord: InitialGasSchedule::initial(),
+            pow: InitialGasSchedule::initial(),
+            runes: InitialGasSchedule::initial(),
+            sighash: InitialGasSchedule::initial(),
         }
     }
 }
@@ -46,6 +62,9 @@ impl GasParameters {
     pub fn zeros() -> Self {
         Self {
             ord: ord::GasParameters::zeros(),
+            pow: pow::GasParameters::zeros(),
+            runes: runes::GasParameters::zeros(),
+            sighash: sighash::GasParameters::zeros(),
         }
     }
 }
@@ -62,6 +81,9 @@ pub fn all_natives(gas_params: GasParameters) -> NativeFunctionTable {
     }
 
     add_natives!("ord", ord::make_all(gas_params.ord));
+    add_natives!("pow", pow::make_all(gas_params.pow));
+    add_natives!("runes", runes::make_all(gas_params.runes));
+    add_natives!("sighash", sighash::make_all(gas_params.sighash));
 
     make_table_from_iter(BITCOIN_MOVE_ADDRESS, natives)
 }
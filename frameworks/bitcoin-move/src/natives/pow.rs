@@ -0,0 +1,344 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use bitcoin::consensus::Params;
+use bitcoin::{CompactTarget, Target};
+use move_binary_format::errors::{PartialVMError, PartialVMResult};
+use move_core_types::gas_algebra::InternalGas;
+use move_core_types::u256::U256;
+use move_core_types::vm_status::StatusCode;
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{StructRef, Value},
+};
+use moveos_stdlib::natives::helpers::{make_module_natives, make_native};
+use moveos_types::state::MoveState;
+use rooch_types::bitcoin::network::Network;
+use rooch_types::bitcoin::types::Header;
+use serde::{Deserialize, Serialize};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+fn pop_header(args: &mut VecDeque<Value>) -> PartialVMResult<bitcoin::block::Header> {
+    let header_ref = pop_arg!(args, StructRef);
+    let header = Header::from_runtime_value(header_ref.read_ref()?).map_err(|e| {
+        PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+            .with_message(format!("Failed to parse header: {}", e))
+    })?;
+    Ok(header.into())
+}
+
+fn network_params(network: u8) -> Params {
+    Params::new(Network::from(network).into())
+}
+
+/// Decode the compact `bits` as the `SetCompact` of Bitcoin Core,
+/// returns None if the target is negative, zero or overflows.
+fn decode_compact_target(bits: u32) -> Option<Target> {
+    let size = bits >> 24;
+    let word = bits & 0x007f_ffff;
+    let negative = word != 0 && (bits & 0x0080_0000) != 0;
+    let overflow =
+        word != 0 && (size > 34 || (word > 0xff && size > 33) || (word > 0xffff && size > 32));
+    if negative || overflow {
+        return None;
+    }
+    let target = Target::from_compact(CompactTarget::from_consensus(bits));
+    if target == Target::ZERO {
+        return None;
+    }
+    Some(target)
+}
+
+/// The `CheckProofOfWork` of Bitcoin Core, the target of the header must be valid and not greater than
+/// the pow limit of the network, and the block hash must not be greater than the target.
+fn check_proof_of_work(network: u8, header: &bitcoin::block::Header) -> bool {
+    match decode_compact_target(header.bits.to_consensus()) {
+        Some(target) => {
+            target <= network_params(network).max_attainable_target
+                && target.is_met_by(header.block_hash())
+        }
+        None => false,
+    }
+}
+
+fn target_to_u256(target: Target) -> U256 {
+    U256::from_le_bytes(&target.to_le_bytes())
+}
+
+/// Returns the target of the compact `bits`.
+pub fn native_bits_to_target(
+    gas_params: &PowGasParametersOptional,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let bits = pop_arg!(args, u32);
+    let cost = gas_params.base.unwrap();
+    let target = Target::from_compact(CompactTarget::from_consensus(bits));
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::u256(target_to_u256(target))],
+    ))
+}
+
+/// Returns true if the target of the header's `bits` is valid and within the pow limit of the network,
+/// and the block hash of the header is not greater than the target.
+pub fn native_check_proof_of_work(
+    gas_params: &PowGasParametersOptional,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let header = pop_header(&mut args)?;
+    let network = pop_arg!(args, u8);
+    let cost = gas_params.base.unwrap();
+    let is_ok = check_proof_of_work(network, &header);
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(is_ok)]))
+}
+
+/// Calculate the `bits` of the first block in a new difficulty adjustment period.
+/// `timespan` is the time between the first and the last block of the previous period, in seconds.
+pub fn native_calculate_next_bits(
+    gas_params: &PowGasParametersOptional,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let timespan = pop_arg!(args, u64);
+    let last_bits = pop_arg!(args, u32);
+    let network = pop_arg!(args, u8);
+    let cost = gas_params.base.unwrap();
+    let next_bits = CompactTarget::from_next_work_required(
+        CompactTarget::from_consensus(last_bits),
+        timespan,
+        network_params(network),
+    );
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::u32(next_bits.to_consensus())],
+    ))
+}
+
+/// Returns the `bits` of the max attainable target(the lowest difficulty) of the network.
+pub fn native_pow_limit_bits(
+    gas_params: &PowGasParametersOptional,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let network = pop_arg!(args, u8);
+    let cost = gas_params.base.unwrap();
+    let params = network_params(network);
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::u32(
+            params
+                .max_attainable_target
+                .to_compact_lossy()
+                .to_consensus()
+        )],
+    ))
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq, Deserialize)]
+pub struct PowGasParametersOptional {
+    pub base: Option<InternalGas>,
+}
+
+impl PowGasParametersOptional {
+    pub fn zeros() -> Self {
+        Self { base: None }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.base.is_none()
+    }
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq, Deserialize)]
+pub struct GasParameters {
+    pub bits_to_target: PowGasParametersOptional,
+    pub check_proof_of_work: PowGasParametersOptional,
+    pub calculate_next_bits: PowGasParametersOptional,
+    pub pow_limit_bits: PowGasParametersOptional,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            bits_to_target: PowGasParametersOptional::zeros(),
+            check_proof_of_work: PowGasParametersOptional::zeros(),
+            calculate_next_bits: PowGasParametersOptional::zeros(),
+            pow_limit_bits: PowGasParametersOptional::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let mut natives = vec![];
+
+    if !gas_params.bits_to_target.is_empty() {
+        natives.push((
+            "bits_to_target",
+            make_native(gas_params.bits_to_target, native_bits_to_target),
+        ));
+    }
+    if !gas_params.check_proof_of_work.is_empty() {
+        natives.push((
+            "check_proof_of_work",
+            make_native(gas_params.check_proof_of_work, native_check_proof_of_work),
+        ));
+    }
+    if !gas_params.calculate_next_bits.is_empty() {
+        natives.push((
+            "calculate_next_bits",
+            make_native(gas_params.calculate_next_bits, native_calculate_next_bits),
+        ));
+    }
+    if !gas_params.pow_limit_bits.is_empty() {
+        natives.push((
+            "pow_limit_bits",
+            make_native(gas_params.pow_limit_bits, native_pow_limit_bits),
+        ));
+    }
+
+    make_module_natives(natives)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::consensus::deserialize;
+    use bitcoin::hex::FromHex;
+    use rooch_types::into_address::IntoAddress;
+
+    // Mainnet block 91812
+    const MAINNET_91812_HEADER: &str = "010000000f362bdc16f2f880097c71fd3296c01b835c8b034e4d2939e8af02000000000065a62f2f6b9102d6eb5eee95be5ec3fcdfa27cf2117deeebefc6be53761d99499423e04c56720e1bb4518a45";
+    // Mainnet block 818677
+    const MAINNET_818677_HEADER: &str = "000000203de19197a6b3dacfd780af51ac64b4e84d94200adda201000000000000000000c6546fe7376f7f0745654bdd42809a38070959da69792d400964c1b421a00e2b01596465502404174a425c60";
+    // Testnet block 2821527, a min difficulty block
+    const TESTNET_2821527_HEADER: &str = "000000207143fd3ec43a820104d358b668a71f308e65e583773c60d6772ab97500000000b5ed02a2cbeb5df3c5c031923997ada339062e53f71a918bb3f49f3afb1833f3a6b86f66ffff001d11717151";
+
+    fn decode_header(hex: &str) -> bitcoin::block::Header {
+        deserialize(&Vec::<u8>::from_hex(hex).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_check_proof_of_work() {
+        for (network, hex) in [
+            (Network::Bitcoin, MAINNET_91812_HEADER),
+            (Network::Bitcoin, MAINNET_818677_HEADER),
+            (Network::Testnet, TESTNET_2821527_HEADER),
+        ] {
+            let header = decode_header(hex);
+            assert!(check_proof_of_work(network.to_num(), &header));
+
+            let move_header: Header = header.into();
+            let header2: bitcoin::block::Header = move_header.clone().into();
+            assert_eq!(header, header2);
+            assert_eq!(move_header.block_hash(), header.block_hash().into_address());
+
+            let mut bad_header = header;
+            bad_header.nonce = bad_header.nonce.wrapping_add(1);
+            assert!(!check_proof_of_work(network.to_num(), &bad_header));
+        }
+    }
+
+    #[test]
+    fn test_check_proof_of_work_target() {
+        // The target above the pow limit of the network is invalid
+        let header = decode_header(TESTNET_2821527_HEADER);
+        assert!(check_proof_of_work(Network::Testnet.to_num(), &header));
+        let mut easy_header = header;
+        easy_header.bits = CompactTarget::from_consensus(0x1d01ffff);
+        assert!(!check_proof_of_work(
+            Network::Bitcoin.to_num(),
+            &easy_header
+        ));
+
+        // The regtest target is far above the pow limit of mainnet
+        let mut regtest_header = header;
+        regtest_header.bits = CompactTarget::from_consensus(0x207fffff);
+        assert!(!check_proof_of_work(
+            Network::Bitcoin.to_num(),
+            &regtest_header
+        ));
+
+        // The negative, zero and overflow targets are invalid
+        assert!(decode_compact_target(0x1d00ffff).is_some());
+        assert!(decode_compact_target(0x04923456).is_none());
+        assert!(decode_compact_target(0x01003456).is_none());
+        assert!(decode_compact_target(0x00000000).is_none());
+        assert!(decode_compact_target(0xff123456).is_none());
+        assert!(decode_compact_target(0x23000001).is_none());
+        let mut negative_header = header;
+        negative_header.bits = CompactTarget::from_consensus(0x1d80ffff);
+        assert!(!check_proof_of_work(
+            Network::Regtest.to_num(),
+            &negative_header
+        ));
+    }
+
+    #[test]
+    fn test_bits_to_target() {
+        let target = Target::from_compact(CompactTarget::from_consensus(0x1d00ffff));
+        assert_eq!(target, Target::MAX_ATTAINABLE_MAINNET);
+        assert_eq!(target_to_u256(target), U256::from(0xffffu64) << 208u8);
+    }
+
+    #[test]
+    fn test_calculate_next_bits() {
+        let params = network_params(Network::Bitcoin.to_num());
+        // Blocks were mined twice as fast as expected, the difficulty doubles
+        let next_bits = CompactTarget::from_next_work_required(
+            CompactTarget::from_consensus(0x1d00ffff),
+            params.pow_target_timespan / 2,
+            params.clone(),
+        );
+        assert_eq!(next_bits.to_consensus(), 0x1c7fff80);
+
+        // The target can not exceed the pow limit
+        let next_bits = CompactTarget::from_next_work_required(
+            CompactTarget::from_consensus(0x1c7fff80),
+            params.pow_target_timespan * 4,
+            params,
+        );
+        assert_eq!(next_bits.to_consensus(), 0x1d00ffff);
+
+        // The regtest network does not retarget
+        let next_bits = CompactTarget::from_next_work_required(
+            CompactTarget::from_consensus(0x207fffff),
+            1,
+            network_params(Network::Regtest.to_num()),
+        );
+        assert_eq!(next_bits.to_consensus(), 0x207fffff);
+    }
+
+    #[test]
+    fn test_pow_limit_bits() {
+        let params = network_params(Network::Bitcoin.to_num());
+        assert_eq!(
+            params
+                .max_attainable_target
+                .to_compact_lossy()
+                .to_consensus(),
+            0x1d00ffff
+        );
+        let params = network_params(Network::Regtest.to_num());
+        assert_eq!(
+            params
+                .max_attainable_target
+                .to_compact_lossy()
+                .to_consensus(),
+            0x207fffff
+        );
+    }
+}
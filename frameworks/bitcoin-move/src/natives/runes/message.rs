@@ -0,0 +1,110 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+// Code from https://github.com/ordinals/ord/
+
+use super::runestone::Flaw;
+use super::tag::Tag;
+use bitcoin::Transaction;
+use rooch_types::bitcoin::runes::{Edict, RuneId};
+use std::collections::{HashMap, VecDeque};
+
+pub(crate) struct Message {
+    pub(crate) flaw: Option<Flaw>,
+    pub(crate) edicts: Vec<Edict>,
+    pub(crate) fields: HashMap<u128, VecDeque<u128>>,
+}
+
+impl Message {
+    pub(crate) fn from_integers(tx: &Transaction, payload: &[u128]) -> Self {
+        let mut edicts = Vec::new();
+        let mut fields = HashMap::<u128, VecDeque<u128>>::new();
+        let mut flaw = None;
+
+        for i in (0..payload.len()).step_by(2) {
+            let tag = payload[i];
+
+            if Tag::Body == tag {
+                let mut id = RuneId::default();
+                for chunk in payload[i + 1..].chunks(4) {
+                    if chunk.len() != 4 {
+                        flaw.get_or_insert(Flaw::TrailingIntegers);
+                        break;
+                    }
+
+                    let Some(next) = next_rune_id(id, chunk[0], chunk[1]) else {
+                        flaw.get_or_insert(Flaw::EdictRuneId);
+                        break;
+                    };
+
+                    let Some(edict) = edict_from_integers(tx, next, chunk[2], chunk[3]) else {
+                        flaw.get_or_insert(Flaw::EdictOutput);
+                        break;
+                    };
+
+                    id = next;
+                    edicts.push(edict);
+                }
+                break;
+            }
+
+            let Some(&value) = payload.get(i + 1) else {
+                flaw.get_or_insert(Flaw::TruncatedField);
+                break;
+            };
+
+            fields.entry(tag).or_default().push_back(value);
+        }
+
+        Self {
+            flaw,
+            edicts,
+            fields,
+        }
+    }
+}
+
+/// Create a RuneId, the `tx` must be zero if the `block` is zero.
+pub(crate) fn new_rune_id(block: u64, tx: u32) -> Option<RuneId> {
+    if block == 0 && tx > 0 {
+        return None;
+    }
+    Some(RuneId::new(block, tx))
+}
+
+/// The RuneIds of the edicts are delta encoded, the `tx` is relative to the previous id only if the `block` delta is zero.
+pub(crate) fn next_rune_id(id: RuneId, block: u128, tx: u128) -> Option<RuneId> {
+    new_rune_id(
+        id.block.checked_add(block.try_into().ok()?)?,
+        if block == 0 {
+            id.tx.checked_add(tx.try_into().ok()?)?
+        } else {
+            tx.try_into().ok()?
+        },
+    )
+}
+
+fn edict_from_integers(tx: &Transaction, id: RuneId, amount: u128, output: u128) -> Option<Edict> {
+    let output = u32::try_from(output).ok()?;
+
+    // note that this allows `output == tx.output.len()`, which means to divide
+    // amount between all non-OP_RETURN outputs
+    if output as usize > tx.output.len() {
+        return None;
+    }
+
+    Some(Edict { id, amount, output })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_rune_id_is_delta_encoded() {
+        let id = RuneId::new(1, 2);
+        assert_eq!(next_rune_id(id, 0, 1), Some(RuneId::new(1, 3)));
+        assert_eq!(next_rune_id(id, 1, 1), Some(RuneId::new(2, 1)));
+        assert_eq!(next_rune_id(id, u128::from(u64::MAX), 0), None);
+        assert_eq!(next_rune_id(RuneId::default(), 0, 1), None);
+    }
+}
@@ -0,0 +1,418 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod message;
+pub mod runestone;
+pub mod tag;
+pub mod varint;
+
+use move_binary_format::errors::{PartialVMError, PartialVMResult};
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_core_types::vm_status::StatusCode;
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{StructRef, Value},
+};
+use moveos_stdlib::natives::helpers::{make_module_natives, make_native};
+use moveos_types::move_std::option::MoveOption;
+use moveos_types::state::MoveState;
+use rooch_types::bitcoin::network::Network;
+use rooch_types::bitcoin::runes::{self, Rune};
+use rooch_types::bitcoin::types::Transaction;
+use runestone::{Artifact, Runestone};
+use serde::{Deserialize, Serialize};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+impl From<runestone::Terms> for runes::Terms {
+    fn from(terms: runestone::Terms) -> Self {
+        Self {
+            amount: terms.amount.into(),
+            cap: terms.cap.into(),
+            height_start: terms.height.0.into(),
+            height_end: terms.height.1.into(),
+            offset_start: terms.offset.0.into(),
+            offset_end: terms.offset.1.into(),
+        }
+    }
+}
+
+impl From<runestone::Etching> for runes::Etching {
+    fn from(etching: runestone::Etching) -> Self {
+        Self {
+            divisibility: etching.divisibility.into(),
+            premine: etching.premine.into(),
+            rune: etching.rune.map(|rune| rune.n()).into(),
+            spacers: etching.spacers.into(),
+            symbol: etching.symbol.map(u32::from).into(),
+            terms: etching.terms.map(Into::into).into(),
+            turbo: etching.turbo,
+        }
+    }
+}
+
+impl From<Artifact> for runes::Runestone {
+    fn from(artifact: Artifact) -> Self {
+        match artifact {
+            Artifact::Runestone(runestone) => Self {
+                edicts: runestone.edicts,
+                etching: runestone.etching.map(Into::into).into(),
+                mint: runestone.mint.into(),
+                pointer: runestone.pointer.into(),
+                is_cenotaph: false,
+            },
+            // The cenotaph only keeps the rune name of the etching and the mint
+            Artifact::Cenotaph(cenotaph) => Self {
+                edicts: vec![],
+                etching: cenotaph
+                    .etching
+                    .map(|rune| runes::Etching {
+                        rune: MoveOption::some(rune.n()),
+                        ..Default::default()
+                    })
+                    .into(),
+                mint: cenotaph.mint.into(),
+                pointer: MoveOption::none(),
+                is_cenotaph: true,
+            },
+        }
+    }
+}
+
+pub(crate) fn decipher_runestone(tx: &bitcoin::Transaction) -> Option<runes::Runestone> {
+    Runestone::decipher(tx).map(Into::into)
+}
+
+/// Returns the indexes of the inputs whose tapscript pushes the commitment of the `rune`.
+/// Extracting a tapscript does not indicate that the spent output is a taproot output,
+/// the spent output and its confirmations are checked in Move.
+pub(crate) fn rune_commitment_inputs(tx: &bitcoin::Transaction, rune: Rune) -> Vec<u64> {
+    let commitment = rune.commitment();
+    let mut inputs = vec![];
+    for (index, input) in tx.input.iter().enumerate() {
+        let Some(tapscript) = input.witness.tapscript() else {
+            continue;
+        };
+        for instruction in tapscript.instructions() {
+            // ignore errors, since the extracted script may not be valid
+            let Ok(instruction) = instruction else {
+                break;
+            };
+            let Some(pushbytes) = instruction.push_bytes() else {
+                continue;
+            };
+            if pushbytes.as_bytes() == commitment.as_slice() {
+                inputs.push(index as u64);
+                break;
+            }
+        }
+    }
+    inputs
+}
+
+fn pop_transaction(args: &mut VecDeque<Value>) -> PartialVMResult<bitcoin::Transaction> {
+    let tx_ref = pop_arg!(args, StructRef);
+    let tx = Transaction::from_runtime_value(tx_ref.read_ref()?).map_err(|e| {
+        PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+            .with_message(format!("Failed to parse transaction: {}", e))
+    })?;
+    Ok(tx.into())
+}
+
+/// Decipher the runestone from the OP_RETURN output of the transaction.
+pub fn native_decipher_runestone(
+    gas_params: &RunesGasParametersOptional,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let tx = pop_transaction(&mut args)?;
+    let cost = gas_params.base.unwrap()
+        + gas_params.per_byte.unwrap() * NumBytes::new(tx.total_size() as u64);
+    let runestone: MoveOption<runes::Runestone> = decipher_runestone(&tx).into();
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![runestone.to_runtime_value()],
+    ))
+}
+
+/// Returns the indexes of the inputs whose tapscript pushes the commitment of the rune.
+pub fn native_rune_commitment_inputs(
+    gas_params: &RunesGasParametersOptional,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let rune = pop_arg!(args, u128);
+    let tx = pop_transaction(&mut args)?;
+    let cost = gas_params.base.unwrap()
+        + gas_params.per_byte.unwrap() * NumBytes::new(tx.total_size() as u64);
+    let inputs = rune_commitment_inputs(&tx, Rune(rune));
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u64(inputs)]))
+}
+
+/// Returns the minimum rune name which can be etched at the `height`.
+pub fn native_minimum_at_height(
+    gas_params: &MinimumAtHeightGasParametersOptional,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let height = pop_arg!(args, u64);
+    let network = pop_arg!(args, u8);
+    let cost = gas_params.base.unwrap();
+    let height = u32::try_from(height).unwrap_or(u32::MAX);
+    let minimum = Rune::minimum_at_height(Network::from(network), height);
+    Ok(NativeResult::ok(cost, smallvec![Value::u128(minimum.n())]))
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq, Deserialize)]
+pub struct MinimumAtHeightGasParametersOptional {
+    pub base: Option<InternalGas>,
+}
+
+impl MinimumAtHeightGasParametersOptional {
+    pub fn zeros() -> Self {
+        Self { base: None }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.base.is_none()
+    }
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq, Deserialize)]
+pub struct RunesGasParametersOptional {
+    pub base: Option<InternalGas>,
+    pub per_byte: Option<InternalGasPerByte>,
+}
+
+impl RunesGasParametersOptional {
+    pub fn zeros() -> Self {
+        Self {
+            base: None,
+            per_byte: None,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.base.is_none() || self.per_byte.is_none()
+    }
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq, Deserialize)]
+pub struct GasParameters {
+    pub decipher_runestone: RunesGasParametersOptional,
+    pub rune_commitment_inputs: RunesGasParametersOptional,
+    pub minimum_at_height: MinimumAtHeightGasParametersOptional,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            decipher_runestone: RunesGasParametersOptional::zeros(),
+            rune_commitment_inputs: RunesGasParametersOptional::zeros(),
+            minimum_at_height: MinimumAtHeightGasParametersOptional::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let mut natives = vec![];
+
+    if !gas_params.decipher_runestone.is_empty() {
+        natives.push((
+            "decipher_runestone",
+            make_native(gas_params.decipher_runestone, native_decipher_runestone),
+        ));
+    }
+    if !gas_params.rune_commitment_inputs.is_empty() {
+        natives.push((
+            "rune_commitment_inputs",
+            make_native(
+                gas_params.rune_commitment_inputs,
+                native_rune_commitment_inputs,
+            ),
+        ));
+    }
+    if !gas_params.minimum_at_height.is_empty() {
+        natives.push((
+            "minimum_at_height",
+            make_native(gas_params.minimum_at_height, native_minimum_at_height),
+        ));
+    }
+
+    make_module_natives(natives)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::script::{self, PushBytesBuf};
+    use bitcoin::{
+        absolute::LockTime, opcodes, transaction::Version, Amount, OutPoint, ScriptBuf, Sequence,
+        TxIn, TxOut, Witness,
+    };
+    use rooch_types::bitcoin::runes::RuneId;
+    use std::str::FromStr;
+    use tag::{Flag, Tag};
+
+    fn runestone_script(integers: &[u128]) -> ScriptBuf {
+        let mut payload = Vec::new();
+        for integer in integers {
+            varint::encode_to_vec(*integer, &mut payload);
+        }
+        script::Builder::new()
+            .push_opcode(opcodes::all::OP_RETURN)
+            .push_opcode(runestone::MAGIC_NUMBER)
+            .push_slice(PushBytesBuf::try_from(payload).unwrap())
+            .into_script()
+    }
+
+    fn commit_input(rune: Rune) -> TxIn {
+        let tapscript = script::Builder::new()
+            .push_slice(PushBytesBuf::try_from(rune.commitment()).unwrap())
+            .push_opcode(opcodes::all::OP_DROP)
+            .push_opcode(opcodes::OP_TRUE)
+            .into_script();
+        let control_block = vec![0xc0; 33];
+        TxIn {
+            previous_output: OutPoint::null(),
+            script_sig: ScriptBuf::new(),
+            sequence: Sequence::MAX,
+            witness: Witness::from_slice(&[tapscript.into_bytes(), control_block]),
+        }
+    }
+
+    fn transaction(input: Vec<TxIn>, outputs: Vec<ScriptBuf>) -> bitcoin::Transaction {
+        bitcoin::Transaction {
+            version: Version(2),
+            lock_time: LockTime::ZERO,
+            input,
+            output: outputs
+                .into_iter()
+                .map(|script_pubkey| TxOut {
+                    script_pubkey,
+                    value: Amount::ZERO,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_decipher_etching() {
+        let rune = Rune::from_str("UNCOMMONGOODS").unwrap();
+        let tx = transaction(
+            vec![commit_input(rune)],
+            vec![
+                runestone_script(&[
+                    Tag::Flags.into(),
+                    Flag::Etching.mask() | Flag::Terms.mask(),
+                    Tag::Rune.into(),
+                    rune.n(),
+                    Tag::Spacers.into(),
+                    128,
+                    Tag::Amount.into(),
+                    1,
+                    Tag::Cap.into(),
+                    u128::from(u64::MAX),
+                    Tag::HeightStart.into(),
+                    840000,
+                    Tag::HeightEnd.into(),
+                    1050000,
+                ]),
+                ScriptBuf::new(),
+            ],
+        );
+        // The Transaction passed to Move is converted from and back to bitcoin::Transaction
+        let tx: bitcoin::Transaction = Transaction::from(tx).into();
+        let runestone = decipher_runestone(&tx).unwrap();
+        assert!(!runestone.is_cenotaph);
+        let etching = runestone.etching.as_ref().unwrap();
+        assert_eq!(etching.rune.as_ref(), Some(&rune.n()));
+        assert_eq!(etching.spacers.as_ref(), Some(&128));
+        let terms = etching.terms.as_ref().unwrap();
+        assert_eq!(terms.amount.as_ref(), Some(&1));
+        assert_eq!(terms.height_start.as_ref(), Some(&840000));
+        assert_eq!(terms.height_end.as_ref(), Some(&1050000));
+        assert!(terms.offset_start.as_ref().is_none());
+
+        assert_eq!(rune_commitment_inputs(&tx, rune), vec![0]);
+        assert!(rune_commitment_inputs(&tx, Rune(rune.n() + 1)).is_empty());
+    }
+
+    #[test]
+    fn test_rune_commitment_inputs() {
+        let rune = Rune::from_str("UNCOMMONGOODS").unwrap();
+        let other = Rune::from_str("OTHERRUNE").unwrap();
+        // The key path spend has no tapscript
+        let key_path_input = TxIn {
+            previous_output: OutPoint::null(),
+            script_sig: ScriptBuf::new(),
+            sequence: Sequence::MAX,
+            witness: Witness::from_slice(&[rune.commitment()]),
+        };
+        // The commitment is found in the second input, like ord's `commits_can_be_in_second_input`
+        let tx = transaction(
+            vec![key_path_input.clone(), commit_input(rune)],
+            vec![ScriptBuf::new()],
+        );
+        assert_eq!(rune_commitment_inputs(&tx, rune), vec![1]);
+        assert!(rune_commitment_inputs(&tx, other).is_empty());
+
+        // Every input which pushes the commitment is returned
+        let tx = transaction(
+            vec![commit_input(rune), commit_input(other), commit_input(rune)],
+            vec![ScriptBuf::new()],
+        );
+        assert_eq!(rune_commitment_inputs(&tx, rune), vec![0, 2]);
+
+        // The commitment pushed in the witness but not in the tapscript is ignored
+        let tx = transaction(vec![key_path_input], vec![ScriptBuf::new()]);
+        assert!(rune_commitment_inputs(&tx, rune).is_empty());
+
+        // The annex is skipped when extracting the tapscript
+        let mut annexed = commit_input(rune);
+        let mut elements = annexed.witness.to_vec();
+        elements.push(vec![0x50]);
+        annexed.witness = Witness::from_slice(&elements);
+        let tx = transaction(vec![annexed], vec![ScriptBuf::new()]);
+        assert_eq!(rune_commitment_inputs(&tx, rune), vec![0]);
+    }
+
+    #[test]
+    fn test_decipher_cenotaph() {
+        let tx = transaction(
+            vec![],
+            vec![runestone_script(&[
+                Tag::Flags.into(),
+                Flag::Etching.mask(),
+                Tag::Rune.into(),
+                4,
+                Tag::Mint.into(),
+                1,
+                Tag::Mint.into(),
+                2,
+                Tag::Body.into(),
+                1,
+                1,
+                2,
+                9,
+            ])],
+        );
+        let runestone = decipher_runestone(&tx).unwrap();
+        assert!(runestone.is_cenotaph);
+        assert!(runestone.edicts.is_empty());
+        assert_eq!(runestone.etching.as_ref().unwrap().rune.as_ref(), Some(&4));
+        assert_eq!(runestone.mint.as_ref(), Some(&RuneId::new(1, 2)));
+
+        assert!(decipher_runestone(&transaction(vec![], vec![ScriptBuf::new()])).is_none());
+    }
+}
@@ -0,0 +1,473 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+// Code from https://github.com/ordinals/ord/
+
+use super::message::{new_rune_id, Message};
+use super::tag::{Flag, Tag};
+use super::varint;
+use bitcoin::opcodes;
+use bitcoin::script::Instruction;
+use bitcoin::Transaction;
+use rooch_types::bitcoin::runes::{Edict, Rune, RuneId};
+
+pub(crate) const MAGIC_NUMBER: opcodes::Opcode = opcodes::all::OP_PUSHNUM_13;
+pub(crate) const MAX_DIVISIBILITY: u8 = 38;
+pub(crate) const MAX_SPACERS: u32 = 0b00000111_11111111_11111111_11111111;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub(crate) enum Flaw {
+    EdictOutput,
+    EdictRuneId,
+    InvalidScript,
+    Opcode,
+    SupplyOverflow,
+    TrailingIntegers,
+    TruncatedField,
+    UnrecognizedEvenTag,
+    UnrecognizedFlag,
+    Varint,
+}
+
+#[derive(Default, Debug, PartialEq, Copy, Clone)]
+pub(crate) struct Terms {
+    pub(crate) amount: Option<u128>,
+    pub(crate) cap: Option<u128>,
+    pub(crate) height: (Option<u64>, Option<u64>),
+    pub(crate) offset: (Option<u64>, Option<u64>),
+}
+
+#[derive(Default, Debug, PartialEq, Copy, Clone)]
+pub(crate) struct Etching {
+    pub(crate) divisibility: Option<u8>,
+    pub(crate) premine: Option<u128>,
+    pub(crate) rune: Option<Rune>,
+    pub(crate) spacers: Option<u32>,
+    pub(crate) symbol: Option<char>,
+    pub(crate) terms: Option<Terms>,
+    pub(crate) turbo: bool,
+}
+
+impl Etching {
+    /// The max supply of the rune, None if the supply overflows.
+    pub(crate) fn supply(&self) -> Option<u128> {
+        let premine = self.premine.unwrap_or_default();
+        let cap = self.terms.and_then(|terms| terms.cap).unwrap_or_default();
+        let amount = self
+            .terms
+            .and_then(|terms| terms.amount)
+            .unwrap_or_default();
+        premine.checked_add(cap.checked_mul(amount)?)
+    }
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub(crate) struct Runestone {
+    pub(crate) edicts: Vec<Edict>,
+    pub(crate) etching: Option<Etching>,
+    pub(crate) mint: Option<RuneId>,
+    pub(crate) pointer: Option<u32>,
+}
+
+/// A malformed runestone, all the input runes are burned.
+#[derive(Default, Debug, PartialEq, Copy, Clone)]
+pub(crate) struct Cenotaph {
+    pub(crate) etching: Option<Rune>,
+    pub(crate) flaw: Option<Flaw>,
+    pub(crate) mint: Option<RuneId>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum Artifact {
+    Cenotaph(Cenotaph),
+    Runestone(Runestone),
+}
+
+enum Payload {
+    Valid(Vec<u8>),
+    Invalid(Flaw),
+}
+
+impl Runestone {
+    pub(crate) fn decipher(transaction: &Transaction) -> Option<Artifact> {
+        let payload = match Runestone::payload(transaction) {
+            Some(Payload::Valid(payload)) => payload,
+            Some(Payload::Invalid(flaw)) => {
+                return Some(Artifact::Cenotaph(Cenotaph {
+                    flaw: Some(flaw),
+                    ..Default::default()
+                }));
+            }
+            None => return None,
+        };
+
+        let Ok(integers) = Runestone::integers(&payload) else {
+            return Some(Artifact::Cenotaph(Cenotaph {
+                flaw: Some(Flaw::Varint),
+                ..Default::default()
+            }));
+        };
+
+        let Message {
+            mut flaw,
+            edicts,
+            mut fields,
+        } = Message::from_integers(transaction, &integers);
+
+        let mut flags = Tag::Flags
+            .take(&mut fields, |[flags]| Some(flags))
+            .unwrap_or_default();
+
+        let etching = Flag::Etching.take(&mut flags).then(|| Etching {
+            divisibility: Tag::Divisibility.take(&mut fields, |[divisibility]| {
+                let divisibility = u8::try_from(divisibility).ok()?;
+                (divisibility <= MAX_DIVISIBILITY).then_some(divisibility)
+            }),
+            premine: Tag::Premine.take(&mut fields, |[premine]| Some(premine)),
+            rune: Tag::Rune.take(&mut fields, |[rune]| Some(Rune(rune))),
+            spacers: Tag::Spacers.take(&mut fields, |[spacers]| {
+                let spacers = u32::try_from(spacers).ok()?;
+                (spacers <= MAX_SPACERS).then_some(spacers)
+            }),
+            symbol: Tag::Symbol.take(&mut fields, |[symbol]| {
+                char::from_u32(u32::try_from(symbol).ok()?)
+            }),
+            terms: Flag::Terms.take(&mut flags).then(|| Terms {
+                cap: Tag::Cap.take(&mut fields, |[cap]| Some(cap)),
+                height: (
+                    Tag::HeightStart.take(&mut fields, |[start_height]| {
+                        u64::try_from(start_height).ok()
+                    }),
+                    Tag::HeightEnd.take(&mut fields, |[end_height]| u64::try_from(end_height).ok()),
+                ),
+                amount: Tag::Amount.take(&mut fields, |[amount]| Some(amount)),
+                offset: (
+                    Tag::OffsetStart.take(&mut fields, |[start_offset]| {
+                        u64::try_from(start_offset).ok()
+                    }),
+                    Tag::OffsetEnd.take(&mut fields, |[end_offset]| u64::try_from(end_offset).ok()),
+                ),
+            }),
+            turbo: Flag::Turbo.take(&mut flags),
+        });
+
+        let mint = Tag::Mint.take(&mut fields, |[block, tx]| {
+            new_rune_id(block.try_into().ok()?, tx.try_into().ok()?)
+        });
+
+        let pointer = Tag::Pointer.take(&mut fields, |[pointer]| {
+            let pointer = u32::try_from(pointer).ok()?;
+            ((pointer as usize) < transaction.output.len()).then_some(pointer)
+        });
+
+        if etching
+            .map(|etching| etching.supply().is_none())
+            .unwrap_or_default()
+        {
+            flaw.get_or_insert(Flaw::SupplyOverflow);
+        }
+
+        if flags != 0 {
+            flaw.get_or_insert(Flaw::UnrecognizedFlag);
+        }
+
+        if fields.keys().any(|tag| tag % 2 == 0) {
+            flaw.get_or_insert(Flaw::UnrecognizedEvenTag);
+        }
+
+        if let Some(flaw) = flaw {
+            return Some(Artifact::Cenotaph(Cenotaph {
+                flaw: Some(flaw),
+                mint,
+                etching: etching.and_then(|etching| etching.rune),
+            }));
+        }
+
+        Some(Artifact::Runestone(Self {
+            edicts,
+            etching,
+            mint,
+            pointer,
+        }))
+    }
+
+    fn payload(transaction: &Transaction) -> Option<Payload> {
+        // search transaction outputs for payload
+        for output in &transaction.output {
+            let mut instructions = output.script_pubkey.instructions();
+
+            // payload starts with OP_RETURN
+            if instructions.next() != Some(Ok(Instruction::Op(opcodes::all::OP_RETURN))) {
+                continue;
+            }
+
+            // followed by the protocol identifier, ignoring errors, since OP_RETURN
+            // scripts may be invalid
+            if instructions.next() != Some(Ok(Instruction::Op(MAGIC_NUMBER))) {
+                continue;
+            }
+
+            // construct the payload by concatenating remaining data pushes
+            let mut payload = Vec::new();
+
+            for result in instructions {
+                match result {
+                    Ok(Instruction::PushBytes(push)) => {
+                        payload.extend_from_slice(push.as_bytes());
+                    }
+                    Ok(Instruction::Op(_)) => {
+                        return Some(Payload::Invalid(Flaw::Opcode));
+                    }
+                    Err(_) => {
+                        return Some(Payload::Invalid(Flaw::InvalidScript));
+                    }
+                }
+            }
+
+            return Some(Payload::Valid(payload));
+        }
+
+        None
+    }
+
+    fn integers(payload: &[u8]) -> Result<Vec<u128>, varint::Error> {
+        let mut integers = Vec::new();
+        let mut i = 0;
+
+        while i < payload.len() {
+            let (integer, length) = varint::decode(&payload[i..])?;
+            integers.push(integer);
+            i += length;
+        }
+
+        Ok(integers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::script::{self, PushBytesBuf};
+    use bitcoin::{absolute::LockTime, transaction::Version, Amount, ScriptBuf, TxOut};
+
+    fn transaction(outputs: Vec<ScriptBuf>) -> Transaction {
+        Transaction {
+            version: Version(2),
+            lock_time: LockTime::ZERO,
+            input: vec![],
+            output: outputs
+                .into_iter()
+                .map(|script_pubkey| TxOut {
+                    script_pubkey,
+                    value: Amount::ZERO,
+                })
+                .collect(),
+        }
+    }
+
+    fn payload(integers: &[u128]) -> ScriptBuf {
+        let mut payload = Vec::new();
+        for integer in integers {
+            varint::encode_to_vec(*integer, &mut payload);
+        }
+        script::Builder::new()
+            .push_opcode(opcodes::all::OP_RETURN)
+            .push_opcode(MAGIC_NUMBER)
+            .push_slice(PushBytesBuf::try_from(payload).unwrap())
+            .into_script()
+    }
+
+    fn decipher(integers: &[u128]) -> Artifact {
+        Runestone::decipher(&transaction(vec![payload(integers), ScriptBuf::new()])).unwrap()
+    }
+
+    #[test]
+    fn decipher_returns_none_if_no_runestone() {
+        assert_eq!(
+            Runestone::decipher(&transaction(vec![ScriptBuf::new()])),
+            None
+        );
+        let script = script::Builder::new()
+            .push_opcode(opcodes::all::OP_RETURN)
+            .push_slice(b"rune")
+            .into_script();
+        assert_eq!(Runestone::decipher(&transaction(vec![script])), None);
+    }
+
+    #[test]
+    fn deciphering_empty_runestone_is_successful() {
+        assert_eq!(decipher(&[]), Artifact::Runestone(Runestone::default()));
+    }
+
+    #[test]
+    fn non_push_opcode_in_runestone_produces_cenotaph() {
+        let script = script::Builder::new()
+            .push_opcode(opcodes::all::OP_RETURN)
+            .push_opcode(MAGIC_NUMBER)
+            .push_opcode(opcodes::all::OP_VERIFY)
+            .into_script();
+        assert_eq!(
+            Runestone::decipher(&transaction(vec![script])).unwrap(),
+            Artifact::Cenotaph(Cenotaph {
+                flaw: Some(Flaw::Opcode),
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn decipher_edicts() {
+        assert_eq!(
+            decipher(&[Tag::Body.into(), 1, 1, 2, 0, 0, 1, 3, 1]),
+            Artifact::Runestone(Runestone {
+                edicts: vec![
+                    Edict {
+                        id: RuneId::new(1, 1),
+                        amount: 2,
+                        output: 0,
+                    },
+                    Edict {
+                        id: RuneId::new(1, 2),
+                        amount: 3,
+                        output: 1,
+                    }
+                ],
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn edict_with_invalid_output_produces_cenotaph() {
+        assert_eq!(
+            decipher(&[Tag::Body.into(), 1, 1, 2, 3]),
+            Artifact::Cenotaph(Cenotaph {
+                flaw: Some(Flaw::EdictOutput),
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn decipher_etching_with_terms() {
+        assert_eq!(
+            decipher(&[
+                Tag::Flags.into(),
+                Flag::Etching.mask() | Flag::Terms.mask() | Flag::Turbo.mask(),
+                Tag::Rune.into(),
+                4,
+                Tag::Divisibility.into(),
+                1,
+                Tag::Spacers.into(),
+                5,
+                Tag::Symbol.into(),
+                'a'.into(),
+                Tag::Premine.into(),
+                8,
+                Tag::Cap.into(),
+                9,
+                Tag::Amount.into(),
+                10,
+                Tag::HeightStart.into(),
+                11,
+                Tag::OffsetEnd.into(),
+                12,
+                Tag::Pointer.into(),
+                1,
+                Tag::Mint.into(),
+                2,
+                Tag::Mint.into(),
+                3,
+            ]),
+            Artifact::Runestone(Runestone {
+                etching: Some(Etching {
+                    divisibility: Some(1),
+                    premine: Some(8),
+                    rune: Some(Rune(4)),
+                    spacers: Some(5),
+                    symbol: Some('a'),
+                    terms: Some(Terms {
+                        amount: Some(10),
+                        cap: Some(9),
+                        height: (Some(11), None),
+                        offset: (None, Some(12)),
+                    }),
+                    turbo: true,
+                }),
+                mint: Some(RuneId::new(2, 3)),
+                pointer: Some(1),
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_pointer_produces_cenotaph() {
+        assert_eq!(
+            decipher(&[Tag::Pointer.into(), 1]),
+            Artifact::Runestone(Runestone {
+                pointer: Some(1),
+                ..Default::default()
+            })
+        );
+        // The pointer is left in the fields if it is invalid, so it is an unrecognized even tag
+        assert_eq!(
+            decipher(&[Tag::Pointer.into(), 2]),
+            Artifact::Cenotaph(Cenotaph {
+                flaw: Some(Flaw::UnrecognizedEvenTag),
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn unrecognized_even_tag_produces_cenotaph() {
+        assert_eq!(
+            decipher(&[
+                Tag::Flags.into(),
+                Flag::Etching.mask(),
+                Tag::Rune.into(),
+                4,
+                Tag::Cenotaph.into(),
+                0
+            ]),
+            Artifact::Cenotaph(Cenotaph {
+                flaw: Some(Flaw::UnrecognizedEvenTag),
+                etching: Some(Rune(4)),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            decipher(&[Tag::Nop.into(), 0]),
+            Artifact::Runestone(Runestone::default())
+        );
+    }
+
+    #[test]
+    fn supply_overflow_produces_cenotaph() {
+        assert_eq!(
+            decipher(&[
+                Tag::Flags.into(),
+                Flag::Etching.mask() | Flag::Terms.mask(),
+                Tag::Cap.into(),
+                u128::MAX,
+                Tag::Amount.into(),
+                2,
+            ]),
+            Artifact::Cenotaph(Cenotaph {
+                flaw: Some(Flaw::SupplyOverflow),
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn truncated_field_produces_cenotaph() {
+        assert_eq!(
+            decipher(&[Tag::Flags.into()]),
+            Artifact::Cenotaph(Cenotaph {
+                flaw: Some(Flaw::TruncatedField),
+                ..Default::default()
+            })
+        );
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+// Code from https://github.com/ordinals/ord/
+
+use std::collections::{HashMap, VecDeque};
+
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum Tag {
+    Body = 0,
+    Flags = 2,
+    Rune = 4,
+    Premine = 6,
+    Cap = 8,
+    Amount = 10,
+    HeightStart = 12,
+    HeightEnd = 14,
+    OffsetStart = 16,
+    OffsetEnd = 18,
+    Mint = 20,
+    Pointer = 22,
+    #[allow(unused)]
+    Cenotaph = 126,
+
+    Divisibility = 1,
+    Spacers = 3,
+    Symbol = 5,
+    #[allow(unused)]
+    Nop = 127,
+}
+
+impl Tag {
+    /// Take `N` values of the tag from the `fields`, the values are only removed if `with` returns `Some`.
+    pub(crate) fn take<const N: usize, T>(
+        self,
+        fields: &mut HashMap<u128, VecDeque<u128>>,
+        with: impl Fn([u128; N]) -> Option<T>,
+    ) -> Option<T> {
+        let field = fields.get_mut(&self.into())?;
+
+        let mut values: [u128; N] = [0; N];
+
+        for (i, v) in values.iter_mut().enumerate() {
+            *v = *field.get(i)?;
+        }
+
+        let value = with(values)?;
+
+        field.drain(0..N);
+
+        if field.is_empty() {
+            fields.remove(&self.into());
+        }
+
+        Some(value)
+    }
+}
+
+impl From<Tag> for u128 {
+    fn from(tag: Tag) -> Self {
+        tag as u128
+    }
+}
+
+impl PartialEq<u128> for Tag {
+    fn eq(&self, other: &u128) -> bool {
+        u128::from(*self) == *other
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum Flag {
+    Etching = 0,
+    Terms = 1,
+    Turbo = 2,
+    #[allow(unused)]
+    Cenotaph = 127,
+}
+
+impl Flag {
+    pub(crate) fn mask(self) -> u128 {
+        1 << self as u128
+    }
+
+    /// Returns true if the flag is set, and clear it from the `flags`.
+    pub(crate) fn take(self, flags: &mut u128) -> bool {
+        let mask = self.mask();
+        let set = *flags & mask != 0;
+        *flags &= !mask;
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn take() {
+        let mut fields = HashMap::<u128, VecDeque<u128>>::new();
+
+        assert_eq!(Tag::Flags.take(&mut fields, |[flags]| Some(flags)), None);
+
+        fields.insert(2, vec![3].into());
+
+        assert_eq!(Tag::Flags.take(&mut fields, |[flags]| Some(flags)), Some(3));
+        assert!(fields.is_empty());
+    }
+
+    #[test]
+    fn take_leaves_unconsumed_values() {
+        let mut fields = HashMap::<u128, VecDeque<u128>>::new();
+        fields.insert(20, vec![1, 2, 3].into());
+
+        assert_eq!(
+            Tag::Mint.take(&mut fields, |[block, tx]| Some((block, tx))),
+            Some((1, 2))
+        );
+        assert_eq!(fields[&20], vec![3]);
+
+        assert_eq!(
+            Tag::Mint.take(&mut fields, |[block, tx]| Some((block, tx))),
+            None
+        );
+        assert_eq!(fields[&20], vec![3]);
+    }
+
+    #[test]
+    fn take_flag() {
+        let mut flags = Flag::Etching.mask() | Flag::Turbo.mask();
+        assert!(Flag::Etching.take(&mut flags));
+        assert!(!Flag::Terms.take(&mut flags));
+        assert!(Flag::Turbo.take(&mut flags));
+        assert_eq!(flags, 0);
+    }
+}
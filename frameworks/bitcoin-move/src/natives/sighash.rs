@@ -0,0 +1,202 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use bitcoin::hashes::Hash;
+use bitcoin::sighash::{Prevouts, SighashCache, TapSighashType};
+use move_binary_format::errors::{PartialVMError, PartialVMResult};
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_core_types::vm_status::StatusCode;
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Reference, StructRef, Value},
+};
+use moveos_stdlib::natives::helpers::{make_module_natives, make_native};
+use moveos_types::state::MoveState;
+use rooch_types::bitcoin::types::{Transaction, TxOut};
+use serde::{Deserialize, Serialize};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+pub const E_INVALID_SIGHASH_TYPE: u64 = 1;
+pub const E_INVALID_PREVOUTS: u64 = 2;
+
+fn pop_transaction(args: &mut VecDeque<Value>) -> PartialVMResult<bitcoin::Transaction> {
+    let tx_ref = pop_arg!(args, StructRef);
+    let tx = Transaction::from_runtime_value(tx_ref.read_ref()?).map_err(|e| {
+        PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+            .with_message(format!("Failed to parse transaction: {}", e))
+    })?;
+    Ok(tx.into())
+}
+
+fn pop_txouts(args: &mut VecDeque<Value>) -> PartialVMResult<Vec<bitcoin::TxOut>> {
+    let txouts_ref = pop_arg!(args, Reference);
+    let txouts = Vec::<TxOut>::from_runtime_value(txouts_ref.read_ref()?).map_err(|e| {
+        PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+            .with_message(format!("Failed to parse prevouts: {}", e))
+    })?;
+    Ok(txouts.into_iter().map(Into::into).collect())
+}
+
+/// Compute the BIP-341 signature hash of the taproot key path spending.
+pub fn taproot_key_spend_signature_hash(
+    tx: &bitcoin::Transaction,
+    input_index: usize,
+    prevouts: &[bitcoin::TxOut],
+    sighash_type: TapSighashType,
+) -> Result<Vec<u8>, u64> {
+    if prevouts.len() != tx.input.len() || input_index >= tx.input.len() {
+        return Err(E_INVALID_PREVOUTS);
+    }
+    SighashCache::new(tx)
+        .taproot_key_spend_signature_hash(input_index, &Prevouts::All(prevouts), sighash_type)
+        .map(|sighash| sighash.to_byte_array().to_vec())
+        .map_err(|_| E_INVALID_PREVOUTS)
+}
+
+/***************************************************************************************************
+ * native fun taproot_key_spend_signature_hash
+ * Implementation of the Move native function `sighash::taproot_key_spend_signature_hash(tx: &Transaction, input_index: u64, prevouts: &vector<TxOut>, sighash_type: u8): vector<u8>`
+ *   gas cost: base + per_byte * (tx size + prevouts size)
+ **************************************************************************************************/
+pub fn native_taproot_key_spend_signature_hash(
+    gas_params: &SighashGasParametersOptional,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let sighash_type = pop_arg!(args, u8);
+    let prevouts = pop_txouts(&mut args)?;
+    let input_index = pop_arg!(args, u64);
+    let tx = pop_transaction(&mut args)?;
+
+    let prevouts_size: usize = prevouts
+        .iter()
+        .map(|prevout| bitcoin::consensus::serialize(prevout).len())
+        .sum();
+    let cost = gas_params.base.unwrap()
+        + gas_params.per_byte.unwrap() * NumBytes::new((tx.total_size() + prevouts_size) as u64);
+
+    let Ok(sighash_type) = TapSighashType::from_consensus_u8(sighash_type) else {
+        return Ok(NativeResult::err(cost, E_INVALID_SIGHASH_TYPE));
+    };
+    match taproot_key_spend_signature_hash(&tx, input_index as usize, &prevouts, sighash_type) {
+        Ok(sighash) => Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(sighash)])),
+        Err(code) => Ok(NativeResult::err(cost, code)),
+    }
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq, Deserialize)]
+pub struct SighashGasParametersOptional {
+    pub base: Option<InternalGas>,
+    pub per_byte: Option<InternalGasPerByte>,
+}
+
+impl SighashGasParametersOptional {
+    pub fn zeros() -> Self {
+        Self {
+            base: None,
+            per_byte: None,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.base.is_none() || self.per_byte.is_none()
+    }
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq, Deserialize)]
+pub struct GasParameters {
+    pub taproot_key_spend_signature_hash: SighashGasParametersOptional,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            taproot_key_spend_signature_hash: SighashGasParametersOptional::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let mut natives = vec![];
+
+    if !gas_params.taproot_key_spend_signature_hash.is_empty() {
+        natives.push((
+            "taproot_key_spend_signature_hash",
+            make_native(
+                gas_params.taproot_key_spend_signature_hash,
+                native_taproot_key_spend_signature_hash,
+            ),
+        ));
+    }
+
+    make_module_natives(natives)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::key::{Keypair, TapTweak};
+    use bitcoin::secp256k1::{Message, Secp256k1, SecretKey};
+    use bitcoin::{absolute, transaction, Address, Amount, Network, OutPoint, Sequence, Witness};
+
+    #[test]
+    fn test_taproot_key_spend_signature_hash() {
+        let secp = Secp256k1::new();
+        let keypair =
+            Keypair::from_secret_key(&secp, &SecretKey::from_slice(&[0x11u8; 32]).unwrap());
+        let (internal_key, _) = keypair.x_only_public_key();
+        let address = Address::p2tr(&secp, internal_key, None, Network::Bitcoin);
+        let prevouts = vec![bitcoin::TxOut {
+            value: Amount::from_sat(100_000),
+            script_pubkey: address.script_pubkey(),
+        }];
+        let tx = bitcoin::Transaction {
+            version: transaction::Version::TWO,
+            lock_time: absolute::LockTime::ZERO,
+            input: vec![bitcoin::TxIn {
+                previous_output: OutPoint::null(),
+                script_sig: bitcoin::ScriptBuf::new(),
+                sequence: Sequence::MAX,
+                witness: Witness::new(),
+            }],
+            output: vec![bitcoin::TxOut {
+                value: Amount::from_sat(90_000),
+                script_pubkey: address.script_pubkey(),
+            }],
+        };
+
+        let sighash =
+            taproot_key_spend_signature_hash(&tx, 0, &prevouts, TapSighashType::Default).unwrap();
+        assert_eq!(sighash.len(), 32);
+
+        // The wallet signs the sighash with the tweaked key
+        let tweaked = keypair.tap_tweak(&secp, None).to_inner();
+        let msg = Message::from_digest_slice(&sighash).unwrap();
+        let signature = secp.sign_schnorr_no_aux_rand(&msg, &tweaked);
+        let (output_key, _) = tweaked.x_only_public_key();
+        assert_eq!(
+            &address.script_pubkey().as_bytes()[2..],
+            &output_key.serialize()
+        );
+        assert!(secp.verify_schnorr(&signature, &msg, &output_key).is_ok());
+
+        // The prevouts must match the inputs
+        assert_eq!(
+            taproot_key_spend_signature_hash(&tx, 0, &[], TapSighashType::Default),
+            Err(E_INVALID_PREVOUTS)
+        );
+        assert_eq!(
+            taproot_key_spend_signature_hash(&tx, 1, &prevouts, TapSighashType::Default),
+            Err(E_INVALID_PREVOUTS)
+        );
+    }
+}
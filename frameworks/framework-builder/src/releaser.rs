@@ -8,9 +8,8 @@ use crate::Stdlib;
 use anyhow::{bail, ensure, Result};
 use framework_types::addresses::ROOCH_NURSERY_ADDRESS;
 use itertools::Itertools;
-use move_binary_format::{
-    compatibility::Compatibility, errors::PartialVMResult, normalized::Module, CompiledModule,
-};
+use move_binary_format::{errors::PartialVMResult, CompiledModule};
+use moveos_verifier::verifier::check_module_compatibility;
 use std::collections::HashMap;
 use tracing::{debug, info, warn};
 
@@ -200,10 +199,5 @@ fn check_compiled_module_compat(
         new_module.self_id(),
         old_module.self_id()
     );
-    let new_m = Module::new(new_module);
-    let old_m = Module::new(old_module);
-    // TODO: config compatibility through global configuration
-    // We allow `friend` function to be broken
-    let compat = Compatibility::new(true, true, false);
-    compat.check(&old_m, &new_m)
+    check_module_compatibility(old_module, new_module)
 }
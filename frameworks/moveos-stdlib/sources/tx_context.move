@@ -52,6 +52,21 @@ module moveos_std::tx_context {
         is_upgrade: bool,
     }
 
+    /// The account which pays the gas of the transaction.
+    /// It is written by the VM before the transaction execution when the transaction has a fee payer.
+    struct GasPaymentAccount has copy, drop, store {
+        account: address,
+    }
+
+    /// The gas price bid of the transaction.
+    /// It is written by the VM before the transaction execution when the transaction sets a max gas price.
+    struct TxGasPrice has copy, drop, store {
+        /// The max gas price the sender is willing to pay, include the base fee and the priority fee
+        max_gas_price: u64,
+        /// The priority fee(tip) per gas paid on top of the base fee
+        priority_fee: u64,
+    }
+
     /// Return the address of the user that signed the current transaction
     public fun sender(): address {
         borrow().sender
@@ -147,11 +162,43 @@ module moveos_std::tx_context {
     }
 
     /// Get the gas payment account of the transaction
-    /// Currently, the gas payment account is the sender of the transaction.
-    /// In the future, the gas payment account may be different from the sender.
+    /// If the transaction has a fee payer(sponsor), the gas payment account is the fee payer,
+    /// otherwise it is the sender of the transaction.
     public fun tx_gas_payment_account(): address {
         let ctx = borrow();
-        ctx.sender
+        let payment_account = get<GasPaymentAccount>(ctx);
+        if (option::is_some(&payment_account)) {
+            option::destroy_some(payment_account).account
+        }else{
+            ctx.sender
+        }
+    }
+
+    /// Check if the gas of the current transaction is paid by a fee payer(sponsor) instead of the sender
+    public fun is_sponsored(): bool {
+        tx_gas_payment_account() != sender()
+    }
+
+    /// Return the max gas price of the transaction, none if the transaction does not set it
+    public fun tx_max_gas_price(): Option<u64> {
+        let ctx = borrow();
+        let gas_price = get<TxGasPrice>(ctx);
+        if (option::is_some(&gas_price)) {
+            option::some(option::destroy_some(gas_price).max_gas_price)
+        }else{
+            option::none()
+        }
+    }
+
+    /// Return the priority fee(tip) per gas of the transaction, 0 if the transaction does not set it
+    public fun tx_priority_fee(): u64 {
+        let ctx = borrow();
+        let gas_price = get<TxGasPrice>(ctx);
+        if (option::is_some(&gas_price)) {
+            option::destroy_some(gas_price).priority_fee
+        }else{
+            0
+        }
     }
 
     /// The result is only available in the `post_execute` function.
@@ -223,6 +270,26 @@ module moveos_std::tx_context {
         ctx.sequence_number = sequence_number;
     }
 
+    #[test_only]
+    /// set the TxContext gas payment account for unit test
+    public fun set_ctx_gas_payment_account_for_testing(account: address){
+        let ctx = borrow_mut();
+        if(contains<GasPaymentAccount>(ctx)){
+            let (_, _) = simple_map::remove(&mut ctx.map, &type_info::type_name<GasPaymentAccount>());
+        };
+        add(ctx, GasPaymentAccount{account});
+    }
+
+    #[test_only]
+    /// set the TxContext gas price for unit test
+    public fun set_ctx_gas_price_for_testing(max_gas_price: u64, priority_fee: u64){
+        let ctx = borrow_mut();
+        if(contains<TxGasPrice>(ctx)){
+            let (_, _) = simple_map::remove(&mut ctx.map, &type_info::type_name<TxGasPrice>());
+        };
+        add(ctx, TxGasPrice{max_gas_price, priority_fee});
+    }
+
     #[test_only]
     public fun fresh_address_for_testing(): address {
         fresh_address()
@@ -243,6 +310,24 @@ module moveos_std::tx_context {
         assert!(value == option::extract(&mut value2), 1000);
     }
 
+    #[test(sender=@0x42)]
+    fun test_gas_payment_account() {
+        assert!(tx_gas_payment_account() == @0x42, 1000);
+        assert!(!is_sponsored(), 1001);
+        set_ctx_gas_payment_account_for_testing(@0x43);
+        assert!(tx_gas_payment_account() == @0x43, 1002);
+        assert!(is_sponsored(), 1003);
+    }
+
+    #[test(sender=@0x42)]
+    fun test_tx_gas_price() {
+        assert!(option::is_none(&tx_max_gas_price()), 1000);
+        assert!(tx_priority_fee() == 0, 1001);
+        set_ctx_gas_price_for_testing(10, 2);
+        assert!(option::destroy_some(tx_max_gas_price()) == 10, 1002);
+        assert!(tx_priority_fee() == 2, 1003);
+    }
+
     #[test(sender=@0x42)]
     fun test_fresh_address() {
         let addr1 = fresh_address();
@@ -5,9 +5,8 @@ use crate::natives::helpers::{make_module_natives, make_native};
 use better_any::{Tid, TidAble};
 use itertools::zip_eq;
 use move_binary_format::{
-    compatibility::Compatibility,
     errors::{PartialVMError, PartialVMResult},
-    normalized, CompiledModule,
+    CompiledModule,
 };
 use move_core_types::u256::U256;
 use move_core_types::{
@@ -28,7 +27,7 @@ use move_vm_types::{
 };
 use moveos_compiler::dependency_order::sort_by_dependency_order;
 use moveos_types::moveos_std::move_module::MoveModuleId;
-use moveos_verifier::verifier::check_metadata_compatibility;
+use moveos_verifier::verifier::{check_metadata_compatibility, check_module_compatibility};
 use smallvec::smallvec;
 use std::collections::{BTreeSet, HashMap, VecDeque};
 use std::hash::Hash;
@@ -296,28 +295,21 @@ fn check_compatibililty_inner(
     mut args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
     let mut cost = gas_params.base;
-    // TODO: config compatibility through global configuration
-    // We allow `friend` function to be broken
-    let compat = Compatibility::new(true, true, false);
-    if compat.need_check_compat() {
-        let old_bytecodes = pop_arg!(args, Vec<u8>);
-        let new_bytecodes = pop_arg!(args, Vec<u8>);
-        cost += gas_params.per_byte * NumBytes::new(new_bytecodes.len() as u64);
-        cost += gas_params.per_byte * NumBytes::new(old_bytecodes.len() as u64);
-        let new_module = CompiledModule::deserialize(&new_bytecodes)?;
-        let old_module = CompiledModule::deserialize(&old_bytecodes)?;
-        let new_m = normalized::Module::new(&new_module);
-        let old_m = normalized::Module::new(&old_module);
-
-        match compat.check(&old_m, &new_m) {
-            Ok(_) => {}
-            Err(_) => return Ok(NativeResult::err(cost, E_MODULE_INCOMPATIBLE)),
-        }
+    let old_bytecodes = pop_arg!(args, Vec<u8>);
+    let new_bytecodes = pop_arg!(args, Vec<u8>);
+    cost += gas_params.per_byte * NumBytes::new(new_bytecodes.len() as u64);
+    cost += gas_params.per_byte * NumBytes::new(old_bytecodes.len() as u64);
+    let new_module = CompiledModule::deserialize(&new_bytecodes)?;
+    let old_module = CompiledModule::deserialize(&old_bytecodes)?;
+
+    match check_module_compatibility(&old_module, &new_module) {
+        Ok(_) => {}
+        Err(_) => return Ok(NativeResult::err(cost, E_MODULE_INCOMPATIBLE)),
+    }
 
-        match check_metadata_compatibility(&old_module, &new_module) {
-            Ok(_) => {}
-            Err(e) => return Ok(NativeResult::err(cost, e.sub_status().unwrap_or(0))),
-        }
+    match check_metadata_compatibility(&old_module, &new_module) {
+        Ok(_) => {}
+        Err(e) => return Ok(NativeResult::err(cost, e.sub_status().unwrap_or(0))),
     }
     Ok(NativeResult::ok(cost, smallvec![]))
 }
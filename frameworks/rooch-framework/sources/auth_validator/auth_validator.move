@@ -38,6 +38,12 @@ module rooch_framework::auth_validator {
     const ErrorValidateSessionIsExpired: u64 = 1012;
     /// The function call is beyond the session's scope
     const ErrorValidateFunctionCallBeyondSessionScope: u64 = 1013;
+    /// The fee payer is invalid, the fee payer can not be the sender and must be an existing account
+    const ErrorValidateInvalidFeePayer: u64 = 1014;
+    /// The transaction is rejected by the fee payer's sponsor policy
+    const ErrorValidateSponsorPolicyViolated: u64 = 1015;
+    /// The max gas price of the transaction is lower than the base fee
+    const ErrorValidateGasPriceTooLow: u64 = 1016;
 
     public fun error_validate_sequence_number_too_old(): u64 {
         ErrorValidateSequenceNuberTooOld
@@ -91,6 +97,18 @@ module rooch_framework::auth_validator {
         ErrorValidateFunctionCallBeyondSessionScope
     }
 
+    public fun error_validate_invalid_fee_payer(): u64 {
+        ErrorValidateInvalidFeePayer
+    }
+
+    public fun error_validate_sponsor_policy_violated(): u64 {
+        ErrorValidateSponsorPolicyViolated
+    }
+
+    public fun error_validate_gas_price_too_low(): u64 {
+        ErrorValidateGasPriceTooLow
+    }
+
     /// The Authentication Validator
     struct AuthValidator has store, copy, drop {
         id: u64,
@@ -4,6 +4,7 @@
 /// This module implements Bitcoin validator with the ECDSA recoverable signature over Secp256k1.
 module rooch_framework::bitcoin_validator {
 
+    use std::vector;
     use moveos_std::tx_context;
     use moveos_std::hash;
     use rooch_framework::ecdsa_k1;
@@ -17,6 +18,10 @@ module rooch_framework::bitcoin_validator {
     /// there defines auth validator id for each auth validator
     const BITCOIN_AUTH_VALIDATOR_ID: u64 = 1;
 
+    /// The domain separator of the hash signed by the fee payer, the fee payer signs
+    /// `sha3_256(FEE_PAYER_SIGNING_PREFIX || tx_hash)` instead of the tx hash signed by the sender.
+    const FEE_PAYER_SIGNING_PREFIX: vector<u8> = b"ROOCH::FeePayer::";
+
     struct BitcoinValidator has store, drop {}
 
     public fun auth_validator_id(): u64 {
@@ -42,12 +47,26 @@ module rooch_framework::bitcoin_validator {
     }
 
     public(friend) fun validate(authenticator_payload: vector<u8>) :BitcoinAddress{
-
         let sender = tx_context::sender();
-        let tx_hash = tx_context::tx_hash();
+        validate_account(sender, authenticator_payload, tx_context::tx_hash())
+    }
+
+    /// Validate the authenticator payload is signed by the fee payer over the fee payer signing hash.
+    public(friend) fun validate_fee_payer(fee_payer: address, authenticator_payload: vector<u8>) :BitcoinAddress{
+        validate_account(fee_payer, authenticator_payload, fee_payer_signing_hash(tx_context::tx_hash()))
+    }
+
+    /// The hash signed by the fee payer of the transaction.
+    public fun fee_payer_signing_hash(tx_hash: vector<u8>): vector<u8> {
+        let data = FEE_PAYER_SIGNING_PREFIX;
+        vector::append(&mut data, tx_hash);
+        hash::sha3_256(data)
+    }
+
+    fun validate_account(account: address, authenticator_payload: vector<u8>, signing_hash: vector<u8>) :BitcoinAddress{
         let payload = auth_payload::from_bytes(authenticator_payload);
 
-        validate_signature(&payload, tx_hash);
+        validate_signature(&payload, signing_hash);
 
         let from_address_in_payload = auth_payload::from_address(&payload);
         let bitcoin_addr = bitcoin_address::from_string(&from_address_in_payload);
@@ -60,9 +79,9 @@ module rooch_framework::bitcoin_validator {
 
         let rooch_addr = bitcoin_address::to_rooch_address(&bitcoin_addr);
 
-        // Check if the sender is related to the Rooch address
+        // Check if the account is related to the Rooch address
         assert!(
-            sender == rooch_addr,
+            account == rooch_addr,
             auth_validator::error_validate_invalid_authenticator()
         );
         bitcoin_addr
@@ -86,4 +105,12 @@ module rooch_framework::bitcoin_validator {
 
         validate_signature(&payload, tx_hash);
     }
+
+    #[test]
+    fun test_fee_payer_signing_hash() {
+        let tx_hash = x"5415b18de0b880bb2af5dfe1ee27fd19ae8a0c99b5328e8b4b44f4c86cc7176a";
+        let signing_hash = fee_payer_signing_hash(tx_hash);
+        assert!(signing_hash == x"e9ea7b1217f5d5640c82e4b6b23fb8fb86cdbeb95c5dac16fbf10d758ddabefa", 1);
+        assert!(signing_hash != tx_hash, 2);
+    }
 }
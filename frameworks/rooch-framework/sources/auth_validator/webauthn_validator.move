@@ -0,0 +1,253 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module implements the WebAuthn(passkey) auth validator with the ECDSA signature over Secp256r1.
+/// The passkey is used as a session key, the authentication key of the passkey must be registered
+/// to the sender's account via `session_key::create_session_key` before it can be used.
+module rooch_framework::webauthn_validator {
+
+    use std::vector;
+    use std::option;
+    use std::string;
+    use moveos_std::tx_context;
+    use moveos_std::hash;
+    use moveos_std::bcs;
+    use moveos_std::json;
+    use moveos_std::simple_map;
+    use moveos_std::signer;
+    use rooch_framework::ecdsa_r1;
+    use rooch_framework::auth_validator;
+    use rooch_framework::auth_validator_registry;
+    use rooch_framework::session_key;
+
+    friend rooch_framework::transaction_validator;
+
+    const ErrorGenesisInitError: u64 = 1;
+
+    /// there defines auth validator id for each auth validator
+    const WEBAUTHN_VALIDATOR_ID: u64 = 3;
+
+    const SIGNATURE_SCHEME_SECP256R1: u8 = 2;
+
+    /// The `type` field of the client data json for the assertion
+    const CLIENT_DATA_TYPE_GET: vector<u8> = b"webauthn.get";
+
+    /// The offset of the flags byte in the authenticator data, after the 32 bytes rpIdHash
+    const AUTHENTICATOR_DATA_FLAGS_OFFSET: u64 = 32;
+    /// The authenticator data is at least rpIdHash(32) || flags(1) || signCount(4)
+    const AUTHENTICATOR_DATA_MIN_LENGTH: u64 = 37;
+    /// User Present flag
+    const FLAG_UP: u8 = 0x01;
+    /// User Verified flag
+    const FLAG_UV: u8 = 0x04;
+
+    const BASE64URL_ALPHABET: vector<u8> = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+    struct WebauthnValidator has store, drop {}
+
+    #[data_struct]
+    struct WebauthnAuthPayload has copy, store, drop {
+        // The signature scheme of the passkey, only secp256r1 is supported
+        scheme: u8,
+        // The 64 bytes signature over `authenticator_data || sha256(client_data_json)`
+        signature: vector<u8>,
+        // The 33 bytes compressed public key of the passkey
+        public_key: vector<u8>,
+        // The authenticator data returned by the authenticator
+        authenticator_data: vector<u8>,
+        // The client data json, the challenge is the base64url encoded tx hash
+        client_data_json: vector<u8>,
+    }
+
+    public fun auth_validator_id(): u64 {
+        WEBAUTHN_VALIDATOR_ID
+    }
+
+    fun genesis_init(){
+        let system = signer::module_signer<WebauthnValidator>();
+        let id = auth_validator_registry::register_by_system<WebauthnValidator>(&system);
+        assert!(id == WEBAUTHN_VALIDATOR_ID, ErrorGenesisInitError);
+    }
+
+    /// Init function called by the bitcoin_move genesis or `upgrade::upgrade_auth_validators`.
+    /// The validator is registered after the Bitcoin multisign validator, so it can not be registered in the rooch_framework genesis.
+    public fun init_for_upgrade(){
+        if(!auth_validator_registry::is_registered<WebauthnValidator>()){
+            genesis_init();
+        }
+    }
+
+    public fun payload_from_bytes(bytes: vector<u8>): WebauthnAuthPayload {
+        bcs::from_bytes<WebauthnAuthPayload>(bytes)
+    }
+
+    /// Get the authentication key of the given public key.
+    fun public_key_to_authentication_key(signature_scheme: u8, public_key: vector<u8>): vector<u8> {
+        let bytes = vector::singleton(signature_scheme);
+        vector::append(&mut bytes, public_key);
+        hash::blake2b256(&bytes)
+    }
+
+    /// Check the client data json is an assertion of the given challenge
+    fun validate_client_data(client_data_json: vector<u8>, tx_hash: &vector<u8>) {
+        let client_data = json::to_map(client_data_json);
+        let type_key = string::utf8(b"type");
+        assert!(
+            simple_map::contains_key(&client_data, &type_key) &&
+                *string::bytes(simple_map::borrow(&client_data, &type_key)) == CLIENT_DATA_TYPE_GET,
+            auth_validator::error_validate_invalid_authenticator()
+        );
+        let challenge_key = string::utf8(b"challenge");
+        assert!(
+            simple_map::contains_key(&client_data, &challenge_key) &&
+                *string::bytes(simple_map::borrow(&client_data, &challenge_key)) == base64url_encode(tx_hash),
+            auth_validator::error_validate_invalid_authenticator()
+        );
+    }
+
+    /// Check the user is present and verified by the authenticator, via the UP and UV flags of the authenticator data
+    fun validate_authenticator_data(authenticator_data: &vector<u8>) {
+        assert!(
+            vector::length(authenticator_data) >= AUTHENTICATOR_DATA_MIN_LENGTH,
+            auth_validator::error_validate_invalid_authenticator()
+        );
+        let flags = *vector::borrow(authenticator_data, AUTHENTICATOR_DATA_FLAGS_OFFSET);
+        assert!(
+            flags & FLAG_UP == FLAG_UP && flags & FLAG_UV == FLAG_UV,
+            auth_validator::error_validate_invalid_authenticator()
+        );
+    }
+
+    /// Validate the signature of the authenticator payload and return auth key
+    public fun validate_signature(payload: &WebauthnAuthPayload, tx_hash: &vector<u8>): vector<u8> {
+        assert!(payload.scheme == SIGNATURE_SCHEME_SECP256R1, auth_validator::error_validate_invalid_authenticator());
+        assert!(
+            vector::length(&payload.public_key) == ecdsa_r1::public_key_length(),
+            auth_validator::error_validate_invalid_authenticator()
+        );
+        validate_authenticator_data(&payload.authenticator_data);
+        validate_client_data(payload.client_data_json, tx_hash);
+
+        // The authenticator signs `authenticator_data || sha256(client_data_json)`,
+        // the `ecdsa_r1::verify` function does sha256 once
+        let message = payload.authenticator_data;
+        vector::append(&mut message, hash::sha2_256(payload.client_data_json));
+        assert!(
+            ecdsa_r1::verify(&payload.signature, &payload.public_key, &message),
+            auth_validator::error_validate_invalid_authenticator()
+        );
+        public_key_to_authentication_key(SIGNATURE_SCHEME_SECP256R1, payload.public_key)
+    }
+
+    public(friend) fun validate(authenticator_payload: vector<u8>): vector<u8> {
+        let sender_addr = tx_context::sender();
+        assert!(session_key::has_session_key(sender_addr), auth_validator::error_validate_invalid_account_auth_key());
+
+        let tx_hash = tx_context::tx_hash();
+        let payload = payload_from_bytes(authenticator_payload);
+        let auth_key = validate_signature(&payload, &tx_hash);
+
+        let session_key_option = session_key::get_session_key(sender_addr, auth_key);
+        assert!(option::is_some(&session_key_option), auth_validator::error_validate_invalid_account_auth_key());
+
+        let session_key = option::extract(&mut session_key_option);
+        assert!(!session_key::is_expired(&session_key), auth_validator::error_validate_session_is_expired());
+
+        assert!(session_key::in_session_scope(&session_key), auth_validator::error_validate_function_call_beyond_session_scope());
+        auth_key
+    }
+
+    /// Encode the bytes with the base64url alphabet and without padding, as the WebAuthn challenge encoding
+    fun base64url_encode(bytes: &vector<u8>): vector<u8> {
+        let result = vector::empty<u8>();
+        let len = vector::length(bytes);
+        let i = 0;
+        while (i < len) {
+            let b0 = (*vector::borrow(bytes, i) as u64);
+            let b1 = if (i + 1 < len) { (*vector::borrow(bytes, i + 1) as u64) } else { 0 };
+            let b2 = if (i + 2 < len) { (*vector::borrow(bytes, i + 2) as u64) } else { 0 };
+            let triple = (b0 << 16) | (b1 << 8) | b2;
+            vector::push_back(&mut result, *vector::borrow(&BASE64URL_ALPHABET, (triple >> 18) & 0x3f));
+            vector::push_back(&mut result, *vector::borrow(&BASE64URL_ALPHABET, (triple >> 12) & 0x3f));
+            if (i + 1 < len) {
+                vector::push_back(&mut result, *vector::borrow(&BASE64URL_ALPHABET, (triple >> 6) & 0x3f));
+            };
+            if (i + 2 < len) {
+                vector::push_back(&mut result, *vector::borrow(&BASE64URL_ALPHABET, triple & 0x3f));
+            };
+            i = i + 3;
+        };
+        result
+    }
+
+    #[test_only]
+    fun test_payload(): WebauthnAuthPayload {
+        WebauthnAuthPayload {
+            scheme: SIGNATURE_SCHEME_SECP256R1,
+            signature: x"0b03bf6e7f617b7874c40fbd79fcc8158712c83902824f0fa4a79dc982a174b45568de80af76b1b1f3cf60ead1c35a3a1d14fc685d0abf2bb292bb61e46e8ecd",
+            public_key: x"020217e617f0b6443928278f96999e69a23a4f2c152bdf6d6cdf66e5b80282d4ed",
+            authenticator_data: x"701e5ca13e155a366732dbcbbbc694edc8c7fc06b03cfebc4c7361057a4f148d0500000001",
+            client_data_json: b"{\"type\":\"webauthn.get\",\"challenge\":\"VBWxjeC4gLsq9d_h7if9Ga6KDJm1Mo6LS0T0yGzHF2o\",\"origin\":\"https://rooch.network\",\"crossOrigin\":false}",
+        }
+    }
+
+    #[test]
+    fun test_base64url_encode() {
+        assert!(base64url_encode(&b"") == b"", 1);
+        assert!(base64url_encode(&b"f") == b"Zg", 2);
+        assert!(base64url_encode(&b"fo") == b"Zm8", 3);
+        assert!(base64url_encode(&b"foo") == b"Zm9v", 4);
+        assert!(base64url_encode(&x"fbff") == b"-_8", 5);
+    }
+
+    #[test]
+    fun test_validate_signature() {
+        let tx_hash = x"5415b18de0b880bb2af5dfe1ee27fd19ae8a0c99b5328e8b4b44f4c86cc7176a";
+        let payload = test_payload();
+        let auth_key = validate_signature(&payload, &tx_hash);
+        assert!(auth_key == x"00264a6adff920225f43c0ee20664fa2fbe38ca231ca7a3d87213a711b033d18", 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1010, location = Self)]
+    fun test_validate_signature_with_wrong_challenge() {
+        let tx_hash = x"5415b18de0b880bb2af5dfe1ee27fd19ae8a0c99b5328e8b4b44f4c86cc7176b";
+        let payload = test_payload();
+        validate_signature(&payload, &tx_hash);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1010, location = Self)]
+    fun test_validate_signature_with_wrong_authenticator_data() {
+        let tx_hash = x"5415b18de0b880bb2af5dfe1ee27fd19ae8a0c99b5328e8b4b44f4c86cc7176a";
+        let payload = test_payload();
+        payload.authenticator_data = x"701e5ca13e155a366732dbcbbbc694edc8c7fc06b03cfebc4c7361057a4f148d0500000002";
+        validate_signature(&payload, &tx_hash);
+    }
+
+    #[test]
+    fun test_validate_authenticator_data() {
+        let payload = test_payload();
+        validate_authenticator_data(&payload.authenticator_data);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1010, location = Self)]
+    fun test_validate_authenticator_data_without_user_verified() {
+        // The flags 0x01 only has the UP flag
+        validate_authenticator_data(&x"701e5ca13e155a366732dbcbbbc694edc8c7fc06b03cfebc4c7361057a4f148d0100000001");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1010, location = Self)]
+    fun test_validate_authenticator_data_without_user_present() {
+        // The flags 0x04 only has the UV flag
+        validate_authenticator_data(&x"701e5ca13e155a366732dbcbbbc694edc8c7fc06b03cfebc4c7361057a4f148d0400000001");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1010, location = Self)]
+    fun test_validate_authenticator_data_too_short() {
+        validate_authenticator_data(&x"701e5ca13e155a366732dbcbbbc694edc8c7fc06b03cfebc4c7361057a4f148d05");
+    }
+}
@@ -0,0 +1,57 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+module rooch_framework::ecdsa_r1 {
+
+    /// constant codes
+    const ECDSA_R1_COMPRESSED_PUBKEY_LENGTH: u64 = 33;
+    const ECDSA_R1_SIG_LENGTH: u64 = 64;
+
+    /// built-in functions
+    public fun public_key_length(): u64 {
+        ECDSA_R1_COMPRESSED_PUBKEY_LENGTH
+    }
+
+    public fun signature_length(): u64 {
+        ECDSA_R1_SIG_LENGTH
+    }
+
+    /// @param signature: A 64-bytes signature in form (r, s) that is signed using
+    /// Ecdsa over Secp256r1, the `s` must be normalized to the lower half of the curve order.
+    /// @param public_key: A 33-bytes compressed public key that is used to sign messages.
+    /// @param msg: The message that the signature is signed against, the message is hashed with sha256.
+    ///
+    /// If the signature is valid to the pubkey and hashed message, return true. Else false.
+    native public fun verify(
+        signature: &vector<u8>,
+        public_key: &vector<u8>,
+        msg: &vector<u8>
+    ): bool;
+
+    #[test]
+    fun test_verify_success() {
+        let msg = x"00010203";
+        let pubkey = x"020217e617f0b6443928278f96999e69a23a4f2c152bdf6d6cdf66e5b80282d4ed";
+        let sig = x"39ec366c7788ae78270b71b84232d4953fbde4e8c0fbd0db9ea195c0f78a57245124473de7e908ede7088a35b36a3d6b54d29f41f1180f5718be7caa6e79e466";
+        let result = verify(&sig, &pubkey, &msg);
+        assert!(result, 0);
+    }
+
+    #[test]
+    fun test_verify_fails_with_wrong_msg() {
+        let msg = x"00010204";
+        let pubkey = x"020217e617f0b6443928278f96999e69a23a4f2c152bdf6d6cdf66e5b80282d4ed";
+        let sig = x"39ec366c7788ae78270b71b84232d4953fbde4e8c0fbd0db9ea195c0f78a57245124473de7e908ede7088a35b36a3d6b54d29f41f1180f5718be7caa6e79e466";
+        let result = verify(&sig, &pubkey, &msg);
+        assert!(!result, 0);
+    }
+
+    #[test]
+    fun test_verify_fails_with_invalid_pubkey() {
+        let msg = x"00010203";
+        let pubkey = x"";
+        let sig = x"39ec366c7788ae78270b71b84232d4953fbde4e8c0fbd0db9ea195c0f78a57245124473de7e908ede7088a35b36a3d6b54d29f41f1180f5718be7caa6e79e466";
+        let result = verify(&sig, &pubkey, &msg);
+        assert!(!result, 0);
+    }
+}
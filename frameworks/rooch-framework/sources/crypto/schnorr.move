@@ -0,0 +1,67 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// BIP-340 Schnorr signature over Secp256k1, the signature scheme of the Bitcoin taproot.
+module rooch_framework::schnorr {
+
+    /// constant codes
+    const SCHNORR_X_ONLY_PUBKEY_LENGTH: u64 = 32;
+    const SCHNORR_SIG_LENGTH: u64 = 64;
+    const SCHNORR_MSG_LENGTH: u64 = 32;
+
+    /// built-in functions
+    public fun public_key_length(): u64 {
+        SCHNORR_X_ONLY_PUBKEY_LENGTH
+    }
+
+    public fun signature_length(): u64 {
+        SCHNORR_SIG_LENGTH
+    }
+
+    public fun message_length(): u64 {
+        SCHNORR_MSG_LENGTH
+    }
+
+    /// @param signature: A 64-bytes BIP-340 signature.
+    /// @param public_key: A 32-bytes x-only public key.
+    /// @param msg: The 32-bytes message digest that the signature is signed against, the message is not hashed again.
+    ///
+    /// If the signature is valid to the pubkey and message, return true. Else false.
+    native public fun verify(
+        signature: &vector<u8>,
+        public_key: &vector<u8>,
+        msg: &vector<u8>
+    ): bool;
+
+    #[test]
+    fun test_verify_success() {
+        let msg = x"243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89";
+        let pubkey = x"dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659";
+        let sig = x"6896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de33418906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a";
+        assert!(verify(&sig, &pubkey, &msg), 0);
+    }
+
+    #[test]
+    fun test_verify_success_with_zero_msg() {
+        let msg = x"0000000000000000000000000000000000000000000000000000000000000000";
+        let pubkey = x"f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9";
+        let sig = x"e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca821525f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0";
+        assert!(verify(&sig, &pubkey, &msg), 0);
+    }
+
+    #[test]
+    fun test_verify_fails_with_wrong_msg() {
+        let msg = x"243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c8a";
+        let pubkey = x"dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659";
+        let sig = x"6896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de33418906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a";
+        assert!(!verify(&sig, &pubkey, &msg), 0);
+    }
+
+    #[test]
+    fun test_verify_fails_with_invalid_msg_length() {
+        let msg = x"00010203";
+        let pubkey = x"dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659";
+        let sig = x"6896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de33418906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a";
+        assert!(!verify(&sig, &pubkey, &msg), 0);
+    }
+}
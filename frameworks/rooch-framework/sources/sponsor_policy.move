@@ -0,0 +1,140 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The sponsor policy of a fee payer.
+/// A fee payer(sponsor) can set a policy to limit the gas and the rate of the transactions it sponsors.
+/// If the fee payer does not set a policy, all the transactions signed by the fee payer are sponsored.
+module rooch_framework::sponsor_policy {
+    use std::signer;
+    use moveos_std::account;
+    use moveos_std::timestamp;
+    use moveos_std::signer::module_signer;
+
+    friend rooch_framework::transaction_validator;
+
+    /// The sponsor policy does not exist
+    const ErrorSponsorPolicyNotFound: u64 = 1;
+    /// The sponsor policy is invalid, the window must be greater than zero when the count limit is set
+    const ErrorInvalidSponsorPolicy: u64 = 2;
+
+    struct SponsorPolicy has key {
+        /// The max gas amount of a sponsored transaction, 0 means unlimited
+        max_gas_amount_per_tx: u64,
+        /// The max number of sponsored transactions in a window, 0 means unlimited
+        max_tx_count_per_window: u64,
+        /// The length of the rate limit window, in milliseconds
+        window_ms: u64,
+    }
+
+    /// The sponsored usage of the fee payer in the current window
+    struct SponsorUsage has key {
+        /// The start time of the current window, in milliseconds
+        window_start: u64,
+        /// The number of sponsored transactions in the current window
+        tx_count: u64,
+        /// The total gas used by all sponsored transactions
+        total_gas_used: u64,
+    }
+
+    public entry fun set_policy(
+        sponsor: &signer,
+        max_gas_amount_per_tx: u64,
+        max_tx_count_per_window: u64,
+        window_ms: u64,
+    ) {
+        assert!(max_tx_count_per_window == 0 || window_ms > 0, ErrorInvalidSponsorPolicy);
+        let sponsor_addr = signer::address_of(sponsor);
+        if (account::exists_resource<SponsorPolicy>(sponsor_addr)) {
+            let policy = account::borrow_mut_resource<SponsorPolicy>(sponsor_addr);
+            policy.max_gas_amount_per_tx = max_gas_amount_per_tx;
+            policy.max_tx_count_per_window = max_tx_count_per_window;
+            policy.window_ms = window_ms;
+        }else{
+            account::move_resource_to(sponsor, SponsorPolicy {
+                max_gas_amount_per_tx,
+                max_tx_count_per_window,
+                window_ms,
+            });
+        };
+    }
+
+    public entry fun remove_policy(sponsor: &signer) {
+        let sponsor_addr = signer::address_of(sponsor);
+        assert!(account::exists_resource<SponsorPolicy>(sponsor_addr), ErrorSponsorPolicyNotFound);
+        let SponsorPolicy {
+            max_gas_amount_per_tx: _,
+            max_tx_count_per_window: _,
+            window_ms: _,
+        } = account::move_resource_from<SponsorPolicy>(sponsor_addr);
+    }
+
+    public fun exists_policy(sponsor: address): bool {
+        account::exists_resource<SponsorPolicy>(sponsor)
+    }
+
+    /// Return the policy of the sponsor: (max_gas_amount_per_tx, max_tx_count_per_window, window_ms)
+    public fun policy(sponsor: address): (u64, u64, u64) {
+        assert!(account::exists_resource<SponsorPolicy>(sponsor), ErrorSponsorPolicyNotFound);
+        let policy = account::borrow_resource<SponsorPolicy>(sponsor);
+        (policy.max_gas_amount_per_tx, policy.max_tx_count_per_window, policy.window_ms)
+    }
+
+    /// Return the usage of the sponsor: (window_start, tx_count, total_gas_used)
+    public fun usage(sponsor: address): (u64, u64, u64) {
+        if (!account::exists_resource<SponsorUsage>(sponsor)) {
+            return (0, 0, 0)
+        };
+        let usage = account::borrow_resource<SponsorUsage>(sponsor);
+        (usage.window_start, usage.tx_count, usage.total_gas_used)
+    }
+
+    /// Check whether a transaction with the `max_gas_amount` can be sponsored by the sponsor
+    public fun check(sponsor: address, max_gas_amount: u64): bool {
+        if (!account::exists_resource<SponsorPolicy>(sponsor)) {
+            return true
+        };
+        let policy = account::borrow_resource<SponsorPolicy>(sponsor);
+        if (policy.max_gas_amount_per_tx > 0 && max_gas_amount > policy.max_gas_amount_per_tx) {
+            return false
+        };
+        if (policy.max_tx_count_per_window == 0 || !account::exists_resource<SponsorUsage>(sponsor)) {
+            return true
+        };
+        let usage = account::borrow_resource<SponsorUsage>(sponsor);
+        let now = timestamp::now_milliseconds();
+        if (now >= usage.window_start + policy.window_ms) {
+            return true
+        };
+        usage.tx_count < policy.max_tx_count_per_window
+    }
+
+    /// Record a sponsored transaction, called by the transaction_validator in `post_execute`
+    public(friend) fun record_usage(sponsor: address, gas_used: u64) {
+        let now = timestamp::now_milliseconds();
+        if (!account::exists_resource<SponsorUsage>(sponsor)) {
+            let sponsor_signer = account::create_signer_for_system(&module_signer<SponsorUsage>(), sponsor);
+            account::move_resource_to(&sponsor_signer, SponsorUsage {
+                window_start: now,
+                tx_count: 0,
+                total_gas_used: 0,
+            });
+        };
+        let window_ms = if (account::exists_resource<SponsorPolicy>(sponsor)) {
+            account::borrow_resource<SponsorPolicy>(sponsor).window_ms
+        }else{
+            0
+        };
+        let usage = account::borrow_mut_resource<SponsorUsage>(sponsor);
+        if (window_ms > 0 && now >= usage.window_start + window_ms) {
+            usage.window_start = now;
+            usage.tx_count = 0;
+        };
+        usage.tx_count = usage.tx_count + 1;
+        usage.total_gas_used = usage.total_gas_used + gas_used;
+    }
+
+    #[test_only]
+    public fun record_usage_for_testing(sponsor: address, gas_used: u64) {
+        record_usage(sponsor, gas_used);
+    }
+}
@@ -0,0 +1,41 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+#[test_only]
+/// This test module is used to test the sponsor policy
+module rooch_framework::sponsor_policy_test{
+
+    use moveos_std::timestamp;
+    use rooch_framework::sponsor_policy;
+
+    #[test]
+    fun test_sponsor_policy_rate_limit(){
+        rooch_framework::genesis::init_for_test();
+        let sponsor_addr = @0x42;
+        let sponsor = moveos_std::account::create_account_for_testing(sponsor_addr);
+        assert!(sponsor_policy::check(sponsor_addr, 1000000), 1000);
+
+        sponsor_policy::set_policy(&sponsor, 10000, 2, 1000);
+        assert!(!sponsor_policy::check(sponsor_addr, 10001), 1001);
+        assert!(sponsor_policy::check(sponsor_addr, 10000), 1002);
+
+        sponsor_policy::record_usage_for_testing(sponsor_addr, 100);
+        assert!(sponsor_policy::check(sponsor_addr, 10000), 1003);
+        sponsor_policy::record_usage_for_testing(sponsor_addr, 200);
+        assert!(!sponsor_policy::check(sponsor_addr, 10000), 1004);
+        let (_, tx_count, total_gas_used) = sponsor_policy::usage(sponsor_addr);
+        assert!(tx_count == 2, 1005);
+        assert!(total_gas_used == 300, 1006);
+
+        timestamp::fast_forward_seconds_for_test(1);
+        assert!(sponsor_policy::check(sponsor_addr, 10000), 1007);
+        sponsor_policy::record_usage_for_testing(sponsor_addr, 100);
+        let (_, tx_count, total_gas_used) = sponsor_policy::usage(sponsor_addr);
+        assert!(tx_count == 1, 1008);
+        assert!(total_gas_used == 400, 1009);
+
+        sponsor_policy::remove_policy(&sponsor);
+        assert!(!sponsor_policy::exists_policy(sponsor_addr), 1010);
+    }
+
+}
@@ -3,7 +3,9 @@
 
 module rooch_framework::transaction_fee {
 
+    use std::option;
     use moveos_std::object::{Self, Object};
+    use moveos_std::tx_context;
     use rooch_framework::coin_store::{Self, CoinStore};
     use rooch_framework::coin::Coin;
     use rooch_framework::gas_coin::{RGas};
@@ -11,10 +13,34 @@ module rooch_framework::transaction_fee {
     friend rooch_framework::genesis;
     friend rooch_framework::transaction_validator;
 
+    /// The min base fee per gas, it is also the initial base fee
+    const MIN_BASE_FEE: u64 = 1;
+    /// The interval of the fee market block in milliseconds, it is the same as the proposer's block interval
+    const BLOCK_INTERVAL_MS: u64 = 5000;
+    /// The target gas used per block, the base fee increases when the gas used of a block is above the target,
+    /// and decreases when it is below the target
+    const TARGET_GAS_PER_BLOCK: u64 = 1_000_000_000;
+    /// The base fee changes at most 1/BASE_FEE_CHANGE_DENOMINATOR per block
+    const BASE_FEE_CHANGE_DENOMINATOR: u64 = 8;
+    /// The max number of the empty blocks to adjust the base fee at once
+    const MAX_EMPTY_BLOCKS_TO_ADJUST: u64 = 64;
+    const MAX_U64: u128 = 18446744073709551615;
+
     struct TransactionFeePool has key {
         fee: Object<CoinStore<RGas>>,
     }
 
+    /// The fee market, the base fee is adjusted per block by the gas used of the previous block versus the target.
+    /// The block is derived from the tx timestamp assigned by the sequencer, so all the nodes get the same base fee.
+    /// It is created by the first transaction after the framework upgrade.
+    struct FeeMarket has key {
+        base_fee: u64,
+        /// The current block number of the fee market
+        block_number: u64,
+        /// The gas used of the current block
+        block_gas_used: u64,
+    }
+
     public(friend) fun genesis_init(_genesis_account: &signer)  {
         let fee_store = coin_store::create_coin_store<RGas>();
         let obj = object::new_named_object(TransactionFeePool{
@@ -23,15 +49,121 @@ module rooch_framework::transaction_fee {
         object::transfer_extend(obj, @rooch_framework);
     }
 
-    /// Returns the gas factor of gas.
+    fun create_fee_market(block_number: u64) {
+        let obj = object::new_named_object(FeeMarket{
+            base_fee: MIN_BASE_FEE,
+            block_number,
+            block_gas_used: 0,
+        });
+        object::transfer_extend(obj, @rooch_framework);
+    }
+
+    /// Returns the gas factor of gas, it is the current base fee per gas.
     public fun get_gas_factor(): u64 {
-        //TODO we should provide a algorithm to cordanate the gas factor based on the network throughput
-        //https://github.com/rooch-network/rooch/issues/1733
-        return 1
+        base_fee()
+    }
+
+    /// Returns the current base fee per gas
+    public fun base_fee(): u64 {
+        let object_id = object::named_object_id<FeeMarket>();
+        if (object::exists_object_with_type<FeeMarket>(object_id)) {
+            let fee_market = object::borrow(object::borrow_object<FeeMarket>(object_id));
+            fee_market.base_fee
+        }else{
+            MIN_BASE_FEE
+        }
+    }
+
+    /// Returns the gas price of the current transaction.
+    /// It is the base fee plus the priority fee of the transaction, but not more than the max gas price of the transaction.
+    public fun gas_price(): u64 {
+        let base_fee = base_fee();
+        let max_gas_price = tx_context::tx_max_gas_price();
+        if (option::is_none(&max_gas_price)) {
+            return base_fee
+        };
+        let max_gas_price = option::destroy_some(max_gas_price);
+        let price = (base_fee as u128) + (tx_context::tx_priority_fee() as u128);
+        if (price > (max_gas_price as u128)) {
+            max_gas_price
+        }else{
+            (price as u64)
+        }
     }
 
     public fun calculate_gas(gas_amount: u64): u256{
-        (gas_amount as u256) * (get_gas_factor() as u256)
+        (gas_amount as u256) * (gas_price() as u256)
+    }
+
+    /// Adjust the base fee if the transaction is in a new block.
+    /// It is called before the transaction execution with the tx timestamp assigned by the sequencer.
+    public(friend) fun update_base_fee(tx_timestamp: u64) {
+        let block_number = tx_timestamp / BLOCK_INTERVAL_MS;
+        let object_id = object::named_object_id<FeeMarket>();
+        if (!object::exists_object(object_id)) {
+            create_fee_market(block_number);
+            return
+        };
+        let fee_market = object::borrow_mut(object::borrow_mut_object_extend<FeeMarket>(object_id));
+        if (block_number <= fee_market.block_number) {
+            return
+        };
+        let base_fee = next_base_fee(fee_market.base_fee, fee_market.block_gas_used);
+        // The blocks between the last block and the current block have no transaction
+        let empty_blocks = block_number - fee_market.block_number - 1;
+        if (empty_blocks > MAX_EMPTY_BLOCKS_TO_ADJUST) {
+            empty_blocks = MAX_EMPTY_BLOCKS_TO_ADJUST;
+        };
+        while (empty_blocks > 0 && base_fee > MIN_BASE_FEE) {
+            base_fee = next_base_fee(base_fee, 0);
+            empty_blocks = empty_blocks - 1;
+        };
+        fee_market.base_fee = base_fee;
+        fee_market.block_number = block_number;
+        fee_market.block_gas_used = 0;
+    }
+
+    /// Record the gas used of the transaction to the current block, it is called after the transaction execution.
+    public(friend) fun record_gas_used(gas_used: u64) {
+        let object_id = object::named_object_id<FeeMarket>();
+        if (!object::exists_object(object_id)) {
+            return
+        };
+        let fee_market = object::borrow_mut(object::borrow_mut_object_extend<FeeMarket>(object_id));
+        let block_gas_used = (fee_market.block_gas_used as u128) + (gas_used as u128);
+        fee_market.block_gas_used = if (block_gas_used > MAX_U64) {
+            (MAX_U64 as u64)
+        }else{
+            (block_gas_used as u64)
+        };
+    }
+
+    fun next_base_fee(base_fee: u64, gas_used: u64): u64 {
+        let base_fee = (base_fee as u128);
+        let gas_used = (gas_used as u128);
+        let target = (TARGET_GAS_PER_BLOCK as u128);
+        let denominator = (BASE_FEE_CHANGE_DENOMINATOR as u128);
+        let next = if (gas_used > target) {
+            let delta = base_fee * (gas_used - target) / target / denominator;
+            if (delta == 0) {
+                delta = 1;
+            };
+            base_fee + delta
+        }else{
+            let delta = base_fee * (target - gas_used) / target / denominator;
+            // Make sure the small base fee can fall back to the min base fee
+            if (delta == 0 && gas_used < target) {
+                delta = 1;
+            };
+            base_fee - delta
+        };
+        if (next < (MIN_BASE_FEE as u128)) {
+            MIN_BASE_FEE
+        }else if (next > MAX_U64) {
+            (MAX_U64 as u64)
+        }else{
+            (next as u64)
+        }
     }
 
     public(friend) fun withdraw_fee(amount: u256) : Coin<RGas> {
@@ -47,4 +179,36 @@ module rooch_framework::transaction_fee {
         let pool = object::borrow_mut(pool_object);
         coin_store::deposit<RGas>(&mut pool.fee, gas_coin);
     }
+
+    #[test]
+    fun test_next_base_fee() {
+        assert!(next_base_fee(MIN_BASE_FEE, 0) == MIN_BASE_FEE, 1);
+        assert!(next_base_fee(100, TARGET_GAS_PER_BLOCK) == 100, 2);
+        assert!(next_base_fee(100, TARGET_GAS_PER_BLOCK * 2) == 112, 3);
+        assert!(next_base_fee(100, 0) == 88, 4);
+        assert!(next_base_fee(MIN_BASE_FEE, TARGET_GAS_PER_BLOCK + 1) == MIN_BASE_FEE + 1, 5);
+    }
+
+    #[test]
+    fun test_update_base_fee() {
+        update_base_fee(0);
+        record_gas_used(TARGET_GAS_PER_BLOCK * 2);
+        update_base_fee(BLOCK_INTERVAL_MS);
+        assert!(base_fee() == 2, 1);
+        // The same block does not change the base fee
+        update_base_fee(BLOCK_INTERVAL_MS + 1);
+        assert!(base_fee() == 2, 2);
+        update_base_fee(BLOCK_INTERVAL_MS * 100);
+        assert!(base_fee() == MIN_BASE_FEE, 3);
+    }
+
+    #[test(sender=@0x42)]
+    fun test_gas_price() {
+        assert!(gas_price() == MIN_BASE_FEE, 1);
+        tx_context::set_ctx_gas_price_for_testing(5, 2);
+        assert!(gas_price() == MIN_BASE_FEE + 2, 2);
+        tx_context::set_ctx_gas_price_for_testing(2, 10);
+        assert!(gas_price() == 2, 3);
+        assert!(calculate_gas(10) == 20, 4);
+    }
 }
@@ -20,9 +20,11 @@ module rooch_framework::transaction_validator {
     use rooch_framework::transaction::{Self, TransactionSequenceInfo};
     use rooch_framework::session_validator;
     use rooch_framework::bitcoin_validator;
+    use rooch_framework::webauthn_validator;
     use rooch_framework::address_mapping;
     use rooch_framework::account_coin_store;
     use rooch_framework::builtin_validators;
+    use rooch_framework::sponsor_policy;
 
     const MAX_U64: u128 = 18446744073709551615;
 
@@ -75,7 +77,18 @@ module rooch_framework::transaction_validator {
             auth_validator::error_validate_max_gas_amount_exceeded(),
         );
 
-        let gas_balance = gas_coin::balance(sender);
+        // The max gas price of the transaction must cover the base fee
+        let max_gas_price = tx_context::tx_max_gas_price();
+        if (option::is_some(&max_gas_price)) {
+            assert!(
+                option::destroy_some(max_gas_price) >= transaction_fee::base_fee(),
+                auth_validator::error_validate_gas_price_too_low(),
+            );
+        };
+
+        // The gas is paid by the fee payer if the transaction is sponsored
+        let gas_payment_account = tx_context::tx_gas_payment_account();
+        let gas_balance = gas_coin::balance(gas_payment_account);
 
         // we do not need to check the gas balance in local or dev chain
         if(!chain_id::is_local_or_dev()){
@@ -95,6 +108,11 @@ module rooch_framework::transaction_validator {
         }else if (auth_validator_id == bitcoin_validator::auth_validator_id()){
             let bitcoin_address = bitcoin_validator::validate(authenticator_payload);
             (option::some(bitcoin_address), option::none(), option::none())
+        }else if (auth_validator_id == webauthn_validator::auth_validator_id()){
+            // The passkey is a session key of the sender
+            let session_key = webauthn_validator::validate(authenticator_payload);
+            let bitcoin_address = address_mapping::resolve_bitcoin(sender);
+            (bitcoin_address, option::some(session_key), option::none())
         }else{
             let auth_validator = auth_validator_registry::borrow_validator(auth_validator_id);
             let validator_id = auth_validator::validator_id(auth_validator);
@@ -110,6 +128,37 @@ module rooch_framework::transaction_validator {
         auth_validator::new_tx_validate_result(auth_validator_id, auth_validator, session_key, bitcoin_address)
     }
 
+    /// This function is for Rooch to validate the fee payer's authenticator if the transaction is sponsored.
+    /// Currently, only the bitcoin auth validator is supported for the fee payer.
+    /// If the authenticator is invalid or the fee payer's sponsor policy rejects the transaction, abort this function.
+    public(friend) fun validate_fee_payer(
+        fee_payer: address,
+        auth_validator_id: u64,
+        authenticator_payload: vector<u8>
+    ) {
+        let sender = tx_context::sender();
+        assert!(
+            fee_payer != sender && account::exists_at(fee_payer),
+            auth_validator::error_validate_invalid_fee_payer(),
+        );
+        assert!(
+            fee_payer == tx_context::tx_gas_payment_account(),
+            auth_validator::error_validate_invalid_fee_payer(),
+        );
+        assert!(
+            auth_validator_id == bitcoin_validator::auth_validator_id(),
+            auth_validator::error_validate_invalid_authenticator(),
+        );
+        bitcoin_validator::validate_fee_payer(fee_payer, authenticator_payload);
+
+        // === validate the sponsor policy ===
+        let max_gas_amount = tx_context::max_gas_amount();
+        assert!(
+            sponsor_policy::check(fee_payer, max_gas_amount),
+            auth_validator::error_validate_sponsor_policy_violated(),
+        );
+    }
+
     /// Transaction pre_execute function.
     /// Execute before the transaction is executed, automatically called by the MoveOS VM.
     /// This function is for Rooch to auto create account and address maping.
@@ -135,6 +184,7 @@ module rooch_framework::transaction_validator {
             let tx_timestamp = transaction::tx_timestamp(&tx_sequence_info);
             let module_signer = module_signer<TransactionValidatorPlaceholder>();
             timestamp::try_update_global_time(&module_signer, tx_timestamp);
+            transaction_fee::update_base_fee(tx_timestamp);
         };
         let gas_payment_account = tx_context::tx_gas_payment_account();
         let max_gas_amount = tx_context::max_gas_amount();
@@ -146,6 +196,7 @@ module rooch_framework::transaction_validator {
     /// Transaction post_execute function.
     /// Execute after the transaction is executed, automatically called by the MoveOS VM.
     /// This function is for Rooch to update the sender's sequence number and pay the gas fee.
+    /// If the transaction is sponsored, the gas fee is refunded to the fee payer.
     fun post_execute(
     ) {
         let sender = tx_context::sender();
@@ -174,5 +225,12 @@ module rooch_framework::transaction_validator {
             let refund_gas_coin = transaction_fee::withdraw_fee(refund_gas);
             account_coin_store::deposit(gas_payment_account, refund_gas_coin);
         };
+
+        transaction_fee::record_gas_used(gas_used);
+
+        // Record the sponsored transaction for the fee payer's sponsor policy
+        if (tx_context::is_sponsored()) {
+            sponsor_policy::record_usage(gas_payment_account, gas_used);
+        };
     }
 }
@@ -7,6 +7,7 @@ module rooch_framework::upgrade {
     use moveos_std::signer::module_signer;
     use moveos_std::account::create_signer_for_system;
     use rooch_framework::onchain_config;
+    use rooch_framework::webauthn_validator;
 
     friend rooch_framework::genesis;
 
@@ -23,4 +24,11 @@ module rooch_framework::upgrade {
         let moveos_std_signer = create_signer_for_system(&system, MoveosStdAccount);
         update_gas_schedule(&moveos_std_signer, gas_schedule_config);
     }
+
+    /// Register the auth validators added after the genesis, the existing chains get them via this upgrade.
+    /// The Bitcoin multisign validator must be registered before, so the validator ids keep the same as the genesis.
+    entry fun upgrade_auth_validators(account: &signer) {
+        onchain_config::ensure_admin(account);
+        webauthn_validator::init_for_upgrade();
+    }
 }
@@ -8,6 +8,7 @@ mod bitcoin_address;
 pub mod bls12381;
 mod cbor;
 mod ecdsa_k1;
+mod ecdsa_r1;
 mod ed25519;
 mod events;
 pub mod evm;
@@ -21,6 +22,7 @@ pub mod native;
 pub mod nursery;
 mod object;
 mod rlp;
+mod schnorr;
 mod signer;
 mod test_helper;
 mod tx_context;
@@ -2,4 +2,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod ecdsa_k1;
+pub mod ecdsa_r1;
 pub mod ed25519;
+pub mod schnorr;
@@ -0,0 +1,112 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::helpers::{make_module_natives, make_native};
+use bitcoin::secp256k1::{schnorr::Signature, Message, Secp256k1, XOnlyPublicKey};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Value, VectorRef},
+};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+/// Verify the BIP-340 Schnorr signature, the message must be a 32 bytes digest.
+pub fn verify_schnorr(signature: &[u8], public_key: &[u8], msg: &[u8]) -> bool {
+    let Ok(signature) = Signature::from_slice(signature) else {
+        return false;
+    };
+    let Ok(public_key) = XOnlyPublicKey::from_slice(public_key) else {
+        return false;
+    };
+    let Ok(msg) = Message::from_digest_slice(msg) else {
+        return false;
+    };
+    Secp256k1::verification_only()
+        .verify_schnorr(&signature, &msg, &public_key)
+        .is_ok()
+}
+
+/***************************************************************************************************
+ * native fun verify
+ * Implementation of the Move native function `schnorr::verify(signature: &vector<u8>, public_key: &vector<u8>, msg: &vector<u8>): bool;`
+ *   gas cost: verify.base                                    | base cost for function call and fixed opers
+ *              + verify.per_byte * (msg + signature + pk)    | cost depends on length of the arguments
+ **************************************************************************************************/
+pub fn native_verify(
+    gas_params: &FromBytesGasParametersOptional,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let msg = pop_arg!(args, VectorRef);
+    let msg_ref = msg.as_bytes_ref();
+    let public_key_bytes = pop_arg!(args, VectorRef);
+    let public_key_bytes_ref = public_key_bytes.as_bytes_ref();
+    let signature_bytes = pop_arg!(args, VectorRef);
+    let signature_bytes_ref = signature_bytes.as_bytes_ref();
+
+    let cost = gas_params.base.unwrap()
+        + gas_params.per_byte.unwrap()
+            * NumBytes::new(
+                (msg_ref.len() + signature_bytes_ref.len() + public_key_bytes_ref.len()) as u64,
+            );
+
+    let result = verify_schnorr(&signature_bytes_ref, &public_key_bytes_ref, &msg_ref);
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(result)]))
+}
+
+// optional params
+#[derive(Debug, Clone)]
+pub struct FromBytesGasParametersOptional {
+    pub base: Option<InternalGas>,
+    pub per_byte: Option<InternalGasPerByte>,
+}
+
+impl FromBytesGasParametersOptional {
+    pub fn zeros() -> Self {
+        Self {
+            base: None,
+            per_byte: None,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.base.is_none() || self.per_byte.is_none()
+    }
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub verify: FromBytesGasParametersOptional,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            verify: FromBytesGasParametersOptional::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let mut natives = vec![];
+
+    // The native is only available when the gas parameters are set in the onchain gas schedule
+    if !gas_params.verify.is_empty() {
+        natives.push(("verify", make_native(gas_params.verify, native_verify)));
+    }
+
+    make_module_natives(natives)
+}
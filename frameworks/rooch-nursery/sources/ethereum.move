@@ -87,4 +87,24 @@ module rooch_nursery::ethereum{
         assert!(table::contains(&block_store.blocks, block_number), ErrorBlockNotFound);
         table::borrow(&block_store.blocks, block_number)
     }
+
+    public fun number(block_header: &BlockHeader): u64 {
+        block_header.number
+    }
+
+    public fun block_hash(block_header: &BlockHeader): vector<u8> {
+        block_header.hash
+    }
+
+    public fun state_root(block_header: &BlockHeader): vector<u8> {
+        block_header.state_root
+    }
+
+    public fun transactions_root(block_header: &BlockHeader): vector<u8> {
+        block_header.transactions_root
+    }
+
+    public fun receipts_root(block_header: &BlockHeader): vector<u8> {
+        block_header.receipts_root
+    }
 }
@@ -0,0 +1,236 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// Ethereum Merkle-Patricia trie proof verification, verify the account, storage and receipt
+/// against the roots of the block headers relayed to the `ethereum` light client.
+module rooch_nursery::ethereum_proof{
+
+    use std::vector;
+    use std::option::{Self, Option};
+    use moveos_std::hash;
+    use moveos_std::rlp;
+    use rooch_nursery::ethereum;
+
+    /// The proof is invalid, the nodes do not match the root or the key
+    const ErrorInvalidProof: u64 = 1;
+    const ErrorAccountNotFound: u64 = 2;
+    const ErrorReceiptNotFound: u64 = 3;
+    const ErrorLogIndexOutOfBounds: u64 = 4;
+    const ErrorInvalidAddressLength: u64 = 5;
+
+    const ETH_ADDRESS_LENGTH: u64 = 20;
+    /// The first byte of the RLP list, the typed receipts(EIP-2718) are prefixed by the tx type less than it.
+    const RLP_LIST_PREFIX: u8 = 0xc0;
+
+    #[data_struct]
+    /// The Ethereum account in the state trie
+    struct Account has store, copy, drop {
+        nonce: u64,
+        balance: u256,
+        storage_root: vector<u8>,
+        code_hash: vector<u8>,
+    }
+
+    #[data_struct]
+    /// The Ethereum transaction receipt in the receipts trie
+    struct Receipt has store, copy, drop {
+        /// 1 if the transaction succeeded, 0 if it failed
+        status: u64,
+        cumulative_gas_used: u256,
+        logs_bloom: vector<u8>,
+        logs: vector<Log>,
+    }
+
+    #[data_struct]
+    /// The log emitted by the Ethereum transaction
+    struct Log has store, copy, drop {
+        /// The 20 bytes address of the contract emitting the log
+        address: vector<u8>,
+        topics: vector<vector<u8>>,
+        data: vector<u8>,
+    }
+
+    /// Verify the Merkle-Patricia trie `proof` of the `key` against the trie `root`, the `proof` is the list of
+    /// the RLP encoded nodes from the root to the key, as returned by the `eth_getProof` RPC.
+    /// Return the value of the key, or an empty vector if the proof shows the key is not in the trie.
+    /// Abort if the proof is invalid.
+    public native fun verify_proof(root: vector<u8>, key: vector<u8>, proof: vector<vector<u8>>): vector<u8>;
+
+    /// Verify the account `proof` of the 20 bytes `address` against the `state_root`.
+    /// Return none if the proof shows the account does not exist.
+    public fun verify_account_proof(state_root: vector<u8>, address: vector<u8>, proof: vector<vector<u8>>): Option<Account> {
+        assert!(vector::length(&address) == ETH_ADDRESS_LENGTH, ErrorInvalidAddressLength);
+        let value = verify_proof(state_root, hash::keccak256(&address), proof);
+        if (vector::is_empty(&value)) {
+            option::none()
+        } else {
+            option::some(rlp::from_bytes<Account>(value))
+        }
+    }
+
+    /// Verify the storage `proof` of the 32 bytes `slot` against the `storage_root` of the account.
+    /// Return the value of the slot, the value of the slot not in the trie is zero.
+    public fun verify_storage_proof(storage_root: vector<u8>, slot: vector<u8>, proof: vector<vector<u8>>): u256 {
+        let value = verify_proof(storage_root, hash::keccak256(&slot), proof);
+        if (vector::is_empty(&value)) {
+            0
+        } else {
+            rlp::from_bytes<u256>(value)
+        }
+    }
+
+    /// Verify the receipt `proof` of the transaction at `tx_index` against the `receipts_root` of the block.
+    public fun verify_receipt_proof(receipts_root: vector<u8>, tx_index: u64, proof: vector<vector<u8>>): Receipt {
+        let value = verify_proof(receipts_root, rlp::to_bytes(&tx_index), proof);
+        assert!(!vector::is_empty(&value), ErrorReceiptNotFound);
+        if (*vector::borrow(&value, 0) < RLP_LIST_PREFIX) {
+            // Remove the transaction type of the typed receipt
+            vector::remove(&mut value, 0);
+        };
+        rlp::from_bytes<Receipt>(value)
+    }
+
+    /// Verify the account `proof` against the state root of the relayed block at `block_number`.
+    public fun verify_account(block_number: u64, address: vector<u8>, proof: vector<vector<u8>>): Option<Account> {
+        let block_header = ethereum::get_block(block_number);
+        verify_account_proof(ethereum::state_root(block_header), address, proof)
+    }
+
+    /// Verify the storage `slot` of the contract `address` in the relayed block at `block_number`.
+    /// The `account_proof` proves the storage root of the account, and the `storage_proof` proves the slot.
+    public fun verify_storage(
+        block_number: u64,
+        address: vector<u8>,
+        account_proof: vector<vector<u8>>,
+        slot: vector<u8>,
+        storage_proof: vector<vector<u8>>
+    ): u256 {
+        let account = verify_account(block_number, address, account_proof);
+        assert!(option::is_some(&account), ErrorAccountNotFound);
+        let account = option::destroy_some(account);
+        verify_storage_proof(account.storage_root, slot, storage_proof)
+    }
+
+    /// Verify the receipt of the transaction at `tx_index` in the relayed block at `block_number`.
+    public fun verify_receipt(block_number: u64, tx_index: u64, proof: vector<vector<u8>>): Receipt {
+        let block_header = ethereum::get_block(block_number);
+        verify_receipt_proof(ethereum::receipts_root(block_header), tx_index, proof)
+    }
+
+    /// Verify the log at `log_index` of the transaction at `tx_index` was emitted in the relayed block at `block_number`.
+    /// The `log_index` is the index of the log in the receipt, not in the block.
+    public fun verify_log(block_number: u64, tx_index: u64, log_index: u64, receipt_proof: vector<vector<u8>>): Log {
+        let receipt = verify_receipt(block_number, tx_index, receipt_proof);
+        assert!(log_index < vector::length(&receipt.logs), ErrorLogIndexOutOfBounds);
+        vector::swap_remove(&mut receipt.logs, log_index)
+    }
+
+    public fun account_nonce(account: &Account): u64 {
+        account.nonce
+    }
+
+    public fun account_balance(account: &Account): u256 {
+        account.balance
+    }
+
+    public fun account_storage_root(account: &Account): vector<u8> {
+        account.storage_root
+    }
+
+    public fun account_code_hash(account: &Account): vector<u8> {
+        account.code_hash
+    }
+
+    public fun receipt_status(receipt: &Receipt): u64 {
+        receipt.status
+    }
+
+    public fun receipt_cumulative_gas_used(receipt: &Receipt): u256 {
+        receipt.cumulative_gas_used
+    }
+
+    public fun receipt_logs_bloom(receipt: &Receipt): vector<u8> {
+        receipt.logs_bloom
+    }
+
+    public fun receipt_logs(receipt: &Receipt): &vector<Log> {
+        &receipt.logs
+    }
+
+    public fun log_address(log: &Log): vector<u8> {
+        log.address
+    }
+
+    public fun log_topics(log: &Log): &vector<vector<u8>> {
+        &log.topics
+    }
+
+    public fun log_data(log: &Log): vector<u8> {
+        log.data
+    }
+
+    #[test_only]
+    /// The state trie with two accounts 0x7e5f4552091a69125d5dfcb7b8c2659029395bdf and 0x2b5ad5c4795c026514f8317c7a215e218dccd6cf
+    fun state_root_and_proof(): (vector<u8>, vector<vector<u8>>) {
+        let state_root = x"99b881f4df7f1aee6acc111651bfcb34d721e606fcd7bf1efdf324b89861c90f";
+        let proof = vector[
+            x"f851808080a089c3641193811b8603733570bbf530339d4fdd3ca5a62ce9128cb1eb2720dee38080808080a03046fa471c7669ef230c51266999bed09d5e1193f98d4a2c15ff37f852f9cc6580808080808080",
+            x"f871a03322f33946a3c503c916c8fc29768a547f01fa665e1eb22f9f66cf7e5a262012b84ef84c01880de0b6b3a7640000a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a0c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+        ];
+        (state_root, proof)
+    }
+
+    #[test]
+    fun test_verify_account_proof() {
+        let (state_root, proof) = state_root_and_proof();
+        let account = verify_account_proof(state_root, x"7e5f4552091a69125d5dfcb7b8c2659029395bdf", proof);
+        let account = option::destroy_some(account);
+        assert!(account_nonce(&account) == 1, 1);
+        assert!(account_balance(&account) == 1000000000000000000, 2);
+        assert!(account_storage_root(&account) == x"56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421", 3);
+        assert!(account_code_hash(&account) == x"c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470", 4);
+    }
+
+    #[test]
+    fun test_verify_account_proof_not_exists() {
+        let (state_root, proof) = state_root_and_proof();
+        // The hashed key of the address is in the empty slot of the root branch node
+        vector::pop_back(&mut proof);
+        let account = verify_account_proof(state_root, x"0000000000000000000000000000000000000001", proof);
+        assert!(option::is_none(&account), 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = ErrorInvalidProof, location = Self)]
+    fun test_verify_account_proof_with_wrong_address() {
+        let (state_root, proof) = state_root_and_proof();
+        verify_account_proof(state_root, x"2b5ad5c4795c026514f8317c7a215e218dccd6cf", proof);
+    }
+
+    #[test]
+    fun test_verify_storage_proof() {
+        let storage_root = x"81d1fa699f807735499cf6f7df860797cf66f6a66b565cfcda3fae3521eb6861";
+        let proof = vector[
+            x"e3a120290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e5632a",
+        ];
+        let slot = x"0000000000000000000000000000000000000000000000000000000000000000";
+        assert!(verify_storage_proof(storage_root, slot, proof) == 42, 1);
+    }
+
+    #[test]
+    fun test_verify_receipt_proof() {
+        let receipts_root = x"846b2ea77bf17b6918c06eba8dc3143942c34faac05ab0f7437329c980eae2fe";
+        let proof = vector[
+            x"f9016d822080b9016702f9016301825208b9010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000f85af85894dac17f958d2ee523a2206206994597c13d831ec7e1a0ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3efa000000000000000000000000000000000000000000000000000000000000003e8",
+        ];
+        let receipt = verify_receipt_proof(receipts_root, 0, proof);
+        assert!(receipt_status(&receipt) == 1, 1);
+        assert!(receipt_cumulative_gas_used(&receipt) == 21000, 2);
+        let logs = receipt_logs(&receipt);
+        assert!(vector::length(logs) == 1, 3);
+        let log = vector::borrow(logs, 0);
+        assert!(log_address(log) == x"dac17f958d2ee523a2206206994597c13d831ec7", 4);
+        assert!(*log_topics(log) == vector[x"ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"], 5);
+        assert!(log_data(log) == x"00000000000000000000000000000000000000000000000000000000000003e8", 6);
+    }
+}
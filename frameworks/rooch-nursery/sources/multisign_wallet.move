@@ -8,9 +8,7 @@ module rooch_nursery::multisign_wallet{
     use moveos_std::signer;
     use moveos_std::object;
     use moveos_std::table_vec::{Self, TableVec};
-    use moveos_std::bcs;
     use bitcoin_move::multisign_account;
-    use rooch_framework::ecdsa_k1;
 
     const PROPOSAL_STATUS_PENDING: u8 = 0;
     const PROPOSAL_STATUS_APPROVED: u8 = 1;
@@ -139,7 +137,10 @@ module rooch_nursery::multisign_wallet{
 
         let participant = multisign_account::participant(multisign_address, sender_addr);
         let participant_public_key = multisign_account::participant_public_key(&participant);
-        verify_bitcoin_signature(proposal.tx_id, &signature, participant_public_key);
+        assert!(
+            multisign_account::verify_bitcoin_signature(proposal.tx_id, &signature, participant_public_key),
+            ErrorInvalidSignature
+        );
         let threshold = multisign_account::threshold(multisign_address);
         vector::push_back(&mut proposal.signatures, signature);
         if(vector::length(&proposal.signatures) >= threshold){
@@ -148,18 +149,6 @@ module rooch_nursery::multisign_wallet{
     }
 
 
-    fun verify_bitcoin_signature(tx_id: address, signature: &vector<u8>, public_key: &vector<u8>) {
-        assert!(
-            ecdsa_k1::verify(
-                signature,
-                public_key,
-                &bcs::to_bytes(&tx_id),
-                ecdsa_k1::sha256()
-            ),
-            ErrorInvalidSignature
-        );
-    }
-
     fun check_public_keys(public_keys: &vector<vector<u8>>) {
         let idx = 0;
         let len = vector::length(public_keys);
@@ -0,0 +1,334 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use fastcrypto::hash::{HashFunction, Keccak256};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+};
+use moveos_stdlib::natives::helpers::{make_module_natives, make_native};
+use rlp::Rlp;
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+pub const E_INVALID_PROOF: u64 = 1;
+
+const HASH_LENGTH: usize = 32;
+const BRANCH_NODE_ITEMS: usize = 17;
+const SHORT_NODE_ITEMS: usize = 2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProofError {
+    /// The node does not match the hash or the inline reference of its parent
+    InvalidNodeReference,
+    /// The node can not be decoded as a branch, extension or leaf node
+    InvalidNode,
+    /// The proof contains more nodes than the path of the key
+    UnusedNodes,
+    /// The proof ends before reaching the key
+    Incomplete,
+}
+
+fn to_nibbles(key: &[u8]) -> Vec<u8> {
+    key.iter().flat_map(|b| [b >> 4, b & 0x0f]).collect()
+}
+
+/// Decode the hex-prefix encoded path of the leaf or extension node, return the nibbles and whether it is a leaf.
+fn decode_compact_path(encoded: &[u8]) -> Result<(Vec<u8>, bool), ProofError> {
+    let first = *encoded.first().ok_or(ProofError::InvalidNode)?;
+    let flag = first >> 4;
+    if flag > 3 {
+        return Err(ProofError::InvalidNode);
+    }
+    let is_leaf = flag >= 2;
+    let is_odd = flag % 2 == 1;
+    let mut nibbles = Vec::with_capacity(encoded.len() * 2);
+    if is_odd {
+        nibbles.push(first & 0x0f);
+    }
+    nibbles.extend(to_nibbles(&encoded[1..]));
+    Ok((nibbles, is_leaf))
+}
+
+/// The reference of a child node: the hash of the node, or the node itself if its rlp encoding is shorter than 32 bytes.
+enum NodeRef {
+    Hash(Vec<u8>),
+    Inline(Vec<u8>),
+    Empty,
+}
+
+fn child_ref(child: Rlp) -> Result<NodeRef, ProofError> {
+    if child.is_list() {
+        return Ok(NodeRef::Inline(child.as_raw().to_vec()));
+    }
+    let data = child.data().map_err(|_| ProofError::InvalidNode)?;
+    match data.len() {
+        0 => Ok(NodeRef::Empty),
+        HASH_LENGTH => Ok(NodeRef::Hash(data.to_vec())),
+        _ => Err(ProofError::InvalidNode),
+    }
+}
+
+/// Verify the Merkle-Patricia trie proof of `key` against the trie `root`.
+/// Returns the value of the key, or None if the proof shows the key is not in the trie.
+/// The `key` is the raw path of the trie, the caller must hash it for the secure trie(state and storage trie).
+pub fn verify_proof(
+    root: &[u8],
+    key: &[u8],
+    proof: &[Vec<u8>],
+) -> Result<Option<Vec<u8>>, ProofError> {
+    let nibbles = to_nibbles(key);
+    let mut path_offset = 0;
+    let mut proof_nodes = proof.iter();
+    let mut next = NodeRef::Hash(root.to_vec());
+
+    loop {
+        let node = match next {
+            NodeRef::Empty => {
+                return match proof_nodes.next() {
+                    Some(_) => Err(ProofError::UnusedNodes),
+                    None => Ok(None),
+                }
+            }
+            NodeRef::Hash(hash) => {
+                let node = proof_nodes.next().ok_or(ProofError::Incomplete)?;
+                if Keccak256::digest(node).digest.as_slice() != hash.as_slice() {
+                    return Err(ProofError::InvalidNodeReference);
+                }
+                node.clone()
+            }
+            NodeRef::Inline(node) => node,
+        };
+
+        let rlp = Rlp::new(&node);
+        let item_count = rlp.item_count().map_err(|_| ProofError::InvalidNode)?;
+        let value = match item_count {
+            BRANCH_NODE_ITEMS => {
+                if path_offset == nibbles.len() {
+                    rlp.at(16)
+                } else {
+                    let child = rlp
+                        .at(nibbles[path_offset] as usize)
+                        .map_err(|_| ProofError::InvalidNode)?;
+                    path_offset += 1;
+                    next = child_ref(child)?;
+                    continue;
+                }
+            }
+            SHORT_NODE_ITEMS => {
+                let encoded_path = rlp
+                    .at(0)
+                    .and_then(|p| p.data().map(|d| d.to_vec()))
+                    .map_err(|_| ProofError::InvalidNode)?;
+                let (path, is_leaf) = decode_compact_path(&encoded_path)?;
+                let remaining = &nibbles[path_offset..];
+                if is_leaf && remaining == path.as_slice() {
+                    rlp.at(1)
+                } else if !is_leaf && remaining.starts_with(&path) {
+                    path_offset += path.len();
+                    next = child_ref(rlp.at(1).map_err(|_| ProofError::InvalidNode)?)?;
+                    continue;
+                } else {
+                    // The path diverges from the key, the key is not in the trie
+                    next = NodeRef::Empty;
+                    continue;
+                }
+            }
+            _ => return Err(ProofError::InvalidNode),
+        };
+
+        let value = value
+            .and_then(|v| v.data().map(|d| d.to_vec()))
+            .map_err(|_| ProofError::InvalidNode)?;
+        if proof_nodes.next().is_some() {
+            return Err(ProofError::UnusedNodes);
+        }
+        return Ok(if value.is_empty() { None } else { Some(value) });
+    }
+}
+
+/***************************************************************************************************
+ * native fun verify_proof
+ * Implementation of the Move native function `ethereum_proof::verify_proof(root: vector<u8>, key: vector<u8>, proof: vector<vector<u8>>): vector<u8>`
+ *   gas cost: base + per_byte * (root + key + proof nodes)
+ **************************************************************************************************/
+pub fn native_verify_proof(
+    gas_params: &VerifyProofGasParametersOptional,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let proof = pop_arg!(args, Vec<Value>)
+        .into_iter()
+        .map(|node| node.value_as::<Vec<u8>>())
+        .collect::<PartialVMResult<Vec<_>>>()?;
+    let key = pop_arg!(args, Vec<u8>);
+    let root = pop_arg!(args, Vec<u8>);
+
+    let proof_size: usize = proof.iter().map(|node| node.len()).sum();
+    let cost = gas_params.base.unwrap()
+        + gas_params.per_byte.unwrap()
+            * NumBytes::new((root.len() + key.len() + proof_size) as u64);
+
+    match verify_proof(&root, &key, &proof) {
+        Ok(value) => Ok(NativeResult::ok(
+            cost,
+            smallvec![Value::vector_u8(value.unwrap_or_default())],
+        )),
+        Err(_) => Ok(NativeResult::err(cost, E_INVALID_PROOF)),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VerifyProofGasParametersOptional {
+    pub base: Option<InternalGas>,
+    pub per_byte: Option<InternalGasPerByte>,
+}
+
+impl VerifyProofGasParametersOptional {
+    pub fn zeros() -> Self {
+        Self {
+            base: None,
+            per_byte: None,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.base.is_none() || self.per_byte.is_none()
+    }
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub verify_proof: VerifyProofGasParametersOptional,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            verify_proof: VerifyProofGasParametersOptional::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let mut natives = vec![];
+
+    if !gas_params.verify_proof.is_empty() {
+        natives.push((
+            "verify_proof",
+            make_native(gas_params.verify_proof, native_verify_proof),
+        ));
+    }
+
+    make_module_natives(natives)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rlp::RlpStream;
+
+    fn keccak(data: &[u8]) -> Vec<u8> {
+        Keccak256::digest(data).digest.to_vec()
+    }
+
+    fn encode_compact_path(nibbles: &[u8], is_leaf: bool) -> Vec<u8> {
+        let flag = if is_leaf { 2 } else { 0 } + (nibbles.len() % 2) as u8;
+        let mut encoded = vec![];
+        let rest = if nibbles.len() % 2 == 1 {
+            encoded.push((flag << 4) | nibbles[0]);
+            &nibbles[1..]
+        } else {
+            encoded.push(flag << 4);
+            nibbles
+        };
+        encoded.extend(rest.chunks(2).map(|c| (c[0] << 4) | c[1]));
+        encoded
+    }
+
+    fn leaf(path: &[u8], value: &[u8]) -> Vec<u8> {
+        let mut s = RlpStream::new_list(2);
+        s.append(&encode_compact_path(path, true));
+        s.append(&value.to_vec());
+        s.out().to_vec()
+    }
+
+    fn branch(children: &[(u8, Vec<u8>)]) -> Vec<u8> {
+        let mut s = RlpStream::new_list(17);
+        for i in 0..16u8 {
+            match children.iter().find(|(index, _)| *index == i) {
+                Some((_, child)) => s.append(child),
+                None => s.append_empty_data(),
+            };
+        }
+        s.append_empty_data();
+        s.out().to_vec()
+    }
+
+    #[test]
+    fn test_compact_path() {
+        for nibbles in [vec![], vec![1], vec![1, 2], vec![0xf, 0, 3]] {
+            for is_leaf in [true, false] {
+                let encoded = encode_compact_path(&nibbles, is_leaf);
+                assert_eq!(
+                    decode_compact_path(&encoded).unwrap(),
+                    (nibbles.clone(), is_leaf)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_proof() {
+        let value1 = vec![0x11u8; 40];
+        let value2 = vec![0x22u8; 40];
+        // key1 = 0x1a.., key2 = 0x2b.., the root is a branch node with two leaves
+        let key1 = [0x1a; 32];
+        let key2 = [0x2b; 32];
+        let leaf1 = leaf(&to_nibbles(&key1)[1..], &value1);
+        let leaf2 = leaf(&to_nibbles(&key2)[1..], &value2);
+        let root_node = branch(&[(1, keccak(&leaf1)), (2, keccak(&leaf2))]);
+        let root = keccak(&root_node);
+
+        assert_eq!(
+            verify_proof(&root, &key1, &[root_node.clone(), leaf1.clone()]),
+            Ok(Some(value1))
+        );
+        assert_eq!(
+            verify_proof(&root, &key2, &[root_node.clone(), leaf2.clone()]),
+            Ok(Some(value2))
+        );
+        // The exclusion proof of a key in an empty branch slot
+        assert_eq!(
+            verify_proof(&root, &[0x3c; 32], &[root_node.clone()]),
+            Ok(None)
+        );
+        // The exclusion proof of a key diverging from the leaf
+        assert_eq!(
+            verify_proof(&root, &[0x1b; 32], &[root_node.clone(), leaf1.clone()]),
+            Ok(None)
+        );
+        // The wrong node
+        assert_eq!(
+            verify_proof(&root, &key1, &[root_node.clone(), leaf2.clone()]),
+            Err(ProofError::InvalidNodeReference)
+        );
+        // The incomplete proof
+        assert_eq!(
+            verify_proof(&root, &key1, &[root_node.clone()]),
+            Err(ProofError::Incomplete)
+        );
+        // The unused nodes
+        assert_eq!(
+            verify_proof(&root, &key1, &[root_node, leaf1.clone(), leaf1]),
+            Err(ProofError::UnusedNodes)
+        );
+    }
+}
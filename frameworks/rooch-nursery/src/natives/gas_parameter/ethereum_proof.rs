@@ -0,0 +1,10 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::ethereum_proof::GasParameters;
+use rooch_framework::natives::gas_parameter::native::MUL;
+
+rooch_framework::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "ethereum_proof", [
+    [.verify_proof.base, optional "verify_proof.base", 2000 * MUL],
+    [.verify_proof.per_byte, optional "verify_proof.per_byte", 30 * MUL],
+]);
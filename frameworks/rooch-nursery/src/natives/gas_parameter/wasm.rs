@@ -6,6 +6,7 @@ use crate::natives::wasm::GasParameters;
 rooch_framework::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "wasm", [
     [.create_instance_gas_parameter.base_create_instance, "create_instance_gas_parameter.base_create_instance", 10000],
     [.create_instance_gas_parameter.per_byte_instance, "create_instance_gas_parameter.per_byte_instance", 100],
+    [.create_instance_gas_parameter.per_execution_point, "create_instance_gas_parameter.per_execution_point", 1],
 
     [.create_cbor_value_gas_parameter.base, "create_cbor_value_gas_parameter.base", 100],
     [.create_cbor_value_gas_parameter.per_byte, "create_cbor_value_gas_parameter.per_byte", 10000],
@@ -8,31 +8,39 @@ use rooch_framework::natives::gas_parameter::gas_member::{
 use rooch_types::addresses::ROOCH_NURSERY_ADDRESS;
 use std::collections::BTreeMap;
 
+pub mod ethereum_proof;
 pub mod gas_parameter;
 pub mod wasm;
 
 #[derive(Debug, Clone)]
 pub struct GasParameters {
     wasm: crate::natives::wasm::GasParameters,
+    ethereum_proof: crate::natives::ethereum_proof::GasParameters,
 }
 
 impl GasParameters {
     pub fn zeros() -> Self {
         Self {
             wasm: crate::natives::wasm::GasParameters::zeros(),
+            ethereum_proof: crate::natives::ethereum_proof::GasParameters::zeros(),
         }
     }
 }
 
 impl FromOnChainGasSchedule for GasParameters {
     fn from_on_chain_gas_schedule(gas_schedule: &BTreeMap<String, u64>) -> Option<Self> {
-        FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule).map(|v| Self { wasm: v })
+        Some(Self {
+            wasm: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+            ethereum_proof: FromOnChainGasSchedule::from_on_chain_gas_schedule(gas_schedule)?,
+        })
     }
 }
 
 impl ToOnChainGasSchedule for GasParameters {
     fn to_on_chain_gas_schedule(&self) -> Vec<(String, u64)> {
-        self.wasm.to_on_chain_gas_schedule()
+        let mut entries = self.wasm.to_on_chain_gas_schedule();
+        entries.extend(self.ethereum_proof.to_on_chain_gas_schedule());
+        entries
     }
 }
 
@@ -40,6 +48,7 @@ impl InitialGasSchedule for GasParameters {
     fn initial() -> Self {
         Self {
             wasm: InitialGasSchedule::initial(),
+            ethereum_proof: InitialGasSchedule::initial(),
         }
     }
 }
@@ -55,6 +64,10 @@ pub fn all_natives(gas_params: GasParameters) -> NativeFunctionTable {
         };
     }
     add_natives!("wasm", wasm::make_all(gas_params.wasm));
+    add_natives!(
+        "ethereum_proof",
+        ethereum_proof::make_all(gas_params.ethereum_proof)
+    );
 
     let rooch_nursery_native_fun_table = make_table_from_iter(ROOCH_NURSERY_ADDRESS, natives);
 
@@ -17,9 +17,7 @@ use std::ffi::CString;
 use std::ops::Deref;
 use std::vec;
 
-use moveos_wasm::wasm::{
-    create_wasm_instance, get_instance_pool, insert_wasm_instance, put_data_on_stack,
-};
+use moveos_wasm::wasm::{create_wasm_instance, put_data_on_stack, NativeWASMContext};
 
 use moveos_stdlib::natives::helpers::{make_module_natives, make_native};
 
@@ -49,6 +47,8 @@ pub const E_VM_ERROR: u64 = 99;
 pub struct WASMCreateInstanceGasParameters {
     pub base_create_instance: InternalGas,
     pub per_byte_instance: InternalGasPerByte,
+    /// The gas of each execution point of the start function, as `WASMExecuteGasParameters::per_execution_point`
+    pub per_execution_point: InternalGasPerByte,
 }
 
 impl WASMCreateInstanceGasParameters {
@@ -56,6 +56,7 @@ impl WASMCreateInstanceGasParameters {
         Self {
             base_create_instance: 0.into(),
             per_byte_instance: 0.into(),
+            per_execution_point: 0.into(),
         }
     }
 }
@@ -64,7 +65,7 @@ impl WASMCreateInstanceGasParameters {
 #[inline]
 fn native_create_wasm_instance(
     gas_params: &WASMCreateInstanceGasParameters,
-    _context: &mut NativeContext,
+    context: &mut NativeContext,
     _ty_args: Vec<Type>,
     mut args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
@@ -77,15 +78,18 @@ fn native_create_wasm_instance(
 
     let wasm_bytes = pop_arg!(args, Vec<u8>);
 
-    let (instance_id, error_code) = match create_wasm_instance(&wasm_bytes) {
+    let mut cost = gas_params.base_create_instance;
+    cost += gas_params.per_byte_instance * NumBytes::new(wasm_bytes.len() as u64);
+
+    let wasm_context = context.extensions_mut().get_mut::<NativeWASMContext>();
+    let gas_limit = wasm_gas_limit(wasm_context.gas_budget(), gas_params.per_execution_point);
+    let (instance_id, error_code) = match create_wasm_instance(&wasm_bytes, gas_limit) {
         Ok(instance) => {
-            match insert_wasm_instance(instance) {
-                Ok(id) => (id, 0), // No error
-                Err(e) => {
-                    warn!("insert_wasm_instance_error: {:?}", &e);
-                    (0, E_WASM_INSERT_POOL_FAILED)
-                }
-            }
+            // The execution points of the start function are charged as the function execution
+            let gas_used = instance.gas_meter.lock().unwrap().used();
+            cost += gas_params.per_execution_point * NumBytes::new(gas_used);
+            wasm_context.consume_gas_budget(cost.into());
+            (wasm_context.insert_instance(instance), 0)
         }
         Err(e) => {
             warn!("create_wasm_instance_error: {:?}", &e);
@@ -98,9 +102,6 @@ fn native_create_wasm_instance(
         &instance_id, &error_code
     );
 
-    let mut cost = gas_params.base_create_instance;
-    cost += gas_params.per_byte_instance * NumBytes::new(wasm_bytes.len() as u64);
-
     Ok(NativeResult::ok(
         cost,
         smallvec![Value::u64(instance_id), Value::u64(error_code)],
@@ -262,7 +263,7 @@ impl WASMCreateArgsGasParameters {
 #[inline]
 fn native_create_wasm_args_in_memory(
     gas_params: &WASMCreateArgsGasParameters,
-    _context: &mut NativeContext,
+    context: &mut NativeContext,
     _ty_args: Vec<Type>,
     mut args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
@@ -294,17 +295,8 @@ fn native_create_wasm_args_in_memory(
 
     let mut data_ptr_list = Vec::new();
 
-    let instance_pool = get_instance_pool();
-    let mut pool_object = match instance_pool.lock() {
-        Ok(v) => v,
-        Err(_) => {
-            return Ok(NativeResult::err(
-                gas_params.base_create_args,
-                E_GET_INSTANCE_POOL_FAILED,
-            ))
-        }
-    };
-    match pool_object.get_mut(&instance_id) {
+    let wasm_context = context.extensions_mut().get_mut::<NativeWASMContext>();
+    match wasm_context.get_instance_mut(instance_id) {
         None => {
             return Ok(NativeResult::err(
                 gas_params.base_create_args,
@@ -367,15 +359,25 @@ fn build_err(cost: InternalGas, abort_code: u64) -> PartialVMResult<NativeResult
     Ok(NativeResult::err(cost, abort_code))
 }
 
+/// The max execution points of the WASM function that the gas budget of the session can pay for.
+fn wasm_gas_limit(gas_budget: u64, per_execution_point: InternalGasPerByte) -> u64 {
+    let per_execution_point: u64 = per_execution_point.into();
+    if per_execution_point == 0 {
+        gas_budget
+    } else {
+        gas_budget / per_execution_point
+    }
+}
+
 // native_execute_wasm_function
 #[inline]
 fn native_execute_wasm_function(
     gas_params: &WASMExecuteGasParameters,
-    _context: &mut NativeContext,
+    context: &mut NativeContext,
     _ty_args: Vec<Type>,
     args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
-    let vm_result = execute_wasm_function_inner(gas_params, _context, _ty_args, args);
+    let vm_result = execute_wasm_function_inner(gas_params, context, _ty_args, args);
     match vm_result {
         PartialVMResult::Ok(native_result) => match native_result {
             NativeResult::Success { cost, ret_vals } => {
@@ -406,7 +408,7 @@ fn native_execute_wasm_function(
 
 fn execute_wasm_function_inner(
     gas_params: &WASMExecuteGasParameters,
-    _context: &mut NativeContext,
+    context: &mut NativeContext,
     _ty_args: Vec<Type>,
     mut args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
@@ -421,23 +423,10 @@ fn execute_wasm_function_inner(
     let func_name = pop_arg!(args, Vec<u8>);
     let instance_id = pop_arg!(args, u64);
 
-    let instance_pool = get_instance_pool();
-    let mut pool_object = match instance_pool.lock() {
-        Ok(v) => v,
-        Err(e) => {
-            warn!(
-                "execute_wasm_function_inner->instance_pool_lock_error: {:?}",
-                &e
-            );
+    let wasm_context = context.extensions_mut().get_mut::<NativeWASMContext>();
+    let gas_limit = wasm_gas_limit(wasm_context.gas_budget(), gas_params.per_execution_point);
 
-            return Ok(NativeResult::err(
-                gas_params.base_create_execution,
-                E_GET_INSTANCE_POOL_FAILED,
-            ));
-        }
-    };
-
-    let ret = match pool_object.get_mut(&instance_id) {
+    let ret = match wasm_context.get_instance_mut(instance_id) {
         None => Ok(NativeResult::err(
             gas_params.base_create_execution,
             E_INSTANCE_NO_EXISTS,
@@ -451,6 +440,7 @@ fn execute_wasm_function_inner(
                 Ok(calling_function) => {
                     let mut gas_meter = instance.gas_meter.lock().unwrap();
                     gas_meter.reset();
+                    gas_meter.set_limit(gas_limit);
                     drop(gas_meter);
 
                     let mut wasm_func_args = Vec::new();
@@ -476,36 +466,29 @@ fn execute_wasm_function_inner(
                     // Set trap handler
                     instance.store.set_trap_handler(Some(trap_handler));
 
-                    match calling_function.call(&mut instance.store, wasm_func_args.as_slice()) {
+                    let call_result =
+                        calling_function.call(&mut instance.store, wasm_func_args.as_slice());
+
+                    // The execution is charged even if it failed, such as running out of the gas limit
+                    let gas_used = instance.gas_meter.lock().unwrap().used();
+                    debug!("execute_wasm_function_inner->gas_used: {:?}", gas_used);
+
+                    let mut cost = gas_params.base_create_execution;
+                    cost += gas_params.per_execution_point * NumBytes::new(gas_used);
+                    wasm_context.consume_gas_budget(cost.into());
+
+                    match call_result {
                         Ok(ret) => {
                             let return_value = match ret.deref().first() {
                                 Some(v) => v,
-                                None => {
-                                    return build_err(
-                                        gas_params.base_create_execution,
-                                        E_EMPTY_RETURN_VALUE,
-                                    )
-                                }
+                                None => return build_err(cost, E_EMPTY_RETURN_VALUE),
                             };
                             let offset = match return_value.i32() {
                                 Some(v) => v,
-                                None => {
-                                    return build_err(
-                                        gas_params.base_create_execution,
-                                        E_VALUE_NOT_I32,
-                                    )
-                                }
+                                None => return build_err(cost, E_VALUE_NOT_I32),
                             };
                             let ret_val = Value::u64(offset as u64);
 
-                            let mut gas_meter = instance.gas_meter.lock().unwrap();
-                            let gas_used = gas_meter.used();
-
-                            debug!("execute_wasm_function_inner->gas_used: {:?}", gas_used);
-
-                            let mut cost = gas_params.base_create_execution;
-                            cost += gas_params.per_execution_point * NumBytes::new(gas_used);
-
                             Ok(NativeResult::Success {
                                 cost,
                                 ret_vals: smallvec![ret_val],
@@ -519,10 +502,7 @@ fn execute_wasm_function_inner(
                             if log::log_enabled!(log::Level::Debug) {
                                 debug!("trace:{:?}", err.trace());
                             }
-                            Ok(NativeResult::err(
-                                gas_params.base_create_execution,
-                                E_WASM_EXECUTION_FAILED,
-                            ))
+                            Ok(NativeResult::err(cost, E_WASM_EXECUTION_FAILED))
                         }
                     }
                 }
@@ -559,7 +539,7 @@ impl WASMReadAddLength {
 #[inline]
 fn native_read_data_length(
     gas_params: &WASMReadAddLength,
-    _context: &mut NativeContext,
+    context: &mut NativeContext,
     _ty_args: Vec<Type>,
     mut args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
@@ -573,17 +553,8 @@ fn native_read_data_length(
     let data_ptr = pop_arg!(args, u64);
     let instance_id = pop_arg!(args, u64);
 
-    let instance_pool = get_instance_pool();
-    let mut pool_object = match instance_pool.lock() {
-        Ok(v) => v,
-        Err(_) => {
-            return Ok(NativeResult::err(
-                gas_params.base,
-                E_GET_INSTANCE_POOL_FAILED,
-            ))
-        }
-    };
-    let ret = match pool_object.get_mut(&instance_id) {
+    let wasm_context = context.extensions_mut().get_mut::<NativeWASMContext>();
+    let ret = match wasm_context.get_instance_mut(instance_id) {
         None => Ok(NativeResult::err(gas_params.base, E_INSTANCE_NO_EXISTS)),
         Some(instance) => {
             let memory = match instance.instance.exports.get_memory("memory") {
@@ -631,7 +602,7 @@ impl WASMReadHeapData {
 #[inline]
 fn native_read_data_from_heap(
     gas_params: &WASMReadHeapData,
-    _context: &mut NativeContext,
+    context: &mut NativeContext,
     _ty_args: Vec<Type>,
     mut args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
@@ -646,17 +617,8 @@ fn native_read_data_from_heap(
     let data_ptr = pop_arg!(args, u32);
     let instance_id = pop_arg!(args, u64);
 
-    let instance_pool = get_instance_pool();
-    let mut pool_object = match instance_pool.lock() {
-        Ok(v) => v,
-        Err(_) => {
-            return Ok(NativeResult::err(
-                gas_params.base,
-                E_GET_INSTANCE_POOL_FAILED,
-            ))
-        }
-    };
-    let ret = match pool_object.get_mut(&instance_id) {
+    let wasm_context = context.extensions_mut().get_mut::<NativeWASMContext>();
+    let ret = match wasm_context.get_instance_mut(instance_id) {
         None => Ok(NativeResult::err(gas_params.base, E_INSTANCE_NO_EXISTS)),
         Some(instance) => {
             let memory = match instance.instance.exports.get_memory("memory") {
@@ -729,7 +691,7 @@ impl WASMReleaseInstance {
 #[inline]
 fn native_release_wasm_instance(
     gas_params: &WASMReleaseInstance,
-    _context: &mut NativeContext,
+    context: &mut NativeContext,
     _ty_args: Vec<Type>,
     mut args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
@@ -751,33 +713,11 @@ fn native_release_wasm_instance(
 
     let instance_id = val.value_as::<u64>()?;
 
-    {
-        let instance_pool = get_instance_pool();
-        let mut pool_object = match instance_pool.lock() {
-            Ok(v) => v,
-            Err(_) => {
-                return Ok(NativeResult::err(
-                    gas_params.base,
-                    E_GET_INSTANCE_POOL_FAILED,
-                ))
-            }
-        };
-        match pool_object.get_mut(&instance_id) {
-            None => return Ok(NativeResult::err(gas_params.base, E_INSTANCE_NO_EXISTS)),
-            Some(_) => {}
-        };
+    let wasm_context = context.extensions_mut().get_mut::<NativeWASMContext>();
+    if wasm_context.remove_instance(instance_id).is_none() {
+        return Ok(NativeResult::err(gas_params.base, E_INSTANCE_NO_EXISTS));
     }
 
-    match moveos_wasm::wasm::remove_instance(instance_id) {
-        Ok(_) => {}
-        Err(_) => {
-            return Ok(NativeResult::err(
-                gas_params.base,
-                E_GET_INSTANCE_POOL_FAILED,
-            ))
-        }
-    };
-
     Ok(NativeResult::Success {
         cost: gas_params.base,
         ret_vals: smallvec![Value::bool(true)],
@@ -8,6 +8,7 @@ use move_vm_types::gas::{GasMeter, UnmeteredGasMeter};
 #[derive(Debug, Clone)]
 pub struct GasStatement {
     pub execution_gas_used: InternalGas,
+    pub io_gas_used: InternalGas,
     pub storage_gas_used: InternalGas,
 }
 
@@ -37,6 +38,7 @@ impl ClassifiedGasMeter for UnmeteredGasMeter {
     fn gas_statement(&self) -> GasStatement {
         GasStatement {
             execution_gas_used: InternalGas::from(0),
+            io_gas_used: InternalGas::from(0),
             storage_gas_used: InternalGas::from(0),
         }
     }
@@ -495,6 +495,7 @@ impl<G: GasMeter> ClassifiedGasMeter for GasProfiler<G> {
     fn gas_statement(&self) -> GasStatement {
         GasStatement {
             execution_gas_used: InternalGas::zero(),
+            io_gas_used: InternalGas::zero(),
             storage_gas_used: InternalGas::zero(),
         }
     }
@@ -1,9 +1,12 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{EVENT_COLUMN_FAMILY_NAME, EVENT_HANDLE_COLUMN_FAMILY_NAME};
+use crate::{
+    EVENT_COLUMN_FAMILY_NAME, EVENT_HANDLE_COLUMN_FAMILY_NAME, TX_EVENT_IDS_COLUMN_FAMILY_NAME,
+};
 use anyhow::{anyhow, Result};
 use move_core_types::language_storage::StructTag;
+use moveos_types::h256::H256;
 use moveos_types::moveos_std::event::{Event, EventHandle, EventID, TransactionEvent};
 use moveos_types::moveos_std::object::ObjectID;
 use raw_store::{derive_store, CodecKVStore, StoreInstance};
@@ -24,11 +27,20 @@ derive_store!(
     EVENT_HANDLE_COLUMN_FAMILY_NAME
 );
 
+derive_store!(
+    TxEventIdsDBStore,
+    H256,
+    Vec<EventID>,
+    TX_EVENT_IDS_COLUMN_FAMILY_NAME
+);
+
 pub trait EventStore {
     fn save_events(&self, events: Vec<TransactionEvent>) -> Result<Vec<EventID>>;
 
     fn get_event(&self, event_id: EventID) -> Result<Option<Event>>;
 
+    fn get_events_by_tx_hash(&self, tx_hash: H256) -> Result<Option<Vec<Event>>>;
+
     fn multi_get_events(&self, event_ids: Vec<EventID>) -> Result<Vec<Option<Event>>>;
 
     fn get_events_by_event_handle_id(
@@ -52,13 +64,15 @@ pub trait EventStore {
 pub struct EventDBStore {
     event_store: EventDBBaseStore,
     event_handle_store: EventHandleDBStore,
+    tx_event_ids_store: TxEventIdsDBStore,
 }
 
 impl EventDBStore {
     pub fn new(instance: StoreInstance) -> Self {
         EventDBStore {
             event_store: EventDBBaseStore::new(instance.clone()),
-            event_handle_store: EventHandleDBStore::new(instance),
+            event_handle_store: EventHandleDBStore::new(instance.clone()),
+            tx_event_ids_store: TxEventIdsDBStore::new(instance),
         }
     }
 
@@ -123,6 +137,34 @@ impl EventDBStore {
         Ok(event_ids)
     }
 
+    /// Save the ids of the events emitted by the transaction, so the events can be loaded by the tx hash.
+    pub fn save_tx_event_ids(&self, tx_hash: H256, event_ids: Vec<EventID>) -> Result<()> {
+        self.tx_event_ids_store.kv_put(tx_hash, event_ids)
+    }
+
+    pub fn remove_tx_event_ids(&self, tx_hash: H256) -> Result<()> {
+        self.tx_event_ids_store.remove(tx_hash)
+    }
+
+    /// Get the events emitted by the transaction.
+    /// Return None if the event ids of the transaction are not recorded, the transactions executed
+    /// before the event ids are recorded have no event ids.
+    pub fn get_events_by_tx_hash(&self, tx_hash: H256) -> Result<Option<Vec<Event>>> {
+        let event_ids = match self.tx_event_ids_store.kv_get(tx_hash)? {
+            Some(event_ids) => event_ids,
+            None => return Ok(None),
+        };
+        let events = self
+            .multi_get_events(event_ids.clone())?
+            .into_iter()
+            .zip(event_ids)
+            .map(|(event, event_id)| {
+                event.ok_or_else(|| anyhow!("Can not find event by id: {:?}", event_id))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Some(events))
+    }
+
     pub fn get_event(&self, event_id: EventID) -> Result<Option<Event>> {
         let key = (event_id.event_handle_id, event_id.event_seq);
         self.event_store.kv_get(key)
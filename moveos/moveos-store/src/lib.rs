@@ -47,6 +47,7 @@ pub const TRANSACTION_EXECUTION_INFO_COLUMN_FAMILY_NAME: ColumnFamilyName =
     "transaction_execution_info";
 pub const EVENT_COLUMN_FAMILY_NAME: ColumnFamilyName = "event";
 pub const EVENT_HANDLE_COLUMN_FAMILY_NAME: ColumnFamilyName = "event_handle";
+pub const TX_EVENT_IDS_COLUMN_FAMILY_NAME: ColumnFamilyName = "tx_event_ids";
 pub const CONFIG_STARTUP_INFO_COLUMN_FAMILY_NAME: ColumnFamilyName = "config_startup_info";
 pub const CONFIG_GENESIS_COLUMN_FAMILY_NAME: ColumnFamilyName = "config_genesis";
 
@@ -58,6 +59,7 @@ static VEC_COLUMN_FAMILY_NAME: Lazy<Vec<ColumnFamilyName>> = Lazy::new(|| {
         TRANSACTION_EXECUTION_INFO_COLUMN_FAMILY_NAME,
         EVENT_COLUMN_FAMILY_NAME,
         EVENT_HANDLE_COLUMN_FAMILY_NAME,
+        TX_EVENT_IDS_COLUMN_FAMILY_NAME,
         CONFIG_STARTUP_INFO_COLUMN_FAMILY_NAME,
         CONFIG_GENESIS_COLUMN_FAMILY_NAME,
     ]
@@ -147,12 +149,15 @@ impl MoveOSStore {
             mut changeset,
             events: tx_events,
             gas_used,
+            gas_used_breakdown: _,
             is_upgrade,
             is_gas_upgrade: _,
         } = output;
 
         self.state_store.apply_change_set(&mut changeset)?;
         let event_ids = self.event_store.save_events(tx_events.clone())?;
+        self.event_store
+            .save_tx_event_ids(tx_hash, event_ids.clone())?;
         let events = tx_events
             .clone()
             .into_iter()
@@ -233,6 +238,10 @@ impl EventStore for MoveOSStore {
         self.get_event_store().get_event(event_id)
     }
 
+    fn get_events_by_tx_hash(&self, tx_hash: H256) -> Result<Option<Vec<Event>>> {
+        self.get_event_store().get_events_by_tx_hash(tx_hash)
+    }
+
     fn multi_get_events(&self, event_ids: Vec<EventID>) -> Result<Vec<Option<Event>>> {
         self.get_event_store().multi_get_events(event_ids)
     }
@@ -5,17 +5,57 @@ pub mod metrics;
 pub mod statedb;
 
 use crate::STATE_NODE_COLUMN_FAMILY_NAME;
-use anyhow::Result;
+use anyhow::{ensure, Result};
 use moveos_types::h256::H256;
+use once_cell::sync::OnceCell;
 use raw_store::rocks::batch::WriteBatch;
-use raw_store::CodecKVStore;
-use raw_store::{derive_store, WriteOp};
+use raw_store::{
+    CodecKVStore, ColumnFamily, ColumnFamilyName, InnerStore, SchemaStore, StoreInstance, WriteOp,
+};
 use smt::{NodeReader, NodeWriter};
 use std::collections::BTreeMap;
+use std::sync::Arc;
 
-derive_store!(NodeDBStore, H256, Vec<u8>, STATE_NODE_COLUMN_FAMILY_NAME);
+pub type RemoteNodeReader = Arc<dyn NodeReader + Send + Sync>;
+
+#[derive(Clone)]
+pub struct NodeDBStore {
+    store: InnerStore<Self>,
+    /// The remote node reader of the fork mode,
+    /// the nodes missing in the local store are read from the remote and cached locally.
+    remote: Arc<OnceCell<RemoteNodeReader>>,
+}
+
+impl ColumnFamily for NodeDBStore {
+    type Key = H256;
+    type Value = Vec<u8>;
+
+    fn name() -> ColumnFamilyName {
+        STATE_NODE_COLUMN_FAMILY_NAME
+    }
+}
+
+impl SchemaStore for NodeDBStore {
+    fn get_store(&self) -> &InnerStore<Self> {
+        &self.store
+    }
+}
 
 impl NodeDBStore {
+    pub fn new(instance: StoreInstance) -> Self {
+        Self {
+            store: InnerStore::new(instance),
+            remote: Arc::new(OnceCell::new()),
+        }
+    }
+
+    /// Set the remote node reader, it can only be set once, and it is shared by all clones of the store.
+    pub fn set_remote_reader(&self, remote: RemoteNodeReader) -> Result<()> {
+        self.remote
+            .set(remote)
+            .map_err(|_| anyhow::anyhow!("The remote node reader is already set"))
+    }
+
     pub fn put(&self, key: H256, node: Vec<u8>) -> Result<()> {
         self.put_raw(key.as_bytes().to_vec(), node)
     }
@@ -33,7 +73,25 @@ impl NodeDBStore {
 
 impl NodeReader for NodeDBStore {
     fn get(&self, hash: &H256) -> Result<Option<Vec<u8>>> {
-        self.get_raw(hash.as_bytes())
+        let node = self.get_raw(hash.as_bytes())?;
+        match (node, self.remote.get()) {
+            (None, Some(remote)) => {
+                let node = remote.get(hash)?;
+                if let Some(node) = &node {
+                    // The remote is not trusted, verify the node before caching it
+                    let node_hash = smt::node_hash(node)?;
+                    ensure!(
+                        node_hash == *hash,
+                        "The hash of the remote node {:?} does not match the requested hash {:?}",
+                        node_hash,
+                        hash
+                    );
+                    self.put(*hash, node.clone())?;
+                }
+                Ok(node)
+            }
+            (node, _) => Ok(node),
+        }
     }
 }
 
@@ -1,15 +1,18 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::state_store::NodeDBStore;
 use crate::{MoveOSStore, StoreMeta};
 use anyhow::Result;
 use moveos_config::store_config::RocksdbConfig;
 use moveos_types::h256::H256;
+use moveos_types::state_resolver::StatelessResolver;
 use moveos_types::test_utils::random_state_change_set;
 use raw_store::metrics::DBMetrics;
 use raw_store::rocks::RocksDB;
-use raw_store::{StoreInstance, CF_METRICS_REPORT_PERIOD_MILLIS};
+use raw_store::{CodecKVStore, StoreInstance, CF_METRICS_REPORT_PERIOD_MILLIS};
 use smt::NodeReader;
+use std::sync::Arc;
 use std::time::Duration;
 
 #[tokio::test]
@@ -71,6 +74,86 @@ async fn test_statedb_state_root() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_remote_node_reader() -> Result<()> {
+    let (remote_store, _remote_dir) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let mut change_set = random_state_change_set();
+    remote_store
+        .get_state_store()
+        .apply_change_set(&mut change_set)?;
+    let state_root = change_set.state_root;
+
+    let (fork_store, _fork_dir) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    assert!(fork_store
+        .get_state_node_store()
+        .get_raw(state_root.as_bytes())?
+        .is_none());
+    fork_store
+        .get_state_node_store()
+        .set_remote_reader(Arc::new(remote_store.get_state_node_store().clone()))?;
+    assert!(fork_store
+        .get_state_node_store()
+        .set_remote_reader(Arc::new(remote_store.get_state_node_store().clone()))
+        .is_err());
+
+    for key in change_set.changes.keys() {
+        let remote_state = remote_store.get_field_at(state_root, key)?;
+        let fork_state = fork_store.get_field_at(state_root, key)?;
+        assert!(fork_state.is_some());
+        assert_eq!(remote_state, fork_state);
+    }
+    // The nodes read from the remote are cached in the local store
+    assert!(fork_store
+        .get_state_node_store()
+        .get_raw(state_root.as_bytes())?
+        .is_some());
+    Ok(())
+}
+
+struct TamperedNodeReader {
+    inner: NodeDBStore,
+}
+
+impl NodeReader for TamperedNodeReader {
+    fn get(&self, hash: &H256) -> Result<Option<Vec<u8>>> {
+        Ok(self.inner.get(hash)?.map(|mut node| {
+            if let Some(last) = node.last_mut() {
+                *last = last.wrapping_add(1);
+            }
+            node
+        }))
+    }
+}
+
+#[tokio::test]
+async fn test_tampered_remote_node() -> Result<()> {
+    let (remote_store, _remote_dir) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let mut change_set = random_state_change_set();
+    remote_store
+        .get_state_store()
+        .apply_change_set(&mut change_set)?;
+    let state_root = change_set.state_root;
+
+    let (fork_store, _fork_dir) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    fork_store
+        .get_state_node_store()
+        .set_remote_reader(Arc::new(TamperedNodeReader {
+            inner: remote_store.get_state_node_store().clone(),
+        }))?;
+
+    assert!(fork_store.get_state_node_store().get(&state_root).is_err());
+    // The tampered node is not cached in the local store
+    assert!(fork_store
+        .get_state_node_store()
+        .get_raw(state_root.as_bytes())?
+        .is_none());
+    Ok(())
+}
+
 // #[tokio::test]
 // async fn test_child_state_db_dump_and_apply() -> Result<()> {
 //     let mut moveos_store = MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
@@ -139,6 +139,15 @@ async fn test_event_store() {
     assert_eq!(event1.event_data, tx_events[1].event_data);
     assert_eq!(event1.event_index, tx_events[1].event_index);
     assert_eq!(event1.event_id.event_seq, 1);
+
+    let tx_hash = H256::random();
+    assert!(store.get_events_by_tx_hash(tx_hash).unwrap().is_none());
+    store
+        .get_event_store()
+        .save_tx_event_ids(tx_hash, event_ids)
+        .unwrap();
+    let events = store.get_events_by_tx_hash(tx_hash).unwrap().unwrap();
+    assert_eq!(events, vec![event0, event1]);
 }
 
 #[tokio::test]
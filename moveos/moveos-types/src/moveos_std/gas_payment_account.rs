@@ -0,0 +1,39 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    addresses::MOVEOS_STD_ADDRESS,
+    state::{MoveStructState, MoveStructType},
+};
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    identifier::IdentStr,
+    value::{MoveStructLayout, MoveTypeLayout},
+};
+use serde::{Deserialize, Serialize};
+
+/// The account which pays the gas of the transaction, it is stored in the `TxContext` map.
+/// If it is absent, the gas is paid by the sender.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct GasPaymentAccount {
+    pub account: AccountAddress,
+}
+
+impl GasPaymentAccount {
+    pub fn new(account: AccountAddress) -> Self {
+        Self { account }
+    }
+}
+
+impl MoveStructType for GasPaymentAccount {
+    const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = ident_str!("tx_context");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("GasPaymentAccount");
+}
+
+impl MoveStructState for GasPaymentAccount {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        MoveStructLayout::new(vec![MoveTypeLayout::Address])
+    }
+}
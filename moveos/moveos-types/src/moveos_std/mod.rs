@@ -8,6 +8,7 @@ pub mod any;
 pub mod copyable_any;
 pub mod display;
 pub mod event;
+pub mod gas_payment_account;
 pub mod gas_schedule;
 pub mod genesis;
 pub mod module_store;
@@ -19,6 +20,7 @@ pub mod simple_multimap;
 pub mod table;
 pub mod timestamp;
 pub mod tx_context;
+pub mod tx_gas_price;
 pub mod tx_meta;
 pub mod tx_result;
 pub mod type_info;
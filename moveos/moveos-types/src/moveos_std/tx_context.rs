@@ -7,6 +7,7 @@ use crate::addresses::MOVEOS_STD_ADDRESS;
 use crate::h256::{self, H256};
 use crate::move_std::string::MoveString;
 use crate::moveos_std::copyable_any::{Any, AnyTrait};
+use crate::moveos_std::gas_payment_account::GasPaymentAccount;
 use crate::moveos_std::gas_schedule::GasScheduleConfig;
 use crate::moveos_std::object::ObjectID;
 use crate::moveos_std::simple_map::SimpleMap;
@@ -136,6 +137,15 @@ impl TxContext {
         self.sender
     }
 
+    /// Return the account which pays the gas of the transaction,
+    /// it is the fee payer if the transaction is sponsored, otherwise the sender.
+    pub fn gas_payment_account(&self) -> Result<AccountAddress> {
+        Ok(self
+            .get::<GasPaymentAccount>()?
+            .map(|payment| payment.account)
+            .unwrap_or(self.sender))
+    }
+
     pub fn to_bytes(&self) -> Vec<u8> {
         debug_assert!(self.tx_hash.len() == h256::LENGTH);
         bcs::to_bytes(&self).unwrap()
@@ -214,4 +224,13 @@ mod tests {
         let serialized2 = decoded_value.simple_serialize().unwrap();
         assert_eq!(serialized, serialized2);
     }
+
+    #[test]
+    pub fn test_tx_context_gas_payment_account() {
+        let mut ctx = TxContext::random_for_testing_only();
+        assert_eq!(ctx.gas_payment_account().unwrap(), ctx.sender());
+        let fee_payer = AccountAddress::random();
+        ctx.add(GasPaymentAccount::new(fee_payer)).unwrap();
+        assert_eq!(ctx.gas_payment_account().unwrap(), fee_payer);
+    }
 }
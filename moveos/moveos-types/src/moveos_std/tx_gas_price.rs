@@ -0,0 +1,63 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    addresses::MOVEOS_STD_ADDRESS,
+    state::{MoveStructState, MoveStructType},
+};
+use move_core_types::{
+    account_address::AccountAddress,
+    ident_str,
+    identifier::IdentStr,
+    value::{MoveStructLayout, MoveTypeLayout},
+};
+use serde::{Deserialize, Serialize};
+
+/// The gas price bid of the transaction, it is stored in the `TxContext` map.
+/// If it is absent, the transaction pays the base fee without priority fee.
+#[derive(Clone, Copy, Debug, Hash, Deserialize, Serialize, PartialEq, Eq)]
+pub struct TxGasPrice {
+    /// The max gas price the sender is willing to pay, include the base fee and the priority fee
+    pub max_gas_price: u64,
+    /// The priority fee(tip) per gas paid on top of the base fee
+    pub priority_fee: u64,
+}
+
+impl TxGasPrice {
+    pub fn new(max_gas_price: u64, priority_fee: u64) -> Self {
+        Self {
+            max_gas_price,
+            priority_fee,
+        }
+    }
+
+    /// The gas price paid under the given base fee, it is the same as `transaction_fee::gas_price` in Move
+    pub fn gas_price(&self, base_fee: u64) -> u64 {
+        std::cmp::min(
+            base_fee.saturating_add(self.priority_fee),
+            self.max_gas_price,
+        )
+    }
+}
+
+impl std::fmt::Display for TxGasPrice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ max_gas_price: {}, priority_fee: {} }}",
+            self.max_gas_price, self.priority_fee
+        )
+    }
+}
+
+impl MoveStructType for TxGasPrice {
+    const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = ident_str!("tx_context");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("TxGasPrice");
+}
+
+impl MoveStructState for TxGasPrice {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        MoveStructLayout::new(vec![MoveTypeLayout::U64, MoveTypeLayout::U64])
+    }
+}
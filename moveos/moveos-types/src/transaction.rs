@@ -123,11 +123,15 @@ pub enum MoveAction {
 }
 
 impl MoveAction {
+    pub const SCRIPT_ACTION_TYPE: u8 = 0;
+    pub const FUNCTION_ACTION_TYPE: u8 = 1;
+    pub const MODULE_BUNDLE_ACTION_TYPE: u8 = 2;
+
     pub fn action_type(&self) -> u8 {
         match self {
-            MoveAction::Script(_) => 0,
-            MoveAction::Function(_) => 1,
-            MoveAction::ModuleBundle(_) => 2,
+            MoveAction::Script(_) => Self::SCRIPT_ACTION_TYPE,
+            MoveAction::Function(_) => Self::FUNCTION_ACTION_TYPE,
+            MoveAction::ModuleBundle(_) => Self::MODULE_BUNDLE_ACTION_TYPE,
         }
     }
 
@@ -415,6 +419,17 @@ impl Default for VMErrorInfo {
     }
 }
 
+/// The gas used of a transaction classified by the gas meter
+/// The gas of the object storage natives is counted as storage gas, the other natives as execution gas.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GasUsedBreakdown {
+    pub execution_gas_used: u64,
+    /// The gas for writing the transaction bytes
+    pub io_gas_used: u64,
+    /// The gas of the global storage operations and the object storage natives
+    pub storage_gas_used: u64,
+}
+
 /// RawTransactionOutput is the execution result of a MoveOS transaction
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct RawTransactionOutput {
@@ -424,6 +439,10 @@ pub struct RawTransactionOutput {
     pub changeset: StateChangeSet,
     pub events: Vec<TransactionEvent>,
     pub gas_used: u64,
+    /// It is only used for the gas estimation and is not serialized,
+    /// to keep the output compatible with the released genesis.
+    #[serde(skip)]
+    pub gas_used_breakdown: GasUsedBreakdown,
     pub is_upgrade: bool,
     pub is_gas_upgrade: bool,
 }
@@ -6,13 +6,14 @@ use std::collections::BTreeMap;
 use std::ops::Deref;
 
 use move_binary_format::binary_views::BinaryIndexedView;
+use move_binary_format::compatibility::Compatibility;
 use move_binary_format::errors::{Location, PartialVMError, PartialVMResult, VMError, VMResult};
 use move_binary_format::file_format::{
     Bytecode, FunctionDefinition, FunctionDefinitionIndex, FunctionHandleIndex,
     FunctionInstantiation, FunctionInstantiationIndex, Signature, SignatureToken, StructDefinition,
     StructFieldInformation, StructHandleIndex, Visibility,
 };
-use move_binary_format::{access::ModuleAccess, CompiledModule};
+use move_binary_format::{access::ModuleAccess, normalized, CompiledModule};
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::ModuleId;
 use move_core_types::resolver::ModuleResolver;
@@ -2032,6 +2033,23 @@ impl TypeDepthFormula {
     }
 }
 
+/// Check the struct and function compatibility between the new and old modules,
+/// this is the same rule as the on-chain module upgrade check.
+pub fn check_module_compatibility(
+    old_module: &CompiledModule,
+    new_module: &CompiledModule,
+) -> PartialVMResult<()> {
+    // TODO: config compatibility through global configuration
+    // We allow `friend` function to be broken
+    let compat = Compatibility::new(true, true, false);
+    if !compat.need_check_compat() {
+        return Ok(());
+    }
+    let old_m = normalized::Module::new(old_module);
+    let new_m = normalized::Module::new(new_module);
+    compat.check(&old_m, &new_m)
+}
+
 /// Check the metadata compatibility between the new and old modules.
 pub fn check_metadata_compatibility(
     old_module: &CompiledModule,
@@ -21,6 +21,10 @@ impl GasMeter {
         self.gas_used = 0;
     }
 
+    pub fn set_limit(&mut self, gas_limit: u64) {
+        self.gas_limit = gas_limit;
+    }
+
     pub fn charge(&mut self, amount: u64) -> Result<(), RuntimeError> {
         if self.gas_used + amount > self.gas_limit {
             Err(RuntimeError::new("GAS limit exceeded"))
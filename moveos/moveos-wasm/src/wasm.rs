@@ -2,11 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
 use std::ops::Deref;
 use std::sync::{Arc, Mutex};
 
+use better_any::{Tid, TidAble};
+use lru::LruCache;
 use once_cell::sync::Lazy;
-use rand;
+use sha3::{Digest, Sha3_256};
 use tracing::{debug, error, warn};
 use wasmer::Value::I32;
 use wasmer::*;
@@ -17,7 +20,9 @@ use crate::cost_function::cost_function;
 use crate::gas_meter::GasMeter;
 use crate::middlewares::gas_metering::GasMiddleware;
 
-const GAS_LIMIT: u64 = 500000;
+/// The magic header of the binary WASM module, the module without it is treated as the text format.
+const WASM_BINARY_MAGIC: &[u8] = b"\0asm";
+const MODULE_CACHE_SIZE: usize = 64;
 
 pub struct WASMInstance {
     pub bytecode: Vec<u8>,
@@ -42,48 +47,95 @@ impl WASMInstance {
     }
 }
 
-/*
-TODO:
-The WASMInstance must be protected by the locker which owned by the signer
-    while we enable the parallel tx execution.
-The way we're doing it now is by using this big global lock, but it's too broad.
- */
-static mut GLOBAL_INSTANCE_POOL: Lazy<Arc<Mutex<BTreeMap<u64, WASMInstance>>>> =
-    Lazy::new(|| Arc::new(Mutex::new(BTreeMap::new())));
-
-pub fn insert_wasm_instance(instance: WASMInstance) -> anyhow::Result<u64> {
-    loop {
-        unsafe {
-            let instance_id: u64 = rand::random();
-            let mut instance_pool = match GLOBAL_INSTANCE_POOL.lock() {
-                Ok(pool_guard) => pool_guard,
-                Err(_) => {
-                    return Err(anyhow::Error::msg("get global instance pool failed"));
-                }
-            };
-            if instance_pool.get(&instance_id).is_none() {
-                instance_pool.insert(instance_id, instance);
-                return Ok(instance_id);
-            }
+/// The WASM instances created in a Move session, the instances are released when the session is finished.
+/// The `gas_budget` is the gas left for the WASM execution, it limits the execution points of the WASM functions,
+/// and the cost of the execution is charged to the Move gas meter by the native functions.
+#[derive(Tid)]
+pub struct NativeWASMContext {
+    instances: BTreeMap<u64, WASMInstance>,
+    next_instance_id: u64,
+    gas_budget: u64,
+}
+
+impl NativeWASMContext {
+    pub fn new(gas_budget: u64) -> Self {
+        Self {
+            instances: BTreeMap::new(),
+            next_instance_id: 1,
+            gas_budget,
         }
     }
+
+    pub fn insert_instance(&mut self, instance: WASMInstance) -> u64 {
+        let instance_id = self.next_instance_id;
+        self.next_instance_id += 1;
+        self.instances.insert(instance_id, instance);
+        instance_id
+    }
+
+    pub fn get_instance_mut(&mut self, instance_id: u64) -> Option<&mut WASMInstance> {
+        self.instances.get_mut(&instance_id)
+    }
+
+    pub fn remove_instance(&mut self, instance_id: u64) -> Option<WASMInstance> {
+        self.instances.remove(&instance_id)
+    }
+
+    pub fn gas_budget(&self) -> u64 {
+        self.gas_budget
+    }
+
+    pub fn consume_gas_budget(&mut self, amount: u64) {
+        self.gas_budget = self.gas_budget.saturating_sub(amount);
+    }
 }
 
-pub fn get_instance_pool() -> Arc<Mutex<BTreeMap<u64, WASMInstance>>> {
-    unsafe { GLOBAL_INSTANCE_POOL.clone() }
+/// All the modules are compiled by the same engine, so the compiled modules can be shared by the stores of the instances.
+static ENGINE: Lazy<Engine> = Lazy::new(|| {
+    let mut compiler = Singlepass::new();
+    // Add gas meter middleware
+    let gas_middleware = GasMiddleware::new(Some(Arc::new(cost_function)));
+    compiler.push_middleware(Arc::new(gas_middleware));
+    compiler.into()
+});
+
+/// The compiled modules keyed by the sha3-256 hash of the WASM bytecode.
+/// The modules are compiled under the lock, the gas middleware of the engine does not support compiling concurrently.
+static MODULE_CACHE: Lazy<Mutex<LruCache<Vec<u8>, Module>>> = Lazy::new(|| {
+    Mutex::new(LruCache::new(
+        NonZeroUsize::new(MODULE_CACHE_SIZE).expect("module cache size must be non-zero"),
+    ))
+});
+
+/// Convert the code to the WASM binary, the code can be the binary module or the text format.
+pub fn wasm_bytecode(code: &[u8]) -> anyhow::Result<Vec<u8>> {
+    if code.starts_with(WASM_BINARY_MAGIC) {
+        return Ok(code.to_vec());
+    }
+    wasmer::wat2wasm(code)
+        .map(|bytecode| bytecode.to_vec())
+        .map_err(|e| anyhow::Error::msg(e.to_string()))
 }
 
-pub fn remove_instance(instance_id: u64) -> anyhow::Result<()> {
-    unsafe {
-        let mut instance_pool = match GLOBAL_INSTANCE_POOL.lock() {
-            Ok(pool_guard) => pool_guard,
-            Err(_) => {
-                return Err(anyhow::Error::msg("get global instance pool failed"));
-            }
-        };
-        instance_pool.remove(&instance_id);
-        Ok(())
+fn load_module(bytecode: &[u8]) -> anyhow::Result<Module> {
+    let code_hash = Sha3_256::digest(bytecode).to_vec();
+    let mut cache = match MODULE_CACHE.lock() {
+        Ok(cache) => cache,
+        Err(_) => return Err(anyhow::Error::msg("get module cache failed")),
+    };
+    if let Some(module) = cache.get(&code_hash) {
+        return Ok(module.clone());
     }
+
+    let module = match Module::new(ENGINE.deref(), bytecode) {
+        Ok(m) => m,
+        Err(e) => {
+            debug!("create_wasm_instance->new_module_error:{:?}", &e);
+            return Err(anyhow::Error::msg(e.to_string()));
+        }
+    };
+    cache.put(code_hash, module.clone());
+    Ok(module)
 }
 
 #[allow(dead_code)]
@@ -262,34 +314,17 @@ fn charge(env: FunctionEnvMut<Env>, amount: i64) -> Result<(), wasmer::RuntimeEr
     gas_meter.charge(amount as u64)
 }
 
-pub fn create_wasm_instance(code: &[u8]) -> anyhow::Result<WASMInstance> {
+/// Create the WASM instance from the binary or text format `code`, the `gas_limit` limits the execution of the start function,
+/// the gas limit of the function execution is set by the caller before each execution.
+pub fn create_wasm_instance(code: &[u8], gas_limit: u64) -> anyhow::Result<WASMInstance> {
     // Create the GasMeter
-    let gas_meter = Arc::new(Mutex::new(GasMeter::new(GAS_LIMIT)));
-
-    // Create and configure the compiler
-    let mut compiler = Singlepass::new();
-
-    // Add gas meter middleware
-    let gas_middleware = GasMiddleware::new(Some(Arc::new(cost_function)));
-    compiler.push_middleware(Arc::new(gas_middleware));
+    let gas_meter = Arc::new(Mutex::new(GasMeter::new(gas_limit)));
 
     // Create the store
-    let mut store = Store::new(compiler);
-
-    let bytecode = match wasmer::wat2wasm(code) {
-        Ok(m) => m,
-        Err(e) => {
-            return Err(anyhow::Error::msg(e.to_string()));
-        }
-    };
+    let mut store = Store::new(ENGINE.clone());
 
-    let module = match Module::new(&store, bytecode.clone()) {
-        Ok(m) => m,
-        Err(e) => {
-            debug!("create_wasm_instance->new_module_error:{:?}", &e);
-            return Err(anyhow::Error::msg(e.to_string()));
-        }
-    };
+    let bytecode = wasm_bytecode(code)?;
+    let module = load_module(&bytecode)?;
 
     let env = FunctionEnv::new(
         &mut store,
@@ -324,10 +359,77 @@ pub fn create_wasm_instance(code: &[u8]) -> anyhow::Result<WASMInstance> {
         env.as_mut(&mut store).memory = Some(Arc::new(Mutex::new(memory.clone())));
     }
 
-    Ok(WASMInstance::new(
-        bytecode.to_vec(),
-        instance,
-        store,
-        gas_meter,
-    ))
+    Ok(WASMInstance::new(bytecode, instance, store, gas_meter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADD_WAT: &str = r#"
+        (module
+            (func (export "add") (param i32 i32) (result i32)
+                local.get 0
+                local.get 1
+                i32.add))
+    "#;
+
+    #[test]
+    fn test_wasm_bytecode() {
+        let bytecode = wasm_bytecode(ADD_WAT.as_bytes()).unwrap();
+        assert!(bytecode.starts_with(WASM_BINARY_MAGIC));
+        // The binary module is used as is
+        assert_eq!(wasm_bytecode(&bytecode).unwrap(), bytecode);
+        // The code without the magic header is parsed as the text format
+        assert!(wasm_bytecode(&bytecode[WASM_BINARY_MAGIC.len()..]).is_err());
+        assert!(wasm_bytecode(b"(module").is_err());
+    }
+
+    #[test]
+    fn test_module_cache() {
+        let bytecode = wasm_bytecode(ADD_WAT.as_bytes()).unwrap();
+        let module = load_module(&bytecode).unwrap();
+        assert!(MODULE_CACHE
+            .lock()
+            .unwrap()
+            .contains(&Sha3_256::digest(&bytecode).to_vec()));
+
+        // The cached module is returned without compiling, the invalid code is loaded from the cache
+        let invalid_code = b"cached invalid wasm code".to_vec();
+        assert!(Module::new(ENGINE.deref(), &invalid_code).is_err());
+        MODULE_CACHE
+            .lock()
+            .unwrap()
+            .put(Sha3_256::digest(&invalid_code).to_vec(), module);
+        assert!(load_module(&invalid_code).is_ok());
+    }
+
+    #[test]
+    fn test_session_instances() {
+        let mut session1 = NativeWASMContext::new(1000);
+        let mut session2 = NativeWASMContext::new(2000);
+        let instance_id1 =
+            session1.insert_instance(create_wasm_instance(ADD_WAT.as_bytes(), 1000).unwrap());
+        let instance_id2 =
+            session2.insert_instance(create_wasm_instance(ADD_WAT.as_bytes(), 2000).unwrap());
+        // The instance ids are allocated per session
+        assert_eq!(instance_id1, 1);
+        assert_eq!(instance_id2, 1);
+
+        // The instances share the cached module, but not the store
+        let instance = session2.get_instance_mut(instance_id2).unwrap();
+        let add = instance.instance.exports.get_function("add").unwrap();
+        let result = add.call(&mut instance.store, &[I32(1), I32(2)]).unwrap();
+        assert_eq!(result.first().and_then(|v| v.i32()), Some(3));
+
+        assert!(session1.remove_instance(instance_id1).is_some());
+        assert!(session1.get_instance_mut(instance_id1).is_none());
+        assert!(session2.get_instance_mut(instance_id2).is_some());
+
+        session1.consume_gas_budget(600);
+        assert_eq!(session1.gas_budget(), 400);
+        assert_eq!(session2.gas_budget(), 2000);
+        session1.consume_gas_budget(600);
+        assert_eq!(session1.gas_budget(), 0);
+    }
 }
@@ -18,6 +18,7 @@ use move_core_types::vm_status::StatusCode;
 use move_vm_types::gas::{GasMeter, SimpleInstruction};
 use move_vm_types::views::{TypeView, ValueView};
 use moveos_common::types::{ClassifiedGasMeter, GasStatement, SwitchableGasMeter};
+use moveos_types::addresses::MOVEOS_STD_ADDRESS;
 use moveos_types::moveos_std::gas_schedule::GasSchedule;
 use moveos_types::state_resolver::StateResolver;
 use once_cell::sync::Lazy;
@@ -525,6 +526,14 @@ impl GasCost {
     }
 }
 
+/// The kind of the gas charged by the gas meter, it is used to classify the gas used of a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GasKind {
+    Execution,
+    IO,
+    Storage,
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct MoveOSGasMeter {
@@ -534,7 +543,10 @@ pub struct MoveOSGasMeter {
     charge: bool,
 
     execution_gas_used: Rc<RefCell<InternalGas>>,
+    io_gas_used: Rc<RefCell<InternalGas>>,
     storage_gas_used: Rc<RefCell<InternalGas>>,
+    // The kind of the gas charged by the next native function, it is set when calling the function.
+    native_gas_kind: GasKind,
 
     // The current height of the operand stack, and the maximal height that it has reached.
     stack_height_high_water_mark: u64,
@@ -574,7 +586,9 @@ impl MoveOSGasMeter {
             cost_table,
             charge: true,
             execution_gas_used: Rc::new(RefCell::new(InternalGas::from(0))),
+            io_gas_used: Rc::new(RefCell::new(InternalGas::from(0))),
             storage_gas_used: Rc::new(RefCell::new(InternalGas::from(0))),
+            native_gas_kind: GasKind::Execution,
             stack_height_high_water_mark: 0,
             stack_height_current: 0,
             stack_size_high_water_mark: 0,
@@ -599,7 +613,9 @@ impl MoveOSGasMeter {
             gas_left: InternalGas::from(0),
             charge: false,
             execution_gas_used: Rc::new(RefCell::new(InternalGas::from(0))),
+            io_gas_used: Rc::new(RefCell::new(InternalGas::from(0))),
             storage_gas_used: Rc::new(RefCell::new(InternalGas::from(0))),
+            native_gas_kind: GasKind::Execution,
             stack_height_high_water_mark: 0,
             stack_height_current: 0,
             stack_height_next_tier_start: None,
@@ -690,22 +706,57 @@ impl MoveOSGasMeter {
     }
 
     pub fn deduct_gas(&mut self, cost: InternalGas) -> PartialVMResult<()> {
+        self.deduct_gas_with_kind(cost, GasKind::Execution)
+    }
+
+    /// The storage of Rooch goes through the object natives,
+    /// so the gas of the natives in the `moveos_std::object` module is classified as storage gas.
+    fn set_native_gas_kind(&mut self, module_id: &ModuleId, func_name: &str) {
+        self.native_gas_kind = if module_id.address() == &MOVEOS_STD_ADDRESS
+            && module_id.name().as_str() == "object"
+            && func_name.starts_with("native_")
+        {
+            GasKind::Storage
+        } else {
+            GasKind::Execution
+        };
+    }
+
+    /// Charge the gas of the global storage operations
+    fn charge_storage(&mut self, cost: InternalGas) -> PartialVMResult<()> {
+        self.deduct_gas_with_kind(cost, GasKind::Storage)
+    }
+
+    fn deduct_gas_with_kind(&mut self, cost: InternalGas, kind: GasKind) -> PartialVMResult<()> {
         if !self.charge {
             return Ok(());
         }
 
         match self.gas_left.checked_sub(cost) {
             None => {
+                // The remaining gas is used up by the failed charge
+                self.record_gas_used(self.gas_left, kind);
                 self.gas_left = InternalGas::from(0);
                 Err(PartialVMError::new(StatusCode::OUT_OF_GAS))
             }
             Some(gas_left) => {
                 self.gas_left = gas_left;
+                self.record_gas_used(cost, kind);
                 Ok(())
             }
         }
     }
 
+    fn record_gas_used(&self, cost: InternalGas, kind: GasKind) {
+        let gas_used = match kind {
+            GasKind::Execution => &self.execution_gas_used,
+            GasKind::IO => &self.io_gas_used,
+            GasKind::Storage => &self.storage_gas_used,
+        };
+        let new_value = gas_used.borrow().add(cost);
+        *gas_used.borrow_mut() = new_value;
+    }
+
     pub fn set_metering(&mut self, enabled: bool) {
         self.charge = enabled;
     }
@@ -740,10 +791,11 @@ impl ClassifiedGasMeter for MoveOSGasMeter {
 
         match tx_size.checked_mul(tx_gas_parameter) {
             None => {
+                self.record_gas_used(self.gas_left, GasKind::IO);
                 self.gas_left = InternalGas::from(0);
                 Err(PartialVMError::new(StatusCode::OUT_OF_GAS))
             }
-            Some(final_gas) => self.charge_v1(InternalGas::from(final_gas)),
+            Some(final_gas) => self.deduct_gas_with_kind(InternalGas::from(final_gas), GasKind::IO),
         }
     }
     //TODO cleanup
@@ -816,8 +868,9 @@ impl ClassifiedGasMeter for MoveOSGasMeter {
         }
 
         let execution_gas_used = *self.execution_gas_used.borrow();
+        let io_gas_used = *self.io_gas_used.borrow();
         let storage_gas_used = *self.storage_gas_used.borrow();
-        if InternalGas::from(gas_used) != execution_gas_used + storage_gas_used {
+        if InternalGas::from(gas_used) != execution_gas_used + io_gas_used + storage_gas_used {
             return Err(PartialVMError::new(StatusCode::ABORTED)
                 .with_message("Failed to check the constraints of the gas_used.".to_owned()));
         }
@@ -827,6 +880,7 @@ impl ClassifiedGasMeter for MoveOSGasMeter {
     fn gas_statement(&self) -> GasStatement {
         GasStatement {
             execution_gas_used: *self.execution_gas_used.borrow(),
+            io_gas_used: *self.io_gas_used.borrow(),
             storage_gas_used: *self.storage_gas_used.borrow(),
         }
     }
@@ -919,11 +973,12 @@ impl GasMeter for MoveOSGasMeter {
 
     fn charge_call(
         &mut self,
-        _module_id: &ModuleId,
-        _func_name: &str,
+        module_id: &ModuleId,
+        func_name: &str,
         args: impl ExactSizeIterator<Item = impl ValueView>,
         num_locals: NumArgs,
     ) -> PartialVMResult<()> {
+        self.set_native_gas_kind(module_id, func_name);
         let call_base = self.cost_table.instruction_gas_parameter.call_base;
         let call_per_arg = self.cost_table.instruction_gas_parameter.call_per_arg;
         let cost = call_base + call_per_arg * NumArgs::new(args.len() as u64);
@@ -933,12 +988,13 @@ impl GasMeter for MoveOSGasMeter {
 
     fn charge_call_generic(
         &mut self,
-        _module_id: &ModuleId,
-        _func_name: &str,
+        module_id: &ModuleId,
+        func_name: &str,
         ty_args: impl ExactSizeIterator<Item = impl TypeView>,
         args: impl ExactSizeIterator<Item = impl ValueView>,
         num_locals: NumArgs,
     ) -> PartialVMResult<()> {
+        self.set_native_gas_kind(module_id, func_name);
         let call_generic_base = self.cost_table.instruction_gas_parameter.call_generic_base;
         let call_generic_per_type_arg = self
             .cost_table
@@ -1142,10 +1198,10 @@ impl GasMeter for MoveOSGasMeter {
             .instruction_gas_parameter
             .mut_borrow_global_generic_base;
         match (is_mut, is_generic) {
-            (false, false) => self.charge_v1(imm_borrow_global_base),
-            (false, true) => self.charge_v1(imm_borrow_global_generic_base),
-            (true, false) => self.charge_v1(mut_borrow_global_base),
-            (true, true) => self.charge_v1(mut_borrow_global_generic_base),
+            (false, false) => self.charge_storage(imm_borrow_global_base),
+            (false, true) => self.charge_storage(imm_borrow_global_generic_base),
+            (true, false) => self.charge_storage(mut_borrow_global_base),
+            (true, true) => self.charge_storage(mut_borrow_global_generic_base),
         }
     }
 
@@ -1162,8 +1218,8 @@ impl GasMeter for MoveOSGasMeter {
             .exists_generic_base;
 
         match is_generic {
-            false => self.charge_v1(exists_base),
-            true => self.charge_v1(exists_generic_base),
+            false => self.charge_storage(exists_base),
+            true => self.charge_storage(exists_generic_base),
         }
     }
 
@@ -1180,8 +1236,8 @@ impl GasMeter for MoveOSGasMeter {
             .move_from_generic_base;
 
         match is_generic {
-            false => self.charge_v1(move_from_base),
-            true => self.charge_v1(move_from_generic_base),
+            false => self.charge_storage(move_from_base),
+            true => self.charge_storage(move_from_generic_base),
         }
     }
 
@@ -1199,8 +1255,8 @@ impl GasMeter for MoveOSGasMeter {
             .move_from_generic_base;
 
         match is_generic {
-            false => self.charge_v1(move_to_base),
-            true => self.charge_v1(move_to_generic_base),
+            false => self.charge_storage(move_to_base),
+            true => self.charge_storage(move_to_generic_base),
         }
     }
 
@@ -1293,7 +1349,7 @@ impl GasMeter for MoveOSGasMeter {
         amount: InternalGas,
         _ret_vals: Option<impl ExactSizeIterator<Item = impl ValueView>>,
     ) -> PartialVMResult<()> {
-        self.charge_v1(amount)
+        self.deduct_gas_with_kind(amount, self.native_gas_kind)
     }
 
     fn charge_native_function_before_execution(
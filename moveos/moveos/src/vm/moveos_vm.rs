@@ -35,7 +35,10 @@ use moveos_stdlib::natives::moveos_stdlib::{
     event::NativeEventContext, move_module::NativeModuleContext,
 };
 use moveos_types::state::ObjectState;
-use moveos_types::{addresses, transaction::RawTransactionOutput};
+use moveos_types::{
+    addresses,
+    transaction::{GasUsedBreakdown, RawTransactionOutput},
+};
 use moveos_types::{
     function_return_value::FunctionReturnValue,
     move_std::string::MoveString,
@@ -48,6 +51,7 @@ use moveos_types::{
     transaction::{FunctionCall, MoveAction, VerifiedMoveAction},
 };
 use moveos_verifier::verifier::INIT_FN_NAME_IDENTIFIER;
+use moveos_wasm::wasm::NativeWASMContext;
 use parking_lot::RwLock;
 use std::collections::BTreeSet;
 use std::rc::Rc;
@@ -186,10 +190,13 @@ where
         object_runtime: Rc<RwLock<ObjectRuntime<'r>>>,
     ) -> Session<'r, 'l, MoveosDataCache<'r, 'l, S>> {
         let mut extensions = NativeContextExtensions::default();
+        // The WASM execution can not use more gas than the transaction gas budget
+        let max_gas_amount = object_runtime.read().tx_context().max_gas_amount;
 
         extensions.add(ObjectRuntimeContext::new(object_runtime.clone()));
         extensions.add(NativeModuleContext::new(remote));
         extensions.add(NativeEventContext::default());
+        extensions.add(NativeWASMContext::new(max_gas_amount));
 
         // The VM code loader has bugs around module upgrade. After a module upgrade, the internal
         // cache needs to be flushed to work around those bugs.
@@ -572,9 +579,15 @@ where
             remote: _,
             session,
             object_runtime,
-            gas_meter: _,
+            gas_meter,
             read_only,
         } = self;
+        let gas_statement = gas_meter.gas_statement();
+        let gas_used_breakdown = GasUsedBreakdown {
+            execution_gas_used: gas_statement.execution_gas_used.into(),
+            io_gas_used: gas_statement.io_gas_used.into(),
+            storage_gas_used: gas_statement.storage_gas_used.into(),
+        };
         let (changeset, raw_events, mut extensions) = session.finish_with_extensions()?;
         //We do not use the event API from data_cache. Instead, we use the NativeEventContext
         debug_assert!(raw_events.is_empty());
@@ -668,6 +681,7 @@ where
                 changeset: state_changeset,
                 events,
                 gas_used,
+                gas_used_breakdown,
                 is_upgrade,
                 is_gas_upgrade: false,
             },
@@ -11,6 +11,7 @@ use std::marker::PhantomData;
 use std::path::Path;
 
 use anyhow::{ensure, format_err, Error, Result};
+use rocksdb::checkpoint::Checkpoint;
 use rocksdb::{
     statistics, AsColumnFamilyRef, BlockBasedIndexType, BlockBasedOptions, CStrLike, Cache,
     ColumnFamily, ColumnFamilyDescriptor, DBCompressionType, DBRawIterator, DBRecoveryMode,
@@ -214,6 +215,21 @@ impl RocksDB {
         Ok(())
     }
 
+    /// Create a consistent point-in-time checkpoint of all column families into `path`.
+    /// The SST files are hard-linked when the `path` is on the same filesystem, so it is cheap
+    /// and can be taken while the DB is still serving writes. The `path` must not exist.
+    pub fn create_checkpoint(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        ensure!(
+            !path.exists(),
+            "Checkpoint path {:?} already exists",
+            path.display()
+        );
+        let checkpoint = Checkpoint::new(&self.db)?;
+        checkpoint.create_checkpoint(path)?;
+        Ok(())
+    }
+
     /// List cf
     pub fn list_cf(path: impl AsRef<Path>) -> Result<Vec<String>, Error> {
         Ok(DB::list_cf(&Options::default(), path)?)
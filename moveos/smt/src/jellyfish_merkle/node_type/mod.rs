@@ -628,6 +628,44 @@ where
     }
 }
 
+/// Computes the hash of a serialized node without decoding the key and the value,
+/// it is used to verify the nodes read from an untrusted source.
+pub(crate) fn encoded_node_hash(val: &[u8]) -> Result<SMTNodeHash> {
+    if val.is_empty() {
+        return Err(NodeDecodeError::EmptyInput.into());
+    }
+    let tag = val[0];
+    let node_tag = NodeTag::from_u8(tag);
+    match node_tag {
+        Some(NodeTag::Null) => Ok(*SPARSE_MERKLE_PLACEHOLDER_HASH_VALUE),
+        Some(NodeTag::Internal) => Ok(InternalNode::deserialize(&val[1..])?.merkle_hash()),
+        Some(NodeTag::Leaf) => {
+            let raw_kv: RawKV = bcs::from_bytes(&val[1..])?;
+            Ok(
+                SparseMerkleLeafNode::new(
+                    raw_kv.key.into(),
+                    SMTNodeHash::tag_sha256(&raw_kv.value),
+                )
+                .merkle_hash(),
+            )
+        }
+        None => Err(NodeDecodeError::UnknownTag { unknown_tag: tag }.into()),
+    }
+}
+
+/// Returns the child hashes of a serialized internal node, the other nodes have no children.
+pub(crate) fn encoded_node_children(val: &[u8]) -> Result<Vec<SMTNodeHash>> {
+    match val.first().and_then(|tag| NodeTag::from_u8(*tag)) {
+        Some(NodeTag::Internal) => {
+            let internal_node = InternalNode::deserialize(&val[1..])?;
+            let mut children = internal_node.children.into_iter().collect::<Vec<_>>();
+            children.sort_by_key(|(nibble, _)| *nibble);
+            Ok(children.into_iter().map(|(_, child)| child.hash).collect())
+        }
+        _ => Ok(vec![]),
+    }
+}
+
 impl<K, V> SMTHash for Node<K, V>
 where
     K: Key,
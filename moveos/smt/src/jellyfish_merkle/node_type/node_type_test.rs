@@ -82,6 +82,48 @@ fn test_encode_decode() {
     }
 }
 
+#[test]
+fn test_encoded_node_hash() {
+    let nibble_path = random_63nibblepath();
+    let leaf1_node: Node<TestKey, TestValue> = Node::new_leaf(
+        gen_leaf_keys(&nibble_path, Nibble::from(1)),
+        TestValue::from(vec![0x00]),
+    );
+    let leaf2_node: Node<TestKey, TestValue> = Node::new_leaf(
+        gen_leaf_keys(&nibble_path, Nibble::from(2)),
+        TestValue::from(vec![0x01]),
+    );
+    let mut children = Children::default();
+    children.insert(Nibble::from(1), Child::new(leaf1_node.merkle_hash(), true));
+    children.insert(Nibble::from(2), Child::new(leaf2_node.merkle_hash(), true));
+    let nodes: Vec<Node<TestKey, TestValue>> = vec![
+        Node::new_null(),
+        Node::new_internal(children),
+        leaf1_node,
+        leaf2_node,
+    ];
+    let children_hashes = vec![nodes[2].merkle_hash(), nodes[3].merkle_hash()];
+    assert_eq!(
+        encoded_node_children(&nodes[1].encode().unwrap()).unwrap(),
+        children_hashes
+    );
+    assert!(encoded_node_children(&nodes[2].encode().unwrap())
+        .unwrap()
+        .is_empty());
+    for node in &nodes {
+        let encoded = node.encode().unwrap();
+        assert_eq!(encoded_node_hash(&encoded).unwrap(), node.merkle_hash());
+        // The tampered node has a different hash
+        let mut tampered = encoded.clone();
+        *tampered.last_mut().unwrap() ^= 0x01;
+        if let Ok(hash) = encoded_node_hash(&tampered) {
+            assert_ne!(hash, node.merkle_hash());
+        }
+    }
+    assert!(encoded_node_hash(&[]).is_err());
+    assert!(encoded_node_hash(&[100]).is_err());
+}
+
 proptest! {
     #[test]
     fn test_u64_varint_roundtrip(input in any::<u64>()) {
@@ -30,6 +30,18 @@ mod smt_object;
 pub(crate) mod tests;
 mod update_set;
 
+/// Computes the hash of the serialized tree node, the node read from an untrusted source
+/// should be verified by comparing the hash with the requested node hash.
+pub fn node_hash(node: &[u8]) -> Result<H256> {
+    jellyfish_merkle::node_type::encoded_node_hash(node).map(Into::into)
+}
+
+/// Returns the child node hashes of the serialized internal node, it is used to prefetch the nodes.
+pub fn node_children(node: &[u8]) -> Result<Vec<H256>> {
+    jellyfish_merkle::node_type::encoded_node_children(node)
+        .map(|children| children.into_iter().map(Into::into).collect())
+}
+
 /// MerkeHashType is a trait to indicate the type can be converted to H256.
 /// So the type can be used as the key of the Sparse Merkle Tree, and do not need to hash again.
 
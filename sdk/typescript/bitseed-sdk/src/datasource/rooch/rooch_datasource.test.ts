@@ -816,7 +816,7 @@ describe('RoochDataSource', () => {
       const mockResponsePage1: PaginatedInscriptionStateViews = {
         data: mockInscriptionsPage1,
         has_next_page: true,
-        next_cursor: { state_index: '1', tx_order: '1' }
+        next_cursor: '0101000000000000000201000000000000000100000000000000'
       };
   
       const mockResponsePage2: PaginatedInscriptionStateViews = {
@@ -2010,7 +2010,7 @@ describe('RoochDataSource', () => {
           }
         ],
         has_next_page: true,
-        next_cursor: { state_index: '1', tx_order: '1' }
+        next_cursor: '0101000000000000000201000000000000000100000000000000'
       };
 
       const mockUTXOsPage2: PaginatedUTXOStateViews = {
@@ -2469,7 +2469,7 @@ describe('RoochDataSource', () => {
           }
         ],
         has_next_page: true,
-        next_cursor: { state_index: '1', tx_order: '1' }
+        next_cursor: '0101000000000000000201000000000000000100000000000000'
       };
     
       const mockUTXOsPage2: PaginatedUTXOStateViews = {
@@ -2776,7 +2776,7 @@ describe('RoochDataSource', () => {
           }
         ],
         has_next_page: true,
-        next_cursor: { state_index: '1', tx_order: '1' }
+        next_cursor: '0101000000000000000201000000000000000100000000000000'
       };
     
       mockTransport.setMockResponse('btc_queryUTXOs', mockUTXOsPage1);
@@ -25,7 +25,6 @@ import {
 import {
   getRoochNodeUrl,
   RoochClient,
-  IndexerStateIDView,
   PaginatedUTXOStateViews,
   RoochTransport,
   PaginatedInscriptionStateViews,
@@ -83,7 +82,7 @@ export class RoochDataSource implements IDatasource {
 
   async getBalance({ address }: GetBalanceOptions): Promise<number> {
     let totalBalance = 0n
-    let cursor: IndexerStateIDView | null = null
+    let cursor: string | null = null
     const limit = 100
 
     while (true) {
@@ -233,9 +232,7 @@ export class RoochDataSource implements IDatasource {
     decodeMetadata,
   }: GetInscriptionsOptions): Promise<Inscription[]> {
     const inscriptions: Inscription[] = []
-    let cursor: IndexerStateIDView | null = next
-      ? { state_index: next.split(':')[0], tx_order: next.split(':')[1] }
-      : null
+    let cursor: string | null = next ?? null
     const pageLimit = Math.min(limit || 100, 100) // Max 100 per page
 
     // Check for unsupported filter types
@@ -447,7 +444,7 @@ export class RoochDataSource implements IDatasource {
     }
 
     const spendables: ExtendedUTXOLimited[] = []
-    let cursor: IndexerStateIDView | null = null
+    let cursor: string | null = null
     let totalSats = 0
 
     while (totalSats < value && spendables.length < limit) {
@@ -562,7 +559,7 @@ export class RoochDataSource implements IDatasource {
     let spendableUTXOs: UTXO[] = []
     let unspendableUTXOs: UTXO[] = []
     let totalUTXOs = 0
-    let cursor: IndexerStateIDView | null = next ? JSON.parse(next) : null
+    let cursor: string | null = next ?? null
 
     while (spendableUTXOs.length + unspendableUTXOs.length < limit) {
       const response: PaginatedUTXOStateViews = await this.roochClient.queryUTXO({
@@ -191,6 +191,17 @@ export const RoochTransactionData = bcs.struct('RoochTransactionData', {
   action: MoveAction,
 })
 
+export const TxGasPrice = bcs.struct('TxGasPrice', {
+  maxGasPrice: bcs.u64(),
+  priorityFee: bcs.u64(),
+})
+
+// The extension of the transaction data, it is appended to the v0 transaction data bytes only if it is not empty.
+export const RoochTransactionDataExt = bcs.struct('RoochTransactionDataExt', {
+  gasPrice: bcs.option(TxGasPrice),
+  feePayer: bcs.option(Address),
+})
+
 export const Authenticator = bcs.struct('Authenticator', {
   authValidatorId: bcs.u64(),
   payload: bcs.vector(bcs.u8()),
@@ -200,3 +211,12 @@ export const RoochTransaction = bcs.struct('RoochTransaction', {
   data: raw(bcs.u8()),
   auth: raw(bcs.u8()),
 })
+
+// The v1 layout of the transaction, the v0 layout followed by the extension of the transaction data
+// and the authenticator of the fee payer.
+export const RoochTransactionV1 = bcs.struct('RoochTransactionV1', {
+  data: raw(bcs.u8()),
+  auth: raw(bcs.u8()),
+  ext: raw(bcs.u8()),
+  feePayer: bcs.option(raw(bcs.u8())),
+})
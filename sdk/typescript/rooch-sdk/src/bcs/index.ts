@@ -16,7 +16,10 @@ import {
   raw,
   RoochTransaction,
   RoochTransactionData,
+  RoochTransactionDataExt,
+  RoochTransactionV1,
   ScriptCall,
+  TxGasPrice,
 } from './bcs.js'
 
 export { BcsType, type BcsTypeOptions } from '@mysten/bcs'
@@ -44,8 +47,11 @@ export const RoochBcs = {
   CallFunction,
   MoveAction,
   RoochTransactionData,
+  TxGasPrice,
+  RoochTransactionDataExt,
   Authenticator,
   RoochTransaction,
+  RoochTransactionV1,
   ...bcs,
 }
 
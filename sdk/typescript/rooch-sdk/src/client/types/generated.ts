@@ -396,7 +396,7 @@ export interface PaginatedEventViews {
 export interface PaginatedIndexerEventViews {
   data: IndexerEventView[]
   has_next_page: boolean
-  next_cursor?: IndexerEventIDView | null
+  next_cursor?: string | null
 }
 /**
  * `next_cursor` points to the last item in the page; Reading with `next_cursor` will start from the
@@ -406,7 +406,7 @@ export interface PaginatedIndexerEventViews {
 export interface PaginatedIndexerObjectStateViews {
   data: IndexerObjectStateView[]
   has_next_page: boolean
-  next_cursor?: IndexerStateIDView | null
+  next_cursor?: string | null
 }
 /**
  * `next_cursor` points to the last item in the page; Reading with `next_cursor` will start from the
@@ -416,7 +416,7 @@ export interface PaginatedIndexerObjectStateViews {
 export interface PaginatedInscriptionStateViews {
   data: InscriptionStateView[]
   has_next_page: boolean
-  next_cursor?: IndexerStateIDView | null
+  next_cursor?: string | null
 }
 /**
  * `next_cursor` points to the last item in the page; Reading with `next_cursor` will start from the
@@ -446,7 +446,7 @@ export interface PaginatedTransactionWithInfoViews {
 export interface PaginatedUTXOStateViews {
   data: UTXOStateView[]
   has_next_page: boolean
-  next_cursor?: IndexerStateIDView | null
+  next_cursor?: string | null
 }
 export interface QueryOptions {
   /** If true, the state is decoded and the decoded value is returned in the response. */
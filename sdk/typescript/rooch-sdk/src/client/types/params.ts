@@ -20,14 +20,14 @@ export interface BroadcastTXParams {
 /** Query the Inscription via global index by Inscription filter */
 export interface QueryInscriptionsParams {
   filter: RpcTypes.InscriptionFilterView
-  cursor?: RpcTypes.IndexerStateIDView | null | undefined
+  cursor?: string | null | undefined
   limit?: string | null | undefined
   descendingOrder?: boolean | null | undefined
 }
 /** Query the UTXO via global index by UTXO filter */
 export interface QueryUTXOsParams {
   filter: RpcTypes.UTXOFilterView
-  cursor?: RpcTypes.IndexerStateIDView | null | undefined
+  cursor?: string | null | undefined
   limit?: string | null | undefined
   descendingOrder?: boolean | null | undefined
 }
@@ -118,14 +118,14 @@ export interface ListStatesParams {
 /** Query the events indexer by event filter */
 export interface QueryEventsParams {
   filter: RpcTypes.EventFilterView
-  cursor?: RpcTypes.IndexerEventIDView | null | undefined
+  cursor?: string | null | undefined
   limit?: string | null | undefined
   queryOption?: RpcTypes.QueryOptions | null | undefined
 }
 /** Query the object states indexer by state filter */
 export interface QueryObjectStatesParams {
   filter: RpcTypes.ObjectStateFilterView
-  cursor?: RpcTypes.IndexerStateIDView | null | undefined
+  cursor?: string | null | undefined
   limit?: string | null | undefined
   queryOption?: RpcTypes.QueryOptions | null | undefined
 }
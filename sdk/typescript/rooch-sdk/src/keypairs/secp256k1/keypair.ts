@@ -161,6 +161,16 @@ export class Secp256k1Keypair extends Keypair {
     )
   }
 
+  /**
+   * Co-sign the sponsored transaction as the fee payer, the fee payer signs the fee payer hash of the transaction.
+   */
+  async signFeePayerTransaction(input: Transaction): Promise<Authenticator> {
+    return await Authenticator.bitcoin(
+      new BitcoinSignMessage(input.hashFeePayerData(), 'Fee Payer:'),
+      this,
+    )
+  }
+
   /**
    * Derive Secp256k1 keypair from mnemonics and path. The mnemonics must be normalized
    * and validated against the english wordlist.
@@ -4,7 +4,7 @@
 import { describe, it, expect } from 'vitest'
 
 import { bcs } from '../bcs/index.js'
-import { str } from '../utils/index.js'
+import { bytesEqual, str } from '../utils/index.js'
 import { BitcoinSignMessage } from '../crypto/index.js'
 import { Secp256k1Keypair } from '../keypairs/index.js'
 import { Transaction } from '../transactions/index.js'
@@ -31,4 +31,97 @@ describe('Transactions', () => {
     const result = await signer.getPublicKey().verify(bitcoinMessage.hash(), payload.signature)
     expect(result).toBeTruthy()
   })
+
+  it('encode transaction with gas price', async () => {
+    const signer = new Secp256k1Keypair()
+
+    const tx = new Transaction()
+    tx.callFunction({
+      target: '0x3::empty::empty_with_signer',
+    })
+
+    tx.setSender(signer.getRoochAddress().toHexAddress())
+    tx.setSeqNumber(BigInt(0))
+    tx.setChainId(BigInt(4))
+
+    const v0Hash = tx.hashData()
+    const v0Data = bcs.RoochTransactionData.serialize({
+      sender: signer.getRoochAddress().toHexAddress(),
+      sequenceNumber: BigInt(0),
+      chainId: BigInt(4),
+      maxGas: BigInt(50000000),
+      action: {
+        kind: 'CallFunction',
+        functionId: {
+          moduleId: {
+            address: '0x3',
+            name: 'empty',
+          },
+          name: 'empty_with_signer',
+        },
+        args: [],
+        typeArgs: [],
+      },
+    }).toBytes()
+
+    tx.setGasPrice({ maxGasPrice: BigInt(100), priorityFee: BigInt(10) })
+    expect(bytesEqual(tx.hashData(), v0Hash)).toBeFalsy()
+
+    tx.setAuth(await signer.signTransaction(tx))
+    const bytes = tx.encode().toBytes()
+    // the v1 transaction starts with the v0 transaction data bytes
+    expect(bytesEqual(bytes.slice(0, v0Data.length), v0Data)).toBeTruthy()
+    // and ends with the extension and the empty fee payer
+    const ext = bcs.RoochTransactionDataExt.serialize({
+      gasPrice: { maxGasPrice: BigInt(100), priorityFee: BigInt(10) },
+      feePayer: null,
+    }).toBytes()
+    expect(
+      bytesEqual(bytes.slice(bytes.length - ext.length - 1, bytes.length - 1), ext),
+    ).toBeTruthy()
+    expect(bytes[bytes.length - 1]).toBe(0)
+  })
+
+  it('sign sponsored transaction', async () => {
+    const sender = new Secp256k1Keypair()
+    const sponsor = new Secp256k1Keypair()
+
+    const tx = new Transaction()
+    tx.callFunction({
+      target: '0x3::empty::empty_with_signer',
+    })
+
+    tx.setSender(sender.getRoochAddress().toHexAddress())
+    tx.setSeqNumber(BigInt(0))
+    tx.setChainId(BigInt(4))
+    const hash = tx.hashData()
+
+    // the sender commits to the fee payer
+    tx.setFeePayer(sponsor.getRoochAddress().toHexAddress())
+    expect(bytesEqual(tx.hashData(), hash)).toBeFalsy()
+    // the fee payer signs a different hash from the sender
+    expect(bytesEqual(tx.hashFeePayerData(), tx.hashData())).toBeFalsy()
+
+    tx.setAuth(await sender.signTransaction(tx))
+    const feePayerAuth = await sponsor.signFeePayerTransaction(tx)
+    tx.setFeePayerAuth(feePayerAuth)
+
+    // the fee payer can not be attached to the transaction without fee payer
+    const other = new Transaction()
+    other.callFunction({
+      target: '0x3::empty::empty_with_signer',
+    })
+    expect(() => other.setFeePayerAuth(feePayerAuth)).toThrow()
+
+    const payload = bcs.BitcoinAuthPayload.parse(feePayerAuth.payload)
+    const message = new BitcoinSignMessage(tx.hashFeePayerData(), str('utf8', payload.messageInfo))
+    const result = await sponsor.getPublicKey().verify(message.hash(), payload.signature)
+    expect(result).toBeTruthy()
+
+    const bytes = tx.encode().toBytes()
+    const auth = feePayerAuth.encode()
+    // the v1 transaction ends with the authenticator of the fee payer
+    expect(bytes[bytes.length - auth.length - 1]).toBe(1)
+    expect(bytesEqual(bytes.slice(bytes.length - auth.length), auth)).toBeTruthy()
+  })
 })
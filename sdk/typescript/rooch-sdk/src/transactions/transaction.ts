@@ -5,12 +5,13 @@ import { bcs } from '../bcs/index.js'
 import { Authenticator } from '../crypto/index.js'
 import { address, Bytes, u64 } from '../types/index.js'
 
-import { MoveAction, TransactionData } from './transactionData.js'
+import { MoveAction, TransactionData, TxGasPrice } from './transactionData.js'
 import { CallFunctionArgs } from './types.js'
 
 export class Transaction {
   private data: TransactionData | undefined
   private auth: Authenticator | undefined
+  private feePayerAuth: Authenticator | undefined
   private info: string | undefined
 
   callFunction(
@@ -42,14 +43,44 @@ export class Transaction {
     this.getData().sequenceNumber = input
   }
 
+  // Set the max gas price and the priority fee(tip) of the transaction
+  setGasPrice(input: TxGasPrice) {
+    this.getData().gasPrice = input
+  }
+
+  // Designate the fee payer(sponsor) of the transaction, it must be set before the sender signs
+  setFeePayer(input: address) {
+    this.getData().feePayer = input
+  }
+
+  setFeePayerAuth(input: Authenticator) {
+    if (this.getData().feePayer === undefined) {
+      throw new Error('The transaction does not designate a fee payer.')
+    }
+    this.feePayerAuth = input
+  }
+
+  // The hash signed by the fee payer
+  hashFeePayerData(): Bytes {
+    return this.getData().feePayerHash()
+  }
+
   hashData(): Bytes {
     return this.getData().hash()
   }
 
   encode() {
-    return bcs.RoochTransaction.serialize({
-      data: this.data!.encode(),
+    if (!this.data!.hasExt()) {
+      return bcs.RoochTransaction.serialize({
+        data: this.data!.encode(),
+        auth: this.auth!.encode(),
+      })
+    }
+    return bcs.RoochTransactionV1.serialize({
+      data: this.data!.encodeV0(),
       auth: this.auth!.encode(),
+      ext: this.data!.encodeExt(),
+      feePayer: this.feePayerAuth?.encode() ?? null,
     })
   }
 
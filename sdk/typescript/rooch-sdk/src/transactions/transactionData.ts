@@ -1,13 +1,14 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-import { sha3_256 } from '../utils/index.js'
+import { bytes, concatBytes, sha3_256 } from '../utils/index.js'
 import { normalizeRoochAddress } from '../address/index.js'
 import { Args, bcs, Serializer } from '../bcs/index.js'
 import { address, Bytes, identifier, u8, u64 } from '../types/index.js'
 import { CallFunctionArgs, CallScript } from './types.js'
 
 const DEFAULT_GAS = BigInt(50000000)
+const FEE_PAYER_SIGNING_PREFIX = 'ROOCH::FeePayer::'
 
 export class CallFunction {
   address: string
@@ -65,12 +66,20 @@ export class MoveAction {
   }
 }
 
+export type TxGasPrice = {
+  maxGasPrice: u64
+  priorityFee: u64
+}
+
 export class TransactionData {
   sender?: address
   sequenceNumber?: u64
   chainId?: u64
   maxGas: u64
   action: MoveAction
+  gasPrice?: TxGasPrice
+  // The fee payer(sponsor) who pays the gas on behalf of the sender, it is committed by the sender's signature.
+  feePayer?: address
 
   constructor(
     action: MoveAction,
@@ -86,7 +95,8 @@ export class TransactionData {
     this.maxGas = maxGas || DEFAULT_GAS
   }
 
-  encode(): Bytes {
+  // Encode the v0 transaction data, without the extension.
+  encodeV0(): Bytes {
     const call = this.action.val as CallFunction
 
     return bcs.RoochTransactionData.serialize({
@@ -109,7 +119,36 @@ export class TransactionData {
     }).toBytes()
   }
 
+  hasExt(): boolean {
+    return this.gasPrice !== undefined || this.feePayer !== undefined
+  }
+
+  encodeExt(): Bytes {
+    return bcs.RoochTransactionDataExt.serialize({
+      gasPrice: this.gasPrice ?? null,
+      feePayer: this.feePayer ?? null,
+    }).toBytes()
+  }
+
+  // The v0 transaction data bytes followed by the extension if it is not empty, the transaction hash is computed over it.
+  encode(): Bytes {
+    const v0 = this.encodeV0()
+    if (!this.hasExt()) {
+      return v0
+    }
+    const ext = this.encodeExt()
+    const bytes = new Uint8Array(v0.length + ext.length)
+    bytes.set(v0)
+    bytes.set(ext, v0.length)
+    return bytes
+  }
+
   hash(): Bytes {
     return sha3_256(this.encode())
   }
+
+  // The hash signed by the fee payer, it is different from the hash signed by the sender.
+  feePayerHash(): Bytes {
+    return sha3_256(concatBytes(bytes('utf8', FEE_PAYER_SIGNING_PREFIX), this.hash()))
+  }
 }